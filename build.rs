@@ -6,6 +6,10 @@ use std::str::{self, FromStr};
 // opening a GitHub issue if your build environment requires some way to enable
 // these cfgs other than by executing our build script.
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(syn_omit_await_from_token_macro)");
+    println!("cargo:rustc-check-cfg=cfg(syn_disable_nightly_tests)");
+    println!("cargo:rustc-check-cfg=cfg(syn_unstable_diagnostics)");
+
     let compiler = match rustc_version() {
         Some(compiler) => compiler,
         None => return,
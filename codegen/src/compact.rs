@@ -0,0 +1,244 @@
+use crate::error::Result;
+use crate::file;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::Index;
+use syn_codegen::{Data, Definitions, Features, Node, Type};
+
+const COMPACT_SRC: &str = "../src/gen/compact.rs";
+
+// Variants that are left out when they are the value of a field.
+const DEFAULTS: &[(&str, &str)] = &[
+    ("Visibility", "Inherited"),
+    ("PathArguments", "None"),
+    ("ReturnType", "Default"),
+];
+
+// Types that are left out when they are the value of a field and none of
+// their fields are printed.
+const EMPTY_DEFAULTS: &[&str] = &["Generics"];
+
+// Optional punctuation that is implied by the fields around it, and left out
+// rather than printed as its text.
+const IMPLIED_TOKENS: &[&str] = &["Colon", "Comma", "Eq", "Gt", "Lt", "Or", "Semi"];
+
+// Types for which the CompactDebug trait is implemented by hand, in
+// src/compact.rs or src/lit.rs.
+const HANDWRITTEN: &[&str] = &["Lifetime", "LitBool", "Path"];
+
+fn requires_full(features: &Features) -> bool {
+    features.any.contains("full") && features.any.len() == 1
+}
+
+fn is_full_only(ty: &Type, defs: &Definitions) -> bool {
+    match ty {
+        Type::Box(t) => is_full_only(t, defs),
+        Type::Syn(t) => requires_full(&lookup(defs, t).features),
+        _ => false,
+    }
+}
+
+fn lookup<'a>(defs: &'a Definitions, name: &str) -> &'a Node {
+    defs.types.iter().find(|node| node.ident == name).unwrap()
+}
+
+// The text of an optional token, like `mut`.
+fn token_text<'a>(defs: &'a Definitions, ty: &Type) -> &'a str {
+    match ty {
+        Type::Token(t) => &defs.tokens[t],
+        Type::Group(t) => match t.as_str() {
+            "Paren" => "()",
+            "Bracket" => "[]",
+            "Brace" => "{}",
+            _ => unimplemented!("group {}", t),
+        },
+        _ => unreachable!(),
+    }
+}
+
+// The expression building the Sexpr of the value `val`, a reference to a
+// field of type `ty`. None for fields that are never printed, like tokens.
+fn value(defs: &Definitions, ty: &Type, val: &TokenStream) -> Option<TokenStream> {
+    match ty {
+        Type::Token(_) | Type::Group(_) => None,
+        Type::Syn(t) if t == "Reserved" => None,
+        Type::Ext(t) if t == "Span" => None,
+        Type::Option(t) => match &**t {
+            Type::Token(t) if IMPLIED_TOKENS.contains(&t.as_str()) => None,
+            Type::Token(_) | Type::Group(_) => {
+                let text = token_text(defs, t);
+                Some(quote!(Sexpr::flag(#val.is_some(), #text)))
+            }
+            Type::Tuple(_) => {
+                let inner = value(defs, t, &quote!(val))?;
+                Some(quote!(#val.as_ref().map_or_else(Sexpr::none, |val| #inner)))
+            }
+            _ => Some(quote!(#val.sexpr())),
+        },
+        Type::Tuple(elems) => {
+            let elems: Vec<TokenStream> = elems
+                .iter()
+                .enumerate()
+                .filter_map(|(i, elem)| {
+                    let i = Index::from(i);
+                    value(defs, elem, &quote!(#val.#i))
+                })
+                .collect();
+            if elems.len() == 1 {
+                elems.into_iter().next()
+            } else {
+                Some(quote!(Sexpr::tuple(vec![#(#elems),*])))
+            }
+        }
+        _ => Some(quote!(#val.sexpr())),
+    }
+}
+
+// Whether a variant with the given fields is printed as the node it holds.
+fn is_transparent(defs: &Definitions, fields: &[Type]) -> bool {
+    match fields {
+        [Type::Syn(t)] => match lookup(defs, t).data {
+            Data::Struct(_) | Data::Private => true,
+            Data::Enum(_) => false,
+        },
+        _ => false,
+    }
+}
+
+fn expand(defs: &Definitions, node: &Node) -> TokenStream {
+    let ty = Ident::new(&node.ident, Span::call_site());
+    let name = &node.ident;
+
+    let body = match &node.data {
+        Data::Enum(variants) => {
+            let mut arms = TokenStream::new();
+            let mut any_full_only = false;
+            for (v, fields) in variants {
+                let variant = Ident::new(v, Span::call_site());
+                let cfg = if !requires_full(&node.features)
+                    && fields.iter().any(|field| is_full_only(field, defs))
+                {
+                    any_full_only = true;
+                    Some(quote!(#[cfg(feature = "full")]))
+                } else {
+                    None
+                };
+                let bindings: Vec<Ident> = (0..fields.len())
+                    .map(|i| Ident::new(&format!("_v{}", i), Span::call_site()))
+                    .collect();
+                let pat = if fields.is_empty() {
+                    quote!(#ty::#variant)
+                } else {
+                    quote!(#ty::#variant(#(#bindings),*))
+                };
+                if is_transparent(defs, fields) {
+                    arms.extend(quote!(#cfg #pat => _v0.sexpr(),));
+                    continue;
+                }
+                let args: Vec<TokenStream> = fields
+                    .iter()
+                    .zip(&bindings)
+                    .filter_map(|(ty, binding)| value(defs, ty, &quote!(#binding)))
+                    .collect();
+                let default = if DEFAULTS.contains(&(name.as_str(), v.as_str())) {
+                    Some(quote!(.into_default()))
+                } else {
+                    None
+                };
+                if args.is_empty() {
+                    arms.extend(quote!(#cfg #pat => Sexpr::atom(#v)#default,));
+                } else {
+                    let path = format!("{}::{}", name, v);
+                    arms.extend(quote! {
+                        #cfg
+                        #pat => {
+                            let mut node = Sexpr::node(#path);
+                            #(node.arg(#args);)*
+                            node #default
+                        }
+                    });
+                }
+            }
+            if !node.exhaustive {
+                arms.extend(quote!(#ty::__Nonexhaustive => unreachable!(),));
+            }
+            if any_full_only {
+                arms.extend(quote! {
+                    #[cfg(not(feature = "full"))]
+                    _ => unreachable!(),
+                });
+            }
+            quote! {
+                match self {
+                    #arms
+                }
+            }
+        }
+        Data::Struct(fields) => {
+            let fields: Vec<TokenStream> = fields
+                .iter()
+                .filter_map(|(f, ty)| {
+                    let ident = Ident::new(f, Span::call_site());
+                    let value = value(defs, ty, &quote!(self.#ident))?;
+                    Some(quote!(node.field(#f, #value);))
+                })
+                .collect();
+            let default = if EMPTY_DEFAULTS.contains(&name.as_str()) {
+                Some(quote!(.default_if_empty()))
+            } else {
+                None
+            };
+            if fields.is_empty() {
+                quote!(Sexpr::node(#name) #default)
+            } else {
+                quote! {
+                    let mut node = Sexpr::node(#name);
+                    #(#fields)*
+                    node #default
+                }
+            }
+        }
+        Data::Private => return TokenStream::new(),
+    };
+
+    let features = &node.features.any;
+    let cfg = match features.len() {
+        0 => quote!(),
+        1 => quote!(#[cfg(feature = #(#features)*)]),
+        _ => quote!(#[cfg(any(#(feature = #features),*))]),
+    };
+
+    quote! {
+        #cfg
+        impl Sealed for #ty {}
+
+        #cfg
+        impl CompactDebug for #ty {
+            fn sexpr(&self) -> Sexpr {
+                #body
+            }
+        }
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let mut impls = TokenStream::new();
+    for node in &defs.types {
+        if node.ident == "Reserved" || HANDWRITTEN.contains(&node.ident.as_str()) {
+            continue;
+        }
+        impls.extend(expand(defs, node));
+    }
+
+    file::write(
+        COMPACT_SRC,
+        quote! {
+            use crate::compact::{CompactDebug, Sexpr};
+            use crate::sealed::compact::Sealed;
+            use crate::*;
+
+            #impls
+        },
+    )?;
+    Ok(())
+}
@@ -0,0 +1,338 @@
+use crate::error::Result;
+use crate::file;
+use crate::operand::{Borrowed, Operand, Owned};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::Index;
+use syn_codegen::{Data, Definitions, Features, Node, Type};
+
+const DIFF_SRC: &str = "../src/gen/diff.rs";
+
+fn requires_full(features: &Features) -> bool {
+    features.any.contains("full") && features.any.len() == 1
+}
+
+fn is_node(ty: &Type) -> bool {
+    match ty {
+        Type::Syn(t) => t != "Reserved",
+        Type::Ext(t) => t == "Ident",
+        _ => false,
+    }
+}
+
+fn is_full_only(ty: &Type, defs: &Definitions) -> bool {
+    match ty {
+        Type::Box(t) => is_full_only(t, defs),
+        Type::Syn(t) => {
+            let target = defs.types.iter().find(|ty| ty.ident == *t).unwrap();
+            requires_full(&target.features)
+        }
+        _ => false,
+    }
+}
+
+// The node inside of an optional tuple, like the expression in the
+// `Option<(Token![=], Expr)>` of a local binding, that is reported as added or
+// removed when the tuple as a whole is.
+fn representative(elems: &[Type]) -> Option<(usize, &Type)> {
+    let mut nodes = elems.iter().enumerate().filter_map(|(i, elem)| match elem {
+        Type::Box(t) if is_node(t) => Some((i, &**t)),
+        _ if is_node(elem) => Some((i, elem)),
+        _ => None,
+    });
+    let first = nodes.next();
+    if nodes.next().is_some() {
+        None
+    } else {
+        first
+    }
+}
+
+// An expression that is true if the parts of `old` and `new` that are not
+// nodes themselves differ, in which case the change is reported on the node
+// containing them.
+fn leaf_changed(ty: &Type, old: &Operand, new: &Operand) -> Option<TokenStream> {
+    let (old_val, new_val) = (old.tokens(), new.tokens());
+    match ty {
+        Type::Syn(_) | Type::Box(_) | Type::Vec(_) | Type::Punctuated(_) => None,
+        Type::Token(_) | Type::Group(_) => None,
+        Type::Ext(t) if t == "Ident" || t == "Span" => None,
+        Type::Option(t) => match &**t {
+            Type::Syn(_) | Type::Box(_) => None,
+            Type::Ext(t) if t == "Ident" => None,
+            Type::Tuple(elems) => {
+                let mut changed = Vec::new();
+                for (i, elem) in elems.iter().enumerate() {
+                    let i = Index::from(i);
+                    let old = Owned(quote!(old.#i));
+                    let new = Owned(quote!(new.#i));
+                    changed.extend(leaf_changed(elem, &old, &new));
+                }
+                let mismatch = representative(elems).is_none();
+                if changed.is_empty() {
+                    if mismatch {
+                        Some(quote!(#old_val.is_some() != #new_val.is_some()))
+                    } else {
+                        None
+                    }
+                } else {
+                    let (old_ref, new_ref) = (old.ref_tokens(), new.ref_tokens());
+                    let otherwise = if mismatch {
+                        quote! {
+                            (None, None) => false,
+                            _ => true,
+                        }
+                    } else {
+                        quote!(_ => false,)
+                    };
+                    Some(quote! {
+                        match (#old_ref, #new_ref) {
+                            (Some(old), Some(new)) => #(#changed)||*,
+                            #otherwise
+                        }
+                    })
+                }
+            }
+            _ => Some(quote!(#old_val != #new_val)),
+        },
+        Type::Tuple(_) => unimplemented!("tuple outside of Option"),
+        Type::Ext(t) if t == "TokenStream" => {
+            let (old_ref, new_ref) = (old.ref_tokens(), new.ref_tokens());
+            Some(quote!(TokenStreamHelper(#old_ref) != TokenStreamHelper(#new_ref)))
+        }
+        Type::Ext(_) | Type::Std(_) => Some(quote!(#old_val != #new_val)),
+    }
+}
+
+// Code that diffs the nodes contained in `old` and `new` of type `ty`.
+fn recurse(ty: &Type, old: &Operand, new: &Operand) -> Option<TokenStream> {
+    let (old_ref, new_ref) = (old.ref_tokens(), new.ref_tokens());
+    let (old_val, new_val) = (old.tokens(), new.tokens());
+    let code = match ty {
+        Type::Box(t) => {
+            let (old, new) = (old.owned_tokens(), new.owned_tokens());
+            let old = Owned(quote!(*#old));
+            let new = Owned(quote!(*#new));
+            return recurse(t, &old, &new);
+        }
+        Type::Vec(t) if is_node(t) => quote!(d.list(#old_ref, #new_ref)),
+        Type::Punctuated(p) if is_node(&p.element) => quote!(d.list(#old_ref, #new_ref)),
+        Type::Option(t) => match &**t {
+            t if is_node(t) => quote!(d.option(#old_val.as_ref(), #new_val.as_ref())),
+            Type::Box(t) if is_node(t) => quote! {
+                d.option(#old_val.as_ref().map(Box::as_ref), #new_val.as_ref().map(Box::as_ref))
+            },
+            Type::Tuple(elems) => {
+                let mut some = TokenStream::new();
+                for (i, elem) in elems.iter().enumerate() {
+                    let i = Index::from(i);
+                    let old = Owned(quote!(old.#i));
+                    let new = Owned(quote!(new.#i));
+                    some.extend(recurse(elem, &old, &new));
+                }
+                if some.is_empty() {
+                    return None;
+                }
+                let mismatch = match representative(elems) {
+                    Some((i, _)) => {
+                        let deref = match &elems[i] {
+                            Type::Box(_) => quote!(&*),
+                            _ => quote!(&),
+                        };
+                        let i = Index::from(i);
+                        quote! {
+                            (Some(old), None) => d.removed(#deref old.#i),
+                            (None, Some(new)) => d.added(#deref new.#i),
+                        }
+                    }
+                    None => quote!(),
+                };
+                if mismatch.is_empty() {
+                    return Some(quote! {
+                        if let (Some(old), Some(new)) = (#old_ref, #new_ref) {
+                            #some
+                        }
+                    });
+                }
+                return Some(quote! {
+                    match (#old_ref, #new_ref) {
+                        (Some(old), Some(new)) => {
+                            #some
+                        }
+                        #mismatch
+                        _ => {}
+                    }
+                });
+            }
+            _ => return None,
+        },
+        Type::Vec(_) | Type::Punctuated(_) => unimplemented!("list of non-nodes"),
+        t if is_node(t) => quote!(d.node(#old_ref, #new_ref)),
+        _ => return None,
+    };
+    Some(quote!(#code;))
+}
+
+fn diff_body(s: &Node, defs: &Definitions) -> TokenStream {
+    match &s.data {
+        Data::Enum(variants) => {
+            let ty = Ident::new(&s.ident, Span::call_site());
+            let mut arms = TokenStream::new();
+            for (variant, fields) in variants {
+                let variant = Ident::new(variant, Span::call_site());
+                let mut old_bindings = Vec::new();
+                let mut new_bindings = Vec::new();
+                let mut changed = Vec::new();
+                let mut code = TokenStream::new();
+                // Variants that only exist with the "full" feature are left to
+                // the fallback arm without it.
+                let full_only = !requires_full(&s.features)
+                    && fields.iter().any(|field| is_full_only(field, defs));
+                for (i, field) in fields.iter().enumerate() {
+                    let old = Ident::new(&format!("old{}", i), Span::call_site());
+                    let new = Ident::new(&format!("new{}", i), Span::call_site());
+                    let leaf =
+                        leaf_changed(field, &Borrowed(quote!(#old)), &Borrowed(quote!(#new)));
+                    let nodes = recurse(field, &Borrowed(quote!(#old)), &Borrowed(quote!(#new)));
+                    if leaf.is_none() && nodes.is_none() {
+                        old_bindings.push(quote!(_));
+                        new_bindings.push(quote!(_));
+                    } else {
+                        old_bindings.push(quote!(#old));
+                        new_bindings.push(quote!(#new));
+                    }
+                    changed.extend(leaf);
+                    code.extend(nodes);
+                }
+                // Variants without nodes inside are reported as a whole by
+                // the fallback arm.
+                if code.is_empty() {
+                    continue;
+                }
+                let body = if changed.is_empty() {
+                    code
+                } else {
+                    quote! {
+                        if #(#changed)||* {
+                            d.modified(old, new);
+                        } else {
+                            #code
+                        }
+                    }
+                };
+                let cfg = if full_only {
+                    Some(quote!(#[cfg(feature = "full")]))
+                } else {
+                    None
+                };
+                arms.extend(quote! {
+                    #cfg
+                    (#ty::#variant(#(#old_bindings),*), #ty::#variant(#(#new_bindings),*)) => {
+                        #body
+                    }
+                });
+            }
+            if arms.is_empty() {
+                return quote!(d.modified(old, new););
+            }
+            quote! {
+                match (old, new) {
+                    #arms
+                    _ => d.modified(old, new),
+                }
+            }
+        }
+        Data::Struct(fields) => {
+            let mut changed = Vec::new();
+            let mut code = TokenStream::new();
+            for (field, ty) in fields {
+                let id = Ident::new(field, Span::call_site());
+                let old = Owned(quote!(old.#id));
+                let new = Owned(quote!(new.#id));
+                changed.extend(leaf_changed(ty, &old, &new));
+                if let Some(nodes) = recurse(ty, &old, &new) {
+                    code.extend(quote! {
+                        d.field(#field, |d| {
+                            #nodes
+                        });
+                    });
+                }
+            }
+            if code.is_empty() {
+                return quote!(d.modified(old, new););
+            }
+            if changed.is_empty() {
+                code
+            } else {
+                quote! {
+                    if #(#changed)||* {
+                        d.modified(old, new);
+                        return;
+                    }
+                    #code
+                }
+            }
+        }
+        Data::Private => quote!(d.modified(old, new);),
+    }
+}
+
+fn expand(s: &Node, defs: &Definitions) -> TokenStream {
+    let ty = Ident::new(&s.ident, Span::call_site());
+    let features = &s.features.any;
+    let cfg = match features.len() {
+        0 => quote!(),
+        1 => quote!(#[cfg(feature = #(#features)*)]),
+        _ => quote!(#[cfg(any(#(feature = #features),*))]),
+    };
+    let body = diff_body(s, defs);
+
+    quote! {
+        #cfg
+        impl Sealed for #ty {}
+
+        #cfg
+        impl Diff for #ty {
+            fn node_ref(&self) -> NodeRef<'_> {
+                NodeRef::#ty(self)
+            }
+
+            fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+                #body
+            }
+        }
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let mut impls = TokenStream::new();
+    for s in &defs.types {
+        if s.ident == "Reserved" {
+            continue;
+        }
+        impls.extend(expand(s, defs));
+    }
+    impls.extend(expand(
+        &Node {
+            ident: "Ident".to_owned(),
+            features: Features::default(),
+            data: Data::Private,
+            exhaustive: true,
+        },
+        defs,
+    ));
+
+    file::write(
+        DIFF_SRC,
+        quote! {
+            use crate::diff::{Diff, Differ};
+            use crate::node::NodeRef;
+            use crate::sealed::diff::Sealed;
+            use crate::tt::TokenStreamHelper;
+            use crate::*;
+
+            #impls
+        },
+    )?;
+    Ok(())
+}
@@ -0,0 +1,418 @@
+use crate::error::Result;
+use crate::file;
+use crate::operand::{Borrowed, Operand, Owned};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::Index;
+use syn_codegen::{Data, Definitions, Features, Node, Type};
+
+const EQUIV_SRC: &str = "../src/gen/equiv.rs";
+
+// Variants that are equivalent to the node in the given field of their
+// payload when the equivalence treats groups as transparent.
+const TRANSPARENT: &[(&str, &[(&str, &str)])] = &[
+    ("Expr", &[("Paren", "expr"), ("Group", "expr")]),
+    ("Type", &[("Paren", "elem"), ("Group", "elem")]),
+];
+
+fn requires_full(features: &Features) -> bool {
+    features.any.contains("full") && features.any.len() == 1
+}
+
+fn is_full_only(ty: &Type, defs: &Definitions) -> bool {
+    match ty {
+        Type::Box(t) => is_full_only(t, defs),
+        Type::Syn(t) => {
+            let target = defs.types.iter().find(|ty| ty.ident == *t).unwrap();
+            requires_full(&target.features)
+        }
+        _ => false,
+    }
+}
+
+// Types for which the Equivalent trait is implemented, either generated here
+// or by hand in src/equiv.rs.
+fn is_equivalent(ty: &Type) -> bool {
+    match ty {
+        Type::Syn(t) => t != "Reserved",
+        Type::Ext(t) => t == "Ident",
+        Type::Box(t) | Type::Vec(t) | Type::Option(t) => is_equivalent(t),
+        Type::Punctuated(p) => is_equivalent(&p.element),
+        _ => false,
+    }
+}
+
+// The expression comparing `this` and `other`, and the statements hashing
+// `this`, for a field of type `ty`. None for fields that are always the same,
+// like tokens.
+fn field(ty: &Type, this: &Operand, other: &Operand) -> Option<(TokenStream, TokenStream)> {
+    let (this_val, other_val) = (this.tokens(), other.tokens());
+    let (this_ref, other_ref) = (this.ref_tokens(), other.ref_tokens());
+    if is_equivalent(ty) {
+        return Some((
+            quote!(#this_val.equiv(#other_ref, equiv)),
+            quote!(#this_val.hash_equiv(equiv, state);),
+        ));
+    }
+    match ty {
+        Type::Token(_) | Type::Group(_) => None,
+        Type::Syn(t) if t == "Reserved" => None,
+        Type::Ext(t) if t == "Span" => None,
+        Type::Ext(t) if t == "TokenStream" => Some((
+            quote!(TokenStreamHelper(#this_ref) == TokenStreamHelper(#other_ref)),
+            quote!(TokenStreamHelper(#this_ref).hash(state);),
+        )),
+        Type::Ext(t) if t == "Literal" => Some((
+            quote!(#this_val.to_string() == #other_val.to_string()),
+            quote!(#this_val.to_string().hash(state);),
+        )),
+        Type::Option(t) => match &**t {
+            Type::Token(_) | Type::Group(_) => Some((
+                quote!(#this_val.is_some() == #other_val.is_some()),
+                quote!(#this_val.is_some().hash(state);),
+            )),
+            Type::Tuple(elems) => {
+                let mut eq = Vec::new();
+                let mut hash = TokenStream::new();
+                for (i, elem) in elems.iter().enumerate() {
+                    let i = Index::from(i);
+                    let this = Owned(quote!(this.#i));
+                    let other = Owned(quote!(other.#i));
+                    if let Some((elem_eq, elem_hash)) = field(elem, &this, &other) {
+                        eq.push(elem_eq);
+                        hash.extend(elem_hash);
+                    }
+                }
+                let (binding, other_binding, eq) = if eq.is_empty() {
+                    (quote!(_), quote!(_), quote!(true))
+                } else {
+                    (quote!(this), quote!(other), quote!(#(#eq)&&*))
+                };
+                Some((
+                    quote! {
+                        match (#this_ref, #other_ref) {
+                            (Some(#binding), Some(#other_binding)) => #eq,
+                            (None, None) => true,
+                            _ => false,
+                        }
+                    },
+                    quote! {
+                        match #this_ref {
+                            Some(#binding) => {
+                                state.write_u8(1);
+                                #hash
+                            }
+                            None => state.write_u8(0),
+                        }
+                    },
+                ))
+            }
+            Type::Std(_) => Some((
+                quote!(#this_val == #other_val),
+                quote!(#this_val.hash(state);),
+            )),
+            _ => unimplemented!("optional {:?}", t),
+        },
+        Type::Ext(_) | Type::Std(_) => Some((
+            quote!(#this_val == #other_val),
+            quote!(#this_val.hash(state);),
+        )),
+        _ => unimplemented!("field of type {:?}", ty),
+    }
+}
+
+// Fields named `attrs` are skipped by equivalences that ignore attributes.
+fn is_attrs(name: &str, ty: &Type) -> bool {
+    match ty {
+        Type::Vec(t) => match &**t {
+            Type::Syn(t) => name == "attrs" && t == "Attribute",
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+// Whether the struct `ty` has attributes, in which case a group is only
+// transparent if they are empty or ignored.
+fn has_attrs(ty: &Type, defs: &Definitions) -> bool {
+    match ty {
+        Type::Syn(t) => {
+            let target = defs.types.iter().find(|ty| ty.ident == *t).unwrap();
+            match &target.data {
+                Data::Struct(fields) => fields.iter().any(|(name, ty)| is_attrs(name, ty)),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn transparent(s: &Node, defs: &Definitions) -> (TokenStream, TokenStream) {
+    let variants = match TRANSPARENT.iter().find(|(ty, _)| *ty == s.ident) {
+        Some((_, variants)) => variants,
+        None => return (TokenStream::new(), TokenStream::new()),
+    };
+    let ty = Ident::new(&s.ident, Span::call_site());
+    let mut this_arms = TokenStream::new();
+    let mut other_arms = TokenStream::new();
+    let mut hash_arms = TokenStream::new();
+    for (variant, field) in *variants {
+        let payload = match &s.data {
+            Data::Enum(data) => &data[*variant],
+            _ => unreachable!(),
+        };
+        let cfg = if payload.iter().any(|ty| is_full_only(ty, defs)) {
+            Some(quote!(#[cfg(feature = "full")]))
+        } else {
+            None
+        };
+        let guard = if payload.iter().any(|ty| has_attrs(ty, defs)) {
+            Some(quote!(if equiv.ignore_attrs || inner.attrs.is_empty()))
+        } else {
+            None
+        };
+        let variant = Ident::new(variant, Span::call_site());
+        let field = Ident::new(field, Span::call_site());
+        this_arms.extend(quote! {
+            #cfg
+            #ty::#variant(inner) #guard => return Equivalent::equiv(&*inner.#field, other, equiv),
+        });
+        other_arms.extend(quote! {
+            #cfg
+            #ty::#variant(inner) #guard => return self.equiv(&inner.#field, equiv),
+        });
+        hash_arms.extend(quote! {
+            #cfg
+            #ty::#variant(inner) #guard => return inner.#field.hash_equiv(equiv, state),
+        });
+    }
+    (
+        quote! {
+            if equiv.transparent_groups {
+                match self {
+                    #this_arms
+                    _ => {}
+                }
+                match other {
+                    #other_arms
+                    _ => {}
+                }
+            }
+        },
+        quote! {
+            if equiv.transparent_groups {
+                match self {
+                    #hash_arms
+                    _ => {}
+                }
+            }
+        },
+    )
+}
+
+fn mentions(tokens: &TokenStream, name: &str) -> bool {
+    tokens.clone().into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => ident == name,
+        TokenTree::Group(group) => mentions(&group.stream(), name),
+        _ => false,
+    })
+}
+
+// The name of a parameter, with a leading underscore if the code does not use
+// it.
+fn unused_prefix(code: &TokenStream, name: &str) -> Ident {
+    if mentions(code, name) {
+        Ident::new(name, Span::call_site())
+    } else {
+        Ident::new(&format!("_{}", name), Span::call_site())
+    }
+}
+
+fn expand(s: &Node, defs: &Definitions) -> TokenStream {
+    let ty = Ident::new(&s.ident, Span::call_site());
+
+    let (eq, hash) = match &s.data {
+        Data::Enum(variants) => {
+            let mut eq_arms = TokenStream::new();
+            let mut hash_arms = TokenStream::new();
+            let mut any_full_only = false;
+            for (i, (variant, fields)) in variants.iter().enumerate() {
+                let variant = Ident::new(variant, Span::call_site());
+                let discriminant = i as u8;
+                let cfg = if !requires_full(&s.features)
+                    && fields.iter().any(|field| is_full_only(field, defs))
+                {
+                    any_full_only = true;
+                    Some(quote!(#[cfg(feature = "full")]))
+                } else {
+                    None
+                };
+                let mut this_bindings = Vec::new();
+                let mut other_bindings = Vec::new();
+                let mut eq = Vec::new();
+                let mut hash = TokenStream::new();
+                for (i, ty) in fields.iter().enumerate() {
+                    let this = Ident::new(&format!("this{}", i), Span::call_site());
+                    let other = Ident::new(&format!("other{}", i), Span::call_site());
+                    match field(ty, &Borrowed(quote!(#this)), &Borrowed(quote!(#other))) {
+                        Some((field_eq, field_hash)) => {
+                            this_bindings.push(quote!(#this));
+                            other_bindings.push(quote!(#other));
+                            eq.push(field_eq);
+                            hash.extend(field_hash);
+                        }
+                        None => {
+                            this_bindings.push(quote!(_));
+                            other_bindings.push(quote!(_));
+                        }
+                    }
+                }
+                let (this_pat, other_pat) = if fields.is_empty() {
+                    (quote!(#ty::#variant), quote!(#ty::#variant))
+                } else {
+                    (
+                        quote!(#ty::#variant(#(#this_bindings),*)),
+                        quote!(#ty::#variant(#(#other_bindings),*)),
+                    )
+                };
+                let eq = if eq.is_empty() {
+                    quote!(true)
+                } else {
+                    quote!(#(#eq)&&*)
+                };
+                eq_arms.extend(quote! {
+                    #cfg
+                    (#this_pat, #other_pat) => #eq,
+                });
+                hash_arms.extend(quote! {
+                    #cfg
+                    #this_pat => {
+                        state.write_u8(#discriminant);
+                        #hash
+                    }
+                });
+            }
+            let mut fallback = TokenStream::new();
+            if !s.exhaustive {
+                fallback.extend(quote!(#ty::__Nonexhaustive => unreachable!(),));
+            }
+            if any_full_only {
+                fallback.extend(quote! {
+                    #[cfg(not(feature = "full"))]
+                    _ => unreachable!(),
+                });
+            }
+            let eq_fallback = if variants.len() > 1 {
+                Some(quote!(_ => false,))
+            } else {
+                None
+            };
+            (
+                quote! {
+                    match (self, other) {
+                        #eq_arms
+                        #eq_fallback
+                    }
+                },
+                quote! {
+                    match self {
+                        #hash_arms
+                        #fallback
+                    }
+                },
+            )
+        }
+        Data::Struct(fields) => {
+            let mut eq = Vec::new();
+            let mut hash = TokenStream::new();
+            for (name, ty) in fields {
+                let id = Ident::new(name, Span::call_site());
+                let this = Owned(quote!(self.#id));
+                let other = Owned(quote!(other.#id));
+                if let Some((field_eq, field_hash)) = field(ty, &this, &other) {
+                    if is_attrs(name, ty) {
+                        eq.push(quote!(equiv.ignore_attrs || #field_eq));
+                        hash.extend(quote! {
+                            if !equiv.ignore_attrs {
+                                #field_hash
+                            }
+                        });
+                    } else {
+                        eq.push(field_eq);
+                        hash.extend(field_hash);
+                    }
+                }
+            }
+            let eq = match eq.len() {
+                0 => quote!(true),
+                1 => quote!(#(#eq)*),
+                _ => {
+                    let eq = eq.iter().map(|field_eq| {
+                        if mentions(field_eq, "ignore_attrs") {
+                            quote!((#field_eq))
+                        } else {
+                            field_eq.clone()
+                        }
+                    });
+                    quote!(#(#eq)&&*)
+                }
+            };
+            (eq, hash)
+        }
+        Data::Private => (quote!(self == other), quote!(self.hash(state);)),
+    };
+
+    let (transparent_eq, transparent_hash) = transparent(s, defs);
+    let eq = quote!(#transparent_eq #eq);
+    let hash = quote!(#transparent_hash #hash);
+    let other = unused_prefix(&eq, "other");
+    let equiv = unused_prefix(&quote!(#eq #hash), "equiv");
+    let state = unused_prefix(&hash, "state");
+
+    let features = &s.features.any;
+    let cfg = match features.len() {
+        0 => quote!(),
+        1 => quote!(#[cfg(feature = #(#features)*)]),
+        _ => quote!(#[cfg(any(#(feature = #features),*))]),
+    };
+
+    quote! {
+        #cfg
+        impl Sealed for #ty {}
+
+        #cfg
+        impl Equivalent for #ty {
+            fn equiv(&self, #other: &Self, #equiv: &Equivalence) -> bool {
+                #eq
+            }
+
+            fn hash_equiv<H: Hasher>(&self, #equiv: &Equivalence, #state: &mut H) {
+                #hash
+            }
+        }
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let mut impls = TokenStream::new();
+    for s in &defs.types {
+        if s.ident == "Reserved" {
+            continue;
+        }
+        impls.extend(expand(s, defs));
+    }
+
+    file::write(
+        EQUIV_SRC,
+        quote! {
+            use crate::equiv::{Equivalence, Equivalent};
+            use crate::sealed::equiv::Sealed;
+            use crate::tt::TokenStreamHelper;
+            use crate::*;
+            use std::hash::{Hash, Hasher};
+
+            #impls
+        },
+    )?;
+    Ok(())
+}
@@ -7,20 +7,29 @@
 // and making use of syn.json from Rust code.
 //
 // Finally this crate generates the Visit, VisitMut, and Fold traits in Syn
-// programmatically from the syntax tree description.
+// programmatically from the syntax tree description, along with the serde
+// impls behind Syn's "serde" feature, the structural diff and equivalence of
+// the diff and equiv modules, the pattern matching of the query module, and
+// the compact debug representation of the compact module.
 
 #![recursion_limit = "128"]
 #![allow(clippy::needless_pass_by_value)]
 
+mod compact;
 mod debug;
+mod diff;
+mod equiv;
 mod error;
 mod file;
 mod fold;
 mod full;
 mod gen;
 mod json;
+mod node;
 mod operand;
 mod parse;
+mod query;
+mod serde;
 mod version;
 mod visit;
 mod visit_mut;
@@ -40,8 +49,14 @@ fn do_main() -> Result<()> {
     let defs = parse::parse()?;
     json::generate(&defs)?;
     fold::generate(&defs)?;
+    node::generate(&defs)?;
     visit::generate(&defs)?;
     visit_mut::generate(&defs)?;
     debug::generate(&defs)?;
+    serde::generate(&defs)?;
+    diff::generate(&defs)?;
+    equiv::generate(&defs)?;
+    query::generate(&defs)?;
+    compact::generate(&defs)?;
     Ok(())
 }
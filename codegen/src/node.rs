@@ -0,0 +1,464 @@
+use crate::error::Result;
+use crate::operand::{Borrowed, Operand, Owned};
+use crate::{file, full};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::Index;
+use syn_codegen::{Data, Definitions, Features, Node, Type};
+
+const NODE_SRC: &str = "../src/gen/node.rs";
+
+struct Flavor {
+    ident: Ident,
+    children: Ident,
+    feature: &'static str,
+    mutable: bool,
+}
+
+impl Flavor {
+    fn new(ident: &str, children: &str, feature: &'static str, mutable: bool) -> Self {
+        Flavor {
+            ident: Ident::new(ident, Span::call_site()),
+            children: Ident::new(children, Span::call_site()),
+            feature,
+            mutable,
+        }
+    }
+
+    fn reference(&self) -> TokenStream {
+        if self.mutable {
+            quote!(&'a mut)
+        } else {
+            quote!(&'a)
+        }
+    }
+
+    fn borrow(&self, operand: &Operand) -> TokenStream {
+        if self.mutable {
+            operand.ref_mut_tokens()
+        } else {
+            operand.ref_tokens()
+        }
+    }
+}
+
+// Every type that has a method in the Visit trait, other than Span which is
+// not a node of its own.
+fn nodes(defs: &Definitions) -> Vec<Node> {
+    let mut types = defs.types.clone();
+    types.push(Node {
+        ident: "Ident".to_owned(),
+        features: Features::default(),
+        data: Data::Private,
+        exhaustive: true,
+    });
+    types.retain(|node| node.ident != "Reserved");
+    types.sort_by(|a, b| a.ident.cmp(&b.ident));
+    types
+}
+
+fn cfg(node: &Node) -> TokenStream {
+    let features = &node.features.any;
+    match features.len() {
+        0 => quote!(),
+        1 => quote!(#[cfg(feature = #(#features)*)]),
+        _ => quote!(#[cfg(any(#(feature = #features),*))]),
+    }
+}
+
+fn requires_full(features: &Features) -> bool {
+    features.any.contains("full") && features.any.len() == 1
+}
+
+// Pushes the nodes contained in `name` of type `ty` onto `children`.
+fn child(
+    flavor: &Flavor,
+    ty: &Type,
+    features: &Features,
+    defs: &Definitions,
+    name: &Operand,
+) -> Option<TokenStream> {
+    let node = &flavor.ident;
+    match ty {
+        Type::Box(t) => {
+            let name = name.owned_tokens();
+            child(flavor, t, features, defs, &Owned(quote!(*#name)))
+        }
+        Type::Vec(t) | Type::Option(t) => {
+            let operand = Borrowed(quote!(it));
+            let val = child(flavor, t, features, defs, &operand)?;
+            let name = flavor.borrow(name);
+            let keyword = match ty {
+                Type::Vec(_) => quote!(for it in),
+                _ => quote!(if let Some(it) =),
+            };
+            Some(quote! {
+                #keyword #name {
+                    #val
+                }
+            })
+        }
+        Type::Punctuated(p) => {
+            let operand = Borrowed(quote!(it));
+            let val = child(flavor, &p.element, features, defs, &operand)?;
+            let name = flavor.borrow(name);
+            Some(quote! {
+                for it in #name {
+                    #val
+                }
+            })
+        }
+        Type::Tuple(t) => {
+            let mut code = TokenStream::new();
+            for (i, elem) in t.iter().enumerate() {
+                let name = name.tokens();
+                let i = Index::from(i);
+                let it = Owned(quote!((#name).#i));
+                code.extend(child(flavor, elem, features, defs, &it));
+            }
+            if code.is_empty() {
+                None
+            } else {
+                Some(code)
+            }
+        }
+        Type::Syn(t) if t == "Reserved" => None,
+        Type::Syn(t) => {
+            let ident = Ident::new(t, Span::call_site());
+            let name = flavor.borrow(name);
+            let mut res = quote!(children.push(#node::#ident(#name)));
+            let target = defs.types.iter().find(|ty| ty.ident == *t).unwrap();
+            if requires_full(&target.features) && !requires_full(features) {
+                res = quote!(full!(#res));
+            }
+            Some(quote!(#res;))
+        }
+        Type::Ext(t) if t == "Ident" => {
+            let name = flavor.borrow(name);
+            Some(quote!(children.push(#node::Ident(#name));))
+        }
+        Type::Token(_) | Type::Group(_) | Type::Ext(_) | Type::Std(_) => None,
+    }
+}
+
+fn children(flavor: &Flavor, s: &Node, defs: &Definitions) -> TokenStream {
+    let mut code = TokenStream::new();
+    match &s.data {
+        Data::Enum(variants) => {
+            let ty = Ident::new(&s.ident, Span::call_site());
+            let mut arms = TokenStream::new();
+            let mut exhaustive = s.exhaustive;
+            for (variant, fields) in variants {
+                let variant_ident = Ident::new(variant, Span::call_site());
+                let mut bindings = Vec::new();
+                let mut visit_fields = TokenStream::new();
+                for (idx, ty) in fields.iter().enumerate() {
+                    let binding = Ident::new(&format!("_binding_{}", idx), Span::call_site());
+                    let operand = Borrowed(quote!(#binding));
+                    visit_fields.extend(child(flavor, ty, &s.features, defs, &operand));
+                    bindings.push(binding);
+                }
+                if !visit_fields.is_empty() {
+                    arms.extend(quote! {
+                        #ty::#variant_ident(#(#bindings),*) => {
+                            #visit_fields
+                        }
+                    });
+                } else {
+                    exhaustive = false;
+                }
+            }
+            if !arms.is_empty() {
+                let nonexhaustive = if exhaustive {
+                    None
+                } else {
+                    Some(quote!(_ => {}))
+                };
+                code.extend(quote! {
+                    match node {
+                        #arms
+                        #nonexhaustive
+                    }
+                });
+            }
+        }
+        Data::Struct(fields) => {
+            for (field, ty) in fields {
+                let id = Ident::new(field, Span::call_site());
+                let operand = Owned(quote!(node.#id));
+                code.extend(child(flavor, ty, &s.features, defs, &operand));
+            }
+        }
+        Data::Private => {}
+    }
+    code
+}
+
+// Types that do not implement ToTokens by themselves because they only make
+// up part of the tokens of their parent node.
+fn manual_to_tokens(ident: &str) -> Option<TokenStream> {
+    let tokens = match ident {
+        "AttrStyle" => quote! {
+            if let AttrStyle::Inner(bang) = node {
+                bang.to_tokens(tokens);
+            }
+        },
+        "Data" => quote! {
+            match node {
+                Data::Struct(data) => {
+                    data.struct_token.to_tokens(tokens);
+                    data.fields.to_tokens(tokens);
+                    data.semi_token.to_tokens(tokens);
+                }
+                Data::Enum(data) => {
+                    data.enum_token.to_tokens(tokens);
+                    data.brace_token.surround(tokens, |tokens| {
+                        data.variants.to_tokens(tokens);
+                    });
+                }
+                Data::Union(data) => {
+                    data.union_token.to_tokens(tokens);
+                    data.fields.to_tokens(tokens);
+                }
+            }
+        },
+        "DataStruct" => quote! {
+            node.struct_token.to_tokens(tokens);
+            node.fields.to_tokens(tokens);
+            node.semi_token.to_tokens(tokens);
+        },
+        "DataEnum" => quote! {
+            node.enum_token.to_tokens(tokens);
+            node.brace_token.surround(tokens, |tokens| {
+                node.variants.to_tokens(tokens);
+            });
+        },
+        "DataUnion" => quote! {
+            node.union_token.to_tokens(tokens);
+            node.fields.to_tokens(tokens);
+        },
+        "MacroDelimiter" => quote! {
+            match node {
+                MacroDelimiter::Paren(paren) => paren.surround(tokens, |_| {}),
+                MacroDelimiter::Brace(brace) => brace.surround(tokens, |_| {}),
+                MacroDelimiter::Bracket(bracket) => bracket.surround(tokens, |_| {}),
+            }
+        },
+        "QSelf" => quote! {
+            node.lt_token.to_tokens(tokens);
+            node.ty.to_tokens(tokens);
+            node.as_token.to_tokens(tokens);
+            node.gt_token.to_tokens(tokens);
+        },
+        "RangeLimits" => quote! {
+            match node {
+                RangeLimits::HalfOpen(dot2) => dot2.to_tokens(tokens),
+                RangeLimits::Closed(dot2_eq) => dot2_eq.to_tokens(tokens),
+            }
+        },
+        _ => return None,
+    };
+    Some(tokens)
+}
+
+fn expand(flavor: &Flavor, defs: &Definitions) -> TokenStream {
+    let node = &flavor.ident;
+    let children_ty = &flavor.children;
+    let feature = flavor.feature;
+    let reference = flavor.reference();
+
+    let mut variants = TokenStream::new();
+    let mut from_impls = TokenStream::new();
+    let mut kind_arms = TokenStream::new();
+    let mut children_arms = TokenStream::new();
+    let mut to_tokens_arms = TokenStream::new();
+
+    for s in nodes(defs) {
+        let cfg = cfg(&s);
+        let ident = Ident::new(&s.ident, Span::call_site());
+        let kind = &s.ident;
+
+        variants.extend(quote! {
+            #cfg
+            #ident(#reference #ident),
+        });
+
+        from_impls.extend(quote! {
+            #[cfg(feature = #feature)]
+            #cfg
+            impl<'a> From<#reference #ident> for #node<'a> {
+                fn from(node: #reference #ident) -> Self {
+                    #node::#ident(node)
+                }
+            }
+        });
+
+        kind_arms.extend(quote! {
+            #cfg
+            #node::#ident(_) => #kind,
+        });
+
+        let children = children(flavor, &s, defs);
+        if !children.is_empty() {
+            children_arms.extend(quote! {
+                #cfg
+                #node::#ident(node) => {
+                    #children
+                }
+            });
+        }
+
+        let to_tokens =
+            manual_to_tokens(&s.ident).unwrap_or_else(|| quote!(node.to_tokens(tokens)));
+        to_tokens_arms.extend(quote! {
+            #cfg
+            #node::#ident(node) => {
+                #to_tokens
+            }
+        });
+    }
+
+    let (doc, children_doc, iterator_doc) = if flavor.mutable {
+        (
+            "An exclusive reference to a syntax tree node of any type.",
+            "The nodes directly contained in this one, in source order.",
+            "An iterator over the children of a [`NodeMut`].",
+        )
+    } else {
+        (
+            "A shared reference to a syntax tree node of any type.",
+            "The nodes directly contained in this one, in source order.",
+            "An iterator over the children of a [`NodeRef`].",
+        )
+    };
+    let available = format!(
+        "*This type is available if Syn is built with the `\"{}\"` feature.*",
+        feature,
+    );
+    let derive_copy = if flavor.mutable {
+        None
+    } else {
+        Some(quote!(#[derive(Copy, Clone)]))
+    };
+    let (receiver, scrutinee) = if flavor.mutable {
+        (quote!(self), quote!(self))
+    } else {
+        (quote!(&self), quote!(*self))
+    };
+
+    quote! {
+        #[doc = #doc]
+        ///
+        #[doc = #available]
+        #[cfg(feature = #feature)]
+        #derive_copy
+        #[cfg_attr(feature = "extra-traits", derive(Debug))]
+        pub enum #node<'a> {
+            #variants
+
+            #[doc(hidden)]
+            __Nonexhaustive,
+        }
+
+        #[cfg(feature = #feature)]
+        impl<'a> #node<'a> {
+            /// The name of the syntax tree type of this node, like
+            /// `"ExprCall"`.
+            pub fn kind(&self) -> &'static str {
+                match self {
+                    #kind_arms
+                    #node::__Nonexhaustive => unreachable!(),
+                }
+            }
+
+            #[doc = #children_doc]
+            pub fn children(#receiver) -> #children_ty<'a> {
+                let mut children = Vec::new();
+                match #scrutinee {
+                    #children_arms
+                    _ => {}
+                }
+                #children_ty {
+                    iter: children.into_iter(),
+                }
+            }
+        }
+
+        #from_impls
+
+        #[cfg(all(feature = #feature, feature = "printing"))]
+        impl<'a> ToTokens for #node<'a> {
+            fn to_tokens(&self, tokens: &mut TokenStream) {
+                match self {
+                    #to_tokens_arms
+                    #node::__Nonexhaustive => unreachable!(),
+                }
+            }
+        }
+
+        #[doc = #iterator_doc]
+        ///
+        #[doc = #available]
+        #[cfg(feature = #feature)]
+        pub struct #children_ty<'a> {
+            iter: vec::IntoIter<#node<'a>>,
+        }
+
+        #[cfg(feature = #feature)]
+        impl<'a> Iterator for #children_ty<'a> {
+            type Item = #node<'a>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        #[cfg(feature = #feature)]
+        impl<'a> DoubleEndedIterator for #children_ty<'a> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.iter.next_back()
+            }
+        }
+
+        #[cfg(feature = #feature)]
+        impl<'a> ExactSizeIterator for #children_ty<'a> {
+            fn len(&self) -> usize {
+                self.iter.len()
+            }
+        }
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let node_ref = expand(&Flavor::new("NodeRef", "Children", "visit", false), defs);
+    let node_mut = expand(
+        &Flavor::new("NodeMut", "ChildrenMut", "visit-mut", true),
+        defs,
+    );
+    let full_macro = full::get_macro();
+
+    file::write(
+        NODE_SRC,
+        quote! {
+            #![allow(unused_mut)]
+
+            use crate::*;
+            #[cfg(feature = "printing")]
+            use proc_macro2::TokenStream;
+            #[cfg(feature = "printing")]
+            use quote::ToTokens;
+            use std::vec;
+
+            #full_macro
+
+            #node_ref
+
+            #node_mut
+        },
+    )?;
+    Ok(())
+}
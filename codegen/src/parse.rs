@@ -4,7 +4,7 @@ use crate::version;
 use indexmap::IndexMap;
 use quote::quote;
 use syn::parse::Parser;
-use syn::{parse_quote, Data, DataStruct, DeriveInput, Ident, Item};
+use syn::{parse_quote, Data, DataStruct, DeriveInput, Ident, Item, Lit, Meta};
 use syn_codegen as types;
 
 use std::collections::BTreeMap;
@@ -507,6 +507,19 @@ fn get_features(attrs: &[syn::Attribute], base: &[syn::Attribute]) -> Vec<syn::A
     ret
 }
 
+fn path_attr(attrs: &[syn::Attribute]) -> Option<String> {
+    for attr in attrs {
+        if let Ok(Meta::NameValue(meta)) = attr.parse_meta() {
+            if let Lit::Str(path) = meta.lit {
+                if meta.path.is_ident("path") {
+                    return Some(path.value());
+                }
+            }
+        }
+    }
+    None
+}
+
 fn load_file<P: AsRef<Path>>(
     name: P,
     features: &[syn::Attribute],
@@ -552,7 +565,10 @@ fn load_file<P: AsRef<Path>>(
 
                 // Look up the submodule file, and recursively parse it.
                 // XXX: Only handles same-directory .rs file submodules.
-                let path = parent.join(&format!("{}.rs", item.ident));
+                let path = match path_attr(&item.attrs) {
+                    Some(path) => parent.join(path),
+                    None => parent.join(&format!("{}.rs", item.ident)),
+                };
                 load_file(path, &features, lookup)?;
             }
             Item::Macro(item) => {
@@ -0,0 +1,261 @@
+use crate::error::Result;
+use crate::file;
+use crate::operand::{Borrowed, Operand, Owned};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::Index;
+use syn_codegen::{Data, Definitions, Node, Type};
+
+const QUERY_SRC: &str = "../src/gen/query.rs";
+
+// Types of which a node in a pattern may be a metavariable, along with the
+// function in src/query.rs that recognizes one.
+const METAVARS: &[(&str, &str)] = &[
+    ("Expr", "expr_metavar"),
+    ("Type", "type_metavar"),
+    ("Pat", "pat_metavar"),
+];
+
+// Types for which the Searchable trait is implemented, either generated here
+// or by hand in src/query.rs.
+fn is_searchable(ty: &Type) -> bool {
+    match ty {
+        Type::Syn(t) => t != "Reserved",
+        Type::Ext(t) => t == "Ident",
+        _ => false,
+    }
+}
+
+// The expression matching the `pattern` operand against the `node` operand
+// for a field of type `ty`. None for fields that always match, like tokens.
+fn field(ty: &Type, pattern: &Operand, node: &Operand) -> Option<TokenStream> {
+    let (pattern_val, node_val) = (pattern.tokens(), node.tokens());
+    let (pattern_ref, node_ref) = (pattern.ref_tokens(), node.ref_tokens());
+    if is_searchable(ty) {
+        return Some(quote!(Searchable::matches(#pattern_ref, #node_ref, matcher)));
+    }
+    match ty {
+        Type::Box(t) => {
+            let pattern = pattern.owned_tokens();
+            let node = node.owned_tokens();
+            field(t, &Owned(quote!(*#pattern)), &Owned(quote!(*#node)))
+        }
+        Type::Vec(t) if is_searchable(t) => Some(quote!(matcher.list(#pattern_ref, #node_ref))),
+        Type::Punctuated(p) if is_searchable(&p.element) => {
+            Some(quote!(matcher.list(#pattern_ref, #node_ref)))
+        }
+        Type::Token(_) | Type::Group(_) => None,
+        Type::Syn(t) if t == "Reserved" => None,
+        Type::Ext(t) if t == "Span" => None,
+        Type::Ext(t) if t == "TokenStream" => {
+            Some(quote!(TokenStreamHelper(#pattern_ref) == TokenStreamHelper(#node_ref)))
+        }
+        Type::Ext(t) if t == "Literal" => {
+            Some(quote!(#pattern_val.to_string() == #node_val.to_string()))
+        }
+        Type::Option(t) => match &**t {
+            t if is_searchable(t) => {
+                Some(quote!(matcher.option(#pattern_val.as_ref(), #node_val.as_ref())))
+            }
+            Type::Box(t) if is_searchable(t) => Some(quote! {
+                matcher.option(
+                    #pattern_val.as_ref().map(Box::as_ref),
+                    #node_val.as_ref().map(Box::as_ref),
+                )
+            }),
+            Type::Token(_) | Type::Group(_) => {
+                Some(quote!(#pattern_val.is_some() == #node_val.is_some()))
+            }
+            Type::Tuple(elems) => {
+                let mut matches = Vec::new();
+                for (i, elem) in elems.iter().enumerate() {
+                    let i = Index::from(i);
+                    let pattern = Owned(quote!(pattern.#i));
+                    let node = Owned(quote!(node.#i));
+                    if let Some(elem_matches) = field(elem, &pattern, &node) {
+                        matches.push(elem_matches);
+                    }
+                }
+                let (pattern_binding, node_binding, matches) = if matches.is_empty() {
+                    (quote!(_), quote!(_), quote!(true))
+                } else {
+                    (quote!(pattern), quote!(node), quote!(#(#matches)&&*))
+                };
+                Some(quote! {
+                    match (#pattern_ref, #node_ref) {
+                        (Some(#pattern_binding), Some(#node_binding)) => #matches,
+                        (None, None) => true,
+                        _ => false,
+                    }
+                })
+            }
+            Type::Std(_) => Some(quote!(#pattern_val == #node_val)),
+            _ => unimplemented!("optional {:?}", t),
+        },
+        Type::Ext(_) | Type::Std(_) => Some(quote!(#pattern_val == #node_val)),
+        _ => unimplemented!("field of type {:?}", ty),
+    }
+}
+
+fn mentions(tokens: &TokenStream, name: &str) -> bool {
+    tokens.clone().into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => ident == name,
+        TokenTree::Group(group) => mentions(&group.stream(), name),
+        _ => false,
+    })
+}
+
+// The name of a parameter, with a leading underscore if the code does not use
+// it.
+fn unused_prefix(code: &TokenStream, name: &str) -> Ident {
+    if mentions(code, name) {
+        Ident::new(name, Span::call_site())
+    } else {
+        Ident::new(&format!("_{}", name), Span::call_site())
+    }
+}
+
+fn expand(s: &Node) -> TokenStream {
+    let ty = Ident::new(&s.ident, Span::call_site());
+
+    let matches = match &s.data {
+        Data::Enum(variants) => {
+            let mut arms = TokenStream::new();
+            for (variant, fields) in variants {
+                let variant = Ident::new(variant, Span::call_site());
+                let mut pattern_bindings = Vec::new();
+                let mut node_bindings = Vec::new();
+                let mut matches = Vec::new();
+                for (i, ty) in fields.iter().enumerate() {
+                    let pattern = Ident::new(&format!("pattern{}", i), Span::call_site());
+                    let node = Ident::new(&format!("node{}", i), Span::call_site());
+                    match field(ty, &Borrowed(quote!(#pattern)), &Borrowed(quote!(#node))) {
+                        Some(field_matches) => {
+                            pattern_bindings.push(quote!(#pattern));
+                            node_bindings.push(quote!(#node));
+                            matches.push(field_matches);
+                        }
+                        None => {
+                            pattern_bindings.push(quote!(_));
+                            node_bindings.push(quote!(_));
+                        }
+                    }
+                }
+                let (pattern_pat, node_pat) = if fields.is_empty() {
+                    (quote!(#ty::#variant), quote!(#ty::#variant))
+                } else {
+                    (
+                        quote!(#ty::#variant(#(#pattern_bindings),*)),
+                        quote!(#ty::#variant(#(#node_bindings),*)),
+                    )
+                };
+                let matches = if matches.is_empty() {
+                    quote!(true)
+                } else {
+                    quote!(#(#matches)&&*)
+                };
+                arms.extend(quote! {
+                    (#pattern_pat, #node_pat) => #matches,
+                });
+            }
+            let fallback = if variants.len() > 1 {
+                Some(quote!(_ => false,))
+            } else {
+                None
+            };
+            quote! {
+                match (pattern, node) {
+                    #arms
+                    #fallback
+                }
+            }
+        }
+        Data::Struct(fields) => {
+            let mut matches = Vec::new();
+            for (name, ty) in fields {
+                let id = Ident::new(name, Span::call_site());
+                let pattern = Owned(quote!(pattern.#id));
+                let node = Owned(quote!(node.#id));
+                if let Some(field_matches) = field(ty, &pattern, &node) {
+                    matches.push(field_matches);
+                }
+            }
+            if matches.is_empty() {
+                quote!(true)
+            } else {
+                quote!(#(#matches)&&*)
+            }
+        }
+        Data::Private => quote!(pattern == node),
+    };
+
+    let metavar = METAVARS
+        .iter()
+        .find(|(name, _)| *name == s.ident)
+        .map(|(_, f)| {
+            let f = Ident::new(f, Span::call_site());
+            quote! {
+                if let Some(name) = query::#f(pattern) {
+                    return matcher.bind(name, node);
+                }
+            }
+        });
+    let body = quote!(#metavar #matches);
+    let pattern = unused_prefix(&body, "pattern");
+    let node = unused_prefix(&body, "node");
+    let matcher = unused_prefix(&body, "matcher");
+
+    let features = &s.features.any;
+    let cfg = match features.len() {
+        0 => quote!(),
+        1 => quote!(#[cfg(feature = #(#features)*)]),
+        _ => quote!(#[cfg(any(#(feature = #features),*))]),
+    };
+
+    quote! {
+        #cfg
+        impl Sealed for #ty {}
+
+        #cfg
+        impl Searchable for #ty {
+            fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+                match node {
+                    NodeRef::#ty(node) => Some(node),
+                    _ => None,
+                }
+            }
+
+            fn node_ref(&self) -> NodeRef<'_> {
+                NodeRef::#ty(self)
+            }
+
+            fn matches<'a>(#pattern: &Self, #node: &'a Self, #matcher: &mut Matcher<'a>) -> bool {
+                #body
+            }
+        }
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let mut impls = TokenStream::new();
+    for s in &defs.types {
+        if s.ident == "Reserved" {
+            continue;
+        }
+        impls.extend(expand(s));
+    }
+
+    file::write(
+        QUERY_SRC,
+        quote! {
+            use crate::node::NodeRef;
+            use crate::query::{self, Matcher, Searchable};
+            use crate::sealed::query::Sealed;
+            use crate::tt::TokenStreamHelper;
+            use crate::*;
+
+            #impls
+        },
+    )?;
+    Ok(())
+}
@@ -0,0 +1,627 @@
+use crate::error::Result;
+use crate::{file, full};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::Index;
+use syn_codegen::{Data, Definitions, Features, Node, Type};
+
+const SERDE_SRC: &str = "../src/gen/serde.rs";
+
+// Fields of these types carry only spans. They are left out of the serialized
+// representation and filled in with a default when deserializing.
+fn is_skipped(ty: &Type) -> bool {
+    match ty {
+        Type::Token(_) | Type::Group(_) => true,
+        Type::Ext(ty) => ty == "Span",
+        Type::Syn(ty) => ty == "Reserved",
+        _ => false,
+    }
+}
+
+// Types whose serde impls line up with their Rust type, so that they can be
+// serialized by reference and deserialized directly.
+fn is_plain(ty: &Type) -> bool {
+    match ty {
+        Type::Syn(ty) => ty != "Reserved",
+        Type::Std(_) => true,
+        Type::Box(ty) | Type::Vec(ty) | Type::Option(ty) => is_plain(ty),
+        Type::Punctuated(ty) => is_plain(&ty.element),
+        Type::Ext(_) | Type::Token(_) | Type::Group(_) | Type::Tuple(_) => false,
+    }
+}
+
+fn is_flag(ty: &Type) -> bool {
+    match ty {
+        Type::Option(ty) => match **ty {
+            Type::Token(_) | Type::Group(_) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+// A tuple whose only serialized element is an `Option`, as in `Option<(Token![&],
+// Option<Lifetime>)>`, is kept as a 1-tuple rather than unwrapped to the
+// element, so that `Some(None)` stays distinct from `None` in formats like JSON.
+fn is_nested_option(elems: &[Type]) -> bool {
+    let mut kept = elems.iter().filter(|ty| !is_skipped(ty));
+    match (kept.next(), kept.next()) {
+        (Some(Type::Option(_)), None) => true,
+        _ => false,
+    }
+}
+
+fn rust_type(ty: &Type) -> TokenStream {
+    match ty {
+        Type::Syn(ty) | Type::Std(ty) | Type::Ext(ty) => {
+            let ident = Ident::new(ty, Span::call_site());
+            quote!(#ident)
+        }
+        Type::Token(ty) | Type::Group(ty) => {
+            let ident = Ident::new(ty, Span::call_site());
+            quote!(token::#ident)
+        }
+        Type::Punctuated(ty) => {
+            let element = rust_type(&ty.element);
+            let punct = Ident::new(&ty.punct, Span::call_site());
+            quote!(Punctuated<#element, token::#punct>)
+        }
+        Type::Option(ty) => {
+            let inner = rust_type(ty);
+            quote!(Option<#inner>)
+        }
+        Type::Box(ty) => {
+            let inner = rust_type(ty);
+            quote!(Box<#inner>)
+        }
+        Type::Vec(ty) => {
+            let inner = rust_type(ty);
+            quote!(Vec<#inner>)
+        }
+        Type::Tuple(ty) => {
+            let inner = ty.iter().map(rust_type);
+            quote!((#(#inner,)*))
+        }
+    }
+}
+
+fn ext_wrapper(ty: &str, suffix: &str) -> Ident {
+    let name = match ty {
+        "Ident" => "Ident",
+        "TokenStream" => "Tokens",
+        "Literal" => "Literal",
+        _ => panic!("unsupported external type: {}", ty),
+    };
+    Ident::new(&format!("{}{}", name, suffix), Span::call_site())
+}
+
+// A reference to something implementing Serialize for the value of type `ty`,
+// given both as a place expression and as a reference to it.
+fn serialize(place: &TokenStream, reference: &TokenStream, ty: &Type) -> TokenStream {
+    if is_plain(ty) {
+        reference.clone()
+    } else {
+        let value = serialize_owned(place, reference, ty);
+        quote!(&#value)
+    }
+}
+
+fn serialize_owned(place: &TokenStream, reference: &TokenStream, ty: &Type) -> TokenStream {
+    if is_plain(ty) {
+        return reference.clone();
+    }
+    if is_flag(ty) {
+        return quote!(#place.is_some());
+    }
+    match ty {
+        Type::Ext(ty) => {
+            let wrapper = ext_wrapper(ty, "Ser");
+            quote!(#wrapper(#reference))
+        }
+        Type::Option(ty) => match &**ty {
+            Type::Ext(ty) => {
+                let wrapper = ext_wrapper(ty, "Ser");
+                quote!(#place.as_ref().map(#wrapper))
+            }
+            ty => {
+                let inner = serialize_owned(&quote!(_v), &quote!(_v), ty);
+                quote!(#place.as_ref().map(|_v| #inner))
+            }
+        },
+        Type::Tuple(ty) => {
+            let elems: Vec<TokenStream> = ty
+                .iter()
+                .enumerate()
+                .filter(|(_, ty)| !is_skipped(ty))
+                .map(|(i, ty)| {
+                    let i = Index::from(i);
+                    serialize_owned(&quote!(#place.#i), &quote!(&#place.#i), ty)
+                })
+                .collect();
+            if is_nested_option(ty) {
+                quote!((#(#elems,)*))
+            } else if elems.len() == 1 {
+                elems.into_iter().next().unwrap()
+            } else {
+                quote!((#(#elems),*))
+            }
+        }
+        _ => panic!("unsupported type"),
+    }
+}
+
+// The type that the serialized form of `ty` is deserialized into.
+fn deserialize_type(ty: &Type) -> TokenStream {
+    if is_plain(ty) {
+        return rust_type(ty);
+    }
+    if is_flag(ty) {
+        return quote!(bool);
+    }
+    match ty {
+        Type::Ext(ty) => {
+            let wrapper = ext_wrapper(ty, "De");
+            quote!(#wrapper)
+        }
+        Type::Option(ty) => {
+            let inner = deserialize_type(ty);
+            quote!(Option<#inner>)
+        }
+        Type::Tuple(ty) => {
+            let elems: Vec<TokenStream> = ty
+                .iter()
+                .filter(|ty| !is_skipped(ty))
+                .map(deserialize_type)
+                .collect();
+            if is_nested_option(ty) {
+                quote!((#(#elems,)*))
+            } else if elems.len() == 1 {
+                elems.into_iter().next().unwrap()
+            } else {
+                quote!((#(#elems),*))
+            }
+        }
+        _ => panic!("unsupported type"),
+    }
+}
+
+// Converts `val` of the type given by `deserialize_type` into `ty`.
+fn convert(val: &TokenStream, ty: &Type) -> TokenStream {
+    if is_plain(ty) {
+        return val.clone();
+    }
+    if is_flag(ty) {
+        return quote! {
+            if #val {
+                Some(Default::default())
+            } else {
+                None
+            }
+        };
+    }
+    match ty {
+        Type::Ext(_) => quote!(#val.0),
+        Type::Option(ty) => {
+            let inner = convert(&quote!(_v), ty);
+            quote!(#val.map(|_v| #inner))
+        }
+        Type::Tuple(ty) => {
+            let kept = ty.iter().filter(|ty| !is_skipped(ty)).count();
+            let unwrapped = kept == 1 && !is_nested_option(ty);
+            let mut bindings = Vec::new();
+            let elems: Vec<TokenStream> = ty
+                .iter()
+                .map(|ty| {
+                    if is_skipped(ty) {
+                        return default(ty);
+                    }
+                    if unwrapped {
+                        return convert(val, ty);
+                    }
+                    let binding = Ident::new(&format!("_v{}", bindings.len()), Span::call_site());
+                    let elem = convert(&quote!(#binding), ty);
+                    bindings.push(binding);
+                    elem
+                })
+                .collect();
+            if unwrapped {
+                quote!((#(#elems),*))
+            } else {
+                quote!({
+                    let (#(#bindings,)*) = #val;
+                    (#(#elems),*)
+                })
+            }
+        }
+        _ => panic!("unsupported type"),
+    }
+}
+
+fn default(ty: &Type) -> TokenStream {
+    match ty {
+        Type::Ext(_) => quote!(Span::call_site()),
+        _ => quote!(Default::default()),
+    }
+}
+
+// Whether a field of this type may be left out of the serialized form, in
+// which case it deserializes as empty.
+fn has_default(ty: &Type) -> bool {
+    match ty {
+        Type::Option(_) | Type::Vec(_) | Type::Punctuated(_) => true,
+        _ => false,
+    }
+}
+
+fn requires_full(features: &Features) -> bool {
+    features.any.contains("full") && features.any.len() == 1
+}
+
+fn cfg_features(features: &Features) -> TokenStream {
+    let features = &features.any;
+    match features.len() {
+        0 => quote!(),
+        1 => quote!(#[cfg(feature = #(#features)*)]),
+        _ => quote!(#[cfg(any(#(feature = #features),*))]),
+    }
+}
+
+fn trailing_punct_key(field: &str) -> String {
+    format!("{}_trailing_punct", field)
+}
+
+fn expand_struct(node: &Node, fields: &[(String, Type)]) -> TokenStream {
+    let cfg = cfg_features(&node.features);
+    let ident = Ident::new(&node.ident, Span::call_site());
+    let name = &node.ident;
+    // The serialized fields, in order. A punctuated field is followed by one
+    // recording whether it has trailing punctuation.
+    let mut keys = Vec::new();
+    let mut values = Vec::new();
+    let mut types = Vec::new();
+    let mut defaults = Vec::new();
+    for (field, ty) in fields.iter().filter(|(_, ty)| !is_skipped(ty)) {
+        let member = Ident::new(field, Span::call_site());
+        keys.push(field.clone());
+        values.push(serialize(&quote!(self.#member), &quote!(&self.#member), ty));
+        types.push(deserialize_type(ty));
+        defaults.push(has_default(ty));
+        if let Type::Punctuated(_) = ty {
+            keys.push(trailing_punct_key(field));
+            values.push(quote!(&self.#member.trailing_punct()));
+            types.push(quote!(bool));
+            defaults.push(true);
+        }
+    }
+    let len = keys.len();
+    let names = &keys;
+
+    let serialize_fields = keys.iter().zip(&values).map(|(key, value)| {
+        quote! {
+            state.serialize_field(#key, #value)?;
+        }
+    });
+
+    let bindings: Vec<Ident> = keys
+        .iter()
+        .map(|key| Ident::new(&format!("_{}", key), Span::call_site()))
+        .collect();
+    let indices = 0..len;
+    let seq_elements = bindings
+        .iter()
+        .zip(&types)
+        .enumerate()
+        .map(|(i, (binding, ty))| {
+            quote! {
+                let #binding = seq
+                    .next_element::<#ty>()?
+                    .ok_or_else(|| de::Error::invalid_length(#i, &self))?;
+            }
+        });
+    let map_unwraps =
+        keys.iter()
+            .zip(&bindings)
+            .zip(&defaults)
+            .map(|((key, binding), &default)| {
+                if default {
+                    quote! {
+                        let #binding = #binding.unwrap_or_default();
+                    }
+                } else {
+                    quote! {
+                        let #binding = #binding.ok_or_else(|| de::Error::missing_field(#key))?;
+                    }
+                }
+            });
+    let construct_fields = fields.iter().map(|(field, ty)| {
+        let member = Ident::new(field, Span::call_site());
+        if is_skipped(ty) {
+            let default = default(ty);
+            return quote!(#member: #default);
+        }
+        let binding = Ident::new(&format!("_{}", field), Span::call_site());
+        let mut value = convert(&quote!(#binding), ty);
+        if let Type::Punctuated(_) = ty {
+            let key = trailing_punct_key(field);
+            let trailing = Ident::new(&format!("_{}", key), Span::call_site());
+            value = quote!(trailing_punct(#value, #trailing));
+        }
+        quote!(#member: #value)
+    });
+    let construct = quote! {
+        Ok(#ident {
+            #(#construct_fields,)*
+        })
+    };
+    let expecting = format!("struct {}", name);
+    let seq_param = if len == 0 {
+        quote!(_seq)
+    } else {
+        quote!(mut seq)
+    };
+    let state_binding = if len == 0 {
+        quote!(state)
+    } else {
+        quote!(mut state)
+    };
+    let map_bindings = &bindings;
+
+    quote! {
+        #cfg
+        impl Serialize for #ident {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let #state_binding = serializer.serialize_struct(#name, #len)?;
+                #(#serialize_fields)*
+                state.end()
+            }
+        }
+
+        #cfg
+        impl<'de> Deserialize<'de> for #ident {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &[#(#names),*];
+
+                struct Visitor;
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = #ident;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str(#expecting)
+                    }
+
+                    fn visit_seq<A>(self, #seq_param: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: SeqAccess<'de>,
+                    {
+                        #(#seq_elements)*
+                        #construct
+                    }
+
+                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: MapAccess<'de>,
+                    {
+                        #(let mut #map_bindings = None;)*
+                        while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                            match key {
+                                #(Some(#indices) => #map_bindings = Some(map.next_value::<#types>()?),)*
+                                _ => {
+                                    map.next_value::<de::IgnoredAny>()?;
+                                }
+                            }
+                        }
+                        #(#map_unwraps)*
+                        #construct
+                    }
+                }
+
+                deserializer.deserialize_struct(#name, FIELDS, Visitor)
+            }
+        }
+    }
+}
+
+fn expand_enum(defs: &Definitions, node: &Node, variants: &[(String, Vec<Type>)]) -> TokenStream {
+    let cfg = cfg_features(&node.features);
+    let ident = Ident::new(&node.ident, Span::call_site());
+    let name = &node.ident;
+    let names = variants.iter().map(|(variant, _)| variant);
+
+    let mut serialize_arms = Vec::new();
+    let mut deserialize_arms = Vec::new();
+    for (i, (variant, fields)) in variants.iter().enumerate() {
+        let variant_ident = Ident::new(variant, Span::call_site());
+        let index = i as u32;
+        let kept: Vec<(usize, &Type)> = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, ty)| !is_skipped(ty))
+            .collect();
+        assert!(kept.len() <= 1, "unsupported variant {}::{}", name, variant);
+
+        let payload_requires_full = kept.iter().any(|(_, ty)| match ty {
+            Type::Syn(ty) => {
+                let target = defs.types.iter().find(|node| node.ident == *ty).unwrap();
+                requires_full(&target.features) && !requires_full(&node.features)
+            }
+            _ => false,
+        });
+
+        let bindings: Vec<Ident> = (0..fields.len())
+            .map(|i| Ident::new(&format!("_v{}", i), Span::call_site()))
+            .collect();
+        let pattern = if fields.is_empty() {
+            quote!(#ident::#variant_ident)
+        } else {
+            quote!(#ident::#variant_ident(#(#bindings),*))
+        };
+
+        match kept.first() {
+            None => {
+                serialize_arms.push(quote! {
+                    #pattern => serializer.serialize_unit_variant(#name, #index, #variant),
+                });
+                let defaults = fields.iter().map(default);
+                let value = if fields.is_empty() {
+                    quote!(#ident::#variant_ident)
+                } else {
+                    quote!(#ident::#variant_ident(#(#defaults),*))
+                };
+                deserialize_arms.push(quote! {
+                    #i => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(#value)
+                    }
+                });
+            }
+            Some(&(field, ty)) => {
+                let binding = &bindings[field];
+                let value = serialize(&quote!(#binding), &quote!(#binding), ty);
+                let mut serialize_arm =
+                    quote!(serializer.serialize_newtype_variant(#name, #index, #variant, #value));
+                if payload_requires_full {
+                    serialize_arm = quote!(full!(#serialize_arm));
+                }
+                serialize_arms.push(quote!(#pattern => #serialize_arm,));
+
+                let de_ty = deserialize_type(ty);
+                let converted = convert(&quote!(_v), ty);
+                let args = fields.iter().enumerate().map(|(i, ty)| {
+                    if i == field {
+                        converted.clone()
+                    } else {
+                        default(ty)
+                    }
+                });
+                let cfg = if payload_requires_full {
+                    Some(quote!(#[cfg(feature = "full")]))
+                } else {
+                    None
+                };
+                deserialize_arms.push(quote! {
+                    #cfg
+                    #i => {
+                        let _v = de::VariantAccess::newtype_variant::<#de_ty>(access)?;
+                        Ok(#ident::#variant_ident(#(#args),*))
+                    }
+                });
+            }
+        }
+    }
+
+    let nonexhaustive = if node.exhaustive {
+        None
+    } else {
+        Some(quote!(_ => unreachable!()))
+    };
+    let expecting = format!("enum {}", name);
+
+    quote! {
+        #cfg
+        impl Serialize for #ident {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match self {
+                    #(#serialize_arms)*
+                    #nonexhaustive
+                }
+            }
+        }
+
+        #cfg
+        impl<'de> Deserialize<'de> for #ident {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                const VARIANTS: &[&str] = &[#(#names),*];
+
+                struct Visitor;
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = #ident;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str(#expecting)
+                    }
+
+                    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: EnumAccess<'de>,
+                    {
+                        let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                        match variant {
+                            #(#deserialize_arms)*
+                            _ => Err(unsupported_variant(#name, VARIANTS[variant])),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_enum(#name, VARIANTS, Visitor)
+            }
+        }
+    }
+}
+
+fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
+    match &node.data {
+        Data::Struct(fields) => {
+            let fields: Vec<(String, Type)> = fields
+                .iter()
+                .map(|(field, ty)| (field.clone(), ty.clone()))
+                .collect();
+            expand_struct(node, &fields)
+        }
+        Data::Enum(variants) => {
+            let variants: Vec<(String, Vec<Type>)> = variants
+                .iter()
+                .map(|(variant, fields)| (variant.clone(), fields.clone()))
+                .collect();
+            expand_enum(defs, node, &variants)
+        }
+        // Literals are serialized by hand as their source representation.
+        Data::Private => TokenStream::new(),
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let mut impls = TokenStream::new();
+    for node in &defs.types {
+        if node.ident == "Reserved" {
+            continue;
+        }
+        impls.extend(expand_impl(defs, node));
+    }
+    let full_macro = full::get_macro();
+
+    file::write(
+        SERDE_SRC,
+        quote! {
+            #![allow(clippy::match_single_binding)]
+
+            use crate::*;
+            use crate::gen::helper::serde::*;
+            use crate::punctuated::Punctuated;
+            use ::serde::de::{self, Deserialize, Deserializer, EnumAccess, MapAccess, SeqAccess};
+            use ::serde::ser::{Serialize, SerializeStruct, Serializer};
+            use proc_macro2::Span;
+            use std::fmt;
+            use std::result::Result;
+
+            #full_macro
+
+            #impls
+        },
+    )?;
+
+    Ok(())
+}
@@ -7,6 +7,7 @@ use syn::Index;
 use syn_codegen::{Data, Definitions, Features, Node, Type};
 
 const VISIT_SRC: &str = "../src/gen/visit.rs";
+const VISIT_ANCESTORS_SRC: &str = "../src/gen/visit_ancestors.rs";
 
 fn simple_visit(item: &str, name: &Operand) -> TokenStream {
     let ident = gen::under_name(item);
@@ -24,6 +25,10 @@ fn noop_visit(name: &Operand) -> TokenStream {
     }
 }
 
+fn requires_full(features: &Features) -> bool {
+    features.any.contains("full") && features.any.len() == 1
+}
+
 fn visit(
     ty: &Type,
     features: &Features,
@@ -101,9 +106,6 @@ fn visit(
             })
         }
         Type::Syn(t) => {
-            fn requires_full(features: &Features) -> bool {
-                features.any.contains("full") && features.any.len() == 1
-            }
             let mut res = simple_visit(t, name);
             let target = defs.types.iter().find(|ty| ty.ident == *t).unwrap();
             if requires_full(&target.features) && !requires_full(features) {
@@ -225,6 +227,192 @@ fn node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Defi
     });
 }
 
+fn ancestors_visit(
+    ty: &Type,
+    features: &Features,
+    defs: &Definitions,
+    name: &Operand,
+) -> Option<TokenStream> {
+    match ty {
+        Type::Box(t) => {
+            let name = name.owned_tokens();
+            ancestors_visit(t, features, defs, &Owned(quote!(*#name)))
+        }
+        Type::Vec(t) => {
+            let operand = Borrowed(quote!(it));
+            let val = ancestors_visit(t, features, defs, &operand)?;
+            let name = name.ref_tokens();
+            Some(quote! {
+                for it in #name {
+                    #val
+                }
+            })
+        }
+        Type::Punctuated(p) => {
+            let operand = Borrowed(quote!(it));
+            let val = ancestors_visit(&p.element, features, defs, &operand)?;
+            let name = name.ref_tokens();
+            Some(quote! {
+                for it in #name {
+                    #val
+                }
+            })
+        }
+        Type::Option(t) => {
+            let it = Borrowed(quote!(it));
+            let val = ancestors_visit(t, features, defs, &it)?;
+            let name = name.owned_tokens();
+            Some(quote! {
+                if let Some(it) = &#name {
+                    #val
+                }
+            })
+        }
+        Type::Tuple(t) => {
+            let mut code = TokenStream::new();
+            for (i, elem) in t.iter().enumerate() {
+                let name = name.tokens();
+                let i = Index::from(i);
+                let it = Owned(quote!((#name).#i));
+                if let Some(val) = ancestors_visit(elem, features, defs, &it) {
+                    code.extend(val);
+                    code.extend(quote!(;));
+                }
+            }
+            if code.is_empty() {
+                None
+            } else {
+                Some(code)
+            }
+        }
+        Type::Syn(t) if t == "Reserved" => None,
+        Type::Syn(t) => {
+            let ident = gen::under_name(t);
+            let method = Ident::new(&format!("visit_{}", ident), Span::call_site());
+            let name = name.ref_tokens();
+            let mut res = quote!(v.#method(#name, ancestors));
+            let target = defs.types.iter().find(|ty| ty.ident == *t).unwrap();
+            if requires_full(&target.features) && !requires_full(features) {
+                res = quote!(full!(#res));
+            }
+            Some(res)
+        }
+        Type::Ext(t) if t == "Ident" => {
+            let name = name.ref_tokens();
+            Some(quote!(v.visit_ident(#name, ancestors)))
+        }
+        Type::Token(_) | Type::Group(_) | Type::Ext(_) | Type::Std(_) => None,
+    }
+}
+
+fn ancestors_node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Definitions) {
+    // Spans are not nodes, so the ancestor-tracking visitor leaves them out
+    // along with tokens.
+    if s.ident == "Span" {
+        return;
+    }
+
+    let under_name = gen::under_name(&s.ident);
+    let ty = Ident::new(&s.ident, Span::call_site());
+    let visit_fn = Ident::new(&format!("visit_{}", under_name), Span::call_site());
+
+    let mut visit_children = TokenStream::new();
+
+    match &s.data {
+        Data::Enum(variants) => {
+            let mut visit_variants = TokenStream::new();
+            let mut any_children = false;
+
+            for (variant, fields) in variants {
+                let variant_ident = Ident::new(variant, Span::call_site());
+                let mut bind_fields = TokenStream::new();
+                let mut visit_fields = TokenStream::new();
+
+                for (idx, ty) in fields.iter().enumerate() {
+                    let name = format!("_binding_{}", idx);
+                    let binding = Ident::new(&name, Span::call_site());
+                    bind_fields.extend(quote!(#binding,));
+                    let borrowed_binding = Borrowed(quote!(#binding));
+                    if let Some(val) = ancestors_visit(ty, &s.features, defs, &borrowed_binding) {
+                        visit_fields.extend(val);
+                        visit_fields.extend(quote!(;));
+                    }
+                }
+
+                if visit_fields.is_empty() {
+                    let pattern = if fields.is_empty() {
+                        quote!(#ty::#variant_ident)
+                    } else {
+                        quote!(#ty::#variant_ident(..))
+                    };
+                    visit_variants.extend(quote! {
+                        #pattern => {}
+                    });
+                } else {
+                    any_children = true;
+                    visit_variants.extend(quote! {
+                        #ty::#variant_ident(#bind_fields) => {
+                            #visit_fields
+                        }
+                    });
+                }
+            }
+
+            let nonexhaustive = if s.exhaustive {
+                None
+            } else {
+                Some(quote!(_ => unreachable!()))
+            };
+
+            if any_children {
+                visit_children.extend(quote! {
+                    match node {
+                        #visit_variants
+                        #nonexhaustive
+                    }
+                });
+            }
+        }
+        Data::Struct(fields) => {
+            for (field, ty) in fields {
+                let id = Ident::new(&field, Span::call_site());
+                let ref_toks = Owned(quote!(node.#id));
+                if let Some(visit_field) = ancestors_visit(&ty, &s.features, defs, &ref_toks) {
+                    visit_children.extend(quote! {
+                        #visit_field;
+                    });
+                }
+            }
+        }
+        Data::Private => {}
+    }
+
+    let visit_impl = if visit_children.is_empty() {
+        visit_children
+    } else {
+        quote! {
+            ancestors.push(NodeRef::#ty(node));
+            #visit_children
+            ancestors.pop();
+        }
+    };
+
+    traits.extend(quote! {
+        fn #visit_fn(&mut self, i: &'ast #ty, ancestors: &mut Ancestors<'ast>) {
+            #visit_fn(self, i, ancestors)
+        }
+    });
+
+    impls.extend(quote! {
+        pub fn #visit_fn<'ast, V>(v: &mut V, node: &'ast #ty, ancestors: &mut Ancestors<'ast>)
+        where
+            V: VisitAncestors<'ast> + ?Sized,
+        {
+            #visit_impl
+        }
+    });
+}
+
 pub fn generate(defs: &Definitions) -> Result<()> {
     let (traits, impls) = gen::traverse(defs, node);
     let full_macro = full::get_macro();
@@ -261,5 +449,75 @@ pub fn generate(defs: &Definitions) -> Result<()> {
             #impls
         },
     )?;
+
+    let (traits, impls) = gen::traverse(defs, ancestors_node);
+    file::write(
+        VISIT_ANCESTORS_SRC,
+        quote! {
+            #![allow(unused_variables)]
+
+            use crate::*;
+            use crate::node::NodeRef;
+            use std::ops::Deref;
+
+            #full_macro
+
+            /// Syntax tree traversal to walk a shared borrow of a syntax tree
+            /// while keeping track of the ancestors of each node.
+            ///
+            /// See the [module documentation] for details.
+            ///
+            /// [module documentation]: self
+            ///
+            /// *This trait is available if Syn is built with the `"visit"` feature.*
+            pub trait VisitAncestors<'ast> {
+                #traits
+            }
+
+            /// The nodes enclosing the node being visited, from the root of the
+            /// traversal down to its parent.
+            ///
+            /// This dereferences to a slice of [`NodeRef`]. The stack is
+            /// maintained by the functions in this module, which push a node
+            /// before visiting its children and pop it afterwards.
+            ///
+            /// *This type is available if Syn is built with the `"visit"` feature.*
+            #[derive(Clone, Default)]
+            #[cfg_attr(feature = "extra-traits", derive(Debug))]
+            pub struct Ancestors<'ast> {
+                stack: Vec<NodeRef<'ast>>,
+            }
+
+            impl<'ast> Ancestors<'ast> {
+                /// An empty stack, for starting a traversal from the root.
+                pub fn new() -> Self {
+                    Ancestors { stack: Vec::new() }
+                }
+
+                /// The innermost enclosing node, if any.
+                pub fn parent(&self) -> Option<NodeRef<'ast>> {
+                    self.stack.last().cloned()
+                }
+
+                fn push(&mut self, node: NodeRef<'ast>) {
+                    self.stack.push(node);
+                }
+
+                fn pop(&mut self) {
+                    self.stack.pop();
+                }
+            }
+
+            impl<'ast> Deref for Ancestors<'ast> {
+                type Target = [NodeRef<'ast>];
+
+                fn deref(&self) -> &Self::Target {
+                    &self.stack
+                }
+            }
+
+            #impls
+        },
+    )?;
     Ok(())
 }
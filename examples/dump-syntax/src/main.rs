@@ -16,18 +16,19 @@
 //!                 style: Inner(
 //!         ...
 //!     }
+//!
+//! With `--compact`, the syntax tree is printed in the shorter representation
+//! of `syn::compact` instead:
+//!
+//!     cargo run -- --compact src/main.rs
 
-use std::borrow::Cow;
 use std::env;
-use std::ffi::OsStr;
 use std::fmt::{self, Display};
 use std::fs;
 use std::io::{self, Write};
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 use std::process;
 
-use colored::Colorize;
-
 enum Error {
     IncorrectUsage,
     ReadFile(io::Error),
@@ -43,13 +44,13 @@ impl Display for Error {
         use self::Error::*;
 
         match self {
-            IncorrectUsage => write!(f, "Usage: dump-syntax path/to/filename.rs"),
+            IncorrectUsage => write!(f, "Usage: dump-syntax [--compact] path/to/filename.rs"),
             ReadFile(error) => write!(f, "Unable to read file: {}", error),
             ParseFile {
                 error,
                 filepath,
                 source_code,
-            } => render_location(f, error, filepath, source_code),
+            } => write!(f, "\n{}", error.render(filepath, source_code)),
         }
     }
 }
@@ -65,8 +66,9 @@ fn try_main() -> Result<(), Error> {
     let mut args = env::args_os();
     let _ = args.next(); // executable name
 
-    let filepath = match (args.next(), args.next()) {
-        (Some(arg), None) => PathBuf::from(arg),
+    let (compact, filepath) = match (args.next(), args.next(), args.next()) {
+        (Some(arg), None, None) => (false, PathBuf::from(arg)),
+        (Some(ref flag), Some(arg), None) if flag == "--compact" => (true, PathBuf::from(arg)),
         _ => return Err(Error::IncorrectUsage),
     };
 
@@ -78,72 +80,11 @@ fn try_main() -> Result<(), Error> {
             source_code: code,
         }
     })?;
-    println!("{:#?}", syntax);
-
-    Ok(())
-}
-
-// Render a rustc-style error message, including colors.
-//
-//     error: Syn unable to parse file
-//       --> main.rs:40:17
-//        |
-//     40 |     fn fmt(&self formatter: &mut fmt::Formatter) -> fmt::Result {
-//        |                  ^^^^^^^^^ expected `,`
-//
-fn render_location(
-    formatter: &mut fmt::Formatter,
-    err: &syn::Error,
-    filepath: &Path,
-    code: &str,
-) -> fmt::Result {
-    let start = err.span().start();
-    let mut end = err.span().end();
-
-    if start.line == end.line && start.column == end.column {
-        return render_fallback(formatter, err);
+    if compact {
+        println!("{:#?}", syn::compact::debug(&syntax));
+    } else {
+        println!("{:#?}", syntax);
     }
 
-    let code_line = match code.lines().nth(start.line - 1) {
-        Some(line) => line,
-        None => return render_fallback(formatter, err),
-    };
-
-    if end.line > start.line {
-        end.line = start.line;
-        end.column = code_line.len();
-    }
-
-    let filename = filepath
-        .file_name()
-        .map(OsStr::to_string_lossy)
-        .unwrap_or(Cow::Borrowed("main.rs"));
-
-    write!(
-        formatter,
-        "\n\
-         {error}{header}\n\
-         {indent}{arrow} {filename}:{linenum}:{colnum}\n\
-         {indent} {pipe}\n\
-         {label} {pipe} {code}\n\
-         {indent} {pipe} {offset}{underline} {message}\n\
-         ",
-        error = "error".red().bold(),
-        header = ": Syn unable to parse file".bold(),
-        indent = " ".repeat(start.line.to_string().len()),
-        arrow = "-->".blue().bold(),
-        filename = filename,
-        linenum = start.line,
-        colnum = start.column,
-        pipe = "|".blue().bold(),
-        label = start.line.to_string().blue().bold(),
-        code = code_line.trim_end(),
-        offset = " ".repeat(start.column),
-        underline = "^".repeat(end.column - start.column).red().bold(),
-        message = err.to_string().red(),
-    )
-}
-
-fn render_fallback(formatter: &mut fmt::Formatter, err: &syn::Error) -> fmt::Result {
-    write!(formatter, "Unable to parse file: {}", err)
+    Ok(())
 }
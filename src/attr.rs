@@ -7,7 +7,6 @@ use proc_macro2::TokenStream;
 
 #[cfg(feature = "parsing")]
 use crate::parse::{Parse, ParseBuffer, ParseStream, Parser, Result};
-#[cfg(feature = "parsing")]
 use crate::punctuated::Pair;
 #[cfg(feature = "extra-traits")]
 use crate::tt::TokenStreamHelper;
@@ -156,6 +155,30 @@ impl Attribute {
     /// feature.*
     #[cfg(feature = "parsing")]
     pub fn parse_meta(&self) -> Result<Meta> {
+        let parser = |input: ParseStream| parsing::parse_meta_after_path(self.meta_path(), input);
+        parse::Parser::parse2(parser, self.tokens.clone())
+    }
+
+    /// Parses the content of the attribute as a [`MetaExpr`], in which the
+    /// value of a name-value pair may be any expression or tokens rather than
+    /// only a literal.
+    ///
+    /// This accepts attributes such as `#[serde(default = path::to::func)]`
+    /// or `#[cfg_attr(x, y = 1 + 2)]` for which [`parse_meta`] fails.
+    ///
+    /// [`parse_meta`]: Attribute::parse_meta
+    ///
+    /// *This function is available if Syn is built with the `"parsing"`
+    /// feature.*
+    #[cfg(feature = "parsing")]
+    pub fn parse_meta_expr(&self) -> Result<MetaExpr> {
+        let parser =
+            |input: ParseStream| parsing::parse_meta_expr_after_path(self.meta_path(), input);
+        parse::Parser::parse2(parser, self.tokens.clone())
+    }
+
+    #[cfg(feature = "parsing")]
+    fn meta_path(&self) -> Path {
         fn clone_ident_segment(segment: &PathSegment) -> PathSegment {
             PathSegment {
                 ident: segment.ident.clone(),
@@ -163,7 +186,7 @@ impl Attribute {
             }
         }
 
-        let path = Path {
+        Path {
             leading_colon: self
                 .path
                 .leading_colon
@@ -180,10 +203,25 @@ impl Attribute {
                     Pair::End(seg) => Pair::End(clone_ident_segment(seg)),
                 })
                 .collect(),
-        };
+        }
+    }
 
-        let parser = |input: ParseStream| parsing::parse_meta_after_path(path, input);
-        parse::Parser::parse2(parser, self.tokens.clone())
+    /// Parses the content of the attribute as a [`Meta`] and then into any
+    /// type that implements [`FromMeta`], such as a struct of arguments
+    /// defined by [`meta_args!`].
+    ///
+    /// [`FromMeta`]: crate::meta::FromMeta
+    /// [`meta_args!`]: crate::meta_args
+    ///
+    /// See the [`meta`] module for an example.
+    ///
+    /// [`meta`]: crate::meta
+    ///
+    /// *This function is available if Syn is built with the `"parsing"`,
+    /// `"printing"` and `"clone-impls"` features.*
+    #[cfg(all(feature = "parsing", feature = "printing", feature = "clone-impls"))]
+    pub fn parse_meta_into<T: crate::meta::FromMeta>(&self) -> Result<T> {
+        T::from_meta(&self.parse_meta()?)
     }
 
     /// Parse the arguments to the attribute as a syntax tree.
@@ -410,6 +448,198 @@ ast_enum_of_structs! {
     }
 }
 
+ast_enum_of_structs! {
+    /// Content of a compile-time structured attribute in which the value of a
+    /// name-value pair may be an arbitrary expression.
+    ///
+    /// This is the same as [`Meta`] except that name-value pairs hold a
+    /// [`MetaValue`] rather than a [`Lit`], so that attributes like
+    /// `#[serde(default = path::to::func)]` or `#[doc = include_str!("x")]`
+    /// can be represented. Use [`Attribute::parse_meta_expr`] to obtain one.
+    ///
+    /// *This type is available if Syn is built with the `"derive"` or `"full"`
+    /// feature.*
+    ///
+    /// # Syntax tree enum
+    ///
+    /// This type is a [syntax tree enum].
+    ///
+    /// [syntax tree enum]: enum.Expr.html#syntax-tree-enums
+    pub enum MetaExpr {
+        Path(Path),
+
+        /// A structured list within an attribute, like `serde(default = f)`.
+        List(MetaExprList),
+
+        /// A name-value pair within an attribute, like `default = f`.
+        NameValue(MetaExprNameValue),
+    }
+}
+
+ast_struct! {
+    /// A structured list within an attribute, like `serde(default = f)`.
+    ///
+    /// *This type is available if Syn is built with the `"derive"` or
+    /// `"full"` feature.*
+    pub struct MetaExprList {
+        pub path: Path,
+        pub paren_token: token::Paren,
+        pub nested: Punctuated<NestedMetaExpr, Token![,]>,
+    }
+}
+
+ast_struct! {
+    /// A name-value pair within an attribute, like `default = path::to::func`.
+    ///
+    /// *This type is available if Syn is built with the `"derive"` or
+    /// `"full"` feature.*
+    pub struct MetaExprNameValue {
+        pub path: Path,
+        pub eq_token: Token![=],
+        pub value: MetaValue,
+    }
+}
+
+ast_enum_of_structs! {
+    /// The value on the right-hand side of a [`MetaExprNameValue`].
+    ///
+    /// *This type is available if Syn is built with the `"derive"` or `"full"`
+    /// feature.*
+    ///
+    /// ## Expr
+    ///
+    /// Any value that parses as an expression, including literals like the
+    /// `"x"` in `rename = "x"`, which become an [`Expr::Lit`].
+    ///
+    /// ## Verbatim
+    ///
+    /// The tokens up to the next top-level comma, for values that do not parse
+    /// as an expression such as `ty = Vec<u8>`.
+    ///
+    /// # Syntax tree enum
+    ///
+    /// This type is a [syntax tree enum].
+    ///
+    /// [syntax tree enum]: enum.Expr.html#syntax-tree-enums
+    pub enum MetaValue #manual_extra_traits {
+        Expr(Expr),
+        Verbatim(TokenStream),
+    }
+}
+
+ast_enum_of_structs! {
+    /// Element of a compile-time attribute list in which name-value pairs may
+    /// hold arbitrary expressions.
+    ///
+    /// *This type is available if Syn is built with the `"derive"` or `"full"`
+    /// feature.*
+    pub enum NestedMetaExpr {
+        /// A structured meta item, like the `default = f` in
+        /// `#[serde(default = f)]`.
+        Meta(MetaExpr),
+
+        /// A Rust literal, like the `"new_name"` in `#[rename("new_name")]`.
+        Lit(Lit),
+    }
+}
+
+impl MetaExpr {
+    /// Returns the identifier that begins this structured meta item.
+    ///
+    /// For example this would return the `serde` in
+    /// `#[serde(default = path::to::func)]`.
+    pub fn path(&self) -> &Path {
+        match self {
+            MetaExpr::Path(path) => path,
+            MetaExpr::List(meta) => &meta.path,
+            MetaExpr::NameValue(meta) => &meta.path,
+        }
+    }
+}
+
+impl MetaValue {
+    /// Returns the literal if this value is nothing but a literal, as in the
+    /// literal-only [`MetaNameValue`].
+    pub fn lit(&self) -> Option<&Lit> {
+        match self {
+            MetaValue::Expr(Expr::Lit(expr)) if expr.attrs.is_empty() => Some(&expr.lit),
+            _ => None,
+        }
+    }
+}
+
+impl From<Meta> for MetaExpr {
+    fn from(meta: Meta) -> Self {
+        match meta {
+            Meta::Path(path) => MetaExpr::Path(path),
+            Meta::List(list) => MetaExpr::List(MetaExprList {
+                path: list.path,
+                paren_token: list.paren_token,
+                nested: list
+                    .nested
+                    .into_pairs()
+                    .map(|pair| match pair {
+                        Pair::Punctuated(nested, punct) => Pair::Punctuated(nested.into(), punct),
+                        Pair::End(nested) => Pair::End(nested.into()),
+                    })
+                    .collect(),
+            }),
+            Meta::NameValue(nv) => MetaExpr::NameValue(MetaExprNameValue {
+                path: nv.path,
+                eq_token: nv.eq_token,
+                value: MetaValue::Expr(Expr::Lit(ExprLit {
+                    attrs: Vec::new(),
+                    lit: nv.lit,
+                })),
+            }),
+        }
+    }
+}
+
+impl From<NestedMeta> for NestedMetaExpr {
+    fn from(nested: NestedMeta) -> Self {
+        match nested {
+            NestedMeta::Meta(meta) => NestedMetaExpr::Meta(meta.into()),
+            NestedMeta::Lit(lit) => NestedMetaExpr::Lit(lit),
+        }
+    }
+}
+
+#[cfg(feature = "extra-traits")]
+impl Eq for MetaValue {}
+
+#[cfg(feature = "extra-traits")]
+impl PartialEq for MetaValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (MetaValue::Expr(this), MetaValue::Expr(other)) => this == other,
+            (MetaValue::Verbatim(this), MetaValue::Verbatim(other)) => {
+                TokenStreamHelper(this) == TokenStreamHelper(other)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "extra-traits")]
+impl Hash for MetaValue {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            MetaValue::Expr(expr) => {
+                state.write_u8(0u8);
+                expr.hash(state);
+            }
+            MetaValue::Verbatim(tokens) => {
+                state.write_u8(1u8);
+                TokenStreamHelper(tokens).hash(state);
+            }
+        }
+    }
+}
+
 /// Conventional argument type associated with an invocation of an attribute
 /// macro.
 ///
@@ -486,7 +716,9 @@ pub mod parsing {
     use super::*;
 
     use crate::ext::IdentExt;
+    use crate::parse::discouraged::Speculative;
     use crate::parse::{Parse, ParseStream, Result};
+    use proc_macro2::TokenTree;
     #[cfg(feature = "full")]
     use crate::private;
 
@@ -579,6 +811,61 @@ pub mod parsing {
         }
     }
 
+    impl Parse for MetaExpr {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let path = input.call(parse_meta_path)?;
+            parse_meta_expr_after_path(path, input)
+        }
+    }
+
+    impl Parse for MetaExprList {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let path = input.call(parse_meta_path)?;
+            parse_meta_expr_list_after_path(path, input)
+        }
+    }
+
+    impl Parse for MetaExprNameValue {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let path = input.call(parse_meta_path)?;
+            parse_meta_expr_name_value_after_path(path, input)
+        }
+    }
+
+    impl Parse for NestedMetaExpr {
+        fn parse(input: ParseStream) -> Result<Self> {
+            if input.peek(Lit) && !(input.peek(LitBool) && input.peek2(Token![=])) {
+                input.parse().map(NestedMetaExpr::Lit)
+            } else if input.peek(Ident::peek_any) {
+                input.parse().map(NestedMetaExpr::Meta)
+            } else {
+                Err(input.error("expected identifier or literal"))
+            }
+        }
+    }
+
+    impl Parse for MetaValue {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let ahead = input.fork();
+            if let Ok(expr) = ahead.parse() {
+                if ahead.is_empty() || ahead.peek(Token![,]) {
+                    input.advance_to(&ahead);
+                    return Ok(MetaValue::Expr(expr));
+                }
+            }
+
+            let mut tokens = TokenStream::new();
+            while !input.is_empty() && !input.peek(Token![,]) {
+                let tt: TokenTree = input.parse()?;
+                tokens.extend(iter::once(tt));
+            }
+            if tokens.is_empty() {
+                return Err(input.error("expected a value"));
+            }
+            Ok(MetaValue::Verbatim(tokens))
+        }
+    }
+
     pub fn parse_meta_after_path(path: Path, input: ParseStream) -> Result<Meta> {
         if input.peek(token::Paren) {
             parse_meta_list_after_path(path, input).map(Meta::List)
@@ -605,6 +892,36 @@ pub mod parsing {
             lit: input.parse()?,
         })
     }
+
+    pub fn parse_meta_expr_after_path(path: Path, input: ParseStream) -> Result<MetaExpr> {
+        if input.peek(token::Paren) {
+            parse_meta_expr_list_after_path(path, input).map(MetaExpr::List)
+        } else if input.peek(Token![=]) {
+            parse_meta_expr_name_value_after_path(path, input).map(MetaExpr::NameValue)
+        } else {
+            Ok(MetaExpr::Path(path))
+        }
+    }
+
+    fn parse_meta_expr_list_after_path(path: Path, input: ParseStream) -> Result<MetaExprList> {
+        let content;
+        Ok(MetaExprList {
+            path,
+            paren_token: parenthesized!(content in input),
+            nested: content.parse_terminated(NestedMetaExpr::parse)?,
+        })
+    }
+
+    fn parse_meta_expr_name_value_after_path(
+        path: Path,
+        input: ParseStream,
+    ) -> Result<MetaExprNameValue> {
+        Ok(MetaExprNameValue {
+            path,
+            eq_token: input.parse()?,
+            value: input.parse()?,
+        })
+    }
 }
 
 #[cfg(feature = "printing")]
@@ -642,4 +959,21 @@ mod printing {
             self.lit.to_tokens(tokens);
         }
     }
+
+    impl ToTokens for MetaExprList {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.path.to_tokens(tokens);
+            self.paren_token.surround(tokens, |tokens| {
+                self.nested.to_tokens(tokens);
+            })
+        }
+    }
+
+    impl ToTokens for MetaExprNameValue {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.path.to_tokens(tokens);
+            self.eq_token.to_tokens(tokens);
+            self.value.to_tokens(tokens);
+        }
+    }
 }
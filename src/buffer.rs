@@ -331,6 +331,22 @@ impl<'a> Cursor<'a> {
         Some((tree, unsafe { self.bump() }))
     }
 
+    /// Returns a cursor pointing past the next token, or `None` if the cursor
+    /// has reached the end of its stream.
+    ///
+    /// Unlike [`token_tree`], this method treats `None`-delimited groups as
+    /// transparent, so it steps into one and past the first token inside it.
+    /// A lifetime counts as one token.
+    ///
+    /// [`token_tree`]: Cursor::token_tree
+    pub fn skip(mut self) -> Option<Cursor<'a>> {
+        self.ignore_none();
+        if let Some((_lifetime, rest)) = self.lifetime() {
+            return Some(rest);
+        }
+        self.token_tree().map(|(_tt, rest)| rest)
+    }
+
     /// Returns the `Span` of the current token, or `Span::call_site()` if this
     /// cursor points to eof.
     pub fn span(self) -> Span {
@@ -348,6 +364,28 @@ pub(crate) fn same_scope(a: Cursor, b: Cursor) -> bool {
     a.scope == b.scope
 }
 
+// Number of tokens from `from` forward to `to`, counting a lifetime as one
+// token and looking through `None`-delimited groups. Both cursors must be in
+// the same scope with `to` not before `from`.
+pub(crate) fn distance(from: Cursor, to: Cursor) -> usize {
+    let mut cursor = from;
+    let mut distance = 0;
+    while cursor != to {
+        let mut inner = cursor;
+        inner.ignore_none();
+        if inner != cursor {
+            cursor = inner;
+            continue;
+        }
+        match cursor.skip() {
+            Some(rest) => cursor = rest,
+            None => break,
+        }
+        distance += 1;
+    }
+    distance
+}
+
 pub(crate) fn open_span_of_group(cursor: Cursor) -> Span {
     match cursor.entry() {
         Entry::Group(group, _) => group.span_open(),
@@ -361,3 +399,21 @@ pub(crate) fn close_span_of_group(cursor: Cursor) -> Span {
         _ => cursor.span(),
     }
 }
+
+// Span of the closing delimiter of the group whose contents the cursor has
+// reached the end of, or `None` if the cursor is not at the end of a group.
+#[cfg(feature = "span-locations")]
+pub(crate) fn close_span_of_scope(cursor: Cursor) -> Option<Span> {
+    match cursor.entry() {
+        Entry::End(up) if !up.is_null() => {
+            // The `End` entry of a group's contents points to the entry
+            // following the group in the enclosing buffer, so the group itself
+            // is the entry right before that one.
+            match unsafe { &*up.sub(1) } {
+                Entry::Group(group, _) => Some(group.span_close()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
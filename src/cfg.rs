@@ -0,0 +1,594 @@
+//! Evaluation of `#[cfg]` and `#[cfg_attr]` attributes.
+//!
+//! A [`CfgExpr`] is the predicate inside of `#[cfg(...)]`, such as `unix` or
+//! `all(feature = "std", not(test))`. It is evaluated against a [`CfgSet`] of
+//! enabled configuration options, which is supplied by the caller since the
+//! options in effect for a given target are not something Syn can know.
+//!
+//! ```
+//! use syn::cfg::{CfgExpr, CfgSet};
+//! use syn::{parse_quote, Attribute};
+//!
+//! # fn main() -> syn::Result<()> {
+//! let attr: Attribute = parse_quote!(#[cfg(all(feature = "std", not(test)))]);
+//! let predicate: CfgExpr = attr.parse_args()?;
+//!
+//! let mut cfgs = CfgSet::new();
+//! cfgs.insert_value("feature", "std");
+//! assert!(predicate.eval(&cfgs));
+//!
+//! cfgs.insert("test");
+//! assert!(!predicate.eval(&cfgs));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! With the `"full"` and `"visit-mut"` features, [`StripCfg`] removes every
+//! disabled item, field, statement and so on from a syntax tree, and expands
+//! the `cfg_attr` attributes of the ones that remain.
+//!
+//! *This module is available if Syn is built with the `"parsing"` feature and
+//! either the `"derive"` or `"full"` feature.*
+
+use std::collections::BTreeSet;
+use std::mem;
+
+use proc_macro2::{TokenStream, TokenTree};
+
+use crate::ext::IdentExt;
+use crate::parse::{Parse, ParseStream, Result};
+use crate::punctuated::Punctuated;
+use crate::token;
+use crate::{AttrStyle, Attribute, Error, Ident, LitStr, Path};
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+use crate::punctuated::Pair;
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+use crate::visit_mut::{self, VisitMut};
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+use crate::*;
+
+/// A configuration predicate, like the `all(unix, feature = "std")` in
+/// `#[cfg(all(unix, feature = "std"))]`.
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq, Hash))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub enum CfgExpr {
+    /// A configuration option that is either set or not, like `unix`.
+    Name(Ident),
+
+    /// A configuration option with a value, like `feature = "std"`.
+    KeyValue(Ident, LitStr),
+
+    /// True if every one of the predicates is true: `all(a, b)`.
+    All(Vec<CfgExpr>),
+
+    /// True if at least one of the predicates is true: `any(a, b)`.
+    Any(Vec<CfgExpr>),
+
+    /// True if the predicate is false: `not(a)`.
+    Not(Box<CfgExpr>),
+}
+
+impl CfgExpr {
+    /// Determines whether the predicate holds for the given set of enabled
+    /// configuration options.
+    ///
+    /// Like in rustc, `all()` is true and `any()` is false.
+    pub fn eval(&self, cfgs: &CfgSet) -> bool {
+        match self {
+            CfgExpr::Name(name) => cfgs.contains(&name.unraw().to_string()),
+            CfgExpr::KeyValue(key, value) => {
+                cfgs.contains_value(&key.unraw().to_string(), &value.value())
+            }
+            CfgExpr::All(predicates) => predicates.iter().all(|predicate| predicate.eval(cfgs)),
+            CfgExpr::Any(predicates) => predicates.iter().any(|predicate| predicate.eval(cfgs)),
+            CfgExpr::Not(predicate) => !predicate.eval(cfgs),
+        }
+    }
+}
+
+impl Parse for CfgExpr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.call(Ident::parse_any)?;
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            return Ok(CfgExpr::KeyValue(name, input.parse()?));
+        }
+        if !input.peek(token::Paren) {
+            return Ok(CfgExpr::Name(name));
+        }
+
+        let content;
+        parenthesized!(content in input);
+        let predicates: Punctuated<CfgExpr, Token![,]> =
+            content.parse_terminated(CfgExpr::parse)?;
+        if name == "all" {
+            Ok(CfgExpr::All(predicates.into_iter().collect()))
+        } else if name == "any" {
+            Ok(CfgExpr::Any(predicates.into_iter().collect()))
+        } else if name == "not" {
+            let mut predicates = predicates.into_iter();
+            match (predicates.next(), predicates.next()) {
+                (Some(predicate), None) => Ok(CfgExpr::Not(Box::new(predicate))),
+                _ => Err(Error::new(
+                    name.span(),
+                    "expected exactly one cfg predicate inside of `not(...)`",
+                )),
+            }
+        } else {
+            Err(Error::new(
+                name.span(),
+                format!(
+                    "invalid cfg predicate `{}`, expected `all`, `any` or `not`",
+                    name,
+                ),
+            ))
+        }
+    }
+}
+
+/// The set of configuration options in effect, against which a [`CfgExpr`] is
+/// evaluated.
+///
+/// Names like `unix` and key-value pairs like `feature = "std"` are enabled
+/// separately; enabling `feature = "std"` does not enable a bare `feature`.
+#[derive(Clone, Default, Debug)]
+pub struct CfgSet {
+    names: BTreeSet<String>,
+    values: BTreeSet<(String, String)>,
+}
+
+impl CfgSet {
+    /// Creates a set in which no configuration option is enabled.
+    pub fn new() -> Self {
+        CfgSet::default()
+    }
+
+    /// Enables a configuration option that has no value, like `unix`.
+    pub fn insert(&mut self, name: &str) {
+        self.names.insert(name.to_owned());
+    }
+
+    /// Enables a configuration option with a value, like `feature = "std"`.
+    ///
+    /// A key may be enabled with any number of different values.
+    pub fn insert_value(&mut self, key: &str, value: &str) {
+        self.values.insert((key.to_owned(), value.to_owned()));
+    }
+
+    /// Whether the configuration option without a value is enabled.
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    /// Whether the configuration option is enabled with the given value.
+    pub fn contains_value(&self, key: &str, value: &str) -> bool {
+        self.values.contains(&(key.to_owned(), value.to_owned()))
+    }
+
+    /// Determines whether something carrying these attributes is compiled,
+    /// which is the case if the predicates of all of its `#[cfg(...)]`
+    /// attributes hold.
+    ///
+    /// `cfg_attr` attributes are not looked through; call [`expand_cfg_attr`]
+    /// first to take into account a `#[cfg_attr(a, cfg(b))]`.
+    ///
+    /// [`expand_cfg_attr`]: CfgSet::expand_cfg_attr
+    pub fn is_enabled(&self, attrs: &[Attribute]) -> Result<bool> {
+        let mut enabled = true;
+        for attr in attrs {
+            if attr.path.is_ident("cfg") {
+                let predicate: CfgExpr = attr.parse_args()?;
+                enabled &= predicate.eval(self);
+            }
+        }
+        Ok(enabled)
+    }
+
+    /// Replaces every `#[cfg_attr(predicate, attrs...)]` with the attributes
+    /// it contains if the predicate holds, or removes it otherwise.
+    ///
+    /// The attributes brought in are expanded as well, so a
+    /// `#[cfg_attr(a, cfg_attr(b, c))]` becomes `#[c]` if both `a` and `b` are
+    /// enabled.
+    pub fn expand_cfg_attr(&self, attrs: &mut Vec<Attribute>) -> Result<()> {
+        let mut expanded = Vec::new();
+        let mut pending: Vec<Attribute> = mem::replace(attrs, Vec::new());
+        pending.reverse();
+        while let Some(attr) = pending.pop() {
+            if !attr.path.is_ident("cfg_attr") {
+                expanded.push(attr);
+                continue;
+            }
+            let (predicate, nested) = attr.parse_args_with(|input: ParseStream| {
+                let predicate: CfgExpr = input.parse()?;
+                input.parse::<Token![,]>()?;
+                let mut nested = Vec::new();
+                while !input.is_empty() {
+                    nested.push(parse_cfg_attr_body(&attr, input)?);
+                    if input.is_empty() {
+                        break;
+                    }
+                    input.parse::<Token![,]>()?;
+                }
+                Ok((predicate, nested))
+            })?;
+            if predicate.eval(self) {
+                pending.extend(nested.into_iter().rev());
+            }
+        }
+        *attrs = expanded;
+        Ok(())
+    }
+}
+
+// Parses one of the attributes after the predicate of a `cfg_attr`, which is
+// a path followed by tokens up to the next top-level comma.
+fn parse_cfg_attr_body(cfg_attr: &Attribute, input: ParseStream) -> Result<Attribute> {
+    let path = input.call(Path::parse_mod_style)?;
+    let mut tokens = TokenStream::new();
+    while !input.is_empty() && !input.peek(Token![,]) {
+        let tt: TokenTree = input.parse()?;
+        tokens.extend(Some(tt));
+    }
+    Ok(Attribute {
+        pound_token: Token![#](cfg_attr.pound_token.spans),
+        style: match &cfg_attr.style {
+            AttrStyle::Outer => AttrStyle::Outer,
+            AttrStyle::Inner(bang) => AttrStyle::Inner(Token![!](bang.spans)),
+        },
+        bracket_token: token::Bracket(cfg_attr.bracket_token.span),
+        path,
+        tokens,
+    })
+}
+
+/// A [`VisitMut`] pass that removes everything disabled by a `#[cfg]`
+/// attribute from a syntax tree.
+///
+/// This covers the places in which rustc evaluates `#[cfg]`: items, including
+/// those of impls, traits, foreign modules and modules, fields and enum
+/// variants, statements, match arms, function parameters, struct literal and
+/// pattern fields, and the elements of array, tuple and call expressions.
+/// On everything that remains, `cfg_attr` attributes are expanded and `cfg`
+/// attributes whose predicate held are removed.
+///
+/// Attributes that cannot be parsed leave their node in place and are
+/// reported by [`finish`].
+///
+/// [`finish`]: StripCfg::finish
+///
+/// ```
+/// use syn::cfg::{CfgSet, StripCfg};
+/// use syn::visit_mut::VisitMut;
+/// use syn::{parse_quote, File};
+///
+/// # fn main() -> syn::Result<()> {
+/// let mut file: File = parse_quote! {
+///     #[cfg(unix)]
+///     fn on_unix() {}
+///
+///     #[cfg(windows)]
+///     fn on_windows() {}
+///
+///     #[cfg_attr(unix, derive(Debug))]
+///     struct S;
+/// };
+///
+/// let mut cfgs = CfgSet::new();
+/// cfgs.insert("unix");
+///
+/// let mut strip = StripCfg::new(&cfgs);
+/// strip.visit_file_mut(&mut file);
+/// strip.finish()?;
+///
+/// let expected: File = parse_quote! {
+///     fn on_unix() {}
+///
+///     #[derive(Debug)]
+///     struct S;
+/// };
+/// assert_eq!(file, expected);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// *This type is available if Syn is built with the `"full"` and
+/// `"visit-mut"` features.*
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+pub struct StripCfg<'a> {
+    cfgs: &'a CfgSet,
+    errors: Vec<Error>,
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+impl<'a> StripCfg<'a> {
+    /// Creates a pass that keeps what is enabled by the given configuration
+    /// options.
+    pub fn new(cfgs: &'a CfgSet) -> Self {
+        StripCfg {
+            cfgs,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Reports the malformed `cfg` and `cfg_attr` attributes encountered by
+    /// the pass, combined into one error.
+    pub fn finish(self) -> Result<()> {
+        let mut errors = self.errors.into_iter();
+        let mut first = match errors.next() {
+            Some(first) => first,
+            None => return Ok(()),
+        };
+        for error in errors {
+            first.combine(error);
+        }
+        Err(first)
+    }
+
+    // Expands cfg_attr and evaluates cfg on the attributes of a node, leaving
+    // behind only the attributes that remain after expansion.
+    fn keep<T: HasAttrs>(&mut self, node: &mut T) -> bool {
+        let mut attrs = node.replace_attrs(Vec::new());
+        let keep = match self.process(&mut attrs) {
+            Ok(keep) => keep,
+            Err(error) => {
+                self.errors.push(error);
+                true
+            }
+        };
+        node.replace_attrs(attrs);
+        keep
+    }
+
+    fn process(&self, attrs: &mut Vec<Attribute>) -> Result<bool> {
+        self.cfgs.expand_cfg_attr(attrs)?;
+        if !self.cfgs.is_enabled(attrs)? {
+            return Ok(false);
+        }
+        attrs.retain(|attr| !attr.path.is_ident("cfg"));
+        Ok(true)
+    }
+
+    fn retain<T: HasAttrs>(&mut self, nodes: &mut Vec<T>) {
+        let mut kept = Vec::with_capacity(nodes.len());
+        for mut node in nodes.drain(..) {
+            if self.keep(&mut node) {
+                kept.push(node);
+            }
+        }
+        *nodes = kept;
+    }
+
+    fn retain_punctuated<T: HasAttrs, P>(&mut self, nodes: &mut Punctuated<T, P>) {
+        let trailing_punct = nodes.trailing_punct();
+        let mut kept = Vec::new();
+        for pair in mem::replace(nodes, Punctuated::new()).into_pairs() {
+            let (mut node, punct) = pair.into_tuple();
+            if self.keep(&mut node) {
+                kept.push((node, punct));
+            }
+        }
+        if !trailing_punct {
+            if let Some((_, punct)) = kept.last_mut() {
+                *punct = None;
+            }
+        }
+        nodes.extend(kept.into_iter().map(|(node, punct)| Pair::new(node, punct)));
+    }
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+impl<'a> VisitMut for StripCfg<'a> {
+    fn visit_file_mut(&mut self, node: &mut File) {
+        let mut attrs = mem::replace(&mut node.attrs, Vec::new());
+        match self.process(&mut attrs) {
+            Ok(true) => {}
+            Ok(false) => node.items.clear(),
+            Err(error) => self.errors.push(error),
+        }
+        node.attrs = attrs;
+        self.retain(&mut node.items);
+        visit_mut::visit_file_mut(self, node);
+    }
+
+    fn visit_item_mod_mut(&mut self, node: &mut ItemMod) {
+        if let Some((_, items)) = &mut node.content {
+            self.retain(items);
+        }
+        visit_mut::visit_item_mod_mut(self, node);
+    }
+
+    fn visit_item_foreign_mod_mut(&mut self, node: &mut ItemForeignMod) {
+        self.retain(&mut node.items);
+        visit_mut::visit_item_foreign_mod_mut(self, node);
+    }
+
+    fn visit_item_impl_mut(&mut self, node: &mut ItemImpl) {
+        self.retain(&mut node.items);
+        visit_mut::visit_item_impl_mut(self, node);
+    }
+
+    fn visit_item_trait_mut(&mut self, node: &mut ItemTrait) {
+        self.retain(&mut node.items);
+        visit_mut::visit_item_trait_mut(self, node);
+    }
+
+    fn visit_item_enum_mut(&mut self, node: &mut ItemEnum) {
+        self.retain_punctuated(&mut node.variants);
+        visit_mut::visit_item_enum_mut(self, node);
+    }
+
+    fn visit_fields_named_mut(&mut self, node: &mut FieldsNamed) {
+        self.retain_punctuated(&mut node.named);
+        visit_mut::visit_fields_named_mut(self, node);
+    }
+
+    fn visit_fields_unnamed_mut(&mut self, node: &mut FieldsUnnamed) {
+        self.retain_punctuated(&mut node.unnamed);
+        visit_mut::visit_fields_unnamed_mut(self, node);
+    }
+
+    fn visit_signature_mut(&mut self, node: &mut Signature) {
+        self.retain_punctuated(&mut node.inputs);
+        visit_mut::visit_signature_mut(self, node);
+    }
+
+    fn visit_block_mut(&mut self, node: &mut Block) {
+        self.retain(&mut node.stmts);
+        visit_mut::visit_block_mut(self, node);
+    }
+
+    fn visit_expr_match_mut(&mut self, node: &mut ExprMatch) {
+        self.retain(&mut node.arms);
+        visit_mut::visit_expr_match_mut(self, node);
+    }
+
+    fn visit_expr_struct_mut(&mut self, node: &mut ExprStruct) {
+        self.retain_punctuated(&mut node.fields);
+        visit_mut::visit_expr_struct_mut(self, node);
+    }
+
+    fn visit_pat_struct_mut(&mut self, node: &mut PatStruct) {
+        self.retain_punctuated(&mut node.fields);
+        visit_mut::visit_pat_struct_mut(self, node);
+    }
+
+    fn visit_expr_array_mut(&mut self, node: &mut ExprArray) {
+        self.retain_punctuated(&mut node.elems);
+        visit_mut::visit_expr_array_mut(self, node);
+    }
+
+    fn visit_expr_tuple_mut(&mut self, node: &mut ExprTuple) {
+        self.retain_punctuated(&mut node.elems);
+        visit_mut::visit_expr_tuple_mut(self, node);
+    }
+
+    fn visit_expr_call_mut(&mut self, node: &mut ExprCall) {
+        self.retain_punctuated(&mut node.args);
+        visit_mut::visit_expr_call_mut(self, node);
+    }
+
+    fn visit_expr_method_call_mut(&mut self, node: &mut ExprMethodCall) {
+        self.retain_punctuated(&mut node.args);
+        visit_mut::visit_expr_method_call_mut(self, node);
+    }
+}
+
+// Syntax tree nodes on which the pass evaluates cfg attributes.
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+trait HasAttrs {
+    fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute>;
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+impl HasAttrs for Expr {
+    fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute> {
+        Expr::replace_attrs(self, new)
+    }
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+impl HasAttrs for Item {
+    fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute> {
+        match self {
+            Item::Const(ItemConst { attrs, .. })
+            | Item::Enum(ItemEnum { attrs, .. })
+            | Item::ExternCrate(ItemExternCrate { attrs, .. })
+            | Item::Fn(ItemFn { attrs, .. })
+            | Item::ForeignMod(ItemForeignMod { attrs, .. })
+            | Item::Impl(ItemImpl { attrs, .. })
+            | Item::Macro(ItemMacro { attrs, .. })
+            | Item::Macro2(ItemMacro2 { attrs, .. })
+            | Item::Mod(ItemMod { attrs, .. })
+            | Item::Static(ItemStatic { attrs, .. })
+            | Item::Struct(ItemStruct { attrs, .. })
+            | Item::Trait(ItemTrait { attrs, .. })
+            | Item::TraitAlias(ItemTraitAlias { attrs, .. })
+            | Item::Type(ItemType { attrs, .. })
+            | Item::Union(ItemUnion { attrs, .. })
+            | Item::Use(ItemUse { attrs, .. }) => mem::replace(attrs, new),
+            Item::Verbatim(_) => Vec::new(),
+            Item::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+impl HasAttrs for ImplItem {
+    fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute> {
+        match self {
+            ImplItem::Const(ImplItemConst { attrs, .. })
+            | ImplItem::Method(ImplItemMethod { attrs, .. })
+            | ImplItem::Type(ImplItemType { attrs, .. })
+            | ImplItem::Macro(ImplItemMacro { attrs, .. }) => mem::replace(attrs, new),
+            ImplItem::Verbatim(_) => Vec::new(),
+            ImplItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+impl HasAttrs for TraitItem {
+    fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute> {
+        match self {
+            TraitItem::Const(TraitItemConst { attrs, .. })
+            | TraitItem::Method(TraitItemMethod { attrs, .. })
+            | TraitItem::Type(TraitItemType { attrs, .. })
+            | TraitItem::Macro(TraitItemMacro { attrs, .. }) => mem::replace(attrs, new),
+            TraitItem::Verbatim(_) => Vec::new(),
+            TraitItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+impl HasAttrs for ForeignItem {
+    fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute> {
+        match self {
+            ForeignItem::Fn(ForeignItemFn { attrs, .. })
+            | ForeignItem::Static(ForeignItemStatic { attrs, .. })
+            | ForeignItem::Type(ForeignItemType { attrs, .. })
+            | ForeignItem::Macro(ForeignItemMacro { attrs, .. }) => mem::replace(attrs, new),
+            ForeignItem::Verbatim(_) => Vec::new(),
+            ForeignItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+impl HasAttrs for Stmt {
+    fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute> {
+        match self {
+            Stmt::Local(local) => mem::replace(&mut local.attrs, new),
+            Stmt::Item(item) => item.replace_attrs(new),
+            Stmt::Expr(expr) | Stmt::Semi(expr, _) => expr.replace_attrs(new),
+        }
+    }
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+impl HasAttrs for FnArg {
+    fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute> {
+        match self {
+            FnArg::Receiver(receiver) => mem::replace(&mut receiver.attrs, new),
+            FnArg::Typed(pat) => mem::replace(&mut pat.attrs, new),
+        }
+    }
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+macro_rules! impl_has_attrs {
+    ($($ty:ident)*) => {
+        $(
+            impl HasAttrs for $ty {
+                fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute> {
+                    mem::replace(&mut self.attrs, new)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+impl_has_attrs!(Field Variant Arm FieldValue FieldPat);
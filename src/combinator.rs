@@ -0,0 +1,668 @@
+//! Composable parsers for custom syntax.
+//!
+//! The input of a function-like procedural macro is often a short sequence of
+//! tokens and syntax tree nodes, some of them optional or repeated, that
+//! would otherwise be parsed by a hand-written [`Parse`] impl. The functions
+//! in this module build such a parser out of smaller ones. Every parser built
+//! this way implements [`Parser`], so that it can be passed to
+//! [`Parser::parse2`], [`Parser::parse_str`] or
+//! [`Attribute::parse_args_with`], and [`Combinator`], which lets it be used
+//! as a part of a larger parser.
+//!
+//! [`Parse`]: crate::parse::Parse
+//! [`Attribute::parse_args_with`]: crate::Attribute::parse_args_with
+//!
+//! Any function or closure taking a `ParseStream` is a parser, for example
+//! `Type::parse` or `Punctuated::parse_terminated`. A tuple of parsers parses
+//! each of them in turn, giving a tuple of what each one parsed.
+//!
+//! # Example
+//!
+//! ```
+//! use syn::parse::combinator::{delimited, opt, separated, token};
+//! use syn::parse::{Parse, Parser};
+//! use syn::{token, Expr, Ident, Token, Type, Visibility};
+//!
+//! // $VISIBILITY static ref $NAME: $TYPE = $EXPR;
+//! let lazy_static = (
+//!     Visibility::parse,
+//!     token(Token![static]),
+//!     token(Token![ref]),
+//!     token(Ident),
+//!     token(Token![:]),
+//!     Type::parse,
+//!     (token(Token![=]), Expr::parse),
+//!     token(Token![;]),
+//! );
+//!
+//! let input = "pub static ref USERNAME: Regex = Regex::new(\"^[a-z0-9_-]{3,16}$\").unwrap();";
+//! let (_vis, _, _, name, _, _ty, (_, _init), _) = lazy_static.parse_str(input)?;
+//! assert_eq!(name, "USERNAME");
+//!
+//! // { $NAME $(: $TYPE)?, ... }
+//! let fields = delimited(
+//!     token::Brace,
+//!     separated((token(Ident), opt((token(Token![:]), Type::parse))), Token![,]),
+//! );
+//!
+//! let (_braces, fields) = fields.parse_str("{ a: u8, b, c: String, }")?;
+//! assert_eq!(fields.len(), 3);
+//! assert!(fields[1].1.is_none());
+//! # Ok::<(), syn::Error>(())
+//! ```
+//!
+//! # Alternatives
+//!
+//! The [`alt`] parser parses the first of several alternatives that matches
+//! the input. An alternative that starts with a token, like one made by
+//! [`token`], [`delimited`] or [`when`], is chosen by looking at the next
+//! token only, in the manner of [`Lookahead1`]. If none of them matches, the
+//! error lists the tokens that each of them expected:
+//!
+//! [`Lookahead1`]: crate::parse::Lookahead1
+//!
+//! ```
+//! use syn::parse::combinator::{alt, map, token};
+//! use syn::parse::Parser;
+//! use syn::{Ident, LitInt, LitStr, Token};
+//!
+//! enum Value {
+//!     Int(LitInt),
+//!     Str(LitStr),
+//!     Ident(Ident),
+//! }
+//!
+//! let value = alt((
+//!     map(token(LitInt), Value::Int),
+//!     map(token(LitStr), Value::Str),
+//!     map(token(Ident), Value::Ident),
+//! ));
+//!
+//! let err = value.parse_str("-1").err().unwrap();
+//! assert_eq!(
+//!     err.to_string(),
+//!     "expected one of: integer literal, string literal, identifier",
+//! );
+//! ```
+//!
+//! Other alternatives are attempted on a fork of the input, in order. An
+//! alternative that fails after parsing part of the input, like a parser of
+//! `Expr` given `1 +`, is taken to be the one that was meant, and its error
+//! is reported. The same holds for [`opt`], [`many0`] and [`separated`],
+//! which stop repeating at the first element that fails without consuming
+//! any input but fail along with an element that consumed some.
+//!
+//! *This module is available if Syn is built with the `"parsing"` feature.*
+
+use proc_macro2::{Span, TokenStream};
+
+use crate::error::{Error, Result};
+use crate::group;
+use crate::parse::discouraged::Speculative;
+use crate::parse::{self, Lookahead1, Parse, ParseBuffer, ParseStream, Parser, Peek};
+use crate::punctuated::Punctuated;
+use crate::sealed::combinator::Sealed;
+use crate::token;
+
+/// A parser that can be used as a part of a larger parser.
+///
+/// Unlike [`Parser`], which consumes the parser, this trait parses by
+/// reference so that the same parser can be applied any number of times, as
+/// in [`many0`].
+///
+/// The trait is implemented for all functions and closures of the form
+/// `Fn(ParseStream) -> Result<T>`, for tuples of up to 8 parsers, and for the
+/// parsers returned by the functions of this module.
+pub trait Combinator {
+    type Output;
+
+    /// Parses from the given parse stream.
+    ///
+    /// Unlike [`Parser::parse2`], this does not require the input to be fully
+    /// parsed.
+    fn parse_stream(&self, input: ParseStream) -> Result<Self::Output>;
+
+    // Not public API.
+    #[doc(hidden)]
+    fn __peek(&self, lookahead: &Lookahead1) -> Option<bool> {
+        let _ = lookahead;
+        None
+    }
+}
+
+impl<F, T> Combinator for F
+where
+    F: Fn(ParseStream) -> Result<T>,
+{
+    type Output = T;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<T> {
+        self(input)
+    }
+}
+
+macro_rules! impl_parser {
+    ($name:ident<$($param:ident),*>) => {
+        impl<$($param),*> Parser for $name<$($param),*>
+        where
+            Self: Combinator,
+        {
+            type Output = <Self as Combinator>::Output;
+
+            fn parse2(self, tokens: TokenStream) -> Result<Self::Output> {
+                Parser::parse2(|input: ParseStream| self.parse_stream(input), tokens)
+            }
+
+            #[doc(hidden)]
+            fn __parse_scoped(self, scope: Span, tokens: TokenStream) -> Result<Self::Output> {
+                let parser = |input: ParseStream| self.parse_stream(input);
+                parse::parse_scoped(parser, scope, tokens)
+            }
+
+            #[doc(hidden)]
+            fn __parse_stream(self, input: ParseStream) -> Result<Self::Output> {
+                self.parse_stream(input)
+            }
+        }
+    };
+}
+
+// Parses with the parser if it matches the input, returning None without
+// consuming any input if it does not.
+fn attempt<P: Combinator + ?Sized>(parser: &P, input: ParseStream) -> Result<Option<P::Output>> {
+    match parser.__peek(&input.lookahead1()) {
+        Some(true) => return parser.parse_stream(input).map(Some),
+        Some(false) => return Ok(None),
+        None => {}
+    }
+    let fork = input.fork();
+    match parser.parse_stream(&fork) {
+        Ok(output) => {
+            input.advance_to(&fork);
+            Ok(Some(output))
+        }
+        Err(_) if fork.cursor() == input.cursor() => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Parses a single token, like `Token![struct]`, or any other syntax tree
+/// node that can be [peeked], like `Ident`, `Lifetime` or `LitStr`.
+///
+/// [peeked]: crate::parse::ParseBuffer::peek
+pub fn token<T>(token: T) -> ParseToken<T>
+where
+    T: Peek,
+    T::Token: Parse,
+{
+    ParseToken { token }
+}
+
+/// Parser returned by [`token`].
+#[derive(Clone, Copy)]
+pub struct ParseToken<T> {
+    token: T,
+}
+
+impl<T> Combinator for ParseToken<T>
+where
+    T: Peek,
+    T::Token: Parse,
+{
+    type Output = T::Token;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<Self::Output> {
+        input.parse()
+    }
+
+    fn __peek(&self, lookahead: &Lookahead1) -> Option<bool> {
+        Some(lookahead.peek(self.token))
+    }
+}
+
+impl_parser!(ParseToken<T>);
+
+/// Parses with `parser` if the next token is `peek`.
+///
+/// This lets [`alt`], [`opt`] and the other parsers of this module decide
+/// whether to use a parser of a syntax tree node by looking at its first
+/// token, instead of attempting to parse the node.
+///
+/// ```
+/// # use syn::parse::combinator::when;
+/// # use syn::parse::Parse;
+/// # use syn::{Token, Type};
+/// #
+/// // A type parsed only if it starts with `&`.
+/// let reference = when(Token![&], Type::parse);
+/// # let _ = reference;
+/// ```
+pub fn when<T: Peek, P: Combinator>(peek: T, parser: P) -> When<T, P> {
+    When { peek, parser }
+}
+
+/// Parser returned by [`when`].
+#[derive(Clone, Copy)]
+pub struct When<T, P> {
+    peek: T,
+    parser: P,
+}
+
+impl<T: Peek, P: Combinator> Combinator for When<T, P> {
+    type Output = P::Output;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<Self::Output> {
+        self.parser.parse_stream(input)
+    }
+
+    fn __peek(&self, lookahead: &Lookahead1) -> Option<bool> {
+        Some(lookahead.peek(self.peek))
+    }
+}
+
+impl_parser!(When<T, P>);
+
+/// Parses with `parser` if it matches the input, or otherwise parses nothing
+/// and gives `None`.
+pub fn opt<P: Combinator>(parser: P) -> Opt<P> {
+    Opt { parser }
+}
+
+/// Parser returned by [`opt`].
+#[derive(Clone, Copy)]
+pub struct Opt<P> {
+    parser: P,
+}
+
+impl<P: Combinator> Combinator for Opt<P> {
+    type Output = Option<P::Output>;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<Self::Output> {
+        attempt(&self.parser, input)
+    }
+}
+
+impl_parser!(Opt<P>);
+
+/// Parses with `parser` as many times as it matches the input, zero or more.
+pub fn many0<P: Combinator>(parser: P) -> Many0<P> {
+    Many0 { parser }
+}
+
+/// Parser returned by [`many0`].
+#[derive(Clone, Copy)]
+pub struct Many0<P> {
+    parser: P,
+}
+
+impl<P: Combinator> Combinator for Many0<P> {
+    type Output = Vec<P::Output>;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<Self::Output> {
+        let mut outputs = Vec::new();
+        loop {
+            let cursor = input.cursor();
+            match attempt(&self.parser, input)? {
+                Some(output) => outputs.push(output),
+                None => break,
+            }
+            // A parser that matches without consuming anything would match
+            // again forever.
+            if input.cursor() == cursor {
+                break;
+            }
+        }
+        Ok(outputs)
+    }
+}
+
+impl_parser!(Many0<P>);
+
+/// Parses with `parser` repeatedly until the next token is `end` or the input
+/// is empty.
+///
+/// The `end` token itself is not consumed.
+///
+/// ```
+/// # use syn::parse::combinator::{many_until, token};
+/// # use syn::parse::Parser;
+/// # use syn::{Ident, Token};
+/// #
+/// let (idents, _) = (many_until(token(Ident), Token![;]), token(Token![;]))
+///     .parse_str("a b c;")?;
+/// assert_eq!(idents.len(), 3);
+/// # Ok::<(), syn::Error>(())
+/// ```
+pub fn many_until<P: Combinator, T: Peek>(parser: P, end: T) -> ManyUntil<P, T> {
+    ManyUntil { parser, end }
+}
+
+/// Parser returned by [`many_until`].
+#[derive(Clone, Copy)]
+pub struct ManyUntil<P, T> {
+    parser: P,
+    end: T,
+}
+
+impl<P: Combinator, T: Peek> Combinator for ManyUntil<P, T> {
+    type Output = Vec<P::Output>;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<Self::Output> {
+        let mut outputs = Vec::new();
+        while !input.is_empty() && !input.peek(self.end) {
+            outputs.push(self.parser.parse_stream(input)?);
+        }
+        Ok(outputs)
+    }
+}
+
+impl_parser!(ManyUntil<P, T>);
+
+/// Parses zero or more values with `parser`, separated by the punctuation
+/// `separator` and optionally followed by a trailing one.
+///
+/// Parsing stops after a value that is not followed by the separator, or
+/// after a separator that is not followed by a value.
+pub fn separated<P, T>(parser: P, separator: T) -> Separated<P, T>
+where
+    P: Combinator,
+    T: Peek,
+    T::Token: Parse,
+{
+    Separated { parser, separator }
+}
+
+/// Parser returned by [`separated`].
+#[derive(Clone, Copy)]
+pub struct Separated<P, T> {
+    parser: P,
+    separator: T,
+}
+
+impl<P, T> Combinator for Separated<P, T>
+where
+    P: Combinator,
+    T: Peek,
+    T::Token: Parse,
+{
+    type Output = Punctuated<P::Output, T::Token>;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<Self::Output> {
+        let mut punctuated = Punctuated::new();
+        loop {
+            match attempt(&self.parser, input)? {
+                Some(value) => punctuated.push_value(value),
+                None => break,
+            }
+            if !input.peek(self.separator) {
+                break;
+            }
+            punctuated.push_punct(input.parse()?);
+        }
+        Ok(punctuated)
+    }
+}
+
+impl_parser!(Separated<P, T>);
+
+/// Parses a group delimited by parentheses, curly braces or square brackets,
+/// and its content with `parser`.
+///
+/// The content must be fully parsed. The delimiter is one of `token::Paren`,
+/// `token::Brace` or `token::Bracket`.
+pub fn delimited<T, P>(delimiter: T, parser: P) -> Delimited<T, P>
+where
+    T: Peek,
+    T::Token: Delimiter,
+    P: Combinator,
+{
+    Delimited { delimiter, parser }
+}
+
+/// Parser returned by [`delimited`].
+#[derive(Clone, Copy)]
+pub struct Delimited<T, P> {
+    delimiter: T,
+    parser: P,
+}
+
+impl<T, P> Combinator for Delimited<T, P>
+where
+    T: Peek,
+    T::Token: Delimiter,
+    P: Combinator,
+{
+    type Output = (T::Token, P::Output);
+
+    fn parse_stream(&self, input: ParseStream) -> Result<Self::Output> {
+        let (delimiter, content) = T::Token::__parse_group(input)?;
+        let output = self.parser.parse_stream(&content)?;
+        if content.is_empty() {
+            Ok((delimiter, output))
+        } else {
+            Err(content.error("unexpected token"))
+        }
+    }
+
+    fn __peek(&self, lookahead: &Lookahead1) -> Option<bool> {
+        Some(lookahead.peek(self.delimiter))
+    }
+}
+
+impl_parser!(Delimited<T, P>);
+
+/// The delimiters of a group that can be parsed by [`delimited`] or peeked by
+/// [`peek_group`].
+///
+/// [`peek_group`]: crate::parse::peek_group
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+pub trait Delimiter: Sealed + Sized {
+    // Not public API.
+    #[doc(hidden)]
+    fn __parse_group<'a>(input: &ParseBuffer<'a>) -> Result<(Self, ParseBuffer<'a>)>;
+
+    // Not public API.
+    #[doc(hidden)]
+    fn __delimiter() -> proc_macro2::Delimiter;
+}
+
+macro_rules! delimiter {
+    ($($ty:ident $parse:ident $delimiter:ident)*) => {
+        $(
+            impl Sealed for token::$ty {}
+
+            impl Delimiter for token::$ty {
+                fn __parse_group<'a>(input: &ParseBuffer<'a>) -> Result<(Self, ParseBuffer<'a>)> {
+                    group::$parse(input).map(|group| (group.token, group.content))
+                }
+
+                fn __delimiter() -> proc_macro2::Delimiter {
+                    proc_macro2::Delimiter::$delimiter
+                }
+            }
+        )*
+    };
+}
+
+delimiter! {
+    Paren parse_parens Parenthesis
+    Brace parse_braces Brace
+    Bracket parse_brackets Bracket
+}
+
+/// Parses with `parser` and transforms its output with `f`.
+pub fn map<P, F, T>(parser: P, f: F) -> Map<P, F>
+where
+    P: Combinator,
+    F: Fn(P::Output) -> T,
+{
+    Map { parser, f }
+}
+
+/// Parser returned by [`map`].
+#[derive(Clone, Copy)]
+pub struct Map<P, F> {
+    parser: P,
+    f: F,
+}
+
+impl<P, F, T> Combinator for Map<P, F>
+where
+    P: Combinator,
+    F: Fn(P::Output) -> T,
+{
+    type Output = T;
+
+    fn parse_stream(&self, input: ParseStream) -> Result<T> {
+        self.parser.parse_stream(input).map(&self.f)
+    }
+
+    fn __peek(&self, lookahead: &Lookahead1) -> Option<bool> {
+        self.parser.__peek(lookahead)
+    }
+}
+
+impl_parser!(Map<P, F>);
+
+/// Parses the first of a tuple of alternatives that matches the input.
+///
+/// All of the alternatives must give the same output, which [`map`] can help
+/// with. Refer to the [module documentation][self#alternatives] for how the
+/// alternative is chosen.
+pub fn alt<T>(alternatives: T) -> Alt<T>
+where
+    Alt<T>: Combinator,
+{
+    Alt { alternatives }
+}
+
+/// Parser returned by [`alt`].
+#[derive(Clone, Copy)]
+pub struct Alt<T> {
+    alternatives: T,
+}
+
+impl_parser!(Alt<T>);
+
+// The alternatives of an `alt` that have been tried so far.
+struct AltState<'a> {
+    input: ParseStream<'a>,
+    lookahead: Lookahead1<'a>,
+    // Whether any alternative has been peeked, and so is named by the error
+    // of the lookahead.
+    peeked: bool,
+    // The error of the first alternative that failed without consuming any
+    // input.
+    error: Option<Error>,
+}
+
+impl<'a> AltState<'a> {
+    fn new(input: ParseStream<'a>) -> Self {
+        AltState {
+            input,
+            lookahead: input.lookahead1(),
+            peeked: false,
+            error: None,
+        }
+    }
+
+    // Tries an alternative, giving the result of the `alt` if the alternative
+    // is the one chosen.
+    fn alternative<P: Combinator>(&mut self, parser: &P) -> Option<Result<P::Output>> {
+        match parser.__peek(&self.lookahead) {
+            Some(true) => return Some(parser.parse_stream(self.input)),
+            Some(false) => {
+                self.peeked = true;
+                return None;
+            }
+            None => {}
+        }
+        let fork = self.input.fork();
+        match parser.parse_stream(&fork) {
+            Ok(output) => {
+                self.input.advance_to(&fork);
+                Some(Ok(output))
+            }
+            Err(err) if fork.cursor() == self.input.cursor() => {
+                self.error = self.error.take().or(Some(err));
+                None
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    fn error(self) -> Error {
+        match self.error {
+            Some(err) if !self.peeked => err,
+            _ => self.lookahead.error(),
+        }
+    }
+}
+
+macro_rules! tuple {
+    ($first:ident $($rest:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<$first: Combinator, $($rest: Combinator),*> Combinator for ($first, $($rest,)*) {
+            type Output = ($first::Output, $($rest::Output,)*);
+
+            fn parse_stream(&self, input: ParseStream) -> Result<Self::Output> {
+                let ($first, $($rest,)*) = self;
+                Ok(($first.parse_stream(input)?, $($rest.parse_stream(input)?,)*))
+            }
+
+            fn __peek(&self, lookahead: &Lookahead1) -> Option<bool> {
+                self.0.__peek(lookahead)
+            }
+        }
+
+        impl<$first: Combinator, $($rest: Combinator),*> Parser for ($first, $($rest,)*) {
+            type Output = <Self as Combinator>::Output;
+
+            fn parse2(self, tokens: TokenStream) -> Result<Self::Output> {
+                Parser::parse2(|input: ParseStream| self.parse_stream(input), tokens)
+            }
+
+            #[doc(hidden)]
+            fn __parse_scoped(self, scope: Span, tokens: TokenStream) -> Result<Self::Output> {
+                let parser = |input: ParseStream| self.parse_stream(input);
+                parse::parse_scoped(parser, scope, tokens)
+            }
+
+            #[doc(hidden)]
+            fn __parse_stream(self, input: ParseStream) -> Result<Self::Output> {
+                self.parse_stream(input)
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$first: Combinator, $($rest: Combinator<Output = $first::Output>),*> Combinator
+            for Alt<($first, $($rest,)*)>
+        {
+            type Output = $first::Output;
+
+            fn parse_stream(&self, input: ParseStream) -> Result<Self::Output> {
+                let ($first, $($rest,)*) = &self.alternatives;
+                let mut state = AltState::new(input);
+                if let Some(result) = state.alternative($first) {
+                    return result;
+                }
+                $(
+                    if let Some(result) = state.alternative($rest) {
+                        return result;
+                    }
+                )*
+                Err(state.error())
+            }
+        }
+    };
+}
+
+tuple!(A);
+tuple!(A B);
+tuple!(A B C);
+tuple!(A B C D);
+tuple!(A B C D E);
+tuple!(A B C D E F);
+tuple!(A B C D E F G);
+tuple!(A B C D E F G H);
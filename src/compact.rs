@@ -0,0 +1,391 @@
+//! Compact debug representation of syntax trees.
+//!
+//! The `Debug` impls of the `"extra-traits"` feature print every field of
+//! every node, down to the tokens and spans, which makes them long enough
+//! that even a small function takes thousands of lines. The [`debug`]
+//! function instead formats a syntax tree as an S-expression that contains
+//! only what distinguishes one tree from another:
+//!
+//! ```
+//! use syn::{compact, parse_quote, Expr};
+//!
+//! let expr: Expr = parse_quote!(x + f("y", 1));
+//! assert_eq!(
+//!     format!("{:?}", compact::debug(&expr)),
+//!     concat!(
+//!         "(ExprBinary :left (ExprPath :path x) :op Add :right (ExprCall",
+//!         r#" :func (ExprPath :path f) :args [(ExprLit :lit "y") (ExprLit :lit 1)]))"#,
+//!     ),
+//! );
+//! ```
+//!
+//! Every node is printed as its type followed by its fields, each preceded by
+//! the name of the field. Identifiers, lifetimes, literals and paths without
+//! generic arguments are printed inline as they appear in the source, and
+//! lists of nodes in square brackets. A variant of
+//! an enum that holds a single node of a struct type, like `Expr::Binary`, is
+//! printed as that node. Other variants are printed as a node named after the
+//! variant, or only the name if they hold nothing to print.
+//!
+//! The following are left out:
+//!
+//! - Tokens, other than those that are optional, like the `mut` in `&mut x`,
+//!   which are printed as their text.
+//! - Optional punctuation that follows from the other fields, like the comma
+//!   after an arm of a `match`.
+//! - Spans.
+//! - Fields that are absent or empty, like an empty list of attributes or
+//!   the generics of a function that has none.
+//! - Fields holding the default variant of an enum: `Visibility::Inherited`,
+//!   `PathArguments::None` and `ReturnType::Default`.
+//!
+//! The alternate form, `{:#?}`, spreads nodes that do not fit on one line
+//! over several lines, with each field on a line of its own.
+//!
+//! *This module is available if Syn is built with the `"derive"` or `"full"`
+//! feature.*
+
+use std::fmt::{self, Debug, Display};
+
+use proc_macro2::{Ident, Literal, TokenStream};
+
+use crate::punctuated::Punctuated;
+use crate::sealed::compact::Sealed;
+use crate::{Lifetime, Path, PathArguments};
+
+// Line width within which the alternate form prints a node on one line.
+const WIDTH: usize = 80;
+
+/// Formats a syntax tree in the compact representation of this module.
+///
+/// The return value implements `Debug`, for use with `{:?}` and `{:#?}`.
+pub fn debug<T: CompactDebug + ?Sized>(node: &T) -> Compact<'_, T> {
+    Compact { node }
+}
+
+/// A syntax tree to be formatted in compact form.
+///
+/// Created by [`debug`].
+pub struct Compact<'a, T: ?Sized> {
+    node: &'a T,
+}
+
+impl<T: CompactDebug + ?Sized> Debug for Compact<'_, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let sexpr = self.node.sexpr();
+        let mut out = String::new();
+        if formatter.alternate() {
+            sexpr.write_pretty(&mut out, 0);
+        } else {
+            sexpr.write_flat(&mut out);
+        }
+        formatter.write_str(&out)
+    }
+}
+
+/// Syntax tree types that can be formatted by [`debug`].
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+pub trait CompactDebug: Sealed {
+    // Not public API.
+    #[doc(hidden)]
+    fn sexpr(&self) -> Sexpr;
+}
+
+// Not public API.
+#[doc(hidden)]
+pub struct Sexpr {
+    repr: Repr,
+    // Whether this is left out when it is the value of a field.
+    default: bool,
+}
+
+enum Repr {
+    Atom(String),
+    Node(&'static str, Vec<(Option<&'static str>, Sexpr)>),
+    List(Vec<Sexpr>),
+}
+
+impl Sexpr {
+    pub fn atom<T: Display>(value: T) -> Self {
+        Sexpr {
+            repr: Repr::Atom(value.to_string()),
+            default: false,
+        }
+    }
+
+    pub fn node(name: &'static str) -> Self {
+        Sexpr {
+            repr: Repr::Node(name, Vec::new()),
+            default: false,
+        }
+    }
+
+    pub fn list<I: IntoIterator<Item = Sexpr>>(elements: I) -> Self {
+        let elements: Vec<Sexpr> = elements.into_iter().collect();
+        Sexpr {
+            default: elements.is_empty(),
+            repr: Repr::List(elements),
+        }
+    }
+
+    pub fn none() -> Self {
+        Sexpr::atom("None").into_default()
+    }
+
+    // An optional token, printed as its text if present.
+    pub fn flag(present: bool, text: &'static str) -> Self {
+        if present {
+            Sexpr::atom(text)
+        } else {
+            Sexpr::none()
+        }
+    }
+
+    // The printed elements of a tuple, as a single value.
+    pub fn tuple(elements: Vec<Sexpr>) -> Self {
+        let mut elements: Vec<Sexpr> = elements.into_iter().filter(|e| !e.default).collect();
+        if elements.len() == 1 {
+            elements.pop().unwrap()
+        } else {
+            Sexpr::list(elements)
+        }
+    }
+
+    pub fn into_default(mut self) -> Self {
+        self.default = true;
+        self
+    }
+
+    pub fn default_if_empty(mut self) -> Self {
+        if let Repr::Node(_, fields) = &self.repr {
+            self.default = fields.is_empty();
+        }
+        self
+    }
+
+    pub fn field(&mut self, name: &'static str, value: Sexpr) {
+        self.push(Some(name), value);
+    }
+
+    pub fn arg(&mut self, value: Sexpr) {
+        self.push(None, value);
+    }
+
+    fn push(&mut self, name: Option<&'static str>, value: Sexpr) {
+        if value.default {
+            return;
+        }
+        match &mut self.repr {
+            Repr::Node(_, fields) => fields.push((name, value)),
+            Repr::Atom(_) | Repr::List(_) => unreachable!(),
+        }
+    }
+
+    fn write_flat(&self, out: &mut String) {
+        match &self.repr {
+            Repr::Atom(atom) => out.push_str(atom),
+            Repr::Node(name, fields) => {
+                out.push('(');
+                out.push_str(name);
+                for (field, value) in fields {
+                    out.push(' ');
+                    if let Some(field) = field {
+                        out.push(':');
+                        out.push_str(field);
+                        out.push(' ');
+                    }
+                    value.write_flat(out);
+                }
+                out.push(')');
+            }
+            Repr::List(elements) => {
+                out.push('[');
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        out.push(' ');
+                    }
+                    element.write_flat(out);
+                }
+                out.push(']');
+            }
+        }
+    }
+
+    // Whether the flat form takes no more than `width` characters, which is
+    // reduced by the number of characters taken.
+    fn fits(&self, width: &mut usize) -> bool {
+        match &self.repr {
+            Repr::Atom(atom) => take(width, atom.len()),
+            Repr::Node(name, fields) => {
+                take(width, name.len() + 2)
+                    && fields.iter().all(|(field, value)| {
+                        take(width, field.map_or(1, |field| field.len() + 3)) && value.fits(width)
+                    })
+            }
+            Repr::List(elements) => {
+                take(width, elements.len().max(1) + 1)
+                    && elements.iter().all(|element| element.fits(width))
+            }
+        }
+    }
+
+    fn write_pretty(&self, out: &mut String, indent: usize) {
+        let column = out.len() - out.rfind('\n').map_or(0, |i| i + 1);
+        if self.fits(&mut WIDTH.saturating_sub(column)) {
+            self.write_flat(out);
+            return;
+        }
+        match &self.repr {
+            Repr::Atom(atom) => out.push_str(atom),
+            Repr::Node(name, fields) => {
+                out.push('(');
+                out.push_str(name);
+                for (field, value) in fields {
+                    newline(out, indent + 2);
+                    if let Some(field) = field {
+                        out.push(':');
+                        out.push_str(field);
+                        out.push(' ');
+                    }
+                    value.write_pretty(out, indent + 2);
+                }
+                out.push(')');
+            }
+            Repr::List(elements) => {
+                out.push('[');
+                for element in elements {
+                    newline(out, indent + 2);
+                    element.write_pretty(out, indent + 2);
+                }
+                newline(out, indent);
+                out.push(']');
+            }
+        }
+    }
+}
+
+fn take(width: &mut usize, len: usize) -> bool {
+    if len > *width {
+        false
+    } else {
+        *width -= len;
+        true
+    }
+}
+
+fn newline(out: &mut String, indent: usize) {
+    out.push('\n');
+    out.extend((0..indent).map(|_| ' '));
+}
+
+impl<T: CompactDebug + ?Sized> Sealed for Box<T> {}
+
+impl<T: CompactDebug + ?Sized> CompactDebug for Box<T> {
+    fn sexpr(&self) -> Sexpr {
+        T::sexpr(self)
+    }
+}
+
+impl<T: CompactDebug> Sealed for Option<T> {}
+
+impl<T: CompactDebug> CompactDebug for Option<T> {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            Some(node) => node.sexpr(),
+            None => Sexpr::none(),
+        }
+    }
+}
+
+impl<T: CompactDebug> Sealed for Vec<T> {}
+
+impl<T: CompactDebug> CompactDebug for Vec<T> {
+    fn sexpr(&self) -> Sexpr {
+        Sexpr::list(self.iter().map(CompactDebug::sexpr))
+    }
+}
+
+impl<T: CompactDebug, P> Sealed for Punctuated<T, P> {}
+
+impl<T: CompactDebug, P> CompactDebug for Punctuated<T, P> {
+    fn sexpr(&self) -> Sexpr {
+        Sexpr::list(self.iter().map(CompactDebug::sexpr))
+    }
+}
+
+macro_rules! atom {
+    ($($ty:ty)*) => {
+        $(
+            impl Sealed for $ty {}
+
+            impl CompactDebug for $ty {
+                fn sexpr(&self) -> Sexpr {
+                    Sexpr::atom(self)
+                }
+            }
+        )*
+    };
+}
+
+atom!(Ident Literal bool u32 usize);
+
+impl Sealed for String {}
+
+impl CompactDebug for String {
+    fn sexpr(&self) -> Sexpr {
+        Sexpr::atom(format_args!("{:?}", self))
+    }
+}
+
+impl Sealed for TokenStream {}
+
+impl CompactDebug for TokenStream {
+    fn sexpr(&self) -> Sexpr {
+        let sexpr = Sexpr::atom(format_args!("`{}`", self));
+        if self.is_empty() {
+            sexpr.into_default()
+        } else {
+            sexpr
+        }
+    }
+}
+
+impl Sealed for Lifetime {}
+
+impl CompactDebug for Lifetime {
+    fn sexpr(&self) -> Sexpr {
+        Sexpr::atom(self)
+    }
+}
+
+impl Sealed for Path {}
+
+// A path without generic arguments is printed inline, like `std::mem`.
+impl CompactDebug for Path {
+    fn sexpr(&self) -> Sexpr {
+        let mut inline = String::new();
+        if self.leading_colon.is_some() {
+            inline.push_str("::");
+        }
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment.arguments {
+                PathArguments::None => {}
+                PathArguments::AngleBracketed(_) | PathArguments::Parenthesized(_) => {
+                    let mut node = Sexpr::node("Path");
+                    node.field(
+                        "leading_colon",
+                        Sexpr::flag(self.leading_colon.is_some(), "::"),
+                    );
+                    node.field("segments", self.segments.sexpr());
+                    return node;
+                }
+            }
+            if i > 0 {
+                inline.push_str("::");
+            }
+            inline.push_str(&segment.ident.to_string());
+        }
+        Sexpr::atom(inline)
+    }
+}
@@ -119,6 +119,12 @@ macro_rules! impl_parse_for_custom_punctuation {
             fn display() -> &'static $crate::export::str {
                 custom_punctuation_concat!("`", stringify_punct!($($tt)+), "`")
             }
+
+            fn skip(
+                cursor: $crate::buffer::Cursor,
+            ) -> $crate::export::Option<$crate::buffer::Cursor> {
+                $crate::token::parsing::skip_punct(cursor, stringify_punct!($($tt)+))
+            }
         }
 
         impl $crate::parse::Parse for $ident {
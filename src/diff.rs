@@ -0,0 +1,275 @@
+//! Structural comparison of two syntax trees.
+//!
+//! The [`diff`] function walks two trees of the same type side by side and
+//! reports every place where they differ as a [`Change`]. Each change carries
+//! the path of [`Step`]s from the root of the trees to the differing node,
+//! along with the old and new version of that node as a [`NodeRef`].
+//!
+//! Nodes are compared using the `PartialEq` impls of the `"extra-traits"`
+//! feature, so spans and the exact delimiters and punctuation used are not
+//! taken into account. Rather than reporting a change for every ancestor of
+//! a node that was modified, the comparison descends as far as possible and
+//! reports the innermost node that contains the whole difference: changing
+//! the literal in `x + 1` to `x + 2` gives a single change whose path ends in
+//! the literal.
+//!
+//! Lists of nodes, like the items of a file or the statements of a block, are
+//! aligned on the elements that are equal on both sides. In between, elements
+//! are paired up in order and reported as modified; any left over are
+//! reported as removed or added.
+//!
+//! ```
+//! use syn::diff::{self, Change};
+//! use syn::{parse_quote, File};
+//!
+//! let old: File = parse_quote! {
+//!     fn f() -> u8 { 1 }
+//!     fn g() {}
+//! };
+//! let new: File = parse_quote! {
+//!     fn f() -> u8 { 2 }
+//!     fn g() {}
+//!     fn h() {}
+//! };
+//!
+//! let changes = diff::diff(&old, &new);
+//! assert_eq!(changes.len(), 2);
+//!
+//! let path = |change: &Change| {
+//!     change.path().iter().map(ToString::to_string).collect::<String>()
+//! };
+//! assert_eq!(path(&changes[0]), ".items[0].block.stmts[0].lit");
+//! assert_eq!(path(&changes[1]), ".items[2]");
+//!
+//! match &changes[1] {
+//!     Change::Added { new, .. } => assert_eq!(new.kind(), "Item"),
+//!     _ => unreachable!(),
+//! }
+//! ```
+//!
+//! *This module is available if Syn is built with the `"visit"` and
+//! `"extra-traits"` features.*
+//!
+//! [`NodeRef`]: crate::node::NodeRef
+
+use std::cmp;
+use std::fmt::{self, Display};
+
+use crate::node::NodeRef;
+use crate::sealed::diff::Sealed;
+
+/// Computes the changes that turn `old` into `new`, in the order of the
+/// nodes in the trees.
+///
+/// An empty list means the two trees are equal.
+pub fn diff<'a, T: Diff>(old: &'a T, new: &'a T) -> Vec<Change<'a>> {
+    let mut differ = Differ {
+        path: Vec::new(),
+        changes: Vec::new(),
+    };
+    differ.node(old, new);
+    differ.changes
+}
+
+/// One difference between two syntax trees.
+#[derive(Clone, Debug)]
+pub enum Change<'a> {
+    /// A node that is present only in the new tree.
+    Added { path: Vec<Step>, new: NodeRef<'a> },
+
+    /// A node that is present only in the old tree.
+    Removed { path: Vec<Step>, old: NodeRef<'a> },
+
+    /// A node that is present in both trees but differs between them.
+    Modified {
+        path: Vec<Step>,
+        old: NodeRef<'a>,
+        new: NodeRef<'a>,
+    },
+}
+
+impl<'a> Change<'a> {
+    /// The path from the root of the trees to the node that changed.
+    ///
+    /// Indices refer to positions in the new tree, except for the last index
+    /// in the path of a removed node which refers to its position in the old
+    /// tree.
+    pub fn path(&self) -> &[Step] {
+        match self {
+            Change::Added { path, .. }
+            | Change::Removed { path, .. }
+            | Change::Modified { path, .. } => path,
+        }
+    }
+}
+
+/// One step in the path from the root of a syntax tree to one of its nodes.
+///
+/// Steps are displayed like `.field` and `[index]`, so a path can be printed
+/// by concatenating its steps, for example `.items[0].block.stmts[2]`.
+/// Choosing a variant of an enum does not count as a step: the path to the
+/// `ExprCall` inside of an `Expr` is the same as the path to the `Expr`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Step {
+    /// A named field of a struct, like `block`.
+    Field(&'static str),
+
+    /// An element of a list, like the items of a file or the fields of a
+    /// struct.
+    Index(usize),
+}
+
+impl Display for Step {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Step::Field(name) => write!(formatter, ".{}", name),
+            Step::Index(index) => write!(formatter, "[{}]", index),
+        }
+    }
+}
+
+/// Syntax tree types that can be compared by [`diff`].
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+pub trait Diff: PartialEq + Sealed {
+    // Not public API.
+    #[doc(hidden)]
+    fn node_ref(&self) -> NodeRef<'_>;
+
+    // Not public API. Called only for nodes that are not equal.
+    #[doc(hidden)]
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, differ: &mut Differ<'a>);
+}
+
+// Not public API.
+#[doc(hidden)]
+pub struct Differ<'a> {
+    path: Vec<Step>,
+    changes: Vec<Change<'a>>,
+}
+
+impl<'a> Differ<'a> {
+    pub fn node<T: Diff>(&mut self, old: &'a T, new: &'a T) {
+        if old != new {
+            T::diff_into(old, new, self);
+        }
+    }
+
+    pub fn option<T: Diff>(&mut self, old: Option<&'a T>, new: Option<&'a T>) {
+        match (old, new) {
+            (Some(old), Some(new)) => self.node(old, new),
+            (Some(old), None) => self.removed(old),
+            (None, Some(new)) => self.added(new),
+            (None, None) => {}
+        }
+    }
+
+    pub fn list<T, I>(&mut self, old: I, new: I)
+    where
+        T: Diff + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        let old: Vec<&T> = old.into_iter().collect();
+        let new: Vec<&T> = new.into_iter().collect();
+
+        // Common prefix and suffix do not need to go through the table.
+        let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+        let suffix = old[prefix..]
+            .iter()
+            .rev()
+            .zip(new[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let old_mid = &old[prefix..old.len() - suffix];
+        let new_mid = &new[prefix..new.len() - suffix];
+
+        // Longest common subsequence: lcs[i * width + j] is the length of the
+        // longest common subsequence of old_mid[i..] and new_mid[j..].
+        let width = new_mid.len() + 1;
+        let mut lcs = vec![0; (old_mid.len() + 1) * width];
+        for i in (0..old_mid.len()).rev() {
+            for j in (0..new_mid.len()).rev() {
+                lcs[i * width + j] = if old_mid[i] == new_mid[j] {
+                    lcs[(i + 1) * width + j + 1] + 1
+                } else {
+                    cmp::max(lcs[(i + 1) * width + j], lcs[i * width + j + 1])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        let (mut gap_i, mut gap_j) = (0, 0);
+        while i < old_mid.len() && j < new_mid.len() {
+            if old_mid[i] == new_mid[j] {
+                self.gap(
+                    &old_mid[gap_i..i],
+                    prefix + gap_i,
+                    &new_mid[gap_j..j],
+                    prefix + gap_j,
+                );
+                i += 1;
+                j += 1;
+                gap_i = i;
+                gap_j = j;
+            } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        self.gap(
+            &old_mid[gap_i..],
+            prefix + gap_i,
+            &new_mid[gap_j..],
+            prefix + gap_j,
+        );
+    }
+
+    // Unmatched elements between two matching ones.
+    fn gap<T: Diff>(&mut self, old: &[&'a T], old_start: usize, new: &[&'a T], new_start: usize) {
+        let paired = cmp::min(old.len(), new.len());
+        for k in 0..paired {
+            self.path.push(Step::Index(new_start + k));
+            self.node(old[k], new[k]);
+            self.path.pop();
+        }
+        for (k, old) in old.iter().enumerate().skip(paired) {
+            self.path.push(Step::Index(old_start + k));
+            self.removed(*old);
+            self.path.pop();
+        }
+        for (k, new) in new.iter().enumerate().skip(paired) {
+            self.path.push(Step::Index(new_start + k));
+            self.added(*new);
+            self.path.pop();
+        }
+    }
+
+    pub fn field<F: FnOnce(&mut Self)>(&mut self, name: &'static str, f: F) {
+        self.path.push(Step::Field(name));
+        f(self);
+        self.path.pop();
+    }
+
+    pub fn added<T: Diff>(&mut self, new: &'a T) {
+        self.changes.push(Change::Added {
+            path: self.path.clone(),
+            new: new.node_ref(),
+        });
+    }
+
+    pub fn removed<T: Diff>(&mut self, old: &'a T) {
+        self.changes.push(Change::Removed {
+            path: self.path.clone(),
+            old: old.node_ref(),
+        });
+    }
+
+    pub fn modified<T: Diff>(&mut self, old: &'a T, new: &'a T) {
+        self.changes.push(Change::Modified {
+            path: self.path.clone(),
+            old: old.node_ref(),
+            new: new.node_ref(),
+        });
+    }
+}
@@ -0,0 +1,268 @@
+//! Structural equivalence of syntax trees under configurable rules.
+//!
+//! The `PartialEq` and `Hash` impls of the `"extra-traits"` feature compare
+//! syntax trees exactly as they were written, other than their spans. An
+//! [`Equivalence`] compares them modulo differences that are irrelevant for a
+//! particular use: for example when memoizing the expansion of a macro, the
+//! input `(a + b)` may well be interchangeable with `a + b`.
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use syn::equiv::Equivalence;
+//! use syn::{parse_quote, Expr};
+//!
+//! let equiv = Equivalence::new()
+//!     .transparent_groups(true)
+//!     .ignore_trailing_punct(true);
+//!
+//! let a: Expr = parse_quote!(f((x + 1), y,));
+//! let b: Expr = parse_quote!(f(x + 1, y));
+//! assert!(a != b);
+//! assert!(equiv.equal(&a, &b));
+//!
+//! let mut memo = HashMap::new();
+//! memo.insert(equiv.key(a), "expanded");
+//! assert_eq!(memo.get(&equiv.key(b)), Some(&"expanded"));
+//! ```
+//!
+//! Spans are never taken into account.
+//!
+//! *This module is available if Syn is built with the `"extra-traits"`
+//! feature and either the `"derive"` or `"full"` feature.*
+
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use crate::punctuated::Punctuated;
+use crate::sealed::equiv::Sealed;
+use crate::Ident;
+
+/// Rules for deciding whether two syntax trees are equivalent.
+///
+/// `Equivalence::new()` and `Equivalence::default()` start out as strict as
+/// `PartialEq`, and each option relaxes the comparison further.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Equivalence {
+    pub(crate) transparent_groups: bool,
+    pub(crate) ignore_trailing_punct: bool,
+    pub(crate) ignore_attrs: bool,
+    pub(crate) unraw_idents: bool,
+}
+
+impl Equivalence {
+    /// The strictest equivalence, under which syntax trees are equivalent
+    /// exactly if they are equal according to `PartialEq`.
+    pub fn new() -> Self {
+        Equivalence::default()
+    }
+
+    /// Treats parenthesized and invisibly grouped expressions and types,
+    /// `Expr::Paren`, `Expr::Group`, `Type::Paren` and `Type::Group`, as
+    /// equivalent to their contents. A group with attributes is only
+    /// transparent if attributes are ignored.
+    pub fn transparent_groups(mut self, yes: bool) -> Self {
+        self.transparent_groups = yes;
+        self
+    }
+
+    /// Ignores whether a punctuated sequence has a trailing punctuation,
+    /// like the comma in `f(a, b,)`.
+    pub fn ignore_trailing_punct(mut self, yes: bool) -> Self {
+        self.ignore_trailing_punct = yes;
+        self
+    }
+
+    /// Ignores the attributes of every node that has attributes, including
+    /// doc comments.
+    pub fn ignore_attrs(mut self, yes: bool) -> Self {
+        self.ignore_attrs = yes;
+        self
+    }
+
+    /// Treats raw identifiers like `r#type` as equivalent to the same
+    /// identifier without the `r#` prefix.
+    pub fn unraw_idents(mut self, yes: bool) -> Self {
+        self.unraw_idents = yes;
+        self
+    }
+
+    /// Determines whether two syntax trees are equivalent.
+    pub fn equal<T: Equivalent + ?Sized>(&self, a: &T, b: &T) -> bool {
+        a.equiv(b, self)
+    }
+
+    /// Feeds a syntax tree into the given hasher, such that equivalent trees
+    /// are hashed the same.
+    pub fn hash<T: Equivalent + ?Sized, H: Hasher>(&self, node: &T, state: &mut H) {
+        node.hash_equiv(self, state);
+    }
+
+    /// Wraps a syntax tree, or a reference to one, for use as the key of a
+    /// `HashMap` or `HashSet` in which equivalent keys are the same key.
+    ///
+    /// All keys in the same map should be created by the same `Equivalence`.
+    pub fn key<T: Equivalent>(&self, node: T) -> Key<T> {
+        Key { node, equiv: *self }
+    }
+}
+
+/// A syntax tree that implements `Eq` and `Hash` according to an
+/// [`Equivalence`].
+///
+/// Created by [`Equivalence::key`].
+#[derive(Clone, Debug)]
+pub struct Key<T> {
+    node: T,
+    equiv: Equivalence,
+}
+
+impl<T> Key<T> {
+    /// Unwraps the syntax tree.
+    pub fn into_inner(self) -> T {
+        self.node
+    }
+}
+
+impl<T> Deref for Key<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.node
+    }
+}
+
+impl<T: Equivalent> Eq for Key<T> {}
+
+impl<T: Equivalent> PartialEq for Key<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node.equiv(&other.node, &self.equiv)
+    }
+}
+
+impl<T: Equivalent> Hash for Key<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash_equiv(&self.equiv, state);
+    }
+}
+
+/// Syntax tree types that can be compared by an [`Equivalence`].
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+pub trait Equivalent: Sealed {
+    // Not public API.
+    #[doc(hidden)]
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool;
+
+    // Not public API.
+    #[doc(hidden)]
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H);
+}
+
+impl<T: Equivalent + ?Sized> Sealed for &T {}
+
+impl<T: Equivalent + ?Sized> Equivalent for &T {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        T::equiv(self, other, equiv)
+    }
+
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        T::hash_equiv(self, equiv, state);
+    }
+}
+
+impl<T: Equivalent + ?Sized> Sealed for Box<T> {}
+
+impl<T: Equivalent + ?Sized> Equivalent for Box<T> {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        T::equiv(self, other, equiv)
+    }
+
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        T::hash_equiv(self, equiv, state);
+    }
+}
+
+impl<T: Equivalent> Sealed for Option<T> {}
+
+impl<T: Equivalent> Equivalent for Option<T> {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (Some(this), Some(other)) => this.equiv(other, equiv),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            Some(this) => {
+                state.write_u8(1);
+                this.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+
+impl<T: Equivalent> Sealed for Vec<T> {}
+
+impl<T: Equivalent> Equivalent for Vec<T> {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.equiv(b, equiv))
+    }
+
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        state.write_usize(self.len());
+        for elem in self {
+            elem.hash_equiv(equiv, state);
+        }
+    }
+}
+
+impl<T: Equivalent, P> Sealed for Punctuated<T, P> {}
+
+impl<T: Equivalent, P> Equivalent for Punctuated<T, P> {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.len() == other.len()
+            && (equiv.ignore_trailing_punct || self.trailing_punct() == other.trailing_punct())
+            && self.iter().zip(other).all(|(a, b)| a.equiv(b, equiv))
+    }
+
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        state.write_usize(self.len());
+        if !equiv.ignore_trailing_punct {
+            self.trailing_punct().hash(state);
+        }
+        for elem in self {
+            elem.hash_equiv(equiv, state);
+        }
+    }
+}
+
+impl Sealed for Ident {}
+
+impl Equivalent for Ident {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        if equiv.unraw_idents {
+            unraw(&self.to_string()) == unraw(&other.to_string())
+        } else {
+            self == other
+        }
+    }
+
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if equiv.unraw_idents {
+            unraw(&self.to_string()).hash(state);
+        } else {
+            self.hash(state);
+        }
+    }
+}
+
+fn unraw(ident: &str) -> &str {
+    if ident.starts_with("r#") {
+        &ident[2..]
+    } else {
+        ident
+    }
+}
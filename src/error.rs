@@ -1,12 +1,19 @@
 use std;
+use std::cell::RefCell;
 use std::fmt::{self, Debug, Display};
+#[cfg(feature = "span-locations")]
+use std::fmt::Write;
 use std::iter::FromIterator;
+#[cfg(feature = "span-locations")]
+use std::path::Path;
 use std::slice;
 use std::vec;
 
 use proc_macro2::{
     Delimiter, Group, Ident, LexError, Literal, Punct, Spacing, Span, TokenStream, TokenTree,
 };
+#[cfg(feature = "span-locations")]
+use proc_macro2::LineColumn;
 #[cfg(feature = "printing")]
 use quote::ToTokens;
 
@@ -82,6 +89,38 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// #     }
 /// # }
 /// ```
+///
+/// # Notes, help messages and labels
+///
+/// Like a diagnostic from rustc, an error can carry `note:` and `help:`
+/// messages and labelled secondary spans in addition to its primary message,
+/// which are added using the builder methods [`note`], [`help`] and
+/// [`span_label`] and their variants.
+///
+/// [`note`]: Error::note
+/// [`help`]: Error::help
+/// [`span_label`]: Error::span_label
+///
+/// ```
+/// use syn::{Error, Field};
+///
+/// fn duplicate_field(first: &Field, second: &Field) -> Error {
+///     let name = second.ident.as_ref().unwrap();
+///     Error::new(name.span(), format!("field `{}` is declared twice", name))
+///         .span_label(first.ident.as_ref().unwrap().span(), "first declared here")
+///         .help("rename one of the fields")
+/// }
+/// ```
+///
+/// Stable Rust has no way for a procedural macro to emit such a diagnostic,
+/// so [`to_compile_error`] expands to a separate `compile_error!` for the
+/// primary message and for each note, help message and label, each one
+/// spanned to the place it talks about. On a nightly compiler, building Syn
+/// with `RUSTFLAGS='--cfg syn_unstable_diagnostics'` provides an `emit`
+/// method which reports the error as a single `proc_macro::Diagnostic`
+/// instead.
+///
+/// [`to_compile_error`]: Error::to_compile_error
 #[derive(Clone)]
 pub struct Error {
     messages: Vec<ErrorMessage>,
@@ -96,6 +135,22 @@ struct ErrorMessage {
     start_span: ThreadBound<Span>,
     end_span: ThreadBound<Span>,
     message: String,
+    children: Vec<SubDiagnostic>,
+}
+
+// A note, help message or label attached to an error message. Notes and help
+// messages without a span of their own refer to the span of the error.
+struct SubDiagnostic {
+    level: Level,
+    span: Option<ThreadBound<Span>>,
+    message: String,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Level {
+    Label,
+    Note,
+    Help,
 }
 
 #[cfg(test)]
@@ -140,6 +195,7 @@ impl Error {
                 start_span: ThreadBound::new(span),
                 end_span: ThreadBound::new(span),
                 message: message.to_string(),
+                children: Vec::new(),
             }],
         }
     }
@@ -167,6 +223,7 @@ impl Error {
                 start_span: ThreadBound::new(start),
                 end_span: ThreadBound::new(end),
                 message: message.to_string(),
+                children: Vec::new(),
             }],
         }
     }
@@ -206,40 +263,396 @@ impl Error {
     pub fn combine(&mut self, another: Error) {
         self.messages.extend(another.messages)
     }
+
+    /// Attaches a `note:` message to the error, for extra context about why
+    /// the error happened.
+    ///
+    /// If several errors have been combined into this one, the note belongs
+    /// to the first of them, which is the one that `Display` and [`span`]
+    /// describe. The same goes for the other builder methods below.
+    ///
+    /// [`span`]: Error::span
+    pub fn note<T: Display>(self, message: T) -> Self {
+        self.child(Level::Note, None, message)
+    }
+
+    /// Attaches a `note:` message pointing at a span of its own.
+    pub fn span_note<T: Display>(self, span: Span, message: T) -> Self {
+        self.child(Level::Note, Some(span), message)
+    }
+
+    /// Attaches a `help:` message to the error, typically suggesting how to
+    /// fix it.
+    pub fn help<T: Display>(self, message: T) -> Self {
+        self.child(Level::Help, None, message)
+    }
+
+    /// Attaches a `help:` message pointing at a span of its own.
+    pub fn span_help<T: Display>(self, span: Span, message: T) -> Self {
+        self.child(Level::Help, Some(span), message)
+    }
+
+    /// Labels a secondary span that is relevant to the error, such as the
+    /// previous declaration of a duplicate name.
+    pub fn span_label<T: Display>(self, span: Span, label: T) -> Self {
+        self.child(Level::Label, Some(span), label)
+    }
+
+    fn child<T: Display>(mut self, level: Level, span: Option<Span>, message: T) -> Self {
+        self.messages[0].children.push(SubDiagnostic {
+            level,
+            span: span.map(ThreadBound::new),
+            message: message.to_string(),
+        });
+        self
+    }
+
+    /// Reports the error to the compiler as a `proc_macro::Diagnostic`, with
+    /// its notes, help messages and labels as sub-diagnostics, instead of
+    /// expanding to `compile_error!`.
+    ///
+    /// *This method is only available on a nightly compiler when Syn is built
+    /// with `RUSTFLAGS='--cfg syn_unstable_diagnostics'` and the
+    /// `"proc-macro"` feature. It panics if called from outside of a
+    /// procedural macro.*
+    #[cfg(all(
+        syn_unstable_diagnostics,
+        not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
+        feature = "proc-macro"
+    ))]
+    pub fn emit(self) {
+        for message in &self.messages {
+            message
+                .to_diagnostic(crate::proc_macro::Level::Error)
+                .emit();
+        }
+    }
+
+    /// Formats the error as a report in the style of rustc, quoting the lines
+    /// of `source` that it points to.
+    ///
+    /// This is meant for parse errors in files parsed outside of a procedural
+    /// macro, for example by a build script. `source` must be the text that
+    /// was parsed and `filename` is the path shown as its location. Every
+    /// error combined into this one is included, each with its notes, help
+    /// messages and labels.
+    ///
+    /// ```text
+    /// error: expected `,`
+    ///  --> src/main.rs:40:18
+    ///    |
+    /// 40 |     fn fmt(&self formatter: &mut fmt::Formatter) -> fmt::Result {
+    ///    |                  ^^^^^^^^^
+    /// ```
+    ///
+    /// *This method is available if Syn is built with the `"span-locations"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// let code = "fn main() {\n    let x = ;\n}\n";
+    /// let err = syn::parse_file(code).unwrap_err();
+    /// assert_eq!(
+    ///     err.render("main.rs", code),
+    ///     "\
+    /// error: expected expression
+    ///  --> main.rs:2:13
+    ///   |
+    /// 2 |     let x = ;
+    ///   |             ^
+    /// ",
+    /// );
+    /// # }
+    /// ```
+    #[cfg(feature = "span-locations")]
+    pub fn render<P: AsRef<Path>>(&self, filename: P, source: &str) -> String {
+        let filename = filename.as_ref().display().to_string();
+        let mut out = String::new();
+        for (i, message) in self.messages.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            message.render(&mut out, &filename, source);
+        }
+        out
+    }
 }
 
 impl ErrorMessage {
     fn to_compile_error(&self) -> TokenStream {
-        let start = self
-            .start_span
-            .get()
-            .cloned()
-            .unwrap_or_else(Span::call_site);
-        let end = self.end_span.get().cloned().unwrap_or_else(Span::call_site);
-
-        // compile_error!($message)
-        TokenStream::from_iter(vec![
-            TokenTree::Ident(Ident::new("compile_error", start)),
-            TokenTree::Punct({
-                let mut punct = Punct::new('!', Spacing::Alone);
-                punct.set_span(start);
-                punct
-            }),
-            TokenTree::Group({
-                let mut group = Group::new(Delimiter::Brace, {
-                    TokenStream::from_iter(vec![TokenTree::Literal({
-                        let mut string = Literal::string(&self.message);
-                        string.set_span(end);
-                        string
-                    })])
-                });
-                group.set_span(end);
-                group
-            }),
-        ])
+        let start = span_or_call_site(&self.start_span);
+        let end = span_or_call_site(&self.end_span);
+
+        let mut tokens = compile_error(start, end, &self.message);
+        for child in &self.children {
+            let (start, end) = match &child.span {
+                Some(span) => (span_or_call_site(span), span_or_call_site(span)),
+                None => (start, end),
+            };
+            let message = match child.level {
+                Level::Label => child.message.clone(),
+                Level::Note => format!("note: {}", child.message),
+                Level::Help => format!("help: {}", child.message),
+            };
+            tokens.extend(compile_error(start, end, &message));
+        }
+        tokens
+    }
+
+    // {
+    //     #[deprecated(note = $message)]
+    //     #[allow(non_upper_case_globals)]
+    //     const warning: () = ();
+    //     let _: () = warning;
+    // }
+    #[cfg(feature = "printing")]
+    fn to_compile_warning(&self) -> TokenStream {
+        let start = span_or_call_site(&self.start_span);
+        let end = span_or_call_site(&self.end_span);
+        let span = start.join(end).unwrap_or(start);
+
+        let note = TokenStream::from_iter(vec![
+            TokenTree::Ident(Ident::new("note", Span::call_site())),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            TokenTree::Literal(Literal::string(&self.message)),
+        ]);
+        let mut body = TokenStream::from_iter(vec![
+            TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Bracket,
+                TokenStream::from_iter(vec![
+                    TokenTree::Ident(Ident::new("deprecated", Span::call_site())),
+                    TokenTree::Group(Group::new(Delimiter::Parenthesis, note)),
+                ]),
+            )),
+        ]);
+        body.extend(
+            "#[allow(non_upper_case_globals)] const warning: () = (); let _: () ="
+                .parse::<TokenStream>()
+                .unwrap(),
+        );
+        body.extend(vec![
+            TokenTree::Ident(Ident::new("warning", span)),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+        ]);
+        TokenStream::from(TokenTree::Group(Group::new(Delimiter::Brace, body)))
+    }
+
+    #[cfg(all(
+        syn_unstable_diagnostics,
+        not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
+        feature = "proc-macro"
+    ))]
+    fn to_diagnostic(&self, level: crate::proc_macro::Level) -> crate::proc_macro::Diagnostic {
+        use crate::proc_macro::Diagnostic;
+
+        let start = unwrap_span(span_or_call_site(&self.start_span));
+        let end = unwrap_span(span_or_call_site(&self.end_span));
+        let span = start.join(end).unwrap_or(start);
+
+        let mut diagnostic = Diagnostic::spanned(span, level, &*self.message);
+        for child in &self.children {
+            let message = &*child.message;
+            let span = child.span.as_ref().map(|span| unwrap_span(span_or_call_site(span)));
+            diagnostic = match (child.level, span) {
+                (Level::Label, Some(span)) | (Level::Note, Some(span)) => {
+                    diagnostic.span_note(span, message)
+                }
+                (Level::Help, Some(span)) => diagnostic.span_help(span, message),
+                (Level::Help, None) => diagnostic.help(message),
+                (_, None) => diagnostic.note(message),
+            };
+        }
+        diagnostic
+    }
+}
+
+#[cfg(feature = "span-locations")]
+impl ErrorMessage {
+    fn render(&self, out: &mut String, filename: &str, source: &str) {
+        let start = span_or_call_site(&self.start_span);
+        let end = span_or_call_site(&self.end_span);
+        let primary = Annotation::new(start.start(), end.end(), '^', None);
+
+        let mut annotations = vec![primary];
+        let mut notes = Vec::new();
+        for child in &self.children {
+            match (child.level, &child.span) {
+                (Level::Label, Some(span)) => {
+                    let span = span_or_call_site(span);
+                    annotations.push(Annotation::new(
+                        span.start(),
+                        span.end(),
+                        '-',
+                        Some(&child.message),
+                    ));
+                }
+                _ => notes.push(child),
+            }
+        }
+
+        let _ = writeln!(out, "error: {}", self.message);
+        render_snippet(out, filename, source, annotations);
+        for note in notes {
+            let level = match note.level {
+                Level::Help => "help",
+                Level::Label | Level::Note => "note",
+            };
+            let span = note.span.as_ref().map(span_or_call_site);
+            let annotation = span.map(|span| Annotation::new(span.start(), span.end(), '^', None));
+            match annotation {
+                Some(annotation) if annotation.located => {
+                    let _ = writeln!(out, "{}: {}", level, note.message);
+                    render_snippet(out, filename, source, vec![annotation]);
+                }
+                _ => {
+                    let _ = writeln!(out, "  = {}: {}", level, note.message);
+                }
+            }
+        }
+    }
+}
+
+// A span to underline in a rendered snippet. Spans covering more than one
+// line are underlined to the end of their first line.
+#[cfg(feature = "span-locations")]
+struct Annotation<'a> {
+    start: LineColumn,
+    end: LineColumn,
+    located: bool,
+    underline: char,
+    label: Option<&'a str>,
+}
+
+#[cfg(feature = "span-locations")]
+impl<'a> Annotation<'a> {
+    fn new(start: LineColumn, end: LineColumn, underline: char, label: Option<&'a str>) -> Self {
+        // Span::call_site() and other spans without a location in the source
+        // text show up as an empty span at the start of the first line.
+        let located = (start.line, start.column) != (end.line, end.column);
+        Annotation {
+            start,
+            end,
+            located,
+            underline,
+            label,
+        }
+    }
+}
+
+#[cfg(feature = "span-locations")]
+fn render_snippet(out: &mut String, filename: &str, source: &str, annotations: Vec<Annotation>) {
+    let mut annotations: Vec<Annotation> = annotations
+        .into_iter()
+        .filter(|annotation| annotation.located)
+        .filter(|annotation| annotation.start.line <= source.lines().count())
+        .collect();
+    let primary = match annotations.first() {
+        Some(primary) => primary.start,
+        None => {
+            let _ = writeln!(out, " --> {}", filename);
+            return;
+        }
+    };
+    annotations.sort_by_key(|annotation| (annotation.start.line, annotation.start.column));
+
+    let last_line = annotations[annotations.len() - 1].start.line;
+    let gutter = " ".repeat(last_line.to_string().len());
+    let _ = writeln!(
+        out,
+        "{}--> {}:{}:{}",
+        gutter,
+        filename,
+        primary.line,
+        primary.column + 1,
+    );
+    let _ = writeln!(out, "{} |", gutter);
+
+    let mut prev_line = None;
+    for annotation in &annotations {
+        let line = annotation.start.line;
+        if prev_line != Some(line) {
+            match prev_line {
+                Some(prev) if line > prev + 1 => {
+                    let _ = writeln!(out, "...");
+                }
+                _ => {}
+            }
+            let mut code = source.lines().nth(line - 1).unwrap_or("");
+            if line == 1 && code.starts_with('\u{feff}') {
+                // Columns on the first line do not count a byte order mark.
+                code = &code['\u{feff}'.len_utf8()..];
+            }
+            let _ = writeln!(out, "{:>width$} | {}", line, code.trim_end(), width = gutter.len());
+            prev_line = Some(line);
+
+            let line_len = code.trim_end().chars().count();
+            for annotation in annotations.iter().filter(|a| a.start.line == line) {
+                let start = annotation.start.column;
+                let end = if annotation.end.line == line {
+                    annotation.end.column
+                } else {
+                    line_len
+                };
+                let width = if end > start { end - start } else { 1 };
+                let mut underline = format!(
+                    "{} | {}{}",
+                    gutter,
+                    " ".repeat(start),
+                    annotation.underline.to_string().repeat(width),
+                );
+                if let Some(label) = annotation.label {
+                    underline.push(' ');
+                    underline.push_str(label);
+                }
+                let _ = writeln!(out, "{}", underline);
+            }
+        }
     }
 }
 
+fn span_or_call_site(span: &ThreadBound<Span>) -> Span {
+    span.get().cloned().unwrap_or_else(Span::call_site)
+}
+
+// compile_error!($message)
+fn compile_error(start: Span, end: Span, message: &str) -> TokenStream {
+    TokenStream::from_iter(vec![
+        TokenTree::Ident(Ident::new("compile_error", start)),
+        TokenTree::Punct({
+            let mut punct = Punct::new('!', Spacing::Alone);
+            punct.set_span(start);
+            punct
+        }),
+        TokenTree::Group({
+            let mut group = Group::new(Delimiter::Brace, {
+                TokenStream::from_iter(vec![TokenTree::Literal({
+                    let mut string = Literal::string(message);
+                    string.set_span(end);
+                    string
+                })])
+            });
+            group.set_span(end);
+            group
+        }),
+    ])
+}
+
+// Converts a span to the compiler's span type by way of a token, which works
+// with every version of proc-macro2.
+#[cfg(all(
+    syn_unstable_diagnostics,
+    not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
+    feature = "proc-macro"
+))]
+fn unwrap_span(span: Span) -> crate::proc_macro::Span {
+    let token = TokenTree::Ident(Ident::new("x", span));
+    let tokens = crate::proc_macro::TokenStream::from(TokenStream::from(token));
+    tokens.into_iter().next().unwrap().span()
+}
+
 #[cfg(feature = "parsing")]
 pub fn new_at<T: Display>(scope: Span, cursor: Cursor, message: T) -> Error {
     if cursor.eof() {
@@ -250,6 +663,154 @@ pub fn new_at<T: Display>(scope: Span, cursor: Cursor, message: T) -> Error {
     }
 }
 
+/// A collection of warnings for a procedural macro to report alongside its
+/// output, for problems that should not fail the build such as the use of a
+/// deprecated option.
+///
+/// Warnings are pushed through a shared reference, so a single collector can
+/// be handed to every helper that parses part of the macro input. Once the
+/// macro has finished, include the collector in the output tokens by way of
+/// its [`ToTokens`] impl.
+///
+/// [`ToTokens`]: quote::ToTokens
+///
+/// ```
+/// # extern crate proc_macro;
+/// #
+/// use proc_macro::TokenStream;
+/// use quote::quote;
+/// use syn::{parse_macro_input, DeriveInput, Meta, Warnings};
+///
+/// # const IGNORE: &str = stringify! {
+/// #[proc_macro_derive(MyDerive, attributes(my_derive))]
+/// # };
+/// pub fn my_derive(input: TokenStream) -> TokenStream {
+///     let input = parse_macro_input!(input as DeriveInput);
+///     let warnings = Warnings::new();
+///
+///     for attr in &input.attrs {
+///         if let Ok(Meta::Path(path)) = attr.parse_meta() {
+///             if path.is_ident("old_option") {
+///                 warnings.warn_spanned(path, "`old_option` is deprecated and has no effect");
+///             }
+///         }
+///     }
+///
+///     /* ... */
+///     # let expanded = proc_macro2::TokenStream::new();
+///
+///     TokenStream::from(quote! {
+///         #expanded
+///         #warnings
+///     })
+/// }
+/// ```
+///
+/// Stable Rust has no way for a procedural macro to emit a warning, so the
+/// tokens of the collector are an item that refers to a `#[deprecated]`
+/// constant for each warning, with the reference spanned to the place that
+/// the warning is about. The compiler then reports a deprecation warning
+/// there whose note is the warning message. The item is an anonymous
+/// constant, `const _: () = ...;`, which requires Rust 1.37 or newer in the
+/// crate using the macro, and must appear where items are allowed.
+///
+/// On a nightly compiler, building Syn with
+/// `RUSTFLAGS='--cfg syn_unstable_diagnostics'` provides an `emit` method
+/// which reports the warnings as `proc_macro::Diagnostic`s instead.
+#[derive(Default)]
+pub struct Warnings {
+    messages: RefCell<Vec<ErrorMessage>>,
+}
+
+impl Warnings {
+    /// Creates a collector with no warnings in it.
+    pub fn new() -> Self {
+        Warnings {
+            messages: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Adds a warning about the given span.
+    pub fn warn<T: Display>(&self, span: Span, message: T) {
+        self.messages
+            .borrow_mut()
+            .extend(Error::new(span, message).messages);
+    }
+
+    /// Adds a warning spanning the given syntax tree node, like
+    /// [`Error::new_spanned`].
+    #[cfg(feature = "printing")]
+    pub fn warn_spanned<T: ToTokens, U: Display>(&self, tokens: T, message: U) {
+        self.messages
+            .borrow_mut()
+            .extend(Error::new_spanned(tokens, message).messages);
+    }
+
+    /// Returns whether no warnings have been added.
+    pub fn is_empty(&self) -> bool {
+        self.messages.borrow().is_empty()
+    }
+
+    /// Returns the number of warnings that have been added.
+    pub fn len(&self) -> usize {
+        self.messages.borrow().len()
+    }
+
+    /// Reports the warnings to the compiler as `proc_macro::Diagnostic`s
+    /// instead of expanding to tokens.
+    ///
+    /// *This method is only available on a nightly compiler when Syn is built
+    /// with `RUSTFLAGS='--cfg syn_unstable_diagnostics'` and the
+    /// `"proc-macro"` feature. It panics if called from outside of a
+    /// procedural macro.*
+    #[cfg(all(
+        syn_unstable_diagnostics,
+        not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "wasi"))),
+        feature = "proc-macro"
+    ))]
+    pub fn emit(self) {
+        for message in self.messages.into_inner() {
+            message
+                .to_diagnostic(crate::proc_macro::Level::Warning)
+                .emit();
+        }
+    }
+}
+
+#[cfg(feature = "printing")]
+impl ToTokens for Warnings {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let messages = self.messages.borrow();
+        if messages.is_empty() {
+            return;
+        }
+
+        // const _: () = { $($warning)* };
+        let mut body = TokenStream::new();
+        for message in messages.iter() {
+            body.extend(message.to_compile_warning());
+        }
+        tokens.extend(TokenStream::from_iter(vec![
+            TokenTree::Ident(Ident::new("const", Span::call_site())),
+            TokenTree::Ident(Ident::new("_", Span::call_site())),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+            TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+            TokenTree::Group(Group::new(Delimiter::Brace, body)),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+        ]));
+    }
+}
+
+impl Debug for Warnings {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_tuple("Warnings")
+            .field(&*self.messages.borrow())
+            .finish()
+    }
+}
+
 impl Debug for Error {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         if self.messages.len() == 1 {
@@ -280,16 +841,26 @@ impl Display for Error {
 
 impl Clone for ErrorMessage {
     fn clone(&self) -> Self {
-        let start = self
-            .start_span
-            .get()
-            .cloned()
-            .unwrap_or_else(Span::call_site);
-        let end = self.end_span.get().cloned().unwrap_or_else(Span::call_site);
+        let start = span_or_call_site(&self.start_span);
+        let end = span_or_call_site(&self.end_span);
         ErrorMessage {
             start_span: ThreadBound::new(start),
             end_span: ThreadBound::new(end),
             message: self.message.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl Clone for SubDiagnostic {
+    fn clone(&self) -> Self {
+        SubDiagnostic {
+            level: self.level,
+            span: self
+                .span
+                .as_ref()
+                .map(|span| ThreadBound::new(span_or_call_site(span))),
+            message: self.message.clone(),
         }
     }
 }
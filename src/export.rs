@@ -5,7 +5,7 @@ pub use std::default::Default;
 pub use std::fmt::{self, Debug, Formatter};
 pub use std::hash::{Hash, Hasher};
 pub use std::marker::Copy;
-pub use std::option::Option::{None, Some};
+pub use std::option::Option::{self, None, Some};
 pub use std::result::Result::{Err, Ok};
 
 #[cfg(feature = "printing")]
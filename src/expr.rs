@@ -1177,12 +1177,219 @@ pub(crate) fn requires_terminator(expr: &Expr) -> bool {
     }
 }
 
+#[cfg(feature = "parsing")]
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub(crate) enum Precedence {
+    Any,
+    Assign,
+    Range,
+    Or,
+    And,
+    Compare,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shift,
+    Arithmetic,
+    Term,
+    Cast,
+    #[cfg(all(feature = "full", feature = "printing"))]
+    Prefix,
+    #[cfg(all(feature = "full", feature = "printing"))]
+    Postfix,
+}
+
+#[cfg(feature = "parsing")]
+impl Precedence {
+    pub(crate) fn of(op: &BinOp) -> Self {
+        match *op {
+            BinOp::Add(_) | BinOp::Sub(_) => Precedence::Arithmetic,
+            BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => Precedence::Term,
+            BinOp::And(_) => Precedence::And,
+            BinOp::Or(_) => Precedence::Or,
+            BinOp::BitXor(_) => Precedence::BitXor,
+            BinOp::BitAnd(_) => Precedence::BitAnd,
+            BinOp::BitOr(_) => Precedence::BitOr,
+            BinOp::Shl(_) | BinOp::Shr(_) => Precedence::Shift,
+            BinOp::Eq(_)
+            | BinOp::Lt(_)
+            | BinOp::Le(_)
+            | BinOp::Ne(_)
+            | BinOp::Ge(_)
+            | BinOp::Gt(_) => Precedence::Compare,
+            BinOp::AddEq(_)
+            | BinOp::SubEq(_)
+            | BinOp::MulEq(_)
+            | BinOp::DivEq(_)
+            | BinOp::RemEq(_)
+            | BinOp::BitXorEq(_)
+            | BinOp::BitAndEq(_)
+            | BinOp::BitOrEq(_)
+            | BinOp::ShlEq(_)
+            | BinOp::ShrEq(_) => Precedence::Assign,
+        }
+    }
+
+    // Precedence of an expression as it appears in source, used to decide
+    // where parentheses are required when printing it as part of a larger
+    // expression.
+    #[cfg(all(feature = "full", feature = "printing"))]
+    pub(crate) fn of_expr(expr: &Expr) -> Self {
+        match *expr {
+            Expr::Binary(ref e) => Precedence::of(&e.op),
+            Expr::Assign(_) | Expr::AssignOp(_) => Precedence::Assign,
+            Expr::Range(_) => Precedence::Range,
+            Expr::Cast(_) | Expr::Type(_) => Precedence::Cast,
+            Expr::Unary(ref e) => Precedence::of_prefix(&e.expr),
+            Expr::Reference(ref e) => Precedence::of_prefix(&e.expr),
+            Expr::Box(ref e) => Precedence::of_prefix(&e.expr),
+            Expr::Closure(_) | Expr::Return(_) | Expr::Break(_) | Expr::Yield(_) | Expr::Let(_) => {
+                Precedence::Any
+            }
+            _ => Precedence::Postfix,
+        }
+    }
+
+    // A prefix operator applied to a closure or jump expression extends as
+    // far to the right as its operand does.
+    #[cfg(all(feature = "full", feature = "printing"))]
+    fn of_prefix(operand: &Expr) -> Self {
+        match *operand {
+            Expr::Closure(_) | Expr::Return(_) | Expr::Break(_) | Expr::Yield(_) => Precedence::Any,
+            Expr::Unary(_) | Expr::Reference(_) | Expr::Box(_) => Precedence::of_expr(operand),
+            _ => Precedence::Prefix,
+        }
+    }
+}
+
+// Whether the operand of a prefix operator like `&`, `!`, `*` or `box` must be
+// parenthesized to be parsed back as the operand. Closures and jump
+// expressions need none, as nothing that follows the operator can end them.
+#[cfg(all(feature = "full", feature = "parsing", feature = "printing"))]
+pub(crate) fn prefix_needs_parens(operand: &Expr) -> bool {
+    match operand {
+        Expr::Closure(_) | Expr::Return(_) | Expr::Break(_) | Expr::Yield(_) => false,
+        Expr::Unary(_) | Expr::Reference(_) | Expr::Box(_) => false,
+        _ => Precedence::of_expr(operand) < Precedence::Prefix,
+    }
+}
+
+// Whether the function of a call expression must be parenthesized. `(a.f)()`
+// calls a field, whereas `a.f()` calls a method, but `a.0()` can only call a
+// tuple field.
+#[cfg(all(feature = "full", feature = "parsing", feature = "printing"))]
+pub(crate) fn callee_needs_parens(func: &Expr) -> bool {
+    match func {
+        Expr::Field(ExprField {
+            member: Member::Named(_),
+            ..
+        }) => true,
+        func => Precedence::of_expr(func) < Precedence::Postfix,
+    }
+}
+
+// Whether the left operand of a binary expression must be parenthesized to
+// be parsed back as the operand.
+#[cfg(all(feature = "full", feature = "parsing", feature = "printing"))]
+pub(crate) fn left_needs_parens(e: &ExprBinary) -> bool {
+    let precedence = Precedence::of(&e.op);
+    let left = Precedence::of_expr(&e.left);
+    // `a as T < b` and `a + b as T << c` would parse as the start of generic
+    // arguments.
+    let left_cast = match e.op {
+        BinOp::Lt(_) | BinOp::Shl(_) => ends_with_cast(&e.left),
+        _ => false,
+    };
+    left < precedence || left == precedence && precedence == Precedence::Compare || left_cast
+}
+
+// Whether the tokens of an expression end with the type of a cast or type
+// ascription that is not in parentheses.
+#[cfg(all(feature = "full", feature = "parsing", feature = "printing"))]
+fn ends_with_cast(expr: &Expr) -> bool {
+    match expr {
+        Expr::Cast(_) | Expr::Type(_) => true,
+        Expr::Binary(e) => !right_needs_parens(e) && ends_with_cast(&e.right),
+        _ => false,
+    }
+}
+
+// Whether the right operand of a binary expression must be parenthesized to
+// be parsed back as the operand.
+#[cfg(all(feature = "full", feature = "parsing", feature = "printing"))]
+pub(crate) fn right_needs_parens(e: &ExprBinary) -> bool {
+    Precedence::of_expr(&e.right) <= Precedence::of(&e.op)
+}
+
+// Whether an expression statement would end early at a block-like expression
+// in its leftmost position. The parser only continues past the closing brace
+// of such an expression for a `.` or `?` that directly follows it.
+#[cfg(all(feature = "full", feature = "parsing", feature = "printing"))]
+pub(crate) fn stmt_needs_parens(expr: &Expr) -> bool {
+    let mut expr = expr;
+    loop {
+        let (next, continues) = match expr {
+            Expr::Assign(e) => (&*e.left, false),
+            Expr::AssignOp(e) => (&*e.left, false),
+            Expr::Binary(e) => (&*e.left, false),
+            Expr::Call(e) => (&*e.func, false),
+            Expr::Cast(e) => (&*e.expr, false),
+            Expr::Index(e) => (&*e.expr, false),
+            Expr::Type(e) => (&*e.expr, false),
+            Expr::Range(e) => match &e.from {
+                Some(from) => (&**from, false),
+                None => return false,
+            },
+            Expr::Await(e) => (&*e.base, true),
+            Expr::Field(e) => (&*e.base, true),
+            Expr::MethodCall(e) => (&*e.receiver, true),
+            Expr::Try(e) => (&*e.expr, true),
+            _ => return false,
+        };
+        if !requires_terminator(next) {
+            return !continues;
+        }
+        expr = next;
+    }
+}
+
+// Whether an expression contains a struct literal outside of any delimiters,
+// which is not allowed in the head of `if`, `while`, `match` and `for`.
+#[cfg(all(feature = "full", feature = "parsing", feature = "printing"))]
+pub(crate) fn contains_exposed_struct(expr: &Expr) -> bool {
+    match expr {
+        Expr::Struct(_) => true,
+        Expr::Assign(e) => contains_exposed_struct(&e.left) || contains_exposed_struct(&e.right),
+        Expr::AssignOp(e) => contains_exposed_struct(&e.left) || contains_exposed_struct(&e.right),
+        Expr::Binary(e) => contains_exposed_struct(&e.left) || contains_exposed_struct(&e.right),
+        Expr::Await(e) => contains_exposed_struct(&e.base),
+        Expr::Box(e) => contains_exposed_struct(&e.expr),
+        Expr::Call(e) => contains_exposed_struct(&e.func),
+        Expr::Cast(e) => contains_exposed_struct(&e.expr),
+        Expr::Field(e) => contains_exposed_struct(&e.base),
+        Expr::Index(e) => contains_exposed_struct(&e.expr),
+        Expr::Let(e) => contains_exposed_struct(&e.expr),
+        Expr::MethodCall(e) => contains_exposed_struct(&e.receiver),
+        Expr::Range(e) => {
+            e.from.as_ref().map_or(false, |from| contains_exposed_struct(from))
+                || e.to.as_ref().map_or(false, |to| contains_exposed_struct(to))
+        }
+        Expr::Reference(e) => contains_exposed_struct(&e.expr),
+        Expr::Try(e) => contains_exposed_struct(&e.expr),
+        Expr::Type(e) => contains_exposed_struct(&e.expr),
+        Expr::Unary(e) => contains_exposed_struct(&e.expr),
+        _ => false,
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub(crate) mod parsing {
     use super::*;
 
-    use crate::parse::{Parse, ParseStream, Result};
+    use crate::buffer::Cursor;
+    use crate::parse::{Parse, ParseStream, PeekNode, Result};
     use crate::path;
+    use crate::token::Token;
 
     // When we're parsing expressions which occur before blocks, like in an if
     // statement's condition, we cannot parse a struct literal.
@@ -1192,60 +1399,67 @@ pub(crate) mod parsing {
     #[derive(Copy, Clone)]
     pub struct AllowStruct(bool);
 
-    #[derive(Copy, Clone, PartialEq, PartialOrd)]
-    enum Precedence {
-        Any,
-        Assign,
-        Range,
-        Or,
-        And,
-        Compare,
-        BitOr,
-        BitXor,
-        BitAnd,
-        Shift,
-        Arithmetic,
-        Term,
-        Cast,
-    }
-
-    impl Precedence {
-        fn of(op: &BinOp) -> Self {
-            match *op {
-                BinOp::Add(_) | BinOp::Sub(_) => Precedence::Arithmetic,
-                BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => Precedence::Term,
-                BinOp::And(_) => Precedence::And,
-                BinOp::Or(_) => Precedence::Or,
-                BinOp::BitXor(_) => Precedence::BitXor,
-                BinOp::BitAnd(_) => Precedence::BitAnd,
-                BinOp::BitOr(_) => Precedence::BitOr,
-                BinOp::Shl(_) | BinOp::Shr(_) => Precedence::Shift,
-                BinOp::Eq(_)
-                | BinOp::Lt(_)
-                | BinOp::Le(_)
-                | BinOp::Ne(_)
-                | BinOp::Ge(_)
-                | BinOp::Gt(_) => Precedence::Compare,
-                BinOp::AddEq(_)
-                | BinOp::SubEq(_)
-                | BinOp::MulEq(_)
-                | BinOp::DivEq(_)
-                | BinOp::RemEq(_)
-                | BinOp::BitXorEq(_)
-                | BinOp::BitAndEq(_)
-                | BinOp::BitOrEq(_)
-                | BinOp::ShlEq(_)
-                | BinOp::ShrEq(_) => Precedence::Assign,
-            }
-        }
-    }
-
     impl Parse for Expr {
         fn parse(input: ParseStream) -> Result<Self> {
             ambiguous_expr(input, AllowStruct(true))
         }
     }
 
+    // Tokens that can start an expression.
+    const EXPR_START: &[fn(Cursor) -> bool] = &[
+        <token::Group as Token>::peek,
+        <token::Paren as Token>::peek,
+        <Lit as Token>::peek,
+        <Ident as Token>::peek,
+        <Token![::] as Token>::peek,
+        <Token![<] as Token>::peek,
+        <Token![-] as Token>::peek,
+        <Token![!] as Token>::peek,
+        <Token![*] as Token>::peek,
+        <Token![crate] as Token>::peek,
+        <Token![self] as Token>::peek,
+        <Token![Self] as Token>::peek,
+        <Token![super] as Token>::peek,
+    ];
+
+    // Tokens that can start an expression only with the "full" feature.
+    const FULL_EXPR_START: &[fn(Cursor) -> bool] = &[
+        <token::Brace as Token>::peek,
+        <token::Bracket as Token>::peek,
+        <Lifetime as Token>::peek,
+        <Token![#] as Token>::peek,
+        <Token![&] as Token>::peek,
+        <Token![|] as Token>::peek,
+        <Token![..] as Token>::peek,
+        <Token![async] as Token>::peek,
+        <Token![box] as Token>::peek,
+        <Token![break] as Token>::peek,
+        <Token![continue] as Token>::peek,
+        <Token![for] as Token>::peek,
+        <Token![if] as Token>::peek,
+        <Token![let] as Token>::peek,
+        <Token![loop] as Token>::peek,
+        <Token![match] as Token>::peek,
+        <Token![move] as Token>::peek,
+        <Token![return] as Token>::peek,
+        <Token![static] as Token>::peek,
+        <Token![try] as Token>::peek,
+        <Token![unsafe] as Token>::peek,
+        <Token![while] as Token>::peek,
+        <Token![yield] as Token>::peek,
+    ];
+
+    impl PeekNode for Expr {
+        fn peek(cursor: Cursor) -> bool {
+            EXPR_START.iter().any(|peek| peek(cursor))
+                || cfg!(feature = "full") && FULL_EXPR_START.iter().any(|peek| peek(cursor))
+        }
+
+        fn display() -> &'static str {
+            "expression"
+        }
+    }
+
     #[cfg(feature = "full")]
     fn expr_no_struct(input: ParseStream) -> Result<Expr> {
         ambiguous_expr(input, AllowStruct(false))
@@ -0,0 +1,199 @@
+//! Insertion of the parentheses that a syntax tree needs in order to be
+//! printed.
+//!
+//! A syntax tree built programmatically can contain expressions that have no
+//! direct representation in source code. When an `Expr::Binary` for `a + b`
+//! is made the receiver of an `ExprMethodCall`, the tokens printed for the
+//! method call are `a + b.f()`, which Rust parses as `a + (b.f())`. Rather
+//! than remembering to wrap such expressions in `Expr::Paren` by hand, the
+//! [`FixupParens`] pass can be run over a tree once it is built to insert the
+//! parentheses that are missing.
+//!
+//! ```
+//! use quote::quote;
+//! use syn::{parse_quote, Expr, ExprMethodCall};
+//!
+//! let sum: Expr = parse_quote!(a + b);
+//! let mut call: ExprMethodCall = parse_quote!(x.count_ones());
+//! call.receiver = Box::new(sum);
+//!
+//! let mut expr = Expr::MethodCall(call);
+//! assert_eq!(quote!(#expr).to_string(), "a + b . count_ones ()");
+//!
+//! expr.fixup_parens();
+//! assert_eq!(quote!(#expr).to_string(), "(a + b) . count_ones ()");
+//! ```
+//!
+//! Only the parentheses needed to preserve the structure of the tree are
+//! inserted, along the same rules that the parser uses to decide precedence
+//! and associativity. Parentheses that are already in the tree, as
+//! `Expr::Paren`, are left alone. Besides operator precedence this covers:
+//!
+//! - Struct literals in the head of `if`, `while`, `match` and `for`, as in
+//!   `if (S { a } == s) {}`, where they would otherwise be taken as the body.
+//! - Expression statements that begin with a block-like expression followed
+//!   by an operator, as in `(match x {}) + 1;`, which would otherwise end at
+//!   the closing brace.
+//! - Casts on the left of `<` and `<<`, as in `(a as u8) < b`, which would
+//!   otherwise be taken as the start of generic arguments.
+//! - Calls of a named field, as in `(s.f)()`, which would otherwise be taken
+//!   as a method call.
+//!
+//! *This module is available if Syn is built with the `"full"`, `"parsing"`,
+//! `"printing"` and `"visit-mut"` features.*
+
+use std::mem;
+
+use proc_macro2::TokenStream;
+
+use crate::expr::{
+    callee_needs_parens, contains_exposed_struct, left_needs_parens, prefix_needs_parens,
+    right_needs_parens, stmt_needs_parens, Precedence,
+};
+use crate::visit_mut::{self, VisitMut};
+use crate::{token, Expr, ExprParen, Stmt};
+
+/// A `VisitMut` pass that wraps in parentheses every expression that would
+/// otherwise not be printed as the part of the tree that it is.
+///
+/// Applying the pass to any node of a syntax tree fixes up all of the
+/// expressions within that node. [`Expr::fixup_parens`] is a shorthand for
+/// applying it to an expression.
+///
+/// ```
+/// use quote::quote;
+/// use syn::fixup::FixupParens;
+/// use syn::visit_mut::VisitMut;
+/// use syn::{parse_quote, Expr, ExprIf, Stmt};
+///
+/// let mut expr: ExprIf = parse_quote!(if s == t {});
+/// if let Expr::Binary(cond) = &mut *expr.cond {
+///     cond.right = Box::new(parse_quote!(S { a: 1 }));
+/// }
+///
+/// let mut stmt = Stmt::Expr(Expr::If(expr));
+/// FixupParens.visit_stmt_mut(&mut stmt);
+/// assert_eq!(quote!(#stmt).to_string(), "if (s == S { a : 1 }) { }");
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FixupParens;
+
+impl Expr {
+    /// Inserts the parentheses needed for this expression to be printed as
+    /// the syntax tree that it is.
+    ///
+    /// See the [`fixup`] module for details.
+    ///
+    /// *This method is available if Syn is built with the `"full"`,
+    /// `"parsing"`, `"printing"` and `"visit-mut"` features.*
+    ///
+    /// [`fixup`]: crate::fixup
+    pub fn fixup_parens(&mut self) {
+        FixupParens.visit_expr_mut(self);
+    }
+}
+
+impl VisitMut for FixupParens {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        visit_mut::visit_expr_mut(self, expr);
+        match expr {
+            Expr::Assign(e) => {
+                paren_if(&mut e.left, |left| {
+                    Precedence::of_expr(left) <= Precedence::Assign
+                });
+                paren_if(&mut e.right, |right| {
+                    Precedence::of_expr(right) < Precedence::Assign
+                });
+            }
+            Expr::AssignOp(e) => {
+                paren_if(&mut e.left, |left| {
+                    Precedence::of_expr(left) <= Precedence::Assign
+                });
+                paren_if(&mut e.right, |right| {
+                    Precedence::of_expr(right) < Precedence::Assign
+                });
+            }
+            Expr::Binary(e) => {
+                if left_needs_parens(e) {
+                    parenthesize(&mut e.left);
+                }
+                if right_needs_parens(e) {
+                    parenthesize(&mut e.right);
+                }
+            }
+            Expr::Box(e) => paren_if(&mut e.expr, prefix_needs_parens),
+            Expr::Reference(e) => paren_if(&mut e.expr, prefix_needs_parens),
+            Expr::Unary(e) => paren_if(&mut e.expr, prefix_needs_parens),
+            Expr::Call(e) => paren_if(&mut e.func, callee_needs_parens),
+            Expr::Await(e) => paren_if(&mut e.base, postfix_needs_parens),
+            Expr::Field(e) => paren_if(&mut e.base, postfix_needs_parens),
+            Expr::Index(e) => paren_if(&mut e.expr, postfix_needs_parens),
+            Expr::MethodCall(e) => paren_if(&mut e.receiver, postfix_needs_parens),
+            Expr::Try(e) => paren_if(&mut e.expr, postfix_needs_parens),
+            Expr::Cast(e) => paren_if(&mut e.expr, |expr| {
+                Precedence::of_expr(expr) < Precedence::Cast
+            }),
+            Expr::Type(e) => paren_if(&mut e.expr, |expr| {
+                Precedence::of_expr(expr) < Precedence::Cast
+            }),
+            Expr::Range(e) => {
+                let range_needs_parens =
+                    |expr: &Expr| Precedence::of_expr(expr) <= Precedence::Range;
+                if let Some(from) = &mut e.from {
+                    paren_if(from, range_needs_parens);
+                }
+                if let Some(to) = &mut e.to {
+                    paren_if(to, range_needs_parens);
+                }
+            }
+            Expr::Let(e) => paren_if(&mut e.expr, |expr| {
+                Precedence::of_expr(expr) <= Precedence::And
+            }),
+            Expr::If(e) => fixup_cond(&mut e.cond),
+            Expr::While(e) => fixup_cond(&mut e.cond),
+            Expr::ForLoop(e) => fixup_cond(&mut e.expr),
+            Expr::Match(e) => fixup_cond(&mut e.expr),
+            _ => {}
+        }
+    }
+
+    fn visit_stmt_mut(&mut self, stmt: &mut Stmt) {
+        visit_mut::visit_stmt_mut(self, stmt);
+        match stmt {
+            Stmt::Expr(expr) | Stmt::Semi(expr, _) => {
+                if stmt_needs_parens(expr) {
+                    parenthesize(expr);
+                }
+            }
+            Stmt::Local(_) | Stmt::Item(_) => {}
+        }
+    }
+}
+
+fn postfix_needs_parens(expr: &Expr) -> bool {
+    Precedence::of_expr(expr) < Precedence::Postfix
+}
+
+// Expressions in the head of `if`, `while`, `match` and `for` may not contain
+// a struct literal outside of parentheses.
+fn fixup_cond(cond: &mut Expr) {
+    match cond {
+        Expr::Let(e) => paren_if(&mut e.expr, contains_exposed_struct),
+        cond => paren_if(cond, contains_exposed_struct),
+    }
+}
+
+fn paren_if<F: FnOnce(&Expr) -> bool>(expr: &mut Expr, needs_parens: F) {
+    if needs_parens(expr) {
+        parenthesize(expr);
+    }
+}
+
+fn parenthesize(expr: &mut Expr) {
+    let inner = mem::replace(expr, Expr::Verbatim(TokenStream::new()));
+    *expr = Expr::Paren(ExprParen {
+        attrs: Vec::new(),
+        paren_token: token::Paren::default(),
+        expr: Box::new(inner),
+    });
+}
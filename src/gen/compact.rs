@@ -0,0 +1,2471 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+use crate::compact::{CompactDebug, Sexpr};
+use crate::sealed::compact::Sealed;
+use crate::*;
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Abi {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Abi {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Abi");
+        node.field("name", self.name.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for AngleBracketedGenericArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for AngleBracketedGenericArguments {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("AngleBracketedGenericArguments");
+        node.field(
+            "colon2_token",
+            Sexpr::flag(self.colon2_token.is_some(), "::"),
+        );
+        node.field("args", self.args.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Arm {}
+#[cfg(feature = "full")]
+impl CompactDebug for Arm {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Arm");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("pat", self.pat.sexpr());
+        node.field(
+            "guard",
+            self.guard
+                .as_ref()
+                .map_or_else(Sexpr::none, |val| val.1.sexpr()),
+        );
+        node.field("body", self.body.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for AttrStyle {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for AttrStyle {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            AttrStyle::Outer => Sexpr::atom("Outer"),
+            AttrStyle::Inner(_v0) => Sexpr::atom("Inner"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Attribute {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Attribute {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Attribute");
+        node.field("style", self.style.sexpr());
+        node.field("path", self.path.sexpr());
+        node.field("tokens", self.tokens.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BareFnArg {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for BareFnArg {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("BareFnArg");
+        node.field("attrs", self.attrs.sexpr());
+        node.field(
+            "name",
+            self.name
+                .as_ref()
+                .map_or_else(Sexpr::none, |val| val.0.sexpr()),
+        );
+        node.field("ty", self.ty.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BinOp {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for BinOp {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            BinOp::Add(_v0) => Sexpr::atom("Add"),
+            BinOp::Sub(_v0) => Sexpr::atom("Sub"),
+            BinOp::Mul(_v0) => Sexpr::atom("Mul"),
+            BinOp::Div(_v0) => Sexpr::atom("Div"),
+            BinOp::Rem(_v0) => Sexpr::atom("Rem"),
+            BinOp::And(_v0) => Sexpr::atom("And"),
+            BinOp::Or(_v0) => Sexpr::atom("Or"),
+            BinOp::BitXor(_v0) => Sexpr::atom("BitXor"),
+            BinOp::BitAnd(_v0) => Sexpr::atom("BitAnd"),
+            BinOp::BitOr(_v0) => Sexpr::atom("BitOr"),
+            BinOp::Shl(_v0) => Sexpr::atom("Shl"),
+            BinOp::Shr(_v0) => Sexpr::atom("Shr"),
+            BinOp::Eq(_v0) => Sexpr::atom("Eq"),
+            BinOp::Lt(_v0) => Sexpr::atom("Lt"),
+            BinOp::Le(_v0) => Sexpr::atom("Le"),
+            BinOp::Ne(_v0) => Sexpr::atom("Ne"),
+            BinOp::Ge(_v0) => Sexpr::atom("Ge"),
+            BinOp::Gt(_v0) => Sexpr::atom("Gt"),
+            BinOp::AddEq(_v0) => Sexpr::atom("AddEq"),
+            BinOp::SubEq(_v0) => Sexpr::atom("SubEq"),
+            BinOp::MulEq(_v0) => Sexpr::atom("MulEq"),
+            BinOp::DivEq(_v0) => Sexpr::atom("DivEq"),
+            BinOp::RemEq(_v0) => Sexpr::atom("RemEq"),
+            BinOp::BitXorEq(_v0) => Sexpr::atom("BitXorEq"),
+            BinOp::BitAndEq(_v0) => Sexpr::atom("BitAndEq"),
+            BinOp::BitOrEq(_v0) => Sexpr::atom("BitOrEq"),
+            BinOp::ShlEq(_v0) => Sexpr::atom("ShlEq"),
+            BinOp::ShrEq(_v0) => Sexpr::atom("ShrEq"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Binding {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Binding {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Binding");
+        node.field("ident", self.ident.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Block {}
+#[cfg(feature = "full")]
+impl CompactDebug for Block {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Block");
+        node.field("stmts", self.stmts.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BoundLifetimes {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for BoundLifetimes {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("BoundLifetimes");
+        node.field("lifetimes", self.lifetimes.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ConstParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ConstParam {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ConstParam");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node.field("default", self.default.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Constraint {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Constraint {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Constraint");
+        node.field("ident", self.ident.sexpr());
+        node.field("bounds", self.bounds.sexpr());
+        node
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for Data {}
+#[cfg(feature = "derive")]
+impl CompactDebug for Data {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            Data::Struct(_v0) => _v0.sexpr(),
+            Data::Enum(_v0) => _v0.sexpr(),
+            Data::Union(_v0) => _v0.sexpr(),
+        }
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataEnum {}
+#[cfg(feature = "derive")]
+impl CompactDebug for DataEnum {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("DataEnum");
+        node.field("variants", self.variants.sexpr());
+        node
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataStruct {}
+#[cfg(feature = "derive")]
+impl CompactDebug for DataStruct {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("DataStruct");
+        node.field("fields", self.fields.sexpr());
+        node
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataUnion {}
+#[cfg(feature = "derive")]
+impl CompactDebug for DataUnion {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("DataUnion");
+        node.field("fields", self.fields.sexpr());
+        node
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DeriveInput {}
+#[cfg(feature = "derive")]
+impl CompactDebug for DeriveInput {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("DeriveInput");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("generics", self.generics.sexpr());
+        node.field("data", self.data.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Expr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Expr {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            #[cfg(feature = "full")]
+            Expr::Array(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Assign(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::AssignOp(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Async(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Await(_v0) => _v0.sexpr(),
+            Expr::Binary(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Block(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Box(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Break(_v0) => _v0.sexpr(),
+            Expr::Call(_v0) => _v0.sexpr(),
+            Expr::Cast(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Closure(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Continue(_v0) => _v0.sexpr(),
+            Expr::Field(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::ForLoop(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Group(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::If(_v0) => _v0.sexpr(),
+            Expr::Index(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Let(_v0) => _v0.sexpr(),
+            Expr::Lit(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Loop(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Macro(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Match(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::MethodCall(_v0) => _v0.sexpr(),
+            Expr::Paren(_v0) => _v0.sexpr(),
+            Expr::Path(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Range(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Reference(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Repeat(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Return(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Struct(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Try(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::TryBlock(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Tuple(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Type(_v0) => _v0.sexpr(),
+            Expr::Unary(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Unsafe(_v0) => _v0.sexpr(),
+            Expr::Verbatim(_v0) => {
+                let mut node = Sexpr::node("Expr::Verbatim");
+                node.arg(_v0.sexpr());
+                node
+            }
+            #[cfg(feature = "full")]
+            Expr::While(_v0) => _v0.sexpr(),
+            #[cfg(feature = "full")]
+            Expr::Yield(_v0) => _v0.sexpr(),
+            Expr::__Nonexhaustive => unreachable!(),
+            #[cfg(not(feature = "full"))]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprArray {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprArray {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprArray");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("elems", self.elems.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAssign {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprAssign {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprAssign");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("left", self.left.sexpr());
+        node.field("right", self.right.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAssignOp {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprAssignOp {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprAssignOp");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("left", self.left.sexpr());
+        node.field("op", self.op.sexpr());
+        node.field("right", self.right.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAsync {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprAsync {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprAsync");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("capture", Sexpr::flag(self.capture.is_some(), "move"));
+        node.field("block", self.block.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAwait {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprAwait {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprAwait");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("base", self.base.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprBinary {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ExprBinary {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprBinary");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("left", self.left.sexpr());
+        node.field("op", self.op.sexpr());
+        node.field("right", self.right.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBlock {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprBlock {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprBlock");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("label", self.label.sexpr());
+        node.field("block", self.block.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBox {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprBox {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprBox");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBreak {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprBreak {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprBreak");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("label", self.label.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprCall {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ExprCall {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprCall");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("func", self.func.sexpr());
+        node.field("args", self.args.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprCast {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ExprCast {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprCast");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprClosure {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprClosure {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprClosure");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("asyncness", Sexpr::flag(self.asyncness.is_some(), "async"));
+        node.field(
+            "movability",
+            Sexpr::flag(self.movability.is_some(), "static"),
+        );
+        node.field("capture", Sexpr::flag(self.capture.is_some(), "move"));
+        node.field("inputs", self.inputs.sexpr());
+        node.field("output", self.output.sexpr());
+        node.field("body", self.body.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprContinue {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprContinue {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprContinue");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("label", self.label.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprField {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ExprField {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprField");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("base", self.base.sexpr());
+        node.field("member", self.member.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprForLoop {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprForLoop {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprForLoop");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("label", self.label.sexpr());
+        node.field("pat", self.pat.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node.field("body", self.body.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprGroup {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprGroup {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprGroup");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprIf {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprIf {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprIf");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("cond", self.cond.sexpr());
+        node.field("then_branch", self.then_branch.sexpr());
+        node.field(
+            "else_branch",
+            self.else_branch
+                .as_ref()
+                .map_or_else(Sexpr::none, |val| val.1.sexpr()),
+        );
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprIndex {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ExprIndex {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprIndex");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node.field("index", self.index.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprLet {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprLet {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprLet");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("pat", self.pat.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprLit {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ExprLit {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprLit");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("lit", self.lit.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprLoop {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprLoop {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprLoop");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("label", self.label.sexpr());
+        node.field("body", self.body.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMacro {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprMacro {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprMacro");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("mac", self.mac.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMatch {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprMatch {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprMatch");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node.field("arms", self.arms.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMethodCall {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprMethodCall {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprMethodCall");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("receiver", self.receiver.sexpr());
+        node.field("method", self.method.sexpr());
+        node.field("turbofish", self.turbofish.sexpr());
+        node.field("args", self.args.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprParen {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ExprParen {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprParen");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprPath {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ExprPath {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprPath");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("qself", self.qself.sexpr());
+        node.field("path", self.path.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprRange {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprRange {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprRange");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("from", self.from.sexpr());
+        node.field("limits", self.limits.sexpr());
+        node.field("to", self.to.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprReference {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprReference {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprReference");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("mutability", Sexpr::flag(self.mutability.is_some(), "mut"));
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprRepeat {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprRepeat {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprRepeat");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node.field("len", self.len.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprReturn {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprReturn {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprReturn");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprStruct {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprStruct {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprStruct");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("path", self.path.sexpr());
+        node.field("fields", self.fields.sexpr());
+        node.field("dot2_token", Sexpr::flag(self.dot2_token.is_some(), ".."));
+        node.field("rest", self.rest.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTry {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprTry {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprTry");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTryBlock {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprTryBlock {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprTryBlock");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("block", self.block.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTuple {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprTuple {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprTuple");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("elems", self.elems.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprType {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprType {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprType");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprUnary {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ExprUnary {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprUnary");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("op", self.op.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprUnsafe {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprUnsafe {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprUnsafe");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("block", self.block.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprWhile {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprWhile {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprWhile");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("label", self.label.sexpr());
+        node.field("cond", self.cond.sexpr());
+        node.field("body", self.body.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprYield {}
+#[cfg(feature = "full")]
+impl CompactDebug for ExprYield {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ExprYield");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Field {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Field {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Field");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FieldPat {}
+#[cfg(feature = "full")]
+impl CompactDebug for FieldPat {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("FieldPat");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("member", self.member.sexpr());
+        node.field("pat", self.pat.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FieldValue {}
+#[cfg(feature = "full")]
+impl CompactDebug for FieldValue {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("FieldValue");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("member", self.member.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Fields {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Fields {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            Fields::Named(_v0) => _v0.sexpr(),
+            Fields::Unnamed(_v0) => _v0.sexpr(),
+            Fields::Unit => Sexpr::atom("Unit"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for FieldsNamed {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for FieldsNamed {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("FieldsNamed");
+        node.field("named", self.named.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for FieldsUnnamed {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for FieldsUnnamed {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("FieldsUnnamed");
+        node.field("unnamed", self.unnamed.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for File {}
+#[cfg(feature = "full")]
+impl CompactDebug for File {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("File");
+        node.field("shebang", self.shebang.sexpr());
+        node.field("attrs", self.attrs.sexpr());
+        node.field("items", self.items.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FnArg {}
+#[cfg(feature = "full")]
+impl CompactDebug for FnArg {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            FnArg::Receiver(_v0) => _v0.sexpr(),
+            FnArg::Typed(_v0) => _v0.sexpr(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItem {}
+#[cfg(feature = "full")]
+impl CompactDebug for ForeignItem {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            ForeignItem::Fn(_v0) => _v0.sexpr(),
+            ForeignItem::Static(_v0) => _v0.sexpr(),
+            ForeignItem::Type(_v0) => _v0.sexpr(),
+            ForeignItem::Macro(_v0) => _v0.sexpr(),
+            ForeignItem::Verbatim(_v0) => {
+                let mut node = Sexpr::node("ForeignItem::Verbatim");
+                node.arg(_v0.sexpr());
+                node
+            }
+            ForeignItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemFn {}
+#[cfg(feature = "full")]
+impl CompactDebug for ForeignItemFn {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ForeignItemFn");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("sig", self.sig.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemMacro {}
+#[cfg(feature = "full")]
+impl CompactDebug for ForeignItemMacro {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ForeignItemMacro");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("mac", self.mac.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemStatic {}
+#[cfg(feature = "full")]
+impl CompactDebug for ForeignItemStatic {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ForeignItemStatic");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("mutability", Sexpr::flag(self.mutability.is_some(), "mut"));
+        node.field("ident", self.ident.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemType {}
+#[cfg(feature = "full")]
+impl CompactDebug for ForeignItemType {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ForeignItemType");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for GenericArgument {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for GenericArgument {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            GenericArgument::Lifetime(_v0) => _v0.sexpr(),
+            GenericArgument::Type(_v0) => {
+                let mut node = Sexpr::node("GenericArgument::Type");
+                node.arg(_v0.sexpr());
+                node
+            }
+            GenericArgument::Binding(_v0) => _v0.sexpr(),
+            GenericArgument::Constraint(_v0) => _v0.sexpr(),
+            GenericArgument::Const(_v0) => {
+                let mut node = Sexpr::node("GenericArgument::Const");
+                node.arg(_v0.sexpr());
+                node
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for GenericMethodArgument {}
+#[cfg(feature = "full")]
+impl CompactDebug for GenericMethodArgument {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            GenericMethodArgument::Type(_v0) => {
+                let mut node = Sexpr::node("GenericMethodArgument::Type");
+                node.arg(_v0.sexpr());
+                node
+            }
+            GenericMethodArgument::Const(_v0) => {
+                let mut node = Sexpr::node("GenericMethodArgument::Const");
+                node.arg(_v0.sexpr());
+                node
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for GenericParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for GenericParam {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            GenericParam::Type(_v0) => _v0.sexpr(),
+            GenericParam::Lifetime(_v0) => _v0.sexpr(),
+            GenericParam::Const(_v0) => _v0.sexpr(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Generics {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Generics {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Generics");
+        node.field("params", self.params.sexpr());
+        node.field("where_clause", self.where_clause.sexpr());
+        node.default_if_empty()
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItem {}
+#[cfg(feature = "full")]
+impl CompactDebug for ImplItem {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            ImplItem::Const(_v0) => _v0.sexpr(),
+            ImplItem::Method(_v0) => _v0.sexpr(),
+            ImplItem::Type(_v0) => _v0.sexpr(),
+            ImplItem::Macro(_v0) => _v0.sexpr(),
+            ImplItem::Verbatim(_v0) => {
+                let mut node = Sexpr::node("ImplItem::Verbatim");
+                node.arg(_v0.sexpr());
+                node
+            }
+            ImplItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemConst {}
+#[cfg(feature = "full")]
+impl CompactDebug for ImplItemConst {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ImplItemConst");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field(
+            "defaultness",
+            Sexpr::flag(self.defaultness.is_some(), "default"),
+        );
+        node.field("ident", self.ident.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemMacro {}
+#[cfg(feature = "full")]
+impl CompactDebug for ImplItemMacro {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ImplItemMacro");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("mac", self.mac.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemMethod {}
+#[cfg(feature = "full")]
+impl CompactDebug for ImplItemMethod {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ImplItemMethod");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field(
+            "defaultness",
+            Sexpr::flag(self.defaultness.is_some(), "default"),
+        );
+        node.field("sig", self.sig.sexpr());
+        node.field("block", self.block.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemType {}
+#[cfg(feature = "full")]
+impl CompactDebug for ImplItemType {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ImplItemType");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field(
+            "defaultness",
+            Sexpr::flag(self.defaultness.is_some(), "default"),
+        );
+        node.field("ident", self.ident.sexpr());
+        node.field("generics", self.generics.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Index {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Index {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Index");
+        node.field("index", self.index.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Item {}
+#[cfg(feature = "full")]
+impl CompactDebug for Item {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            Item::Const(_v0) => _v0.sexpr(),
+            Item::Enum(_v0) => _v0.sexpr(),
+            Item::ExternCrate(_v0) => _v0.sexpr(),
+            Item::Fn(_v0) => _v0.sexpr(),
+            Item::ForeignMod(_v0) => _v0.sexpr(),
+            Item::Impl(_v0) => _v0.sexpr(),
+            Item::Macro(_v0) => _v0.sexpr(),
+            Item::Macro2(_v0) => _v0.sexpr(),
+            Item::Mod(_v0) => _v0.sexpr(),
+            Item::Static(_v0) => _v0.sexpr(),
+            Item::Struct(_v0) => _v0.sexpr(),
+            Item::Trait(_v0) => _v0.sexpr(),
+            Item::TraitAlias(_v0) => _v0.sexpr(),
+            Item::Type(_v0) => _v0.sexpr(),
+            Item::Union(_v0) => _v0.sexpr(),
+            Item::Use(_v0) => _v0.sexpr(),
+            Item::Verbatim(_v0) => {
+                let mut node = Sexpr::node("Item::Verbatim");
+                node.arg(_v0.sexpr());
+                node
+            }
+            Item::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemConst {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemConst {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemConst");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemEnum {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemEnum {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemEnum");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("generics", self.generics.sexpr());
+        node.field("variants", self.variants.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemExternCrate {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemExternCrate {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemExternCrate");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field(
+            "rename",
+            self.rename
+                .as_ref()
+                .map_or_else(Sexpr::none, |val| val.1.sexpr()),
+        );
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemFn {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemFn {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemFn");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("sig", self.sig.sexpr());
+        node.field("block", self.block.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemForeignMod {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemForeignMod {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemForeignMod");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("abi", self.abi.sexpr());
+        node.field("items", self.items.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemImpl {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemImpl {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemImpl");
+        node.field("attrs", self.attrs.sexpr());
+        node.field(
+            "defaultness",
+            Sexpr::flag(self.defaultness.is_some(), "default"),
+        );
+        node.field("unsafety", Sexpr::flag(self.unsafety.is_some(), "unsafe"));
+        node.field("generics", self.generics.sexpr());
+        node.field(
+            "trait_",
+            self.trait_.as_ref().map_or_else(Sexpr::none, |val| {
+                Sexpr::tuple(vec![Sexpr::flag(val.0.is_some(), "!"), val.1.sexpr()])
+            }),
+        );
+        node.field("self_ty", self.self_ty.sexpr());
+        node.field("items", self.items.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMacro {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemMacro {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemMacro");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("mac", self.mac.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMacro2 {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemMacro2 {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemMacro2");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("rules", self.rules.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMod {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemMod {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemMod");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field(
+            "content",
+            self.content
+                .as_ref()
+                .map_or_else(Sexpr::none, |val| val.1.sexpr()),
+        );
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemStatic {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemStatic {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemStatic");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("mutability", Sexpr::flag(self.mutability.is_some(), "mut"));
+        node.field("ident", self.ident.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemStruct {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemStruct {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemStruct");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("generics", self.generics.sexpr());
+        node.field("fields", self.fields.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemTrait {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemTrait {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemTrait");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("unsafety", Sexpr::flag(self.unsafety.is_some(), "unsafe"));
+        node.field("auto_token", Sexpr::flag(self.auto_token.is_some(), "auto"));
+        node.field("ident", self.ident.sexpr());
+        node.field("generics", self.generics.sexpr());
+        node.field("supertraits", self.supertraits.sexpr());
+        node.field("items", self.items.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemTraitAlias {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemTraitAlias {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemTraitAlias");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("generics", self.generics.sexpr());
+        node.field("bounds", self.bounds.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemType {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemType {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemType");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("generics", self.generics.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemUnion {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemUnion {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemUnion");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("generics", self.generics.sexpr());
+        node.field("fields", self.fields.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemUse {}
+#[cfg(feature = "full")]
+impl CompactDebug for ItemUse {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ItemUse");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("vis", self.vis.sexpr());
+        node.field(
+            "leading_colon",
+            Sexpr::flag(self.leading_colon.is_some(), "::"),
+        );
+        node.field("tree", self.tree.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Label {}
+#[cfg(feature = "full")]
+impl CompactDebug for Label {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Label");
+        node.field("name", self.name.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LifetimeDef {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for LifetimeDef {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("LifetimeDef");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("lifetime", self.lifetime.sexpr());
+        node.field("bounds", self.bounds.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Lit {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Lit {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            Lit::Str(_v0) => _v0.sexpr(),
+            Lit::ByteStr(_v0) => _v0.sexpr(),
+            Lit::Byte(_v0) => _v0.sexpr(),
+            Lit::Char(_v0) => _v0.sexpr(),
+            Lit::Int(_v0) => _v0.sexpr(),
+            Lit::Float(_v0) => _v0.sexpr(),
+            Lit::Bool(_v0) => _v0.sexpr(),
+            Lit::Verbatim(_v0) => {
+                let mut node = Sexpr::node("Lit::Verbatim");
+                node.arg(_v0.sexpr());
+                node
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Local {}
+#[cfg(feature = "full")]
+impl CompactDebug for Local {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Local");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("pat", self.pat.sexpr());
+        node.field(
+            "init",
+            self.init
+                .as_ref()
+                .map_or_else(Sexpr::none, |val| val.1.sexpr()),
+        );
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Macro {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Macro {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Macro");
+        node.field("path", self.path.sexpr());
+        node.field("delimiter", self.delimiter.sexpr());
+        node.field("tokens", self.tokens.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MacroDelimiter {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for MacroDelimiter {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            MacroDelimiter::Paren(_v0) => Sexpr::atom("Paren"),
+            MacroDelimiter::Brace(_v0) => Sexpr::atom("Brace"),
+            MacroDelimiter::Bracket(_v0) => Sexpr::atom("Bracket"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Member {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Member {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            Member::Named(_v0) => {
+                let mut node = Sexpr::node("Member::Named");
+                node.arg(_v0.sexpr());
+                node
+            }
+            Member::Unnamed(_v0) => _v0.sexpr(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Meta {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Meta {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            Meta::Path(_v0) => _v0.sexpr(),
+            Meta::List(_v0) => _v0.sexpr(),
+            Meta::NameValue(_v0) => _v0.sexpr(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExpr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for MetaExpr {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            MetaExpr::Path(_v0) => _v0.sexpr(),
+            MetaExpr::List(_v0) => _v0.sexpr(),
+            MetaExpr::NameValue(_v0) => _v0.sexpr(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExprList {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for MetaExprList {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("MetaExprList");
+        node.field("path", self.path.sexpr());
+        node.field("nested", self.nested.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExprNameValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for MetaExprNameValue {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("MetaExprNameValue");
+        node.field("path", self.path.sexpr());
+        node.field("value", self.value.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaList {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for MetaList {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("MetaList");
+        node.field("path", self.path.sexpr());
+        node.field("nested", self.nested.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaNameValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for MetaNameValue {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("MetaNameValue");
+        node.field("path", self.path.sexpr());
+        node.field("lit", self.lit.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for MetaValue {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            MetaValue::Expr(_v0) => {
+                let mut node = Sexpr::node("MetaValue::Expr");
+                node.arg(_v0.sexpr());
+                node
+            }
+            MetaValue::Verbatim(_v0) => {
+                let mut node = Sexpr::node("MetaValue::Verbatim");
+                node.arg(_v0.sexpr());
+                node
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for MethodTurbofish {}
+#[cfg(feature = "full")]
+impl CompactDebug for MethodTurbofish {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("MethodTurbofish");
+        node.field("args", self.args.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for NestedMeta {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for NestedMeta {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            NestedMeta::Meta(_v0) => {
+                let mut node = Sexpr::node("NestedMeta::Meta");
+                node.arg(_v0.sexpr());
+                node
+            }
+            NestedMeta::Lit(_v0) => {
+                let mut node = Sexpr::node("NestedMeta::Lit");
+                node.arg(_v0.sexpr());
+                node
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for NestedMetaExpr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for NestedMetaExpr {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            NestedMetaExpr::Meta(_v0) => {
+                let mut node = Sexpr::node("NestedMetaExpr::Meta");
+                node.arg(_v0.sexpr());
+                node
+            }
+            NestedMetaExpr::Lit(_v0) => {
+                let mut node = Sexpr::node("NestedMetaExpr::Lit");
+                node.arg(_v0.sexpr());
+                node
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ParenthesizedGenericArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ParenthesizedGenericArguments {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("ParenthesizedGenericArguments");
+        node.field("inputs", self.inputs.sexpr());
+        node.field("output", self.output.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Pat {}
+#[cfg(feature = "full")]
+impl CompactDebug for Pat {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            Pat::Box(_v0) => _v0.sexpr(),
+            Pat::Ident(_v0) => _v0.sexpr(),
+            Pat::Lit(_v0) => _v0.sexpr(),
+            Pat::Macro(_v0) => _v0.sexpr(),
+            Pat::Or(_v0) => _v0.sexpr(),
+            Pat::Path(_v0) => _v0.sexpr(),
+            Pat::Range(_v0) => _v0.sexpr(),
+            Pat::Reference(_v0) => _v0.sexpr(),
+            Pat::Rest(_v0) => _v0.sexpr(),
+            Pat::Slice(_v0) => _v0.sexpr(),
+            Pat::Struct(_v0) => _v0.sexpr(),
+            Pat::Tuple(_v0) => _v0.sexpr(),
+            Pat::TupleStruct(_v0) => _v0.sexpr(),
+            Pat::Type(_v0) => _v0.sexpr(),
+            Pat::Verbatim(_v0) => {
+                let mut node = Sexpr::node("Pat::Verbatim");
+                node.arg(_v0.sexpr());
+                node
+            }
+            Pat::Wild(_v0) => _v0.sexpr(),
+            Pat::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatBox {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatBox {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatBox");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("pat", self.pat.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatIdent {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatIdent {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatIdent");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("by_ref", Sexpr::flag(self.by_ref.is_some(), "ref"));
+        node.field("mutability", Sexpr::flag(self.mutability.is_some(), "mut"));
+        node.field("ident", self.ident.sexpr());
+        node.field(
+            "subpat",
+            self.subpat
+                .as_ref()
+                .map_or_else(Sexpr::none, |val| val.1.sexpr()),
+        );
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatLit {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatLit {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatLit");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("expr", self.expr.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatMacro {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatMacro {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatMacro");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("mac", self.mac.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatOr {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatOr {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatOr");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("cases", self.cases.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatPath {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatPath {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatPath");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("qself", self.qself.sexpr());
+        node.field("path", self.path.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatRange {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatRange {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatRange");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("lo", self.lo.sexpr());
+        node.field("limits", self.limits.sexpr());
+        node.field("hi", self.hi.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatReference {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatReference {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatReference");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("mutability", Sexpr::flag(self.mutability.is_some(), "mut"));
+        node.field("pat", self.pat.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatRest {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatRest {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatRest");
+        node.field("attrs", self.attrs.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatSlice {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatSlice {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatSlice");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("elems", self.elems.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatStruct {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatStruct {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatStruct");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("path", self.path.sexpr());
+        node.field("fields", self.fields.sexpr());
+        node.field("dot2_token", Sexpr::flag(self.dot2_token.is_some(), ".."));
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatTuple {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatTuple {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatTuple");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("elems", self.elems.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatTupleStruct {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatTupleStruct {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatTupleStruct");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("path", self.path.sexpr());
+        node.field("pat", self.pat.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatType {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatType {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatType");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("pat", self.pat.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatWild {}
+#[cfg(feature = "full")]
+impl CompactDebug for PatWild {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PatWild");
+        node.field("attrs", self.attrs.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PathArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for PathArguments {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            PathArguments::None => Sexpr::atom("None").into_default(),
+            PathArguments::AngleBracketed(_v0) => _v0.sexpr(),
+            PathArguments::Parenthesized(_v0) => _v0.sexpr(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PathSegment {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for PathSegment {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PathSegment");
+        node.field("ident", self.ident.sexpr());
+        node.field("arguments", self.arguments.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateEq {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for PredicateEq {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PredicateEq");
+        node.field("lhs_ty", self.lhs_ty.sexpr());
+        node.field("rhs_ty", self.rhs_ty.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateLifetime {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for PredicateLifetime {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PredicateLifetime");
+        node.field("lifetime", self.lifetime.sexpr());
+        node.field("bounds", self.bounds.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateType {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for PredicateType {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("PredicateType");
+        node.field("lifetimes", self.lifetimes.sexpr());
+        node.field("bounded_ty", self.bounded_ty.sexpr());
+        node.field("bounds", self.bounds.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for QSelf {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for QSelf {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("QSelf");
+        node.field("ty", self.ty.sexpr());
+        node.field("position", self.position.sexpr());
+        node.field("as_token", Sexpr::flag(self.as_token.is_some(), "as"));
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for RangeLimits {}
+#[cfg(feature = "full")]
+impl CompactDebug for RangeLimits {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            RangeLimits::HalfOpen(_v0) => Sexpr::atom("HalfOpen"),
+            RangeLimits::Closed(_v0) => Sexpr::atom("Closed"),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Receiver {}
+#[cfg(feature = "full")]
+impl CompactDebug for Receiver {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Receiver");
+        node.field("attrs", self.attrs.sexpr());
+        node.field(
+            "reference",
+            self.reference
+                .as_ref()
+                .map_or_else(Sexpr::none, |val| val.1.sexpr()),
+        );
+        node.field("mutability", Sexpr::flag(self.mutability.is_some(), "mut"));
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ReturnType {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for ReturnType {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            ReturnType::Default => Sexpr::atom("Default").into_default(),
+            ReturnType::Type(_v0, _v1) => {
+                let mut node = Sexpr::node("ReturnType::Type");
+                node.arg(_v1.sexpr());
+                node
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Signature {}
+#[cfg(feature = "full")]
+impl CompactDebug for Signature {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Signature");
+        node.field("constness", Sexpr::flag(self.constness.is_some(), "const"));
+        node.field("asyncness", Sexpr::flag(self.asyncness.is_some(), "async"));
+        node.field("unsafety", Sexpr::flag(self.unsafety.is_some(), "unsafe"));
+        node.field("abi", self.abi.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("generics", self.generics.sexpr());
+        node.field("inputs", self.inputs.sexpr());
+        node.field("variadic", self.variadic.sexpr());
+        node.field("output", self.output.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Stmt {}
+#[cfg(feature = "full")]
+impl CompactDebug for Stmt {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            Stmt::Local(_v0) => _v0.sexpr(),
+            Stmt::Item(_v0) => {
+                let mut node = Sexpr::node("Stmt::Item");
+                node.arg(_v0.sexpr());
+                node
+            }
+            Stmt::Expr(_v0) => {
+                let mut node = Sexpr::node("Stmt::Expr");
+                node.arg(_v0.sexpr());
+                node
+            }
+            Stmt::Semi(_v0, _v1) => {
+                let mut node = Sexpr::node("Stmt::Semi");
+                node.arg(_v0.sexpr());
+                node
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TraitBound {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TraitBound {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TraitBound");
+        node.field("paren_token", Sexpr::flag(self.paren_token.is_some(), "()"));
+        node.field("modifier", self.modifier.sexpr());
+        node.field("lifetimes", self.lifetimes.sexpr());
+        node.field("path", self.path.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TraitBoundModifier {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TraitBoundModifier {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            TraitBoundModifier::None => Sexpr::atom("None"),
+            TraitBoundModifier::Maybe(_v0) => Sexpr::atom("Maybe"),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItem {}
+#[cfg(feature = "full")]
+impl CompactDebug for TraitItem {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            TraitItem::Const(_v0) => _v0.sexpr(),
+            TraitItem::Method(_v0) => _v0.sexpr(),
+            TraitItem::Type(_v0) => _v0.sexpr(),
+            TraitItem::Macro(_v0) => _v0.sexpr(),
+            TraitItem::Verbatim(_v0) => {
+                let mut node = Sexpr::node("TraitItem::Verbatim");
+                node.arg(_v0.sexpr());
+                node
+            }
+            TraitItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemConst {}
+#[cfg(feature = "full")]
+impl CompactDebug for TraitItemConst {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TraitItemConst");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("ty", self.ty.sexpr());
+        node.field(
+            "default",
+            self.default
+                .as_ref()
+                .map_or_else(Sexpr::none, |val| val.1.sexpr()),
+        );
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemMacro {}
+#[cfg(feature = "full")]
+impl CompactDebug for TraitItemMacro {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TraitItemMacro");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("mac", self.mac.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemMethod {}
+#[cfg(feature = "full")]
+impl CompactDebug for TraitItemMethod {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TraitItemMethod");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("sig", self.sig.sexpr());
+        node.field("default", self.default.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemType {}
+#[cfg(feature = "full")]
+impl CompactDebug for TraitItemType {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TraitItemType");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("generics", self.generics.sexpr());
+        node.field("bounds", self.bounds.sexpr());
+        node.field(
+            "default",
+            self.default
+                .as_ref()
+                .map_or_else(Sexpr::none, |val| val.1.sexpr()),
+        );
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Type {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Type {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            Type::Array(_v0) => _v0.sexpr(),
+            Type::BareFn(_v0) => _v0.sexpr(),
+            Type::Group(_v0) => _v0.sexpr(),
+            Type::ImplTrait(_v0) => _v0.sexpr(),
+            Type::Infer(_v0) => _v0.sexpr(),
+            Type::Macro(_v0) => _v0.sexpr(),
+            Type::Never(_v0) => _v0.sexpr(),
+            Type::Paren(_v0) => _v0.sexpr(),
+            Type::Path(_v0) => _v0.sexpr(),
+            Type::Ptr(_v0) => _v0.sexpr(),
+            Type::Reference(_v0) => _v0.sexpr(),
+            Type::Slice(_v0) => _v0.sexpr(),
+            Type::TraitObject(_v0) => _v0.sexpr(),
+            Type::Tuple(_v0) => _v0.sexpr(),
+            Type::Verbatim(_v0) => {
+                let mut node = Sexpr::node("Type::Verbatim");
+                node.arg(_v0.sexpr());
+                node
+            }
+            Type::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeArray {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeArray {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypeArray");
+        node.field("elem", self.elem.sexpr());
+        node.field("len", self.len.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeBareFn {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeBareFn {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypeBareFn");
+        node.field("lifetimes", self.lifetimes.sexpr());
+        node.field("unsafety", Sexpr::flag(self.unsafety.is_some(), "unsafe"));
+        node.field("abi", self.abi.sexpr());
+        node.field("inputs", self.inputs.sexpr());
+        node.field("variadic", Sexpr::flag(self.variadic.is_some(), "..."));
+        node.field("output", self.output.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeGroup {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeGroup {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypeGroup");
+        node.field("elem", self.elem.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeImplTrait {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeImplTrait {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypeImplTrait");
+        node.field("bounds", self.bounds.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeInfer {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeInfer {
+    fn sexpr(&self) -> Sexpr {
+        Sexpr::node("TypeInfer")
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeMacro {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeMacro {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypeMacro");
+        node.field("mac", self.mac.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeNever {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeNever {
+    fn sexpr(&self) -> Sexpr {
+        Sexpr::node("TypeNever")
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeParam {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypeParam");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("bounds", self.bounds.sexpr());
+        node.field("default", self.default.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParamBound {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeParamBound {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            TypeParamBound::Trait(_v0) => _v0.sexpr(),
+            TypeParamBound::Lifetime(_v0) => _v0.sexpr(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParen {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeParen {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypeParen");
+        node.field("elem", self.elem.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypePath {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypePath {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypePath");
+        node.field("qself", self.qself.sexpr());
+        node.field("path", self.path.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypePtr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypePtr {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypePtr");
+        node.field(
+            "const_token",
+            Sexpr::flag(self.const_token.is_some(), "const"),
+        );
+        node.field("mutability", Sexpr::flag(self.mutability.is_some(), "mut"));
+        node.field("elem", self.elem.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeReference {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeReference {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypeReference");
+        node.field("lifetime", self.lifetime.sexpr());
+        node.field("mutability", Sexpr::flag(self.mutability.is_some(), "mut"));
+        node.field("elem", self.elem.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeSlice {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeSlice {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypeSlice");
+        node.field("elem", self.elem.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeTraitObject {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeTraitObject {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypeTraitObject");
+        node.field("dyn_token", Sexpr::flag(self.dyn_token.is_some(), "dyn"));
+        node.field("bounds", self.bounds.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeTuple {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for TypeTuple {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("TypeTuple");
+        node.field("elems", self.elems.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for UnOp {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for UnOp {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            UnOp::Deref(_v0) => Sexpr::atom("Deref"),
+            UnOp::Not(_v0) => Sexpr::atom("Not"),
+            UnOp::Neg(_v0) => Sexpr::atom("Neg"),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseGlob {}
+#[cfg(feature = "full")]
+impl CompactDebug for UseGlob {
+    fn sexpr(&self) -> Sexpr {
+        Sexpr::node("UseGlob")
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseGroup {}
+#[cfg(feature = "full")]
+impl CompactDebug for UseGroup {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("UseGroup");
+        node.field("items", self.items.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseName {}
+#[cfg(feature = "full")]
+impl CompactDebug for UseName {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("UseName");
+        node.field("ident", self.ident.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UsePath {}
+#[cfg(feature = "full")]
+impl CompactDebug for UsePath {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("UsePath");
+        node.field("ident", self.ident.sexpr());
+        node.field("tree", self.tree.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseRename {}
+#[cfg(feature = "full")]
+impl CompactDebug for UseRename {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("UseRename");
+        node.field("ident", self.ident.sexpr());
+        node.field("rename", self.rename.sexpr());
+        node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseTree {}
+#[cfg(feature = "full")]
+impl CompactDebug for UseTree {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            UseTree::Path(_v0) => _v0.sexpr(),
+            UseTree::Name(_v0) => _v0.sexpr(),
+            UseTree::Rename(_v0) => _v0.sexpr(),
+            UseTree::Glob(_v0) => _v0.sexpr(),
+            UseTree::Group(_v0) => _v0.sexpr(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Variadic {}
+#[cfg(feature = "full")]
+impl CompactDebug for Variadic {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Variadic");
+        node.field("attrs", self.attrs.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Variant {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Variant {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("Variant");
+        node.field("attrs", self.attrs.sexpr());
+        node.field("ident", self.ident.sexpr());
+        node.field("fields", self.fields.sexpr());
+        node.field(
+            "discriminant",
+            self.discriminant
+                .as_ref()
+                .map_or_else(Sexpr::none, |val| val.1.sexpr()),
+        );
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisCrate {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for VisCrate {
+    fn sexpr(&self) -> Sexpr {
+        Sexpr::node("VisCrate")
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisPublic {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for VisPublic {
+    fn sexpr(&self) -> Sexpr {
+        Sexpr::node("VisPublic")
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisRestricted {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for VisRestricted {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("VisRestricted");
+        node.field("in_token", Sexpr::flag(self.in_token.is_some(), "in"));
+        node.field("path", self.path.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Visibility {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for Visibility {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            Visibility::Public(_v0) => _v0.sexpr(),
+            Visibility::Crate(_v0) => _v0.sexpr(),
+            Visibility::Restricted(_v0) => _v0.sexpr(),
+            Visibility::Inherited => Sexpr::atom("Inherited").into_default(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for WhereClause {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for WhereClause {
+    fn sexpr(&self) -> Sexpr {
+        let mut node = Sexpr::node("WhereClause");
+        node.field("predicates", self.predicates.sexpr());
+        node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for WherePredicate {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl CompactDebug for WherePredicate {
+    fn sexpr(&self) -> Sexpr {
+        match self {
+            WherePredicate::Type(_v0) => _v0.sexpr(),
+            WherePredicate::Lifetime(_v0) => _v0.sexpr(),
+            WherePredicate::Eq(_v0) => _v0.sexpr(),
+        }
+    }
+}
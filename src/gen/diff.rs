@@ -0,0 +1,3933 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+use crate::diff::{Diff, Differ};
+use crate::node::NodeRef;
+use crate::sealed::diff::Sealed;
+use crate::tt::TokenStreamHelper;
+use crate::*;
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Abi {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Abi {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Abi(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("name", |d| {
+            d.option(old.name.as_ref(), new.name.as_ref());
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for AngleBracketedGenericArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for AngleBracketedGenericArguments {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::AngleBracketedGenericArguments(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.colon2_token != new.colon2_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("args", |d| {
+            d.list(&old.args, &new.args);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Arm {}
+#[cfg(feature = "full")]
+impl Diff for Arm {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Arm(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.comma != new.comma {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("pat", |d| {
+            d.node(&old.pat, &new.pat);
+        });
+        d.field("guard", |d| match (&old.guard, &new.guard) {
+            (Some(old), Some(new)) => {
+                d.node(&*old.1, &*new.1);
+            }
+            (Some(old), None) => d.removed(&*old.1),
+            (None, Some(new)) => d.added(&*new.1),
+            _ => {}
+        });
+        d.field("body", |d| {
+            d.node(&*old.body, &*new.body);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for AttrStyle {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for AttrStyle {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::AttrStyle(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Attribute {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Attribute {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Attribute(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if TokenStreamHelper(&old.tokens) != TokenStreamHelper(&new.tokens) {
+            d.modified(old, new);
+            return;
+        }
+        d.field("style", |d| {
+            d.node(&old.style, &new.style);
+        });
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BareFnArg {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for BareFnArg {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::BareFnArg(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("name", |d| match (&old.name, &new.name) {
+            (Some(old), Some(new)) => {
+                d.node(&old.0, &new.0);
+            }
+            (Some(old), None) => d.removed(&old.0),
+            (None, Some(new)) => d.added(&new.0),
+            _ => {}
+        });
+        d.field("ty", |d| {
+            d.node(&old.ty, &new.ty);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BinOp {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for BinOp {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::BinOp(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Binding {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Binding {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Binding(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("ty", |d| {
+            d.node(&old.ty, &new.ty);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Block {}
+#[cfg(feature = "full")]
+impl Diff for Block {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Block(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("stmts", |d| {
+            d.list(&old.stmts, &new.stmts);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BoundLifetimes {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for BoundLifetimes {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::BoundLifetimes(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("lifetimes", |d| {
+            d.list(&old.lifetimes, &new.lifetimes);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ConstParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ConstParam {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ConstParam(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.eq_token != new.eq_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("ty", |d| {
+            d.node(&old.ty, &new.ty);
+        });
+        d.field("default", |d| {
+            d.option(old.default.as_ref(), new.default.as_ref());
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Constraint {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Constraint {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Constraint(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("bounds", |d| {
+            d.list(&old.bounds, &new.bounds);
+        });
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for Data {}
+#[cfg(feature = "derive")]
+impl Diff for Data {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Data(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (Data::Struct(old0), Data::Struct(new0)) => {
+                d.node(old0, new0);
+            }
+            (Data::Enum(old0), Data::Enum(new0)) => {
+                d.node(old0, new0);
+            }
+            (Data::Union(old0), Data::Union(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataEnum {}
+#[cfg(feature = "derive")]
+impl Diff for DataEnum {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::DataEnum(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("variants", |d| {
+            d.list(&old.variants, &new.variants);
+        });
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataStruct {}
+#[cfg(feature = "derive")]
+impl Diff for DataStruct {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::DataStruct(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.semi_token != new.semi_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("fields", |d| {
+            d.node(&old.fields, &new.fields);
+        });
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataUnion {}
+#[cfg(feature = "derive")]
+impl Diff for DataUnion {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::DataUnion(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("fields", |d| {
+            d.node(&old.fields, &new.fields);
+        });
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DeriveInput {}
+#[cfg(feature = "derive")]
+impl Diff for DeriveInput {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::DeriveInput(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("generics", |d| {
+            d.node(&old.generics, &new.generics);
+        });
+        d.field("data", |d| {
+            d.node(&old.data, &new.data);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Expr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Expr {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Expr(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            #[cfg(feature = "full")]
+            (Expr::Array(old0), Expr::Array(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Assign(old0), Expr::Assign(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::AssignOp(old0), Expr::AssignOp(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Async(old0), Expr::Async(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Await(old0), Expr::Await(new0)) => {
+                d.node(old0, new0);
+            }
+            (Expr::Binary(old0), Expr::Binary(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Block(old0), Expr::Block(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Box(old0), Expr::Box(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Break(old0), Expr::Break(new0)) => {
+                d.node(old0, new0);
+            }
+            (Expr::Call(old0), Expr::Call(new0)) => {
+                d.node(old0, new0);
+            }
+            (Expr::Cast(old0), Expr::Cast(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Closure(old0), Expr::Closure(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Continue(old0), Expr::Continue(new0)) => {
+                d.node(old0, new0);
+            }
+            (Expr::Field(old0), Expr::Field(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::ForLoop(old0), Expr::ForLoop(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Group(old0), Expr::Group(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::If(old0), Expr::If(new0)) => {
+                d.node(old0, new0);
+            }
+            (Expr::Index(old0), Expr::Index(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Let(old0), Expr::Let(new0)) => {
+                d.node(old0, new0);
+            }
+            (Expr::Lit(old0), Expr::Lit(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Loop(old0), Expr::Loop(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Macro(old0), Expr::Macro(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Match(old0), Expr::Match(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::MethodCall(old0), Expr::MethodCall(new0)) => {
+                d.node(old0, new0);
+            }
+            (Expr::Paren(old0), Expr::Paren(new0)) => {
+                d.node(old0, new0);
+            }
+            (Expr::Path(old0), Expr::Path(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Range(old0), Expr::Range(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Reference(old0), Expr::Reference(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Repeat(old0), Expr::Repeat(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Return(old0), Expr::Return(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Struct(old0), Expr::Struct(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Try(old0), Expr::Try(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::TryBlock(old0), Expr::TryBlock(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Tuple(old0), Expr::Tuple(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Type(old0), Expr::Type(new0)) => {
+                d.node(old0, new0);
+            }
+            (Expr::Unary(old0), Expr::Unary(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Unsafe(old0), Expr::Unsafe(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::While(old0), Expr::While(new0)) => {
+                d.node(old0, new0);
+            }
+            #[cfg(feature = "full")]
+            (Expr::Yield(old0), Expr::Yield(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprArray {}
+#[cfg(feature = "full")]
+impl Diff for ExprArray {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprArray(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("elems", |d| {
+            d.list(&old.elems, &new.elems);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAssign {}
+#[cfg(feature = "full")]
+impl Diff for ExprAssign {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprAssign(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("left", |d| {
+            d.node(&*old.left, &*new.left);
+        });
+        d.field("right", |d| {
+            d.node(&*old.right, &*new.right);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAssignOp {}
+#[cfg(feature = "full")]
+impl Diff for ExprAssignOp {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprAssignOp(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("left", |d| {
+            d.node(&*old.left, &*new.left);
+        });
+        d.field("op", |d| {
+            d.node(&old.op, &new.op);
+        });
+        d.field("right", |d| {
+            d.node(&*old.right, &*new.right);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAsync {}
+#[cfg(feature = "full")]
+impl Diff for ExprAsync {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprAsync(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.capture != new.capture {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("block", |d| {
+            d.node(&old.block, &new.block);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAwait {}
+#[cfg(feature = "full")]
+impl Diff for ExprAwait {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprAwait(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("base", |d| {
+            d.node(&*old.base, &*new.base);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprBinary {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ExprBinary {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprBinary(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("left", |d| {
+            d.node(&*old.left, &*new.left);
+        });
+        d.field("op", |d| {
+            d.node(&old.op, &new.op);
+        });
+        d.field("right", |d| {
+            d.node(&*old.right, &*new.right);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBlock {}
+#[cfg(feature = "full")]
+impl Diff for ExprBlock {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprBlock(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("label", |d| {
+            d.option(old.label.as_ref(), new.label.as_ref());
+        });
+        d.field("block", |d| {
+            d.node(&old.block, &new.block);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBox {}
+#[cfg(feature = "full")]
+impl Diff for ExprBox {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprBox(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBreak {}
+#[cfg(feature = "full")]
+impl Diff for ExprBreak {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprBreak(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("label", |d| {
+            d.option(old.label.as_ref(), new.label.as_ref());
+        });
+        d.field("expr", |d| {
+            d.option(
+                old.expr.as_ref().map(Box::as_ref),
+                new.expr.as_ref().map(Box::as_ref),
+            );
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprCall {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ExprCall {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprCall(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("func", |d| {
+            d.node(&*old.func, &*new.func);
+        });
+        d.field("args", |d| {
+            d.list(&old.args, &new.args);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprCast {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ExprCast {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprCast(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+        d.field("ty", |d| {
+            d.node(&*old.ty, &*new.ty);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprClosure {}
+#[cfg(feature = "full")]
+impl Diff for ExprClosure {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprClosure(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.asyncness != new.asyncness
+            || old.movability != new.movability
+            || old.capture != new.capture
+        {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("inputs", |d| {
+            d.list(&old.inputs, &new.inputs);
+        });
+        d.field("output", |d| {
+            d.node(&old.output, &new.output);
+        });
+        d.field("body", |d| {
+            d.node(&*old.body, &*new.body);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprContinue {}
+#[cfg(feature = "full")]
+impl Diff for ExprContinue {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprContinue(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("label", |d| {
+            d.option(old.label.as_ref(), new.label.as_ref());
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprField {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ExprField {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprField(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("base", |d| {
+            d.node(&*old.base, &*new.base);
+        });
+        d.field("member", |d| {
+            d.node(&old.member, &new.member);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprForLoop {}
+#[cfg(feature = "full")]
+impl Diff for ExprForLoop {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprForLoop(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("label", |d| {
+            d.option(old.label.as_ref(), new.label.as_ref());
+        });
+        d.field("pat", |d| {
+            d.node(&old.pat, &new.pat);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+        d.field("body", |d| {
+            d.node(&old.body, &new.body);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprGroup {}
+#[cfg(feature = "full")]
+impl Diff for ExprGroup {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprGroup(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprIf {}
+#[cfg(feature = "full")]
+impl Diff for ExprIf {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprIf(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("cond", |d| {
+            d.node(&*old.cond, &*new.cond);
+        });
+        d.field("then_branch", |d| {
+            d.node(&old.then_branch, &new.then_branch);
+        });
+        d.field("else_branch", |d| {
+            match (&old.else_branch, &new.else_branch) {
+                (Some(old), Some(new)) => {
+                    d.node(&*old.1, &*new.1);
+                }
+                (Some(old), None) => d.removed(&*old.1),
+                (None, Some(new)) => d.added(&*new.1),
+                _ => {}
+            }
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprIndex {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ExprIndex {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprIndex(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+        d.field("index", |d| {
+            d.node(&*old.index, &*new.index);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprLet {}
+#[cfg(feature = "full")]
+impl Diff for ExprLet {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprLet(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("pat", |d| {
+            d.node(&old.pat, &new.pat);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprLit {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ExprLit {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprLit(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("lit", |d| {
+            d.node(&old.lit, &new.lit);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprLoop {}
+#[cfg(feature = "full")]
+impl Diff for ExprLoop {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprLoop(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("label", |d| {
+            d.option(old.label.as_ref(), new.label.as_ref());
+        });
+        d.field("body", |d| {
+            d.node(&old.body, &new.body);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMacro {}
+#[cfg(feature = "full")]
+impl Diff for ExprMacro {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprMacro(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("mac", |d| {
+            d.node(&old.mac, &new.mac);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMatch {}
+#[cfg(feature = "full")]
+impl Diff for ExprMatch {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprMatch(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+        d.field("arms", |d| {
+            d.list(&old.arms, &new.arms);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMethodCall {}
+#[cfg(feature = "full")]
+impl Diff for ExprMethodCall {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprMethodCall(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("receiver", |d| {
+            d.node(&*old.receiver, &*new.receiver);
+        });
+        d.field("method", |d| {
+            d.node(&old.method, &new.method);
+        });
+        d.field("turbofish", |d| {
+            d.option(old.turbofish.as_ref(), new.turbofish.as_ref());
+        });
+        d.field("args", |d| {
+            d.list(&old.args, &new.args);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprParen {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ExprParen {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprParen(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprPath {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ExprPath {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprPath(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("qself", |d| {
+            d.option(old.qself.as_ref(), new.qself.as_ref());
+        });
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprRange {}
+#[cfg(feature = "full")]
+impl Diff for ExprRange {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprRange(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("from", |d| {
+            d.option(
+                old.from.as_ref().map(Box::as_ref),
+                new.from.as_ref().map(Box::as_ref),
+            );
+        });
+        d.field("limits", |d| {
+            d.node(&old.limits, &new.limits);
+        });
+        d.field("to", |d| {
+            d.option(
+                old.to.as_ref().map(Box::as_ref),
+                new.to.as_ref().map(Box::as_ref),
+            );
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprReference {}
+#[cfg(feature = "full")]
+impl Diff for ExprReference {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprReference(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.mutability != new.mutability {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprRepeat {}
+#[cfg(feature = "full")]
+impl Diff for ExprRepeat {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprRepeat(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+        d.field("len", |d| {
+            d.node(&*old.len, &*new.len);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprReturn {}
+#[cfg(feature = "full")]
+impl Diff for ExprReturn {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprReturn(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.option(
+                old.expr.as_ref().map(Box::as_ref),
+                new.expr.as_ref().map(Box::as_ref),
+            );
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprStruct {}
+#[cfg(feature = "full")]
+impl Diff for ExprStruct {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprStruct(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.dot2_token != new.dot2_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+        d.field("fields", |d| {
+            d.list(&old.fields, &new.fields);
+        });
+        d.field("rest", |d| {
+            d.option(
+                old.rest.as_ref().map(Box::as_ref),
+                new.rest.as_ref().map(Box::as_ref),
+            );
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTry {}
+#[cfg(feature = "full")]
+impl Diff for ExprTry {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprTry(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTryBlock {}
+#[cfg(feature = "full")]
+impl Diff for ExprTryBlock {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprTryBlock(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("block", |d| {
+            d.node(&old.block, &new.block);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTuple {}
+#[cfg(feature = "full")]
+impl Diff for ExprTuple {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprTuple(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("elems", |d| {
+            d.list(&old.elems, &new.elems);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprType {}
+#[cfg(feature = "full")]
+impl Diff for ExprType {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprType(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+        d.field("ty", |d| {
+            d.node(&*old.ty, &*new.ty);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprUnary {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ExprUnary {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprUnary(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("op", |d| {
+            d.node(&old.op, &new.op);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprUnsafe {}
+#[cfg(feature = "full")]
+impl Diff for ExprUnsafe {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprUnsafe(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("block", |d| {
+            d.node(&old.block, &new.block);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprWhile {}
+#[cfg(feature = "full")]
+impl Diff for ExprWhile {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprWhile(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("label", |d| {
+            d.option(old.label.as_ref(), new.label.as_ref());
+        });
+        d.field("cond", |d| {
+            d.node(&*old.cond, &*new.cond);
+        });
+        d.field("body", |d| {
+            d.node(&old.body, &new.body);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprYield {}
+#[cfg(feature = "full")]
+impl Diff for ExprYield {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprYield(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.option(
+                old.expr.as_ref().map(Box::as_ref),
+                new.expr.as_ref().map(Box::as_ref),
+            );
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Field {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Field {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Field(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.colon_token != new.colon_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.option(old.ident.as_ref(), new.ident.as_ref());
+        });
+        d.field("ty", |d| {
+            d.node(&old.ty, &new.ty);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FieldPat {}
+#[cfg(feature = "full")]
+impl Diff for FieldPat {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::FieldPat(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.colon_token != new.colon_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("member", |d| {
+            d.node(&old.member, &new.member);
+        });
+        d.field("pat", |d| {
+            d.node(&*old.pat, &*new.pat);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FieldValue {}
+#[cfg(feature = "full")]
+impl Diff for FieldValue {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::FieldValue(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.colon_token != new.colon_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("member", |d| {
+            d.node(&old.member, &new.member);
+        });
+        d.field("expr", |d| {
+            d.node(&old.expr, &new.expr);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Fields {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Fields {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Fields(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (Fields::Named(old0), Fields::Named(new0)) => {
+                d.node(old0, new0);
+            }
+            (Fields::Unnamed(old0), Fields::Unnamed(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for FieldsNamed {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for FieldsNamed {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::FieldsNamed(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("named", |d| {
+            d.list(&old.named, &new.named);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for FieldsUnnamed {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for FieldsUnnamed {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::FieldsUnnamed(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("unnamed", |d| {
+            d.list(&old.unnamed, &new.unnamed);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for File {}
+#[cfg(feature = "full")]
+impl Diff for File {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::File(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.shebang != new.shebang {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("items", |d| {
+            d.list(&old.items, &new.items);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FnArg {}
+#[cfg(feature = "full")]
+impl Diff for FnArg {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::FnArg(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (FnArg::Receiver(old0), FnArg::Receiver(new0)) => {
+                d.node(old0, new0);
+            }
+            (FnArg::Typed(old0), FnArg::Typed(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItem {}
+#[cfg(feature = "full")]
+impl Diff for ForeignItem {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ForeignItem(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (ForeignItem::Fn(old0), ForeignItem::Fn(new0)) => {
+                d.node(old0, new0);
+            }
+            (ForeignItem::Static(old0), ForeignItem::Static(new0)) => {
+                d.node(old0, new0);
+            }
+            (ForeignItem::Type(old0), ForeignItem::Type(new0)) => {
+                d.node(old0, new0);
+            }
+            (ForeignItem::Macro(old0), ForeignItem::Macro(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemFn {}
+#[cfg(feature = "full")]
+impl Diff for ForeignItemFn {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ForeignItemFn(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("sig", |d| {
+            d.node(&old.sig, &new.sig);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemMacro {}
+#[cfg(feature = "full")]
+impl Diff for ForeignItemMacro {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ForeignItemMacro(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.semi_token != new.semi_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("mac", |d| {
+            d.node(&old.mac, &new.mac);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemStatic {}
+#[cfg(feature = "full")]
+impl Diff for ForeignItemStatic {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ForeignItemStatic(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.mutability != new.mutability {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("ty", |d| {
+            d.node(&*old.ty, &*new.ty);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemType {}
+#[cfg(feature = "full")]
+impl Diff for ForeignItemType {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ForeignItemType(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for GenericArgument {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for GenericArgument {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::GenericArgument(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (GenericArgument::Lifetime(old0), GenericArgument::Lifetime(new0)) => {
+                d.node(old0, new0);
+            }
+            (GenericArgument::Type(old0), GenericArgument::Type(new0)) => {
+                d.node(old0, new0);
+            }
+            (GenericArgument::Binding(old0), GenericArgument::Binding(new0)) => {
+                d.node(old0, new0);
+            }
+            (GenericArgument::Constraint(old0), GenericArgument::Constraint(new0)) => {
+                d.node(old0, new0);
+            }
+            (GenericArgument::Const(old0), GenericArgument::Const(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for GenericMethodArgument {}
+#[cfg(feature = "full")]
+impl Diff for GenericMethodArgument {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::GenericMethodArgument(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (GenericMethodArgument::Type(old0), GenericMethodArgument::Type(new0)) => {
+                d.node(old0, new0);
+            }
+            (GenericMethodArgument::Const(old0), GenericMethodArgument::Const(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for GenericParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for GenericParam {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::GenericParam(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (GenericParam::Type(old0), GenericParam::Type(new0)) => {
+                d.node(old0, new0);
+            }
+            (GenericParam::Lifetime(old0), GenericParam::Lifetime(new0)) => {
+                d.node(old0, new0);
+            }
+            (GenericParam::Const(old0), GenericParam::Const(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Generics {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Generics {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Generics(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.lt_token != new.lt_token || old.gt_token != new.gt_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("params", |d| {
+            d.list(&old.params, &new.params);
+        });
+        d.field("where_clause", |d| {
+            d.option(old.where_clause.as_ref(), new.where_clause.as_ref());
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItem {}
+#[cfg(feature = "full")]
+impl Diff for ImplItem {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ImplItem(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (ImplItem::Const(old0), ImplItem::Const(new0)) => {
+                d.node(old0, new0);
+            }
+            (ImplItem::Method(old0), ImplItem::Method(new0)) => {
+                d.node(old0, new0);
+            }
+            (ImplItem::Type(old0), ImplItem::Type(new0)) => {
+                d.node(old0, new0);
+            }
+            (ImplItem::Macro(old0), ImplItem::Macro(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemConst {}
+#[cfg(feature = "full")]
+impl Diff for ImplItemConst {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ImplItemConst(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.defaultness != new.defaultness {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("ty", |d| {
+            d.node(&old.ty, &new.ty);
+        });
+        d.field("expr", |d| {
+            d.node(&old.expr, &new.expr);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemMacro {}
+#[cfg(feature = "full")]
+impl Diff for ImplItemMacro {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ImplItemMacro(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.semi_token != new.semi_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("mac", |d| {
+            d.node(&old.mac, &new.mac);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemMethod {}
+#[cfg(feature = "full")]
+impl Diff for ImplItemMethod {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ImplItemMethod(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.defaultness != new.defaultness {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("sig", |d| {
+            d.node(&old.sig, &new.sig);
+        });
+        d.field("block", |d| {
+            d.node(&old.block, &new.block);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemType {}
+#[cfg(feature = "full")]
+impl Diff for ImplItemType {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ImplItemType(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.defaultness != new.defaultness {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("generics", |d| {
+            d.node(&old.generics, &new.generics);
+        });
+        d.field("ty", |d| {
+            d.node(&old.ty, &new.ty);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Index {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Index {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Index(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Item {}
+#[cfg(feature = "full")]
+impl Diff for Item {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Item(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (Item::Const(old0), Item::Const(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Enum(old0), Item::Enum(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::ExternCrate(old0), Item::ExternCrate(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Fn(old0), Item::Fn(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::ForeignMod(old0), Item::ForeignMod(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Impl(old0), Item::Impl(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Macro(old0), Item::Macro(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Macro2(old0), Item::Macro2(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Mod(old0), Item::Mod(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Static(old0), Item::Static(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Struct(old0), Item::Struct(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Trait(old0), Item::Trait(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::TraitAlias(old0), Item::TraitAlias(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Type(old0), Item::Type(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Union(old0), Item::Union(new0)) => {
+                d.node(old0, new0);
+            }
+            (Item::Use(old0), Item::Use(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemConst {}
+#[cfg(feature = "full")]
+impl Diff for ItemConst {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemConst(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("ty", |d| {
+            d.node(&*old.ty, &*new.ty);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemEnum {}
+#[cfg(feature = "full")]
+impl Diff for ItemEnum {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemEnum(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("generics", |d| {
+            d.node(&old.generics, &new.generics);
+        });
+        d.field("variants", |d| {
+            d.list(&old.variants, &new.variants);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemExternCrate {}
+#[cfg(feature = "full")]
+impl Diff for ItemExternCrate {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemExternCrate(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("rename", |d| match (&old.rename, &new.rename) {
+            (Some(old), Some(new)) => {
+                d.node(&old.1, &new.1);
+            }
+            (Some(old), None) => d.removed(&old.1),
+            (None, Some(new)) => d.added(&new.1),
+            _ => {}
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemFn {}
+#[cfg(feature = "full")]
+impl Diff for ItemFn {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemFn(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("sig", |d| {
+            d.node(&old.sig, &new.sig);
+        });
+        d.field("block", |d| {
+            d.node(&*old.block, &*new.block);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemForeignMod {}
+#[cfg(feature = "full")]
+impl Diff for ItemForeignMod {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemForeignMod(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("abi", |d| {
+            d.node(&old.abi, &new.abi);
+        });
+        d.field("items", |d| {
+            d.list(&old.items, &new.items);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemImpl {}
+#[cfg(feature = "full")]
+impl Diff for ItemImpl {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemImpl(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.defaultness != new.defaultness
+            || old.unsafety != new.unsafety
+            || match (&old.trait_, &new.trait_) {
+                (Some(old), Some(new)) => old.0 != new.0,
+                _ => false,
+            }
+        {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("generics", |d| {
+            d.node(&old.generics, &new.generics);
+        });
+        d.field("trait_", |d| match (&old.trait_, &new.trait_) {
+            (Some(old), Some(new)) => {
+                d.node(&old.1, &new.1);
+            }
+            (Some(old), None) => d.removed(&old.1),
+            (None, Some(new)) => d.added(&new.1),
+            _ => {}
+        });
+        d.field("self_ty", |d| {
+            d.node(&*old.self_ty, &*new.self_ty);
+        });
+        d.field("items", |d| {
+            d.list(&old.items, &new.items);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMacro {}
+#[cfg(feature = "full")]
+impl Diff for ItemMacro {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemMacro(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.semi_token != new.semi_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("ident", |d| {
+            d.option(old.ident.as_ref(), new.ident.as_ref());
+        });
+        d.field("mac", |d| {
+            d.node(&old.mac, &new.mac);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMacro2 {}
+#[cfg(feature = "full")]
+impl Diff for ItemMacro2 {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemMacro2(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if TokenStreamHelper(&old.rules) != TokenStreamHelper(&new.rules) {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMod {}
+#[cfg(feature = "full")]
+impl Diff for ItemMod {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemMod(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.content.is_some() != new.content.is_some() || old.semi != new.semi {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("content", |d| {
+            if let (Some(old), Some(new)) = (&old.content, &new.content) {
+                d.list(&old.1, &new.1);
+            }
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemStatic {}
+#[cfg(feature = "full")]
+impl Diff for ItemStatic {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemStatic(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.mutability != new.mutability {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("ty", |d| {
+            d.node(&*old.ty, &*new.ty);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemStruct {}
+#[cfg(feature = "full")]
+impl Diff for ItemStruct {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemStruct(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.semi_token != new.semi_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("generics", |d| {
+            d.node(&old.generics, &new.generics);
+        });
+        d.field("fields", |d| {
+            d.node(&old.fields, &new.fields);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemTrait {}
+#[cfg(feature = "full")]
+impl Diff for ItemTrait {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemTrait(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.unsafety != new.unsafety
+            || old.auto_token != new.auto_token
+            || old.colon_token != new.colon_token
+        {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("generics", |d| {
+            d.node(&old.generics, &new.generics);
+        });
+        d.field("supertraits", |d| {
+            d.list(&old.supertraits, &new.supertraits);
+        });
+        d.field("items", |d| {
+            d.list(&old.items, &new.items);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemTraitAlias {}
+#[cfg(feature = "full")]
+impl Diff for ItemTraitAlias {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemTraitAlias(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("generics", |d| {
+            d.node(&old.generics, &new.generics);
+        });
+        d.field("bounds", |d| {
+            d.list(&old.bounds, &new.bounds);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemType {}
+#[cfg(feature = "full")]
+impl Diff for ItemType {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemType(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("generics", |d| {
+            d.node(&old.generics, &new.generics);
+        });
+        d.field("ty", |d| {
+            d.node(&*old.ty, &*new.ty);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemUnion {}
+#[cfg(feature = "full")]
+impl Diff for ItemUnion {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemUnion(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("generics", |d| {
+            d.node(&old.generics, &new.generics);
+        });
+        d.field("fields", |d| {
+            d.node(&old.fields, &new.fields);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemUse {}
+#[cfg(feature = "full")]
+impl Diff for ItemUse {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemUse(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.leading_colon != new.leading_colon {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("vis", |d| {
+            d.node(&old.vis, &new.vis);
+        });
+        d.field("tree", |d| {
+            d.node(&old.tree, &new.tree);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Label {}
+#[cfg(feature = "full")]
+impl Diff for Label {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Label(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("name", |d| {
+            d.node(&old.name, &new.name);
+        });
+    }
+}
+impl Sealed for Lifetime {}
+impl Diff for Lifetime {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Lifetime(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LifetimeDef {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for LifetimeDef {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LifetimeDef(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.colon_token != new.colon_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("lifetime", |d| {
+            d.node(&old.lifetime, &new.lifetime);
+        });
+        d.field("bounds", |d| {
+            d.list(&old.bounds, &new.bounds);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Lit {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Lit {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Lit(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (Lit::Str(old0), Lit::Str(new0)) => {
+                d.node(old0, new0);
+            }
+            (Lit::ByteStr(old0), Lit::ByteStr(new0)) => {
+                d.node(old0, new0);
+            }
+            (Lit::Byte(old0), Lit::Byte(new0)) => {
+                d.node(old0, new0);
+            }
+            (Lit::Char(old0), Lit::Char(new0)) => {
+                d.node(old0, new0);
+            }
+            (Lit::Int(old0), Lit::Int(new0)) => {
+                d.node(old0, new0);
+            }
+            (Lit::Float(old0), Lit::Float(new0)) => {
+                d.node(old0, new0);
+            }
+            (Lit::Bool(old0), Lit::Bool(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitBool {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for LitBool {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitBool(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitByte {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for LitByte {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitByte(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitByteStr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for LitByteStr {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitByteStr(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitChar {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for LitChar {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitChar(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitFloat {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for LitFloat {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitFloat(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitInt {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for LitInt {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitInt(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitStr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for LitStr {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitStr(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Local {}
+#[cfg(feature = "full")]
+impl Diff for Local {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Local(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("pat", |d| {
+            d.node(&old.pat, &new.pat);
+        });
+        d.field("init", |d| match (&old.init, &new.init) {
+            (Some(old), Some(new)) => {
+                d.node(&*old.1, &*new.1);
+            }
+            (Some(old), None) => d.removed(&*old.1),
+            (None, Some(new)) => d.added(&*new.1),
+            _ => {}
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Macro {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Macro {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Macro(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if TokenStreamHelper(&old.tokens) != TokenStreamHelper(&new.tokens) {
+            d.modified(old, new);
+            return;
+        }
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+        d.field("delimiter", |d| {
+            d.node(&old.delimiter, &new.delimiter);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MacroDelimiter {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for MacroDelimiter {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MacroDelimiter(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Member {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Member {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Member(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (Member::Named(old0), Member::Named(new0)) => {
+                d.node(old0, new0);
+            }
+            (Member::Unnamed(old0), Member::Unnamed(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Meta {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Meta {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Meta(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (Meta::Path(old0), Meta::Path(new0)) => {
+                d.node(old0, new0);
+            }
+            (Meta::List(old0), Meta::List(new0)) => {
+                d.node(old0, new0);
+            }
+            (Meta::NameValue(old0), Meta::NameValue(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExpr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for MetaExpr {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaExpr(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (MetaExpr::Path(old0), MetaExpr::Path(new0)) => {
+                d.node(old0, new0);
+            }
+            (MetaExpr::List(old0), MetaExpr::List(new0)) => {
+                d.node(old0, new0);
+            }
+            (MetaExpr::NameValue(old0), MetaExpr::NameValue(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExprList {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for MetaExprList {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaExprList(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+        d.field("nested", |d| {
+            d.list(&old.nested, &new.nested);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExprNameValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for MetaExprNameValue {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaExprNameValue(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+        d.field("value", |d| {
+            d.node(&old.value, &new.value);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaList {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for MetaList {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaList(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+        d.field("nested", |d| {
+            d.list(&old.nested, &new.nested);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaNameValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for MetaNameValue {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaNameValue(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+        d.field("lit", |d| {
+            d.node(&old.lit, &new.lit);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for MetaValue {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaValue(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (MetaValue::Expr(old0), MetaValue::Expr(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for MethodTurbofish {}
+#[cfg(feature = "full")]
+impl Diff for MethodTurbofish {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MethodTurbofish(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("args", |d| {
+            d.list(&old.args, &new.args);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for NestedMeta {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for NestedMeta {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::NestedMeta(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (NestedMeta::Meta(old0), NestedMeta::Meta(new0)) => {
+                d.node(old0, new0);
+            }
+            (NestedMeta::Lit(old0), NestedMeta::Lit(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for NestedMetaExpr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for NestedMetaExpr {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::NestedMetaExpr(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (NestedMetaExpr::Meta(old0), NestedMetaExpr::Meta(new0)) => {
+                d.node(old0, new0);
+            }
+            (NestedMetaExpr::Lit(old0), NestedMetaExpr::Lit(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ParenthesizedGenericArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ParenthesizedGenericArguments {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ParenthesizedGenericArguments(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("inputs", |d| {
+            d.list(&old.inputs, &new.inputs);
+        });
+        d.field("output", |d| {
+            d.node(&old.output, &new.output);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Pat {}
+#[cfg(feature = "full")]
+impl Diff for Pat {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Pat(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (Pat::Box(old0), Pat::Box(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Ident(old0), Pat::Ident(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Lit(old0), Pat::Lit(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Macro(old0), Pat::Macro(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Or(old0), Pat::Or(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Path(old0), Pat::Path(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Range(old0), Pat::Range(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Reference(old0), Pat::Reference(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Rest(old0), Pat::Rest(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Slice(old0), Pat::Slice(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Struct(old0), Pat::Struct(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Tuple(old0), Pat::Tuple(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::TupleStruct(old0), Pat::TupleStruct(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Type(old0), Pat::Type(new0)) => {
+                d.node(old0, new0);
+            }
+            (Pat::Wild(old0), Pat::Wild(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatBox {}
+#[cfg(feature = "full")]
+impl Diff for PatBox {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatBox(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("pat", |d| {
+            d.node(&*old.pat, &*new.pat);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatIdent {}
+#[cfg(feature = "full")]
+impl Diff for PatIdent {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatIdent(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.by_ref != new.by_ref || old.mutability != new.mutability {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("subpat", |d| match (&old.subpat, &new.subpat) {
+            (Some(old), Some(new)) => {
+                d.node(&*old.1, &*new.1);
+            }
+            (Some(old), None) => d.removed(&*old.1),
+            (None, Some(new)) => d.added(&*new.1),
+            _ => {}
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatLit {}
+#[cfg(feature = "full")]
+impl Diff for PatLit {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatLit(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("expr", |d| {
+            d.node(&*old.expr, &*new.expr);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatMacro {}
+#[cfg(feature = "full")]
+impl Diff for PatMacro {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatMacro(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("mac", |d| {
+            d.node(&old.mac, &new.mac);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatOr {}
+#[cfg(feature = "full")]
+impl Diff for PatOr {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatOr(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.leading_vert != new.leading_vert {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("cases", |d| {
+            d.list(&old.cases, &new.cases);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatPath {}
+#[cfg(feature = "full")]
+impl Diff for PatPath {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatPath(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("qself", |d| {
+            d.option(old.qself.as_ref(), new.qself.as_ref());
+        });
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatRange {}
+#[cfg(feature = "full")]
+impl Diff for PatRange {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatRange(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("lo", |d| {
+            d.node(&*old.lo, &*new.lo);
+        });
+        d.field("limits", |d| {
+            d.node(&old.limits, &new.limits);
+        });
+        d.field("hi", |d| {
+            d.node(&*old.hi, &*new.hi);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatReference {}
+#[cfg(feature = "full")]
+impl Diff for PatReference {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatReference(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.mutability != new.mutability {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("pat", |d| {
+            d.node(&*old.pat, &*new.pat);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatRest {}
+#[cfg(feature = "full")]
+impl Diff for PatRest {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatRest(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatSlice {}
+#[cfg(feature = "full")]
+impl Diff for PatSlice {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatSlice(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("elems", |d| {
+            d.list(&old.elems, &new.elems);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatStruct {}
+#[cfg(feature = "full")]
+impl Diff for PatStruct {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatStruct(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.dot2_token != new.dot2_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+        d.field("fields", |d| {
+            d.list(&old.fields, &new.fields);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatTuple {}
+#[cfg(feature = "full")]
+impl Diff for PatTuple {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatTuple(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("elems", |d| {
+            d.list(&old.elems, &new.elems);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatTupleStruct {}
+#[cfg(feature = "full")]
+impl Diff for PatTupleStruct {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatTupleStruct(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+        d.field("pat", |d| {
+            d.node(&old.pat, &new.pat);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatType {}
+#[cfg(feature = "full")]
+impl Diff for PatType {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatType(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("pat", |d| {
+            d.node(&*old.pat, &*new.pat);
+        });
+        d.field("ty", |d| {
+            d.node(&*old.ty, &*new.ty);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatWild {}
+#[cfg(feature = "full")]
+impl Diff for PatWild {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatWild(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Path {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Path {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Path(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.leading_colon != new.leading_colon {
+            d.modified(old, new);
+            return;
+        }
+        d.field("segments", |d| {
+            d.list(&old.segments, &new.segments);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PathArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for PathArguments {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PathArguments(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (PathArguments::AngleBracketed(old0), PathArguments::AngleBracketed(new0)) => {
+                d.node(old0, new0);
+            }
+            (PathArguments::Parenthesized(old0), PathArguments::Parenthesized(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PathSegment {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for PathSegment {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PathSegment(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("arguments", |d| {
+            d.node(&old.arguments, &new.arguments);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateEq {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for PredicateEq {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PredicateEq(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("lhs_ty", |d| {
+            d.node(&old.lhs_ty, &new.lhs_ty);
+        });
+        d.field("rhs_ty", |d| {
+            d.node(&old.rhs_ty, &new.rhs_ty);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateLifetime {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for PredicateLifetime {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PredicateLifetime(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("lifetime", |d| {
+            d.node(&old.lifetime, &new.lifetime);
+        });
+        d.field("bounds", |d| {
+            d.list(&old.bounds, &new.bounds);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateType {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for PredicateType {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PredicateType(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("lifetimes", |d| {
+            d.option(old.lifetimes.as_ref(), new.lifetimes.as_ref());
+        });
+        d.field("bounded_ty", |d| {
+            d.node(&old.bounded_ty, &new.bounded_ty);
+        });
+        d.field("bounds", |d| {
+            d.list(&old.bounds, &new.bounds);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for QSelf {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for QSelf {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::QSelf(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.position != new.position || old.as_token != new.as_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("ty", |d| {
+            d.node(&*old.ty, &*new.ty);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for RangeLimits {}
+#[cfg(feature = "full")]
+impl Diff for RangeLimits {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::RangeLimits(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Receiver {}
+#[cfg(feature = "full")]
+impl Diff for Receiver {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Receiver(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.reference.is_some() != new.reference.is_some() || old.mutability != new.mutability {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("reference", |d| {
+            if let (Some(old), Some(new)) = (&old.reference, &new.reference) {
+                d.option(old.1.as_ref(), new.1.as_ref());
+            }
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ReturnType {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for ReturnType {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ReturnType(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (ReturnType::Type(_, old1), ReturnType::Type(_, new1)) => {
+                d.node(&**old1, &**new1);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Signature {}
+#[cfg(feature = "full")]
+impl Diff for Signature {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Signature(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.constness != new.constness
+            || old.asyncness != new.asyncness
+            || old.unsafety != new.unsafety
+        {
+            d.modified(old, new);
+            return;
+        }
+        d.field("abi", |d| {
+            d.option(old.abi.as_ref(), new.abi.as_ref());
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("generics", |d| {
+            d.node(&old.generics, &new.generics);
+        });
+        d.field("inputs", |d| {
+            d.list(&old.inputs, &new.inputs);
+        });
+        d.field("variadic", |d| {
+            d.option(old.variadic.as_ref(), new.variadic.as_ref());
+        });
+        d.field("output", |d| {
+            d.node(&old.output, &new.output);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Stmt {}
+#[cfg(feature = "full")]
+impl Diff for Stmt {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Stmt(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (Stmt::Local(old0), Stmt::Local(new0)) => {
+                d.node(old0, new0);
+            }
+            (Stmt::Item(old0), Stmt::Item(new0)) => {
+                d.node(old0, new0);
+            }
+            (Stmt::Expr(old0), Stmt::Expr(new0)) => {
+                d.node(old0, new0);
+            }
+            (Stmt::Semi(old0, _), Stmt::Semi(new0, _)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TraitBound {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TraitBound {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitBound(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.paren_token != new.paren_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("modifier", |d| {
+            d.node(&old.modifier, &new.modifier);
+        });
+        d.field("lifetimes", |d| {
+            d.option(old.lifetimes.as_ref(), new.lifetimes.as_ref());
+        });
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TraitBoundModifier {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TraitBoundModifier {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitBoundModifier(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItem {}
+#[cfg(feature = "full")]
+impl Diff for TraitItem {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitItem(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (TraitItem::Const(old0), TraitItem::Const(new0)) => {
+                d.node(old0, new0);
+            }
+            (TraitItem::Method(old0), TraitItem::Method(new0)) => {
+                d.node(old0, new0);
+            }
+            (TraitItem::Type(old0), TraitItem::Type(new0)) => {
+                d.node(old0, new0);
+            }
+            (TraitItem::Macro(old0), TraitItem::Macro(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemConst {}
+#[cfg(feature = "full")]
+impl Diff for TraitItemConst {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitItemConst(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("ty", |d| {
+            d.node(&old.ty, &new.ty);
+        });
+        d.field("default", |d| match (&old.default, &new.default) {
+            (Some(old), Some(new)) => {
+                d.node(&old.1, &new.1);
+            }
+            (Some(old), None) => d.removed(&old.1),
+            (None, Some(new)) => d.added(&new.1),
+            _ => {}
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemMacro {}
+#[cfg(feature = "full")]
+impl Diff for TraitItemMacro {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitItemMacro(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.semi_token != new.semi_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("mac", |d| {
+            d.node(&old.mac, &new.mac);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemMethod {}
+#[cfg(feature = "full")]
+impl Diff for TraitItemMethod {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitItemMethod(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.semi_token != new.semi_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("sig", |d| {
+            d.node(&old.sig, &new.sig);
+        });
+        d.field("default", |d| {
+            d.option(old.default.as_ref(), new.default.as_ref());
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemType {}
+#[cfg(feature = "full")]
+impl Diff for TraitItemType {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitItemType(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.colon_token != new.colon_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("generics", |d| {
+            d.node(&old.generics, &new.generics);
+        });
+        d.field("bounds", |d| {
+            d.list(&old.bounds, &new.bounds);
+        });
+        d.field("default", |d| match (&old.default, &new.default) {
+            (Some(old), Some(new)) => {
+                d.node(&old.1, &new.1);
+            }
+            (Some(old), None) => d.removed(&old.1),
+            (None, Some(new)) => d.added(&new.1),
+            _ => {}
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Type {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Type {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Type(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (Type::Array(old0), Type::Array(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::BareFn(old0), Type::BareFn(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::Group(old0), Type::Group(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::ImplTrait(old0), Type::ImplTrait(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::Infer(old0), Type::Infer(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::Macro(old0), Type::Macro(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::Never(old0), Type::Never(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::Paren(old0), Type::Paren(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::Path(old0), Type::Path(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::Ptr(old0), Type::Ptr(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::Reference(old0), Type::Reference(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::Slice(old0), Type::Slice(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::TraitObject(old0), Type::TraitObject(new0)) => {
+                d.node(old0, new0);
+            }
+            (Type::Tuple(old0), Type::Tuple(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeArray {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeArray {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeArray(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("elem", |d| {
+            d.node(&*old.elem, &*new.elem);
+        });
+        d.field("len", |d| {
+            d.node(&old.len, &new.len);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeBareFn {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeBareFn {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeBareFn(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.unsafety != new.unsafety || old.variadic != new.variadic {
+            d.modified(old, new);
+            return;
+        }
+        d.field("lifetimes", |d| {
+            d.option(old.lifetimes.as_ref(), new.lifetimes.as_ref());
+        });
+        d.field("abi", |d| {
+            d.option(old.abi.as_ref(), new.abi.as_ref());
+        });
+        d.field("inputs", |d| {
+            d.list(&old.inputs, &new.inputs);
+        });
+        d.field("output", |d| {
+            d.node(&old.output, &new.output);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeGroup {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeGroup {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeGroup(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("elem", |d| {
+            d.node(&*old.elem, &*new.elem);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeImplTrait {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeImplTrait {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeImplTrait(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("bounds", |d| {
+            d.list(&old.bounds, &new.bounds);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeInfer {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeInfer {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeInfer(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeMacro {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeMacro {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeMacro(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("mac", |d| {
+            d.node(&old.mac, &new.mac);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeNever {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeNever {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeNever(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeParam {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeParam(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.colon_token != new.colon_token || old.eq_token != new.eq_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("bounds", |d| {
+            d.list(&old.bounds, &new.bounds);
+        });
+        d.field("default", |d| {
+            d.option(old.default.as_ref(), new.default.as_ref());
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParamBound {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeParamBound {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeParamBound(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (TypeParamBound::Trait(old0), TypeParamBound::Trait(new0)) => {
+                d.node(old0, new0);
+            }
+            (TypeParamBound::Lifetime(old0), TypeParamBound::Lifetime(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParen {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeParen {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeParen(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("elem", |d| {
+            d.node(&*old.elem, &*new.elem);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypePath {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypePath {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypePath(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("qself", |d| {
+            d.option(old.qself.as_ref(), new.qself.as_ref());
+        });
+        d.field("path", |d| {
+            d.node(&old.path, &new.path);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypePtr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypePtr {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypePtr(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.const_token != new.const_token || old.mutability != new.mutability {
+            d.modified(old, new);
+            return;
+        }
+        d.field("elem", |d| {
+            d.node(&*old.elem, &*new.elem);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeReference {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeReference {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeReference(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.mutability != new.mutability {
+            d.modified(old, new);
+            return;
+        }
+        d.field("lifetime", |d| {
+            d.option(old.lifetime.as_ref(), new.lifetime.as_ref());
+        });
+        d.field("elem", |d| {
+            d.node(&*old.elem, &*new.elem);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeSlice {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeSlice {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeSlice(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("elem", |d| {
+            d.node(&*old.elem, &*new.elem);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeTraitObject {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeTraitObject {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeTraitObject(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.dyn_token != new.dyn_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("bounds", |d| {
+            d.list(&old.bounds, &new.bounds);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeTuple {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for TypeTuple {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeTuple(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("elems", |d| {
+            d.list(&old.elems, &new.elems);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for UnOp {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for UnOp {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UnOp(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseGlob {}
+#[cfg(feature = "full")]
+impl Diff for UseGlob {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UseGlob(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseGroup {}
+#[cfg(feature = "full")]
+impl Diff for UseGroup {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UseGroup(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("items", |d| {
+            d.list(&old.items, &new.items);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseName {}
+#[cfg(feature = "full")]
+impl Diff for UseName {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UseName(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UsePath {}
+#[cfg(feature = "full")]
+impl Diff for UsePath {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UsePath(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("tree", |d| {
+            d.node(&*old.tree, &*new.tree);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseRename {}
+#[cfg(feature = "full")]
+impl Diff for UseRename {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UseRename(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("rename", |d| {
+            d.node(&old.rename, &new.rename);
+        });
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseTree {}
+#[cfg(feature = "full")]
+impl Diff for UseTree {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UseTree(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (UseTree::Path(old0), UseTree::Path(new0)) => {
+                d.node(old0, new0);
+            }
+            (UseTree::Name(old0), UseTree::Name(new0)) => {
+                d.node(old0, new0);
+            }
+            (UseTree::Rename(old0), UseTree::Rename(new0)) => {
+                d.node(old0, new0);
+            }
+            (UseTree::Glob(old0), UseTree::Glob(new0)) => {
+                d.node(old0, new0);
+            }
+            (UseTree::Group(old0), UseTree::Group(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Variadic {}
+#[cfg(feature = "full")]
+impl Diff for Variadic {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Variadic(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Variant {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Variant {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Variant(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("attrs", |d| {
+            d.list(&old.attrs, &new.attrs);
+        });
+        d.field("ident", |d| {
+            d.node(&old.ident, &new.ident);
+        });
+        d.field("fields", |d| {
+            d.node(&old.fields, &new.fields);
+        });
+        d.field("discriminant", |d| {
+            match (&old.discriminant, &new.discriminant) {
+                (Some(old), Some(new)) => {
+                    d.node(&old.1, &new.1);
+                }
+                (Some(old), None) => d.removed(&old.1),
+                (None, Some(new)) => d.added(&new.1),
+                _ => {}
+            }
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisCrate {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for VisCrate {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::VisCrate(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisPublic {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for VisPublic {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::VisPublic(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisRestricted {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for VisRestricted {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::VisRestricted(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        if old.in_token != new.in_token {
+            d.modified(old, new);
+            return;
+        }
+        d.field("path", |d| {
+            d.node(&*old.path, &*new.path);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Visibility {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for Visibility {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Visibility(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (Visibility::Public(old0), Visibility::Public(new0)) => {
+                d.node(old0, new0);
+            }
+            (Visibility::Crate(old0), Visibility::Crate(new0)) => {
+                d.node(old0, new0);
+            }
+            (Visibility::Restricted(old0), Visibility::Restricted(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for WhereClause {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for WhereClause {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::WhereClause(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.field("predicates", |d| {
+            d.list(&old.predicates, &new.predicates);
+        });
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for WherePredicate {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Diff for WherePredicate {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::WherePredicate(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        match (old, new) {
+            (WherePredicate::Type(old0), WherePredicate::Type(new0)) => {
+                d.node(old0, new0);
+            }
+            (WherePredicate::Lifetime(old0), WherePredicate::Lifetime(new0)) => {
+                d.node(old0, new0);
+            }
+            (WherePredicate::Eq(old0), WherePredicate::Eq(new0)) => {
+                d.node(old0, new0);
+            }
+            _ => d.modified(old, new),
+        }
+    }
+}
+impl Sealed for Ident {}
+impl Diff for Ident {
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Ident(self)
+    }
+    fn diff_into<'a>(old: &'a Self, new: &'a Self, d: &mut Differ<'a>) {
+        d.modified(old, new);
+    }
+}
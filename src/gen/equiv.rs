@@ -0,0 +1,4241 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+use crate::equiv::{Equivalence, Equivalent};
+use crate::sealed::equiv::Sealed;
+use crate::tt::TokenStreamHelper;
+use crate::*;
+use std::hash::{Hash, Hasher};
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Abi {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Abi {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.name.equiv(&other.name, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.name.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for AngleBracketedGenericArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for AngleBracketedGenericArguments {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.colon2_token.is_some() == other.colon2_token.is_some()
+            && self.args.equiv(&other.args, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.colon2_token.is_some().hash(state);
+        self.args.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Arm {}
+#[cfg(feature = "full")]
+impl Equivalent for Arm {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.pat.equiv(&other.pat, equiv)
+            && match (&self.guard, &other.guard) {
+                (Some(this), Some(other)) => this.1.equiv(&other.1, equiv),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.body.equiv(&other.body, equiv)
+            && self.comma.is_some() == other.comma.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.pat.hash_equiv(equiv, state);
+        match &self.guard {
+            Some(this) => {
+                state.write_u8(1);
+                this.1.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+        self.body.hash_equiv(equiv, state);
+        self.comma.is_some().hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for AttrStyle {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for AttrStyle {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (AttrStyle::Outer, AttrStyle::Outer) => true,
+            (AttrStyle::Inner(_), AttrStyle::Inner(_)) => true,
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        match self {
+            AttrStyle::Outer => {
+                state.write_u8(0u8);
+            }
+            AttrStyle::Inner(_) => {
+                state.write_u8(1u8);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Attribute {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Attribute {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.style.equiv(&other.style, equiv)
+            && self.path.equiv(&other.path, equiv)
+            && TokenStreamHelper(&self.tokens) == TokenStreamHelper(&other.tokens)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.style.hash_equiv(equiv, state);
+        self.path.hash_equiv(equiv, state);
+        TokenStreamHelper(&self.tokens).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BareFnArg {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for BareFnArg {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && match (&self.name, &other.name) {
+                (Some(this), Some(other)) => this.0.equiv(&other.0, equiv),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.ty.equiv(&other.ty, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        match &self.name {
+            Some(this) => {
+                state.write_u8(1);
+                this.0.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+        self.ty.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BinOp {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for BinOp {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (BinOp::Add(_), BinOp::Add(_)) => true,
+            (BinOp::Sub(_), BinOp::Sub(_)) => true,
+            (BinOp::Mul(_), BinOp::Mul(_)) => true,
+            (BinOp::Div(_), BinOp::Div(_)) => true,
+            (BinOp::Rem(_), BinOp::Rem(_)) => true,
+            (BinOp::And(_), BinOp::And(_)) => true,
+            (BinOp::Or(_), BinOp::Or(_)) => true,
+            (BinOp::BitXor(_), BinOp::BitXor(_)) => true,
+            (BinOp::BitAnd(_), BinOp::BitAnd(_)) => true,
+            (BinOp::BitOr(_), BinOp::BitOr(_)) => true,
+            (BinOp::Shl(_), BinOp::Shl(_)) => true,
+            (BinOp::Shr(_), BinOp::Shr(_)) => true,
+            (BinOp::Eq(_), BinOp::Eq(_)) => true,
+            (BinOp::Lt(_), BinOp::Lt(_)) => true,
+            (BinOp::Le(_), BinOp::Le(_)) => true,
+            (BinOp::Ne(_), BinOp::Ne(_)) => true,
+            (BinOp::Ge(_), BinOp::Ge(_)) => true,
+            (BinOp::Gt(_), BinOp::Gt(_)) => true,
+            (BinOp::AddEq(_), BinOp::AddEq(_)) => true,
+            (BinOp::SubEq(_), BinOp::SubEq(_)) => true,
+            (BinOp::MulEq(_), BinOp::MulEq(_)) => true,
+            (BinOp::DivEq(_), BinOp::DivEq(_)) => true,
+            (BinOp::RemEq(_), BinOp::RemEq(_)) => true,
+            (BinOp::BitXorEq(_), BinOp::BitXorEq(_)) => true,
+            (BinOp::BitAndEq(_), BinOp::BitAndEq(_)) => true,
+            (BinOp::BitOrEq(_), BinOp::BitOrEq(_)) => true,
+            (BinOp::ShlEq(_), BinOp::ShlEq(_)) => true,
+            (BinOp::ShrEq(_), BinOp::ShrEq(_)) => true,
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        match self {
+            BinOp::Add(_) => {
+                state.write_u8(0u8);
+            }
+            BinOp::Sub(_) => {
+                state.write_u8(1u8);
+            }
+            BinOp::Mul(_) => {
+                state.write_u8(2u8);
+            }
+            BinOp::Div(_) => {
+                state.write_u8(3u8);
+            }
+            BinOp::Rem(_) => {
+                state.write_u8(4u8);
+            }
+            BinOp::And(_) => {
+                state.write_u8(5u8);
+            }
+            BinOp::Or(_) => {
+                state.write_u8(6u8);
+            }
+            BinOp::BitXor(_) => {
+                state.write_u8(7u8);
+            }
+            BinOp::BitAnd(_) => {
+                state.write_u8(8u8);
+            }
+            BinOp::BitOr(_) => {
+                state.write_u8(9u8);
+            }
+            BinOp::Shl(_) => {
+                state.write_u8(10u8);
+            }
+            BinOp::Shr(_) => {
+                state.write_u8(11u8);
+            }
+            BinOp::Eq(_) => {
+                state.write_u8(12u8);
+            }
+            BinOp::Lt(_) => {
+                state.write_u8(13u8);
+            }
+            BinOp::Le(_) => {
+                state.write_u8(14u8);
+            }
+            BinOp::Ne(_) => {
+                state.write_u8(15u8);
+            }
+            BinOp::Ge(_) => {
+                state.write_u8(16u8);
+            }
+            BinOp::Gt(_) => {
+                state.write_u8(17u8);
+            }
+            BinOp::AddEq(_) => {
+                state.write_u8(18u8);
+            }
+            BinOp::SubEq(_) => {
+                state.write_u8(19u8);
+            }
+            BinOp::MulEq(_) => {
+                state.write_u8(20u8);
+            }
+            BinOp::DivEq(_) => {
+                state.write_u8(21u8);
+            }
+            BinOp::RemEq(_) => {
+                state.write_u8(22u8);
+            }
+            BinOp::BitXorEq(_) => {
+                state.write_u8(23u8);
+            }
+            BinOp::BitAndEq(_) => {
+                state.write_u8(24u8);
+            }
+            BinOp::BitOrEq(_) => {
+                state.write_u8(25u8);
+            }
+            BinOp::ShlEq(_) => {
+                state.write_u8(26u8);
+            }
+            BinOp::ShrEq(_) => {
+                state.write_u8(27u8);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Binding {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Binding {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.ident.equiv(&other.ident, equiv) && self.ty.equiv(&other.ty, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.ident.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Block {}
+#[cfg(feature = "full")]
+impl Equivalent for Block {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.stmts.equiv(&other.stmts, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.stmts.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BoundLifetimes {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for BoundLifetimes {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.lifetimes.equiv(&other.lifetimes, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.lifetimes.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ConstParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ConstParam {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.ident.equiv(&other.ident, equiv)
+            && self.ty.equiv(&other.ty, equiv)
+            && self.eq_token.is_some() == other.eq_token.is_some()
+            && self.default.equiv(&other.default, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.ident.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+        self.eq_token.is_some().hash(state);
+        self.default.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Constraint {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Constraint {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.ident.equiv(&other.ident, equiv) && self.bounds.equiv(&other.bounds, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.ident.hash_equiv(equiv, state);
+        self.bounds.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for Data {}
+#[cfg(feature = "derive")]
+impl Equivalent for Data {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (Data::Struct(this0), Data::Struct(other0)) => this0.equiv(other0, equiv),
+            (Data::Enum(this0), Data::Enum(other0)) => this0.equiv(other0, equiv),
+            (Data::Union(this0), Data::Union(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            Data::Struct(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Data::Enum(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Data::Union(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataEnum {}
+#[cfg(feature = "derive")]
+impl Equivalent for DataEnum {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.variants.equiv(&other.variants, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.variants.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataStruct {}
+#[cfg(feature = "derive")]
+impl Equivalent for DataStruct {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.fields.equiv(&other.fields, equiv)
+            && self.semi_token.is_some() == other.semi_token.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.fields.hash_equiv(equiv, state);
+        self.semi_token.is_some().hash(state);
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataUnion {}
+#[cfg(feature = "derive")]
+impl Equivalent for DataUnion {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.fields.equiv(&other.fields, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.fields.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DeriveInput {}
+#[cfg(feature = "derive")]
+impl Equivalent for DeriveInput {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && self.generics.equiv(&other.generics, equiv)
+            && self.data.equiv(&other.data, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        self.generics.hash_equiv(equiv, state);
+        self.data.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Expr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Expr {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        if equiv.transparent_groups {
+            match self {
+                Expr::Paren(inner) if equiv.ignore_attrs || inner.attrs.is_empty() => {
+                    return Equivalent::equiv(&*inner.expr, other, equiv)
+                }
+                #[cfg(feature = "full")]
+                Expr::Group(inner) if equiv.ignore_attrs || inner.attrs.is_empty() => {
+                    return Equivalent::equiv(&*inner.expr, other, equiv)
+                }
+                _ => {}
+            }
+            match other {
+                Expr::Paren(inner) if equiv.ignore_attrs || inner.attrs.is_empty() => {
+                    return self.equiv(&inner.expr, equiv)
+                }
+                #[cfg(feature = "full")]
+                Expr::Group(inner) if equiv.ignore_attrs || inner.attrs.is_empty() => {
+                    return self.equiv(&inner.expr, equiv)
+                }
+                _ => {}
+            }
+        }
+        match (self, other) {
+            #[cfg(feature = "full")]
+            (Expr::Array(this0), Expr::Array(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Assign(this0), Expr::Assign(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::AssignOp(this0), Expr::AssignOp(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Async(this0), Expr::Async(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Await(this0), Expr::Await(other0)) => this0.equiv(other0, equiv),
+            (Expr::Binary(this0), Expr::Binary(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Block(this0), Expr::Block(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Box(this0), Expr::Box(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Break(this0), Expr::Break(other0)) => this0.equiv(other0, equiv),
+            (Expr::Call(this0), Expr::Call(other0)) => this0.equiv(other0, equiv),
+            (Expr::Cast(this0), Expr::Cast(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Closure(this0), Expr::Closure(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Continue(this0), Expr::Continue(other0)) => this0.equiv(other0, equiv),
+            (Expr::Field(this0), Expr::Field(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::ForLoop(this0), Expr::ForLoop(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Group(this0), Expr::Group(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::If(this0), Expr::If(other0)) => this0.equiv(other0, equiv),
+            (Expr::Index(this0), Expr::Index(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Let(this0), Expr::Let(other0)) => this0.equiv(other0, equiv),
+            (Expr::Lit(this0), Expr::Lit(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Loop(this0), Expr::Loop(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Macro(this0), Expr::Macro(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Match(this0), Expr::Match(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::MethodCall(this0), Expr::MethodCall(other0)) => this0.equiv(other0, equiv),
+            (Expr::Paren(this0), Expr::Paren(other0)) => this0.equiv(other0, equiv),
+            (Expr::Path(this0), Expr::Path(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Range(this0), Expr::Range(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Reference(this0), Expr::Reference(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Repeat(this0), Expr::Repeat(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Return(this0), Expr::Return(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Struct(this0), Expr::Struct(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Try(this0), Expr::Try(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::TryBlock(this0), Expr::TryBlock(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Tuple(this0), Expr::Tuple(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Type(this0), Expr::Type(other0)) => this0.equiv(other0, equiv),
+            (Expr::Unary(this0), Expr::Unary(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Unsafe(this0), Expr::Unsafe(other0)) => this0.equiv(other0, equiv),
+            (Expr::Verbatim(this0), Expr::Verbatim(other0)) => {
+                TokenStreamHelper(this0) == TokenStreamHelper(other0)
+            }
+            #[cfg(feature = "full")]
+            (Expr::While(this0), Expr::While(other0)) => this0.equiv(other0, equiv),
+            #[cfg(feature = "full")]
+            (Expr::Yield(this0), Expr::Yield(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if equiv.transparent_groups {
+            match self {
+                Expr::Paren(inner) if equiv.ignore_attrs || inner.attrs.is_empty() => {
+                    return inner.expr.hash_equiv(equiv, state)
+                }
+                #[cfg(feature = "full")]
+                Expr::Group(inner) if equiv.ignore_attrs || inner.attrs.is_empty() => {
+                    return inner.expr.hash_equiv(equiv, state)
+                }
+                _ => {}
+            }
+        }
+        match self {
+            #[cfg(feature = "full")]
+            Expr::Array(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Assign(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::AssignOp(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Async(this0) => {
+                state.write_u8(3u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Await(this0) => {
+                state.write_u8(4u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Expr::Binary(this0) => {
+                state.write_u8(5u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Block(this0) => {
+                state.write_u8(6u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Box(this0) => {
+                state.write_u8(7u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Break(this0) => {
+                state.write_u8(8u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Expr::Call(this0) => {
+                state.write_u8(9u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Expr::Cast(this0) => {
+                state.write_u8(10u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Closure(this0) => {
+                state.write_u8(11u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Continue(this0) => {
+                state.write_u8(12u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Expr::Field(this0) => {
+                state.write_u8(13u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::ForLoop(this0) => {
+                state.write_u8(14u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Group(this0) => {
+                state.write_u8(15u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::If(this0) => {
+                state.write_u8(16u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Expr::Index(this0) => {
+                state.write_u8(17u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Let(this0) => {
+                state.write_u8(18u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Expr::Lit(this0) => {
+                state.write_u8(19u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Loop(this0) => {
+                state.write_u8(20u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Macro(this0) => {
+                state.write_u8(21u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Match(this0) => {
+                state.write_u8(22u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::MethodCall(this0) => {
+                state.write_u8(23u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Expr::Paren(this0) => {
+                state.write_u8(24u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Expr::Path(this0) => {
+                state.write_u8(25u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Range(this0) => {
+                state.write_u8(26u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Reference(this0) => {
+                state.write_u8(27u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Repeat(this0) => {
+                state.write_u8(28u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Return(this0) => {
+                state.write_u8(29u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Struct(this0) => {
+                state.write_u8(30u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Try(this0) => {
+                state.write_u8(31u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::TryBlock(this0) => {
+                state.write_u8(32u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Tuple(this0) => {
+                state.write_u8(33u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Type(this0) => {
+                state.write_u8(34u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Expr::Unary(this0) => {
+                state.write_u8(35u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Unsafe(this0) => {
+                state.write_u8(36u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Expr::Verbatim(this0) => {
+                state.write_u8(37u8);
+                TokenStreamHelper(this0).hash(state);
+            }
+            #[cfg(feature = "full")]
+            Expr::While(this0) => {
+                state.write_u8(38u8);
+                this0.hash_equiv(equiv, state);
+            }
+            #[cfg(feature = "full")]
+            Expr::Yield(this0) => {
+                state.write_u8(39u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Expr::__Nonexhaustive => unreachable!(),
+            #[cfg(not(feature = "full"))]
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprArray {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprArray {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.elems.equiv(&other.elems, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.elems.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAssign {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprAssign {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.left.equiv(&other.left, equiv)
+            && self.right.equiv(&other.right, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.left.hash_equiv(equiv, state);
+        self.right.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAssignOp {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprAssignOp {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.left.equiv(&other.left, equiv)
+            && self.op.equiv(&other.op, equiv)
+            && self.right.equiv(&other.right, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.left.hash_equiv(equiv, state);
+        self.op.hash_equiv(equiv, state);
+        self.right.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAsync {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprAsync {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.capture.is_some() == other.capture.is_some()
+            && self.block.equiv(&other.block, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.capture.is_some().hash(state);
+        self.block.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAwait {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprAwait {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.base.equiv(&other.base, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.base.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprBinary {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ExprBinary {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.left.equiv(&other.left, equiv)
+            && self.op.equiv(&other.op, equiv)
+            && self.right.equiv(&other.right, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.left.hash_equiv(equiv, state);
+        self.op.hash_equiv(equiv, state);
+        self.right.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBlock {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprBlock {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.label.equiv(&other.label, equiv)
+            && self.block.equiv(&other.block, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.label.hash_equiv(equiv, state);
+        self.block.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBox {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprBox {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBreak {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprBreak {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.label.equiv(&other.label, equiv)
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.label.hash_equiv(equiv, state);
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprCall {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ExprCall {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.func.equiv(&other.func, equiv)
+            && self.args.equiv(&other.args, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.func.hash_equiv(equiv, state);
+        self.args.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprCast {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ExprCast {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+            && self.ty.equiv(&other.ty, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprClosure {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprClosure {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.asyncness.is_some() == other.asyncness.is_some()
+            && self.movability.is_some() == other.movability.is_some()
+            && self.capture.is_some() == other.capture.is_some()
+            && self.inputs.equiv(&other.inputs, equiv)
+            && self.output.equiv(&other.output, equiv)
+            && self.body.equiv(&other.body, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.asyncness.is_some().hash(state);
+        self.movability.is_some().hash(state);
+        self.capture.is_some().hash(state);
+        self.inputs.hash_equiv(equiv, state);
+        self.output.hash_equiv(equiv, state);
+        self.body.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprContinue {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprContinue {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.label.equiv(&other.label, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.label.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprField {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ExprField {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.base.equiv(&other.base, equiv)
+            && self.member.equiv(&other.member, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.base.hash_equiv(equiv, state);
+        self.member.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprForLoop {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprForLoop {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.label.equiv(&other.label, equiv)
+            && self.pat.equiv(&other.pat, equiv)
+            && self.expr.equiv(&other.expr, equiv)
+            && self.body.equiv(&other.body, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.label.hash_equiv(equiv, state);
+        self.pat.hash_equiv(equiv, state);
+        self.expr.hash_equiv(equiv, state);
+        self.body.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprGroup {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprGroup {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprIf {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprIf {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.cond.equiv(&other.cond, equiv)
+            && self.then_branch.equiv(&other.then_branch, equiv)
+            && match (&self.else_branch, &other.else_branch) {
+                (Some(this), Some(other)) => this.1.equiv(&other.1, equiv),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.cond.hash_equiv(equiv, state);
+        self.then_branch.hash_equiv(equiv, state);
+        match &self.else_branch {
+            Some(this) => {
+                state.write_u8(1);
+                this.1.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprIndex {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ExprIndex {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+            && self.index.equiv(&other.index, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+        self.index.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprLet {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprLet {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.pat.equiv(&other.pat, equiv)
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.pat.hash_equiv(equiv, state);
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprLit {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ExprLit {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.lit.equiv(&other.lit, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.lit.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprLoop {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprLoop {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.label.equiv(&other.label, equiv)
+            && self.body.equiv(&other.body, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.label.hash_equiv(equiv, state);
+        self.body.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMacro {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprMacro {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.mac.equiv(&other.mac, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.mac.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMatch {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprMatch {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+            && self.arms.equiv(&other.arms, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+        self.arms.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMethodCall {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprMethodCall {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.receiver.equiv(&other.receiver, equiv)
+            && self.method.equiv(&other.method, equiv)
+            && self.turbofish.equiv(&other.turbofish, equiv)
+            && self.args.equiv(&other.args, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.receiver.hash_equiv(equiv, state);
+        self.method.hash_equiv(equiv, state);
+        self.turbofish.hash_equiv(equiv, state);
+        self.args.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprParen {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ExprParen {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprPath {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ExprPath {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.qself.equiv(&other.qself, equiv)
+            && self.path.equiv(&other.path, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.qself.hash_equiv(equiv, state);
+        self.path.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprRange {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprRange {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.from.equiv(&other.from, equiv)
+            && self.limits.equiv(&other.limits, equiv)
+            && self.to.equiv(&other.to, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.from.hash_equiv(equiv, state);
+        self.limits.hash_equiv(equiv, state);
+        self.to.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprReference {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprReference {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.mutability.is_some() == other.mutability.is_some()
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.mutability.is_some().hash(state);
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprRepeat {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprRepeat {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+            && self.len.equiv(&other.len, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+        self.len.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprReturn {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprReturn {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprStruct {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprStruct {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.path.equiv(&other.path, equiv)
+            && self.fields.equiv(&other.fields, equiv)
+            && self.dot2_token.is_some() == other.dot2_token.is_some()
+            && self.rest.equiv(&other.rest, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.path.hash_equiv(equiv, state);
+        self.fields.hash_equiv(equiv, state);
+        self.dot2_token.is_some().hash(state);
+        self.rest.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTry {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprTry {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTryBlock {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprTryBlock {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.block.equiv(&other.block, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.block.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTuple {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprTuple {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.elems.equiv(&other.elems, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.elems.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprType {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprType {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+            && self.ty.equiv(&other.ty, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprUnary {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ExprUnary {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.op.equiv(&other.op, equiv)
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.op.hash_equiv(equiv, state);
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprUnsafe {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprUnsafe {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.block.equiv(&other.block, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.block.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprWhile {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprWhile {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.label.equiv(&other.label, equiv)
+            && self.cond.equiv(&other.cond, equiv)
+            && self.body.equiv(&other.body, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.label.hash_equiv(equiv, state);
+        self.cond.hash_equiv(equiv, state);
+        self.body.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprYield {}
+#[cfg(feature = "full")]
+impl Equivalent for ExprYield {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Field {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Field {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && self.colon_token.is_some() == other.colon_token.is_some()
+            && self.ty.equiv(&other.ty, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        self.colon_token.is_some().hash(state);
+        self.ty.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FieldPat {}
+#[cfg(feature = "full")]
+impl Equivalent for FieldPat {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.member.equiv(&other.member, equiv)
+            && self.colon_token.is_some() == other.colon_token.is_some()
+            && self.pat.equiv(&other.pat, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.member.hash_equiv(equiv, state);
+        self.colon_token.is_some().hash(state);
+        self.pat.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FieldValue {}
+#[cfg(feature = "full")]
+impl Equivalent for FieldValue {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.member.equiv(&other.member, equiv)
+            && self.colon_token.is_some() == other.colon_token.is_some()
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.member.hash_equiv(equiv, state);
+        self.colon_token.is_some().hash(state);
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Fields {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Fields {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (Fields::Named(this0), Fields::Named(other0)) => this0.equiv(other0, equiv),
+            (Fields::Unnamed(this0), Fields::Unnamed(other0)) => this0.equiv(other0, equiv),
+            (Fields::Unit, Fields::Unit) => true,
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            Fields::Named(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Fields::Unnamed(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Fields::Unit => {
+                state.write_u8(2u8);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for FieldsNamed {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for FieldsNamed {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.named.equiv(&other.named, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.named.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for FieldsUnnamed {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for FieldsUnnamed {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.unnamed.equiv(&other.unnamed, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.unnamed.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for File {}
+#[cfg(feature = "full")]
+impl Equivalent for File {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.shebang == other.shebang
+            && (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.items.equiv(&other.items, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.shebang.hash(state);
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.items.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FnArg {}
+#[cfg(feature = "full")]
+impl Equivalent for FnArg {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (FnArg::Receiver(this0), FnArg::Receiver(other0)) => this0.equiv(other0, equiv),
+            (FnArg::Typed(this0), FnArg::Typed(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            FnArg::Receiver(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            FnArg::Typed(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItem {}
+#[cfg(feature = "full")]
+impl Equivalent for ForeignItem {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (ForeignItem::Fn(this0), ForeignItem::Fn(other0)) => this0.equiv(other0, equiv),
+            (ForeignItem::Static(this0), ForeignItem::Static(other0)) => this0.equiv(other0, equiv),
+            (ForeignItem::Type(this0), ForeignItem::Type(other0)) => this0.equiv(other0, equiv),
+            (ForeignItem::Macro(this0), ForeignItem::Macro(other0)) => this0.equiv(other0, equiv),
+            (ForeignItem::Verbatim(this0), ForeignItem::Verbatim(other0)) => {
+                TokenStreamHelper(this0) == TokenStreamHelper(other0)
+            }
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            ForeignItem::Fn(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            ForeignItem::Static(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            ForeignItem::Type(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            ForeignItem::Macro(this0) => {
+                state.write_u8(3u8);
+                this0.hash_equiv(equiv, state);
+            }
+            ForeignItem::Verbatim(this0) => {
+                state.write_u8(4u8);
+                TokenStreamHelper(this0).hash(state);
+            }
+            ForeignItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemFn {}
+#[cfg(feature = "full")]
+impl Equivalent for ForeignItemFn {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.sig.equiv(&other.sig, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.sig.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemMacro {}
+#[cfg(feature = "full")]
+impl Equivalent for ForeignItemMacro {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.mac.equiv(&other.mac, equiv)
+            && self.semi_token.is_some() == other.semi_token.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.mac.hash_equiv(equiv, state);
+        self.semi_token.is_some().hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemStatic {}
+#[cfg(feature = "full")]
+impl Equivalent for ForeignItemStatic {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.mutability.is_some() == other.mutability.is_some()
+            && self.ident.equiv(&other.ident, equiv)
+            && self.ty.equiv(&other.ty, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.mutability.is_some().hash(state);
+        self.ident.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemType {}
+#[cfg(feature = "full")]
+impl Equivalent for ForeignItemType {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for GenericArgument {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for GenericArgument {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (GenericArgument::Lifetime(this0), GenericArgument::Lifetime(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (GenericArgument::Type(this0), GenericArgument::Type(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (GenericArgument::Binding(this0), GenericArgument::Binding(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (GenericArgument::Constraint(this0), GenericArgument::Constraint(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (GenericArgument::Const(this0), GenericArgument::Const(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            GenericArgument::Lifetime(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            GenericArgument::Type(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            GenericArgument::Binding(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            GenericArgument::Constraint(this0) => {
+                state.write_u8(3u8);
+                this0.hash_equiv(equiv, state);
+            }
+            GenericArgument::Const(this0) => {
+                state.write_u8(4u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for GenericMethodArgument {}
+#[cfg(feature = "full")]
+impl Equivalent for GenericMethodArgument {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (GenericMethodArgument::Type(this0), GenericMethodArgument::Type(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (GenericMethodArgument::Const(this0), GenericMethodArgument::Const(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            GenericMethodArgument::Type(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            GenericMethodArgument::Const(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for GenericParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for GenericParam {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (GenericParam::Type(this0), GenericParam::Type(other0)) => this0.equiv(other0, equiv),
+            (GenericParam::Lifetime(this0), GenericParam::Lifetime(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (GenericParam::Const(this0), GenericParam::Const(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            GenericParam::Type(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            GenericParam::Lifetime(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            GenericParam::Const(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Generics {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Generics {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.lt_token.is_some() == other.lt_token.is_some()
+            && self.params.equiv(&other.params, equiv)
+            && self.gt_token.is_some() == other.gt_token.is_some()
+            && self.where_clause.equiv(&other.where_clause, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.lt_token.is_some().hash(state);
+        self.params.hash_equiv(equiv, state);
+        self.gt_token.is_some().hash(state);
+        self.where_clause.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItem {}
+#[cfg(feature = "full")]
+impl Equivalent for ImplItem {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (ImplItem::Const(this0), ImplItem::Const(other0)) => this0.equiv(other0, equiv),
+            (ImplItem::Method(this0), ImplItem::Method(other0)) => this0.equiv(other0, equiv),
+            (ImplItem::Type(this0), ImplItem::Type(other0)) => this0.equiv(other0, equiv),
+            (ImplItem::Macro(this0), ImplItem::Macro(other0)) => this0.equiv(other0, equiv),
+            (ImplItem::Verbatim(this0), ImplItem::Verbatim(other0)) => {
+                TokenStreamHelper(this0) == TokenStreamHelper(other0)
+            }
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            ImplItem::Const(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            ImplItem::Method(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            ImplItem::Type(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            ImplItem::Macro(this0) => {
+                state.write_u8(3u8);
+                this0.hash_equiv(equiv, state);
+            }
+            ImplItem::Verbatim(this0) => {
+                state.write_u8(4u8);
+                TokenStreamHelper(this0).hash(state);
+            }
+            ImplItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemConst {}
+#[cfg(feature = "full")]
+impl Equivalent for ImplItemConst {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.defaultness.is_some() == other.defaultness.is_some()
+            && self.ident.equiv(&other.ident, equiv)
+            && self.ty.equiv(&other.ty, equiv)
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.defaultness.is_some().hash(state);
+        self.ident.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemMacro {}
+#[cfg(feature = "full")]
+impl Equivalent for ImplItemMacro {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.mac.equiv(&other.mac, equiv)
+            && self.semi_token.is_some() == other.semi_token.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.mac.hash_equiv(equiv, state);
+        self.semi_token.is_some().hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemMethod {}
+#[cfg(feature = "full")]
+impl Equivalent for ImplItemMethod {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.defaultness.is_some() == other.defaultness.is_some()
+            && self.sig.equiv(&other.sig, equiv)
+            && self.block.equiv(&other.block, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.defaultness.is_some().hash(state);
+        self.sig.hash_equiv(equiv, state);
+        self.block.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemType {}
+#[cfg(feature = "full")]
+impl Equivalent for ImplItemType {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.defaultness.is_some() == other.defaultness.is_some()
+            && self.ident.equiv(&other.ident, equiv)
+            && self.generics.equiv(&other.generics, equiv)
+            && self.ty.equiv(&other.ty, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.defaultness.is_some().hash(state);
+        self.ident.hash_equiv(equiv, state);
+        self.generics.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Index {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Index {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        self.index == other.index
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Item {}
+#[cfg(feature = "full")]
+impl Equivalent for Item {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (Item::Const(this0), Item::Const(other0)) => this0.equiv(other0, equiv),
+            (Item::Enum(this0), Item::Enum(other0)) => this0.equiv(other0, equiv),
+            (Item::ExternCrate(this0), Item::ExternCrate(other0)) => this0.equiv(other0, equiv),
+            (Item::Fn(this0), Item::Fn(other0)) => this0.equiv(other0, equiv),
+            (Item::ForeignMod(this0), Item::ForeignMod(other0)) => this0.equiv(other0, equiv),
+            (Item::Impl(this0), Item::Impl(other0)) => this0.equiv(other0, equiv),
+            (Item::Macro(this0), Item::Macro(other0)) => this0.equiv(other0, equiv),
+            (Item::Macro2(this0), Item::Macro2(other0)) => this0.equiv(other0, equiv),
+            (Item::Mod(this0), Item::Mod(other0)) => this0.equiv(other0, equiv),
+            (Item::Static(this0), Item::Static(other0)) => this0.equiv(other0, equiv),
+            (Item::Struct(this0), Item::Struct(other0)) => this0.equiv(other0, equiv),
+            (Item::Trait(this0), Item::Trait(other0)) => this0.equiv(other0, equiv),
+            (Item::TraitAlias(this0), Item::TraitAlias(other0)) => this0.equiv(other0, equiv),
+            (Item::Type(this0), Item::Type(other0)) => this0.equiv(other0, equiv),
+            (Item::Union(this0), Item::Union(other0)) => this0.equiv(other0, equiv),
+            (Item::Use(this0), Item::Use(other0)) => this0.equiv(other0, equiv),
+            (Item::Verbatim(this0), Item::Verbatim(other0)) => {
+                TokenStreamHelper(this0) == TokenStreamHelper(other0)
+            }
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            Item::Const(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Enum(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::ExternCrate(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Fn(this0) => {
+                state.write_u8(3u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::ForeignMod(this0) => {
+                state.write_u8(4u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Impl(this0) => {
+                state.write_u8(5u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Macro(this0) => {
+                state.write_u8(6u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Macro2(this0) => {
+                state.write_u8(7u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Mod(this0) => {
+                state.write_u8(8u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Static(this0) => {
+                state.write_u8(9u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Struct(this0) => {
+                state.write_u8(10u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Trait(this0) => {
+                state.write_u8(11u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::TraitAlias(this0) => {
+                state.write_u8(12u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Type(this0) => {
+                state.write_u8(13u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Union(this0) => {
+                state.write_u8(14u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Use(this0) => {
+                state.write_u8(15u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Item::Verbatim(this0) => {
+                state.write_u8(16u8);
+                TokenStreamHelper(this0).hash(state);
+            }
+            Item::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemConst {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemConst {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && self.ty.equiv(&other.ty, equiv)
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemEnum {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemEnum {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && self.generics.equiv(&other.generics, equiv)
+            && self.variants.equiv(&other.variants, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        self.generics.hash_equiv(equiv, state);
+        self.variants.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemExternCrate {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemExternCrate {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && match (&self.rename, &other.rename) {
+                (Some(this), Some(other)) => this.1.equiv(&other.1, equiv),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        match &self.rename {
+            Some(this) => {
+                state.write_u8(1);
+                this.1.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemFn {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemFn {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.sig.equiv(&other.sig, equiv)
+            && self.block.equiv(&other.block, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.sig.hash_equiv(equiv, state);
+        self.block.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemForeignMod {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemForeignMod {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.abi.equiv(&other.abi, equiv)
+            && self.items.equiv(&other.items, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.abi.hash_equiv(equiv, state);
+        self.items.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemImpl {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemImpl {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.defaultness.is_some() == other.defaultness.is_some()
+            && self.unsafety.is_some() == other.unsafety.is_some()
+            && self.generics.equiv(&other.generics, equiv)
+            && match (&self.trait_, &other.trait_) {
+                (Some(this), Some(other)) => {
+                    this.0.is_some() == other.0.is_some() && this.1.equiv(&other.1, equiv)
+                }
+                (None, None) => true,
+                _ => false,
+            }
+            && self.self_ty.equiv(&other.self_ty, equiv)
+            && self.items.equiv(&other.items, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.defaultness.is_some().hash(state);
+        self.unsafety.is_some().hash(state);
+        self.generics.hash_equiv(equiv, state);
+        match &self.trait_ {
+            Some(this) => {
+                state.write_u8(1);
+                this.0.is_some().hash(state);
+                this.1.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+        self.self_ty.hash_equiv(equiv, state);
+        self.items.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMacro {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemMacro {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.ident.equiv(&other.ident, equiv)
+            && self.mac.equiv(&other.mac, equiv)
+            && self.semi_token.is_some() == other.semi_token.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.ident.hash_equiv(equiv, state);
+        self.mac.hash_equiv(equiv, state);
+        self.semi_token.is_some().hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMacro2 {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemMacro2 {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && TokenStreamHelper(&self.rules) == TokenStreamHelper(&other.rules)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        TokenStreamHelper(&self.rules).hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMod {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemMod {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && match (&self.content, &other.content) {
+                (Some(this), Some(other)) => this.1.equiv(&other.1, equiv),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.semi.is_some() == other.semi.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        match &self.content {
+            Some(this) => {
+                state.write_u8(1);
+                this.1.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+        self.semi.is_some().hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemStatic {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemStatic {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.mutability.is_some() == other.mutability.is_some()
+            && self.ident.equiv(&other.ident, equiv)
+            && self.ty.equiv(&other.ty, equiv)
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.mutability.is_some().hash(state);
+        self.ident.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemStruct {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemStruct {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && self.generics.equiv(&other.generics, equiv)
+            && self.fields.equiv(&other.fields, equiv)
+            && self.semi_token.is_some() == other.semi_token.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        self.generics.hash_equiv(equiv, state);
+        self.fields.hash_equiv(equiv, state);
+        self.semi_token.is_some().hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemTrait {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemTrait {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.unsafety.is_some() == other.unsafety.is_some()
+            && self.auto_token.is_some() == other.auto_token.is_some()
+            && self.ident.equiv(&other.ident, equiv)
+            && self.generics.equiv(&other.generics, equiv)
+            && self.colon_token.is_some() == other.colon_token.is_some()
+            && self.supertraits.equiv(&other.supertraits, equiv)
+            && self.items.equiv(&other.items, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.unsafety.is_some().hash(state);
+        self.auto_token.is_some().hash(state);
+        self.ident.hash_equiv(equiv, state);
+        self.generics.hash_equiv(equiv, state);
+        self.colon_token.is_some().hash(state);
+        self.supertraits.hash_equiv(equiv, state);
+        self.items.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemTraitAlias {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemTraitAlias {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && self.generics.equiv(&other.generics, equiv)
+            && self.bounds.equiv(&other.bounds, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        self.generics.hash_equiv(equiv, state);
+        self.bounds.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemType {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemType {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && self.generics.equiv(&other.generics, equiv)
+            && self.ty.equiv(&other.ty, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        self.generics.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemUnion {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemUnion {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && self.generics.equiv(&other.generics, equiv)
+            && self.fields.equiv(&other.fields, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        self.generics.hash_equiv(equiv, state);
+        self.fields.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemUse {}
+#[cfg(feature = "full")]
+impl Equivalent for ItemUse {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.vis.equiv(&other.vis, equiv)
+            && self.leading_colon.is_some() == other.leading_colon.is_some()
+            && self.tree.equiv(&other.tree, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.vis.hash_equiv(equiv, state);
+        self.leading_colon.is_some().hash(state);
+        self.tree.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Label {}
+#[cfg(feature = "full")]
+impl Equivalent for Label {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.name.equiv(&other.name, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.name.hash_equiv(equiv, state);
+    }
+}
+impl Sealed for Lifetime {}
+impl Equivalent for Lifetime {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.ident.equiv(&other.ident, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.ident.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LifetimeDef {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for LifetimeDef {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.lifetime.equiv(&other.lifetime, equiv)
+            && self.colon_token.is_some() == other.colon_token.is_some()
+            && self.bounds.equiv(&other.bounds, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.lifetime.hash_equiv(equiv, state);
+        self.colon_token.is_some().hash(state);
+        self.bounds.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Lit {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Lit {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (Lit::Str(this0), Lit::Str(other0)) => this0.equiv(other0, equiv),
+            (Lit::ByteStr(this0), Lit::ByteStr(other0)) => this0.equiv(other0, equiv),
+            (Lit::Byte(this0), Lit::Byte(other0)) => this0.equiv(other0, equiv),
+            (Lit::Char(this0), Lit::Char(other0)) => this0.equiv(other0, equiv),
+            (Lit::Int(this0), Lit::Int(other0)) => this0.equiv(other0, equiv),
+            (Lit::Float(this0), Lit::Float(other0)) => this0.equiv(other0, equiv),
+            (Lit::Bool(this0), Lit::Bool(other0)) => this0.equiv(other0, equiv),
+            (Lit::Verbatim(this0), Lit::Verbatim(other0)) => {
+                this0.to_string() == other0.to_string()
+            }
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            Lit::Str(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Lit::ByteStr(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Lit::Byte(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Lit::Char(this0) => {
+                state.write_u8(3u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Lit::Int(this0) => {
+                state.write_u8(4u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Lit::Float(this0) => {
+                state.write_u8(5u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Lit::Bool(this0) => {
+                state.write_u8(6u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Lit::Verbatim(this0) => {
+                state.write_u8(7u8);
+                this0.to_string().hash(state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitBool {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for LitBool {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        self.value == other.value
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitByte {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for LitByte {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        self == other
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        self.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitByteStr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for LitByteStr {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        self == other
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        self.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitChar {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for LitChar {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        self == other
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        self.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitFloat {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for LitFloat {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        self == other
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        self.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitInt {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for LitInt {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        self == other
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        self.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitStr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for LitStr {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        self == other
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        self.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Local {}
+#[cfg(feature = "full")]
+impl Equivalent for Local {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.pat.equiv(&other.pat, equiv)
+            && match (&self.init, &other.init) {
+                (Some(this), Some(other)) => this.1.equiv(&other.1, equiv),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.pat.hash_equiv(equiv, state);
+        match &self.init {
+            Some(this) => {
+                state.write_u8(1);
+                this.1.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Macro {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Macro {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.path.equiv(&other.path, equiv)
+            && self.delimiter.equiv(&other.delimiter, equiv)
+            && TokenStreamHelper(&self.tokens) == TokenStreamHelper(&other.tokens)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.path.hash_equiv(equiv, state);
+        self.delimiter.hash_equiv(equiv, state);
+        TokenStreamHelper(&self.tokens).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MacroDelimiter {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for MacroDelimiter {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (MacroDelimiter::Paren(_), MacroDelimiter::Paren(_)) => true,
+            (MacroDelimiter::Brace(_), MacroDelimiter::Brace(_)) => true,
+            (MacroDelimiter::Bracket(_), MacroDelimiter::Bracket(_)) => true,
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        match self {
+            MacroDelimiter::Paren(_) => {
+                state.write_u8(0u8);
+            }
+            MacroDelimiter::Brace(_) => {
+                state.write_u8(1u8);
+            }
+            MacroDelimiter::Bracket(_) => {
+                state.write_u8(2u8);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Member {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Member {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (Member::Named(this0), Member::Named(other0)) => this0.equiv(other0, equiv),
+            (Member::Unnamed(this0), Member::Unnamed(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            Member::Named(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Member::Unnamed(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Meta {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Meta {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (Meta::Path(this0), Meta::Path(other0)) => this0.equiv(other0, equiv),
+            (Meta::List(this0), Meta::List(other0)) => this0.equiv(other0, equiv),
+            (Meta::NameValue(this0), Meta::NameValue(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            Meta::Path(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Meta::List(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Meta::NameValue(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExpr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for MetaExpr {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (MetaExpr::Path(this0), MetaExpr::Path(other0)) => this0.equiv(other0, equiv),
+            (MetaExpr::List(this0), MetaExpr::List(other0)) => this0.equiv(other0, equiv),
+            (MetaExpr::NameValue(this0), MetaExpr::NameValue(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            MetaExpr::Path(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            MetaExpr::List(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            MetaExpr::NameValue(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExprList {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for MetaExprList {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.path.equiv(&other.path, equiv) && self.nested.equiv(&other.nested, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.path.hash_equiv(equiv, state);
+        self.nested.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExprNameValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for MetaExprNameValue {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.path.equiv(&other.path, equiv) && self.value.equiv(&other.value, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.path.hash_equiv(equiv, state);
+        self.value.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaList {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for MetaList {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.path.equiv(&other.path, equiv) && self.nested.equiv(&other.nested, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.path.hash_equiv(equiv, state);
+        self.nested.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaNameValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for MetaNameValue {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.path.equiv(&other.path, equiv) && self.lit.equiv(&other.lit, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.path.hash_equiv(equiv, state);
+        self.lit.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for MetaValue {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (MetaValue::Expr(this0), MetaValue::Expr(other0)) => this0.equiv(other0, equiv),
+            (MetaValue::Verbatim(this0), MetaValue::Verbatim(other0)) => {
+                TokenStreamHelper(this0) == TokenStreamHelper(other0)
+            }
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            MetaValue::Expr(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            MetaValue::Verbatim(this0) => {
+                state.write_u8(1u8);
+                TokenStreamHelper(this0).hash(state);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for MethodTurbofish {}
+#[cfg(feature = "full")]
+impl Equivalent for MethodTurbofish {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.args.equiv(&other.args, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.args.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for NestedMeta {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for NestedMeta {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (NestedMeta::Meta(this0), NestedMeta::Meta(other0)) => this0.equiv(other0, equiv),
+            (NestedMeta::Lit(this0), NestedMeta::Lit(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            NestedMeta::Meta(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            NestedMeta::Lit(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for NestedMetaExpr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for NestedMetaExpr {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (NestedMetaExpr::Meta(this0), NestedMetaExpr::Meta(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (NestedMetaExpr::Lit(this0), NestedMetaExpr::Lit(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            NestedMetaExpr::Meta(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            NestedMetaExpr::Lit(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ParenthesizedGenericArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ParenthesizedGenericArguments {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.inputs.equiv(&other.inputs, equiv) && self.output.equiv(&other.output, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.inputs.hash_equiv(equiv, state);
+        self.output.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Pat {}
+#[cfg(feature = "full")]
+impl Equivalent for Pat {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (Pat::Box(this0), Pat::Box(other0)) => this0.equiv(other0, equiv),
+            (Pat::Ident(this0), Pat::Ident(other0)) => this0.equiv(other0, equiv),
+            (Pat::Lit(this0), Pat::Lit(other0)) => this0.equiv(other0, equiv),
+            (Pat::Macro(this0), Pat::Macro(other0)) => this0.equiv(other0, equiv),
+            (Pat::Or(this0), Pat::Or(other0)) => this0.equiv(other0, equiv),
+            (Pat::Path(this0), Pat::Path(other0)) => this0.equiv(other0, equiv),
+            (Pat::Range(this0), Pat::Range(other0)) => this0.equiv(other0, equiv),
+            (Pat::Reference(this0), Pat::Reference(other0)) => this0.equiv(other0, equiv),
+            (Pat::Rest(this0), Pat::Rest(other0)) => this0.equiv(other0, equiv),
+            (Pat::Slice(this0), Pat::Slice(other0)) => this0.equiv(other0, equiv),
+            (Pat::Struct(this0), Pat::Struct(other0)) => this0.equiv(other0, equiv),
+            (Pat::Tuple(this0), Pat::Tuple(other0)) => this0.equiv(other0, equiv),
+            (Pat::TupleStruct(this0), Pat::TupleStruct(other0)) => this0.equiv(other0, equiv),
+            (Pat::Type(this0), Pat::Type(other0)) => this0.equiv(other0, equiv),
+            (Pat::Verbatim(this0), Pat::Verbatim(other0)) => {
+                TokenStreamHelper(this0) == TokenStreamHelper(other0)
+            }
+            (Pat::Wild(this0), Pat::Wild(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            Pat::Box(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Ident(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Lit(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Macro(this0) => {
+                state.write_u8(3u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Or(this0) => {
+                state.write_u8(4u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Path(this0) => {
+                state.write_u8(5u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Range(this0) => {
+                state.write_u8(6u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Reference(this0) => {
+                state.write_u8(7u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Rest(this0) => {
+                state.write_u8(8u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Slice(this0) => {
+                state.write_u8(9u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Struct(this0) => {
+                state.write_u8(10u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Tuple(this0) => {
+                state.write_u8(11u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::TupleStruct(this0) => {
+                state.write_u8(12u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Type(this0) => {
+                state.write_u8(13u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::Verbatim(this0) => {
+                state.write_u8(14u8);
+                TokenStreamHelper(this0).hash(state);
+            }
+            Pat::Wild(this0) => {
+                state.write_u8(15u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Pat::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatBox {}
+#[cfg(feature = "full")]
+impl Equivalent for PatBox {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.pat.equiv(&other.pat, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.pat.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatIdent {}
+#[cfg(feature = "full")]
+impl Equivalent for PatIdent {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.by_ref.is_some() == other.by_ref.is_some()
+            && self.mutability.is_some() == other.mutability.is_some()
+            && self.ident.equiv(&other.ident, equiv)
+            && match (&self.subpat, &other.subpat) {
+                (Some(this), Some(other)) => this.1.equiv(&other.1, equiv),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.by_ref.is_some().hash(state);
+        self.mutability.is_some().hash(state);
+        self.ident.hash_equiv(equiv, state);
+        match &self.subpat {
+            Some(this) => {
+                state.write_u8(1);
+                this.1.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatLit {}
+#[cfg(feature = "full")]
+impl Equivalent for PatLit {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.expr.equiv(&other.expr, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.expr.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatMacro {}
+#[cfg(feature = "full")]
+impl Equivalent for PatMacro {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.mac.equiv(&other.mac, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.mac.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatOr {}
+#[cfg(feature = "full")]
+impl Equivalent for PatOr {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.leading_vert.is_some() == other.leading_vert.is_some()
+            && self.cases.equiv(&other.cases, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.leading_vert.is_some().hash(state);
+        self.cases.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatPath {}
+#[cfg(feature = "full")]
+impl Equivalent for PatPath {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.qself.equiv(&other.qself, equiv)
+            && self.path.equiv(&other.path, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.qself.hash_equiv(equiv, state);
+        self.path.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatRange {}
+#[cfg(feature = "full")]
+impl Equivalent for PatRange {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.lo.equiv(&other.lo, equiv)
+            && self.limits.equiv(&other.limits, equiv)
+            && self.hi.equiv(&other.hi, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.lo.hash_equiv(equiv, state);
+        self.limits.hash_equiv(equiv, state);
+        self.hi.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatReference {}
+#[cfg(feature = "full")]
+impl Equivalent for PatReference {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.mutability.is_some() == other.mutability.is_some()
+            && self.pat.equiv(&other.pat, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.mutability.is_some().hash(state);
+        self.pat.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatRest {}
+#[cfg(feature = "full")]
+impl Equivalent for PatRest {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatSlice {}
+#[cfg(feature = "full")]
+impl Equivalent for PatSlice {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.elems.equiv(&other.elems, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.elems.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatStruct {}
+#[cfg(feature = "full")]
+impl Equivalent for PatStruct {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.path.equiv(&other.path, equiv)
+            && self.fields.equiv(&other.fields, equiv)
+            && self.dot2_token.is_some() == other.dot2_token.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.path.hash_equiv(equiv, state);
+        self.fields.hash_equiv(equiv, state);
+        self.dot2_token.is_some().hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatTuple {}
+#[cfg(feature = "full")]
+impl Equivalent for PatTuple {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.elems.equiv(&other.elems, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.elems.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatTupleStruct {}
+#[cfg(feature = "full")]
+impl Equivalent for PatTupleStruct {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.path.equiv(&other.path, equiv)
+            && self.pat.equiv(&other.pat, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.path.hash_equiv(equiv, state);
+        self.pat.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatType {}
+#[cfg(feature = "full")]
+impl Equivalent for PatType {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.pat.equiv(&other.pat, equiv)
+            && self.ty.equiv(&other.ty, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.pat.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatWild {}
+#[cfg(feature = "full")]
+impl Equivalent for PatWild {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Path {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Path {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.leading_colon.is_some() == other.leading_colon.is_some()
+            && self.segments.equiv(&other.segments, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.leading_colon.is_some().hash(state);
+        self.segments.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PathArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for PathArguments {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (PathArguments::None, PathArguments::None) => true,
+            (PathArguments::AngleBracketed(this0), PathArguments::AngleBracketed(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (PathArguments::Parenthesized(this0), PathArguments::Parenthesized(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            PathArguments::None => {
+                state.write_u8(0u8);
+            }
+            PathArguments::AngleBracketed(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            PathArguments::Parenthesized(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PathSegment {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for PathSegment {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.ident.equiv(&other.ident, equiv) && self.arguments.equiv(&other.arguments, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.ident.hash_equiv(equiv, state);
+        self.arguments.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateEq {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for PredicateEq {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.lhs_ty.equiv(&other.lhs_ty, equiv) && self.rhs_ty.equiv(&other.rhs_ty, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.lhs_ty.hash_equiv(equiv, state);
+        self.rhs_ty.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateLifetime {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for PredicateLifetime {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.lifetime.equiv(&other.lifetime, equiv) && self.bounds.equiv(&other.bounds, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.lifetime.hash_equiv(equiv, state);
+        self.bounds.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateType {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for PredicateType {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.lifetimes.equiv(&other.lifetimes, equiv)
+            && self.bounded_ty.equiv(&other.bounded_ty, equiv)
+            && self.bounds.equiv(&other.bounds, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.lifetimes.hash_equiv(equiv, state);
+        self.bounded_ty.hash_equiv(equiv, state);
+        self.bounds.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for QSelf {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for QSelf {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.ty.equiv(&other.ty, equiv)
+            && self.position == other.position
+            && self.as_token.is_some() == other.as_token.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.ty.hash_equiv(equiv, state);
+        self.position.hash(state);
+        self.as_token.is_some().hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for RangeLimits {}
+#[cfg(feature = "full")]
+impl Equivalent for RangeLimits {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (RangeLimits::HalfOpen(_), RangeLimits::HalfOpen(_)) => true,
+            (RangeLimits::Closed(_), RangeLimits::Closed(_)) => true,
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        match self {
+            RangeLimits::HalfOpen(_) => {
+                state.write_u8(0u8);
+            }
+            RangeLimits::Closed(_) => {
+                state.write_u8(1u8);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Receiver {}
+#[cfg(feature = "full")]
+impl Equivalent for Receiver {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && match (&self.reference, &other.reference) {
+                (Some(this), Some(other)) => this.1.equiv(&other.1, equiv),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.mutability.is_some() == other.mutability.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        match &self.reference {
+            Some(this) => {
+                state.write_u8(1);
+                this.1.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+        self.mutability.is_some().hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ReturnType {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for ReturnType {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (ReturnType::Default, ReturnType::Default) => true,
+            (ReturnType::Type(_, this1), ReturnType::Type(_, other1)) => this1.equiv(other1, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            ReturnType::Default => {
+                state.write_u8(0u8);
+            }
+            ReturnType::Type(_, this1) => {
+                state.write_u8(1u8);
+                this1.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Signature {}
+#[cfg(feature = "full")]
+impl Equivalent for Signature {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.constness.is_some() == other.constness.is_some()
+            && self.asyncness.is_some() == other.asyncness.is_some()
+            && self.unsafety.is_some() == other.unsafety.is_some()
+            && self.abi.equiv(&other.abi, equiv)
+            && self.ident.equiv(&other.ident, equiv)
+            && self.generics.equiv(&other.generics, equiv)
+            && self.inputs.equiv(&other.inputs, equiv)
+            && self.variadic.equiv(&other.variadic, equiv)
+            && self.output.equiv(&other.output, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.constness.is_some().hash(state);
+        self.asyncness.is_some().hash(state);
+        self.unsafety.is_some().hash(state);
+        self.abi.hash_equiv(equiv, state);
+        self.ident.hash_equiv(equiv, state);
+        self.generics.hash_equiv(equiv, state);
+        self.inputs.hash_equiv(equiv, state);
+        self.variadic.hash_equiv(equiv, state);
+        self.output.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Stmt {}
+#[cfg(feature = "full")]
+impl Equivalent for Stmt {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (Stmt::Local(this0), Stmt::Local(other0)) => this0.equiv(other0, equiv),
+            (Stmt::Item(this0), Stmt::Item(other0)) => this0.equiv(other0, equiv),
+            (Stmt::Expr(this0), Stmt::Expr(other0)) => this0.equiv(other0, equiv),
+            (Stmt::Semi(this0, _), Stmt::Semi(other0, _)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            Stmt::Local(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Stmt::Item(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Stmt::Expr(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Stmt::Semi(this0, _) => {
+                state.write_u8(3u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TraitBound {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TraitBound {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.paren_token.is_some() == other.paren_token.is_some()
+            && self.modifier.equiv(&other.modifier, equiv)
+            && self.lifetimes.equiv(&other.lifetimes, equiv)
+            && self.path.equiv(&other.path, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.paren_token.is_some().hash(state);
+        self.modifier.hash_equiv(equiv, state);
+        self.lifetimes.hash_equiv(equiv, state);
+        self.path.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TraitBoundModifier {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TraitBoundModifier {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (TraitBoundModifier::None, TraitBoundModifier::None) => true,
+            (TraitBoundModifier::Maybe(_), TraitBoundModifier::Maybe(_)) => true,
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        match self {
+            TraitBoundModifier::None => {
+                state.write_u8(0u8);
+            }
+            TraitBoundModifier::Maybe(_) => {
+                state.write_u8(1u8);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItem {}
+#[cfg(feature = "full")]
+impl Equivalent for TraitItem {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (TraitItem::Const(this0), TraitItem::Const(other0)) => this0.equiv(other0, equiv),
+            (TraitItem::Method(this0), TraitItem::Method(other0)) => this0.equiv(other0, equiv),
+            (TraitItem::Type(this0), TraitItem::Type(other0)) => this0.equiv(other0, equiv),
+            (TraitItem::Macro(this0), TraitItem::Macro(other0)) => this0.equiv(other0, equiv),
+            (TraitItem::Verbatim(this0), TraitItem::Verbatim(other0)) => {
+                TokenStreamHelper(this0) == TokenStreamHelper(other0)
+            }
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            TraitItem::Const(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            TraitItem::Method(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            TraitItem::Type(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            TraitItem::Macro(this0) => {
+                state.write_u8(3u8);
+                this0.hash_equiv(equiv, state);
+            }
+            TraitItem::Verbatim(this0) => {
+                state.write_u8(4u8);
+                TokenStreamHelper(this0).hash(state);
+            }
+            TraitItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemConst {}
+#[cfg(feature = "full")]
+impl Equivalent for TraitItemConst {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.ident.equiv(&other.ident, equiv)
+            && self.ty.equiv(&other.ty, equiv)
+            && match (&self.default, &other.default) {
+                (Some(this), Some(other)) => this.1.equiv(&other.1, equiv),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.ident.hash_equiv(equiv, state);
+        self.ty.hash_equiv(equiv, state);
+        match &self.default {
+            Some(this) => {
+                state.write_u8(1);
+                this.1.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemMacro {}
+#[cfg(feature = "full")]
+impl Equivalent for TraitItemMacro {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.mac.equiv(&other.mac, equiv)
+            && self.semi_token.is_some() == other.semi_token.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.mac.hash_equiv(equiv, state);
+        self.semi_token.is_some().hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemMethod {}
+#[cfg(feature = "full")]
+impl Equivalent for TraitItemMethod {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.sig.equiv(&other.sig, equiv)
+            && self.default.equiv(&other.default, equiv)
+            && self.semi_token.is_some() == other.semi_token.is_some()
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.sig.hash_equiv(equiv, state);
+        self.default.hash_equiv(equiv, state);
+        self.semi_token.is_some().hash(state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemType {}
+#[cfg(feature = "full")]
+impl Equivalent for TraitItemType {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.ident.equiv(&other.ident, equiv)
+            && self.generics.equiv(&other.generics, equiv)
+            && self.colon_token.is_some() == other.colon_token.is_some()
+            && self.bounds.equiv(&other.bounds, equiv)
+            && match (&self.default, &other.default) {
+                (Some(this), Some(other)) => this.1.equiv(&other.1, equiv),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.ident.hash_equiv(equiv, state);
+        self.generics.hash_equiv(equiv, state);
+        self.colon_token.is_some().hash(state);
+        self.bounds.hash_equiv(equiv, state);
+        match &self.default {
+            Some(this) => {
+                state.write_u8(1);
+                this.1.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Type {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Type {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        if equiv.transparent_groups {
+            match self {
+                Type::Paren(inner) => return Equivalent::equiv(&*inner.elem, other, equiv),
+                Type::Group(inner) => return Equivalent::equiv(&*inner.elem, other, equiv),
+                _ => {}
+            }
+            match other {
+                Type::Paren(inner) => return self.equiv(&inner.elem, equiv),
+                Type::Group(inner) => return self.equiv(&inner.elem, equiv),
+                _ => {}
+            }
+        }
+        match (self, other) {
+            (Type::Array(this0), Type::Array(other0)) => this0.equiv(other0, equiv),
+            (Type::BareFn(this0), Type::BareFn(other0)) => this0.equiv(other0, equiv),
+            (Type::Group(this0), Type::Group(other0)) => this0.equiv(other0, equiv),
+            (Type::ImplTrait(this0), Type::ImplTrait(other0)) => this0.equiv(other0, equiv),
+            (Type::Infer(this0), Type::Infer(other0)) => this0.equiv(other0, equiv),
+            (Type::Macro(this0), Type::Macro(other0)) => this0.equiv(other0, equiv),
+            (Type::Never(this0), Type::Never(other0)) => this0.equiv(other0, equiv),
+            (Type::Paren(this0), Type::Paren(other0)) => this0.equiv(other0, equiv),
+            (Type::Path(this0), Type::Path(other0)) => this0.equiv(other0, equiv),
+            (Type::Ptr(this0), Type::Ptr(other0)) => this0.equiv(other0, equiv),
+            (Type::Reference(this0), Type::Reference(other0)) => this0.equiv(other0, equiv),
+            (Type::Slice(this0), Type::Slice(other0)) => this0.equiv(other0, equiv),
+            (Type::TraitObject(this0), Type::TraitObject(other0)) => this0.equiv(other0, equiv),
+            (Type::Tuple(this0), Type::Tuple(other0)) => this0.equiv(other0, equiv),
+            (Type::Verbatim(this0), Type::Verbatim(other0)) => {
+                TokenStreamHelper(this0) == TokenStreamHelper(other0)
+            }
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if equiv.transparent_groups {
+            match self {
+                Type::Paren(inner) => return inner.elem.hash_equiv(equiv, state),
+                Type::Group(inner) => return inner.elem.hash_equiv(equiv, state),
+                _ => {}
+            }
+        }
+        match self {
+            Type::Array(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::BareFn(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::Group(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::ImplTrait(this0) => {
+                state.write_u8(3u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::Infer(this0) => {
+                state.write_u8(4u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::Macro(this0) => {
+                state.write_u8(5u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::Never(this0) => {
+                state.write_u8(6u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::Paren(this0) => {
+                state.write_u8(7u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::Path(this0) => {
+                state.write_u8(8u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::Ptr(this0) => {
+                state.write_u8(9u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::Reference(this0) => {
+                state.write_u8(10u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::Slice(this0) => {
+                state.write_u8(11u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::TraitObject(this0) => {
+                state.write_u8(12u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::Tuple(this0) => {
+                state.write_u8(13u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Type::Verbatim(this0) => {
+                state.write_u8(14u8);
+                TokenStreamHelper(this0).hash(state);
+            }
+            Type::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeArray {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeArray {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.elem.equiv(&other.elem, equiv) && self.len.equiv(&other.len, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.elem.hash_equiv(equiv, state);
+        self.len.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeBareFn {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeBareFn {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.lifetimes.equiv(&other.lifetimes, equiv)
+            && self.unsafety.is_some() == other.unsafety.is_some()
+            && self.abi.equiv(&other.abi, equiv)
+            && self.inputs.equiv(&other.inputs, equiv)
+            && self.variadic.is_some() == other.variadic.is_some()
+            && self.output.equiv(&other.output, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.lifetimes.hash_equiv(equiv, state);
+        self.unsafety.is_some().hash(state);
+        self.abi.hash_equiv(equiv, state);
+        self.inputs.hash_equiv(equiv, state);
+        self.variadic.is_some().hash(state);
+        self.output.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeGroup {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeGroup {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.elem.equiv(&other.elem, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.elem.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeImplTrait {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeImplTrait {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.bounds.equiv(&other.bounds, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.bounds.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeInfer {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeInfer {
+    fn equiv(&self, _other: &Self, _equiv: &Equivalence) -> bool {
+        true
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, _state: &mut H) {}
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeMacro {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeMacro {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.mac.equiv(&other.mac, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.mac.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeNever {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeNever {
+    fn equiv(&self, _other: &Self, _equiv: &Equivalence) -> bool {
+        true
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, _state: &mut H) {}
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeParam {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.ident.equiv(&other.ident, equiv)
+            && self.colon_token.is_some() == other.colon_token.is_some()
+            && self.bounds.equiv(&other.bounds, equiv)
+            && self.eq_token.is_some() == other.eq_token.is_some()
+            && self.default.equiv(&other.default, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.ident.hash_equiv(equiv, state);
+        self.colon_token.is_some().hash(state);
+        self.bounds.hash_equiv(equiv, state);
+        self.eq_token.is_some().hash(state);
+        self.default.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParamBound {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeParamBound {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (TypeParamBound::Trait(this0), TypeParamBound::Trait(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (TypeParamBound::Lifetime(this0), TypeParamBound::Lifetime(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            TypeParamBound::Trait(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            TypeParamBound::Lifetime(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParen {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeParen {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.elem.equiv(&other.elem, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.elem.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypePath {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypePath {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.qself.equiv(&other.qself, equiv) && self.path.equiv(&other.path, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.qself.hash_equiv(equiv, state);
+        self.path.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypePtr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypePtr {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.const_token.is_some() == other.const_token.is_some()
+            && self.mutability.is_some() == other.mutability.is_some()
+            && self.elem.equiv(&other.elem, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.const_token.is_some().hash(state);
+        self.mutability.is_some().hash(state);
+        self.elem.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeReference {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeReference {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.lifetime.equiv(&other.lifetime, equiv)
+            && self.mutability.is_some() == other.mutability.is_some()
+            && self.elem.equiv(&other.elem, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.lifetime.hash_equiv(equiv, state);
+        self.mutability.is_some().hash(state);
+        self.elem.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeSlice {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeSlice {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.elem.equiv(&other.elem, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.elem.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeTraitObject {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeTraitObject {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.dyn_token.is_some() == other.dyn_token.is_some()
+            && self.bounds.equiv(&other.bounds, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.dyn_token.is_some().hash(state);
+        self.bounds.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeTuple {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for TypeTuple {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.elems.equiv(&other.elems, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.elems.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for UnOp {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for UnOp {
+    fn equiv(&self, other: &Self, _equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (UnOp::Deref(_), UnOp::Deref(_)) => true,
+            (UnOp::Not(_), UnOp::Not(_)) => true,
+            (UnOp::Neg(_), UnOp::Neg(_)) => true,
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, state: &mut H) {
+        match self {
+            UnOp::Deref(_) => {
+                state.write_u8(0u8);
+            }
+            UnOp::Not(_) => {
+                state.write_u8(1u8);
+            }
+            UnOp::Neg(_) => {
+                state.write_u8(2u8);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseGlob {}
+#[cfg(feature = "full")]
+impl Equivalent for UseGlob {
+    fn equiv(&self, _other: &Self, _equiv: &Equivalence) -> bool {
+        true
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, _state: &mut H) {}
+}
+#[cfg(feature = "full")]
+impl Sealed for UseGroup {}
+#[cfg(feature = "full")]
+impl Equivalent for UseGroup {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.items.equiv(&other.items, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.items.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseName {}
+#[cfg(feature = "full")]
+impl Equivalent for UseName {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.ident.equiv(&other.ident, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.ident.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UsePath {}
+#[cfg(feature = "full")]
+impl Equivalent for UsePath {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.ident.equiv(&other.ident, equiv) && self.tree.equiv(&other.tree, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.ident.hash_equiv(equiv, state);
+        self.tree.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseRename {}
+#[cfg(feature = "full")]
+impl Equivalent for UseRename {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.ident.equiv(&other.ident, equiv) && self.rename.equiv(&other.rename, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.ident.hash_equiv(equiv, state);
+        self.rename.hash_equiv(equiv, state);
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseTree {}
+#[cfg(feature = "full")]
+impl Equivalent for UseTree {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (UseTree::Path(this0), UseTree::Path(other0)) => this0.equiv(other0, equiv),
+            (UseTree::Name(this0), UseTree::Name(other0)) => this0.equiv(other0, equiv),
+            (UseTree::Rename(this0), UseTree::Rename(other0)) => this0.equiv(other0, equiv),
+            (UseTree::Glob(this0), UseTree::Glob(other0)) => this0.equiv(other0, equiv),
+            (UseTree::Group(this0), UseTree::Group(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            UseTree::Path(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            UseTree::Name(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            UseTree::Rename(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            UseTree::Glob(this0) => {
+                state.write_u8(3u8);
+                this0.hash_equiv(equiv, state);
+            }
+            UseTree::Group(this0) => {
+                state.write_u8(4u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Variadic {}
+#[cfg(feature = "full")]
+impl Equivalent for Variadic {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Variant {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Variant {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        (equiv.ignore_attrs || self.attrs.equiv(&other.attrs, equiv))
+            && self.ident.equiv(&other.ident, equiv)
+            && self.fields.equiv(&other.fields, equiv)
+            && match (&self.discriminant, &other.discriminant) {
+                (Some(this), Some(other)) => this.1.equiv(&other.1, equiv),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        if !equiv.ignore_attrs {
+            self.attrs.hash_equiv(equiv, state);
+        }
+        self.ident.hash_equiv(equiv, state);
+        self.fields.hash_equiv(equiv, state);
+        match &self.discriminant {
+            Some(this) => {
+                state.write_u8(1);
+                this.1.hash_equiv(equiv, state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisCrate {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for VisCrate {
+    fn equiv(&self, _other: &Self, _equiv: &Equivalence) -> bool {
+        true
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, _state: &mut H) {}
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisPublic {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for VisPublic {
+    fn equiv(&self, _other: &Self, _equiv: &Equivalence) -> bool {
+        true
+    }
+    fn hash_equiv<H: Hasher>(&self, _equiv: &Equivalence, _state: &mut H) {}
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisRestricted {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for VisRestricted {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.in_token.is_some() == other.in_token.is_some() && self.path.equiv(&other.path, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.in_token.is_some().hash(state);
+        self.path.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Visibility {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for Visibility {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (Visibility::Public(this0), Visibility::Public(other0)) => this0.equiv(other0, equiv),
+            (Visibility::Crate(this0), Visibility::Crate(other0)) => this0.equiv(other0, equiv),
+            (Visibility::Restricted(this0), Visibility::Restricted(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (Visibility::Inherited, Visibility::Inherited) => true,
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            Visibility::Public(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Visibility::Crate(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Visibility::Restricted(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+            Visibility::Inherited => {
+                state.write_u8(3u8);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for WhereClause {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for WhereClause {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        self.predicates.equiv(&other.predicates, equiv)
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        self.predicates.hash_equiv(equiv, state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for WherePredicate {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Equivalent for WherePredicate {
+    fn equiv(&self, other: &Self, equiv: &Equivalence) -> bool {
+        match (self, other) {
+            (WherePredicate::Type(this0), WherePredicate::Type(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (WherePredicate::Lifetime(this0), WherePredicate::Lifetime(other0)) => {
+                this0.equiv(other0, equiv)
+            }
+            (WherePredicate::Eq(this0), WherePredicate::Eq(other0)) => this0.equiv(other0, equiv),
+            _ => false,
+        }
+    }
+    fn hash_equiv<H: Hasher>(&self, equiv: &Equivalence, state: &mut H) {
+        match self {
+            WherePredicate::Type(this0) => {
+                state.write_u8(0u8);
+                this0.hash_equiv(equiv, state);
+            }
+            WherePredicate::Lifetime(this0) => {
+                state.write_u8(1u8);
+                this0.hash_equiv(equiv, state);
+            }
+            WherePredicate::Eq(this0) => {
+                state.write_u8(2u8);
+                this0.hash_equiv(equiv, state);
+            }
+        }
+    }
+}
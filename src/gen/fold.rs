@@ -486,6 +486,18 @@ pub trait Fold {
         fold_meta(self, i)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_meta_expr(&mut self, i: MetaExpr) -> MetaExpr {
+        fold_meta_expr(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_meta_expr_list(&mut self, i: MetaExprList) -> MetaExprList {
+        fold_meta_expr_list(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_meta_expr_name_value(&mut self, i: MetaExprNameValue) -> MetaExprNameValue {
+        fold_meta_expr_name_value(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_meta_list(&mut self, i: MetaList) -> MetaList {
         fold_meta_list(self, i)
     }
@@ -493,6 +505,10 @@ pub trait Fold {
     fn fold_meta_name_value(&mut self, i: MetaNameValue) -> MetaNameValue {
         fold_meta_name_value(self, i)
     }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_meta_value(&mut self, i: MetaValue) -> MetaValue {
+        fold_meta_value(self, i)
+    }
     #[cfg(feature = "full")]
     fn fold_method_turbofish(&mut self, i: MethodTurbofish) -> MethodTurbofish {
         fold_method_turbofish(self, i)
@@ -502,6 +518,10 @@ pub trait Fold {
         fold_nested_meta(self, i)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_nested_meta_expr(&mut self, i: NestedMetaExpr) -> NestedMetaExpr {
+        fold_nested_meta_expr(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_parenthesized_generic_arguments(
         &mut self,
         i: ParenthesizedGenericArguments,
@@ -2344,6 +2364,41 @@ where
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_meta_expr<F>(f: &mut F, node: MetaExpr) -> MetaExpr
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        MetaExpr::Path(_binding_0) => MetaExpr::Path(f.fold_path(_binding_0)),
+        MetaExpr::List(_binding_0) => MetaExpr::List(f.fold_meta_expr_list(_binding_0)),
+        MetaExpr::NameValue(_binding_0) => {
+            MetaExpr::NameValue(f.fold_meta_expr_name_value(_binding_0))
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_meta_expr_list<F>(f: &mut F, node: MetaExprList) -> MetaExprList
+where
+    F: Fold + ?Sized,
+{
+    MetaExprList {
+        path: f.fold_path(node.path),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)),
+        nested: FoldHelper::lift(node.nested, |it| f.fold_nested_meta_expr(it)),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_meta_expr_name_value<F>(f: &mut F, node: MetaExprNameValue) -> MetaExprNameValue
+where
+    F: Fold + ?Sized,
+{
+    MetaExprNameValue {
+        path: f.fold_path(node.path),
+        eq_token: Token ! [ = ](tokens_helper(f, &node.eq_token.spans)),
+        value: f.fold_meta_value(node.value),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
 pub fn fold_meta_list<F>(f: &mut F, node: MetaList) -> MetaList
 where
     F: Fold + ?Sized,
@@ -2365,6 +2420,16 @@ where
         lit: f.fold_lit(node.lit),
     }
 }
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_meta_value<F>(f: &mut F, node: MetaValue) -> MetaValue
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        MetaValue::Expr(_binding_0) => MetaValue::Expr(f.fold_expr(_binding_0)),
+        MetaValue::Verbatim(_binding_0) => MetaValue::Verbatim(_binding_0),
+    }
+}
 #[cfg(feature = "full")]
 pub fn fold_method_turbofish<F>(f: &mut F, node: MethodTurbofish) -> MethodTurbofish
 where
@@ -2388,6 +2453,16 @@ where
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_nested_meta_expr<F>(f: &mut F, node: NestedMetaExpr) -> NestedMetaExpr
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        NestedMetaExpr::Meta(_binding_0) => NestedMetaExpr::Meta(f.fold_meta_expr(_binding_0)),
+        NestedMetaExpr::Lit(_binding_0) => NestedMetaExpr::Lit(f.fold_lit(_binding_0)),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
 pub fn fold_parenthesized_generic_arguments<F>(
     f: &mut F,
     node: ParenthesizedGenericArguments,
@@ -0,0 +1,8657 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(unused_mut)]
+use crate::*;
+#[cfg(feature = "printing")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "printing")]
+use quote::ToTokens;
+use std::vec;
+#[cfg(feature = "full")]
+macro_rules! full {
+    ($e:expr) => {
+        $e
+    };
+}
+#[cfg(all(feature = "derive", not(feature = "full")))]
+macro_rules! full {
+    ($e:expr) => {
+        unreachable!()
+    };
+}
+///A shared reference to a syntax tree node of any type.
+///
+///*This type is available if Syn is built with the `"visit"` feature.*
+#[cfg(feature = "visit")]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub enum NodeRef<'a> {
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Abi(&'a Abi),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AngleBracketedGenericArguments(&'a AngleBracketedGenericArguments),
+    #[cfg(feature = "full")]
+    Arm(&'a Arm),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AttrStyle(&'a AttrStyle),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Attribute(&'a Attribute),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BareFnArg(&'a BareFnArg),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BinOp(&'a BinOp),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Binding(&'a Binding),
+    #[cfg(feature = "full")]
+    Block(&'a Block),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BoundLifetimes(&'a BoundLifetimes),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ConstParam(&'a ConstParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Constraint(&'a Constraint),
+    #[cfg(feature = "derive")]
+    Data(&'a Data),
+    #[cfg(feature = "derive")]
+    DataEnum(&'a DataEnum),
+    #[cfg(feature = "derive")]
+    DataStruct(&'a DataStruct),
+    #[cfg(feature = "derive")]
+    DataUnion(&'a DataUnion),
+    #[cfg(feature = "derive")]
+    DeriveInput(&'a DeriveInput),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Expr(&'a Expr),
+    #[cfg(feature = "full")]
+    ExprArray(&'a ExprArray),
+    #[cfg(feature = "full")]
+    ExprAssign(&'a ExprAssign),
+    #[cfg(feature = "full")]
+    ExprAssignOp(&'a ExprAssignOp),
+    #[cfg(feature = "full")]
+    ExprAsync(&'a ExprAsync),
+    #[cfg(feature = "full")]
+    ExprAwait(&'a ExprAwait),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprBinary(&'a ExprBinary),
+    #[cfg(feature = "full")]
+    ExprBlock(&'a ExprBlock),
+    #[cfg(feature = "full")]
+    ExprBox(&'a ExprBox),
+    #[cfg(feature = "full")]
+    ExprBreak(&'a ExprBreak),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprCall(&'a ExprCall),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprCast(&'a ExprCast),
+    #[cfg(feature = "full")]
+    ExprClosure(&'a ExprClosure),
+    #[cfg(feature = "full")]
+    ExprContinue(&'a ExprContinue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprField(&'a ExprField),
+    #[cfg(feature = "full")]
+    ExprForLoop(&'a ExprForLoop),
+    #[cfg(feature = "full")]
+    ExprGroup(&'a ExprGroup),
+    #[cfg(feature = "full")]
+    ExprIf(&'a ExprIf),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprIndex(&'a ExprIndex),
+    #[cfg(feature = "full")]
+    ExprLet(&'a ExprLet),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprLit(&'a ExprLit),
+    #[cfg(feature = "full")]
+    ExprLoop(&'a ExprLoop),
+    #[cfg(feature = "full")]
+    ExprMacro(&'a ExprMacro),
+    #[cfg(feature = "full")]
+    ExprMatch(&'a ExprMatch),
+    #[cfg(feature = "full")]
+    ExprMethodCall(&'a ExprMethodCall),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprParen(&'a ExprParen),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprPath(&'a ExprPath),
+    #[cfg(feature = "full")]
+    ExprRange(&'a ExprRange),
+    #[cfg(feature = "full")]
+    ExprReference(&'a ExprReference),
+    #[cfg(feature = "full")]
+    ExprRepeat(&'a ExprRepeat),
+    #[cfg(feature = "full")]
+    ExprReturn(&'a ExprReturn),
+    #[cfg(feature = "full")]
+    ExprStruct(&'a ExprStruct),
+    #[cfg(feature = "full")]
+    ExprTry(&'a ExprTry),
+    #[cfg(feature = "full")]
+    ExprTryBlock(&'a ExprTryBlock),
+    #[cfg(feature = "full")]
+    ExprTuple(&'a ExprTuple),
+    #[cfg(feature = "full")]
+    ExprType(&'a ExprType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprUnary(&'a ExprUnary),
+    #[cfg(feature = "full")]
+    ExprUnsafe(&'a ExprUnsafe),
+    #[cfg(feature = "full")]
+    ExprWhile(&'a ExprWhile),
+    #[cfg(feature = "full")]
+    ExprYield(&'a ExprYield),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Field(&'a Field),
+    #[cfg(feature = "full")]
+    FieldPat(&'a FieldPat),
+    #[cfg(feature = "full")]
+    FieldValue(&'a FieldValue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Fields(&'a Fields),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    FieldsNamed(&'a FieldsNamed),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    FieldsUnnamed(&'a FieldsUnnamed),
+    #[cfg(feature = "full")]
+    File(&'a File),
+    #[cfg(feature = "full")]
+    FnArg(&'a FnArg),
+    #[cfg(feature = "full")]
+    ForeignItem(&'a ForeignItem),
+    #[cfg(feature = "full")]
+    ForeignItemFn(&'a ForeignItemFn),
+    #[cfg(feature = "full")]
+    ForeignItemMacro(&'a ForeignItemMacro),
+    #[cfg(feature = "full")]
+    ForeignItemStatic(&'a ForeignItemStatic),
+    #[cfg(feature = "full")]
+    ForeignItemType(&'a ForeignItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    GenericArgument(&'a GenericArgument),
+    #[cfg(feature = "full")]
+    GenericMethodArgument(&'a GenericMethodArgument),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    GenericParam(&'a GenericParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Generics(&'a Generics),
+    Ident(&'a Ident),
+    #[cfg(feature = "full")]
+    ImplItem(&'a ImplItem),
+    #[cfg(feature = "full")]
+    ImplItemConst(&'a ImplItemConst),
+    #[cfg(feature = "full")]
+    ImplItemMacro(&'a ImplItemMacro),
+    #[cfg(feature = "full")]
+    ImplItemMethod(&'a ImplItemMethod),
+    #[cfg(feature = "full")]
+    ImplItemType(&'a ImplItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Index(&'a Index),
+    #[cfg(feature = "full")]
+    Item(&'a Item),
+    #[cfg(feature = "full")]
+    ItemConst(&'a ItemConst),
+    #[cfg(feature = "full")]
+    ItemEnum(&'a ItemEnum),
+    #[cfg(feature = "full")]
+    ItemExternCrate(&'a ItemExternCrate),
+    #[cfg(feature = "full")]
+    ItemFn(&'a ItemFn),
+    #[cfg(feature = "full")]
+    ItemForeignMod(&'a ItemForeignMod),
+    #[cfg(feature = "full")]
+    ItemImpl(&'a ItemImpl),
+    #[cfg(feature = "full")]
+    ItemMacro(&'a ItemMacro),
+    #[cfg(feature = "full")]
+    ItemMacro2(&'a ItemMacro2),
+    #[cfg(feature = "full")]
+    ItemMod(&'a ItemMod),
+    #[cfg(feature = "full")]
+    ItemStatic(&'a ItemStatic),
+    #[cfg(feature = "full")]
+    ItemStruct(&'a ItemStruct),
+    #[cfg(feature = "full")]
+    ItemTrait(&'a ItemTrait),
+    #[cfg(feature = "full")]
+    ItemTraitAlias(&'a ItemTraitAlias),
+    #[cfg(feature = "full")]
+    ItemType(&'a ItemType),
+    #[cfg(feature = "full")]
+    ItemUnion(&'a ItemUnion),
+    #[cfg(feature = "full")]
+    ItemUse(&'a ItemUse),
+    #[cfg(feature = "full")]
+    Label(&'a Label),
+    Lifetime(&'a Lifetime),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LifetimeDef(&'a LifetimeDef),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Lit(&'a Lit),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitBool(&'a LitBool),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitByte(&'a LitByte),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitByteStr(&'a LitByteStr),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitChar(&'a LitChar),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitFloat(&'a LitFloat),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitInt(&'a LitInt),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitStr(&'a LitStr),
+    #[cfg(feature = "full")]
+    Local(&'a Local),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Macro(&'a Macro),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MacroDelimiter(&'a MacroDelimiter),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Member(&'a Member),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Meta(&'a Meta),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaExpr(&'a MetaExpr),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaExprList(&'a MetaExprList),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaExprNameValue(&'a MetaExprNameValue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaList(&'a MetaList),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaNameValue(&'a MetaNameValue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaValue(&'a MetaValue),
+    #[cfg(feature = "full")]
+    MethodTurbofish(&'a MethodTurbofish),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    NestedMeta(&'a NestedMeta),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    NestedMetaExpr(&'a NestedMetaExpr),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ParenthesizedGenericArguments(&'a ParenthesizedGenericArguments),
+    #[cfg(feature = "full")]
+    Pat(&'a Pat),
+    #[cfg(feature = "full")]
+    PatBox(&'a PatBox),
+    #[cfg(feature = "full")]
+    PatIdent(&'a PatIdent),
+    #[cfg(feature = "full")]
+    PatLit(&'a PatLit),
+    #[cfg(feature = "full")]
+    PatMacro(&'a PatMacro),
+    #[cfg(feature = "full")]
+    PatOr(&'a PatOr),
+    #[cfg(feature = "full")]
+    PatPath(&'a PatPath),
+    #[cfg(feature = "full")]
+    PatRange(&'a PatRange),
+    #[cfg(feature = "full")]
+    PatReference(&'a PatReference),
+    #[cfg(feature = "full")]
+    PatRest(&'a PatRest),
+    #[cfg(feature = "full")]
+    PatSlice(&'a PatSlice),
+    #[cfg(feature = "full")]
+    PatStruct(&'a PatStruct),
+    #[cfg(feature = "full")]
+    PatTuple(&'a PatTuple),
+    #[cfg(feature = "full")]
+    PatTupleStruct(&'a PatTupleStruct),
+    #[cfg(feature = "full")]
+    PatType(&'a PatType),
+    #[cfg(feature = "full")]
+    PatWild(&'a PatWild),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Path(&'a Path),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PathArguments(&'a PathArguments),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PathSegment(&'a PathSegment),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateEq(&'a PredicateEq),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateLifetime(&'a PredicateLifetime),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateType(&'a PredicateType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    QSelf(&'a QSelf),
+    #[cfg(feature = "full")]
+    RangeLimits(&'a RangeLimits),
+    #[cfg(feature = "full")]
+    Receiver(&'a Receiver),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ReturnType(&'a ReturnType),
+    #[cfg(feature = "full")]
+    Signature(&'a Signature),
+    #[cfg(feature = "full")]
+    Stmt(&'a Stmt),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TraitBound(&'a TraitBound),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TraitBoundModifier(&'a TraitBoundModifier),
+    #[cfg(feature = "full")]
+    TraitItem(&'a TraitItem),
+    #[cfg(feature = "full")]
+    TraitItemConst(&'a TraitItemConst),
+    #[cfg(feature = "full")]
+    TraitItemMacro(&'a TraitItemMacro),
+    #[cfg(feature = "full")]
+    TraitItemMethod(&'a TraitItemMethod),
+    #[cfg(feature = "full")]
+    TraitItemType(&'a TraitItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Type(&'a Type),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeArray(&'a TypeArray),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeBareFn(&'a TypeBareFn),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeGroup(&'a TypeGroup),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeImplTrait(&'a TypeImplTrait),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeInfer(&'a TypeInfer),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeMacro(&'a TypeMacro),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeNever(&'a TypeNever),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParam(&'a TypeParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParamBound(&'a TypeParamBound),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParen(&'a TypeParen),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypePath(&'a TypePath),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypePtr(&'a TypePtr),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeReference(&'a TypeReference),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeSlice(&'a TypeSlice),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeTraitObject(&'a TypeTraitObject),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeTuple(&'a TypeTuple),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    UnOp(&'a UnOp),
+    #[cfg(feature = "full")]
+    UseGlob(&'a UseGlob),
+    #[cfg(feature = "full")]
+    UseGroup(&'a UseGroup),
+    #[cfg(feature = "full")]
+    UseName(&'a UseName),
+    #[cfg(feature = "full")]
+    UsePath(&'a UsePath),
+    #[cfg(feature = "full")]
+    UseRename(&'a UseRename),
+    #[cfg(feature = "full")]
+    UseTree(&'a UseTree),
+    #[cfg(feature = "full")]
+    Variadic(&'a Variadic),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Variant(&'a Variant),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisCrate(&'a VisCrate),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisPublic(&'a VisPublic),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisRestricted(&'a VisRestricted),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Visibility(&'a Visibility),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    WhereClause(&'a WhereClause),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    WherePredicate(&'a WherePredicate),
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+#[cfg(feature = "visit")]
+impl<'a> NodeRef<'a> {
+    /// The name of the syntax tree type of this node, like
+    /// `"ExprCall"`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Abi(_) => "Abi",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::AngleBracketedGenericArguments(_) => "AngleBracketedGenericArguments",
+            #[cfg(feature = "full")]
+            NodeRef::Arm(_) => "Arm",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::AttrStyle(_) => "AttrStyle",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Attribute(_) => "Attribute",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::BareFnArg(_) => "BareFnArg",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::BinOp(_) => "BinOp",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Binding(_) => "Binding",
+            #[cfg(feature = "full")]
+            NodeRef::Block(_) => "Block",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::BoundLifetimes(_) => "BoundLifetimes",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ConstParam(_) => "ConstParam",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Constraint(_) => "Constraint",
+            #[cfg(feature = "derive")]
+            NodeRef::Data(_) => "Data",
+            #[cfg(feature = "derive")]
+            NodeRef::DataEnum(_) => "DataEnum",
+            #[cfg(feature = "derive")]
+            NodeRef::DataStruct(_) => "DataStruct",
+            #[cfg(feature = "derive")]
+            NodeRef::DataUnion(_) => "DataUnion",
+            #[cfg(feature = "derive")]
+            NodeRef::DeriveInput(_) => "DeriveInput",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Expr(_) => "Expr",
+            #[cfg(feature = "full")]
+            NodeRef::ExprArray(_) => "ExprArray",
+            #[cfg(feature = "full")]
+            NodeRef::ExprAssign(_) => "ExprAssign",
+            #[cfg(feature = "full")]
+            NodeRef::ExprAssignOp(_) => "ExprAssignOp",
+            #[cfg(feature = "full")]
+            NodeRef::ExprAsync(_) => "ExprAsync",
+            #[cfg(feature = "full")]
+            NodeRef::ExprAwait(_) => "ExprAwait",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprBinary(_) => "ExprBinary",
+            #[cfg(feature = "full")]
+            NodeRef::ExprBlock(_) => "ExprBlock",
+            #[cfg(feature = "full")]
+            NodeRef::ExprBox(_) => "ExprBox",
+            #[cfg(feature = "full")]
+            NodeRef::ExprBreak(_) => "ExprBreak",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprCall(_) => "ExprCall",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprCast(_) => "ExprCast",
+            #[cfg(feature = "full")]
+            NodeRef::ExprClosure(_) => "ExprClosure",
+            #[cfg(feature = "full")]
+            NodeRef::ExprContinue(_) => "ExprContinue",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprField(_) => "ExprField",
+            #[cfg(feature = "full")]
+            NodeRef::ExprForLoop(_) => "ExprForLoop",
+            #[cfg(feature = "full")]
+            NodeRef::ExprGroup(_) => "ExprGroup",
+            #[cfg(feature = "full")]
+            NodeRef::ExprIf(_) => "ExprIf",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprIndex(_) => "ExprIndex",
+            #[cfg(feature = "full")]
+            NodeRef::ExprLet(_) => "ExprLet",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprLit(_) => "ExprLit",
+            #[cfg(feature = "full")]
+            NodeRef::ExprLoop(_) => "ExprLoop",
+            #[cfg(feature = "full")]
+            NodeRef::ExprMacro(_) => "ExprMacro",
+            #[cfg(feature = "full")]
+            NodeRef::ExprMatch(_) => "ExprMatch",
+            #[cfg(feature = "full")]
+            NodeRef::ExprMethodCall(_) => "ExprMethodCall",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprParen(_) => "ExprParen",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprPath(_) => "ExprPath",
+            #[cfg(feature = "full")]
+            NodeRef::ExprRange(_) => "ExprRange",
+            #[cfg(feature = "full")]
+            NodeRef::ExprReference(_) => "ExprReference",
+            #[cfg(feature = "full")]
+            NodeRef::ExprRepeat(_) => "ExprRepeat",
+            #[cfg(feature = "full")]
+            NodeRef::ExprReturn(_) => "ExprReturn",
+            #[cfg(feature = "full")]
+            NodeRef::ExprStruct(_) => "ExprStruct",
+            #[cfg(feature = "full")]
+            NodeRef::ExprTry(_) => "ExprTry",
+            #[cfg(feature = "full")]
+            NodeRef::ExprTryBlock(_) => "ExprTryBlock",
+            #[cfg(feature = "full")]
+            NodeRef::ExprTuple(_) => "ExprTuple",
+            #[cfg(feature = "full")]
+            NodeRef::ExprType(_) => "ExprType",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprUnary(_) => "ExprUnary",
+            #[cfg(feature = "full")]
+            NodeRef::ExprUnsafe(_) => "ExprUnsafe",
+            #[cfg(feature = "full")]
+            NodeRef::ExprWhile(_) => "ExprWhile",
+            #[cfg(feature = "full")]
+            NodeRef::ExprYield(_) => "ExprYield",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Field(_) => "Field",
+            #[cfg(feature = "full")]
+            NodeRef::FieldPat(_) => "FieldPat",
+            #[cfg(feature = "full")]
+            NodeRef::FieldValue(_) => "FieldValue",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Fields(_) => "Fields",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::FieldsNamed(_) => "FieldsNamed",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::FieldsUnnamed(_) => "FieldsUnnamed",
+            #[cfg(feature = "full")]
+            NodeRef::File(_) => "File",
+            #[cfg(feature = "full")]
+            NodeRef::FnArg(_) => "FnArg",
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItem(_) => "ForeignItem",
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemFn(_) => "ForeignItemFn",
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemMacro(_) => "ForeignItemMacro",
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemStatic(_) => "ForeignItemStatic",
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemType(_) => "ForeignItemType",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::GenericArgument(_) => "GenericArgument",
+            #[cfg(feature = "full")]
+            NodeRef::GenericMethodArgument(_) => "GenericMethodArgument",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::GenericParam(_) => "GenericParam",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Generics(_) => "Generics",
+            NodeRef::Ident(_) => "Ident",
+            #[cfg(feature = "full")]
+            NodeRef::ImplItem(_) => "ImplItem",
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemConst(_) => "ImplItemConst",
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemMacro(_) => "ImplItemMacro",
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemMethod(_) => "ImplItemMethod",
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemType(_) => "ImplItemType",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Index(_) => "Index",
+            #[cfg(feature = "full")]
+            NodeRef::Item(_) => "Item",
+            #[cfg(feature = "full")]
+            NodeRef::ItemConst(_) => "ItemConst",
+            #[cfg(feature = "full")]
+            NodeRef::ItemEnum(_) => "ItemEnum",
+            #[cfg(feature = "full")]
+            NodeRef::ItemExternCrate(_) => "ItemExternCrate",
+            #[cfg(feature = "full")]
+            NodeRef::ItemFn(_) => "ItemFn",
+            #[cfg(feature = "full")]
+            NodeRef::ItemForeignMod(_) => "ItemForeignMod",
+            #[cfg(feature = "full")]
+            NodeRef::ItemImpl(_) => "ItemImpl",
+            #[cfg(feature = "full")]
+            NodeRef::ItemMacro(_) => "ItemMacro",
+            #[cfg(feature = "full")]
+            NodeRef::ItemMacro2(_) => "ItemMacro2",
+            #[cfg(feature = "full")]
+            NodeRef::ItemMod(_) => "ItemMod",
+            #[cfg(feature = "full")]
+            NodeRef::ItemStatic(_) => "ItemStatic",
+            #[cfg(feature = "full")]
+            NodeRef::ItemStruct(_) => "ItemStruct",
+            #[cfg(feature = "full")]
+            NodeRef::ItemTrait(_) => "ItemTrait",
+            #[cfg(feature = "full")]
+            NodeRef::ItemTraitAlias(_) => "ItemTraitAlias",
+            #[cfg(feature = "full")]
+            NodeRef::ItemType(_) => "ItemType",
+            #[cfg(feature = "full")]
+            NodeRef::ItemUnion(_) => "ItemUnion",
+            #[cfg(feature = "full")]
+            NodeRef::ItemUse(_) => "ItemUse",
+            #[cfg(feature = "full")]
+            NodeRef::Label(_) => "Label",
+            NodeRef::Lifetime(_) => "Lifetime",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LifetimeDef(_) => "LifetimeDef",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Lit(_) => "Lit",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitBool(_) => "LitBool",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitByte(_) => "LitByte",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitByteStr(_) => "LitByteStr",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitChar(_) => "LitChar",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitFloat(_) => "LitFloat",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitInt(_) => "LitInt",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitStr(_) => "LitStr",
+            #[cfg(feature = "full")]
+            NodeRef::Local(_) => "Local",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Macro(_) => "Macro",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MacroDelimiter(_) => "MacroDelimiter",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Member(_) => "Member",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Meta(_) => "Meta",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaExpr(_) => "MetaExpr",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaExprList(_) => "MetaExprList",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaExprNameValue(_) => "MetaExprNameValue",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaList(_) => "MetaList",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaNameValue(_) => "MetaNameValue",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaValue(_) => "MetaValue",
+            #[cfg(feature = "full")]
+            NodeRef::MethodTurbofish(_) => "MethodTurbofish",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::NestedMeta(_) => "NestedMeta",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::NestedMetaExpr(_) => "NestedMetaExpr",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ParenthesizedGenericArguments(_) => "ParenthesizedGenericArguments",
+            #[cfg(feature = "full")]
+            NodeRef::Pat(_) => "Pat",
+            #[cfg(feature = "full")]
+            NodeRef::PatBox(_) => "PatBox",
+            #[cfg(feature = "full")]
+            NodeRef::PatIdent(_) => "PatIdent",
+            #[cfg(feature = "full")]
+            NodeRef::PatLit(_) => "PatLit",
+            #[cfg(feature = "full")]
+            NodeRef::PatMacro(_) => "PatMacro",
+            #[cfg(feature = "full")]
+            NodeRef::PatOr(_) => "PatOr",
+            #[cfg(feature = "full")]
+            NodeRef::PatPath(_) => "PatPath",
+            #[cfg(feature = "full")]
+            NodeRef::PatRange(_) => "PatRange",
+            #[cfg(feature = "full")]
+            NodeRef::PatReference(_) => "PatReference",
+            #[cfg(feature = "full")]
+            NodeRef::PatRest(_) => "PatRest",
+            #[cfg(feature = "full")]
+            NodeRef::PatSlice(_) => "PatSlice",
+            #[cfg(feature = "full")]
+            NodeRef::PatStruct(_) => "PatStruct",
+            #[cfg(feature = "full")]
+            NodeRef::PatTuple(_) => "PatTuple",
+            #[cfg(feature = "full")]
+            NodeRef::PatTupleStruct(_) => "PatTupleStruct",
+            #[cfg(feature = "full")]
+            NodeRef::PatType(_) => "PatType",
+            #[cfg(feature = "full")]
+            NodeRef::PatWild(_) => "PatWild",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Path(_) => "Path",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PathArguments(_) => "PathArguments",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PathSegment(_) => "PathSegment",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PredicateEq(_) => "PredicateEq",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PredicateLifetime(_) => "PredicateLifetime",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PredicateType(_) => "PredicateType",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::QSelf(_) => "QSelf",
+            #[cfg(feature = "full")]
+            NodeRef::RangeLimits(_) => "RangeLimits",
+            #[cfg(feature = "full")]
+            NodeRef::Receiver(_) => "Receiver",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ReturnType(_) => "ReturnType",
+            #[cfg(feature = "full")]
+            NodeRef::Signature(_) => "Signature",
+            #[cfg(feature = "full")]
+            NodeRef::Stmt(_) => "Stmt",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TraitBound(_) => "TraitBound",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TraitBoundModifier(_) => "TraitBoundModifier",
+            #[cfg(feature = "full")]
+            NodeRef::TraitItem(_) => "TraitItem",
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemConst(_) => "TraitItemConst",
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemMacro(_) => "TraitItemMacro",
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemMethod(_) => "TraitItemMethod",
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemType(_) => "TraitItemType",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Type(_) => "Type",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeArray(_) => "TypeArray",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeBareFn(_) => "TypeBareFn",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeGroup(_) => "TypeGroup",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeImplTrait(_) => "TypeImplTrait",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeInfer(_) => "TypeInfer",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeMacro(_) => "TypeMacro",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeNever(_) => "TypeNever",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeParam(_) => "TypeParam",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeParamBound(_) => "TypeParamBound",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeParen(_) => "TypeParen",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypePath(_) => "TypePath",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypePtr(_) => "TypePtr",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeReference(_) => "TypeReference",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeSlice(_) => "TypeSlice",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeTraitObject(_) => "TypeTraitObject",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeTuple(_) => "TypeTuple",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::UnOp(_) => "UnOp",
+            #[cfg(feature = "full")]
+            NodeRef::UseGlob(_) => "UseGlob",
+            #[cfg(feature = "full")]
+            NodeRef::UseGroup(_) => "UseGroup",
+            #[cfg(feature = "full")]
+            NodeRef::UseName(_) => "UseName",
+            #[cfg(feature = "full")]
+            NodeRef::UsePath(_) => "UsePath",
+            #[cfg(feature = "full")]
+            NodeRef::UseRename(_) => "UseRename",
+            #[cfg(feature = "full")]
+            NodeRef::UseTree(_) => "UseTree",
+            #[cfg(feature = "full")]
+            NodeRef::Variadic(_) => "Variadic",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Variant(_) => "Variant",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::VisCrate(_) => "VisCrate",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::VisPublic(_) => "VisPublic",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::VisRestricted(_) => "VisRestricted",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Visibility(_) => "Visibility",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::WhereClause(_) => "WhereClause",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::WherePredicate(_) => "WherePredicate",
+            NodeRef::__Nonexhaustive => unreachable!(),
+        }
+    }
+    ///The nodes directly contained in this one, in source order.
+    pub fn children(&self) -> Children<'a> {
+        let mut children = Vec::new();
+        match *self {
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Abi(node) => {
+                if let Some(it) = &node.name {
+                    children.push(NodeRef::LitStr(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::AngleBracketedGenericArguments(node) => {
+                for it in &node.args {
+                    children.push(NodeRef::GenericArgument(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::Arm(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Pat(&node.pat));
+                if let Some(it) = &node.guard {
+                    children.push(NodeRef::Expr(&*(it).1));
+                }
+                children.push(NodeRef::Expr(&*node.body));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Attribute(node) => {
+                children.push(NodeRef::AttrStyle(&node.style));
+                children.push(NodeRef::Path(&node.path));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::BareFnArg(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.name {
+                    children.push(NodeRef::Ident(&(it).0));
+                }
+                children.push(NodeRef::Type(&node.ty));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Binding(node) => {
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Type(&node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::Block(node) => {
+                for it in &node.stmts {
+                    children.push(NodeRef::Stmt(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::BoundLifetimes(node) => {
+                for it in &node.lifetimes {
+                    children.push(NodeRef::LifetimeDef(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ConstParam(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Type(&node.ty));
+                if let Some(it) = &node.default {
+                    children.push(NodeRef::Expr(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Constraint(node) => {
+                children.push(NodeRef::Ident(&node.ident));
+                for it in &node.bounds {
+                    children.push(NodeRef::TypeParamBound(it));
+                }
+            }
+            #[cfg(feature = "derive")]
+            NodeRef::Data(node) => match node {
+                Data::Struct(_binding_0) => {
+                    children.push(NodeRef::DataStruct(_binding_0));
+                }
+                Data::Enum(_binding_0) => {
+                    children.push(NodeRef::DataEnum(_binding_0));
+                }
+                Data::Union(_binding_0) => {
+                    children.push(NodeRef::DataUnion(_binding_0));
+                }
+            },
+            #[cfg(feature = "derive")]
+            NodeRef::DataEnum(node) => {
+                for it in &node.variants {
+                    children.push(NodeRef::Variant(it));
+                }
+            }
+            #[cfg(feature = "derive")]
+            NodeRef::DataStruct(node) => {
+                children.push(NodeRef::Fields(&node.fields));
+            }
+            #[cfg(feature = "derive")]
+            NodeRef::DataUnion(node) => {
+                children.push(NodeRef::FieldsNamed(&node.fields));
+            }
+            #[cfg(feature = "derive")]
+            NodeRef::DeriveInput(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Generics(&node.generics));
+                children.push(NodeRef::Data(&node.data));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Expr(node) => match node {
+                Expr::Array(_binding_0) => {
+                    full!(children.push(NodeRef::ExprArray(_binding_0)));
+                }
+                Expr::Assign(_binding_0) => {
+                    full!(children.push(NodeRef::ExprAssign(_binding_0)));
+                }
+                Expr::AssignOp(_binding_0) => {
+                    full!(children.push(NodeRef::ExprAssignOp(_binding_0)));
+                }
+                Expr::Async(_binding_0) => {
+                    full!(children.push(NodeRef::ExprAsync(_binding_0)));
+                }
+                Expr::Await(_binding_0) => {
+                    full!(children.push(NodeRef::ExprAwait(_binding_0)));
+                }
+                Expr::Binary(_binding_0) => {
+                    children.push(NodeRef::ExprBinary(_binding_0));
+                }
+                Expr::Block(_binding_0) => {
+                    full!(children.push(NodeRef::ExprBlock(_binding_0)));
+                }
+                Expr::Box(_binding_0) => {
+                    full!(children.push(NodeRef::ExprBox(_binding_0)));
+                }
+                Expr::Break(_binding_0) => {
+                    full!(children.push(NodeRef::ExprBreak(_binding_0)));
+                }
+                Expr::Call(_binding_0) => {
+                    children.push(NodeRef::ExprCall(_binding_0));
+                }
+                Expr::Cast(_binding_0) => {
+                    children.push(NodeRef::ExprCast(_binding_0));
+                }
+                Expr::Closure(_binding_0) => {
+                    full!(children.push(NodeRef::ExprClosure(_binding_0)));
+                }
+                Expr::Continue(_binding_0) => {
+                    full!(children.push(NodeRef::ExprContinue(_binding_0)));
+                }
+                Expr::Field(_binding_0) => {
+                    children.push(NodeRef::ExprField(_binding_0));
+                }
+                Expr::ForLoop(_binding_0) => {
+                    full!(children.push(NodeRef::ExprForLoop(_binding_0)));
+                }
+                Expr::Group(_binding_0) => {
+                    full!(children.push(NodeRef::ExprGroup(_binding_0)));
+                }
+                Expr::If(_binding_0) => {
+                    full!(children.push(NodeRef::ExprIf(_binding_0)));
+                }
+                Expr::Index(_binding_0) => {
+                    children.push(NodeRef::ExprIndex(_binding_0));
+                }
+                Expr::Let(_binding_0) => {
+                    full!(children.push(NodeRef::ExprLet(_binding_0)));
+                }
+                Expr::Lit(_binding_0) => {
+                    children.push(NodeRef::ExprLit(_binding_0));
+                }
+                Expr::Loop(_binding_0) => {
+                    full!(children.push(NodeRef::ExprLoop(_binding_0)));
+                }
+                Expr::Macro(_binding_0) => {
+                    full!(children.push(NodeRef::ExprMacro(_binding_0)));
+                }
+                Expr::Match(_binding_0) => {
+                    full!(children.push(NodeRef::ExprMatch(_binding_0)));
+                }
+                Expr::MethodCall(_binding_0) => {
+                    full!(children.push(NodeRef::ExprMethodCall(_binding_0)));
+                }
+                Expr::Paren(_binding_0) => {
+                    children.push(NodeRef::ExprParen(_binding_0));
+                }
+                Expr::Path(_binding_0) => {
+                    children.push(NodeRef::ExprPath(_binding_0));
+                }
+                Expr::Range(_binding_0) => {
+                    full!(children.push(NodeRef::ExprRange(_binding_0)));
+                }
+                Expr::Reference(_binding_0) => {
+                    full!(children.push(NodeRef::ExprReference(_binding_0)));
+                }
+                Expr::Repeat(_binding_0) => {
+                    full!(children.push(NodeRef::ExprRepeat(_binding_0)));
+                }
+                Expr::Return(_binding_0) => {
+                    full!(children.push(NodeRef::ExprReturn(_binding_0)));
+                }
+                Expr::Struct(_binding_0) => {
+                    full!(children.push(NodeRef::ExprStruct(_binding_0)));
+                }
+                Expr::Try(_binding_0) => {
+                    full!(children.push(NodeRef::ExprTry(_binding_0)));
+                }
+                Expr::TryBlock(_binding_0) => {
+                    full!(children.push(NodeRef::ExprTryBlock(_binding_0)));
+                }
+                Expr::Tuple(_binding_0) => {
+                    full!(children.push(NodeRef::ExprTuple(_binding_0)));
+                }
+                Expr::Type(_binding_0) => {
+                    full!(children.push(NodeRef::ExprType(_binding_0)));
+                }
+                Expr::Unary(_binding_0) => {
+                    children.push(NodeRef::ExprUnary(_binding_0));
+                }
+                Expr::Unsafe(_binding_0) => {
+                    full!(children.push(NodeRef::ExprUnsafe(_binding_0)));
+                }
+                Expr::While(_binding_0) => {
+                    full!(children.push(NodeRef::ExprWhile(_binding_0)));
+                }
+                Expr::Yield(_binding_0) => {
+                    full!(children.push(NodeRef::ExprYield(_binding_0)));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeRef::ExprArray(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                for it in &node.elems {
+                    children.push(NodeRef::Expr(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprAssign(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.left));
+                children.push(NodeRef::Expr(&*node.right));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprAssignOp(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.left));
+                children.push(NodeRef::BinOp(&node.op));
+                children.push(NodeRef::Expr(&*node.right));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprAsync(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Block(&node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprAwait(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.base));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprBinary(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.left));
+                children.push(NodeRef::BinOp(&node.op));
+                children.push(NodeRef::Expr(&*node.right));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprBlock(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.label {
+                    children.push(NodeRef::Label(it));
+                }
+                children.push(NodeRef::Block(&node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprBox(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprBreak(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.label {
+                    children.push(NodeRef::Lifetime(it));
+                }
+                if let Some(it) = &node.expr {
+                    children.push(NodeRef::Expr(&**it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprCall(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.func));
+                for it in &node.args {
+                    children.push(NodeRef::Expr(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprCast(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.expr));
+                children.push(NodeRef::Type(&*node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprClosure(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                for it in &node.inputs {
+                    children.push(NodeRef::Pat(it));
+                }
+                children.push(NodeRef::ReturnType(&node.output));
+                children.push(NodeRef::Expr(&*node.body));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprContinue(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.label {
+                    children.push(NodeRef::Lifetime(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprField(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.base));
+                children.push(NodeRef::Member(&node.member));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprForLoop(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.label {
+                    children.push(NodeRef::Label(it));
+                }
+                children.push(NodeRef::Pat(&node.pat));
+                children.push(NodeRef::Expr(&*node.expr));
+                children.push(NodeRef::Block(&node.body));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprGroup(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprIf(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.cond));
+                children.push(NodeRef::Block(&node.then_branch));
+                if let Some(it) = &node.else_branch {
+                    children.push(NodeRef::Expr(&*(it).1));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprIndex(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.expr));
+                children.push(NodeRef::Expr(&*node.index));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprLet(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Pat(&node.pat));
+                children.push(NodeRef::Expr(&*node.expr));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprLit(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Lit(&node.lit));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprLoop(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.label {
+                    children.push(NodeRef::Label(it));
+                }
+                children.push(NodeRef::Block(&node.body));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprMacro(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Macro(&node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprMatch(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.expr));
+                for it in &node.arms {
+                    children.push(NodeRef::Arm(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprMethodCall(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.receiver));
+                children.push(NodeRef::Ident(&node.method));
+                if let Some(it) = &node.turbofish {
+                    children.push(NodeRef::MethodTurbofish(it));
+                }
+                for it in &node.args {
+                    children.push(NodeRef::Expr(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprParen(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.expr));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprPath(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.qself {
+                    children.push(NodeRef::QSelf(it));
+                }
+                children.push(NodeRef::Path(&node.path));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprRange(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.from {
+                    children.push(NodeRef::Expr(&**it));
+                }
+                children.push(NodeRef::RangeLimits(&node.limits));
+                if let Some(it) = &node.to {
+                    children.push(NodeRef::Expr(&**it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprReference(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprRepeat(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.expr));
+                children.push(NodeRef::Expr(&*node.len));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprReturn(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.expr {
+                    children.push(NodeRef::Expr(&**it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprStruct(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Path(&node.path));
+                for it in &node.fields {
+                    children.push(NodeRef::FieldValue(it));
+                }
+                if let Some(it) = &node.rest {
+                    children.push(NodeRef::Expr(&**it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprTry(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprTryBlock(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Block(&node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprTuple(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                for it in &node.elems {
+                    children.push(NodeRef::Expr(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprType(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.expr));
+                children.push(NodeRef::Type(&*node.ty));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprUnary(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::UnOp(&node.op));
+                children.push(NodeRef::Expr(&*node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprUnsafe(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Block(&node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprWhile(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.label {
+                    children.push(NodeRef::Label(it));
+                }
+                children.push(NodeRef::Expr(&*node.cond));
+                children.push(NodeRef::Block(&node.body));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ExprYield(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.expr {
+                    children.push(NodeRef::Expr(&**it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Field(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                if let Some(it) = &node.ident {
+                    children.push(NodeRef::Ident(it));
+                }
+                children.push(NodeRef::Type(&node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::FieldPat(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Member(&node.member));
+                children.push(NodeRef::Pat(&*node.pat));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::FieldValue(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Member(&node.member));
+                children.push(NodeRef::Expr(&node.expr));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Fields(node) => match node {
+                Fields::Named(_binding_0) => {
+                    children.push(NodeRef::FieldsNamed(_binding_0));
+                }
+                Fields::Unnamed(_binding_0) => {
+                    children.push(NodeRef::FieldsUnnamed(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::FieldsNamed(node) => {
+                for it in &node.named {
+                    children.push(NodeRef::Field(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::FieldsUnnamed(node) => {
+                for it in &node.unnamed {
+                    children.push(NodeRef::Field(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::File(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                for it in &node.items {
+                    children.push(NodeRef::Item(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::FnArg(node) => match node {
+                FnArg::Receiver(_binding_0) => {
+                    children.push(NodeRef::Receiver(_binding_0));
+                }
+                FnArg::Typed(_binding_0) => {
+                    children.push(NodeRef::PatType(_binding_0));
+                }
+            },
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItem(node) => match node {
+                ForeignItem::Fn(_binding_0) => {
+                    children.push(NodeRef::ForeignItemFn(_binding_0));
+                }
+                ForeignItem::Static(_binding_0) => {
+                    children.push(NodeRef::ForeignItemStatic(_binding_0));
+                }
+                ForeignItem::Type(_binding_0) => {
+                    children.push(NodeRef::ForeignItemType(_binding_0));
+                }
+                ForeignItem::Macro(_binding_0) => {
+                    children.push(NodeRef::ForeignItemMacro(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemFn(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Signature(&node.sig));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemMacro(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Macro(&node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemStatic(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Type(&*node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemType(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::GenericArgument(node) => match node {
+                GenericArgument::Lifetime(_binding_0) => {
+                    children.push(NodeRef::Lifetime(_binding_0));
+                }
+                GenericArgument::Type(_binding_0) => {
+                    children.push(NodeRef::Type(_binding_0));
+                }
+                GenericArgument::Binding(_binding_0) => {
+                    children.push(NodeRef::Binding(_binding_0));
+                }
+                GenericArgument::Constraint(_binding_0) => {
+                    children.push(NodeRef::Constraint(_binding_0));
+                }
+                GenericArgument::Const(_binding_0) => {
+                    children.push(NodeRef::Expr(_binding_0));
+                }
+            },
+            #[cfg(feature = "full")]
+            NodeRef::GenericMethodArgument(node) => match node {
+                GenericMethodArgument::Type(_binding_0) => {
+                    children.push(NodeRef::Type(_binding_0));
+                }
+                GenericMethodArgument::Const(_binding_0) => {
+                    children.push(NodeRef::Expr(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::GenericParam(node) => match node {
+                GenericParam::Type(_binding_0) => {
+                    children.push(NodeRef::TypeParam(_binding_0));
+                }
+                GenericParam::Lifetime(_binding_0) => {
+                    children.push(NodeRef::LifetimeDef(_binding_0));
+                }
+                GenericParam::Const(_binding_0) => {
+                    children.push(NodeRef::ConstParam(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Generics(node) => {
+                for it in &node.params {
+                    children.push(NodeRef::GenericParam(it));
+                }
+                if let Some(it) = &node.where_clause {
+                    children.push(NodeRef::WhereClause(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ImplItem(node) => match node {
+                ImplItem::Const(_binding_0) => {
+                    children.push(NodeRef::ImplItemConst(_binding_0));
+                }
+                ImplItem::Method(_binding_0) => {
+                    children.push(NodeRef::ImplItemMethod(_binding_0));
+                }
+                ImplItem::Type(_binding_0) => {
+                    children.push(NodeRef::ImplItemType(_binding_0));
+                }
+                ImplItem::Macro(_binding_0) => {
+                    children.push(NodeRef::ImplItemMacro(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemConst(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Type(&node.ty));
+                children.push(NodeRef::Expr(&node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemMacro(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Macro(&node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemMethod(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Signature(&node.sig));
+                children.push(NodeRef::Block(&node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemType(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Generics(&node.generics));
+                children.push(NodeRef::Type(&node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::Item(node) => match node {
+                Item::Const(_binding_0) => {
+                    children.push(NodeRef::ItemConst(_binding_0));
+                }
+                Item::Enum(_binding_0) => {
+                    children.push(NodeRef::ItemEnum(_binding_0));
+                }
+                Item::ExternCrate(_binding_0) => {
+                    children.push(NodeRef::ItemExternCrate(_binding_0));
+                }
+                Item::Fn(_binding_0) => {
+                    children.push(NodeRef::ItemFn(_binding_0));
+                }
+                Item::ForeignMod(_binding_0) => {
+                    children.push(NodeRef::ItemForeignMod(_binding_0));
+                }
+                Item::Impl(_binding_0) => {
+                    children.push(NodeRef::ItemImpl(_binding_0));
+                }
+                Item::Macro(_binding_0) => {
+                    children.push(NodeRef::ItemMacro(_binding_0));
+                }
+                Item::Macro2(_binding_0) => {
+                    children.push(NodeRef::ItemMacro2(_binding_0));
+                }
+                Item::Mod(_binding_0) => {
+                    children.push(NodeRef::ItemMod(_binding_0));
+                }
+                Item::Static(_binding_0) => {
+                    children.push(NodeRef::ItemStatic(_binding_0));
+                }
+                Item::Struct(_binding_0) => {
+                    children.push(NodeRef::ItemStruct(_binding_0));
+                }
+                Item::Trait(_binding_0) => {
+                    children.push(NodeRef::ItemTrait(_binding_0));
+                }
+                Item::TraitAlias(_binding_0) => {
+                    children.push(NodeRef::ItemTraitAlias(_binding_0));
+                }
+                Item::Type(_binding_0) => {
+                    children.push(NodeRef::ItemType(_binding_0));
+                }
+                Item::Union(_binding_0) => {
+                    children.push(NodeRef::ItemUnion(_binding_0));
+                }
+                Item::Use(_binding_0) => {
+                    children.push(NodeRef::ItemUse(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeRef::ItemConst(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Type(&*node.ty));
+                children.push(NodeRef::Expr(&*node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemEnum(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Generics(&node.generics));
+                for it in &node.variants {
+                    children.push(NodeRef::Variant(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemExternCrate(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                if let Some(it) = &node.rename {
+                    children.push(NodeRef::Ident(&(it).1));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemFn(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Signature(&node.sig));
+                children.push(NodeRef::Block(&*node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemForeignMod(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Abi(&node.abi));
+                for it in &node.items {
+                    children.push(NodeRef::ForeignItem(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemImpl(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Generics(&node.generics));
+                if let Some(it) = &node.trait_ {
+                    children.push(NodeRef::Path(&(it).1));
+                }
+                children.push(NodeRef::Type(&*node.self_ty));
+                for it in &node.items {
+                    children.push(NodeRef::ImplItem(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemMacro(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.ident {
+                    children.push(NodeRef::Ident(it));
+                }
+                children.push(NodeRef::Macro(&node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemMacro2(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemMod(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                if let Some(it) = &node.content {
+                    for it in &(it).1 {
+                        children.push(NodeRef::Item(it));
+                    }
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemStatic(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Type(&*node.ty));
+                children.push(NodeRef::Expr(&*node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemStruct(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Generics(&node.generics));
+                children.push(NodeRef::Fields(&node.fields));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemTrait(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Generics(&node.generics));
+                for it in &node.supertraits {
+                    children.push(NodeRef::TypeParamBound(it));
+                }
+                for it in &node.items {
+                    children.push(NodeRef::TraitItem(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemTraitAlias(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Generics(&node.generics));
+                for it in &node.bounds {
+                    children.push(NodeRef::TypeParamBound(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemType(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Generics(&node.generics));
+                children.push(NodeRef::Type(&*node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemUnion(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Generics(&node.generics));
+                children.push(NodeRef::FieldsNamed(&node.fields));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::ItemUse(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Visibility(&node.vis));
+                children.push(NodeRef::UseTree(&node.tree));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::Label(node) => {
+                children.push(NodeRef::Lifetime(&node.name));
+            }
+            NodeRef::Lifetime(node) => {
+                children.push(NodeRef::Ident(&node.ident));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LifetimeDef(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Lifetime(&node.lifetime));
+                for it in &node.bounds {
+                    children.push(NodeRef::Lifetime(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Lit(node) => match node {
+                Lit::Str(_binding_0) => {
+                    children.push(NodeRef::LitStr(_binding_0));
+                }
+                Lit::ByteStr(_binding_0) => {
+                    children.push(NodeRef::LitByteStr(_binding_0));
+                }
+                Lit::Byte(_binding_0) => {
+                    children.push(NodeRef::LitByte(_binding_0));
+                }
+                Lit::Char(_binding_0) => {
+                    children.push(NodeRef::LitChar(_binding_0));
+                }
+                Lit::Int(_binding_0) => {
+                    children.push(NodeRef::LitInt(_binding_0));
+                }
+                Lit::Float(_binding_0) => {
+                    children.push(NodeRef::LitFloat(_binding_0));
+                }
+                Lit::Bool(_binding_0) => {
+                    children.push(NodeRef::LitBool(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeRef::Local(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Pat(&node.pat));
+                if let Some(it) = &node.init {
+                    children.push(NodeRef::Expr(&*(it).1));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Macro(node) => {
+                children.push(NodeRef::Path(&node.path));
+                children.push(NodeRef::MacroDelimiter(&node.delimiter));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Member(node) => match node {
+                Member::Named(_binding_0) => {
+                    children.push(NodeRef::Ident(_binding_0));
+                }
+                Member::Unnamed(_binding_0) => {
+                    children.push(NodeRef::Index(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Meta(node) => match node {
+                Meta::Path(_binding_0) => {
+                    children.push(NodeRef::Path(_binding_0));
+                }
+                Meta::List(_binding_0) => {
+                    children.push(NodeRef::MetaList(_binding_0));
+                }
+                Meta::NameValue(_binding_0) => {
+                    children.push(NodeRef::MetaNameValue(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaExpr(node) => match node {
+                MetaExpr::Path(_binding_0) => {
+                    children.push(NodeRef::Path(_binding_0));
+                }
+                MetaExpr::List(_binding_0) => {
+                    children.push(NodeRef::MetaExprList(_binding_0));
+                }
+                MetaExpr::NameValue(_binding_0) => {
+                    children.push(NodeRef::MetaExprNameValue(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaExprList(node) => {
+                children.push(NodeRef::Path(&node.path));
+                for it in &node.nested {
+                    children.push(NodeRef::NestedMetaExpr(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaExprNameValue(node) => {
+                children.push(NodeRef::Path(&node.path));
+                children.push(NodeRef::MetaValue(&node.value));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaList(node) => {
+                children.push(NodeRef::Path(&node.path));
+                for it in &node.nested {
+                    children.push(NodeRef::NestedMeta(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaNameValue(node) => {
+                children.push(NodeRef::Path(&node.path));
+                children.push(NodeRef::Lit(&node.lit));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaValue(node) => match node {
+                MetaValue::Expr(_binding_0) => {
+                    children.push(NodeRef::Expr(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeRef::MethodTurbofish(node) => {
+                for it in &node.args {
+                    children.push(NodeRef::GenericMethodArgument(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::NestedMeta(node) => match node {
+                NestedMeta::Meta(_binding_0) => {
+                    children.push(NodeRef::Meta(_binding_0));
+                }
+                NestedMeta::Lit(_binding_0) => {
+                    children.push(NodeRef::Lit(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::NestedMetaExpr(node) => match node {
+                NestedMetaExpr::Meta(_binding_0) => {
+                    children.push(NodeRef::MetaExpr(_binding_0));
+                }
+                NestedMetaExpr::Lit(_binding_0) => {
+                    children.push(NodeRef::Lit(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ParenthesizedGenericArguments(node) => {
+                for it in &node.inputs {
+                    children.push(NodeRef::Type(it));
+                }
+                children.push(NodeRef::ReturnType(&node.output));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::Pat(node) => match node {
+                Pat::Box(_binding_0) => {
+                    children.push(NodeRef::PatBox(_binding_0));
+                }
+                Pat::Ident(_binding_0) => {
+                    children.push(NodeRef::PatIdent(_binding_0));
+                }
+                Pat::Lit(_binding_0) => {
+                    children.push(NodeRef::PatLit(_binding_0));
+                }
+                Pat::Macro(_binding_0) => {
+                    children.push(NodeRef::PatMacro(_binding_0));
+                }
+                Pat::Or(_binding_0) => {
+                    children.push(NodeRef::PatOr(_binding_0));
+                }
+                Pat::Path(_binding_0) => {
+                    children.push(NodeRef::PatPath(_binding_0));
+                }
+                Pat::Range(_binding_0) => {
+                    children.push(NodeRef::PatRange(_binding_0));
+                }
+                Pat::Reference(_binding_0) => {
+                    children.push(NodeRef::PatReference(_binding_0));
+                }
+                Pat::Rest(_binding_0) => {
+                    children.push(NodeRef::PatRest(_binding_0));
+                }
+                Pat::Slice(_binding_0) => {
+                    children.push(NodeRef::PatSlice(_binding_0));
+                }
+                Pat::Struct(_binding_0) => {
+                    children.push(NodeRef::PatStruct(_binding_0));
+                }
+                Pat::Tuple(_binding_0) => {
+                    children.push(NodeRef::PatTuple(_binding_0));
+                }
+                Pat::TupleStruct(_binding_0) => {
+                    children.push(NodeRef::PatTupleStruct(_binding_0));
+                }
+                Pat::Type(_binding_0) => {
+                    children.push(NodeRef::PatType(_binding_0));
+                }
+                Pat::Wild(_binding_0) => {
+                    children.push(NodeRef::PatWild(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeRef::PatBox(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Pat(&*node.pat));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatIdent(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Ident(&node.ident));
+                if let Some(it) = &node.subpat {
+                    children.push(NodeRef::Pat(&*(it).1));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatLit(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatMacro(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Macro(&node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatOr(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                for it in &node.cases {
+                    children.push(NodeRef::Pat(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatPath(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.qself {
+                    children.push(NodeRef::QSelf(it));
+                }
+                children.push(NodeRef::Path(&node.path));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatRange(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Expr(&*node.lo));
+                children.push(NodeRef::RangeLimits(&node.limits));
+                children.push(NodeRef::Expr(&*node.hi));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatReference(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Pat(&*node.pat));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatRest(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatSlice(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                for it in &node.elems {
+                    children.push(NodeRef::Pat(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatStruct(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Path(&node.path));
+                for it in &node.fields {
+                    children.push(NodeRef::FieldPat(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatTuple(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                for it in &node.elems {
+                    children.push(NodeRef::Pat(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatTupleStruct(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Path(&node.path));
+                children.push(NodeRef::PatTuple(&node.pat));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatType(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Pat(&*node.pat));
+                children.push(NodeRef::Type(&*node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::PatWild(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Path(node) => {
+                for it in &node.segments {
+                    children.push(NodeRef::PathSegment(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PathArguments(node) => match node {
+                PathArguments::AngleBracketed(_binding_0) => {
+                    children.push(NodeRef::AngleBracketedGenericArguments(_binding_0));
+                }
+                PathArguments::Parenthesized(_binding_0) => {
+                    children.push(NodeRef::ParenthesizedGenericArguments(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PathSegment(node) => {
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::PathArguments(&node.arguments));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PredicateEq(node) => {
+                children.push(NodeRef::Type(&node.lhs_ty));
+                children.push(NodeRef::Type(&node.rhs_ty));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PredicateLifetime(node) => {
+                children.push(NodeRef::Lifetime(&node.lifetime));
+                for it in &node.bounds {
+                    children.push(NodeRef::Lifetime(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PredicateType(node) => {
+                if let Some(it) = &node.lifetimes {
+                    children.push(NodeRef::BoundLifetimes(it));
+                }
+                children.push(NodeRef::Type(&node.bounded_ty));
+                for it in &node.bounds {
+                    children.push(NodeRef::TypeParamBound(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::QSelf(node) => {
+                children.push(NodeRef::Type(&*node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::Receiver(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                if let Some(it) = &node.reference {
+                    if let Some(it) = &(it).1 {
+                        children.push(NodeRef::Lifetime(it));
+                    }
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ReturnType(node) => match node {
+                ReturnType::Type(_binding_0, _binding_1) => {
+                    children.push(NodeRef::Type(&**_binding_1));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeRef::Signature(node) => {
+                if let Some(it) = &node.abi {
+                    children.push(NodeRef::Abi(it));
+                }
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Generics(&node.generics));
+                for it in &node.inputs {
+                    children.push(NodeRef::FnArg(it));
+                }
+                if let Some(it) = &node.variadic {
+                    children.push(NodeRef::Variadic(it));
+                }
+                children.push(NodeRef::ReturnType(&node.output));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::Stmt(node) => match node {
+                Stmt::Local(_binding_0) => {
+                    children.push(NodeRef::Local(_binding_0));
+                }
+                Stmt::Item(_binding_0) => {
+                    children.push(NodeRef::Item(_binding_0));
+                }
+                Stmt::Expr(_binding_0) => {
+                    children.push(NodeRef::Expr(_binding_0));
+                }
+                Stmt::Semi(_binding_0, _binding_1) => {
+                    children.push(NodeRef::Expr(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TraitBound(node) => {
+                children.push(NodeRef::TraitBoundModifier(&node.modifier));
+                if let Some(it) = &node.lifetimes {
+                    children.push(NodeRef::BoundLifetimes(it));
+                }
+                children.push(NodeRef::Path(&node.path));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::TraitItem(node) => match node {
+                TraitItem::Const(_binding_0) => {
+                    children.push(NodeRef::TraitItemConst(_binding_0));
+                }
+                TraitItem::Method(_binding_0) => {
+                    children.push(NodeRef::TraitItemMethod(_binding_0));
+                }
+                TraitItem::Type(_binding_0) => {
+                    children.push(NodeRef::TraitItemType(_binding_0));
+                }
+                TraitItem::Macro(_binding_0) => {
+                    children.push(NodeRef::TraitItemMacro(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemConst(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Type(&node.ty));
+                if let Some(it) = &node.default {
+                    children.push(NodeRef::Expr(&(it).1));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemMacro(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Macro(&node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemMethod(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Signature(&node.sig));
+                if let Some(it) = &node.default {
+                    children.push(NodeRef::Block(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemType(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Generics(&node.generics));
+                for it in &node.bounds {
+                    children.push(NodeRef::TypeParamBound(it));
+                }
+                if let Some(it) = &node.default {
+                    children.push(NodeRef::Type(&(it).1));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Type(node) => match node {
+                Type::Array(_binding_0) => {
+                    children.push(NodeRef::TypeArray(_binding_0));
+                }
+                Type::BareFn(_binding_0) => {
+                    children.push(NodeRef::TypeBareFn(_binding_0));
+                }
+                Type::Group(_binding_0) => {
+                    children.push(NodeRef::TypeGroup(_binding_0));
+                }
+                Type::ImplTrait(_binding_0) => {
+                    children.push(NodeRef::TypeImplTrait(_binding_0));
+                }
+                Type::Infer(_binding_0) => {
+                    children.push(NodeRef::TypeInfer(_binding_0));
+                }
+                Type::Macro(_binding_0) => {
+                    children.push(NodeRef::TypeMacro(_binding_0));
+                }
+                Type::Never(_binding_0) => {
+                    children.push(NodeRef::TypeNever(_binding_0));
+                }
+                Type::Paren(_binding_0) => {
+                    children.push(NodeRef::TypeParen(_binding_0));
+                }
+                Type::Path(_binding_0) => {
+                    children.push(NodeRef::TypePath(_binding_0));
+                }
+                Type::Ptr(_binding_0) => {
+                    children.push(NodeRef::TypePtr(_binding_0));
+                }
+                Type::Reference(_binding_0) => {
+                    children.push(NodeRef::TypeReference(_binding_0));
+                }
+                Type::Slice(_binding_0) => {
+                    children.push(NodeRef::TypeSlice(_binding_0));
+                }
+                Type::TraitObject(_binding_0) => {
+                    children.push(NodeRef::TypeTraitObject(_binding_0));
+                }
+                Type::Tuple(_binding_0) => {
+                    children.push(NodeRef::TypeTuple(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeArray(node) => {
+                children.push(NodeRef::Type(&*node.elem));
+                children.push(NodeRef::Expr(&node.len));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeBareFn(node) => {
+                if let Some(it) = &node.lifetimes {
+                    children.push(NodeRef::BoundLifetimes(it));
+                }
+                if let Some(it) = &node.abi {
+                    children.push(NodeRef::Abi(it));
+                }
+                for it in &node.inputs {
+                    children.push(NodeRef::BareFnArg(it));
+                }
+                children.push(NodeRef::ReturnType(&node.output));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeGroup(node) => {
+                children.push(NodeRef::Type(&*node.elem));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeImplTrait(node) => {
+                for it in &node.bounds {
+                    children.push(NodeRef::TypeParamBound(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeMacro(node) => {
+                children.push(NodeRef::Macro(&node.mac));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeParam(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Ident(&node.ident));
+                for it in &node.bounds {
+                    children.push(NodeRef::TypeParamBound(it));
+                }
+                if let Some(it) = &node.default {
+                    children.push(NodeRef::Type(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeParamBound(node) => match node {
+                TypeParamBound::Trait(_binding_0) => {
+                    children.push(NodeRef::TraitBound(_binding_0));
+                }
+                TypeParamBound::Lifetime(_binding_0) => {
+                    children.push(NodeRef::Lifetime(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeParen(node) => {
+                children.push(NodeRef::Type(&*node.elem));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypePath(node) => {
+                if let Some(it) = &node.qself {
+                    children.push(NodeRef::QSelf(it));
+                }
+                children.push(NodeRef::Path(&node.path));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypePtr(node) => {
+                children.push(NodeRef::Type(&*node.elem));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeReference(node) => {
+                if let Some(it) = &node.lifetime {
+                    children.push(NodeRef::Lifetime(it));
+                }
+                children.push(NodeRef::Type(&*node.elem));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeSlice(node) => {
+                children.push(NodeRef::Type(&*node.elem));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeTraitObject(node) => {
+                for it in &node.bounds {
+                    children.push(NodeRef::TypeParamBound(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeTuple(node) => {
+                for it in &node.elems {
+                    children.push(NodeRef::Type(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::UseGroup(node) => {
+                for it in &node.items {
+                    children.push(NodeRef::UseTree(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeRef::UseName(node) => {
+                children.push(NodeRef::Ident(&node.ident));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::UsePath(node) => {
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::UseTree(&*node.tree));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::UseRename(node) => {
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Ident(&node.rename));
+            }
+            #[cfg(feature = "full")]
+            NodeRef::UseTree(node) => match node {
+                UseTree::Path(_binding_0) => {
+                    children.push(NodeRef::UsePath(_binding_0));
+                }
+                UseTree::Name(_binding_0) => {
+                    children.push(NodeRef::UseName(_binding_0));
+                }
+                UseTree::Rename(_binding_0) => {
+                    children.push(NodeRef::UseRename(_binding_0));
+                }
+                UseTree::Glob(_binding_0) => {
+                    children.push(NodeRef::UseGlob(_binding_0));
+                }
+                UseTree::Group(_binding_0) => {
+                    children.push(NodeRef::UseGroup(_binding_0));
+                }
+            },
+            #[cfg(feature = "full")]
+            NodeRef::Variadic(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Variant(node) => {
+                for it in &node.attrs {
+                    children.push(NodeRef::Attribute(it));
+                }
+                children.push(NodeRef::Ident(&node.ident));
+                children.push(NodeRef::Fields(&node.fields));
+                if let Some(it) = &node.discriminant {
+                    children.push(NodeRef::Expr(&(it).1));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::VisRestricted(node) => {
+                children.push(NodeRef::Path(&*node.path));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Visibility(node) => match node {
+                Visibility::Public(_binding_0) => {
+                    children.push(NodeRef::VisPublic(_binding_0));
+                }
+                Visibility::Crate(_binding_0) => {
+                    children.push(NodeRef::VisCrate(_binding_0));
+                }
+                Visibility::Restricted(_binding_0) => {
+                    children.push(NodeRef::VisRestricted(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::WhereClause(node) => {
+                for it in &node.predicates {
+                    children.push(NodeRef::WherePredicate(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::WherePredicate(node) => match node {
+                WherePredicate::Type(_binding_0) => {
+                    children.push(NodeRef::PredicateType(_binding_0));
+                }
+                WherePredicate::Lifetime(_binding_0) => {
+                    children.push(NodeRef::PredicateLifetime(_binding_0));
+                }
+                WherePredicate::Eq(_binding_0) => {
+                    children.push(NodeRef::PredicateEq(_binding_0));
+                }
+            },
+            _ => {}
+        }
+        Children {
+            iter: children.into_iter(),
+        }
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Abi> for NodeRef<'a> {
+    fn from(node: &'a Abi) -> Self {
+        NodeRef::Abi(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a AngleBracketedGenericArguments> for NodeRef<'a> {
+    fn from(node: &'a AngleBracketedGenericArguments) -> Self {
+        NodeRef::AngleBracketedGenericArguments(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a Arm> for NodeRef<'a> {
+    fn from(node: &'a Arm) -> Self {
+        NodeRef::Arm(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a AttrStyle> for NodeRef<'a> {
+    fn from(node: &'a AttrStyle) -> Self {
+        NodeRef::AttrStyle(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Attribute> for NodeRef<'a> {
+    fn from(node: &'a Attribute) -> Self {
+        NodeRef::Attribute(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a BareFnArg> for NodeRef<'a> {
+    fn from(node: &'a BareFnArg) -> Self {
+        NodeRef::BareFnArg(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a BinOp> for NodeRef<'a> {
+    fn from(node: &'a BinOp) -> Self {
+        NodeRef::BinOp(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Binding> for NodeRef<'a> {
+    fn from(node: &'a Binding) -> Self {
+        NodeRef::Binding(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a Block> for NodeRef<'a> {
+    fn from(node: &'a Block) -> Self {
+        NodeRef::Block(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a BoundLifetimes> for NodeRef<'a> {
+    fn from(node: &'a BoundLifetimes) -> Self {
+        NodeRef::BoundLifetimes(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ConstParam> for NodeRef<'a> {
+    fn from(node: &'a ConstParam) -> Self {
+        NodeRef::ConstParam(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Constraint> for NodeRef<'a> {
+    fn from(node: &'a Constraint) -> Self {
+        NodeRef::Constraint(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "derive")]
+impl<'a> From<&'a Data> for NodeRef<'a> {
+    fn from(node: &'a Data) -> Self {
+        NodeRef::Data(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "derive")]
+impl<'a> From<&'a DataEnum> for NodeRef<'a> {
+    fn from(node: &'a DataEnum) -> Self {
+        NodeRef::DataEnum(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "derive")]
+impl<'a> From<&'a DataStruct> for NodeRef<'a> {
+    fn from(node: &'a DataStruct) -> Self {
+        NodeRef::DataStruct(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "derive")]
+impl<'a> From<&'a DataUnion> for NodeRef<'a> {
+    fn from(node: &'a DataUnion) -> Self {
+        NodeRef::DataUnion(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "derive")]
+impl<'a> From<&'a DeriveInput> for NodeRef<'a> {
+    fn from(node: &'a DeriveInput) -> Self {
+        NodeRef::DeriveInput(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Expr> for NodeRef<'a> {
+    fn from(node: &'a Expr) -> Self {
+        NodeRef::Expr(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprArray> for NodeRef<'a> {
+    fn from(node: &'a ExprArray) -> Self {
+        NodeRef::ExprArray(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprAssign> for NodeRef<'a> {
+    fn from(node: &'a ExprAssign) -> Self {
+        NodeRef::ExprAssign(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprAssignOp> for NodeRef<'a> {
+    fn from(node: &'a ExprAssignOp) -> Self {
+        NodeRef::ExprAssignOp(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprAsync> for NodeRef<'a> {
+    fn from(node: &'a ExprAsync) -> Self {
+        NodeRef::ExprAsync(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprAwait> for NodeRef<'a> {
+    fn from(node: &'a ExprAwait) -> Self {
+        NodeRef::ExprAwait(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ExprBinary> for NodeRef<'a> {
+    fn from(node: &'a ExprBinary) -> Self {
+        NodeRef::ExprBinary(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprBlock> for NodeRef<'a> {
+    fn from(node: &'a ExprBlock) -> Self {
+        NodeRef::ExprBlock(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprBox> for NodeRef<'a> {
+    fn from(node: &'a ExprBox) -> Self {
+        NodeRef::ExprBox(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprBreak> for NodeRef<'a> {
+    fn from(node: &'a ExprBreak) -> Self {
+        NodeRef::ExprBreak(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ExprCall> for NodeRef<'a> {
+    fn from(node: &'a ExprCall) -> Self {
+        NodeRef::ExprCall(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ExprCast> for NodeRef<'a> {
+    fn from(node: &'a ExprCast) -> Self {
+        NodeRef::ExprCast(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprClosure> for NodeRef<'a> {
+    fn from(node: &'a ExprClosure) -> Self {
+        NodeRef::ExprClosure(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprContinue> for NodeRef<'a> {
+    fn from(node: &'a ExprContinue) -> Self {
+        NodeRef::ExprContinue(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ExprField> for NodeRef<'a> {
+    fn from(node: &'a ExprField) -> Self {
+        NodeRef::ExprField(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprForLoop> for NodeRef<'a> {
+    fn from(node: &'a ExprForLoop) -> Self {
+        NodeRef::ExprForLoop(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprGroup> for NodeRef<'a> {
+    fn from(node: &'a ExprGroup) -> Self {
+        NodeRef::ExprGroup(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprIf> for NodeRef<'a> {
+    fn from(node: &'a ExprIf) -> Self {
+        NodeRef::ExprIf(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ExprIndex> for NodeRef<'a> {
+    fn from(node: &'a ExprIndex) -> Self {
+        NodeRef::ExprIndex(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprLet> for NodeRef<'a> {
+    fn from(node: &'a ExprLet) -> Self {
+        NodeRef::ExprLet(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ExprLit> for NodeRef<'a> {
+    fn from(node: &'a ExprLit) -> Self {
+        NodeRef::ExprLit(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprLoop> for NodeRef<'a> {
+    fn from(node: &'a ExprLoop) -> Self {
+        NodeRef::ExprLoop(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprMacro> for NodeRef<'a> {
+    fn from(node: &'a ExprMacro) -> Self {
+        NodeRef::ExprMacro(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprMatch> for NodeRef<'a> {
+    fn from(node: &'a ExprMatch) -> Self {
+        NodeRef::ExprMatch(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprMethodCall> for NodeRef<'a> {
+    fn from(node: &'a ExprMethodCall) -> Self {
+        NodeRef::ExprMethodCall(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ExprParen> for NodeRef<'a> {
+    fn from(node: &'a ExprParen) -> Self {
+        NodeRef::ExprParen(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ExprPath> for NodeRef<'a> {
+    fn from(node: &'a ExprPath) -> Self {
+        NodeRef::ExprPath(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprRange> for NodeRef<'a> {
+    fn from(node: &'a ExprRange) -> Self {
+        NodeRef::ExprRange(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprReference> for NodeRef<'a> {
+    fn from(node: &'a ExprReference) -> Self {
+        NodeRef::ExprReference(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprRepeat> for NodeRef<'a> {
+    fn from(node: &'a ExprRepeat) -> Self {
+        NodeRef::ExprRepeat(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprReturn> for NodeRef<'a> {
+    fn from(node: &'a ExprReturn) -> Self {
+        NodeRef::ExprReturn(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprStruct> for NodeRef<'a> {
+    fn from(node: &'a ExprStruct) -> Self {
+        NodeRef::ExprStruct(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprTry> for NodeRef<'a> {
+    fn from(node: &'a ExprTry) -> Self {
+        NodeRef::ExprTry(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprTryBlock> for NodeRef<'a> {
+    fn from(node: &'a ExprTryBlock) -> Self {
+        NodeRef::ExprTryBlock(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprTuple> for NodeRef<'a> {
+    fn from(node: &'a ExprTuple) -> Self {
+        NodeRef::ExprTuple(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprType> for NodeRef<'a> {
+    fn from(node: &'a ExprType) -> Self {
+        NodeRef::ExprType(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ExprUnary> for NodeRef<'a> {
+    fn from(node: &'a ExprUnary) -> Self {
+        NodeRef::ExprUnary(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprUnsafe> for NodeRef<'a> {
+    fn from(node: &'a ExprUnsafe) -> Self {
+        NodeRef::ExprUnsafe(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprWhile> for NodeRef<'a> {
+    fn from(node: &'a ExprWhile) -> Self {
+        NodeRef::ExprWhile(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ExprYield> for NodeRef<'a> {
+    fn from(node: &'a ExprYield) -> Self {
+        NodeRef::ExprYield(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Field> for NodeRef<'a> {
+    fn from(node: &'a Field) -> Self {
+        NodeRef::Field(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a FieldPat> for NodeRef<'a> {
+    fn from(node: &'a FieldPat) -> Self {
+        NodeRef::FieldPat(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a FieldValue> for NodeRef<'a> {
+    fn from(node: &'a FieldValue) -> Self {
+        NodeRef::FieldValue(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Fields> for NodeRef<'a> {
+    fn from(node: &'a Fields) -> Self {
+        NodeRef::Fields(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a FieldsNamed> for NodeRef<'a> {
+    fn from(node: &'a FieldsNamed) -> Self {
+        NodeRef::FieldsNamed(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a FieldsUnnamed> for NodeRef<'a> {
+    fn from(node: &'a FieldsUnnamed) -> Self {
+        NodeRef::FieldsUnnamed(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a File> for NodeRef<'a> {
+    fn from(node: &'a File) -> Self {
+        NodeRef::File(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a FnArg> for NodeRef<'a> {
+    fn from(node: &'a FnArg) -> Self {
+        NodeRef::FnArg(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ForeignItem> for NodeRef<'a> {
+    fn from(node: &'a ForeignItem) -> Self {
+        NodeRef::ForeignItem(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ForeignItemFn> for NodeRef<'a> {
+    fn from(node: &'a ForeignItemFn) -> Self {
+        NodeRef::ForeignItemFn(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ForeignItemMacro> for NodeRef<'a> {
+    fn from(node: &'a ForeignItemMacro) -> Self {
+        NodeRef::ForeignItemMacro(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ForeignItemStatic> for NodeRef<'a> {
+    fn from(node: &'a ForeignItemStatic) -> Self {
+        NodeRef::ForeignItemStatic(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ForeignItemType> for NodeRef<'a> {
+    fn from(node: &'a ForeignItemType) -> Self {
+        NodeRef::ForeignItemType(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a GenericArgument> for NodeRef<'a> {
+    fn from(node: &'a GenericArgument) -> Self {
+        NodeRef::GenericArgument(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a GenericMethodArgument> for NodeRef<'a> {
+    fn from(node: &'a GenericMethodArgument) -> Self {
+        NodeRef::GenericMethodArgument(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a GenericParam> for NodeRef<'a> {
+    fn from(node: &'a GenericParam) -> Self {
+        NodeRef::GenericParam(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Generics> for NodeRef<'a> {
+    fn from(node: &'a Generics) -> Self {
+        NodeRef::Generics(node)
+    }
+}
+#[cfg(feature = "visit")]
+impl<'a> From<&'a Ident> for NodeRef<'a> {
+    fn from(node: &'a Ident) -> Self {
+        NodeRef::Ident(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ImplItem> for NodeRef<'a> {
+    fn from(node: &'a ImplItem) -> Self {
+        NodeRef::ImplItem(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ImplItemConst> for NodeRef<'a> {
+    fn from(node: &'a ImplItemConst) -> Self {
+        NodeRef::ImplItemConst(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ImplItemMacro> for NodeRef<'a> {
+    fn from(node: &'a ImplItemMacro) -> Self {
+        NodeRef::ImplItemMacro(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ImplItemMethod> for NodeRef<'a> {
+    fn from(node: &'a ImplItemMethod) -> Self {
+        NodeRef::ImplItemMethod(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ImplItemType> for NodeRef<'a> {
+    fn from(node: &'a ImplItemType) -> Self {
+        NodeRef::ImplItemType(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Index> for NodeRef<'a> {
+    fn from(node: &'a Index) -> Self {
+        NodeRef::Index(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a Item> for NodeRef<'a> {
+    fn from(node: &'a Item) -> Self {
+        NodeRef::Item(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemConst> for NodeRef<'a> {
+    fn from(node: &'a ItemConst) -> Self {
+        NodeRef::ItemConst(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemEnum> for NodeRef<'a> {
+    fn from(node: &'a ItemEnum) -> Self {
+        NodeRef::ItemEnum(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemExternCrate> for NodeRef<'a> {
+    fn from(node: &'a ItemExternCrate) -> Self {
+        NodeRef::ItemExternCrate(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemFn> for NodeRef<'a> {
+    fn from(node: &'a ItemFn) -> Self {
+        NodeRef::ItemFn(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemForeignMod> for NodeRef<'a> {
+    fn from(node: &'a ItemForeignMod) -> Self {
+        NodeRef::ItemForeignMod(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemImpl> for NodeRef<'a> {
+    fn from(node: &'a ItemImpl) -> Self {
+        NodeRef::ItemImpl(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemMacro> for NodeRef<'a> {
+    fn from(node: &'a ItemMacro) -> Self {
+        NodeRef::ItemMacro(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemMacro2> for NodeRef<'a> {
+    fn from(node: &'a ItemMacro2) -> Self {
+        NodeRef::ItemMacro2(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemMod> for NodeRef<'a> {
+    fn from(node: &'a ItemMod) -> Self {
+        NodeRef::ItemMod(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemStatic> for NodeRef<'a> {
+    fn from(node: &'a ItemStatic) -> Self {
+        NodeRef::ItemStatic(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemStruct> for NodeRef<'a> {
+    fn from(node: &'a ItemStruct) -> Self {
+        NodeRef::ItemStruct(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemTrait> for NodeRef<'a> {
+    fn from(node: &'a ItemTrait) -> Self {
+        NodeRef::ItemTrait(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemTraitAlias> for NodeRef<'a> {
+    fn from(node: &'a ItemTraitAlias) -> Self {
+        NodeRef::ItemTraitAlias(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemType> for NodeRef<'a> {
+    fn from(node: &'a ItemType) -> Self {
+        NodeRef::ItemType(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemUnion> for NodeRef<'a> {
+    fn from(node: &'a ItemUnion) -> Self {
+        NodeRef::ItemUnion(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a ItemUse> for NodeRef<'a> {
+    fn from(node: &'a ItemUse) -> Self {
+        NodeRef::ItemUse(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a Label> for NodeRef<'a> {
+    fn from(node: &'a Label) -> Self {
+        NodeRef::Label(node)
+    }
+}
+#[cfg(feature = "visit")]
+impl<'a> From<&'a Lifetime> for NodeRef<'a> {
+    fn from(node: &'a Lifetime) -> Self {
+        NodeRef::Lifetime(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a LifetimeDef> for NodeRef<'a> {
+    fn from(node: &'a LifetimeDef) -> Self {
+        NodeRef::LifetimeDef(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Lit> for NodeRef<'a> {
+    fn from(node: &'a Lit) -> Self {
+        NodeRef::Lit(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a LitBool> for NodeRef<'a> {
+    fn from(node: &'a LitBool) -> Self {
+        NodeRef::LitBool(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a LitByte> for NodeRef<'a> {
+    fn from(node: &'a LitByte) -> Self {
+        NodeRef::LitByte(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a LitByteStr> for NodeRef<'a> {
+    fn from(node: &'a LitByteStr) -> Self {
+        NodeRef::LitByteStr(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a LitChar> for NodeRef<'a> {
+    fn from(node: &'a LitChar) -> Self {
+        NodeRef::LitChar(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a LitFloat> for NodeRef<'a> {
+    fn from(node: &'a LitFloat) -> Self {
+        NodeRef::LitFloat(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a LitInt> for NodeRef<'a> {
+    fn from(node: &'a LitInt) -> Self {
+        NodeRef::LitInt(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a LitStr> for NodeRef<'a> {
+    fn from(node: &'a LitStr) -> Self {
+        NodeRef::LitStr(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a Local> for NodeRef<'a> {
+    fn from(node: &'a Local) -> Self {
+        NodeRef::Local(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Macro> for NodeRef<'a> {
+    fn from(node: &'a Macro) -> Self {
+        NodeRef::Macro(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a MacroDelimiter> for NodeRef<'a> {
+    fn from(node: &'a MacroDelimiter) -> Self {
+        NodeRef::MacroDelimiter(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Member> for NodeRef<'a> {
+    fn from(node: &'a Member) -> Self {
+        NodeRef::Member(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Meta> for NodeRef<'a> {
+    fn from(node: &'a Meta) -> Self {
+        NodeRef::Meta(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a MetaExpr> for NodeRef<'a> {
+    fn from(node: &'a MetaExpr) -> Self {
+        NodeRef::MetaExpr(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a MetaExprList> for NodeRef<'a> {
+    fn from(node: &'a MetaExprList) -> Self {
+        NodeRef::MetaExprList(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a MetaExprNameValue> for NodeRef<'a> {
+    fn from(node: &'a MetaExprNameValue) -> Self {
+        NodeRef::MetaExprNameValue(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a MetaList> for NodeRef<'a> {
+    fn from(node: &'a MetaList) -> Self {
+        NodeRef::MetaList(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a MetaNameValue> for NodeRef<'a> {
+    fn from(node: &'a MetaNameValue) -> Self {
+        NodeRef::MetaNameValue(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a MetaValue> for NodeRef<'a> {
+    fn from(node: &'a MetaValue) -> Self {
+        NodeRef::MetaValue(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a MethodTurbofish> for NodeRef<'a> {
+    fn from(node: &'a MethodTurbofish) -> Self {
+        NodeRef::MethodTurbofish(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a NestedMeta> for NodeRef<'a> {
+    fn from(node: &'a NestedMeta) -> Self {
+        NodeRef::NestedMeta(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a NestedMetaExpr> for NodeRef<'a> {
+    fn from(node: &'a NestedMetaExpr) -> Self {
+        NodeRef::NestedMetaExpr(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ParenthesizedGenericArguments> for NodeRef<'a> {
+    fn from(node: &'a ParenthesizedGenericArguments) -> Self {
+        NodeRef::ParenthesizedGenericArguments(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a Pat> for NodeRef<'a> {
+    fn from(node: &'a Pat) -> Self {
+        NodeRef::Pat(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatBox> for NodeRef<'a> {
+    fn from(node: &'a PatBox) -> Self {
+        NodeRef::PatBox(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatIdent> for NodeRef<'a> {
+    fn from(node: &'a PatIdent) -> Self {
+        NodeRef::PatIdent(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatLit> for NodeRef<'a> {
+    fn from(node: &'a PatLit) -> Self {
+        NodeRef::PatLit(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatMacro> for NodeRef<'a> {
+    fn from(node: &'a PatMacro) -> Self {
+        NodeRef::PatMacro(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatOr> for NodeRef<'a> {
+    fn from(node: &'a PatOr) -> Self {
+        NodeRef::PatOr(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatPath> for NodeRef<'a> {
+    fn from(node: &'a PatPath) -> Self {
+        NodeRef::PatPath(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatRange> for NodeRef<'a> {
+    fn from(node: &'a PatRange) -> Self {
+        NodeRef::PatRange(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatReference> for NodeRef<'a> {
+    fn from(node: &'a PatReference) -> Self {
+        NodeRef::PatReference(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatRest> for NodeRef<'a> {
+    fn from(node: &'a PatRest) -> Self {
+        NodeRef::PatRest(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatSlice> for NodeRef<'a> {
+    fn from(node: &'a PatSlice) -> Self {
+        NodeRef::PatSlice(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatStruct> for NodeRef<'a> {
+    fn from(node: &'a PatStruct) -> Self {
+        NodeRef::PatStruct(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatTuple> for NodeRef<'a> {
+    fn from(node: &'a PatTuple) -> Self {
+        NodeRef::PatTuple(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatTupleStruct> for NodeRef<'a> {
+    fn from(node: &'a PatTupleStruct) -> Self {
+        NodeRef::PatTupleStruct(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatType> for NodeRef<'a> {
+    fn from(node: &'a PatType) -> Self {
+        NodeRef::PatType(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a PatWild> for NodeRef<'a> {
+    fn from(node: &'a PatWild) -> Self {
+        NodeRef::PatWild(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Path> for NodeRef<'a> {
+    fn from(node: &'a Path) -> Self {
+        NodeRef::Path(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a PathArguments> for NodeRef<'a> {
+    fn from(node: &'a PathArguments) -> Self {
+        NodeRef::PathArguments(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a PathSegment> for NodeRef<'a> {
+    fn from(node: &'a PathSegment) -> Self {
+        NodeRef::PathSegment(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a PredicateEq> for NodeRef<'a> {
+    fn from(node: &'a PredicateEq) -> Self {
+        NodeRef::PredicateEq(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a PredicateLifetime> for NodeRef<'a> {
+    fn from(node: &'a PredicateLifetime) -> Self {
+        NodeRef::PredicateLifetime(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a PredicateType> for NodeRef<'a> {
+    fn from(node: &'a PredicateType) -> Self {
+        NodeRef::PredicateType(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a QSelf> for NodeRef<'a> {
+    fn from(node: &'a QSelf) -> Self {
+        NodeRef::QSelf(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a RangeLimits> for NodeRef<'a> {
+    fn from(node: &'a RangeLimits) -> Self {
+        NodeRef::RangeLimits(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a Receiver> for NodeRef<'a> {
+    fn from(node: &'a Receiver) -> Self {
+        NodeRef::Receiver(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a ReturnType> for NodeRef<'a> {
+    fn from(node: &'a ReturnType) -> Self {
+        NodeRef::ReturnType(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a Signature> for NodeRef<'a> {
+    fn from(node: &'a Signature) -> Self {
+        NodeRef::Signature(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a Stmt> for NodeRef<'a> {
+    fn from(node: &'a Stmt) -> Self {
+        NodeRef::Stmt(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TraitBound> for NodeRef<'a> {
+    fn from(node: &'a TraitBound) -> Self {
+        NodeRef::TraitBound(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TraitBoundModifier> for NodeRef<'a> {
+    fn from(node: &'a TraitBoundModifier) -> Self {
+        NodeRef::TraitBoundModifier(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a TraitItem> for NodeRef<'a> {
+    fn from(node: &'a TraitItem) -> Self {
+        NodeRef::TraitItem(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a TraitItemConst> for NodeRef<'a> {
+    fn from(node: &'a TraitItemConst) -> Self {
+        NodeRef::TraitItemConst(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a TraitItemMacro> for NodeRef<'a> {
+    fn from(node: &'a TraitItemMacro) -> Self {
+        NodeRef::TraitItemMacro(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a TraitItemMethod> for NodeRef<'a> {
+    fn from(node: &'a TraitItemMethod) -> Self {
+        NodeRef::TraitItemMethod(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a TraitItemType> for NodeRef<'a> {
+    fn from(node: &'a TraitItemType) -> Self {
+        NodeRef::TraitItemType(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Type> for NodeRef<'a> {
+    fn from(node: &'a Type) -> Self {
+        NodeRef::Type(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeArray> for NodeRef<'a> {
+    fn from(node: &'a TypeArray) -> Self {
+        NodeRef::TypeArray(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeBareFn> for NodeRef<'a> {
+    fn from(node: &'a TypeBareFn) -> Self {
+        NodeRef::TypeBareFn(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeGroup> for NodeRef<'a> {
+    fn from(node: &'a TypeGroup) -> Self {
+        NodeRef::TypeGroup(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeImplTrait> for NodeRef<'a> {
+    fn from(node: &'a TypeImplTrait) -> Self {
+        NodeRef::TypeImplTrait(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeInfer> for NodeRef<'a> {
+    fn from(node: &'a TypeInfer) -> Self {
+        NodeRef::TypeInfer(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeMacro> for NodeRef<'a> {
+    fn from(node: &'a TypeMacro) -> Self {
+        NodeRef::TypeMacro(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeNever> for NodeRef<'a> {
+    fn from(node: &'a TypeNever) -> Self {
+        NodeRef::TypeNever(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeParam> for NodeRef<'a> {
+    fn from(node: &'a TypeParam) -> Self {
+        NodeRef::TypeParam(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeParamBound> for NodeRef<'a> {
+    fn from(node: &'a TypeParamBound) -> Self {
+        NodeRef::TypeParamBound(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeParen> for NodeRef<'a> {
+    fn from(node: &'a TypeParen) -> Self {
+        NodeRef::TypeParen(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypePath> for NodeRef<'a> {
+    fn from(node: &'a TypePath) -> Self {
+        NodeRef::TypePath(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypePtr> for NodeRef<'a> {
+    fn from(node: &'a TypePtr) -> Self {
+        NodeRef::TypePtr(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeReference> for NodeRef<'a> {
+    fn from(node: &'a TypeReference) -> Self {
+        NodeRef::TypeReference(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeSlice> for NodeRef<'a> {
+    fn from(node: &'a TypeSlice) -> Self {
+        NodeRef::TypeSlice(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeTraitObject> for NodeRef<'a> {
+    fn from(node: &'a TypeTraitObject) -> Self {
+        NodeRef::TypeTraitObject(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a TypeTuple> for NodeRef<'a> {
+    fn from(node: &'a TypeTuple) -> Self {
+        NodeRef::TypeTuple(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a UnOp> for NodeRef<'a> {
+    fn from(node: &'a UnOp) -> Self {
+        NodeRef::UnOp(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a UseGlob> for NodeRef<'a> {
+    fn from(node: &'a UseGlob) -> Self {
+        NodeRef::UseGlob(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a UseGroup> for NodeRef<'a> {
+    fn from(node: &'a UseGroup) -> Self {
+        NodeRef::UseGroup(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a UseName> for NodeRef<'a> {
+    fn from(node: &'a UseName) -> Self {
+        NodeRef::UseName(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a UsePath> for NodeRef<'a> {
+    fn from(node: &'a UsePath) -> Self {
+        NodeRef::UsePath(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a UseRename> for NodeRef<'a> {
+    fn from(node: &'a UseRename) -> Self {
+        NodeRef::UseRename(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a UseTree> for NodeRef<'a> {
+    fn from(node: &'a UseTree) -> Self {
+        NodeRef::UseTree(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a Variadic> for NodeRef<'a> {
+    fn from(node: &'a Variadic) -> Self {
+        NodeRef::Variadic(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Variant> for NodeRef<'a> {
+    fn from(node: &'a Variant) -> Self {
+        NodeRef::Variant(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a VisCrate> for NodeRef<'a> {
+    fn from(node: &'a VisCrate) -> Self {
+        NodeRef::VisCrate(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a VisPublic> for NodeRef<'a> {
+    fn from(node: &'a VisPublic) -> Self {
+        NodeRef::VisPublic(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a VisRestricted> for NodeRef<'a> {
+    fn from(node: &'a VisRestricted) -> Self {
+        NodeRef::VisRestricted(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a Visibility> for NodeRef<'a> {
+    fn from(node: &'a Visibility) -> Self {
+        NodeRef::Visibility(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a WhereClause> for NodeRef<'a> {
+    fn from(node: &'a WhereClause) -> Self {
+        NodeRef::WhereClause(node)
+    }
+}
+#[cfg(feature = "visit")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a WherePredicate> for NodeRef<'a> {
+    fn from(node: &'a WherePredicate) -> Self {
+        NodeRef::WherePredicate(node)
+    }
+}
+#[cfg(all(feature = "visit", feature = "printing"))]
+impl<'a> ToTokens for NodeRef<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Abi(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::AngleBracketedGenericArguments(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::Arm(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::AttrStyle(node) => {
+                if let AttrStyle::Inner(bang) = node {
+                    bang.to_tokens(tokens);
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Attribute(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::BareFnArg(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::BinOp(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Binding(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::Block(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::BoundLifetimes(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ConstParam(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Constraint(node) => node.to_tokens(tokens),
+            #[cfg(feature = "derive")]
+            NodeRef::Data(node) => match node {
+                Data::Struct(data) => {
+                    data.struct_token.to_tokens(tokens);
+                    data.fields.to_tokens(tokens);
+                    data.semi_token.to_tokens(tokens);
+                }
+                Data::Enum(data) => {
+                    data.enum_token.to_tokens(tokens);
+                    data.brace_token.surround(tokens, |tokens| {
+                        data.variants.to_tokens(tokens);
+                    });
+                }
+                Data::Union(data) => {
+                    data.union_token.to_tokens(tokens);
+                    data.fields.to_tokens(tokens);
+                }
+            },
+            #[cfg(feature = "derive")]
+            NodeRef::DataEnum(node) => {
+                node.enum_token.to_tokens(tokens);
+                node.brace_token.surround(tokens, |tokens| {
+                    node.variants.to_tokens(tokens);
+                });
+            }
+            #[cfg(feature = "derive")]
+            NodeRef::DataStruct(node) => {
+                node.struct_token.to_tokens(tokens);
+                node.fields.to_tokens(tokens);
+                node.semi_token.to_tokens(tokens);
+            }
+            #[cfg(feature = "derive")]
+            NodeRef::DataUnion(node) => {
+                node.union_token.to_tokens(tokens);
+                node.fields.to_tokens(tokens);
+            }
+            #[cfg(feature = "derive")]
+            NodeRef::DeriveInput(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Expr(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprArray(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprAssign(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprAssignOp(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprAsync(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprAwait(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprBinary(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprBlock(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprBox(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprBreak(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprCall(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprCast(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprClosure(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprContinue(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprField(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprForLoop(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprGroup(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprIf(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprIndex(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprLet(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprLit(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprLoop(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprMatch(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprMethodCall(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprParen(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprPath(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprRange(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprReference(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprRepeat(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprReturn(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprStruct(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprTry(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprTryBlock(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprTuple(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprType(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ExprUnary(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprUnsafe(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprWhile(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ExprYield(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Field(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::FieldPat(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::FieldValue(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Fields(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::FieldsNamed(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::FieldsUnnamed(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::File(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::FnArg(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItem(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemFn(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemStatic(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ForeignItemType(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::GenericArgument(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::GenericMethodArgument(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::GenericParam(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Generics(node) => node.to_tokens(tokens),
+            NodeRef::Ident(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ImplItem(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemConst(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemMethod(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ImplItemType(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Index(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::Item(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemConst(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemEnum(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemExternCrate(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemFn(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemForeignMod(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemImpl(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemMacro2(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemMod(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemStatic(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemStruct(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemTrait(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemTraitAlias(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemType(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemUnion(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::ItemUse(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::Label(node) => node.to_tokens(tokens),
+            NodeRef::Lifetime(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LifetimeDef(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Lit(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitBool(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitByte(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitByteStr(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitChar(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitFloat(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitInt(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::LitStr(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::Local(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Macro(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MacroDelimiter(node) => match node {
+                MacroDelimiter::Paren(paren) => paren.surround(tokens, |_| {}),
+                MacroDelimiter::Brace(brace) => brace.surround(tokens, |_| {}),
+                MacroDelimiter::Bracket(bracket) => bracket.surround(tokens, |_| {}),
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Member(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Meta(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaExpr(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaExprList(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaExprNameValue(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaList(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaNameValue(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::MetaValue(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::MethodTurbofish(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::NestedMeta(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::NestedMetaExpr(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ParenthesizedGenericArguments(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::Pat(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatBox(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatIdent(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatLit(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatOr(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatPath(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatRange(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatReference(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatRest(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatSlice(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatStruct(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatTuple(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatTupleStruct(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatType(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::PatWild(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Path(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PathArguments(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PathSegment(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PredicateEq(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PredicateLifetime(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::PredicateType(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::QSelf(node) => {
+                node.lt_token.to_tokens(tokens);
+                node.ty.to_tokens(tokens);
+                node.as_token.to_tokens(tokens);
+                node.gt_token.to_tokens(tokens);
+            }
+            #[cfg(feature = "full")]
+            NodeRef::RangeLimits(node) => match node {
+                RangeLimits::HalfOpen(dot2) => dot2.to_tokens(tokens),
+                RangeLimits::Closed(dot2_eq) => dot2_eq.to_tokens(tokens),
+            },
+            #[cfg(feature = "full")]
+            NodeRef::Receiver(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::ReturnType(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::Signature(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::Stmt(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TraitBound(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TraitBoundModifier(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::TraitItem(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemConst(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemMethod(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::TraitItemType(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Type(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeArray(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeBareFn(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeGroup(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeImplTrait(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeInfer(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeMacro(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeNever(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeParam(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeParamBound(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeParen(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypePath(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypePtr(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeReference(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeSlice(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeTraitObject(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::TypeTuple(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::UnOp(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::UseGlob(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::UseGroup(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::UseName(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::UsePath(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::UseRename(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::UseTree(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeRef::Variadic(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Variant(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::VisCrate(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::VisPublic(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::VisRestricted(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::Visibility(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::WhereClause(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeRef::WherePredicate(node) => node.to_tokens(tokens),
+            NodeRef::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+///An iterator over the children of a [`NodeRef`].
+///
+///*This type is available if Syn is built with the `"visit"` feature.*
+#[cfg(feature = "visit")]
+pub struct Children<'a> {
+    iter: vec::IntoIter<NodeRef<'a>>,
+}
+#[cfg(feature = "visit")]
+impl<'a> Iterator for Children<'a> {
+    type Item = NodeRef<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+#[cfg(feature = "visit")]
+impl<'a> DoubleEndedIterator for Children<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+#[cfg(feature = "visit")]
+impl<'a> ExactSizeIterator for Children<'a> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+///An exclusive reference to a syntax tree node of any type.
+///
+///*This type is available if Syn is built with the `"visit-mut"` feature.*
+#[cfg(feature = "visit-mut")]
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub enum NodeMut<'a> {
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Abi(&'a mut Abi),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AngleBracketedGenericArguments(&'a mut AngleBracketedGenericArguments),
+    #[cfg(feature = "full")]
+    Arm(&'a mut Arm),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AttrStyle(&'a mut AttrStyle),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Attribute(&'a mut Attribute),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BareFnArg(&'a mut BareFnArg),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BinOp(&'a mut BinOp),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Binding(&'a mut Binding),
+    #[cfg(feature = "full")]
+    Block(&'a mut Block),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BoundLifetimes(&'a mut BoundLifetimes),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ConstParam(&'a mut ConstParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Constraint(&'a mut Constraint),
+    #[cfg(feature = "derive")]
+    Data(&'a mut Data),
+    #[cfg(feature = "derive")]
+    DataEnum(&'a mut DataEnum),
+    #[cfg(feature = "derive")]
+    DataStruct(&'a mut DataStruct),
+    #[cfg(feature = "derive")]
+    DataUnion(&'a mut DataUnion),
+    #[cfg(feature = "derive")]
+    DeriveInput(&'a mut DeriveInput),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Expr(&'a mut Expr),
+    #[cfg(feature = "full")]
+    ExprArray(&'a mut ExprArray),
+    #[cfg(feature = "full")]
+    ExprAssign(&'a mut ExprAssign),
+    #[cfg(feature = "full")]
+    ExprAssignOp(&'a mut ExprAssignOp),
+    #[cfg(feature = "full")]
+    ExprAsync(&'a mut ExprAsync),
+    #[cfg(feature = "full")]
+    ExprAwait(&'a mut ExprAwait),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprBinary(&'a mut ExprBinary),
+    #[cfg(feature = "full")]
+    ExprBlock(&'a mut ExprBlock),
+    #[cfg(feature = "full")]
+    ExprBox(&'a mut ExprBox),
+    #[cfg(feature = "full")]
+    ExprBreak(&'a mut ExprBreak),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprCall(&'a mut ExprCall),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprCast(&'a mut ExprCast),
+    #[cfg(feature = "full")]
+    ExprClosure(&'a mut ExprClosure),
+    #[cfg(feature = "full")]
+    ExprContinue(&'a mut ExprContinue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprField(&'a mut ExprField),
+    #[cfg(feature = "full")]
+    ExprForLoop(&'a mut ExprForLoop),
+    #[cfg(feature = "full")]
+    ExprGroup(&'a mut ExprGroup),
+    #[cfg(feature = "full")]
+    ExprIf(&'a mut ExprIf),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprIndex(&'a mut ExprIndex),
+    #[cfg(feature = "full")]
+    ExprLet(&'a mut ExprLet),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprLit(&'a mut ExprLit),
+    #[cfg(feature = "full")]
+    ExprLoop(&'a mut ExprLoop),
+    #[cfg(feature = "full")]
+    ExprMacro(&'a mut ExprMacro),
+    #[cfg(feature = "full")]
+    ExprMatch(&'a mut ExprMatch),
+    #[cfg(feature = "full")]
+    ExprMethodCall(&'a mut ExprMethodCall),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprParen(&'a mut ExprParen),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprPath(&'a mut ExprPath),
+    #[cfg(feature = "full")]
+    ExprRange(&'a mut ExprRange),
+    #[cfg(feature = "full")]
+    ExprReference(&'a mut ExprReference),
+    #[cfg(feature = "full")]
+    ExprRepeat(&'a mut ExprRepeat),
+    #[cfg(feature = "full")]
+    ExprReturn(&'a mut ExprReturn),
+    #[cfg(feature = "full")]
+    ExprStruct(&'a mut ExprStruct),
+    #[cfg(feature = "full")]
+    ExprTry(&'a mut ExprTry),
+    #[cfg(feature = "full")]
+    ExprTryBlock(&'a mut ExprTryBlock),
+    #[cfg(feature = "full")]
+    ExprTuple(&'a mut ExprTuple),
+    #[cfg(feature = "full")]
+    ExprType(&'a mut ExprType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprUnary(&'a mut ExprUnary),
+    #[cfg(feature = "full")]
+    ExprUnsafe(&'a mut ExprUnsafe),
+    #[cfg(feature = "full")]
+    ExprWhile(&'a mut ExprWhile),
+    #[cfg(feature = "full")]
+    ExprYield(&'a mut ExprYield),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Field(&'a mut Field),
+    #[cfg(feature = "full")]
+    FieldPat(&'a mut FieldPat),
+    #[cfg(feature = "full")]
+    FieldValue(&'a mut FieldValue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Fields(&'a mut Fields),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    FieldsNamed(&'a mut FieldsNamed),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    FieldsUnnamed(&'a mut FieldsUnnamed),
+    #[cfg(feature = "full")]
+    File(&'a mut File),
+    #[cfg(feature = "full")]
+    FnArg(&'a mut FnArg),
+    #[cfg(feature = "full")]
+    ForeignItem(&'a mut ForeignItem),
+    #[cfg(feature = "full")]
+    ForeignItemFn(&'a mut ForeignItemFn),
+    #[cfg(feature = "full")]
+    ForeignItemMacro(&'a mut ForeignItemMacro),
+    #[cfg(feature = "full")]
+    ForeignItemStatic(&'a mut ForeignItemStatic),
+    #[cfg(feature = "full")]
+    ForeignItemType(&'a mut ForeignItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    GenericArgument(&'a mut GenericArgument),
+    #[cfg(feature = "full")]
+    GenericMethodArgument(&'a mut GenericMethodArgument),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    GenericParam(&'a mut GenericParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Generics(&'a mut Generics),
+    Ident(&'a mut Ident),
+    #[cfg(feature = "full")]
+    ImplItem(&'a mut ImplItem),
+    #[cfg(feature = "full")]
+    ImplItemConst(&'a mut ImplItemConst),
+    #[cfg(feature = "full")]
+    ImplItemMacro(&'a mut ImplItemMacro),
+    #[cfg(feature = "full")]
+    ImplItemMethod(&'a mut ImplItemMethod),
+    #[cfg(feature = "full")]
+    ImplItemType(&'a mut ImplItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Index(&'a mut Index),
+    #[cfg(feature = "full")]
+    Item(&'a mut Item),
+    #[cfg(feature = "full")]
+    ItemConst(&'a mut ItemConst),
+    #[cfg(feature = "full")]
+    ItemEnum(&'a mut ItemEnum),
+    #[cfg(feature = "full")]
+    ItemExternCrate(&'a mut ItemExternCrate),
+    #[cfg(feature = "full")]
+    ItemFn(&'a mut ItemFn),
+    #[cfg(feature = "full")]
+    ItemForeignMod(&'a mut ItemForeignMod),
+    #[cfg(feature = "full")]
+    ItemImpl(&'a mut ItemImpl),
+    #[cfg(feature = "full")]
+    ItemMacro(&'a mut ItemMacro),
+    #[cfg(feature = "full")]
+    ItemMacro2(&'a mut ItemMacro2),
+    #[cfg(feature = "full")]
+    ItemMod(&'a mut ItemMod),
+    #[cfg(feature = "full")]
+    ItemStatic(&'a mut ItemStatic),
+    #[cfg(feature = "full")]
+    ItemStruct(&'a mut ItemStruct),
+    #[cfg(feature = "full")]
+    ItemTrait(&'a mut ItemTrait),
+    #[cfg(feature = "full")]
+    ItemTraitAlias(&'a mut ItemTraitAlias),
+    #[cfg(feature = "full")]
+    ItemType(&'a mut ItemType),
+    #[cfg(feature = "full")]
+    ItemUnion(&'a mut ItemUnion),
+    #[cfg(feature = "full")]
+    ItemUse(&'a mut ItemUse),
+    #[cfg(feature = "full")]
+    Label(&'a mut Label),
+    Lifetime(&'a mut Lifetime),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LifetimeDef(&'a mut LifetimeDef),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Lit(&'a mut Lit),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitBool(&'a mut LitBool),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitByte(&'a mut LitByte),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitByteStr(&'a mut LitByteStr),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitChar(&'a mut LitChar),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitFloat(&'a mut LitFloat),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitInt(&'a mut LitInt),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitStr(&'a mut LitStr),
+    #[cfg(feature = "full")]
+    Local(&'a mut Local),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Macro(&'a mut Macro),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MacroDelimiter(&'a mut MacroDelimiter),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Member(&'a mut Member),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Meta(&'a mut Meta),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaExpr(&'a mut MetaExpr),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaExprList(&'a mut MetaExprList),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaExprNameValue(&'a mut MetaExprNameValue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaList(&'a mut MetaList),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaNameValue(&'a mut MetaNameValue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaValue(&'a mut MetaValue),
+    #[cfg(feature = "full")]
+    MethodTurbofish(&'a mut MethodTurbofish),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    NestedMeta(&'a mut NestedMeta),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    NestedMetaExpr(&'a mut NestedMetaExpr),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ParenthesizedGenericArguments(&'a mut ParenthesizedGenericArguments),
+    #[cfg(feature = "full")]
+    Pat(&'a mut Pat),
+    #[cfg(feature = "full")]
+    PatBox(&'a mut PatBox),
+    #[cfg(feature = "full")]
+    PatIdent(&'a mut PatIdent),
+    #[cfg(feature = "full")]
+    PatLit(&'a mut PatLit),
+    #[cfg(feature = "full")]
+    PatMacro(&'a mut PatMacro),
+    #[cfg(feature = "full")]
+    PatOr(&'a mut PatOr),
+    #[cfg(feature = "full")]
+    PatPath(&'a mut PatPath),
+    #[cfg(feature = "full")]
+    PatRange(&'a mut PatRange),
+    #[cfg(feature = "full")]
+    PatReference(&'a mut PatReference),
+    #[cfg(feature = "full")]
+    PatRest(&'a mut PatRest),
+    #[cfg(feature = "full")]
+    PatSlice(&'a mut PatSlice),
+    #[cfg(feature = "full")]
+    PatStruct(&'a mut PatStruct),
+    #[cfg(feature = "full")]
+    PatTuple(&'a mut PatTuple),
+    #[cfg(feature = "full")]
+    PatTupleStruct(&'a mut PatTupleStruct),
+    #[cfg(feature = "full")]
+    PatType(&'a mut PatType),
+    #[cfg(feature = "full")]
+    PatWild(&'a mut PatWild),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Path(&'a mut Path),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PathArguments(&'a mut PathArguments),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PathSegment(&'a mut PathSegment),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateEq(&'a mut PredicateEq),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateLifetime(&'a mut PredicateLifetime),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateType(&'a mut PredicateType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    QSelf(&'a mut QSelf),
+    #[cfg(feature = "full")]
+    RangeLimits(&'a mut RangeLimits),
+    #[cfg(feature = "full")]
+    Receiver(&'a mut Receiver),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ReturnType(&'a mut ReturnType),
+    #[cfg(feature = "full")]
+    Signature(&'a mut Signature),
+    #[cfg(feature = "full")]
+    Stmt(&'a mut Stmt),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TraitBound(&'a mut TraitBound),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TraitBoundModifier(&'a mut TraitBoundModifier),
+    #[cfg(feature = "full")]
+    TraitItem(&'a mut TraitItem),
+    #[cfg(feature = "full")]
+    TraitItemConst(&'a mut TraitItemConst),
+    #[cfg(feature = "full")]
+    TraitItemMacro(&'a mut TraitItemMacro),
+    #[cfg(feature = "full")]
+    TraitItemMethod(&'a mut TraitItemMethod),
+    #[cfg(feature = "full")]
+    TraitItemType(&'a mut TraitItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Type(&'a mut Type),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeArray(&'a mut TypeArray),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeBareFn(&'a mut TypeBareFn),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeGroup(&'a mut TypeGroup),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeImplTrait(&'a mut TypeImplTrait),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeInfer(&'a mut TypeInfer),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeMacro(&'a mut TypeMacro),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeNever(&'a mut TypeNever),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParam(&'a mut TypeParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParamBound(&'a mut TypeParamBound),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParen(&'a mut TypeParen),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypePath(&'a mut TypePath),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypePtr(&'a mut TypePtr),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeReference(&'a mut TypeReference),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeSlice(&'a mut TypeSlice),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeTraitObject(&'a mut TypeTraitObject),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeTuple(&'a mut TypeTuple),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    UnOp(&'a mut UnOp),
+    #[cfg(feature = "full")]
+    UseGlob(&'a mut UseGlob),
+    #[cfg(feature = "full")]
+    UseGroup(&'a mut UseGroup),
+    #[cfg(feature = "full")]
+    UseName(&'a mut UseName),
+    #[cfg(feature = "full")]
+    UsePath(&'a mut UsePath),
+    #[cfg(feature = "full")]
+    UseRename(&'a mut UseRename),
+    #[cfg(feature = "full")]
+    UseTree(&'a mut UseTree),
+    #[cfg(feature = "full")]
+    Variadic(&'a mut Variadic),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Variant(&'a mut Variant),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisCrate(&'a mut VisCrate),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisPublic(&'a mut VisPublic),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisRestricted(&'a mut VisRestricted),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Visibility(&'a mut Visibility),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    WhereClause(&'a mut WhereClause),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    WherePredicate(&'a mut WherePredicate),
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+#[cfg(feature = "visit-mut")]
+impl<'a> NodeMut<'a> {
+    /// The name of the syntax tree type of this node, like
+    /// `"ExprCall"`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Abi(_) => "Abi",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::AngleBracketedGenericArguments(_) => "AngleBracketedGenericArguments",
+            #[cfg(feature = "full")]
+            NodeMut::Arm(_) => "Arm",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::AttrStyle(_) => "AttrStyle",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Attribute(_) => "Attribute",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::BareFnArg(_) => "BareFnArg",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::BinOp(_) => "BinOp",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Binding(_) => "Binding",
+            #[cfg(feature = "full")]
+            NodeMut::Block(_) => "Block",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::BoundLifetimes(_) => "BoundLifetimes",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ConstParam(_) => "ConstParam",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Constraint(_) => "Constraint",
+            #[cfg(feature = "derive")]
+            NodeMut::Data(_) => "Data",
+            #[cfg(feature = "derive")]
+            NodeMut::DataEnum(_) => "DataEnum",
+            #[cfg(feature = "derive")]
+            NodeMut::DataStruct(_) => "DataStruct",
+            #[cfg(feature = "derive")]
+            NodeMut::DataUnion(_) => "DataUnion",
+            #[cfg(feature = "derive")]
+            NodeMut::DeriveInput(_) => "DeriveInput",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Expr(_) => "Expr",
+            #[cfg(feature = "full")]
+            NodeMut::ExprArray(_) => "ExprArray",
+            #[cfg(feature = "full")]
+            NodeMut::ExprAssign(_) => "ExprAssign",
+            #[cfg(feature = "full")]
+            NodeMut::ExprAssignOp(_) => "ExprAssignOp",
+            #[cfg(feature = "full")]
+            NodeMut::ExprAsync(_) => "ExprAsync",
+            #[cfg(feature = "full")]
+            NodeMut::ExprAwait(_) => "ExprAwait",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprBinary(_) => "ExprBinary",
+            #[cfg(feature = "full")]
+            NodeMut::ExprBlock(_) => "ExprBlock",
+            #[cfg(feature = "full")]
+            NodeMut::ExprBox(_) => "ExprBox",
+            #[cfg(feature = "full")]
+            NodeMut::ExprBreak(_) => "ExprBreak",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprCall(_) => "ExprCall",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprCast(_) => "ExprCast",
+            #[cfg(feature = "full")]
+            NodeMut::ExprClosure(_) => "ExprClosure",
+            #[cfg(feature = "full")]
+            NodeMut::ExprContinue(_) => "ExprContinue",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprField(_) => "ExprField",
+            #[cfg(feature = "full")]
+            NodeMut::ExprForLoop(_) => "ExprForLoop",
+            #[cfg(feature = "full")]
+            NodeMut::ExprGroup(_) => "ExprGroup",
+            #[cfg(feature = "full")]
+            NodeMut::ExprIf(_) => "ExprIf",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprIndex(_) => "ExprIndex",
+            #[cfg(feature = "full")]
+            NodeMut::ExprLet(_) => "ExprLet",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprLit(_) => "ExprLit",
+            #[cfg(feature = "full")]
+            NodeMut::ExprLoop(_) => "ExprLoop",
+            #[cfg(feature = "full")]
+            NodeMut::ExprMacro(_) => "ExprMacro",
+            #[cfg(feature = "full")]
+            NodeMut::ExprMatch(_) => "ExprMatch",
+            #[cfg(feature = "full")]
+            NodeMut::ExprMethodCall(_) => "ExprMethodCall",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprParen(_) => "ExprParen",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprPath(_) => "ExprPath",
+            #[cfg(feature = "full")]
+            NodeMut::ExprRange(_) => "ExprRange",
+            #[cfg(feature = "full")]
+            NodeMut::ExprReference(_) => "ExprReference",
+            #[cfg(feature = "full")]
+            NodeMut::ExprRepeat(_) => "ExprRepeat",
+            #[cfg(feature = "full")]
+            NodeMut::ExprReturn(_) => "ExprReturn",
+            #[cfg(feature = "full")]
+            NodeMut::ExprStruct(_) => "ExprStruct",
+            #[cfg(feature = "full")]
+            NodeMut::ExprTry(_) => "ExprTry",
+            #[cfg(feature = "full")]
+            NodeMut::ExprTryBlock(_) => "ExprTryBlock",
+            #[cfg(feature = "full")]
+            NodeMut::ExprTuple(_) => "ExprTuple",
+            #[cfg(feature = "full")]
+            NodeMut::ExprType(_) => "ExprType",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprUnary(_) => "ExprUnary",
+            #[cfg(feature = "full")]
+            NodeMut::ExprUnsafe(_) => "ExprUnsafe",
+            #[cfg(feature = "full")]
+            NodeMut::ExprWhile(_) => "ExprWhile",
+            #[cfg(feature = "full")]
+            NodeMut::ExprYield(_) => "ExprYield",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Field(_) => "Field",
+            #[cfg(feature = "full")]
+            NodeMut::FieldPat(_) => "FieldPat",
+            #[cfg(feature = "full")]
+            NodeMut::FieldValue(_) => "FieldValue",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Fields(_) => "Fields",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::FieldsNamed(_) => "FieldsNamed",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::FieldsUnnamed(_) => "FieldsUnnamed",
+            #[cfg(feature = "full")]
+            NodeMut::File(_) => "File",
+            #[cfg(feature = "full")]
+            NodeMut::FnArg(_) => "FnArg",
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItem(_) => "ForeignItem",
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemFn(_) => "ForeignItemFn",
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemMacro(_) => "ForeignItemMacro",
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemStatic(_) => "ForeignItemStatic",
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemType(_) => "ForeignItemType",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::GenericArgument(_) => "GenericArgument",
+            #[cfg(feature = "full")]
+            NodeMut::GenericMethodArgument(_) => "GenericMethodArgument",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::GenericParam(_) => "GenericParam",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Generics(_) => "Generics",
+            NodeMut::Ident(_) => "Ident",
+            #[cfg(feature = "full")]
+            NodeMut::ImplItem(_) => "ImplItem",
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemConst(_) => "ImplItemConst",
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemMacro(_) => "ImplItemMacro",
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemMethod(_) => "ImplItemMethod",
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemType(_) => "ImplItemType",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Index(_) => "Index",
+            #[cfg(feature = "full")]
+            NodeMut::Item(_) => "Item",
+            #[cfg(feature = "full")]
+            NodeMut::ItemConst(_) => "ItemConst",
+            #[cfg(feature = "full")]
+            NodeMut::ItemEnum(_) => "ItemEnum",
+            #[cfg(feature = "full")]
+            NodeMut::ItemExternCrate(_) => "ItemExternCrate",
+            #[cfg(feature = "full")]
+            NodeMut::ItemFn(_) => "ItemFn",
+            #[cfg(feature = "full")]
+            NodeMut::ItemForeignMod(_) => "ItemForeignMod",
+            #[cfg(feature = "full")]
+            NodeMut::ItemImpl(_) => "ItemImpl",
+            #[cfg(feature = "full")]
+            NodeMut::ItemMacro(_) => "ItemMacro",
+            #[cfg(feature = "full")]
+            NodeMut::ItemMacro2(_) => "ItemMacro2",
+            #[cfg(feature = "full")]
+            NodeMut::ItemMod(_) => "ItemMod",
+            #[cfg(feature = "full")]
+            NodeMut::ItemStatic(_) => "ItemStatic",
+            #[cfg(feature = "full")]
+            NodeMut::ItemStruct(_) => "ItemStruct",
+            #[cfg(feature = "full")]
+            NodeMut::ItemTrait(_) => "ItemTrait",
+            #[cfg(feature = "full")]
+            NodeMut::ItemTraitAlias(_) => "ItemTraitAlias",
+            #[cfg(feature = "full")]
+            NodeMut::ItemType(_) => "ItemType",
+            #[cfg(feature = "full")]
+            NodeMut::ItemUnion(_) => "ItemUnion",
+            #[cfg(feature = "full")]
+            NodeMut::ItemUse(_) => "ItemUse",
+            #[cfg(feature = "full")]
+            NodeMut::Label(_) => "Label",
+            NodeMut::Lifetime(_) => "Lifetime",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LifetimeDef(_) => "LifetimeDef",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Lit(_) => "Lit",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitBool(_) => "LitBool",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitByte(_) => "LitByte",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitByteStr(_) => "LitByteStr",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitChar(_) => "LitChar",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitFloat(_) => "LitFloat",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitInt(_) => "LitInt",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitStr(_) => "LitStr",
+            #[cfg(feature = "full")]
+            NodeMut::Local(_) => "Local",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Macro(_) => "Macro",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MacroDelimiter(_) => "MacroDelimiter",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Member(_) => "Member",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Meta(_) => "Meta",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaExpr(_) => "MetaExpr",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaExprList(_) => "MetaExprList",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaExprNameValue(_) => "MetaExprNameValue",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaList(_) => "MetaList",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaNameValue(_) => "MetaNameValue",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaValue(_) => "MetaValue",
+            #[cfg(feature = "full")]
+            NodeMut::MethodTurbofish(_) => "MethodTurbofish",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::NestedMeta(_) => "NestedMeta",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::NestedMetaExpr(_) => "NestedMetaExpr",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ParenthesizedGenericArguments(_) => "ParenthesizedGenericArguments",
+            #[cfg(feature = "full")]
+            NodeMut::Pat(_) => "Pat",
+            #[cfg(feature = "full")]
+            NodeMut::PatBox(_) => "PatBox",
+            #[cfg(feature = "full")]
+            NodeMut::PatIdent(_) => "PatIdent",
+            #[cfg(feature = "full")]
+            NodeMut::PatLit(_) => "PatLit",
+            #[cfg(feature = "full")]
+            NodeMut::PatMacro(_) => "PatMacro",
+            #[cfg(feature = "full")]
+            NodeMut::PatOr(_) => "PatOr",
+            #[cfg(feature = "full")]
+            NodeMut::PatPath(_) => "PatPath",
+            #[cfg(feature = "full")]
+            NodeMut::PatRange(_) => "PatRange",
+            #[cfg(feature = "full")]
+            NodeMut::PatReference(_) => "PatReference",
+            #[cfg(feature = "full")]
+            NodeMut::PatRest(_) => "PatRest",
+            #[cfg(feature = "full")]
+            NodeMut::PatSlice(_) => "PatSlice",
+            #[cfg(feature = "full")]
+            NodeMut::PatStruct(_) => "PatStruct",
+            #[cfg(feature = "full")]
+            NodeMut::PatTuple(_) => "PatTuple",
+            #[cfg(feature = "full")]
+            NodeMut::PatTupleStruct(_) => "PatTupleStruct",
+            #[cfg(feature = "full")]
+            NodeMut::PatType(_) => "PatType",
+            #[cfg(feature = "full")]
+            NodeMut::PatWild(_) => "PatWild",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Path(_) => "Path",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PathArguments(_) => "PathArguments",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PathSegment(_) => "PathSegment",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PredicateEq(_) => "PredicateEq",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PredicateLifetime(_) => "PredicateLifetime",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PredicateType(_) => "PredicateType",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::QSelf(_) => "QSelf",
+            #[cfg(feature = "full")]
+            NodeMut::RangeLimits(_) => "RangeLimits",
+            #[cfg(feature = "full")]
+            NodeMut::Receiver(_) => "Receiver",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ReturnType(_) => "ReturnType",
+            #[cfg(feature = "full")]
+            NodeMut::Signature(_) => "Signature",
+            #[cfg(feature = "full")]
+            NodeMut::Stmt(_) => "Stmt",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TraitBound(_) => "TraitBound",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TraitBoundModifier(_) => "TraitBoundModifier",
+            #[cfg(feature = "full")]
+            NodeMut::TraitItem(_) => "TraitItem",
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemConst(_) => "TraitItemConst",
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemMacro(_) => "TraitItemMacro",
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemMethod(_) => "TraitItemMethod",
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemType(_) => "TraitItemType",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Type(_) => "Type",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeArray(_) => "TypeArray",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeBareFn(_) => "TypeBareFn",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeGroup(_) => "TypeGroup",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeImplTrait(_) => "TypeImplTrait",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeInfer(_) => "TypeInfer",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeMacro(_) => "TypeMacro",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeNever(_) => "TypeNever",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeParam(_) => "TypeParam",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeParamBound(_) => "TypeParamBound",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeParen(_) => "TypeParen",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypePath(_) => "TypePath",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypePtr(_) => "TypePtr",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeReference(_) => "TypeReference",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeSlice(_) => "TypeSlice",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeTraitObject(_) => "TypeTraitObject",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeTuple(_) => "TypeTuple",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::UnOp(_) => "UnOp",
+            #[cfg(feature = "full")]
+            NodeMut::UseGlob(_) => "UseGlob",
+            #[cfg(feature = "full")]
+            NodeMut::UseGroup(_) => "UseGroup",
+            #[cfg(feature = "full")]
+            NodeMut::UseName(_) => "UseName",
+            #[cfg(feature = "full")]
+            NodeMut::UsePath(_) => "UsePath",
+            #[cfg(feature = "full")]
+            NodeMut::UseRename(_) => "UseRename",
+            #[cfg(feature = "full")]
+            NodeMut::UseTree(_) => "UseTree",
+            #[cfg(feature = "full")]
+            NodeMut::Variadic(_) => "Variadic",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Variant(_) => "Variant",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::VisCrate(_) => "VisCrate",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::VisPublic(_) => "VisPublic",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::VisRestricted(_) => "VisRestricted",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Visibility(_) => "Visibility",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::WhereClause(_) => "WhereClause",
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::WherePredicate(_) => "WherePredicate",
+            NodeMut::__Nonexhaustive => unreachable!(),
+        }
+    }
+    ///The nodes directly contained in this one, in source order.
+    pub fn children(self) -> ChildrenMut<'a> {
+        let mut children = Vec::new();
+        match self {
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Abi(node) => {
+                if let Some(it) = &mut node.name {
+                    children.push(NodeMut::LitStr(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::AngleBracketedGenericArguments(node) => {
+                for it in &mut node.args {
+                    children.push(NodeMut::GenericArgument(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::Arm(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Pat(&mut node.pat));
+                if let Some(it) = &mut node.guard {
+                    children.push(NodeMut::Expr(&mut *(it).1));
+                }
+                children.push(NodeMut::Expr(&mut *node.body));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Attribute(node) => {
+                children.push(NodeMut::AttrStyle(&mut node.style));
+                children.push(NodeMut::Path(&mut node.path));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::BareFnArg(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.name {
+                    children.push(NodeMut::Ident(&mut (it).0));
+                }
+                children.push(NodeMut::Type(&mut node.ty));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Binding(node) => {
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Type(&mut node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::Block(node) => {
+                for it in &mut node.stmts {
+                    children.push(NodeMut::Stmt(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::BoundLifetimes(node) => {
+                for it in &mut node.lifetimes {
+                    children.push(NodeMut::LifetimeDef(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ConstParam(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Type(&mut node.ty));
+                if let Some(it) = &mut node.default {
+                    children.push(NodeMut::Expr(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Constraint(node) => {
+                children.push(NodeMut::Ident(&mut node.ident));
+                for it in &mut node.bounds {
+                    children.push(NodeMut::TypeParamBound(it));
+                }
+            }
+            #[cfg(feature = "derive")]
+            NodeMut::Data(node) => match node {
+                Data::Struct(_binding_0) => {
+                    children.push(NodeMut::DataStruct(_binding_0));
+                }
+                Data::Enum(_binding_0) => {
+                    children.push(NodeMut::DataEnum(_binding_0));
+                }
+                Data::Union(_binding_0) => {
+                    children.push(NodeMut::DataUnion(_binding_0));
+                }
+            },
+            #[cfg(feature = "derive")]
+            NodeMut::DataEnum(node) => {
+                for it in &mut node.variants {
+                    children.push(NodeMut::Variant(it));
+                }
+            }
+            #[cfg(feature = "derive")]
+            NodeMut::DataStruct(node) => {
+                children.push(NodeMut::Fields(&mut node.fields));
+            }
+            #[cfg(feature = "derive")]
+            NodeMut::DataUnion(node) => {
+                children.push(NodeMut::FieldsNamed(&mut node.fields));
+            }
+            #[cfg(feature = "derive")]
+            NodeMut::DeriveInput(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Generics(&mut node.generics));
+                children.push(NodeMut::Data(&mut node.data));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Expr(node) => match node {
+                Expr::Array(_binding_0) => {
+                    full!(children.push(NodeMut::ExprArray(_binding_0)));
+                }
+                Expr::Assign(_binding_0) => {
+                    full!(children.push(NodeMut::ExprAssign(_binding_0)));
+                }
+                Expr::AssignOp(_binding_0) => {
+                    full!(children.push(NodeMut::ExprAssignOp(_binding_0)));
+                }
+                Expr::Async(_binding_0) => {
+                    full!(children.push(NodeMut::ExprAsync(_binding_0)));
+                }
+                Expr::Await(_binding_0) => {
+                    full!(children.push(NodeMut::ExprAwait(_binding_0)));
+                }
+                Expr::Binary(_binding_0) => {
+                    children.push(NodeMut::ExprBinary(_binding_0));
+                }
+                Expr::Block(_binding_0) => {
+                    full!(children.push(NodeMut::ExprBlock(_binding_0)));
+                }
+                Expr::Box(_binding_0) => {
+                    full!(children.push(NodeMut::ExprBox(_binding_0)));
+                }
+                Expr::Break(_binding_0) => {
+                    full!(children.push(NodeMut::ExprBreak(_binding_0)));
+                }
+                Expr::Call(_binding_0) => {
+                    children.push(NodeMut::ExprCall(_binding_0));
+                }
+                Expr::Cast(_binding_0) => {
+                    children.push(NodeMut::ExprCast(_binding_0));
+                }
+                Expr::Closure(_binding_0) => {
+                    full!(children.push(NodeMut::ExprClosure(_binding_0)));
+                }
+                Expr::Continue(_binding_0) => {
+                    full!(children.push(NodeMut::ExprContinue(_binding_0)));
+                }
+                Expr::Field(_binding_0) => {
+                    children.push(NodeMut::ExprField(_binding_0));
+                }
+                Expr::ForLoop(_binding_0) => {
+                    full!(children.push(NodeMut::ExprForLoop(_binding_0)));
+                }
+                Expr::Group(_binding_0) => {
+                    full!(children.push(NodeMut::ExprGroup(_binding_0)));
+                }
+                Expr::If(_binding_0) => {
+                    full!(children.push(NodeMut::ExprIf(_binding_0)));
+                }
+                Expr::Index(_binding_0) => {
+                    children.push(NodeMut::ExprIndex(_binding_0));
+                }
+                Expr::Let(_binding_0) => {
+                    full!(children.push(NodeMut::ExprLet(_binding_0)));
+                }
+                Expr::Lit(_binding_0) => {
+                    children.push(NodeMut::ExprLit(_binding_0));
+                }
+                Expr::Loop(_binding_0) => {
+                    full!(children.push(NodeMut::ExprLoop(_binding_0)));
+                }
+                Expr::Macro(_binding_0) => {
+                    full!(children.push(NodeMut::ExprMacro(_binding_0)));
+                }
+                Expr::Match(_binding_0) => {
+                    full!(children.push(NodeMut::ExprMatch(_binding_0)));
+                }
+                Expr::MethodCall(_binding_0) => {
+                    full!(children.push(NodeMut::ExprMethodCall(_binding_0)));
+                }
+                Expr::Paren(_binding_0) => {
+                    children.push(NodeMut::ExprParen(_binding_0));
+                }
+                Expr::Path(_binding_0) => {
+                    children.push(NodeMut::ExprPath(_binding_0));
+                }
+                Expr::Range(_binding_0) => {
+                    full!(children.push(NodeMut::ExprRange(_binding_0)));
+                }
+                Expr::Reference(_binding_0) => {
+                    full!(children.push(NodeMut::ExprReference(_binding_0)));
+                }
+                Expr::Repeat(_binding_0) => {
+                    full!(children.push(NodeMut::ExprRepeat(_binding_0)));
+                }
+                Expr::Return(_binding_0) => {
+                    full!(children.push(NodeMut::ExprReturn(_binding_0)));
+                }
+                Expr::Struct(_binding_0) => {
+                    full!(children.push(NodeMut::ExprStruct(_binding_0)));
+                }
+                Expr::Try(_binding_0) => {
+                    full!(children.push(NodeMut::ExprTry(_binding_0)));
+                }
+                Expr::TryBlock(_binding_0) => {
+                    full!(children.push(NodeMut::ExprTryBlock(_binding_0)));
+                }
+                Expr::Tuple(_binding_0) => {
+                    full!(children.push(NodeMut::ExprTuple(_binding_0)));
+                }
+                Expr::Type(_binding_0) => {
+                    full!(children.push(NodeMut::ExprType(_binding_0)));
+                }
+                Expr::Unary(_binding_0) => {
+                    children.push(NodeMut::ExprUnary(_binding_0));
+                }
+                Expr::Unsafe(_binding_0) => {
+                    full!(children.push(NodeMut::ExprUnsafe(_binding_0)));
+                }
+                Expr::While(_binding_0) => {
+                    full!(children.push(NodeMut::ExprWhile(_binding_0)));
+                }
+                Expr::Yield(_binding_0) => {
+                    full!(children.push(NodeMut::ExprYield(_binding_0)));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeMut::ExprArray(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                for it in &mut node.elems {
+                    children.push(NodeMut::Expr(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprAssign(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.left));
+                children.push(NodeMut::Expr(&mut *node.right));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprAssignOp(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.left));
+                children.push(NodeMut::BinOp(&mut node.op));
+                children.push(NodeMut::Expr(&mut *node.right));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprAsync(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Block(&mut node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprAwait(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.base));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprBinary(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.left));
+                children.push(NodeMut::BinOp(&mut node.op));
+                children.push(NodeMut::Expr(&mut *node.right));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprBlock(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.label {
+                    children.push(NodeMut::Label(it));
+                }
+                children.push(NodeMut::Block(&mut node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprBox(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprBreak(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.label {
+                    children.push(NodeMut::Lifetime(it));
+                }
+                if let Some(it) = &mut node.expr {
+                    children.push(NodeMut::Expr(&mut **it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprCall(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.func));
+                for it in &mut node.args {
+                    children.push(NodeMut::Expr(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprCast(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.expr));
+                children.push(NodeMut::Type(&mut *node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprClosure(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                for it in &mut node.inputs {
+                    children.push(NodeMut::Pat(it));
+                }
+                children.push(NodeMut::ReturnType(&mut node.output));
+                children.push(NodeMut::Expr(&mut *node.body));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprContinue(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.label {
+                    children.push(NodeMut::Lifetime(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprField(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.base));
+                children.push(NodeMut::Member(&mut node.member));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprForLoop(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.label {
+                    children.push(NodeMut::Label(it));
+                }
+                children.push(NodeMut::Pat(&mut node.pat));
+                children.push(NodeMut::Expr(&mut *node.expr));
+                children.push(NodeMut::Block(&mut node.body));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprGroup(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprIf(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.cond));
+                children.push(NodeMut::Block(&mut node.then_branch));
+                if let Some(it) = &mut node.else_branch {
+                    children.push(NodeMut::Expr(&mut *(it).1));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprIndex(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.expr));
+                children.push(NodeMut::Expr(&mut *node.index));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprLet(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Pat(&mut node.pat));
+                children.push(NodeMut::Expr(&mut *node.expr));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprLit(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Lit(&mut node.lit));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprLoop(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.label {
+                    children.push(NodeMut::Label(it));
+                }
+                children.push(NodeMut::Block(&mut node.body));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprMacro(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Macro(&mut node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprMatch(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.expr));
+                for it in &mut node.arms {
+                    children.push(NodeMut::Arm(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprMethodCall(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.receiver));
+                children.push(NodeMut::Ident(&mut node.method));
+                if let Some(it) = &mut node.turbofish {
+                    children.push(NodeMut::MethodTurbofish(it));
+                }
+                for it in &mut node.args {
+                    children.push(NodeMut::Expr(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprParen(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.expr));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprPath(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.qself {
+                    children.push(NodeMut::QSelf(it));
+                }
+                children.push(NodeMut::Path(&mut node.path));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprRange(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.from {
+                    children.push(NodeMut::Expr(&mut **it));
+                }
+                children.push(NodeMut::RangeLimits(&mut node.limits));
+                if let Some(it) = &mut node.to {
+                    children.push(NodeMut::Expr(&mut **it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprReference(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprRepeat(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.expr));
+                children.push(NodeMut::Expr(&mut *node.len));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprReturn(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.expr {
+                    children.push(NodeMut::Expr(&mut **it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprStruct(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Path(&mut node.path));
+                for it in &mut node.fields {
+                    children.push(NodeMut::FieldValue(it));
+                }
+                if let Some(it) = &mut node.rest {
+                    children.push(NodeMut::Expr(&mut **it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprTry(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprTryBlock(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Block(&mut node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprTuple(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                for it in &mut node.elems {
+                    children.push(NodeMut::Expr(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprType(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.expr));
+                children.push(NodeMut::Type(&mut *node.ty));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprUnary(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::UnOp(&mut node.op));
+                children.push(NodeMut::Expr(&mut *node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprUnsafe(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Block(&mut node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprWhile(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.label {
+                    children.push(NodeMut::Label(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.cond));
+                children.push(NodeMut::Block(&mut node.body));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ExprYield(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.expr {
+                    children.push(NodeMut::Expr(&mut **it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Field(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                if let Some(it) = &mut node.ident {
+                    children.push(NodeMut::Ident(it));
+                }
+                children.push(NodeMut::Type(&mut node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::FieldPat(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Member(&mut node.member));
+                children.push(NodeMut::Pat(&mut *node.pat));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::FieldValue(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Member(&mut node.member));
+                children.push(NodeMut::Expr(&mut node.expr));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Fields(node) => match node {
+                Fields::Named(_binding_0) => {
+                    children.push(NodeMut::FieldsNamed(_binding_0));
+                }
+                Fields::Unnamed(_binding_0) => {
+                    children.push(NodeMut::FieldsUnnamed(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::FieldsNamed(node) => {
+                for it in &mut node.named {
+                    children.push(NodeMut::Field(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::FieldsUnnamed(node) => {
+                for it in &mut node.unnamed {
+                    children.push(NodeMut::Field(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::File(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                for it in &mut node.items {
+                    children.push(NodeMut::Item(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::FnArg(node) => match node {
+                FnArg::Receiver(_binding_0) => {
+                    children.push(NodeMut::Receiver(_binding_0));
+                }
+                FnArg::Typed(_binding_0) => {
+                    children.push(NodeMut::PatType(_binding_0));
+                }
+            },
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItem(node) => match node {
+                ForeignItem::Fn(_binding_0) => {
+                    children.push(NodeMut::ForeignItemFn(_binding_0));
+                }
+                ForeignItem::Static(_binding_0) => {
+                    children.push(NodeMut::ForeignItemStatic(_binding_0));
+                }
+                ForeignItem::Type(_binding_0) => {
+                    children.push(NodeMut::ForeignItemType(_binding_0));
+                }
+                ForeignItem::Macro(_binding_0) => {
+                    children.push(NodeMut::ForeignItemMacro(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemFn(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Signature(&mut node.sig));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemMacro(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Macro(&mut node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemStatic(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Type(&mut *node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemType(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::GenericArgument(node) => match node {
+                GenericArgument::Lifetime(_binding_0) => {
+                    children.push(NodeMut::Lifetime(_binding_0));
+                }
+                GenericArgument::Type(_binding_0) => {
+                    children.push(NodeMut::Type(_binding_0));
+                }
+                GenericArgument::Binding(_binding_0) => {
+                    children.push(NodeMut::Binding(_binding_0));
+                }
+                GenericArgument::Constraint(_binding_0) => {
+                    children.push(NodeMut::Constraint(_binding_0));
+                }
+                GenericArgument::Const(_binding_0) => {
+                    children.push(NodeMut::Expr(_binding_0));
+                }
+            },
+            #[cfg(feature = "full")]
+            NodeMut::GenericMethodArgument(node) => match node {
+                GenericMethodArgument::Type(_binding_0) => {
+                    children.push(NodeMut::Type(_binding_0));
+                }
+                GenericMethodArgument::Const(_binding_0) => {
+                    children.push(NodeMut::Expr(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::GenericParam(node) => match node {
+                GenericParam::Type(_binding_0) => {
+                    children.push(NodeMut::TypeParam(_binding_0));
+                }
+                GenericParam::Lifetime(_binding_0) => {
+                    children.push(NodeMut::LifetimeDef(_binding_0));
+                }
+                GenericParam::Const(_binding_0) => {
+                    children.push(NodeMut::ConstParam(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Generics(node) => {
+                for it in &mut node.params {
+                    children.push(NodeMut::GenericParam(it));
+                }
+                if let Some(it) = &mut node.where_clause {
+                    children.push(NodeMut::WhereClause(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ImplItem(node) => match node {
+                ImplItem::Const(_binding_0) => {
+                    children.push(NodeMut::ImplItemConst(_binding_0));
+                }
+                ImplItem::Method(_binding_0) => {
+                    children.push(NodeMut::ImplItemMethod(_binding_0));
+                }
+                ImplItem::Type(_binding_0) => {
+                    children.push(NodeMut::ImplItemType(_binding_0));
+                }
+                ImplItem::Macro(_binding_0) => {
+                    children.push(NodeMut::ImplItemMacro(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemConst(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Type(&mut node.ty));
+                children.push(NodeMut::Expr(&mut node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemMacro(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Macro(&mut node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemMethod(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Signature(&mut node.sig));
+                children.push(NodeMut::Block(&mut node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemType(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Generics(&mut node.generics));
+                children.push(NodeMut::Type(&mut node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::Item(node) => match node {
+                Item::Const(_binding_0) => {
+                    children.push(NodeMut::ItemConst(_binding_0));
+                }
+                Item::Enum(_binding_0) => {
+                    children.push(NodeMut::ItemEnum(_binding_0));
+                }
+                Item::ExternCrate(_binding_0) => {
+                    children.push(NodeMut::ItemExternCrate(_binding_0));
+                }
+                Item::Fn(_binding_0) => {
+                    children.push(NodeMut::ItemFn(_binding_0));
+                }
+                Item::ForeignMod(_binding_0) => {
+                    children.push(NodeMut::ItemForeignMod(_binding_0));
+                }
+                Item::Impl(_binding_0) => {
+                    children.push(NodeMut::ItemImpl(_binding_0));
+                }
+                Item::Macro(_binding_0) => {
+                    children.push(NodeMut::ItemMacro(_binding_0));
+                }
+                Item::Macro2(_binding_0) => {
+                    children.push(NodeMut::ItemMacro2(_binding_0));
+                }
+                Item::Mod(_binding_0) => {
+                    children.push(NodeMut::ItemMod(_binding_0));
+                }
+                Item::Static(_binding_0) => {
+                    children.push(NodeMut::ItemStatic(_binding_0));
+                }
+                Item::Struct(_binding_0) => {
+                    children.push(NodeMut::ItemStruct(_binding_0));
+                }
+                Item::Trait(_binding_0) => {
+                    children.push(NodeMut::ItemTrait(_binding_0));
+                }
+                Item::TraitAlias(_binding_0) => {
+                    children.push(NodeMut::ItemTraitAlias(_binding_0));
+                }
+                Item::Type(_binding_0) => {
+                    children.push(NodeMut::ItemType(_binding_0));
+                }
+                Item::Union(_binding_0) => {
+                    children.push(NodeMut::ItemUnion(_binding_0));
+                }
+                Item::Use(_binding_0) => {
+                    children.push(NodeMut::ItemUse(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeMut::ItemConst(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Type(&mut *node.ty));
+                children.push(NodeMut::Expr(&mut *node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemEnum(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Generics(&mut node.generics));
+                for it in &mut node.variants {
+                    children.push(NodeMut::Variant(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemExternCrate(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                if let Some(it) = &mut node.rename {
+                    children.push(NodeMut::Ident(&mut (it).1));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemFn(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Signature(&mut node.sig));
+                children.push(NodeMut::Block(&mut *node.block));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemForeignMod(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Abi(&mut node.abi));
+                for it in &mut node.items {
+                    children.push(NodeMut::ForeignItem(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemImpl(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Generics(&mut node.generics));
+                if let Some(it) = &mut node.trait_ {
+                    children.push(NodeMut::Path(&mut (it).1));
+                }
+                children.push(NodeMut::Type(&mut *node.self_ty));
+                for it in &mut node.items {
+                    children.push(NodeMut::ImplItem(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemMacro(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.ident {
+                    children.push(NodeMut::Ident(it));
+                }
+                children.push(NodeMut::Macro(&mut node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemMacro2(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemMod(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                if let Some(it) = &mut node.content {
+                    for it in &mut (it).1 {
+                        children.push(NodeMut::Item(it));
+                    }
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemStatic(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Type(&mut *node.ty));
+                children.push(NodeMut::Expr(&mut *node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemStruct(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Generics(&mut node.generics));
+                children.push(NodeMut::Fields(&mut node.fields));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemTrait(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Generics(&mut node.generics));
+                for it in &mut node.supertraits {
+                    children.push(NodeMut::TypeParamBound(it));
+                }
+                for it in &mut node.items {
+                    children.push(NodeMut::TraitItem(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemTraitAlias(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Generics(&mut node.generics));
+                for it in &mut node.bounds {
+                    children.push(NodeMut::TypeParamBound(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemType(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Generics(&mut node.generics));
+                children.push(NodeMut::Type(&mut *node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemUnion(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Generics(&mut node.generics));
+                children.push(NodeMut::FieldsNamed(&mut node.fields));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::ItemUse(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Visibility(&mut node.vis));
+                children.push(NodeMut::UseTree(&mut node.tree));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::Label(node) => {
+                children.push(NodeMut::Lifetime(&mut node.name));
+            }
+            NodeMut::Lifetime(node) => {
+                children.push(NodeMut::Ident(&mut node.ident));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LifetimeDef(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Lifetime(&mut node.lifetime));
+                for it in &mut node.bounds {
+                    children.push(NodeMut::Lifetime(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Lit(node) => match node {
+                Lit::Str(_binding_0) => {
+                    children.push(NodeMut::LitStr(_binding_0));
+                }
+                Lit::ByteStr(_binding_0) => {
+                    children.push(NodeMut::LitByteStr(_binding_0));
+                }
+                Lit::Byte(_binding_0) => {
+                    children.push(NodeMut::LitByte(_binding_0));
+                }
+                Lit::Char(_binding_0) => {
+                    children.push(NodeMut::LitChar(_binding_0));
+                }
+                Lit::Int(_binding_0) => {
+                    children.push(NodeMut::LitInt(_binding_0));
+                }
+                Lit::Float(_binding_0) => {
+                    children.push(NodeMut::LitFloat(_binding_0));
+                }
+                Lit::Bool(_binding_0) => {
+                    children.push(NodeMut::LitBool(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeMut::Local(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Pat(&mut node.pat));
+                if let Some(it) = &mut node.init {
+                    children.push(NodeMut::Expr(&mut *(it).1));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Macro(node) => {
+                children.push(NodeMut::Path(&mut node.path));
+                children.push(NodeMut::MacroDelimiter(&mut node.delimiter));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Member(node) => match node {
+                Member::Named(_binding_0) => {
+                    children.push(NodeMut::Ident(_binding_0));
+                }
+                Member::Unnamed(_binding_0) => {
+                    children.push(NodeMut::Index(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Meta(node) => match node {
+                Meta::Path(_binding_0) => {
+                    children.push(NodeMut::Path(_binding_0));
+                }
+                Meta::List(_binding_0) => {
+                    children.push(NodeMut::MetaList(_binding_0));
+                }
+                Meta::NameValue(_binding_0) => {
+                    children.push(NodeMut::MetaNameValue(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaExpr(node) => match node {
+                MetaExpr::Path(_binding_0) => {
+                    children.push(NodeMut::Path(_binding_0));
+                }
+                MetaExpr::List(_binding_0) => {
+                    children.push(NodeMut::MetaExprList(_binding_0));
+                }
+                MetaExpr::NameValue(_binding_0) => {
+                    children.push(NodeMut::MetaExprNameValue(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaExprList(node) => {
+                children.push(NodeMut::Path(&mut node.path));
+                for it in &mut node.nested {
+                    children.push(NodeMut::NestedMetaExpr(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaExprNameValue(node) => {
+                children.push(NodeMut::Path(&mut node.path));
+                children.push(NodeMut::MetaValue(&mut node.value));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaList(node) => {
+                children.push(NodeMut::Path(&mut node.path));
+                for it in &mut node.nested {
+                    children.push(NodeMut::NestedMeta(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaNameValue(node) => {
+                children.push(NodeMut::Path(&mut node.path));
+                children.push(NodeMut::Lit(&mut node.lit));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaValue(node) => match node {
+                MetaValue::Expr(_binding_0) => {
+                    children.push(NodeMut::Expr(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeMut::MethodTurbofish(node) => {
+                for it in &mut node.args {
+                    children.push(NodeMut::GenericMethodArgument(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::NestedMeta(node) => match node {
+                NestedMeta::Meta(_binding_0) => {
+                    children.push(NodeMut::Meta(_binding_0));
+                }
+                NestedMeta::Lit(_binding_0) => {
+                    children.push(NodeMut::Lit(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::NestedMetaExpr(node) => match node {
+                NestedMetaExpr::Meta(_binding_0) => {
+                    children.push(NodeMut::MetaExpr(_binding_0));
+                }
+                NestedMetaExpr::Lit(_binding_0) => {
+                    children.push(NodeMut::Lit(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ParenthesizedGenericArguments(node) => {
+                for it in &mut node.inputs {
+                    children.push(NodeMut::Type(it));
+                }
+                children.push(NodeMut::ReturnType(&mut node.output));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::Pat(node) => match node {
+                Pat::Box(_binding_0) => {
+                    children.push(NodeMut::PatBox(_binding_0));
+                }
+                Pat::Ident(_binding_0) => {
+                    children.push(NodeMut::PatIdent(_binding_0));
+                }
+                Pat::Lit(_binding_0) => {
+                    children.push(NodeMut::PatLit(_binding_0));
+                }
+                Pat::Macro(_binding_0) => {
+                    children.push(NodeMut::PatMacro(_binding_0));
+                }
+                Pat::Or(_binding_0) => {
+                    children.push(NodeMut::PatOr(_binding_0));
+                }
+                Pat::Path(_binding_0) => {
+                    children.push(NodeMut::PatPath(_binding_0));
+                }
+                Pat::Range(_binding_0) => {
+                    children.push(NodeMut::PatRange(_binding_0));
+                }
+                Pat::Reference(_binding_0) => {
+                    children.push(NodeMut::PatReference(_binding_0));
+                }
+                Pat::Rest(_binding_0) => {
+                    children.push(NodeMut::PatRest(_binding_0));
+                }
+                Pat::Slice(_binding_0) => {
+                    children.push(NodeMut::PatSlice(_binding_0));
+                }
+                Pat::Struct(_binding_0) => {
+                    children.push(NodeMut::PatStruct(_binding_0));
+                }
+                Pat::Tuple(_binding_0) => {
+                    children.push(NodeMut::PatTuple(_binding_0));
+                }
+                Pat::TupleStruct(_binding_0) => {
+                    children.push(NodeMut::PatTupleStruct(_binding_0));
+                }
+                Pat::Type(_binding_0) => {
+                    children.push(NodeMut::PatType(_binding_0));
+                }
+                Pat::Wild(_binding_0) => {
+                    children.push(NodeMut::PatWild(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeMut::PatBox(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Pat(&mut *node.pat));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatIdent(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Ident(&mut node.ident));
+                if let Some(it) = &mut node.subpat {
+                    children.push(NodeMut::Pat(&mut *(it).1));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatLit(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.expr));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatMacro(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Macro(&mut node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatOr(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                for it in &mut node.cases {
+                    children.push(NodeMut::Pat(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatPath(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.qself {
+                    children.push(NodeMut::QSelf(it));
+                }
+                children.push(NodeMut::Path(&mut node.path));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatRange(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Expr(&mut *node.lo));
+                children.push(NodeMut::RangeLimits(&mut node.limits));
+                children.push(NodeMut::Expr(&mut *node.hi));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatReference(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Pat(&mut *node.pat));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatRest(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatSlice(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                for it in &mut node.elems {
+                    children.push(NodeMut::Pat(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatStruct(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Path(&mut node.path));
+                for it in &mut node.fields {
+                    children.push(NodeMut::FieldPat(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatTuple(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                for it in &mut node.elems {
+                    children.push(NodeMut::Pat(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatTupleStruct(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Path(&mut node.path));
+                children.push(NodeMut::PatTuple(&mut node.pat));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatType(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Pat(&mut *node.pat));
+                children.push(NodeMut::Type(&mut *node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::PatWild(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Path(node) => {
+                for it in &mut node.segments {
+                    children.push(NodeMut::PathSegment(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PathArguments(node) => match node {
+                PathArguments::AngleBracketed(_binding_0) => {
+                    children.push(NodeMut::AngleBracketedGenericArguments(_binding_0));
+                }
+                PathArguments::Parenthesized(_binding_0) => {
+                    children.push(NodeMut::ParenthesizedGenericArguments(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PathSegment(node) => {
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::PathArguments(&mut node.arguments));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PredicateEq(node) => {
+                children.push(NodeMut::Type(&mut node.lhs_ty));
+                children.push(NodeMut::Type(&mut node.rhs_ty));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PredicateLifetime(node) => {
+                children.push(NodeMut::Lifetime(&mut node.lifetime));
+                for it in &mut node.bounds {
+                    children.push(NodeMut::Lifetime(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PredicateType(node) => {
+                if let Some(it) = &mut node.lifetimes {
+                    children.push(NodeMut::BoundLifetimes(it));
+                }
+                children.push(NodeMut::Type(&mut node.bounded_ty));
+                for it in &mut node.bounds {
+                    children.push(NodeMut::TypeParamBound(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::QSelf(node) => {
+                children.push(NodeMut::Type(&mut *node.ty));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::Receiver(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                if let Some(it) = &mut node.reference {
+                    if let Some(it) = &mut (it).1 {
+                        children.push(NodeMut::Lifetime(it));
+                    }
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ReturnType(node) => match node {
+                ReturnType::Type(_binding_0, _binding_1) => {
+                    children.push(NodeMut::Type(&mut **_binding_1));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeMut::Signature(node) => {
+                if let Some(it) = &mut node.abi {
+                    children.push(NodeMut::Abi(it));
+                }
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Generics(&mut node.generics));
+                for it in &mut node.inputs {
+                    children.push(NodeMut::FnArg(it));
+                }
+                if let Some(it) = &mut node.variadic {
+                    children.push(NodeMut::Variadic(it));
+                }
+                children.push(NodeMut::ReturnType(&mut node.output));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::Stmt(node) => match node {
+                Stmt::Local(_binding_0) => {
+                    children.push(NodeMut::Local(_binding_0));
+                }
+                Stmt::Item(_binding_0) => {
+                    children.push(NodeMut::Item(_binding_0));
+                }
+                Stmt::Expr(_binding_0) => {
+                    children.push(NodeMut::Expr(_binding_0));
+                }
+                Stmt::Semi(_binding_0, _binding_1) => {
+                    children.push(NodeMut::Expr(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TraitBound(node) => {
+                children.push(NodeMut::TraitBoundModifier(&mut node.modifier));
+                if let Some(it) = &mut node.lifetimes {
+                    children.push(NodeMut::BoundLifetimes(it));
+                }
+                children.push(NodeMut::Path(&mut node.path));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::TraitItem(node) => match node {
+                TraitItem::Const(_binding_0) => {
+                    children.push(NodeMut::TraitItemConst(_binding_0));
+                }
+                TraitItem::Method(_binding_0) => {
+                    children.push(NodeMut::TraitItemMethod(_binding_0));
+                }
+                TraitItem::Type(_binding_0) => {
+                    children.push(NodeMut::TraitItemType(_binding_0));
+                }
+                TraitItem::Macro(_binding_0) => {
+                    children.push(NodeMut::TraitItemMacro(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemConst(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Type(&mut node.ty));
+                if let Some(it) = &mut node.default {
+                    children.push(NodeMut::Expr(&mut (it).1));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemMacro(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Macro(&mut node.mac));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemMethod(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Signature(&mut node.sig));
+                if let Some(it) = &mut node.default {
+                    children.push(NodeMut::Block(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemType(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Generics(&mut node.generics));
+                for it in &mut node.bounds {
+                    children.push(NodeMut::TypeParamBound(it));
+                }
+                if let Some(it) = &mut node.default {
+                    children.push(NodeMut::Type(&mut (it).1));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Type(node) => match node {
+                Type::Array(_binding_0) => {
+                    children.push(NodeMut::TypeArray(_binding_0));
+                }
+                Type::BareFn(_binding_0) => {
+                    children.push(NodeMut::TypeBareFn(_binding_0));
+                }
+                Type::Group(_binding_0) => {
+                    children.push(NodeMut::TypeGroup(_binding_0));
+                }
+                Type::ImplTrait(_binding_0) => {
+                    children.push(NodeMut::TypeImplTrait(_binding_0));
+                }
+                Type::Infer(_binding_0) => {
+                    children.push(NodeMut::TypeInfer(_binding_0));
+                }
+                Type::Macro(_binding_0) => {
+                    children.push(NodeMut::TypeMacro(_binding_0));
+                }
+                Type::Never(_binding_0) => {
+                    children.push(NodeMut::TypeNever(_binding_0));
+                }
+                Type::Paren(_binding_0) => {
+                    children.push(NodeMut::TypeParen(_binding_0));
+                }
+                Type::Path(_binding_0) => {
+                    children.push(NodeMut::TypePath(_binding_0));
+                }
+                Type::Ptr(_binding_0) => {
+                    children.push(NodeMut::TypePtr(_binding_0));
+                }
+                Type::Reference(_binding_0) => {
+                    children.push(NodeMut::TypeReference(_binding_0));
+                }
+                Type::Slice(_binding_0) => {
+                    children.push(NodeMut::TypeSlice(_binding_0));
+                }
+                Type::TraitObject(_binding_0) => {
+                    children.push(NodeMut::TypeTraitObject(_binding_0));
+                }
+                Type::Tuple(_binding_0) => {
+                    children.push(NodeMut::TypeTuple(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeArray(node) => {
+                children.push(NodeMut::Type(&mut *node.elem));
+                children.push(NodeMut::Expr(&mut node.len));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeBareFn(node) => {
+                if let Some(it) = &mut node.lifetimes {
+                    children.push(NodeMut::BoundLifetimes(it));
+                }
+                if let Some(it) = &mut node.abi {
+                    children.push(NodeMut::Abi(it));
+                }
+                for it in &mut node.inputs {
+                    children.push(NodeMut::BareFnArg(it));
+                }
+                children.push(NodeMut::ReturnType(&mut node.output));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeGroup(node) => {
+                children.push(NodeMut::Type(&mut *node.elem));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeImplTrait(node) => {
+                for it in &mut node.bounds {
+                    children.push(NodeMut::TypeParamBound(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeMacro(node) => {
+                children.push(NodeMut::Macro(&mut node.mac));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeParam(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Ident(&mut node.ident));
+                for it in &mut node.bounds {
+                    children.push(NodeMut::TypeParamBound(it));
+                }
+                if let Some(it) = &mut node.default {
+                    children.push(NodeMut::Type(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeParamBound(node) => match node {
+                TypeParamBound::Trait(_binding_0) => {
+                    children.push(NodeMut::TraitBound(_binding_0));
+                }
+                TypeParamBound::Lifetime(_binding_0) => {
+                    children.push(NodeMut::Lifetime(_binding_0));
+                }
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeParen(node) => {
+                children.push(NodeMut::Type(&mut *node.elem));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypePath(node) => {
+                if let Some(it) = &mut node.qself {
+                    children.push(NodeMut::QSelf(it));
+                }
+                children.push(NodeMut::Path(&mut node.path));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypePtr(node) => {
+                children.push(NodeMut::Type(&mut *node.elem));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeReference(node) => {
+                if let Some(it) = &mut node.lifetime {
+                    children.push(NodeMut::Lifetime(it));
+                }
+                children.push(NodeMut::Type(&mut *node.elem));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeSlice(node) => {
+                children.push(NodeMut::Type(&mut *node.elem));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeTraitObject(node) => {
+                for it in &mut node.bounds {
+                    children.push(NodeMut::TypeParamBound(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeTuple(node) => {
+                for it in &mut node.elems {
+                    children.push(NodeMut::Type(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::UseGroup(node) => {
+                for it in &mut node.items {
+                    children.push(NodeMut::UseTree(it));
+                }
+            }
+            #[cfg(feature = "full")]
+            NodeMut::UseName(node) => {
+                children.push(NodeMut::Ident(&mut node.ident));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::UsePath(node) => {
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::UseTree(&mut *node.tree));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::UseRename(node) => {
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Ident(&mut node.rename));
+            }
+            #[cfg(feature = "full")]
+            NodeMut::UseTree(node) => match node {
+                UseTree::Path(_binding_0) => {
+                    children.push(NodeMut::UsePath(_binding_0));
+                }
+                UseTree::Name(_binding_0) => {
+                    children.push(NodeMut::UseName(_binding_0));
+                }
+                UseTree::Rename(_binding_0) => {
+                    children.push(NodeMut::UseRename(_binding_0));
+                }
+                UseTree::Glob(_binding_0) => {
+                    children.push(NodeMut::UseGlob(_binding_0));
+                }
+                UseTree::Group(_binding_0) => {
+                    children.push(NodeMut::UseGroup(_binding_0));
+                }
+            },
+            #[cfg(feature = "full")]
+            NodeMut::Variadic(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Variant(node) => {
+                for it in &mut node.attrs {
+                    children.push(NodeMut::Attribute(it));
+                }
+                children.push(NodeMut::Ident(&mut node.ident));
+                children.push(NodeMut::Fields(&mut node.fields));
+                if let Some(it) = &mut node.discriminant {
+                    children.push(NodeMut::Expr(&mut (it).1));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::VisRestricted(node) => {
+                children.push(NodeMut::Path(&mut *node.path));
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Visibility(node) => match node {
+                Visibility::Public(_binding_0) => {
+                    children.push(NodeMut::VisPublic(_binding_0));
+                }
+                Visibility::Crate(_binding_0) => {
+                    children.push(NodeMut::VisCrate(_binding_0));
+                }
+                Visibility::Restricted(_binding_0) => {
+                    children.push(NodeMut::VisRestricted(_binding_0));
+                }
+                _ => {}
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::WhereClause(node) => {
+                for it in &mut node.predicates {
+                    children.push(NodeMut::WherePredicate(it));
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::WherePredicate(node) => match node {
+                WherePredicate::Type(_binding_0) => {
+                    children.push(NodeMut::PredicateType(_binding_0));
+                }
+                WherePredicate::Lifetime(_binding_0) => {
+                    children.push(NodeMut::PredicateLifetime(_binding_0));
+                }
+                WherePredicate::Eq(_binding_0) => {
+                    children.push(NodeMut::PredicateEq(_binding_0));
+                }
+            },
+            _ => {}
+        }
+        ChildrenMut {
+            iter: children.into_iter(),
+        }
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Abi> for NodeMut<'a> {
+    fn from(node: &'a mut Abi) -> Self {
+        NodeMut::Abi(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut AngleBracketedGenericArguments> for NodeMut<'a> {
+    fn from(node: &'a mut AngleBracketedGenericArguments) -> Self {
+        NodeMut::AngleBracketedGenericArguments(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut Arm> for NodeMut<'a> {
+    fn from(node: &'a mut Arm) -> Self {
+        NodeMut::Arm(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut AttrStyle> for NodeMut<'a> {
+    fn from(node: &'a mut AttrStyle) -> Self {
+        NodeMut::AttrStyle(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Attribute> for NodeMut<'a> {
+    fn from(node: &'a mut Attribute) -> Self {
+        NodeMut::Attribute(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut BareFnArg> for NodeMut<'a> {
+    fn from(node: &'a mut BareFnArg) -> Self {
+        NodeMut::BareFnArg(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut BinOp> for NodeMut<'a> {
+    fn from(node: &'a mut BinOp) -> Self {
+        NodeMut::BinOp(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Binding> for NodeMut<'a> {
+    fn from(node: &'a mut Binding) -> Self {
+        NodeMut::Binding(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut Block> for NodeMut<'a> {
+    fn from(node: &'a mut Block) -> Self {
+        NodeMut::Block(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut BoundLifetimes> for NodeMut<'a> {
+    fn from(node: &'a mut BoundLifetimes) -> Self {
+        NodeMut::BoundLifetimes(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ConstParam> for NodeMut<'a> {
+    fn from(node: &'a mut ConstParam) -> Self {
+        NodeMut::ConstParam(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Constraint> for NodeMut<'a> {
+    fn from(node: &'a mut Constraint) -> Self {
+        NodeMut::Constraint(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "derive")]
+impl<'a> From<&'a mut Data> for NodeMut<'a> {
+    fn from(node: &'a mut Data) -> Self {
+        NodeMut::Data(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "derive")]
+impl<'a> From<&'a mut DataEnum> for NodeMut<'a> {
+    fn from(node: &'a mut DataEnum) -> Self {
+        NodeMut::DataEnum(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "derive")]
+impl<'a> From<&'a mut DataStruct> for NodeMut<'a> {
+    fn from(node: &'a mut DataStruct) -> Self {
+        NodeMut::DataStruct(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "derive")]
+impl<'a> From<&'a mut DataUnion> for NodeMut<'a> {
+    fn from(node: &'a mut DataUnion) -> Self {
+        NodeMut::DataUnion(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "derive")]
+impl<'a> From<&'a mut DeriveInput> for NodeMut<'a> {
+    fn from(node: &'a mut DeriveInput) -> Self {
+        NodeMut::DeriveInput(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Expr> for NodeMut<'a> {
+    fn from(node: &'a mut Expr) -> Self {
+        NodeMut::Expr(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprArray> for NodeMut<'a> {
+    fn from(node: &'a mut ExprArray) -> Self {
+        NodeMut::ExprArray(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprAssign> for NodeMut<'a> {
+    fn from(node: &'a mut ExprAssign) -> Self {
+        NodeMut::ExprAssign(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprAssignOp> for NodeMut<'a> {
+    fn from(node: &'a mut ExprAssignOp) -> Self {
+        NodeMut::ExprAssignOp(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprAsync> for NodeMut<'a> {
+    fn from(node: &'a mut ExprAsync) -> Self {
+        NodeMut::ExprAsync(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprAwait> for NodeMut<'a> {
+    fn from(node: &'a mut ExprAwait) -> Self {
+        NodeMut::ExprAwait(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ExprBinary> for NodeMut<'a> {
+    fn from(node: &'a mut ExprBinary) -> Self {
+        NodeMut::ExprBinary(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprBlock> for NodeMut<'a> {
+    fn from(node: &'a mut ExprBlock) -> Self {
+        NodeMut::ExprBlock(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprBox> for NodeMut<'a> {
+    fn from(node: &'a mut ExprBox) -> Self {
+        NodeMut::ExprBox(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprBreak> for NodeMut<'a> {
+    fn from(node: &'a mut ExprBreak) -> Self {
+        NodeMut::ExprBreak(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ExprCall> for NodeMut<'a> {
+    fn from(node: &'a mut ExprCall) -> Self {
+        NodeMut::ExprCall(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ExprCast> for NodeMut<'a> {
+    fn from(node: &'a mut ExprCast) -> Self {
+        NodeMut::ExprCast(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprClosure> for NodeMut<'a> {
+    fn from(node: &'a mut ExprClosure) -> Self {
+        NodeMut::ExprClosure(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprContinue> for NodeMut<'a> {
+    fn from(node: &'a mut ExprContinue) -> Self {
+        NodeMut::ExprContinue(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ExprField> for NodeMut<'a> {
+    fn from(node: &'a mut ExprField) -> Self {
+        NodeMut::ExprField(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprForLoop> for NodeMut<'a> {
+    fn from(node: &'a mut ExprForLoop) -> Self {
+        NodeMut::ExprForLoop(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprGroup> for NodeMut<'a> {
+    fn from(node: &'a mut ExprGroup) -> Self {
+        NodeMut::ExprGroup(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprIf> for NodeMut<'a> {
+    fn from(node: &'a mut ExprIf) -> Self {
+        NodeMut::ExprIf(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ExprIndex> for NodeMut<'a> {
+    fn from(node: &'a mut ExprIndex) -> Self {
+        NodeMut::ExprIndex(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprLet> for NodeMut<'a> {
+    fn from(node: &'a mut ExprLet) -> Self {
+        NodeMut::ExprLet(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ExprLit> for NodeMut<'a> {
+    fn from(node: &'a mut ExprLit) -> Self {
+        NodeMut::ExprLit(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprLoop> for NodeMut<'a> {
+    fn from(node: &'a mut ExprLoop) -> Self {
+        NodeMut::ExprLoop(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprMacro> for NodeMut<'a> {
+    fn from(node: &'a mut ExprMacro) -> Self {
+        NodeMut::ExprMacro(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprMatch> for NodeMut<'a> {
+    fn from(node: &'a mut ExprMatch) -> Self {
+        NodeMut::ExprMatch(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprMethodCall> for NodeMut<'a> {
+    fn from(node: &'a mut ExprMethodCall) -> Self {
+        NodeMut::ExprMethodCall(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ExprParen> for NodeMut<'a> {
+    fn from(node: &'a mut ExprParen) -> Self {
+        NodeMut::ExprParen(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ExprPath> for NodeMut<'a> {
+    fn from(node: &'a mut ExprPath) -> Self {
+        NodeMut::ExprPath(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprRange> for NodeMut<'a> {
+    fn from(node: &'a mut ExprRange) -> Self {
+        NodeMut::ExprRange(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprReference> for NodeMut<'a> {
+    fn from(node: &'a mut ExprReference) -> Self {
+        NodeMut::ExprReference(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprRepeat> for NodeMut<'a> {
+    fn from(node: &'a mut ExprRepeat) -> Self {
+        NodeMut::ExprRepeat(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprReturn> for NodeMut<'a> {
+    fn from(node: &'a mut ExprReturn) -> Self {
+        NodeMut::ExprReturn(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprStruct> for NodeMut<'a> {
+    fn from(node: &'a mut ExprStruct) -> Self {
+        NodeMut::ExprStruct(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprTry> for NodeMut<'a> {
+    fn from(node: &'a mut ExprTry) -> Self {
+        NodeMut::ExprTry(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprTryBlock> for NodeMut<'a> {
+    fn from(node: &'a mut ExprTryBlock) -> Self {
+        NodeMut::ExprTryBlock(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprTuple> for NodeMut<'a> {
+    fn from(node: &'a mut ExprTuple) -> Self {
+        NodeMut::ExprTuple(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprType> for NodeMut<'a> {
+    fn from(node: &'a mut ExprType) -> Self {
+        NodeMut::ExprType(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ExprUnary> for NodeMut<'a> {
+    fn from(node: &'a mut ExprUnary) -> Self {
+        NodeMut::ExprUnary(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprUnsafe> for NodeMut<'a> {
+    fn from(node: &'a mut ExprUnsafe) -> Self {
+        NodeMut::ExprUnsafe(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprWhile> for NodeMut<'a> {
+    fn from(node: &'a mut ExprWhile) -> Self {
+        NodeMut::ExprWhile(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ExprYield> for NodeMut<'a> {
+    fn from(node: &'a mut ExprYield) -> Self {
+        NodeMut::ExprYield(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Field> for NodeMut<'a> {
+    fn from(node: &'a mut Field) -> Self {
+        NodeMut::Field(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut FieldPat> for NodeMut<'a> {
+    fn from(node: &'a mut FieldPat) -> Self {
+        NodeMut::FieldPat(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut FieldValue> for NodeMut<'a> {
+    fn from(node: &'a mut FieldValue) -> Self {
+        NodeMut::FieldValue(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Fields> for NodeMut<'a> {
+    fn from(node: &'a mut Fields) -> Self {
+        NodeMut::Fields(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut FieldsNamed> for NodeMut<'a> {
+    fn from(node: &'a mut FieldsNamed) -> Self {
+        NodeMut::FieldsNamed(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut FieldsUnnamed> for NodeMut<'a> {
+    fn from(node: &'a mut FieldsUnnamed) -> Self {
+        NodeMut::FieldsUnnamed(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut File> for NodeMut<'a> {
+    fn from(node: &'a mut File) -> Self {
+        NodeMut::File(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut FnArg> for NodeMut<'a> {
+    fn from(node: &'a mut FnArg) -> Self {
+        NodeMut::FnArg(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ForeignItem> for NodeMut<'a> {
+    fn from(node: &'a mut ForeignItem) -> Self {
+        NodeMut::ForeignItem(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ForeignItemFn> for NodeMut<'a> {
+    fn from(node: &'a mut ForeignItemFn) -> Self {
+        NodeMut::ForeignItemFn(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ForeignItemMacro> for NodeMut<'a> {
+    fn from(node: &'a mut ForeignItemMacro) -> Self {
+        NodeMut::ForeignItemMacro(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ForeignItemStatic> for NodeMut<'a> {
+    fn from(node: &'a mut ForeignItemStatic) -> Self {
+        NodeMut::ForeignItemStatic(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ForeignItemType> for NodeMut<'a> {
+    fn from(node: &'a mut ForeignItemType) -> Self {
+        NodeMut::ForeignItemType(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut GenericArgument> for NodeMut<'a> {
+    fn from(node: &'a mut GenericArgument) -> Self {
+        NodeMut::GenericArgument(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut GenericMethodArgument> for NodeMut<'a> {
+    fn from(node: &'a mut GenericMethodArgument) -> Self {
+        NodeMut::GenericMethodArgument(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut GenericParam> for NodeMut<'a> {
+    fn from(node: &'a mut GenericParam) -> Self {
+        NodeMut::GenericParam(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Generics> for NodeMut<'a> {
+    fn from(node: &'a mut Generics) -> Self {
+        NodeMut::Generics(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+impl<'a> From<&'a mut Ident> for NodeMut<'a> {
+    fn from(node: &'a mut Ident) -> Self {
+        NodeMut::Ident(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ImplItem> for NodeMut<'a> {
+    fn from(node: &'a mut ImplItem) -> Self {
+        NodeMut::ImplItem(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ImplItemConst> for NodeMut<'a> {
+    fn from(node: &'a mut ImplItemConst) -> Self {
+        NodeMut::ImplItemConst(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ImplItemMacro> for NodeMut<'a> {
+    fn from(node: &'a mut ImplItemMacro) -> Self {
+        NodeMut::ImplItemMacro(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ImplItemMethod> for NodeMut<'a> {
+    fn from(node: &'a mut ImplItemMethod) -> Self {
+        NodeMut::ImplItemMethod(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ImplItemType> for NodeMut<'a> {
+    fn from(node: &'a mut ImplItemType) -> Self {
+        NodeMut::ImplItemType(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Index> for NodeMut<'a> {
+    fn from(node: &'a mut Index) -> Self {
+        NodeMut::Index(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut Item> for NodeMut<'a> {
+    fn from(node: &'a mut Item) -> Self {
+        NodeMut::Item(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemConst> for NodeMut<'a> {
+    fn from(node: &'a mut ItemConst) -> Self {
+        NodeMut::ItemConst(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemEnum> for NodeMut<'a> {
+    fn from(node: &'a mut ItemEnum) -> Self {
+        NodeMut::ItemEnum(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemExternCrate> for NodeMut<'a> {
+    fn from(node: &'a mut ItemExternCrate) -> Self {
+        NodeMut::ItemExternCrate(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemFn> for NodeMut<'a> {
+    fn from(node: &'a mut ItemFn) -> Self {
+        NodeMut::ItemFn(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemForeignMod> for NodeMut<'a> {
+    fn from(node: &'a mut ItemForeignMod) -> Self {
+        NodeMut::ItemForeignMod(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemImpl> for NodeMut<'a> {
+    fn from(node: &'a mut ItemImpl) -> Self {
+        NodeMut::ItemImpl(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemMacro> for NodeMut<'a> {
+    fn from(node: &'a mut ItemMacro) -> Self {
+        NodeMut::ItemMacro(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemMacro2> for NodeMut<'a> {
+    fn from(node: &'a mut ItemMacro2) -> Self {
+        NodeMut::ItemMacro2(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemMod> for NodeMut<'a> {
+    fn from(node: &'a mut ItemMod) -> Self {
+        NodeMut::ItemMod(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemStatic> for NodeMut<'a> {
+    fn from(node: &'a mut ItemStatic) -> Self {
+        NodeMut::ItemStatic(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemStruct> for NodeMut<'a> {
+    fn from(node: &'a mut ItemStruct) -> Self {
+        NodeMut::ItemStruct(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemTrait> for NodeMut<'a> {
+    fn from(node: &'a mut ItemTrait) -> Self {
+        NodeMut::ItemTrait(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemTraitAlias> for NodeMut<'a> {
+    fn from(node: &'a mut ItemTraitAlias) -> Self {
+        NodeMut::ItemTraitAlias(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemType> for NodeMut<'a> {
+    fn from(node: &'a mut ItemType) -> Self {
+        NodeMut::ItemType(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemUnion> for NodeMut<'a> {
+    fn from(node: &'a mut ItemUnion) -> Self {
+        NodeMut::ItemUnion(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut ItemUse> for NodeMut<'a> {
+    fn from(node: &'a mut ItemUse) -> Self {
+        NodeMut::ItemUse(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut Label> for NodeMut<'a> {
+    fn from(node: &'a mut Label) -> Self {
+        NodeMut::Label(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+impl<'a> From<&'a mut Lifetime> for NodeMut<'a> {
+    fn from(node: &'a mut Lifetime) -> Self {
+        NodeMut::Lifetime(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut LifetimeDef> for NodeMut<'a> {
+    fn from(node: &'a mut LifetimeDef) -> Self {
+        NodeMut::LifetimeDef(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Lit> for NodeMut<'a> {
+    fn from(node: &'a mut Lit) -> Self {
+        NodeMut::Lit(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut LitBool> for NodeMut<'a> {
+    fn from(node: &'a mut LitBool) -> Self {
+        NodeMut::LitBool(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut LitByte> for NodeMut<'a> {
+    fn from(node: &'a mut LitByte) -> Self {
+        NodeMut::LitByte(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut LitByteStr> for NodeMut<'a> {
+    fn from(node: &'a mut LitByteStr) -> Self {
+        NodeMut::LitByteStr(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut LitChar> for NodeMut<'a> {
+    fn from(node: &'a mut LitChar) -> Self {
+        NodeMut::LitChar(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut LitFloat> for NodeMut<'a> {
+    fn from(node: &'a mut LitFloat) -> Self {
+        NodeMut::LitFloat(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut LitInt> for NodeMut<'a> {
+    fn from(node: &'a mut LitInt) -> Self {
+        NodeMut::LitInt(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut LitStr> for NodeMut<'a> {
+    fn from(node: &'a mut LitStr) -> Self {
+        NodeMut::LitStr(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut Local> for NodeMut<'a> {
+    fn from(node: &'a mut Local) -> Self {
+        NodeMut::Local(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Macro> for NodeMut<'a> {
+    fn from(node: &'a mut Macro) -> Self {
+        NodeMut::Macro(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut MacroDelimiter> for NodeMut<'a> {
+    fn from(node: &'a mut MacroDelimiter) -> Self {
+        NodeMut::MacroDelimiter(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Member> for NodeMut<'a> {
+    fn from(node: &'a mut Member) -> Self {
+        NodeMut::Member(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Meta> for NodeMut<'a> {
+    fn from(node: &'a mut Meta) -> Self {
+        NodeMut::Meta(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut MetaExpr> for NodeMut<'a> {
+    fn from(node: &'a mut MetaExpr) -> Self {
+        NodeMut::MetaExpr(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut MetaExprList> for NodeMut<'a> {
+    fn from(node: &'a mut MetaExprList) -> Self {
+        NodeMut::MetaExprList(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut MetaExprNameValue> for NodeMut<'a> {
+    fn from(node: &'a mut MetaExprNameValue) -> Self {
+        NodeMut::MetaExprNameValue(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut MetaList> for NodeMut<'a> {
+    fn from(node: &'a mut MetaList) -> Self {
+        NodeMut::MetaList(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut MetaNameValue> for NodeMut<'a> {
+    fn from(node: &'a mut MetaNameValue) -> Self {
+        NodeMut::MetaNameValue(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut MetaValue> for NodeMut<'a> {
+    fn from(node: &'a mut MetaValue) -> Self {
+        NodeMut::MetaValue(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut MethodTurbofish> for NodeMut<'a> {
+    fn from(node: &'a mut MethodTurbofish) -> Self {
+        NodeMut::MethodTurbofish(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut NestedMeta> for NodeMut<'a> {
+    fn from(node: &'a mut NestedMeta) -> Self {
+        NodeMut::NestedMeta(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut NestedMetaExpr> for NodeMut<'a> {
+    fn from(node: &'a mut NestedMetaExpr) -> Self {
+        NodeMut::NestedMetaExpr(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ParenthesizedGenericArguments> for NodeMut<'a> {
+    fn from(node: &'a mut ParenthesizedGenericArguments) -> Self {
+        NodeMut::ParenthesizedGenericArguments(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut Pat> for NodeMut<'a> {
+    fn from(node: &'a mut Pat) -> Self {
+        NodeMut::Pat(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatBox> for NodeMut<'a> {
+    fn from(node: &'a mut PatBox) -> Self {
+        NodeMut::PatBox(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatIdent> for NodeMut<'a> {
+    fn from(node: &'a mut PatIdent) -> Self {
+        NodeMut::PatIdent(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatLit> for NodeMut<'a> {
+    fn from(node: &'a mut PatLit) -> Self {
+        NodeMut::PatLit(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatMacro> for NodeMut<'a> {
+    fn from(node: &'a mut PatMacro) -> Self {
+        NodeMut::PatMacro(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatOr> for NodeMut<'a> {
+    fn from(node: &'a mut PatOr) -> Self {
+        NodeMut::PatOr(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatPath> for NodeMut<'a> {
+    fn from(node: &'a mut PatPath) -> Self {
+        NodeMut::PatPath(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatRange> for NodeMut<'a> {
+    fn from(node: &'a mut PatRange) -> Self {
+        NodeMut::PatRange(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatReference> for NodeMut<'a> {
+    fn from(node: &'a mut PatReference) -> Self {
+        NodeMut::PatReference(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatRest> for NodeMut<'a> {
+    fn from(node: &'a mut PatRest) -> Self {
+        NodeMut::PatRest(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatSlice> for NodeMut<'a> {
+    fn from(node: &'a mut PatSlice) -> Self {
+        NodeMut::PatSlice(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatStruct> for NodeMut<'a> {
+    fn from(node: &'a mut PatStruct) -> Self {
+        NodeMut::PatStruct(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatTuple> for NodeMut<'a> {
+    fn from(node: &'a mut PatTuple) -> Self {
+        NodeMut::PatTuple(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatTupleStruct> for NodeMut<'a> {
+    fn from(node: &'a mut PatTupleStruct) -> Self {
+        NodeMut::PatTupleStruct(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatType> for NodeMut<'a> {
+    fn from(node: &'a mut PatType) -> Self {
+        NodeMut::PatType(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut PatWild> for NodeMut<'a> {
+    fn from(node: &'a mut PatWild) -> Self {
+        NodeMut::PatWild(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Path> for NodeMut<'a> {
+    fn from(node: &'a mut Path) -> Self {
+        NodeMut::Path(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut PathArguments> for NodeMut<'a> {
+    fn from(node: &'a mut PathArguments) -> Self {
+        NodeMut::PathArguments(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut PathSegment> for NodeMut<'a> {
+    fn from(node: &'a mut PathSegment) -> Self {
+        NodeMut::PathSegment(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut PredicateEq> for NodeMut<'a> {
+    fn from(node: &'a mut PredicateEq) -> Self {
+        NodeMut::PredicateEq(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut PredicateLifetime> for NodeMut<'a> {
+    fn from(node: &'a mut PredicateLifetime) -> Self {
+        NodeMut::PredicateLifetime(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut PredicateType> for NodeMut<'a> {
+    fn from(node: &'a mut PredicateType) -> Self {
+        NodeMut::PredicateType(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut QSelf> for NodeMut<'a> {
+    fn from(node: &'a mut QSelf) -> Self {
+        NodeMut::QSelf(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut RangeLimits> for NodeMut<'a> {
+    fn from(node: &'a mut RangeLimits) -> Self {
+        NodeMut::RangeLimits(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut Receiver> for NodeMut<'a> {
+    fn from(node: &'a mut Receiver) -> Self {
+        NodeMut::Receiver(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut ReturnType> for NodeMut<'a> {
+    fn from(node: &'a mut ReturnType) -> Self {
+        NodeMut::ReturnType(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut Signature> for NodeMut<'a> {
+    fn from(node: &'a mut Signature) -> Self {
+        NodeMut::Signature(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut Stmt> for NodeMut<'a> {
+    fn from(node: &'a mut Stmt) -> Self {
+        NodeMut::Stmt(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TraitBound> for NodeMut<'a> {
+    fn from(node: &'a mut TraitBound) -> Self {
+        NodeMut::TraitBound(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TraitBoundModifier> for NodeMut<'a> {
+    fn from(node: &'a mut TraitBoundModifier) -> Self {
+        NodeMut::TraitBoundModifier(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut TraitItem> for NodeMut<'a> {
+    fn from(node: &'a mut TraitItem) -> Self {
+        NodeMut::TraitItem(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut TraitItemConst> for NodeMut<'a> {
+    fn from(node: &'a mut TraitItemConst) -> Self {
+        NodeMut::TraitItemConst(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut TraitItemMacro> for NodeMut<'a> {
+    fn from(node: &'a mut TraitItemMacro) -> Self {
+        NodeMut::TraitItemMacro(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut TraitItemMethod> for NodeMut<'a> {
+    fn from(node: &'a mut TraitItemMethod) -> Self {
+        NodeMut::TraitItemMethod(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut TraitItemType> for NodeMut<'a> {
+    fn from(node: &'a mut TraitItemType) -> Self {
+        NodeMut::TraitItemType(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Type> for NodeMut<'a> {
+    fn from(node: &'a mut Type) -> Self {
+        NodeMut::Type(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeArray> for NodeMut<'a> {
+    fn from(node: &'a mut TypeArray) -> Self {
+        NodeMut::TypeArray(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeBareFn> for NodeMut<'a> {
+    fn from(node: &'a mut TypeBareFn) -> Self {
+        NodeMut::TypeBareFn(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeGroup> for NodeMut<'a> {
+    fn from(node: &'a mut TypeGroup) -> Self {
+        NodeMut::TypeGroup(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeImplTrait> for NodeMut<'a> {
+    fn from(node: &'a mut TypeImplTrait) -> Self {
+        NodeMut::TypeImplTrait(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeInfer> for NodeMut<'a> {
+    fn from(node: &'a mut TypeInfer) -> Self {
+        NodeMut::TypeInfer(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeMacro> for NodeMut<'a> {
+    fn from(node: &'a mut TypeMacro) -> Self {
+        NodeMut::TypeMacro(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeNever> for NodeMut<'a> {
+    fn from(node: &'a mut TypeNever) -> Self {
+        NodeMut::TypeNever(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeParam> for NodeMut<'a> {
+    fn from(node: &'a mut TypeParam) -> Self {
+        NodeMut::TypeParam(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeParamBound> for NodeMut<'a> {
+    fn from(node: &'a mut TypeParamBound) -> Self {
+        NodeMut::TypeParamBound(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeParen> for NodeMut<'a> {
+    fn from(node: &'a mut TypeParen) -> Self {
+        NodeMut::TypeParen(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypePath> for NodeMut<'a> {
+    fn from(node: &'a mut TypePath) -> Self {
+        NodeMut::TypePath(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypePtr> for NodeMut<'a> {
+    fn from(node: &'a mut TypePtr) -> Self {
+        NodeMut::TypePtr(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeReference> for NodeMut<'a> {
+    fn from(node: &'a mut TypeReference) -> Self {
+        NodeMut::TypeReference(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeSlice> for NodeMut<'a> {
+    fn from(node: &'a mut TypeSlice) -> Self {
+        NodeMut::TypeSlice(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeTraitObject> for NodeMut<'a> {
+    fn from(node: &'a mut TypeTraitObject) -> Self {
+        NodeMut::TypeTraitObject(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut TypeTuple> for NodeMut<'a> {
+    fn from(node: &'a mut TypeTuple) -> Self {
+        NodeMut::TypeTuple(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut UnOp> for NodeMut<'a> {
+    fn from(node: &'a mut UnOp) -> Self {
+        NodeMut::UnOp(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut UseGlob> for NodeMut<'a> {
+    fn from(node: &'a mut UseGlob) -> Self {
+        NodeMut::UseGlob(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut UseGroup> for NodeMut<'a> {
+    fn from(node: &'a mut UseGroup) -> Self {
+        NodeMut::UseGroup(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut UseName> for NodeMut<'a> {
+    fn from(node: &'a mut UseName) -> Self {
+        NodeMut::UseName(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut UsePath> for NodeMut<'a> {
+    fn from(node: &'a mut UsePath) -> Self {
+        NodeMut::UsePath(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut UseRename> for NodeMut<'a> {
+    fn from(node: &'a mut UseRename) -> Self {
+        NodeMut::UseRename(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut UseTree> for NodeMut<'a> {
+    fn from(node: &'a mut UseTree) -> Self {
+        NodeMut::UseTree(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(feature = "full")]
+impl<'a> From<&'a mut Variadic> for NodeMut<'a> {
+    fn from(node: &'a mut Variadic) -> Self {
+        NodeMut::Variadic(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Variant> for NodeMut<'a> {
+    fn from(node: &'a mut Variant) -> Self {
+        NodeMut::Variant(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut VisCrate> for NodeMut<'a> {
+    fn from(node: &'a mut VisCrate) -> Self {
+        NodeMut::VisCrate(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut VisPublic> for NodeMut<'a> {
+    fn from(node: &'a mut VisPublic) -> Self {
+        NodeMut::VisPublic(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut VisRestricted> for NodeMut<'a> {
+    fn from(node: &'a mut VisRestricted) -> Self {
+        NodeMut::VisRestricted(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut Visibility> for NodeMut<'a> {
+    fn from(node: &'a mut Visibility) -> Self {
+        NodeMut::Visibility(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut WhereClause> for NodeMut<'a> {
+    fn from(node: &'a mut WhereClause) -> Self {
+        NodeMut::WhereClause(node)
+    }
+}
+#[cfg(feature = "visit-mut")]
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'a> From<&'a mut WherePredicate> for NodeMut<'a> {
+    fn from(node: &'a mut WherePredicate) -> Self {
+        NodeMut::WherePredicate(node)
+    }
+}
+#[cfg(all(feature = "visit-mut", feature = "printing"))]
+impl<'a> ToTokens for NodeMut<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Abi(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::AngleBracketedGenericArguments(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::Arm(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::AttrStyle(node) => {
+                if let AttrStyle::Inner(bang) = node {
+                    bang.to_tokens(tokens);
+                }
+            }
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Attribute(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::BareFnArg(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::BinOp(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Binding(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::Block(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::BoundLifetimes(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ConstParam(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Constraint(node) => node.to_tokens(tokens),
+            #[cfg(feature = "derive")]
+            NodeMut::Data(node) => match node {
+                Data::Struct(data) => {
+                    data.struct_token.to_tokens(tokens);
+                    data.fields.to_tokens(tokens);
+                    data.semi_token.to_tokens(tokens);
+                }
+                Data::Enum(data) => {
+                    data.enum_token.to_tokens(tokens);
+                    data.brace_token.surround(tokens, |tokens| {
+                        data.variants.to_tokens(tokens);
+                    });
+                }
+                Data::Union(data) => {
+                    data.union_token.to_tokens(tokens);
+                    data.fields.to_tokens(tokens);
+                }
+            },
+            #[cfg(feature = "derive")]
+            NodeMut::DataEnum(node) => {
+                node.enum_token.to_tokens(tokens);
+                node.brace_token.surround(tokens, |tokens| {
+                    node.variants.to_tokens(tokens);
+                });
+            }
+            #[cfg(feature = "derive")]
+            NodeMut::DataStruct(node) => {
+                node.struct_token.to_tokens(tokens);
+                node.fields.to_tokens(tokens);
+                node.semi_token.to_tokens(tokens);
+            }
+            #[cfg(feature = "derive")]
+            NodeMut::DataUnion(node) => {
+                node.union_token.to_tokens(tokens);
+                node.fields.to_tokens(tokens);
+            }
+            #[cfg(feature = "derive")]
+            NodeMut::DeriveInput(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Expr(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprArray(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprAssign(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprAssignOp(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprAsync(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprAwait(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprBinary(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprBlock(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprBox(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprBreak(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprCall(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprCast(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprClosure(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprContinue(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprField(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprForLoop(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprGroup(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprIf(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprIndex(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprLet(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprLit(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprLoop(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprMatch(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprMethodCall(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprParen(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprPath(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprRange(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprReference(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprRepeat(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprReturn(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprStruct(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprTry(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprTryBlock(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprTuple(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprType(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ExprUnary(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprUnsafe(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprWhile(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ExprYield(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Field(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::FieldPat(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::FieldValue(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Fields(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::FieldsNamed(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::FieldsUnnamed(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::File(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::FnArg(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItem(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemFn(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemStatic(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ForeignItemType(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::GenericArgument(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::GenericMethodArgument(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::GenericParam(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Generics(node) => node.to_tokens(tokens),
+            NodeMut::Ident(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ImplItem(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemConst(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemMethod(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ImplItemType(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Index(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::Item(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemConst(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemEnum(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemExternCrate(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemFn(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemForeignMod(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemImpl(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemMacro2(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemMod(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemStatic(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemStruct(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemTrait(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemTraitAlias(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemType(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemUnion(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::ItemUse(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::Label(node) => node.to_tokens(tokens),
+            NodeMut::Lifetime(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LifetimeDef(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Lit(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitBool(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitByte(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitByteStr(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitChar(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitFloat(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitInt(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::LitStr(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::Local(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Macro(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MacroDelimiter(node) => match node {
+                MacroDelimiter::Paren(paren) => paren.surround(tokens, |_| {}),
+                MacroDelimiter::Brace(brace) => brace.surround(tokens, |_| {}),
+                MacroDelimiter::Bracket(bracket) => bracket.surround(tokens, |_| {}),
+            },
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Member(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Meta(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaExpr(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaExprList(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaExprNameValue(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaList(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaNameValue(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::MetaValue(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::MethodTurbofish(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::NestedMeta(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::NestedMetaExpr(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ParenthesizedGenericArguments(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::Pat(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatBox(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatIdent(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatLit(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatOr(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatPath(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatRange(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatReference(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatRest(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatSlice(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatStruct(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatTuple(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatTupleStruct(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatType(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::PatWild(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Path(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PathArguments(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PathSegment(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PredicateEq(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PredicateLifetime(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::PredicateType(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::QSelf(node) => {
+                node.lt_token.to_tokens(tokens);
+                node.ty.to_tokens(tokens);
+                node.as_token.to_tokens(tokens);
+                node.gt_token.to_tokens(tokens);
+            }
+            #[cfg(feature = "full")]
+            NodeMut::RangeLimits(node) => match node {
+                RangeLimits::HalfOpen(dot2) => dot2.to_tokens(tokens),
+                RangeLimits::Closed(dot2_eq) => dot2_eq.to_tokens(tokens),
+            },
+            #[cfg(feature = "full")]
+            NodeMut::Receiver(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::ReturnType(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::Signature(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::Stmt(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TraitBound(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TraitBoundModifier(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::TraitItem(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemConst(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemMacro(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemMethod(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::TraitItemType(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Type(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeArray(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeBareFn(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeGroup(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeImplTrait(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeInfer(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeMacro(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeNever(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeParam(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeParamBound(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeParen(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypePath(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypePtr(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeReference(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeSlice(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeTraitObject(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::TypeTuple(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::UnOp(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::UseGlob(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::UseGroup(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::UseName(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::UsePath(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::UseRename(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::UseTree(node) => node.to_tokens(tokens),
+            #[cfg(feature = "full")]
+            NodeMut::Variadic(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Variant(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::VisCrate(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::VisPublic(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::VisRestricted(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::Visibility(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::WhereClause(node) => node.to_tokens(tokens),
+            #[cfg(any(feature = "derive", feature = "full"))]
+            NodeMut::WherePredicate(node) => node.to_tokens(tokens),
+            NodeMut::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+///An iterator over the children of a [`NodeMut`].
+///
+///*This type is available if Syn is built with the `"visit-mut"` feature.*
+#[cfg(feature = "visit-mut")]
+pub struct ChildrenMut<'a> {
+    iter: vec::IntoIter<NodeMut<'a>>,
+}
+#[cfg(feature = "visit-mut")]
+impl<'a> Iterator for ChildrenMut<'a> {
+    type Item = NodeMut<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+#[cfg(feature = "visit-mut")]
+impl<'a> DoubleEndedIterator for ChildrenMut<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+#[cfg(feature = "visit-mut")]
+impl<'a> ExactSizeIterator for ChildrenMut<'a> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -0,0 +1,4191 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+use crate::node::NodeRef;
+use crate::query::{self, Matcher, Searchable};
+use crate::sealed::query::Sealed;
+use crate::tt::TokenStreamHelper;
+use crate::*;
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Abi {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Abi {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Abi(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Abi(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.option(pattern.name.as_ref(), node.name.as_ref())
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for AngleBracketedGenericArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for AngleBracketedGenericArguments {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::AngleBracketedGenericArguments(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::AngleBracketedGenericArguments(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        pattern.colon2_token.is_some() == node.colon2_token.is_some()
+            && matcher.list(&pattern.args, &node.args)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Arm {}
+#[cfg(feature = "full")]
+impl Searchable for Arm {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Arm(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Arm(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.pat, &node.pat, matcher)
+            && match (&pattern.guard, &node.guard) {
+                (Some(pattern), Some(node)) => Searchable::matches(&*pattern.1, &*node.1, matcher),
+                (None, None) => true,
+                _ => false,
+            }
+            && Searchable::matches(&*pattern.body, &*node.body, matcher)
+            && pattern.comma.is_some() == node.comma.is_some()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for AttrStyle {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for AttrStyle {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::AttrStyle(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::AttrStyle(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (AttrStyle::Outer, AttrStyle::Outer) => true,
+            (AttrStyle::Inner(_), AttrStyle::Inner(_)) => true,
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Attribute {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Attribute {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Attribute(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Attribute(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.style, &node.style, matcher)
+            && Searchable::matches(&pattern.path, &node.path, matcher)
+            && TokenStreamHelper(&pattern.tokens) == TokenStreamHelper(&node.tokens)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BareFnArg {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for BareFnArg {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::BareFnArg(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::BareFnArg(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && match (&pattern.name, &node.name) {
+                (Some(pattern), Some(node)) => Searchable::matches(&pattern.0, &node.0, matcher),
+                (None, None) => true,
+                _ => false,
+            }
+            && Searchable::matches(&pattern.ty, &node.ty, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BinOp {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for BinOp {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::BinOp(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::BinOp(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (BinOp::Add(_), BinOp::Add(_)) => true,
+            (BinOp::Sub(_), BinOp::Sub(_)) => true,
+            (BinOp::Mul(_), BinOp::Mul(_)) => true,
+            (BinOp::Div(_), BinOp::Div(_)) => true,
+            (BinOp::Rem(_), BinOp::Rem(_)) => true,
+            (BinOp::And(_), BinOp::And(_)) => true,
+            (BinOp::Or(_), BinOp::Or(_)) => true,
+            (BinOp::BitXor(_), BinOp::BitXor(_)) => true,
+            (BinOp::BitAnd(_), BinOp::BitAnd(_)) => true,
+            (BinOp::BitOr(_), BinOp::BitOr(_)) => true,
+            (BinOp::Shl(_), BinOp::Shl(_)) => true,
+            (BinOp::Shr(_), BinOp::Shr(_)) => true,
+            (BinOp::Eq(_), BinOp::Eq(_)) => true,
+            (BinOp::Lt(_), BinOp::Lt(_)) => true,
+            (BinOp::Le(_), BinOp::Le(_)) => true,
+            (BinOp::Ne(_), BinOp::Ne(_)) => true,
+            (BinOp::Ge(_), BinOp::Ge(_)) => true,
+            (BinOp::Gt(_), BinOp::Gt(_)) => true,
+            (BinOp::AddEq(_), BinOp::AddEq(_)) => true,
+            (BinOp::SubEq(_), BinOp::SubEq(_)) => true,
+            (BinOp::MulEq(_), BinOp::MulEq(_)) => true,
+            (BinOp::DivEq(_), BinOp::DivEq(_)) => true,
+            (BinOp::RemEq(_), BinOp::RemEq(_)) => true,
+            (BinOp::BitXorEq(_), BinOp::BitXorEq(_)) => true,
+            (BinOp::BitAndEq(_), BinOp::BitAndEq(_)) => true,
+            (BinOp::BitOrEq(_), BinOp::BitOrEq(_)) => true,
+            (BinOp::ShlEq(_), BinOp::ShlEq(_)) => true,
+            (BinOp::ShrEq(_), BinOp::ShrEq(_)) => true,
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Binding {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Binding {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Binding(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Binding(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.ty, &node.ty, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Block {}
+#[cfg(feature = "full")]
+impl Searchable for Block {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Block(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Block(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.stmts, &node.stmts)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for BoundLifetimes {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for BoundLifetimes {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::BoundLifetimes(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::BoundLifetimes(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.lifetimes, &node.lifetimes)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ConstParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ConstParam {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ConstParam(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ConstParam(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.ty, &node.ty, matcher)
+            && pattern.eq_token.is_some() == node.eq_token.is_some()
+            && matcher.option(pattern.default.as_ref(), node.default.as_ref())
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Constraint {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Constraint {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Constraint(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Constraint(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && matcher.list(&pattern.bounds, &node.bounds)
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for Data {}
+#[cfg(feature = "derive")]
+impl Searchable for Data {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Data(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Data(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (Data::Struct(pattern0), Data::Struct(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Data::Enum(pattern0), Data::Enum(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Data::Union(pattern0), Data::Union(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataEnum {}
+#[cfg(feature = "derive")]
+impl Searchable for DataEnum {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::DataEnum(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::DataEnum(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.variants, &node.variants)
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataStruct {}
+#[cfg(feature = "derive")]
+impl Searchable for DataStruct {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::DataStruct(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::DataStruct(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.fields, &node.fields, matcher)
+            && pattern.semi_token.is_some() == node.semi_token.is_some()
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DataUnion {}
+#[cfg(feature = "derive")]
+impl Searchable for DataUnion {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::DataUnion(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::DataUnion(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.fields, &node.fields, matcher)
+    }
+}
+#[cfg(feature = "derive")]
+impl Sealed for DeriveInput {}
+#[cfg(feature = "derive")]
+impl Searchable for DeriveInput {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::DeriveInput(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::DeriveInput(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.generics, &node.generics, matcher)
+            && Searchable::matches(&pattern.data, &node.data, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Expr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Expr {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Expr(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Expr(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        if let Some(name) = query::expr_metavar(pattern) {
+            return matcher.bind(name, node);
+        }
+        match (pattern, node) {
+            (Expr::Array(pattern0), Expr::Array(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Assign(pattern0), Expr::Assign(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::AssignOp(pattern0), Expr::AssignOp(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Async(pattern0), Expr::Async(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Await(pattern0), Expr::Await(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Binary(pattern0), Expr::Binary(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Block(pattern0), Expr::Block(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Box(pattern0), Expr::Box(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Break(pattern0), Expr::Break(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Call(pattern0), Expr::Call(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Cast(pattern0), Expr::Cast(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Closure(pattern0), Expr::Closure(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Continue(pattern0), Expr::Continue(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Field(pattern0), Expr::Field(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::ForLoop(pattern0), Expr::ForLoop(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Group(pattern0), Expr::Group(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::If(pattern0), Expr::If(node0)) => Searchable::matches(pattern0, node0, matcher),
+            (Expr::Index(pattern0), Expr::Index(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Let(pattern0), Expr::Let(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Lit(pattern0), Expr::Lit(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Loop(pattern0), Expr::Loop(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Macro(pattern0), Expr::Macro(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Match(pattern0), Expr::Match(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::MethodCall(pattern0), Expr::MethodCall(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Paren(pattern0), Expr::Paren(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Path(pattern0), Expr::Path(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Range(pattern0), Expr::Range(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Reference(pattern0), Expr::Reference(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Repeat(pattern0), Expr::Repeat(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Return(pattern0), Expr::Return(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Struct(pattern0), Expr::Struct(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Try(pattern0), Expr::Try(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::TryBlock(pattern0), Expr::TryBlock(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Tuple(pattern0), Expr::Tuple(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Type(pattern0), Expr::Type(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Unary(pattern0), Expr::Unary(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Unsafe(pattern0), Expr::Unsafe(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Verbatim(pattern0), Expr::Verbatim(node0)) => {
+                TokenStreamHelper(pattern0) == TokenStreamHelper(node0)
+            }
+            (Expr::While(pattern0), Expr::While(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Expr::Yield(pattern0), Expr::Yield(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprArray {}
+#[cfg(feature = "full")]
+impl Searchable for ExprArray {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprArray(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprArray(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs) && matcher.list(&pattern.elems, &node.elems)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAssign {}
+#[cfg(feature = "full")]
+impl Searchable for ExprAssign {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprAssign(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprAssign(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.left, &*node.left, matcher)
+            && Searchable::matches(&*pattern.right, &*node.right, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAssignOp {}
+#[cfg(feature = "full")]
+impl Searchable for ExprAssignOp {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprAssignOp(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprAssignOp(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.left, &*node.left, matcher)
+            && Searchable::matches(&pattern.op, &node.op, matcher)
+            && Searchable::matches(&*pattern.right, &*node.right, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAsync {}
+#[cfg(feature = "full")]
+impl Searchable for ExprAsync {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprAsync(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprAsync(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && pattern.capture.is_some() == node.capture.is_some()
+            && Searchable::matches(&pattern.block, &node.block, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprAwait {}
+#[cfg(feature = "full")]
+impl Searchable for ExprAwait {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprAwait(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprAwait(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.base, &*node.base, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprBinary {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ExprBinary {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprBinary(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprBinary(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.left, &*node.left, matcher)
+            && Searchable::matches(&pattern.op, &node.op, matcher)
+            && Searchable::matches(&*pattern.right, &*node.right, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBlock {}
+#[cfg(feature = "full")]
+impl Searchable for ExprBlock {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprBlock(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprBlock(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(pattern.label.as_ref(), node.label.as_ref())
+            && Searchable::matches(&pattern.block, &node.block, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBox {}
+#[cfg(feature = "full")]
+impl Searchable for ExprBox {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprBox(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprBox(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprBreak {}
+#[cfg(feature = "full")]
+impl Searchable for ExprBreak {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprBreak(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprBreak(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(pattern.label.as_ref(), node.label.as_ref())
+            && matcher.option(
+                pattern.expr.as_ref().map(Box::as_ref),
+                node.expr.as_ref().map(Box::as_ref),
+            )
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprCall {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ExprCall {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprCall(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprCall(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.func, &*node.func, matcher)
+            && matcher.list(&pattern.args, &node.args)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprCast {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ExprCast {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprCast(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprCast(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+            && Searchable::matches(&*pattern.ty, &*node.ty, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprClosure {}
+#[cfg(feature = "full")]
+impl Searchable for ExprClosure {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprClosure(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprClosure(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && pattern.asyncness.is_some() == node.asyncness.is_some()
+            && pattern.movability.is_some() == node.movability.is_some()
+            && pattern.capture.is_some() == node.capture.is_some()
+            && matcher.list(&pattern.inputs, &node.inputs)
+            && Searchable::matches(&pattern.output, &node.output, matcher)
+            && Searchable::matches(&*pattern.body, &*node.body, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprContinue {}
+#[cfg(feature = "full")]
+impl Searchable for ExprContinue {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprContinue(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprContinue(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(pattern.label.as_ref(), node.label.as_ref())
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprField {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ExprField {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprField(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprField(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.base, &*node.base, matcher)
+            && Searchable::matches(&pattern.member, &node.member, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprForLoop {}
+#[cfg(feature = "full")]
+impl Searchable for ExprForLoop {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprForLoop(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprForLoop(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(pattern.label.as_ref(), node.label.as_ref())
+            && Searchable::matches(&pattern.pat, &node.pat, matcher)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+            && Searchable::matches(&pattern.body, &node.body, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprGroup {}
+#[cfg(feature = "full")]
+impl Searchable for ExprGroup {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprGroup(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprGroup(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprIf {}
+#[cfg(feature = "full")]
+impl Searchable for ExprIf {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprIf(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprIf(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.cond, &*node.cond, matcher)
+            && Searchable::matches(&pattern.then_branch, &node.then_branch, matcher)
+            && match (&pattern.else_branch, &node.else_branch) {
+                (Some(pattern), Some(node)) => Searchable::matches(&*pattern.1, &*node.1, matcher),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprIndex {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ExprIndex {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprIndex(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprIndex(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+            && Searchable::matches(&*pattern.index, &*node.index, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprLet {}
+#[cfg(feature = "full")]
+impl Searchable for ExprLet {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprLet(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprLet(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.pat, &node.pat, matcher)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprLit {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ExprLit {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprLit(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprLit(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.lit, &node.lit, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprLoop {}
+#[cfg(feature = "full")]
+impl Searchable for ExprLoop {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprLoop(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprLoop(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(pattern.label.as_ref(), node.label.as_ref())
+            && Searchable::matches(&pattern.body, &node.body, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMacro {}
+#[cfg(feature = "full")]
+impl Searchable for ExprMacro {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprMacro(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprMacro(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.mac, &node.mac, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMatch {}
+#[cfg(feature = "full")]
+impl Searchable for ExprMatch {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprMatch(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprMatch(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+            && matcher.list(&pattern.arms, &node.arms)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprMethodCall {}
+#[cfg(feature = "full")]
+impl Searchable for ExprMethodCall {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprMethodCall(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprMethodCall(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.receiver, &*node.receiver, matcher)
+            && Searchable::matches(&pattern.method, &node.method, matcher)
+            && matcher.option(pattern.turbofish.as_ref(), node.turbofish.as_ref())
+            && matcher.list(&pattern.args, &node.args)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprParen {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ExprParen {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprParen(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprParen(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprPath {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ExprPath {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprPath(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprPath(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(pattern.qself.as_ref(), node.qself.as_ref())
+            && Searchable::matches(&pattern.path, &node.path, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprRange {}
+#[cfg(feature = "full")]
+impl Searchable for ExprRange {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprRange(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprRange(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(
+                pattern.from.as_ref().map(Box::as_ref),
+                node.from.as_ref().map(Box::as_ref),
+            )
+            && Searchable::matches(&pattern.limits, &node.limits, matcher)
+            && matcher.option(
+                pattern.to.as_ref().map(Box::as_ref),
+                node.to.as_ref().map(Box::as_ref),
+            )
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprReference {}
+#[cfg(feature = "full")]
+impl Searchable for ExprReference {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprReference(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprReference(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && pattern.mutability.is_some() == node.mutability.is_some()
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprRepeat {}
+#[cfg(feature = "full")]
+impl Searchable for ExprRepeat {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprRepeat(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprRepeat(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+            && Searchable::matches(&*pattern.len, &*node.len, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprReturn {}
+#[cfg(feature = "full")]
+impl Searchable for ExprReturn {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprReturn(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprReturn(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(
+                pattern.expr.as_ref().map(Box::as_ref),
+                node.expr.as_ref().map(Box::as_ref),
+            )
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprStruct {}
+#[cfg(feature = "full")]
+impl Searchable for ExprStruct {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprStruct(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprStruct(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.path, &node.path, matcher)
+            && matcher.list(&pattern.fields, &node.fields)
+            && pattern.dot2_token.is_some() == node.dot2_token.is_some()
+            && matcher.option(
+                pattern.rest.as_ref().map(Box::as_ref),
+                node.rest.as_ref().map(Box::as_ref),
+            )
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTry {}
+#[cfg(feature = "full")]
+impl Searchable for ExprTry {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprTry(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprTry(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTryBlock {}
+#[cfg(feature = "full")]
+impl Searchable for ExprTryBlock {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprTryBlock(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprTryBlock(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.block, &node.block, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprTuple {}
+#[cfg(feature = "full")]
+impl Searchable for ExprTuple {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprTuple(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprTuple(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs) && matcher.list(&pattern.elems, &node.elems)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprType {}
+#[cfg(feature = "full")]
+impl Searchable for ExprType {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprType(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprType(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+            && Searchable::matches(&*pattern.ty, &*node.ty, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ExprUnary {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ExprUnary {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprUnary(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprUnary(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.op, &node.op, matcher)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprUnsafe {}
+#[cfg(feature = "full")]
+impl Searchable for ExprUnsafe {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprUnsafe(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprUnsafe(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.block, &node.block, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprWhile {}
+#[cfg(feature = "full")]
+impl Searchable for ExprWhile {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprWhile(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprWhile(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(pattern.label.as_ref(), node.label.as_ref())
+            && Searchable::matches(&*pattern.cond, &*node.cond, matcher)
+            && Searchable::matches(&pattern.body, &node.body, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ExprYield {}
+#[cfg(feature = "full")]
+impl Searchable for ExprYield {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ExprYield(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ExprYield(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(
+                pattern.expr.as_ref().map(Box::as_ref),
+                node.expr.as_ref().map(Box::as_ref),
+            )
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Field {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Field {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Field(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Field(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && matcher.option(pattern.ident.as_ref(), node.ident.as_ref())
+            && pattern.colon_token.is_some() == node.colon_token.is_some()
+            && Searchable::matches(&pattern.ty, &node.ty, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FieldPat {}
+#[cfg(feature = "full")]
+impl Searchable for FieldPat {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::FieldPat(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::FieldPat(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.member, &node.member, matcher)
+            && pattern.colon_token.is_some() == node.colon_token.is_some()
+            && Searchable::matches(&*pattern.pat, &*node.pat, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FieldValue {}
+#[cfg(feature = "full")]
+impl Searchable for FieldValue {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::FieldValue(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::FieldValue(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.member, &node.member, matcher)
+            && pattern.colon_token.is_some() == node.colon_token.is_some()
+            && Searchable::matches(&pattern.expr, &node.expr, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Fields {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Fields {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Fields(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Fields(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (Fields::Named(pattern0), Fields::Named(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Fields::Unnamed(pattern0), Fields::Unnamed(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Fields::Unit, Fields::Unit) => true,
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for FieldsNamed {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for FieldsNamed {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::FieldsNamed(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::FieldsNamed(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.named, &node.named)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for FieldsUnnamed {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for FieldsUnnamed {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::FieldsUnnamed(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::FieldsUnnamed(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.unnamed, &node.unnamed)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for File {}
+#[cfg(feature = "full")]
+impl Searchable for File {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::File(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::File(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        pattern.shebang == node.shebang
+            && matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.list(&pattern.items, &node.items)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for FnArg {}
+#[cfg(feature = "full")]
+impl Searchable for FnArg {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::FnArg(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::FnArg(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (FnArg::Receiver(pattern0), FnArg::Receiver(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (FnArg::Typed(pattern0), FnArg::Typed(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItem {}
+#[cfg(feature = "full")]
+impl Searchable for ForeignItem {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ForeignItem(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ForeignItem(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (ForeignItem::Fn(pattern0), ForeignItem::Fn(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (ForeignItem::Static(pattern0), ForeignItem::Static(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (ForeignItem::Type(pattern0), ForeignItem::Type(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (ForeignItem::Macro(pattern0), ForeignItem::Macro(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (ForeignItem::Verbatim(pattern0), ForeignItem::Verbatim(node0)) => {
+                TokenStreamHelper(pattern0) == TokenStreamHelper(node0)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemFn {}
+#[cfg(feature = "full")]
+impl Searchable for ForeignItemFn {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ForeignItemFn(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ForeignItemFn(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.sig, &node.sig, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemMacro {}
+#[cfg(feature = "full")]
+impl Searchable for ForeignItemMacro {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ForeignItemMacro(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ForeignItemMacro(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.mac, &node.mac, matcher)
+            && pattern.semi_token.is_some() == node.semi_token.is_some()
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemStatic {}
+#[cfg(feature = "full")]
+impl Searchable for ForeignItemStatic {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ForeignItemStatic(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ForeignItemStatic(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && pattern.mutability.is_some() == node.mutability.is_some()
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&*pattern.ty, &*node.ty, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ForeignItemType {}
+#[cfg(feature = "full")]
+impl Searchable for ForeignItemType {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ForeignItemType(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ForeignItemType(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for GenericArgument {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for GenericArgument {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::GenericArgument(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::GenericArgument(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (GenericArgument::Lifetime(pattern0), GenericArgument::Lifetime(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (GenericArgument::Type(pattern0), GenericArgument::Type(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (GenericArgument::Binding(pattern0), GenericArgument::Binding(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (GenericArgument::Constraint(pattern0), GenericArgument::Constraint(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (GenericArgument::Const(pattern0), GenericArgument::Const(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for GenericMethodArgument {}
+#[cfg(feature = "full")]
+impl Searchable for GenericMethodArgument {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::GenericMethodArgument(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::GenericMethodArgument(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (GenericMethodArgument::Type(pattern0), GenericMethodArgument::Type(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (GenericMethodArgument::Const(pattern0), GenericMethodArgument::Const(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for GenericParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for GenericParam {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::GenericParam(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::GenericParam(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (GenericParam::Type(pattern0), GenericParam::Type(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (GenericParam::Lifetime(pattern0), GenericParam::Lifetime(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (GenericParam::Const(pattern0), GenericParam::Const(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Generics {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Generics {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Generics(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Generics(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        pattern.lt_token.is_some() == node.lt_token.is_some()
+            && matcher.list(&pattern.params, &node.params)
+            && pattern.gt_token.is_some() == node.gt_token.is_some()
+            && matcher.option(pattern.where_clause.as_ref(), node.where_clause.as_ref())
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItem {}
+#[cfg(feature = "full")]
+impl Searchable for ImplItem {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ImplItem(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ImplItem(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (ImplItem::Const(pattern0), ImplItem::Const(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (ImplItem::Method(pattern0), ImplItem::Method(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (ImplItem::Type(pattern0), ImplItem::Type(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (ImplItem::Macro(pattern0), ImplItem::Macro(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (ImplItem::Verbatim(pattern0), ImplItem::Verbatim(node0)) => {
+                TokenStreamHelper(pattern0) == TokenStreamHelper(node0)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemConst {}
+#[cfg(feature = "full")]
+impl Searchable for ImplItemConst {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ImplItemConst(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ImplItemConst(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && pattern.defaultness.is_some() == node.defaultness.is_some()
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.ty, &node.ty, matcher)
+            && Searchable::matches(&pattern.expr, &node.expr, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemMacro {}
+#[cfg(feature = "full")]
+impl Searchable for ImplItemMacro {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ImplItemMacro(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ImplItemMacro(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.mac, &node.mac, matcher)
+            && pattern.semi_token.is_some() == node.semi_token.is_some()
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemMethod {}
+#[cfg(feature = "full")]
+impl Searchable for ImplItemMethod {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ImplItemMethod(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ImplItemMethod(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && pattern.defaultness.is_some() == node.defaultness.is_some()
+            && Searchable::matches(&pattern.sig, &node.sig, matcher)
+            && Searchable::matches(&pattern.block, &node.block, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ImplItemType {}
+#[cfg(feature = "full")]
+impl Searchable for ImplItemType {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ImplItemType(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ImplItemType(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && pattern.defaultness.is_some() == node.defaultness.is_some()
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.generics, &node.generics, matcher)
+            && Searchable::matches(&pattern.ty, &node.ty, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Index {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Index {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Index(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Index(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        pattern.index == node.index
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Item {}
+#[cfg(feature = "full")]
+impl Searchable for Item {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Item(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Item(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (Item::Const(pattern0), Item::Const(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Enum(pattern0), Item::Enum(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::ExternCrate(pattern0), Item::ExternCrate(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Fn(pattern0), Item::Fn(node0)) => Searchable::matches(pattern0, node0, matcher),
+            (Item::ForeignMod(pattern0), Item::ForeignMod(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Impl(pattern0), Item::Impl(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Macro(pattern0), Item::Macro(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Macro2(pattern0), Item::Macro2(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Mod(pattern0), Item::Mod(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Static(pattern0), Item::Static(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Struct(pattern0), Item::Struct(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Trait(pattern0), Item::Trait(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::TraitAlias(pattern0), Item::TraitAlias(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Type(pattern0), Item::Type(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Union(pattern0), Item::Union(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Use(pattern0), Item::Use(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Item::Verbatim(pattern0), Item::Verbatim(node0)) => {
+                TokenStreamHelper(pattern0) == TokenStreamHelper(node0)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemConst {}
+#[cfg(feature = "full")]
+impl Searchable for ItemConst {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemConst(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemConst(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&*pattern.ty, &*node.ty, matcher)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemEnum {}
+#[cfg(feature = "full")]
+impl Searchable for ItemEnum {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemEnum(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemEnum(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.generics, &node.generics, matcher)
+            && matcher.list(&pattern.variants, &node.variants)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemExternCrate {}
+#[cfg(feature = "full")]
+impl Searchable for ItemExternCrate {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemExternCrate(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemExternCrate(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && match (&pattern.rename, &node.rename) {
+                (Some(pattern), Some(node)) => Searchable::matches(&pattern.1, &node.1, matcher),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemFn {}
+#[cfg(feature = "full")]
+impl Searchable for ItemFn {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemFn(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemFn(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.sig, &node.sig, matcher)
+            && Searchable::matches(&*pattern.block, &*node.block, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemForeignMod {}
+#[cfg(feature = "full")]
+impl Searchable for ItemForeignMod {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemForeignMod(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemForeignMod(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.abi, &node.abi, matcher)
+            && matcher.list(&pattern.items, &node.items)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemImpl {}
+#[cfg(feature = "full")]
+impl Searchable for ItemImpl {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemImpl(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemImpl(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && pattern.defaultness.is_some() == node.defaultness.is_some()
+            && pattern.unsafety.is_some() == node.unsafety.is_some()
+            && Searchable::matches(&pattern.generics, &node.generics, matcher)
+            && match (&pattern.trait_, &node.trait_) {
+                (Some(pattern), Some(node)) => {
+                    pattern.0.is_some() == node.0.is_some()
+                        && Searchable::matches(&pattern.1, &node.1, matcher)
+                }
+                (None, None) => true,
+                _ => false,
+            }
+            && Searchable::matches(&*pattern.self_ty, &*node.self_ty, matcher)
+            && matcher.list(&pattern.items, &node.items)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMacro {}
+#[cfg(feature = "full")]
+impl Searchable for ItemMacro {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemMacro(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemMacro(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(pattern.ident.as_ref(), node.ident.as_ref())
+            && Searchable::matches(&pattern.mac, &node.mac, matcher)
+            && pattern.semi_token.is_some() == node.semi_token.is_some()
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMacro2 {}
+#[cfg(feature = "full")]
+impl Searchable for ItemMacro2 {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemMacro2(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemMacro2(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && TokenStreamHelper(&pattern.rules) == TokenStreamHelper(&node.rules)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemMod {}
+#[cfg(feature = "full")]
+impl Searchable for ItemMod {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemMod(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemMod(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && match (&pattern.content, &node.content) {
+                (Some(pattern), Some(node)) => matcher.list(&pattern.1, &node.1),
+                (None, None) => true,
+                _ => false,
+            }
+            && pattern.semi.is_some() == node.semi.is_some()
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemStatic {}
+#[cfg(feature = "full")]
+impl Searchable for ItemStatic {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemStatic(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemStatic(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && pattern.mutability.is_some() == node.mutability.is_some()
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&*pattern.ty, &*node.ty, matcher)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemStruct {}
+#[cfg(feature = "full")]
+impl Searchable for ItemStruct {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemStruct(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemStruct(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.generics, &node.generics, matcher)
+            && Searchable::matches(&pattern.fields, &node.fields, matcher)
+            && pattern.semi_token.is_some() == node.semi_token.is_some()
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemTrait {}
+#[cfg(feature = "full")]
+impl Searchable for ItemTrait {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemTrait(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemTrait(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && pattern.unsafety.is_some() == node.unsafety.is_some()
+            && pattern.auto_token.is_some() == node.auto_token.is_some()
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.generics, &node.generics, matcher)
+            && pattern.colon_token.is_some() == node.colon_token.is_some()
+            && matcher.list(&pattern.supertraits, &node.supertraits)
+            && matcher.list(&pattern.items, &node.items)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemTraitAlias {}
+#[cfg(feature = "full")]
+impl Searchable for ItemTraitAlias {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemTraitAlias(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemTraitAlias(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.generics, &node.generics, matcher)
+            && matcher.list(&pattern.bounds, &node.bounds)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemType {}
+#[cfg(feature = "full")]
+impl Searchable for ItemType {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemType(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemType(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.generics, &node.generics, matcher)
+            && Searchable::matches(&*pattern.ty, &*node.ty, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemUnion {}
+#[cfg(feature = "full")]
+impl Searchable for ItemUnion {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemUnion(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemUnion(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.generics, &node.generics, matcher)
+            && Searchable::matches(&pattern.fields, &node.fields, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for ItemUse {}
+#[cfg(feature = "full")]
+impl Searchable for ItemUse {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ItemUse(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ItemUse(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.vis, &node.vis, matcher)
+            && pattern.leading_colon.is_some() == node.leading_colon.is_some()
+            && Searchable::matches(&pattern.tree, &node.tree, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Label {}
+#[cfg(feature = "full")]
+impl Searchable for Label {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Label(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Label(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.name, &node.name, matcher)
+    }
+}
+impl Sealed for Lifetime {}
+impl Searchable for Lifetime {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Lifetime(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Lifetime(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.ident, &node.ident, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LifetimeDef {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for LifetimeDef {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::LifetimeDef(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LifetimeDef(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.lifetime, &node.lifetime, matcher)
+            && pattern.colon_token.is_some() == node.colon_token.is_some()
+            && matcher.list(&pattern.bounds, &node.bounds)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Lit {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Lit {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Lit(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Lit(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (Lit::Str(pattern0), Lit::Str(node0)) => Searchable::matches(pattern0, node0, matcher),
+            (Lit::ByteStr(pattern0), Lit::ByteStr(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Lit::Byte(pattern0), Lit::Byte(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Lit::Char(pattern0), Lit::Char(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Lit::Int(pattern0), Lit::Int(node0)) => Searchable::matches(pattern0, node0, matcher),
+            (Lit::Float(pattern0), Lit::Float(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Lit::Bool(pattern0), Lit::Bool(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Lit::Verbatim(pattern0), Lit::Verbatim(node0)) => {
+                pattern0.to_string() == node0.to_string()
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitBool {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for LitBool {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::LitBool(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitBool(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        pattern.value == node.value
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitByte {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for LitByte {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::LitByte(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitByte(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        pattern == node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitByteStr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for LitByteStr {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::LitByteStr(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitByteStr(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        pattern == node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitChar {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for LitChar {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::LitChar(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitChar(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        pattern == node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitFloat {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for LitFloat {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::LitFloat(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitFloat(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        pattern == node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitInt {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for LitInt {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::LitInt(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitInt(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        pattern == node
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for LitStr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for LitStr {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::LitStr(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::LitStr(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        pattern == node
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Local {}
+#[cfg(feature = "full")]
+impl Searchable for Local {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Local(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Local(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.pat, &node.pat, matcher)
+            && match (&pattern.init, &node.init) {
+                (Some(pattern), Some(node)) => Searchable::matches(&*pattern.1, &*node.1, matcher),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Macro {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Macro {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Macro(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Macro(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.path, &node.path, matcher)
+            && Searchable::matches(&pattern.delimiter, &node.delimiter, matcher)
+            && TokenStreamHelper(&pattern.tokens) == TokenStreamHelper(&node.tokens)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MacroDelimiter {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for MacroDelimiter {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::MacroDelimiter(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MacroDelimiter(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (MacroDelimiter::Paren(_), MacroDelimiter::Paren(_)) => true,
+            (MacroDelimiter::Brace(_), MacroDelimiter::Brace(_)) => true,
+            (MacroDelimiter::Bracket(_), MacroDelimiter::Bracket(_)) => true,
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Member {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Member {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Member(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Member(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (Member::Named(pattern0), Member::Named(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Member::Unnamed(pattern0), Member::Unnamed(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Meta {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Meta {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Meta(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Meta(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (Meta::Path(pattern0), Meta::Path(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Meta::List(pattern0), Meta::List(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Meta::NameValue(pattern0), Meta::NameValue(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExpr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for MetaExpr {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::MetaExpr(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaExpr(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (MetaExpr::Path(pattern0), MetaExpr::Path(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (MetaExpr::List(pattern0), MetaExpr::List(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (MetaExpr::NameValue(pattern0), MetaExpr::NameValue(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExprList {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for MetaExprList {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::MetaExprList(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaExprList(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.path, &node.path, matcher)
+            && matcher.list(&pattern.nested, &node.nested)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaExprNameValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for MetaExprNameValue {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::MetaExprNameValue(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaExprNameValue(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.path, &node.path, matcher)
+            && Searchable::matches(&pattern.value, &node.value, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaList {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for MetaList {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::MetaList(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaList(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.path, &node.path, matcher)
+            && matcher.list(&pattern.nested, &node.nested)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaNameValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for MetaNameValue {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::MetaNameValue(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaNameValue(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.path, &node.path, matcher)
+            && Searchable::matches(&pattern.lit, &node.lit, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for MetaValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for MetaValue {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::MetaValue(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MetaValue(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (MetaValue::Expr(pattern0), MetaValue::Expr(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (MetaValue::Verbatim(pattern0), MetaValue::Verbatim(node0)) => {
+                TokenStreamHelper(pattern0) == TokenStreamHelper(node0)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for MethodTurbofish {}
+#[cfg(feature = "full")]
+impl Searchable for MethodTurbofish {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::MethodTurbofish(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::MethodTurbofish(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.args, &node.args)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for NestedMeta {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for NestedMeta {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::NestedMeta(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::NestedMeta(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (NestedMeta::Meta(pattern0), NestedMeta::Meta(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (NestedMeta::Lit(pattern0), NestedMeta::Lit(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for NestedMetaExpr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for NestedMetaExpr {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::NestedMetaExpr(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::NestedMetaExpr(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (NestedMetaExpr::Meta(pattern0), NestedMetaExpr::Meta(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (NestedMetaExpr::Lit(pattern0), NestedMetaExpr::Lit(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ParenthesizedGenericArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ParenthesizedGenericArguments {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ParenthesizedGenericArguments(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ParenthesizedGenericArguments(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.inputs, &node.inputs)
+            && Searchable::matches(&pattern.output, &node.output, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Pat {}
+#[cfg(feature = "full")]
+impl Searchable for Pat {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Pat(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Pat(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        if let Some(name) = query::pat_metavar(pattern) {
+            return matcher.bind(name, node);
+        }
+        match (pattern, node) {
+            (Pat::Box(pattern0), Pat::Box(node0)) => Searchable::matches(pattern0, node0, matcher),
+            (Pat::Ident(pattern0), Pat::Ident(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Pat::Lit(pattern0), Pat::Lit(node0)) => Searchable::matches(pattern0, node0, matcher),
+            (Pat::Macro(pattern0), Pat::Macro(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Pat::Or(pattern0), Pat::Or(node0)) => Searchable::matches(pattern0, node0, matcher),
+            (Pat::Path(pattern0), Pat::Path(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Pat::Range(pattern0), Pat::Range(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Pat::Reference(pattern0), Pat::Reference(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Pat::Rest(pattern0), Pat::Rest(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Pat::Slice(pattern0), Pat::Slice(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Pat::Struct(pattern0), Pat::Struct(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Pat::Tuple(pattern0), Pat::Tuple(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Pat::TupleStruct(pattern0), Pat::TupleStruct(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Pat::Type(pattern0), Pat::Type(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Pat::Verbatim(pattern0), Pat::Verbatim(node0)) => {
+                TokenStreamHelper(pattern0) == TokenStreamHelper(node0)
+            }
+            (Pat::Wild(pattern0), Pat::Wild(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatBox {}
+#[cfg(feature = "full")]
+impl Searchable for PatBox {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatBox(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatBox(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.pat, &*node.pat, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatIdent {}
+#[cfg(feature = "full")]
+impl Searchable for PatIdent {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatIdent(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatIdent(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && pattern.by_ref.is_some() == node.by_ref.is_some()
+            && pattern.mutability.is_some() == node.mutability.is_some()
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && match (&pattern.subpat, &node.subpat) {
+                (Some(pattern), Some(node)) => Searchable::matches(&*pattern.1, &*node.1, matcher),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatLit {}
+#[cfg(feature = "full")]
+impl Searchable for PatLit {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatLit(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatLit(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.expr, &*node.expr, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatMacro {}
+#[cfg(feature = "full")]
+impl Searchable for PatMacro {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatMacro(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatMacro(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.mac, &node.mac, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatOr {}
+#[cfg(feature = "full")]
+impl Searchable for PatOr {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatOr(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatOr(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && pattern.leading_vert.is_some() == node.leading_vert.is_some()
+            && matcher.list(&pattern.cases, &node.cases)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatPath {}
+#[cfg(feature = "full")]
+impl Searchable for PatPath {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatPath(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatPath(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && matcher.option(pattern.qself.as_ref(), node.qself.as_ref())
+            && Searchable::matches(&pattern.path, &node.path, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatRange {}
+#[cfg(feature = "full")]
+impl Searchable for PatRange {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatRange(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatRange(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.lo, &*node.lo, matcher)
+            && Searchable::matches(&pattern.limits, &node.limits, matcher)
+            && Searchable::matches(&*pattern.hi, &*node.hi, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatReference {}
+#[cfg(feature = "full")]
+impl Searchable for PatReference {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatReference(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatReference(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && pattern.mutability.is_some() == node.mutability.is_some()
+            && Searchable::matches(&*pattern.pat, &*node.pat, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatRest {}
+#[cfg(feature = "full")]
+impl Searchable for PatRest {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatRest(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatRest(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatSlice {}
+#[cfg(feature = "full")]
+impl Searchable for PatSlice {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatSlice(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatSlice(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs) && matcher.list(&pattern.elems, &node.elems)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatStruct {}
+#[cfg(feature = "full")]
+impl Searchable for PatStruct {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatStruct(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatStruct(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.path, &node.path, matcher)
+            && matcher.list(&pattern.fields, &node.fields)
+            && pattern.dot2_token.is_some() == node.dot2_token.is_some()
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatTuple {}
+#[cfg(feature = "full")]
+impl Searchable for PatTuple {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatTuple(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatTuple(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs) && matcher.list(&pattern.elems, &node.elems)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatTupleStruct {}
+#[cfg(feature = "full")]
+impl Searchable for PatTupleStruct {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatTupleStruct(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatTupleStruct(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.path, &node.path, matcher)
+            && Searchable::matches(&pattern.pat, &node.pat, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatType {}
+#[cfg(feature = "full")]
+impl Searchable for PatType {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatType(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatType(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&*pattern.pat, &*node.pat, matcher)
+            && Searchable::matches(&*pattern.ty, &*node.ty, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for PatWild {}
+#[cfg(feature = "full")]
+impl Searchable for PatWild {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PatWild(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PatWild(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Path {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Path {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Path(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Path(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        pattern.leading_colon.is_some() == node.leading_colon.is_some()
+            && matcher.list(&pattern.segments, &node.segments)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PathArguments {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for PathArguments {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PathArguments(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PathArguments(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (PathArguments::None, PathArguments::None) => true,
+            (PathArguments::AngleBracketed(pattern0), PathArguments::AngleBracketed(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (PathArguments::Parenthesized(pattern0), PathArguments::Parenthesized(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PathSegment {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for PathSegment {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PathSegment(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PathSegment(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.arguments, &node.arguments, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateEq {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for PredicateEq {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PredicateEq(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PredicateEq(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.lhs_ty, &node.lhs_ty, matcher)
+            && Searchable::matches(&pattern.rhs_ty, &node.rhs_ty, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateLifetime {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for PredicateLifetime {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PredicateLifetime(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PredicateLifetime(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.lifetime, &node.lifetime, matcher)
+            && matcher.list(&pattern.bounds, &node.bounds)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for PredicateType {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for PredicateType {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::PredicateType(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::PredicateType(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.option(pattern.lifetimes.as_ref(), node.lifetimes.as_ref())
+            && Searchable::matches(&pattern.bounded_ty, &node.bounded_ty, matcher)
+            && matcher.list(&pattern.bounds, &node.bounds)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for QSelf {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for QSelf {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::QSelf(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::QSelf(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&*pattern.ty, &*node.ty, matcher)
+            && pattern.position == node.position
+            && pattern.as_token.is_some() == node.as_token.is_some()
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for RangeLimits {}
+#[cfg(feature = "full")]
+impl Searchable for RangeLimits {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::RangeLimits(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::RangeLimits(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (RangeLimits::HalfOpen(_), RangeLimits::HalfOpen(_)) => true,
+            (RangeLimits::Closed(_), RangeLimits::Closed(_)) => true,
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Receiver {}
+#[cfg(feature = "full")]
+impl Searchable for Receiver {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Receiver(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Receiver(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && match (&pattern.reference, &node.reference) {
+                (Some(pattern), Some(node)) => matcher.option(pattern.1.as_ref(), node.1.as_ref()),
+                (None, None) => true,
+                _ => false,
+            }
+            && pattern.mutability.is_some() == node.mutability.is_some()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for ReturnType {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for ReturnType {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::ReturnType(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::ReturnType(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (ReturnType::Default, ReturnType::Default) => true,
+            (ReturnType::Type(_, pattern1), ReturnType::Type(_, node1)) => {
+                Searchable::matches(&**pattern1, &**node1, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Signature {}
+#[cfg(feature = "full")]
+impl Searchable for Signature {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Signature(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Signature(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        pattern.constness.is_some() == node.constness.is_some()
+            && pattern.asyncness.is_some() == node.asyncness.is_some()
+            && pattern.unsafety.is_some() == node.unsafety.is_some()
+            && matcher.option(pattern.abi.as_ref(), node.abi.as_ref())
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.generics, &node.generics, matcher)
+            && matcher.list(&pattern.inputs, &node.inputs)
+            && matcher.option(pattern.variadic.as_ref(), node.variadic.as_ref())
+            && Searchable::matches(&pattern.output, &node.output, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Stmt {}
+#[cfg(feature = "full")]
+impl Searchable for Stmt {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Stmt(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Stmt(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (Stmt::Local(pattern0), Stmt::Local(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Stmt::Item(pattern0), Stmt::Item(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Stmt::Expr(pattern0), Stmt::Expr(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Stmt::Semi(pattern0, _), Stmt::Semi(node0, _)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TraitBound {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TraitBound {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TraitBound(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitBound(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        pattern.paren_token.is_some() == node.paren_token.is_some()
+            && Searchable::matches(&pattern.modifier, &node.modifier, matcher)
+            && matcher.option(pattern.lifetimes.as_ref(), node.lifetimes.as_ref())
+            && Searchable::matches(&pattern.path, &node.path, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TraitBoundModifier {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TraitBoundModifier {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TraitBoundModifier(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitBoundModifier(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (TraitBoundModifier::None, TraitBoundModifier::None) => true,
+            (TraitBoundModifier::Maybe(_), TraitBoundModifier::Maybe(_)) => true,
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItem {}
+#[cfg(feature = "full")]
+impl Searchable for TraitItem {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TraitItem(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitItem(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (TraitItem::Const(pattern0), TraitItem::Const(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (TraitItem::Method(pattern0), TraitItem::Method(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (TraitItem::Type(pattern0), TraitItem::Type(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (TraitItem::Macro(pattern0), TraitItem::Macro(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (TraitItem::Verbatim(pattern0), TraitItem::Verbatim(node0)) => {
+                TokenStreamHelper(pattern0) == TokenStreamHelper(node0)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemConst {}
+#[cfg(feature = "full")]
+impl Searchable for TraitItemConst {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TraitItemConst(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitItemConst(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.ty, &node.ty, matcher)
+            && match (&pattern.default, &node.default) {
+                (Some(pattern), Some(node)) => Searchable::matches(&pattern.1, &node.1, matcher),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemMacro {}
+#[cfg(feature = "full")]
+impl Searchable for TraitItemMacro {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TraitItemMacro(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitItemMacro(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.mac, &node.mac, matcher)
+            && pattern.semi_token.is_some() == node.semi_token.is_some()
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemMethod {}
+#[cfg(feature = "full")]
+impl Searchable for TraitItemMethod {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TraitItemMethod(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitItemMethod(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.sig, &node.sig, matcher)
+            && matcher.option(pattern.default.as_ref(), node.default.as_ref())
+            && pattern.semi_token.is_some() == node.semi_token.is_some()
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for TraitItemType {}
+#[cfg(feature = "full")]
+impl Searchable for TraitItemType {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TraitItemType(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TraitItemType(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.generics, &node.generics, matcher)
+            && pattern.colon_token.is_some() == node.colon_token.is_some()
+            && matcher.list(&pattern.bounds, &node.bounds)
+            && match (&pattern.default, &node.default) {
+                (Some(pattern), Some(node)) => Searchable::matches(&pattern.1, &node.1, matcher),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Type {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Type {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Type(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Type(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        if let Some(name) = query::type_metavar(pattern) {
+            return matcher.bind(name, node);
+        }
+        match (pattern, node) {
+            (Type::Array(pattern0), Type::Array(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::BareFn(pattern0), Type::BareFn(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::Group(pattern0), Type::Group(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::ImplTrait(pattern0), Type::ImplTrait(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::Infer(pattern0), Type::Infer(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::Macro(pattern0), Type::Macro(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::Never(pattern0), Type::Never(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::Paren(pattern0), Type::Paren(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::Path(pattern0), Type::Path(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::Ptr(pattern0), Type::Ptr(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::Reference(pattern0), Type::Reference(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::Slice(pattern0), Type::Slice(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::TraitObject(pattern0), Type::TraitObject(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::Tuple(pattern0), Type::Tuple(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Type::Verbatim(pattern0), Type::Verbatim(node0)) => {
+                TokenStreamHelper(pattern0) == TokenStreamHelper(node0)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeArray {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeArray {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeArray(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeArray(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&*pattern.elem, &*node.elem, matcher)
+            && Searchable::matches(&pattern.len, &node.len, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeBareFn {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeBareFn {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeBareFn(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeBareFn(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.option(pattern.lifetimes.as_ref(), node.lifetimes.as_ref())
+            && pattern.unsafety.is_some() == node.unsafety.is_some()
+            && matcher.option(pattern.abi.as_ref(), node.abi.as_ref())
+            && matcher.list(&pattern.inputs, &node.inputs)
+            && pattern.variadic.is_some() == node.variadic.is_some()
+            && Searchable::matches(&pattern.output, &node.output, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeGroup {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeGroup {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeGroup(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeGroup(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&*pattern.elem, &*node.elem, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeImplTrait {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeImplTrait {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeImplTrait(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeImplTrait(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.bounds, &node.bounds)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeInfer {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeInfer {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeInfer(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeInfer(self)
+    }
+    fn matches<'a>(_pattern: &Self, _node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        true
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeMacro {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeMacro {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeMacro(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeMacro(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.mac, &node.mac, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeNever {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeNever {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeNever(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeNever(self)
+    }
+    fn matches<'a>(_pattern: &Self, _node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        true
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParam {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeParam {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeParam(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeParam(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && pattern.colon_token.is_some() == node.colon_token.is_some()
+            && matcher.list(&pattern.bounds, &node.bounds)
+            && pattern.eq_token.is_some() == node.eq_token.is_some()
+            && matcher.option(pattern.default.as_ref(), node.default.as_ref())
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParamBound {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeParamBound {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeParamBound(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeParamBound(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (TypeParamBound::Trait(pattern0), TypeParamBound::Trait(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (TypeParamBound::Lifetime(pattern0), TypeParamBound::Lifetime(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeParen {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeParen {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeParen(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeParen(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&*pattern.elem, &*node.elem, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypePath {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypePath {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypePath(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypePath(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.option(pattern.qself.as_ref(), node.qself.as_ref())
+            && Searchable::matches(&pattern.path, &node.path, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypePtr {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypePtr {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypePtr(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypePtr(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        pattern.const_token.is_some() == node.const_token.is_some()
+            && pattern.mutability.is_some() == node.mutability.is_some()
+            && Searchable::matches(&*pattern.elem, &*node.elem, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeReference {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeReference {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeReference(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeReference(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.option(pattern.lifetime.as_ref(), node.lifetime.as_ref())
+            && pattern.mutability.is_some() == node.mutability.is_some()
+            && Searchable::matches(&*pattern.elem, &*node.elem, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeSlice {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeSlice {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeSlice(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeSlice(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&*pattern.elem, &*node.elem, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeTraitObject {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeTraitObject {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeTraitObject(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeTraitObject(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        pattern.dyn_token.is_some() == node.dyn_token.is_some()
+            && matcher.list(&pattern.bounds, &node.bounds)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for TypeTuple {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for TypeTuple {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::TypeTuple(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::TypeTuple(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.elems, &node.elems)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for UnOp {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for UnOp {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::UnOp(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UnOp(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (UnOp::Deref(_), UnOp::Deref(_)) => true,
+            (UnOp::Not(_), UnOp::Not(_)) => true,
+            (UnOp::Neg(_), UnOp::Neg(_)) => true,
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseGlob {}
+#[cfg(feature = "full")]
+impl Searchable for UseGlob {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::UseGlob(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UseGlob(self)
+    }
+    fn matches<'a>(_pattern: &Self, _node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        true
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseGroup {}
+#[cfg(feature = "full")]
+impl Searchable for UseGroup {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::UseGroup(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UseGroup(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.items, &node.items)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseName {}
+#[cfg(feature = "full")]
+impl Searchable for UseName {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::UseName(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UseName(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.ident, &node.ident, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UsePath {}
+#[cfg(feature = "full")]
+impl Searchable for UsePath {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::UsePath(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UsePath(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&*pattern.tree, &*node.tree, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseRename {}
+#[cfg(feature = "full")]
+impl Searchable for UseRename {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::UseRename(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UseRename(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.rename, &node.rename, matcher)
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for UseTree {}
+#[cfg(feature = "full")]
+impl Searchable for UseTree {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::UseTree(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::UseTree(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (UseTree::Path(pattern0), UseTree::Path(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (UseTree::Name(pattern0), UseTree::Name(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (UseTree::Rename(pattern0), UseTree::Rename(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (UseTree::Glob(pattern0), UseTree::Glob(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (UseTree::Group(pattern0), UseTree::Group(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl Sealed for Variadic {}
+#[cfg(feature = "full")]
+impl Searchable for Variadic {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Variadic(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Variadic(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Variant {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Variant {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Variant(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Variant(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.attrs, &node.attrs)
+            && Searchable::matches(&pattern.ident, &node.ident, matcher)
+            && Searchable::matches(&pattern.fields, &node.fields, matcher)
+            && match (&pattern.discriminant, &node.discriminant) {
+                (Some(pattern), Some(node)) => Searchable::matches(&pattern.1, &node.1, matcher),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisCrate {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for VisCrate {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::VisCrate(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::VisCrate(self)
+    }
+    fn matches<'a>(_pattern: &Self, _node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        true
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisPublic {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for VisPublic {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::VisPublic(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::VisPublic(self)
+    }
+    fn matches<'a>(_pattern: &Self, _node: &'a Self, _matcher: &mut Matcher<'a>) -> bool {
+        true
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for VisRestricted {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for VisRestricted {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::VisRestricted(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::VisRestricted(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        pattern.in_token.is_some() == node.in_token.is_some()
+            && Searchable::matches(&*pattern.path, &*node.path, matcher)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for Visibility {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for Visibility {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Visibility(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Visibility(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (Visibility::Public(pattern0), Visibility::Public(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Visibility::Crate(pattern0), Visibility::Crate(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Visibility::Restricted(pattern0), Visibility::Restricted(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (Visibility::Inherited, Visibility::Inherited) => true,
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for WhereClause {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for WhereClause {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::WhereClause(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::WhereClause(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        matcher.list(&pattern.predicates, &node.predicates)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Sealed for WherePredicate {}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Searchable for WherePredicate {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::WherePredicate(node) => Some(node),
+            _ => None,
+        }
+    }
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::WherePredicate(self)
+    }
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match (pattern, node) {
+            (WherePredicate::Type(pattern0), WherePredicate::Type(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (WherePredicate::Lifetime(pattern0), WherePredicate::Lifetime(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            (WherePredicate::Eq(pattern0), WherePredicate::Eq(node0)) => {
+                Searchable::matches(pattern0, node0, matcher)
+            }
+            _ => false,
+        }
+    }
+}
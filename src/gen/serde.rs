@@ -0,0 +1,15118 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(clippy::match_single_binding)]
+use crate::gen::helper::serde::*;
+use crate::punctuated::Punctuated;
+use crate::*;
+use ::serde::de::{self, Deserialize, Deserializer, EnumAccess, MapAccess, SeqAccess};
+use ::serde::ser::{Serialize, SerializeStruct, Serializer};
+use proc_macro2::Span;
+use std::fmt;
+use std::result::Result;
+#[cfg(feature = "full")]
+macro_rules! full {
+    ($e:expr) => {
+        $e
+    };
+}
+#[cfg(all(feature = "derive", not(feature = "full")))]
+macro_rules! full {
+    ($e:expr) => {
+        unreachable!()
+    };
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Abi {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Abi", 1usize)?;
+        state.serialize_field("name", &self.name)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Abi {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["name"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Abi;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Abi")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _name = seq
+                    .next_element::<Option<LitStr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(Abi {
+                    extern_token: Default::default(),
+                    name: _name,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _name = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _name = Some(map.next_value::<Option<LitStr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _name = _name.unwrap_or_default();
+                Ok(Abi {
+                    extern_token: Default::default(),
+                    name: _name,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Abi", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for AngleBracketedGenericArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("AngleBracketedGenericArguments", 3usize)?;
+        state.serialize_field("colon2_token", &self.colon2_token.is_some())?;
+        state.serialize_field("args", &self.args)?;
+        state.serialize_field("args_trailing_punct", &self.args.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for AngleBracketedGenericArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["colon2_token", "args", "args_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = AngleBracketedGenericArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct AngleBracketedGenericArguments")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _colon2_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _args = seq
+                    .next_element::<Punctuated<GenericArgument, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _args_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(AngleBracketedGenericArguments {
+                    colon2_token: if _colon2_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    lt_token: Default::default(),
+                    args: trailing_punct(_args, _args_trailing_punct),
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _colon2_token = None;
+                let mut _args = None;
+                let mut _args_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _colon2_token = Some(map.next_value::<bool>()?),
+                        Some(1usize) => {
+                            _args =
+                                Some(map.next_value::<Punctuated<GenericArgument, token::Comma>>()?)
+                        }
+                        Some(2usize) => _args_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _colon2_token = _colon2_token.unwrap_or_default();
+                let _args = _args.unwrap_or_default();
+                let _args_trailing_punct = _args_trailing_punct.unwrap_or_default();
+                Ok(AngleBracketedGenericArguments {
+                    colon2_token: if _colon2_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    lt_token: Default::default(),
+                    args: trailing_punct(_args, _args_trailing_punct),
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("AngleBracketedGenericArguments", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Arm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Arm", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("guard", &self.guard.as_ref().map(|_v| &_v.1))?;
+        state.serialize_field("body", &self.body)?;
+        state.serialize_field("comma", &self.comma.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Arm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "guard", "body", "comma"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Arm;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Arm")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _pat = seq
+                    .next_element::<Pat>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _guard = seq
+                    .next_element::<Option<Box<Expr>>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _body = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _comma = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(Arm {
+                    attrs: _attrs,
+                    pat: _pat,
+                    guard: _guard.map(|_v| (Default::default(), _v)),
+                    fat_arrow_token: Default::default(),
+                    body: _body,
+                    comma: if _comma {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _pat = None;
+                let mut _guard = None;
+                let mut _body = None;
+                let mut _comma = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _pat = Some(map.next_value::<Pat>()?),
+                        Some(2usize) => _guard = Some(map.next_value::<Option<Box<Expr>>>()?),
+                        Some(3usize) => _body = Some(map.next_value::<Box<Expr>>()?),
+                        Some(4usize) => _comma = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _pat = _pat.ok_or_else(|| de::Error::missing_field("pat"))?;
+                let _guard = _guard.unwrap_or_default();
+                let _body = _body.ok_or_else(|| de::Error::missing_field("body"))?;
+                let _comma = _comma.unwrap_or_default();
+                Ok(Arm {
+                    attrs: _attrs,
+                    pat: _pat,
+                    guard: _guard.map(|_v| (Default::default(), _v)),
+                    fat_arrow_token: Default::default(),
+                    body: _body,
+                    comma: if _comma {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+        deserializer.deserialize_struct("Arm", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for AttrStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AttrStyle::Outer => serializer.serialize_unit_variant("AttrStyle", 0u32, "Outer"),
+            AttrStyle::Inner(_v0) => serializer.serialize_unit_variant("AttrStyle", 1u32, "Inner"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for AttrStyle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Outer", "Inner"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = AttrStyle;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum AttrStyle")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(AttrStyle::Outer)
+                    }
+                    1usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(AttrStyle::Inner(Default::default()))
+                    }
+                    _ => Err(unsupported_variant("AttrStyle", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("AttrStyle", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Attribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Attribute", 3usize)?;
+        state.serialize_field("style", &self.style)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("tokens", &TokensSer(&self.tokens))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Attribute {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["style", "path", "tokens"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Attribute;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Attribute")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _style = seq
+                    .next_element::<AttrStyle>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _tokens = seq
+                    .next_element::<TokensDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(Attribute {
+                    pound_token: Default::default(),
+                    style: _style,
+                    bracket_token: Default::default(),
+                    path: _path,
+                    tokens: _tokens.0,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _style = None;
+                let mut _path = None;
+                let mut _tokens = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _style = Some(map.next_value::<AttrStyle>()?),
+                        Some(1usize) => _path = Some(map.next_value::<Path>()?),
+                        Some(2usize) => _tokens = Some(map.next_value::<TokensDe>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _style = _style.ok_or_else(|| de::Error::missing_field("style"))?;
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                let _tokens = _tokens.ok_or_else(|| de::Error::missing_field("tokens"))?;
+                Ok(Attribute {
+                    pound_token: Default::default(),
+                    style: _style,
+                    bracket_token: Default::default(),
+                    path: _path,
+                    tokens: _tokens.0,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Attribute", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for BareFnArg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("BareFnArg", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("name", &self.name.as_ref().map(|_v| IdentSer(&_v.0)))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for BareFnArg {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "name", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = BareFnArg;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct BareFnArg")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _name = seq
+                    .next_element::<Option<IdentDe>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ty = seq
+                    .next_element::<Type>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(BareFnArg {
+                    attrs: _attrs,
+                    name: _name.map(|_v| (_v.0, Default::default())),
+                    ty: _ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _name = None;
+                let mut _ty = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _name = Some(map.next_value::<Option<IdentDe>>()?),
+                        Some(2usize) => _ty = Some(map.next_value::<Type>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _name = _name.unwrap_or_default();
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                Ok(BareFnArg {
+                    attrs: _attrs,
+                    name: _name.map(|_v| (_v.0, Default::default())),
+                    ty: _ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("BareFnArg", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for BinOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            BinOp::Add(_v0) => serializer.serialize_unit_variant("BinOp", 0u32, "Add"),
+            BinOp::Sub(_v0) => serializer.serialize_unit_variant("BinOp", 1u32, "Sub"),
+            BinOp::Mul(_v0) => serializer.serialize_unit_variant("BinOp", 2u32, "Mul"),
+            BinOp::Div(_v0) => serializer.serialize_unit_variant("BinOp", 3u32, "Div"),
+            BinOp::Rem(_v0) => serializer.serialize_unit_variant("BinOp", 4u32, "Rem"),
+            BinOp::And(_v0) => serializer.serialize_unit_variant("BinOp", 5u32, "And"),
+            BinOp::Or(_v0) => serializer.serialize_unit_variant("BinOp", 6u32, "Or"),
+            BinOp::BitXor(_v0) => serializer.serialize_unit_variant("BinOp", 7u32, "BitXor"),
+            BinOp::BitAnd(_v0) => serializer.serialize_unit_variant("BinOp", 8u32, "BitAnd"),
+            BinOp::BitOr(_v0) => serializer.serialize_unit_variant("BinOp", 9u32, "BitOr"),
+            BinOp::Shl(_v0) => serializer.serialize_unit_variant("BinOp", 10u32, "Shl"),
+            BinOp::Shr(_v0) => serializer.serialize_unit_variant("BinOp", 11u32, "Shr"),
+            BinOp::Eq(_v0) => serializer.serialize_unit_variant("BinOp", 12u32, "Eq"),
+            BinOp::Lt(_v0) => serializer.serialize_unit_variant("BinOp", 13u32, "Lt"),
+            BinOp::Le(_v0) => serializer.serialize_unit_variant("BinOp", 14u32, "Le"),
+            BinOp::Ne(_v0) => serializer.serialize_unit_variant("BinOp", 15u32, "Ne"),
+            BinOp::Ge(_v0) => serializer.serialize_unit_variant("BinOp", 16u32, "Ge"),
+            BinOp::Gt(_v0) => serializer.serialize_unit_variant("BinOp", 17u32, "Gt"),
+            BinOp::AddEq(_v0) => serializer.serialize_unit_variant("BinOp", 18u32, "AddEq"),
+            BinOp::SubEq(_v0) => serializer.serialize_unit_variant("BinOp", 19u32, "SubEq"),
+            BinOp::MulEq(_v0) => serializer.serialize_unit_variant("BinOp", 20u32, "MulEq"),
+            BinOp::DivEq(_v0) => serializer.serialize_unit_variant("BinOp", 21u32, "DivEq"),
+            BinOp::RemEq(_v0) => serializer.serialize_unit_variant("BinOp", 22u32, "RemEq"),
+            BinOp::BitXorEq(_v0) => serializer.serialize_unit_variant("BinOp", 23u32, "BitXorEq"),
+            BinOp::BitAndEq(_v0) => serializer.serialize_unit_variant("BinOp", 24u32, "BitAndEq"),
+            BinOp::BitOrEq(_v0) => serializer.serialize_unit_variant("BinOp", 25u32, "BitOrEq"),
+            BinOp::ShlEq(_v0) => serializer.serialize_unit_variant("BinOp", 26u32, "ShlEq"),
+            BinOp::ShrEq(_v0) => serializer.serialize_unit_variant("BinOp", 27u32, "ShrEq"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for BinOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Add", "Sub", "Mul", "Div", "Rem", "And", "Or", "BitXor", "BitAnd", "BitOr", "Shl",
+            "Shr", "Eq", "Lt", "Le", "Ne", "Ge", "Gt", "AddEq", "SubEq", "MulEq", "DivEq", "RemEq",
+            "BitXorEq", "BitAndEq", "BitOrEq", "ShlEq", "ShrEq",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = BinOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum BinOp")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Add(Default::default()))
+                    }
+                    1usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Sub(Default::default()))
+                    }
+                    2usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Mul(Default::default()))
+                    }
+                    3usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Div(Default::default()))
+                    }
+                    4usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Rem(Default::default()))
+                    }
+                    5usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::And(Default::default()))
+                    }
+                    6usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Or(Default::default()))
+                    }
+                    7usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::BitXor(Default::default()))
+                    }
+                    8usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::BitAnd(Default::default()))
+                    }
+                    9usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::BitOr(Default::default()))
+                    }
+                    10usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Shl(Default::default()))
+                    }
+                    11usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Shr(Default::default()))
+                    }
+                    12usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Eq(Default::default()))
+                    }
+                    13usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Lt(Default::default()))
+                    }
+                    14usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Le(Default::default()))
+                    }
+                    15usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Ne(Default::default()))
+                    }
+                    16usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Ge(Default::default()))
+                    }
+                    17usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::Gt(Default::default()))
+                    }
+                    18usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::AddEq(Default::default()))
+                    }
+                    19usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::SubEq(Default::default()))
+                    }
+                    20usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::MulEq(Default::default()))
+                    }
+                    21usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::DivEq(Default::default()))
+                    }
+                    22usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::RemEq(Default::default()))
+                    }
+                    23usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::BitXorEq(Default::default()))
+                    }
+                    24usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::BitAndEq(Default::default()))
+                    }
+                    25usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::BitOrEq(Default::default()))
+                    }
+                    26usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::ShlEq(Default::default()))
+                    }
+                    27usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(BinOp::ShrEq(Default::default()))
+                    }
+                    _ => Err(unsupported_variant("BinOp", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("BinOp", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Binding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Binding", 2usize)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Binding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Binding;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Binding")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _ty = seq
+                    .next_element::<Type>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(Binding {
+                    ident: _ident.0,
+                    eq_token: Default::default(),
+                    ty: _ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _ident = None;
+                let mut _ty = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(1usize) => _ty = Some(map.next_value::<Type>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                Ok(Binding {
+                    ident: _ident.0,
+                    eq_token: Default::default(),
+                    ty: _ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Binding", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Block {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Block", 1usize)?;
+        state.serialize_field("stmts", &self.stmts)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Block {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["stmts"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Block;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Block")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _stmts = seq
+                    .next_element::<Vec<Stmt>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(Block {
+                    brace_token: Default::default(),
+                    stmts: _stmts,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _stmts = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _stmts = Some(map.next_value::<Vec<Stmt>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _stmts = _stmts.unwrap_or_default();
+                Ok(Block {
+                    brace_token: Default::default(),
+                    stmts: _stmts,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Block", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for BoundLifetimes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("BoundLifetimes", 2usize)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.serialize_field("lifetimes_trailing_punct", &self.lifetimes.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for BoundLifetimes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetimes", "lifetimes_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = BoundLifetimes;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct BoundLifetimes")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _lifetimes = seq
+                    .next_element::<Punctuated<LifetimeDef, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _lifetimes_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(BoundLifetimes {
+                    for_token: Default::default(),
+                    lt_token: Default::default(),
+                    lifetimes: trailing_punct(_lifetimes, _lifetimes_trailing_punct),
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _lifetimes = None;
+                let mut _lifetimes_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _lifetimes =
+                                Some(map.next_value::<Punctuated<LifetimeDef, token::Comma>>()?)
+                        }
+                        Some(1usize) => _lifetimes_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _lifetimes = _lifetimes.unwrap_or_default();
+                let _lifetimes_trailing_punct = _lifetimes_trailing_punct.unwrap_or_default();
+                Ok(BoundLifetimes {
+                    for_token: Default::default(),
+                    lt_token: Default::default(),
+                    lifetimes: trailing_punct(_lifetimes, _lifetimes_trailing_punct),
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("BoundLifetimes", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ConstParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ConstParam", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("eq_token", &self.eq_token.is_some())?;
+        state.serialize_field("default", &self.default)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ConstParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "ty", "eq_token", "default"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ConstParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ConstParam")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ty = seq
+                    .next_element::<Type>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _eq_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _default = seq
+                    .next_element::<Option<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(ConstParam {
+                    attrs: _attrs,
+                    const_token: Default::default(),
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    eq_token: if _eq_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    default: _default,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _ident = None;
+                let mut _ty = None;
+                let mut _eq_token = None;
+                let mut _default = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(2usize) => _ty = Some(map.next_value::<Type>()?),
+                        Some(3usize) => _eq_token = Some(map.next_value::<bool>()?),
+                        Some(4usize) => _default = Some(map.next_value::<Option<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                let _eq_token = _eq_token.unwrap_or_default();
+                let _default = _default.unwrap_or_default();
+                Ok(ConstParam {
+                    attrs: _attrs,
+                    const_token: Default::default(),
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    eq_token: if _eq_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    default: _default,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ConstParam", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Constraint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Constraint", 3usize)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("bounds_trailing_punct", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Constraint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "bounds", "bounds_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Constraint;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Constraint")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _bounds = seq
+                    .next_element::<Punctuated<TypeParamBound, token::Add>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _bounds_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(Constraint {
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _ident = None;
+                let mut _bounds = None;
+                let mut _bounds_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(1usize) => {
+                            _bounds =
+                                Some(map.next_value::<Punctuated<TypeParamBound, token::Add>>()?)
+                        }
+                        Some(2usize) => _bounds_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _bounds = _bounds.unwrap_or_default();
+                let _bounds_trailing_punct = _bounds_trailing_punct.unwrap_or_default();
+                Ok(Constraint {
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Constraint", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Serialize for Data {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Data::Struct(_v0) => serializer.serialize_newtype_variant("Data", 0u32, "Struct", _v0),
+            Data::Enum(_v0) => serializer.serialize_newtype_variant("Data", 1u32, "Enum", _v0),
+            Data::Union(_v0) => serializer.serialize_newtype_variant("Data", 2u32, "Union", _v0),
+        }
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> Deserialize<'de> for Data {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Struct", "Enum", "Union"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Data;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Data")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<DataStruct>(access)?;
+                        Ok(Data::Struct(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<DataEnum>(access)?;
+                        Ok(Data::Enum(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<DataUnion>(access)?;
+                        Ok(Data::Union(_v))
+                    }
+                    _ => Err(unsupported_variant("Data", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Data", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Serialize for DataEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataEnum", 2usize)?;
+        state.serialize_field("variants", &self.variants)?;
+        state.serialize_field("variants_trailing_punct", &self.variants.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> Deserialize<'de> for DataEnum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["variants", "variants_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DataEnum;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataEnum")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _variants = seq
+                    .next_element::<Punctuated<Variant, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _variants_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(DataEnum {
+                    enum_token: Default::default(),
+                    brace_token: Default::default(),
+                    variants: trailing_punct(_variants, _variants_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _variants = None;
+                let mut _variants_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _variants = Some(map.next_value::<Punctuated<Variant, token::Comma>>()?)
+                        }
+                        Some(1usize) => _variants_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _variants = _variants.unwrap_or_default();
+                let _variants_trailing_punct = _variants_trailing_punct.unwrap_or_default();
+                Ok(DataEnum {
+                    enum_token: Default::default(),
+                    brace_token: Default::default(),
+                    variants: trailing_punct(_variants, _variants_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataEnum", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Serialize for DataStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataStruct", 2usize)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> Deserialize<'de> for DataStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["fields", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DataStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _fields = seq
+                    .next_element::<Fields>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _semi_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(DataStruct {
+                    struct_token: Default::default(),
+                    fields: _fields,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _fields = None;
+                let mut _semi_token = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _fields = Some(map.next_value::<Fields>()?),
+                        Some(1usize) => _semi_token = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _fields = _fields.ok_or_else(|| de::Error::missing_field("fields"))?;
+                let _semi_token = _semi_token.unwrap_or_default();
+                Ok(DataStruct {
+                    struct_token: Default::default(),
+                    fields: _fields,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Serialize for DataUnion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataUnion", 1usize)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> Deserialize<'de> for DataUnion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["fields"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DataUnion;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataUnion")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _fields = seq
+                    .next_element::<FieldsNamed>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(DataUnion {
+                    union_token: Default::default(),
+                    fields: _fields,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _fields = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _fields = Some(map.next_value::<FieldsNamed>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _fields = _fields.ok_or_else(|| de::Error::missing_field("fields"))?;
+                Ok(DataUnion {
+                    union_token: Default::default(),
+                    fields: _fields,
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataUnion", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Serialize for DeriveInput {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DeriveInput", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("data", &self.data)?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> Deserialize<'de> for DeriveInput {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "data"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DeriveInput;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DeriveInput")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _generics = seq
+                    .next_element::<Generics>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _data = seq
+                    .next_element::<Data>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(DeriveInput {
+                    attrs: _attrs,
+                    vis: _vis,
+                    ident: _ident.0,
+                    generics: _generics,
+                    data: _data,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                let mut _generics = None;
+                let mut _data = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(3usize) => _generics = Some(map.next_value::<Generics>()?),
+                        Some(4usize) => _data = Some(map.next_value::<Data>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _generics = _generics.ok_or_else(|| de::Error::missing_field("generics"))?;
+                let _data = _data.ok_or_else(|| de::Error::missing_field("data"))?;
+                Ok(DeriveInput {
+                    attrs: _attrs,
+                    vis: _vis,
+                    ident: _ident.0,
+                    generics: _generics,
+                    data: _data,
+                })
+            }
+        }
+        deserializer.deserialize_struct("DeriveInput", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Expr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Expr::Array(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 0u32, "Array", _v0))
+            }
+            Expr::Assign(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 1u32, "Assign", _v0))
+            }
+            Expr::AssignOp(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 2u32, "AssignOp", _v0))
+            }
+            Expr::Async(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 3u32, "Async", _v0))
+            }
+            Expr::Await(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 4u32, "Await", _v0))
+            }
+            Expr::Binary(_v0) => serializer.serialize_newtype_variant("Expr", 5u32, "Binary", _v0),
+            Expr::Block(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 6u32, "Block", _v0))
+            }
+            Expr::Box(_v0) => full!(serializer.serialize_newtype_variant("Expr", 7u32, "Box", _v0)),
+            Expr::Break(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 8u32, "Break", _v0))
+            }
+            Expr::Call(_v0) => serializer.serialize_newtype_variant("Expr", 9u32, "Call", _v0),
+            Expr::Cast(_v0) => serializer.serialize_newtype_variant("Expr", 10u32, "Cast", _v0),
+            Expr::Closure(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 11u32, "Closure", _v0))
+            }
+            Expr::Continue(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 12u32, "Continue", _v0))
+            }
+            Expr::Field(_v0) => serializer.serialize_newtype_variant("Expr", 13u32, "Field", _v0),
+            Expr::ForLoop(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 14u32, "ForLoop", _v0))
+            }
+            Expr::Group(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 15u32, "Group", _v0))
+            }
+            Expr::If(_v0) => full!(serializer.serialize_newtype_variant("Expr", 16u32, "If", _v0)),
+            Expr::Index(_v0) => serializer.serialize_newtype_variant("Expr", 17u32, "Index", _v0),
+            Expr::Let(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 18u32, "Let", _v0))
+            }
+            Expr::Lit(_v0) => serializer.serialize_newtype_variant("Expr", 19u32, "Lit", _v0),
+            Expr::Loop(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 20u32, "Loop", _v0))
+            }
+            Expr::Macro(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 21u32, "Macro", _v0))
+            }
+            Expr::Match(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 22u32, "Match", _v0))
+            }
+            Expr::MethodCall(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 23u32, "MethodCall", _v0))
+            }
+            Expr::Paren(_v0) => serializer.serialize_newtype_variant("Expr", 24u32, "Paren", _v0),
+            Expr::Path(_v0) => serializer.serialize_newtype_variant("Expr", 25u32, "Path", _v0),
+            Expr::Range(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 26u32, "Range", _v0))
+            }
+            Expr::Reference(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 27u32, "Reference", _v0))
+            }
+            Expr::Repeat(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 28u32, "Repeat", _v0))
+            }
+            Expr::Return(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 29u32, "Return", _v0))
+            }
+            Expr::Struct(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 30u32, "Struct", _v0))
+            }
+            Expr::Try(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 31u32, "Try", _v0))
+            }
+            Expr::TryBlock(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 32u32, "TryBlock", _v0))
+            }
+            Expr::Tuple(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 33u32, "Tuple", _v0))
+            }
+            Expr::Type(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 34u32, "Type", _v0))
+            }
+            Expr::Unary(_v0) => serializer.serialize_newtype_variant("Expr", 35u32, "Unary", _v0),
+            Expr::Unsafe(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 36u32, "Unsafe", _v0))
+            }
+            Expr::Verbatim(_v0) => {
+                serializer.serialize_newtype_variant("Expr", 37u32, "Verbatim", &TokensSer(_v0))
+            }
+            Expr::While(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 38u32, "While", _v0))
+            }
+            Expr::Yield(_v0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 39u32, "Yield", _v0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Expr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Array",
+            "Assign",
+            "AssignOp",
+            "Async",
+            "Await",
+            "Binary",
+            "Block",
+            "Box",
+            "Break",
+            "Call",
+            "Cast",
+            "Closure",
+            "Continue",
+            "Field",
+            "ForLoop",
+            "Group",
+            "If",
+            "Index",
+            "Let",
+            "Lit",
+            "Loop",
+            "Macro",
+            "Match",
+            "MethodCall",
+            "Paren",
+            "Path",
+            "Range",
+            "Reference",
+            "Repeat",
+            "Return",
+            "Struct",
+            "Try",
+            "TryBlock",
+            "Tuple",
+            "Type",
+            "Unary",
+            "Unsafe",
+            "Verbatim",
+            "While",
+            "Yield",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Expr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Expr")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    #[cfg(feature = "full")]
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprArray>(access)?;
+                        Ok(Expr::Array(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprAssign>(access)?;
+                        Ok(Expr::Assign(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprAssignOp>(access)?;
+                        Ok(Expr::AssignOp(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    3usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprAsync>(access)?;
+                        Ok(Expr::Async(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    4usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprAwait>(access)?;
+                        Ok(Expr::Await(_v))
+                    }
+                    5usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprBinary>(access)?;
+                        Ok(Expr::Binary(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    6usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprBlock>(access)?;
+                        Ok(Expr::Block(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    7usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprBox>(access)?;
+                        Ok(Expr::Box(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    8usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprBreak>(access)?;
+                        Ok(Expr::Break(_v))
+                    }
+                    9usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprCall>(access)?;
+                        Ok(Expr::Call(_v))
+                    }
+                    10usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprCast>(access)?;
+                        Ok(Expr::Cast(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    11usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprClosure>(access)?;
+                        Ok(Expr::Closure(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    12usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprContinue>(access)?;
+                        Ok(Expr::Continue(_v))
+                    }
+                    13usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprField>(access)?;
+                        Ok(Expr::Field(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    14usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprForLoop>(access)?;
+                        Ok(Expr::ForLoop(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    15usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprGroup>(access)?;
+                        Ok(Expr::Group(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    16usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprIf>(access)?;
+                        Ok(Expr::If(_v))
+                    }
+                    17usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprIndex>(access)?;
+                        Ok(Expr::Index(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    18usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprLet>(access)?;
+                        Ok(Expr::Let(_v))
+                    }
+                    19usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprLit>(access)?;
+                        Ok(Expr::Lit(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    20usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprLoop>(access)?;
+                        Ok(Expr::Loop(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    21usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprMacro>(access)?;
+                        Ok(Expr::Macro(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    22usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprMatch>(access)?;
+                        Ok(Expr::Match(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    23usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprMethodCall>(access)?;
+                        Ok(Expr::MethodCall(_v))
+                    }
+                    24usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprParen>(access)?;
+                        Ok(Expr::Paren(_v))
+                    }
+                    25usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprPath>(access)?;
+                        Ok(Expr::Path(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    26usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprRange>(access)?;
+                        Ok(Expr::Range(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    27usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprReference>(access)?;
+                        Ok(Expr::Reference(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    28usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprRepeat>(access)?;
+                        Ok(Expr::Repeat(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    29usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprReturn>(access)?;
+                        Ok(Expr::Return(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    30usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprStruct>(access)?;
+                        Ok(Expr::Struct(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    31usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprTry>(access)?;
+                        Ok(Expr::Try(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    32usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprTryBlock>(access)?;
+                        Ok(Expr::TryBlock(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    33usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprTuple>(access)?;
+                        Ok(Expr::Tuple(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    34usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprType>(access)?;
+                        Ok(Expr::Type(_v))
+                    }
+                    35usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprUnary>(access)?;
+                        Ok(Expr::Unary(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    36usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprUnsafe>(access)?;
+                        Ok(Expr::Unsafe(_v))
+                    }
+                    37usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TokensDe>(access)?;
+                        Ok(Expr::Verbatim(_v.0))
+                    }
+                    #[cfg(feature = "full")]
+                    38usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprWhile>(access)?;
+                        Ok(Expr::While(_v))
+                    }
+                    #[cfg(feature = "full")]
+                    39usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ExprYield>(access)?;
+                        Ok(Expr::Yield(_v))
+                    }
+                    _ => Err(unsupported_variant("Expr", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Expr", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprArray", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.serialize_field("elems_trailing_punct", &self.elems.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems", "elems_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprArray;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprArray")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _elems = seq
+                    .next_element::<Punctuated<Expr, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _elems_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprArray {
+                    attrs: _attrs,
+                    bracket_token: Default::default(),
+                    elems: trailing_punct(_elems, _elems_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _elems = None;
+                let mut _elems_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => {
+                            _elems = Some(map.next_value::<Punctuated<Expr, token::Comma>>()?)
+                        }
+                        Some(2usize) => _elems_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _elems = _elems.unwrap_or_default();
+                let _elems_trailing_punct = _elems_trailing_punct.unwrap_or_default();
+                Ok(ExprArray {
+                    attrs: _attrs,
+                    bracket_token: Default::default(),
+                    elems: trailing_punct(_elems, _elems_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprArray", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprAssign {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAssign", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("left", &self.left)?;
+        state.serialize_field("right", &self.right)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprAssign {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "right"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAssign;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAssign")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _left = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _right = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprAssign {
+                    attrs: _attrs,
+                    left: _left,
+                    eq_token: Default::default(),
+                    right: _right,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _left = None;
+                let mut _right = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _left = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _right = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _left = _left.ok_or_else(|| de::Error::missing_field("left"))?;
+                let _right = _right.ok_or_else(|| de::Error::missing_field("right"))?;
+                Ok(ExprAssign {
+                    attrs: _attrs,
+                    left: _left,
+                    eq_token: Default::default(),
+                    right: _right,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAssign", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprAssignOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAssignOp", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("left", &self.left)?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("right", &self.right)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprAssignOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "op", "right"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAssignOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAssignOp")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _left = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _op = seq
+                    .next_element::<BinOp>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _right = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(ExprAssignOp {
+                    attrs: _attrs,
+                    left: _left,
+                    op: _op,
+                    right: _right,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _left = None;
+                let mut _op = None;
+                let mut _right = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _left = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _op = Some(map.next_value::<BinOp>()?),
+                        Some(3usize) => _right = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _left = _left.ok_or_else(|| de::Error::missing_field("left"))?;
+                let _op = _op.ok_or_else(|| de::Error::missing_field("op"))?;
+                let _right = _right.ok_or_else(|| de::Error::missing_field("right"))?;
+                Ok(ExprAssignOp {
+                    attrs: _attrs,
+                    left: _left,
+                    op: _op,
+                    right: _right,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAssignOp", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprAsync {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAsync", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("capture", &self.capture.is_some())?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprAsync {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "capture", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAsync;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAsync")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _capture = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _block = seq
+                    .next_element::<Block>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprAsync {
+                    attrs: _attrs,
+                    async_token: Default::default(),
+                    capture: if _capture {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    block: _block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _capture = None;
+                let mut _block = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _capture = Some(map.next_value::<bool>()?),
+                        Some(2usize) => _block = Some(map.next_value::<Block>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _capture = _capture.unwrap_or_default();
+                let _block = _block.ok_or_else(|| de::Error::missing_field("block"))?;
+                Ok(ExprAsync {
+                    attrs: _attrs,
+                    async_token: Default::default(),
+                    capture: if _capture {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    block: _block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAsync", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprAwait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAwait", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("base", &self.base)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprAwait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "base"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAwait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAwait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _base = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprAwait {
+                    attrs: _attrs,
+                    base: _base,
+                    dot_token: Default::default(),
+                    await_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _base = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _base = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _base = _base.ok_or_else(|| de::Error::missing_field("base"))?;
+                Ok(ExprAwait {
+                    attrs: _attrs,
+                    base: _base,
+                    dot_token: Default::default(),
+                    await_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAwait", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprBinary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBinary", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("left", &self.left)?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("right", &self.right)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprBinary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "op", "right"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBinary;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBinary")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _left = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _op = seq
+                    .next_element::<BinOp>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _right = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(ExprBinary {
+                    attrs: _attrs,
+                    left: _left,
+                    op: _op,
+                    right: _right,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _left = None;
+                let mut _op = None;
+                let mut _right = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _left = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _op = Some(map.next_value::<BinOp>()?),
+                        Some(3usize) => _right = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _left = _left.ok_or_else(|| de::Error::missing_field("left"))?;
+                let _op = _op.ok_or_else(|| de::Error::missing_field("op"))?;
+                let _right = _right.ok_or_else(|| de::Error::missing_field("right"))?;
+                Ok(ExprBinary {
+                    attrs: _attrs,
+                    left: _left,
+                    op: _op,
+                    right: _right,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBinary", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBlock", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBlock;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBlock")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _label = seq
+                    .next_element::<Option<Label>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _block = seq
+                    .next_element::<Block>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprBlock {
+                    attrs: _attrs,
+                    label: _label,
+                    block: _block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _label = None;
+                let mut _block = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _label = Some(map.next_value::<Option<Label>>()?),
+                        Some(2usize) => _block = Some(map.next_value::<Block>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _label = _label.unwrap_or_default();
+                let _block = _block.ok_or_else(|| de::Error::missing_field("block"))?;
+                Ok(ExprBlock {
+                    attrs: _attrs,
+                    label: _label,
+                    block: _block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBlock", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprBox {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBox", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprBox {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBox;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBox")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprBox {
+                    attrs: _attrs,
+                    box_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(ExprBox {
+                    attrs: _attrs,
+                    box_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBox", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprBreak {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBreak", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprBreak {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBreak;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBreak")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _label = seq
+                    .next_element::<Option<Lifetime>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _expr = seq
+                    .next_element::<Option<Box<Expr>>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprBreak {
+                    attrs: _attrs,
+                    break_token: Default::default(),
+                    label: _label,
+                    expr: _expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _label = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _label = Some(map.next_value::<Option<Lifetime>>()?),
+                        Some(2usize) => _expr = Some(map.next_value::<Option<Box<Expr>>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _label = _label.unwrap_or_default();
+                let _expr = _expr.unwrap_or_default();
+                Ok(ExprBreak {
+                    attrs: _attrs,
+                    break_token: Default::default(),
+                    label: _label,
+                    expr: _expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBreak", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprCall {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprCall", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("func", &self.func)?;
+        state.serialize_field("args", &self.args)?;
+        state.serialize_field("args_trailing_punct", &self.args.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprCall {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "func", "args", "args_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprCall;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprCall")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _func = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _args = seq
+                    .next_element::<Punctuated<Expr, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _args_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(ExprCall {
+                    attrs: _attrs,
+                    func: _func,
+                    paren_token: Default::default(),
+                    args: trailing_punct(_args, _args_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _func = None;
+                let mut _args = None;
+                let mut _args_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _func = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => {
+                            _args = Some(map.next_value::<Punctuated<Expr, token::Comma>>()?)
+                        }
+                        Some(3usize) => _args_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _func = _func.ok_or_else(|| de::Error::missing_field("func"))?;
+                let _args = _args.unwrap_or_default();
+                let _args_trailing_punct = _args_trailing_punct.unwrap_or_default();
+                Ok(ExprCall {
+                    attrs: _attrs,
+                    func: _func,
+                    paren_token: Default::default(),
+                    args: trailing_punct(_args, _args_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprCall", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprCast {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprCast", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprCast {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprCast;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprCast")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ty = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprCast {
+                    attrs: _attrs,
+                    expr: _expr,
+                    as_token: Default::default(),
+                    ty: _ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                let mut _ty = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _ty = Some(map.next_value::<Box<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                Ok(ExprCast {
+                    attrs: _attrs,
+                    expr: _expr,
+                    as_token: Default::default(),
+                    ty: _ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprCast", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprClosure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprClosure", 8usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("asyncness", &self.asyncness.is_some())?;
+        state.serialize_field("movability", &self.movability.is_some())?;
+        state.serialize_field("capture", &self.capture.is_some())?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("inputs_trailing_punct", &self.inputs.trailing_punct())?;
+        state.serialize_field("output", &self.output)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprClosure {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "asyncness",
+            "movability",
+            "capture",
+            "inputs",
+            "inputs_trailing_punct",
+            "output",
+            "body",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprClosure;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprClosure")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _asyncness = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _movability = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _capture = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _inputs = seq
+                    .next_element::<Punctuated<Pat, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _inputs_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                let _output = seq
+                    .next_element::<ReturnType>()?
+                    .ok_or_else(|| de::Error::invalid_length(6usize, &self))?;
+                let _body = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(7usize, &self))?;
+                Ok(ExprClosure {
+                    attrs: _attrs,
+                    asyncness: if _asyncness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    movability: if _movability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    capture: if _capture {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    or1_token: Default::default(),
+                    inputs: trailing_punct(_inputs, _inputs_trailing_punct),
+                    or2_token: Default::default(),
+                    output: _output,
+                    body: _body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _asyncness = None;
+                let mut _movability = None;
+                let mut _capture = None;
+                let mut _inputs = None;
+                let mut _inputs_trailing_punct = None;
+                let mut _output = None;
+                let mut _body = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _asyncness = Some(map.next_value::<bool>()?),
+                        Some(2usize) => _movability = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _capture = Some(map.next_value::<bool>()?),
+                        Some(4usize) => {
+                            _inputs = Some(map.next_value::<Punctuated<Pat, token::Comma>>()?)
+                        }
+                        Some(5usize) => _inputs_trailing_punct = Some(map.next_value::<bool>()?),
+                        Some(6usize) => _output = Some(map.next_value::<ReturnType>()?),
+                        Some(7usize) => _body = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _asyncness = _asyncness.unwrap_or_default();
+                let _movability = _movability.unwrap_or_default();
+                let _capture = _capture.unwrap_or_default();
+                let _inputs = _inputs.unwrap_or_default();
+                let _inputs_trailing_punct = _inputs_trailing_punct.unwrap_or_default();
+                let _output = _output.ok_or_else(|| de::Error::missing_field("output"))?;
+                let _body = _body.ok_or_else(|| de::Error::missing_field("body"))?;
+                Ok(ExprClosure {
+                    attrs: _attrs,
+                    asyncness: if _asyncness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    movability: if _movability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    capture: if _capture {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    or1_token: Default::default(),
+                    inputs: trailing_punct(_inputs, _inputs_trailing_punct),
+                    or2_token: Default::default(),
+                    output: _output,
+                    body: _body,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprClosure", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprContinue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprContinue", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprContinue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprContinue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprContinue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _label = seq
+                    .next_element::<Option<Lifetime>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprContinue {
+                    attrs: _attrs,
+                    continue_token: Default::default(),
+                    label: _label,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _label = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _label = Some(map.next_value::<Option<Lifetime>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _label = _label.unwrap_or_default();
+                Ok(ExprContinue {
+                    attrs: _attrs,
+                    continue_token: Default::default(),
+                    label: _label,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprContinue", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprField", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field("member", &self.member)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "base", "member"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprField;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprField")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _base = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _member = seq
+                    .next_element::<Member>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprField {
+                    attrs: _attrs,
+                    base: _base,
+                    dot_token: Default::default(),
+                    member: _member,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _base = None;
+                let mut _member = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _base = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _member = Some(map.next_value::<Member>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _base = _base.ok_or_else(|| de::Error::missing_field("base"))?;
+                let _member = _member.ok_or_else(|| de::Error::missing_field("member"))?;
+                Ok(ExprField {
+                    attrs: _attrs,
+                    base: _base,
+                    dot_token: Default::default(),
+                    member: _member,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprField", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprForLoop {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprForLoop", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprForLoop {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "pat", "expr", "body"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprForLoop;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprForLoop")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _label = seq
+                    .next_element::<Option<Label>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _pat = seq
+                    .next_element::<Pat>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _body = seq
+                    .next_element::<Block>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(ExprForLoop {
+                    attrs: _attrs,
+                    label: _label,
+                    for_token: Default::default(),
+                    pat: _pat,
+                    in_token: Default::default(),
+                    expr: _expr,
+                    body: _body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _label = None;
+                let mut _pat = None;
+                let mut _expr = None;
+                let mut _body = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _label = Some(map.next_value::<Option<Label>>()?),
+                        Some(2usize) => _pat = Some(map.next_value::<Pat>()?),
+                        Some(3usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        Some(4usize) => _body = Some(map.next_value::<Block>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _label = _label.unwrap_or_default();
+                let _pat = _pat.ok_or_else(|| de::Error::missing_field("pat"))?;
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                let _body = _body.ok_or_else(|| de::Error::missing_field("body"))?;
+                Ok(ExprForLoop {
+                    attrs: _attrs,
+                    label: _label,
+                    for_token: Default::default(),
+                    pat: _pat,
+                    in_token: Default::default(),
+                    expr: _expr,
+                    body: _body,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprForLoop", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprGroup", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprGroup {
+                    attrs: _attrs,
+                    group_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(ExprGroup {
+                    attrs: _attrs,
+                    group_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprGroup", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprIf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprIf", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("cond", &self.cond)?;
+        state.serialize_field("then_branch", &self.then_branch)?;
+        state.serialize_field("else_branch", &self.else_branch.as_ref().map(|_v| &_v.1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprIf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "cond", "then_branch", "else_branch"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprIf;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprIf")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _cond = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _then_branch = seq
+                    .next_element::<Block>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _else_branch = seq
+                    .next_element::<Option<Box<Expr>>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(ExprIf {
+                    attrs: _attrs,
+                    if_token: Default::default(),
+                    cond: _cond,
+                    then_branch: _then_branch,
+                    else_branch: _else_branch.map(|_v| (Default::default(), _v)),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _cond = None;
+                let mut _then_branch = None;
+                let mut _else_branch = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _cond = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _then_branch = Some(map.next_value::<Block>()?),
+                        Some(3usize) => _else_branch = Some(map.next_value::<Option<Box<Expr>>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _cond = _cond.ok_or_else(|| de::Error::missing_field("cond"))?;
+                let _then_branch =
+                    _then_branch.ok_or_else(|| de::Error::missing_field("then_branch"))?;
+                let _else_branch = _else_branch.unwrap_or_default();
+                Ok(ExprIf {
+                    attrs: _attrs,
+                    if_token: Default::default(),
+                    cond: _cond,
+                    then_branch: _then_branch,
+                    else_branch: _else_branch.map(|_v| (Default::default(), _v)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprIf", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprIndex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprIndex", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("index", &self.index)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprIndex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "index"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprIndex;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprIndex")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _index = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprIndex {
+                    attrs: _attrs,
+                    expr: _expr,
+                    bracket_token: Default::default(),
+                    index: _index,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                let mut _index = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _index = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                let _index = _index.ok_or_else(|| de::Error::missing_field("index"))?;
+                Ok(ExprIndex {
+                    attrs: _attrs,
+                    expr: _expr,
+                    bracket_token: Default::default(),
+                    index: _index,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprIndex", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprLet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLet", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprLet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprLet;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLet")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _pat = seq
+                    .next_element::<Pat>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprLet {
+                    attrs: _attrs,
+                    let_token: Default::default(),
+                    pat: _pat,
+                    eq_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _pat = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _pat = Some(map.next_value::<Pat>()?),
+                        Some(2usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _pat = _pat.ok_or_else(|| de::Error::missing_field("pat"))?;
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(ExprLet {
+                    attrs: _attrs,
+                    let_token: Default::default(),
+                    pat: _pat,
+                    eq_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprLet", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprLit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLit", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("lit", &self.lit)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprLit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "lit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprLit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLit")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _lit = seq
+                    .next_element::<Lit>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprLit {
+                    attrs: _attrs,
+                    lit: _lit,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _lit = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _lit = Some(map.next_value::<Lit>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _lit = _lit.ok_or_else(|| de::Error::missing_field("lit"))?;
+                Ok(ExprLit {
+                    attrs: _attrs,
+                    lit: _lit,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprLit", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprLoop {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLoop", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprLoop {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "body"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprLoop;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLoop")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _label = seq
+                    .next_element::<Option<Label>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _body = seq
+                    .next_element::<Block>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprLoop {
+                    attrs: _attrs,
+                    label: _label,
+                    loop_token: Default::default(),
+                    body: _body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _label = None;
+                let mut _body = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _label = Some(map.next_value::<Option<Label>>()?),
+                        Some(2usize) => _body = Some(map.next_value::<Block>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _label = _label.unwrap_or_default();
+                let _body = _body.ok_or_else(|| de::Error::missing_field("body"))?;
+                Ok(ExprLoop {
+                    attrs: _attrs,
+                    label: _label,
+                    loop_token: Default::default(),
+                    body: _body,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprLoop", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMacro", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _mac = seq
+                    .next_element::<Macro>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprMacro {
+                    attrs: _attrs,
+                    mac: _mac,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _mac = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _mac = Some(map.next_value::<Macro>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _mac = _mac.ok_or_else(|| de::Error::missing_field("mac"))?;
+                Ok(ExprMacro {
+                    attrs: _attrs,
+                    mac: _mac,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMatch", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("arms", &self.arms)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprMatch {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "arms"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprMatch;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMatch")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _arms = seq
+                    .next_element::<Vec<Arm>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprMatch {
+                    attrs: _attrs,
+                    match_token: Default::default(),
+                    expr: _expr,
+                    brace_token: Default::default(),
+                    arms: _arms,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                let mut _arms = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _arms = Some(map.next_value::<Vec<Arm>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                let _arms = _arms.unwrap_or_default();
+                Ok(ExprMatch {
+                    attrs: _attrs,
+                    match_token: Default::default(),
+                    expr: _expr,
+                    brace_token: Default::default(),
+                    arms: _arms,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprMatch", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprMethodCall {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMethodCall", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("receiver", &self.receiver)?;
+        state.serialize_field("method", &IdentSer(&self.method))?;
+        state.serialize_field("turbofish", &self.turbofish)?;
+        state.serialize_field("args", &self.args)?;
+        state.serialize_field("args_trailing_punct", &self.args.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprMethodCall {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "receiver",
+            "method",
+            "turbofish",
+            "args",
+            "args_trailing_punct",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprMethodCall;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMethodCall")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _receiver = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _method = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _turbofish = seq
+                    .next_element::<Option<MethodTurbofish>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _args = seq
+                    .next_element::<Punctuated<Expr, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _args_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                Ok(ExprMethodCall {
+                    attrs: _attrs,
+                    receiver: _receiver,
+                    dot_token: Default::default(),
+                    method: _method.0,
+                    turbofish: _turbofish,
+                    paren_token: Default::default(),
+                    args: trailing_punct(_args, _args_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _receiver = None;
+                let mut _method = None;
+                let mut _turbofish = None;
+                let mut _args = None;
+                let mut _args_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _receiver = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _method = Some(map.next_value::<IdentDe>()?),
+                        Some(3usize) => {
+                            _turbofish = Some(map.next_value::<Option<MethodTurbofish>>()?)
+                        }
+                        Some(4usize) => {
+                            _args = Some(map.next_value::<Punctuated<Expr, token::Comma>>()?)
+                        }
+                        Some(5usize) => _args_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _receiver = _receiver.ok_or_else(|| de::Error::missing_field("receiver"))?;
+                let _method = _method.ok_or_else(|| de::Error::missing_field("method"))?;
+                let _turbofish = _turbofish.unwrap_or_default();
+                let _args = _args.unwrap_or_default();
+                let _args_trailing_punct = _args_trailing_punct.unwrap_or_default();
+                Ok(ExprMethodCall {
+                    attrs: _attrs,
+                    receiver: _receiver,
+                    dot_token: Default::default(),
+                    method: _method.0,
+                    turbofish: _turbofish,
+                    paren_token: Default::default(),
+                    args: trailing_punct(_args, _args_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprMethodCall", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprParen {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprParen", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprParen {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprParen;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprParen")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprParen {
+                    attrs: _attrs,
+                    paren_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(ExprParen {
+                    attrs: _attrs,
+                    paren_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprParen", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprPath", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("qself", &self.qself)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "qself", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprPath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprPath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _qself = seq
+                    .next_element::<Option<QSelf>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprPath {
+                    attrs: _attrs,
+                    qself: _qself,
+                    path: _path,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _qself = None;
+                let mut _path = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _qself = Some(map.next_value::<Option<QSelf>>()?),
+                        Some(2usize) => _path = Some(map.next_value::<Path>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _qself = _qself.unwrap_or_default();
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                Ok(ExprPath {
+                    attrs: _attrs,
+                    qself: _qself,
+                    path: _path,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprPath", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprRange", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("from", &self.from)?;
+        state.serialize_field("limits", &self.limits)?;
+        state.serialize_field("to", &self.to)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "from", "limits", "to"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprRange;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprRange")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _from = seq
+                    .next_element::<Option<Box<Expr>>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _limits = seq
+                    .next_element::<RangeLimits>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _to = seq
+                    .next_element::<Option<Box<Expr>>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(ExprRange {
+                    attrs: _attrs,
+                    from: _from,
+                    limits: _limits,
+                    to: _to,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _from = None;
+                let mut _limits = None;
+                let mut _to = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _from = Some(map.next_value::<Option<Box<Expr>>>()?),
+                        Some(2usize) => _limits = Some(map.next_value::<RangeLimits>()?),
+                        Some(3usize) => _to = Some(map.next_value::<Option<Box<Expr>>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _from = _from.unwrap_or_default();
+                let _limits = _limits.ok_or_else(|| de::Error::missing_field("limits"))?;
+                let _to = _to.unwrap_or_default();
+                Ok(ExprRange {
+                    attrs: _attrs,
+                    from: _from,
+                    limits: _limits,
+                    to: _to,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprRange", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprReference", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mutability", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _mutability = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprReference {
+                    attrs: _attrs,
+                    and_token: Default::default(),
+                    raw: Default::default(),
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    expr: _expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _mutability = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _mutability = Some(map.next_value::<bool>()?),
+                        Some(2usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _mutability = _mutability.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(ExprReference {
+                    attrs: _attrs,
+                    and_token: Default::default(),
+                    raw: Default::default(),
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    expr: _expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprReference", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprRepeat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprRepeat", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("len", &self.len)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprRepeat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "len"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprRepeat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprRepeat")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _len = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprRepeat {
+                    attrs: _attrs,
+                    bracket_token: Default::default(),
+                    expr: _expr,
+                    semi_token: Default::default(),
+                    len: _len,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                let mut _len = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _len = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                let _len = _len.ok_or_else(|| de::Error::missing_field("len"))?;
+                Ok(ExprRepeat {
+                    attrs: _attrs,
+                    bracket_token: Default::default(),
+                    expr: _expr,
+                    semi_token: Default::default(),
+                    len: _len,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprRepeat", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprReturn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprReturn", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprReturn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprReturn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprReturn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Option<Box<Expr>>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprReturn {
+                    attrs: _attrs,
+                    return_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Option<Box<Expr>>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.unwrap_or_default();
+                Ok(ExprReturn {
+                    attrs: _attrs,
+                    return_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprReturn", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprStruct", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("fields_trailing_punct", &self.fields.trailing_punct())?;
+        state.serialize_field("dot2_token", &self.dot2_token.is_some())?;
+        state.serialize_field("rest", &self.rest)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "path",
+            "fields",
+            "fields_trailing_punct",
+            "dot2_token",
+            "rest",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _fields = seq
+                    .next_element::<Punctuated<FieldValue, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _fields_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _dot2_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _rest = seq
+                    .next_element::<Option<Box<Expr>>>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                Ok(ExprStruct {
+                    attrs: _attrs,
+                    path: _path,
+                    brace_token: Default::default(),
+                    fields: trailing_punct(_fields, _fields_trailing_punct),
+                    dot2_token: if _dot2_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    rest: _rest,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _path = None;
+                let mut _fields = None;
+                let mut _fields_trailing_punct = None;
+                let mut _dot2_token = None;
+                let mut _rest = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _path = Some(map.next_value::<Path>()?),
+                        Some(2usize) => {
+                            _fields =
+                                Some(map.next_value::<Punctuated<FieldValue, token::Comma>>()?)
+                        }
+                        Some(3usize) => _fields_trailing_punct = Some(map.next_value::<bool>()?),
+                        Some(4usize) => _dot2_token = Some(map.next_value::<bool>()?),
+                        Some(5usize) => _rest = Some(map.next_value::<Option<Box<Expr>>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                let _fields = _fields.unwrap_or_default();
+                let _fields_trailing_punct = _fields_trailing_punct.unwrap_or_default();
+                let _dot2_token = _dot2_token.unwrap_or_default();
+                let _rest = _rest.unwrap_or_default();
+                Ok(ExprStruct {
+                    attrs: _attrs,
+                    path: _path,
+                    brace_token: Default::default(),
+                    fields: trailing_punct(_fields, _fields_trailing_punct),
+                    dot2_token: if _dot2_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    rest: _rest,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprTry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTry", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprTry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprTry;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTry")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprTry {
+                    attrs: _attrs,
+                    expr: _expr,
+                    question_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(ExprTry {
+                    attrs: _attrs,
+                    expr: _expr,
+                    question_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTry", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprTryBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTryBlock", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprTryBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprTryBlock;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTryBlock")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _block = seq
+                    .next_element::<Block>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprTryBlock {
+                    attrs: _attrs,
+                    try_token: Default::default(),
+                    block: _block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _block = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _block = Some(map.next_value::<Block>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _block = _block.ok_or_else(|| de::Error::missing_field("block"))?;
+                Ok(ExprTryBlock {
+                    attrs: _attrs,
+                    try_token: Default::default(),
+                    block: _block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTryBlock", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTuple", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.serialize_field("elems_trailing_punct", &self.elems.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems", "elems_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _elems = seq
+                    .next_element::<Punctuated<Expr, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _elems_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprTuple {
+                    attrs: _attrs,
+                    paren_token: Default::default(),
+                    elems: trailing_punct(_elems, _elems_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _elems = None;
+                let mut _elems_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => {
+                            _elems = Some(map.next_value::<Punctuated<Expr, token::Comma>>()?)
+                        }
+                        Some(2usize) => _elems_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _elems = _elems.unwrap_or_default();
+                let _elems_trailing_punct = _elems_trailing_punct.unwrap_or_default();
+                Ok(ExprTuple {
+                    attrs: _attrs,
+                    paren_token: Default::default(),
+                    elems: trailing_punct(_elems, _elems_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTuple", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprType", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ty = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprType {
+                    attrs: _attrs,
+                    expr: _expr,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                let mut _ty = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _ty = Some(map.next_value::<Box<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                Ok(ExprType {
+                    attrs: _attrs,
+                    expr: _expr,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprType", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprUnary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprUnary", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprUnary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "op", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprUnary;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprUnary")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _op = seq
+                    .next_element::<UnOp>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ExprUnary {
+                    attrs: _attrs,
+                    op: _op,
+                    expr: _expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _op = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _op = Some(map.next_value::<UnOp>()?),
+                        Some(2usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _op = _op.ok_or_else(|| de::Error::missing_field("op"))?;
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(ExprUnary {
+                    attrs: _attrs,
+                    op: _op,
+                    expr: _expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprUnary", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprUnsafe {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprUnsafe", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprUnsafe {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprUnsafe;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprUnsafe")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _block = seq
+                    .next_element::<Block>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprUnsafe {
+                    attrs: _attrs,
+                    unsafe_token: Default::default(),
+                    block: _block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _block = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _block = Some(map.next_value::<Block>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _block = _block.ok_or_else(|| de::Error::missing_field("block"))?;
+                Ok(ExprUnsafe {
+                    attrs: _attrs,
+                    unsafe_token: Default::default(),
+                    block: _block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprUnsafe", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprWhile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprWhile", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("cond", &self.cond)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprWhile {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "cond", "body"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprWhile;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprWhile")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _label = seq
+                    .next_element::<Option<Label>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _cond = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _body = seq
+                    .next_element::<Block>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(ExprWhile {
+                    attrs: _attrs,
+                    label: _label,
+                    while_token: Default::default(),
+                    cond: _cond,
+                    body: _body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _label = None;
+                let mut _cond = None;
+                let mut _body = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _label = Some(map.next_value::<Option<Label>>()?),
+                        Some(2usize) => _cond = Some(map.next_value::<Box<Expr>>()?),
+                        Some(3usize) => _body = Some(map.next_value::<Block>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _label = _label.unwrap_or_default();
+                let _cond = _cond.ok_or_else(|| de::Error::missing_field("cond"))?;
+                let _body = _body.ok_or_else(|| de::Error::missing_field("body"))?;
+                Ok(ExprWhile {
+                    attrs: _attrs,
+                    label: _label,
+                    while_token: Default::default(),
+                    cond: _cond,
+                    body: _body,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprWhile", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprYield {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprYield", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprYield {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprYield;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprYield")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Option<Box<Expr>>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(ExprYield {
+                    attrs: _attrs,
+                    yield_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Option<Box<Expr>>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.unwrap_or_default();
+                Ok(ExprYield {
+                    attrs: _attrs,
+                    yield_token: Default::default(),
+                    expr: _expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprYield", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Field {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Field", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &self.ident.as_ref().map(IdentSer))?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "colon_token", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Field;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Field")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<Option<IdentDe>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _colon_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _ty = seq
+                    .next_element::<Type>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(Field {
+                    attrs: _attrs,
+                    vis: _vis,
+                    ident: _ident.map(|_v| _v.0),
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    ty: _ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                let mut _colon_token = None;
+                let mut _ty = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<Option<IdentDe>>()?),
+                        Some(3usize) => _colon_token = Some(map.next_value::<bool>()?),
+                        Some(4usize) => _ty = Some(map.next_value::<Type>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.unwrap_or_default();
+                let _colon_token = _colon_token.unwrap_or_default();
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                Ok(Field {
+                    attrs: _attrs,
+                    vis: _vis,
+                    ident: _ident.map(|_v| _v.0),
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    ty: _ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Field", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for FieldPat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldPat", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("member", &self.member)?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for FieldPat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "member", "colon_token", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldPat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldPat")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _member = seq
+                    .next_element::<Member>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _colon_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _pat = seq
+                    .next_element::<Box<Pat>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(FieldPat {
+                    attrs: _attrs,
+                    member: _member,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    pat: _pat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _member = None;
+                let mut _colon_token = None;
+                let mut _pat = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _member = Some(map.next_value::<Member>()?),
+                        Some(2usize) => _colon_token = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _pat = Some(map.next_value::<Box<Pat>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _member = _member.ok_or_else(|| de::Error::missing_field("member"))?;
+                let _colon_token = _colon_token.unwrap_or_default();
+                let _pat = _pat.ok_or_else(|| de::Error::missing_field("pat"))?;
+                Ok(FieldPat {
+                    attrs: _attrs,
+                    member: _member,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    pat: _pat,
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldPat", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for FieldValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldValue", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("member", &self.member)?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for FieldValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "member", "colon_token", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldValue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _member = seq
+                    .next_element::<Member>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _colon_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _expr = seq
+                    .next_element::<Expr>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(FieldValue {
+                    attrs: _attrs,
+                    member: _member,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    expr: _expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _member = None;
+                let mut _colon_token = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _member = Some(map.next_value::<Member>()?),
+                        Some(2usize) => _colon_token = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _expr = Some(map.next_value::<Expr>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _member = _member.ok_or_else(|| de::Error::missing_field("member"))?;
+                let _colon_token = _colon_token.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(FieldValue {
+                    attrs: _attrs,
+                    member: _member,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    expr: _expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldValue", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Fields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Fields::Named(_v0) => {
+                serializer.serialize_newtype_variant("Fields", 0u32, "Named", _v0)
+            }
+            Fields::Unnamed(_v0) => {
+                serializer.serialize_newtype_variant("Fields", 1u32, "Unnamed", _v0)
+            }
+            Fields::Unit => serializer.serialize_unit_variant("Fields", 2u32, "Unit"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Fields {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Named", "Unnamed", "Unit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Fields;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Fields")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<FieldsNamed>(access)?;
+                        Ok(Fields::Named(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<FieldsUnnamed>(access)?;
+                        Ok(Fields::Unnamed(_v))
+                    }
+                    2usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(Fields::Unit)
+                    }
+                    _ => Err(unsupported_variant("Fields", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Fields", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for FieldsNamed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldsNamed", 2usize)?;
+        state.serialize_field("named", &self.named)?;
+        state.serialize_field("named_trailing_punct", &self.named.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for FieldsNamed {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["named", "named_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldsNamed;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldsNamed")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _named = seq
+                    .next_element::<Punctuated<Field, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _named_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(FieldsNamed {
+                    brace_token: Default::default(),
+                    named: trailing_punct(_named, _named_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _named = None;
+                let mut _named_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _named = Some(map.next_value::<Punctuated<Field, token::Comma>>()?)
+                        }
+                        Some(1usize) => _named_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _named = _named.unwrap_or_default();
+                let _named_trailing_punct = _named_trailing_punct.unwrap_or_default();
+                Ok(FieldsNamed {
+                    brace_token: Default::default(),
+                    named: trailing_punct(_named, _named_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldsNamed", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for FieldsUnnamed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldsUnnamed", 2usize)?;
+        state.serialize_field("unnamed", &self.unnamed)?;
+        state.serialize_field("unnamed_trailing_punct", &self.unnamed.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for FieldsUnnamed {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["unnamed", "unnamed_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldsUnnamed;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldsUnnamed")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _unnamed = seq
+                    .next_element::<Punctuated<Field, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _unnamed_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(FieldsUnnamed {
+                    paren_token: Default::default(),
+                    unnamed: trailing_punct(_unnamed, _unnamed_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _unnamed = None;
+                let mut _unnamed_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _unnamed = Some(map.next_value::<Punctuated<Field, token::Comma>>()?)
+                        }
+                        Some(1usize) => _unnamed_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _unnamed = _unnamed.unwrap_or_default();
+                let _unnamed_trailing_punct = _unnamed_trailing_punct.unwrap_or_default();
+                Ok(FieldsUnnamed {
+                    paren_token: Default::default(),
+                    unnamed: trailing_punct(_unnamed, _unnamed_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldsUnnamed", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for File {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("File", 3usize)?;
+        state.serialize_field("shebang", &self.shebang)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for File {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["shebang", "attrs", "items"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = File;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct File")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _shebang = seq
+                    .next_element::<Option<String>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _items = seq
+                    .next_element::<Vec<Item>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(File {
+                    shebang: _shebang,
+                    attrs: _attrs,
+                    items: _items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _shebang = None;
+                let mut _attrs = None;
+                let mut _items = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _shebang = Some(map.next_value::<Option<String>>()?),
+                        Some(1usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(2usize) => _items = Some(map.next_value::<Vec<Item>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _shebang = _shebang.unwrap_or_default();
+                let _attrs = _attrs.unwrap_or_default();
+                let _items = _items.unwrap_or_default();
+                Ok(File {
+                    shebang: _shebang,
+                    attrs: _attrs,
+                    items: _items,
+                })
+            }
+        }
+        deserializer.deserialize_struct("File", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for FnArg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            FnArg::Receiver(_v0) => {
+                serializer.serialize_newtype_variant("FnArg", 0u32, "Receiver", _v0)
+            }
+            FnArg::Typed(_v0) => serializer.serialize_newtype_variant("FnArg", 1u32, "Typed", _v0),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for FnArg {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Receiver", "Typed"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FnArg;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum FnArg")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Receiver>(access)?;
+                        Ok(FnArg::Receiver(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatType>(access)?;
+                        Ok(FnArg::Typed(_v))
+                    }
+                    _ => Err(unsupported_variant("FnArg", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("FnArg", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ForeignItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ForeignItem::Fn(_v0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 0u32, "Fn", _v0)
+            }
+            ForeignItem::Static(_v0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 1u32, "Static", _v0)
+            }
+            ForeignItem::Type(_v0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 2u32, "Type", _v0)
+            }
+            ForeignItem::Macro(_v0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 3u32, "Macro", _v0)
+            }
+            ForeignItem::Verbatim(_v0) => serializer.serialize_newtype_variant(
+                "ForeignItem",
+                4u32,
+                "Verbatim",
+                &TokensSer(_v0),
+            ),
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ForeignItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Fn", "Static", "Type", "Macro", "Verbatim"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ForeignItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ForeignItemFn>(access)?;
+                        Ok(ForeignItem::Fn(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ForeignItemStatic>(access)?;
+                        Ok(ForeignItem::Static(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ForeignItemType>(access)?;
+                        Ok(ForeignItem::Type(_v))
+                    }
+                    3usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ForeignItemMacro>(access)?;
+                        Ok(ForeignItem::Macro(_v))
+                    }
+                    4usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TokensDe>(access)?;
+                        Ok(ForeignItem::Verbatim(_v.0))
+                    }
+                    _ => Err(unsupported_variant("ForeignItem", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ForeignItem", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ForeignItemFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemFn", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("sig", &self.sig)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ForeignItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "sig"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _sig = seq
+                    .next_element::<Signature>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ForeignItemFn {
+                    attrs: _attrs,
+                    vis: _vis,
+                    sig: _sig,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _sig = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _sig = Some(map.next_value::<Signature>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _sig = _sig.ok_or_else(|| de::Error::missing_field("sig"))?;
+                Ok(ForeignItemFn {
+                    attrs: _attrs,
+                    vis: _vis,
+                    sig: _sig,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemFn", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ForeignItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemMacro", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ForeignItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _mac = seq
+                    .next_element::<Macro>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _semi_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ForeignItemMacro {
+                    attrs: _attrs,
+                    mac: _mac,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _mac = None;
+                let mut _semi_token = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _mac = Some(map.next_value::<Macro>()?),
+                        Some(2usize) => _semi_token = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _mac = _mac.ok_or_else(|| de::Error::missing_field("mac"))?;
+                let _semi_token = _semi_token.unwrap_or_default();
+                Ok(ForeignItemMacro {
+                    attrs: _attrs,
+                    mac: _mac,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ForeignItemStatic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemStatic", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ForeignItemStatic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "mutability", "ident", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemStatic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemStatic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _mutability = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _ty = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(ForeignItemStatic {
+                    attrs: _attrs,
+                    vis: _vis,
+                    static_token: Default::default(),
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _mutability = None;
+                let mut _ident = None;
+                let mut _ty = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _mutability = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(4usize) => _ty = Some(map.next_value::<Box<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _mutability = _mutability.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                Ok(ForeignItemStatic {
+                    attrs: _attrs,
+                    vis: _vis,
+                    static_token: Default::default(),
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemStatic", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ForeignItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemType", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ForeignItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ForeignItemType {
+                    attrs: _attrs,
+                    vis: _vis,
+                    type_token: Default::default(),
+                    ident: _ident.0,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                Ok(ForeignItemType {
+                    attrs: _attrs,
+                    vis: _vis,
+                    type_token: Default::default(),
+                    ident: _ident.0,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for GenericArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericArgument::Lifetime(_v0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 0u32, "Lifetime", _v0)
+            }
+            GenericArgument::Type(_v0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 1u32, "Type", _v0)
+            }
+            GenericArgument::Binding(_v0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 2u32, "Binding", _v0)
+            }
+            GenericArgument::Constraint(_v0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 3u32, "Constraint", _v0)
+            }
+            GenericArgument::Const(_v0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 4u32, "Const", _v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for GenericArgument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Lifetime", "Type", "Binding", "Constraint", "Const"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = GenericArgument;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericArgument")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Lifetime>(access)?;
+                        Ok(GenericArgument::Lifetime(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Type>(access)?;
+                        Ok(GenericArgument::Type(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Binding>(access)?;
+                        Ok(GenericArgument::Binding(_v))
+                    }
+                    3usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Constraint>(access)?;
+                        Ok(GenericArgument::Constraint(_v))
+                    }
+                    4usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Expr>(access)?;
+                        Ok(GenericArgument::Const(_v))
+                    }
+                    _ => Err(unsupported_variant("GenericArgument", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericArgument", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for GenericMethodArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericMethodArgument::Type(_v0) => {
+                serializer.serialize_newtype_variant("GenericMethodArgument", 0u32, "Type", _v0)
+            }
+            GenericMethodArgument::Const(_v0) => {
+                serializer.serialize_newtype_variant("GenericMethodArgument", 1u32, "Const", _v0)
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for GenericMethodArgument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Const"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = GenericMethodArgument;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericMethodArgument")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Type>(access)?;
+                        Ok(GenericMethodArgument::Type(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Expr>(access)?;
+                        Ok(GenericMethodArgument::Const(_v))
+                    }
+                    _ => Err(unsupported_variant(
+                        "GenericMethodArgument",
+                        VARIANTS[variant],
+                    )),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericMethodArgument", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for GenericParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericParam::Type(_v0) => {
+                serializer.serialize_newtype_variant("GenericParam", 0u32, "Type", _v0)
+            }
+            GenericParam::Lifetime(_v0) => {
+                serializer.serialize_newtype_variant("GenericParam", 1u32, "Lifetime", _v0)
+            }
+            GenericParam::Const(_v0) => {
+                serializer.serialize_newtype_variant("GenericParam", 2u32, "Const", _v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for GenericParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Lifetime", "Const"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = GenericParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericParam")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeParam>(access)?;
+                        Ok(GenericParam::Type(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<LifetimeDef>(access)?;
+                        Ok(GenericParam::Lifetime(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ConstParam>(access)?;
+                        Ok(GenericParam::Const(_v))
+                    }
+                    _ => Err(unsupported_variant("GenericParam", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericParam", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Generics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Generics", 5usize)?;
+        state.serialize_field("lt_token", &self.lt_token.is_some())?;
+        state.serialize_field("params", &self.params)?;
+        state.serialize_field("params_trailing_punct", &self.params.trailing_punct())?;
+        state.serialize_field("gt_token", &self.gt_token.is_some())?;
+        state.serialize_field("where_clause", &self.where_clause)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Generics {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "lt_token",
+            "params",
+            "params_trailing_punct",
+            "gt_token",
+            "where_clause",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Generics;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Generics")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _lt_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _params = seq
+                    .next_element::<Punctuated<GenericParam, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _params_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _gt_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _where_clause = seq
+                    .next_element::<Option<WhereClause>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(Generics {
+                    lt_token: if _lt_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    params: trailing_punct(_params, _params_trailing_punct),
+                    gt_token: if _gt_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    where_clause: _where_clause,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _lt_token = None;
+                let mut _params = None;
+                let mut _params_trailing_punct = None;
+                let mut _gt_token = None;
+                let mut _where_clause = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _lt_token = Some(map.next_value::<bool>()?),
+                        Some(1usize) => {
+                            _params =
+                                Some(map.next_value::<Punctuated<GenericParam, token::Comma>>()?)
+                        }
+                        Some(2usize) => _params_trailing_punct = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _gt_token = Some(map.next_value::<bool>()?),
+                        Some(4usize) => {
+                            _where_clause = Some(map.next_value::<Option<WhereClause>>()?)
+                        }
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _lt_token = _lt_token.unwrap_or_default();
+                let _params = _params.unwrap_or_default();
+                let _params_trailing_punct = _params_trailing_punct.unwrap_or_default();
+                let _gt_token = _gt_token.unwrap_or_default();
+                let _where_clause = _where_clause.unwrap_or_default();
+                Ok(Generics {
+                    lt_token: if _lt_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    params: trailing_punct(_params, _params_trailing_punct),
+                    gt_token: if _gt_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    where_clause: _where_clause,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Generics", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ImplItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ImplItem::Const(_v0) => {
+                serializer.serialize_newtype_variant("ImplItem", 0u32, "Const", _v0)
+            }
+            ImplItem::Method(_v0) => {
+                serializer.serialize_newtype_variant("ImplItem", 1u32, "Method", _v0)
+            }
+            ImplItem::Type(_v0) => {
+                serializer.serialize_newtype_variant("ImplItem", 2u32, "Type", _v0)
+            }
+            ImplItem::Macro(_v0) => {
+                serializer.serialize_newtype_variant("ImplItem", 3u32, "Macro", _v0)
+            }
+            ImplItem::Verbatim(_v0) => {
+                serializer.serialize_newtype_variant("ImplItem", 4u32, "Verbatim", &TokensSer(_v0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ImplItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Const", "Method", "Type", "Macro", "Verbatim"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ImplItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ImplItemConst>(access)?;
+                        Ok(ImplItem::Const(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ImplItemMethod>(access)?;
+                        Ok(ImplItem::Method(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ImplItemType>(access)?;
+                        Ok(ImplItem::Type(_v))
+                    }
+                    3usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ImplItemMacro>(access)?;
+                        Ok(ImplItem::Macro(_v))
+                    }
+                    4usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TokensDe>(access)?;
+                        Ok(ImplItem::Verbatim(_v.0))
+                    }
+                    _ => Err(unsupported_variant("ImplItem", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ImplItem", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ImplItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemConst", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ImplItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "defaultness", "ident", "ty", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _defaultness = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _ty = seq
+                    .next_element::<Type>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _expr = seq
+                    .next_element::<Expr>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                Ok(ImplItemConst {
+                    attrs: _attrs,
+                    vis: _vis,
+                    defaultness: if _defaultness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    const_token: Default::default(),
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    eq_token: Default::default(),
+                    expr: _expr,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _defaultness = None;
+                let mut _ident = None;
+                let mut _ty = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _defaultness = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(4usize) => _ty = Some(map.next_value::<Type>()?),
+                        Some(5usize) => _expr = Some(map.next_value::<Expr>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _defaultness = _defaultness.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(ImplItemConst {
+                    attrs: _attrs,
+                    vis: _vis,
+                    defaultness: if _defaultness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    const_token: Default::default(),
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    eq_token: Default::default(),
+                    expr: _expr,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemConst", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ImplItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemMacro", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ImplItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _mac = seq
+                    .next_element::<Macro>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _semi_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ImplItemMacro {
+                    attrs: _attrs,
+                    mac: _mac,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _mac = None;
+                let mut _semi_token = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _mac = Some(map.next_value::<Macro>()?),
+                        Some(2usize) => _semi_token = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _mac = _mac.ok_or_else(|| de::Error::missing_field("mac"))?;
+                let _semi_token = _semi_token.unwrap_or_default();
+                Ok(ImplItemMacro {
+                    attrs: _attrs,
+                    mac: _mac,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ImplItemMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemMethod", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("sig", &self.sig)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ImplItemMethod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "defaultness", "sig", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemMethod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemMethod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _defaultness = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _sig = seq
+                    .next_element::<Signature>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _block = seq
+                    .next_element::<Block>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(ImplItemMethod {
+                    attrs: _attrs,
+                    vis: _vis,
+                    defaultness: if _defaultness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    sig: _sig,
+                    block: _block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _defaultness = None;
+                let mut _sig = None;
+                let mut _block = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _defaultness = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _sig = Some(map.next_value::<Signature>()?),
+                        Some(4usize) => _block = Some(map.next_value::<Block>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _defaultness = _defaultness.unwrap_or_default();
+                let _sig = _sig.ok_or_else(|| de::Error::missing_field("sig"))?;
+                let _block = _block.ok_or_else(|| de::Error::missing_field("block"))?;
+                Ok(ImplItemMethod {
+                    attrs: _attrs,
+                    vis: _vis,
+                    defaultness: if _defaultness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    sig: _sig,
+                    block: _block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemMethod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ImplItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemType", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ImplItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "defaultness", "ident", "generics", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _defaultness = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _generics = seq
+                    .next_element::<Generics>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _ty = seq
+                    .next_element::<Type>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                Ok(ImplItemType {
+                    attrs: _attrs,
+                    vis: _vis,
+                    defaultness: if _defaultness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    type_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    eq_token: Default::default(),
+                    ty: _ty,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _defaultness = None;
+                let mut _ident = None;
+                let mut _generics = None;
+                let mut _ty = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _defaultness = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(4usize) => _generics = Some(map.next_value::<Generics>()?),
+                        Some(5usize) => _ty = Some(map.next_value::<Type>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _defaultness = _defaultness.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _generics = _generics.ok_or_else(|| de::Error::missing_field("generics"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                Ok(ImplItemType {
+                    attrs: _attrs,
+                    vis: _vis,
+                    defaultness: if _defaultness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    type_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    eq_token: Default::default(),
+                    ty: _ty,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Index {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Index", 1usize)?;
+        state.serialize_field("index", &self.index)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Index {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["index"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Index;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Index")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _index = seq
+                    .next_element::<u32>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(Index {
+                    index: _index,
+                    span: Span::call_site(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _index = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _index = Some(map.next_value::<u32>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _index = _index.ok_or_else(|| de::Error::missing_field("index"))?;
+                Ok(Index {
+                    index: _index,
+                    span: Span::call_site(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Index", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Item {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Item::Const(_v0) => serializer.serialize_newtype_variant("Item", 0u32, "Const", _v0),
+            Item::Enum(_v0) => serializer.serialize_newtype_variant("Item", 1u32, "Enum", _v0),
+            Item::ExternCrate(_v0) => {
+                serializer.serialize_newtype_variant("Item", 2u32, "ExternCrate", _v0)
+            }
+            Item::Fn(_v0) => serializer.serialize_newtype_variant("Item", 3u32, "Fn", _v0),
+            Item::ForeignMod(_v0) => {
+                serializer.serialize_newtype_variant("Item", 4u32, "ForeignMod", _v0)
+            }
+            Item::Impl(_v0) => serializer.serialize_newtype_variant("Item", 5u32, "Impl", _v0),
+            Item::Macro(_v0) => serializer.serialize_newtype_variant("Item", 6u32, "Macro", _v0),
+            Item::Macro2(_v0) => serializer.serialize_newtype_variant("Item", 7u32, "Macro2", _v0),
+            Item::Mod(_v0) => serializer.serialize_newtype_variant("Item", 8u32, "Mod", _v0),
+            Item::Static(_v0) => serializer.serialize_newtype_variant("Item", 9u32, "Static", _v0),
+            Item::Struct(_v0) => serializer.serialize_newtype_variant("Item", 10u32, "Struct", _v0),
+            Item::Trait(_v0) => serializer.serialize_newtype_variant("Item", 11u32, "Trait", _v0),
+            Item::TraitAlias(_v0) => {
+                serializer.serialize_newtype_variant("Item", 12u32, "TraitAlias", _v0)
+            }
+            Item::Type(_v0) => serializer.serialize_newtype_variant("Item", 13u32, "Type", _v0),
+            Item::Union(_v0) => serializer.serialize_newtype_variant("Item", 14u32, "Union", _v0),
+            Item::Use(_v0) => serializer.serialize_newtype_variant("Item", 15u32, "Use", _v0),
+            Item::Verbatim(_v0) => {
+                serializer.serialize_newtype_variant("Item", 16u32, "Verbatim", &TokensSer(_v0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Const",
+            "Enum",
+            "ExternCrate",
+            "Fn",
+            "ForeignMod",
+            "Impl",
+            "Macro",
+            "Macro2",
+            "Mod",
+            "Static",
+            "Struct",
+            "Trait",
+            "TraitAlias",
+            "Type",
+            "Union",
+            "Use",
+            "Verbatim",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Item;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Item")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemConst>(access)?;
+                        Ok(Item::Const(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemEnum>(access)?;
+                        Ok(Item::Enum(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemExternCrate>(access)?;
+                        Ok(Item::ExternCrate(_v))
+                    }
+                    3usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemFn>(access)?;
+                        Ok(Item::Fn(_v))
+                    }
+                    4usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemForeignMod>(access)?;
+                        Ok(Item::ForeignMod(_v))
+                    }
+                    5usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemImpl>(access)?;
+                        Ok(Item::Impl(_v))
+                    }
+                    6usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemMacro>(access)?;
+                        Ok(Item::Macro(_v))
+                    }
+                    7usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemMacro2>(access)?;
+                        Ok(Item::Macro2(_v))
+                    }
+                    8usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemMod>(access)?;
+                        Ok(Item::Mod(_v))
+                    }
+                    9usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemStatic>(access)?;
+                        Ok(Item::Static(_v))
+                    }
+                    10usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemStruct>(access)?;
+                        Ok(Item::Struct(_v))
+                    }
+                    11usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemTrait>(access)?;
+                        Ok(Item::Trait(_v))
+                    }
+                    12usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemTraitAlias>(access)?;
+                        Ok(Item::TraitAlias(_v))
+                    }
+                    13usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemType>(access)?;
+                        Ok(Item::Type(_v))
+                    }
+                    14usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemUnion>(access)?;
+                        Ok(Item::Union(_v))
+                    }
+                    15usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ItemUse>(access)?;
+                        Ok(Item::Use(_v))
+                    }
+                    16usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TokensDe>(access)?;
+                        Ok(Item::Verbatim(_v.0))
+                    }
+                    _ => Err(unsupported_variant("Item", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Item", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemConst", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "ty", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _ty = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(ItemConst {
+                    attrs: _attrs,
+                    vis: _vis,
+                    const_token: Default::default(),
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    eq_token: Default::default(),
+                    expr: _expr,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                let mut _ty = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(3usize) => _ty = Some(map.next_value::<Box<Type>>()?),
+                        Some(4usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(ItemConst {
+                    attrs: _attrs,
+                    vis: _vis,
+                    const_token: Default::default(),
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    eq_token: Default::default(),
+                    expr: _expr,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemConst", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemEnum", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("variants", &self.variants)?;
+        state.serialize_field("variants_trailing_punct", &self.variants.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemEnum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "ident",
+            "generics",
+            "variants",
+            "variants_trailing_punct",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemEnum;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemEnum")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _generics = seq
+                    .next_element::<Generics>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _variants = seq
+                    .next_element::<Punctuated<Variant, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _variants_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                Ok(ItemEnum {
+                    attrs: _attrs,
+                    vis: _vis,
+                    enum_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    brace_token: Default::default(),
+                    variants: trailing_punct(_variants, _variants_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                let mut _generics = None;
+                let mut _variants = None;
+                let mut _variants_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(3usize) => _generics = Some(map.next_value::<Generics>()?),
+                        Some(4usize) => {
+                            _variants = Some(map.next_value::<Punctuated<Variant, token::Comma>>()?)
+                        }
+                        Some(5usize) => _variants_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _generics = _generics.ok_or_else(|| de::Error::missing_field("generics"))?;
+                let _variants = _variants.unwrap_or_default();
+                let _variants_trailing_punct = _variants_trailing_punct.unwrap_or_default();
+                Ok(ItemEnum {
+                    attrs: _attrs,
+                    vis: _vis,
+                    enum_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    brace_token: Default::default(),
+                    variants: trailing_punct(_variants, _variants_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemEnum", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemExternCrate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemExternCrate", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("rename", &self.rename.as_ref().map(|_v| IdentSer(&_v.1)))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemExternCrate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "rename"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemExternCrate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemExternCrate")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _rename = seq
+                    .next_element::<Option<IdentDe>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(ItemExternCrate {
+                    attrs: _attrs,
+                    vis: _vis,
+                    extern_token: Default::default(),
+                    crate_token: Default::default(),
+                    ident: _ident.0,
+                    rename: _rename.map(|_v| (Default::default(), _v.0)),
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                let mut _rename = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(3usize) => _rename = Some(map.next_value::<Option<IdentDe>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _rename = _rename.unwrap_or_default();
+                Ok(ItemExternCrate {
+                    attrs: _attrs,
+                    vis: _vis,
+                    extern_token: Default::default(),
+                    crate_token: Default::default(),
+                    ident: _ident.0,
+                    rename: _rename.map(|_v| (Default::default(), _v.0)),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemExternCrate", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemFn", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("sig", &self.sig)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "sig", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _sig = seq
+                    .next_element::<Signature>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _block = seq
+                    .next_element::<Box<Block>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(ItemFn {
+                    attrs: _attrs,
+                    vis: _vis,
+                    sig: _sig,
+                    block: _block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _sig = None;
+                let mut _block = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _sig = Some(map.next_value::<Signature>()?),
+                        Some(3usize) => _block = Some(map.next_value::<Box<Block>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _sig = _sig.ok_or_else(|| de::Error::missing_field("sig"))?;
+                let _block = _block.ok_or_else(|| de::Error::missing_field("block"))?;
+                Ok(ItemFn {
+                    attrs: _attrs,
+                    vis: _vis,
+                    sig: _sig,
+                    block: _block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemFn", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemForeignMod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemForeignMod", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("abi", &self.abi)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemForeignMod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "abi", "items"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemForeignMod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemForeignMod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _abi = seq
+                    .next_element::<Abi>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _items = seq
+                    .next_element::<Vec<ForeignItem>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ItemForeignMod {
+                    attrs: _attrs,
+                    abi: _abi,
+                    brace_token: Default::default(),
+                    items: _items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _abi = None;
+                let mut _items = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _abi = Some(map.next_value::<Abi>()?),
+                        Some(2usize) => _items = Some(map.next_value::<Vec<ForeignItem>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _abi = _abi.ok_or_else(|| de::Error::missing_field("abi"))?;
+                let _items = _items.unwrap_or_default();
+                Ok(ItemForeignMod {
+                    attrs: _attrs,
+                    abi: _abi,
+                    brace_token: Default::default(),
+                    items: _items,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemForeignMod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemImpl {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemImpl", 7usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field(
+            "trait_",
+            &self.trait_.as_ref().map(|_v| (_v.0.is_some(), &_v.1)),
+        )?;
+        state.serialize_field("self_ty", &self.self_ty)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemImpl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "defaultness",
+            "unsafety",
+            "generics",
+            "trait_",
+            "self_ty",
+            "items",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemImpl;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemImpl")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _defaultness = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _unsafety = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _generics = seq
+                    .next_element::<Generics>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _trait_ = seq
+                    .next_element::<Option<(bool, Path)>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _self_ty = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                let _items = seq
+                    .next_element::<Vec<ImplItem>>()?
+                    .ok_or_else(|| de::Error::invalid_length(6usize, &self))?;
+                Ok(ItemImpl {
+                    attrs: _attrs,
+                    defaultness: if _defaultness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    unsafety: if _unsafety {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    impl_token: Default::default(),
+                    generics: _generics,
+                    trait_: _trait_.map(|_v| {
+                        let (_v0, _v1) = _v;
+                        (
+                            if _v0 { Some(Default::default()) } else { None },
+                            _v1,
+                            Default::default(),
+                        )
+                    }),
+                    self_ty: _self_ty,
+                    brace_token: Default::default(),
+                    items: _items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _defaultness = None;
+                let mut _unsafety = None;
+                let mut _generics = None;
+                let mut _trait_ = None;
+                let mut _self_ty = None;
+                let mut _items = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _defaultness = Some(map.next_value::<bool>()?),
+                        Some(2usize) => _unsafety = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _generics = Some(map.next_value::<Generics>()?),
+                        Some(4usize) => _trait_ = Some(map.next_value::<Option<(bool, Path)>>()?),
+                        Some(5usize) => _self_ty = Some(map.next_value::<Box<Type>>()?),
+                        Some(6usize) => _items = Some(map.next_value::<Vec<ImplItem>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _defaultness = _defaultness.unwrap_or_default();
+                let _unsafety = _unsafety.unwrap_or_default();
+                let _generics = _generics.ok_or_else(|| de::Error::missing_field("generics"))?;
+                let _trait_ = _trait_.unwrap_or_default();
+                let _self_ty = _self_ty.ok_or_else(|| de::Error::missing_field("self_ty"))?;
+                let _items = _items.unwrap_or_default();
+                Ok(ItemImpl {
+                    attrs: _attrs,
+                    defaultness: if _defaultness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    unsafety: if _unsafety {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    impl_token: Default::default(),
+                    generics: _generics,
+                    trait_: _trait_.map(|_v| {
+                        let (_v0, _v1) = _v;
+                        (
+                            if _v0 { Some(Default::default()) } else { None },
+                            _v1,
+                            Default::default(),
+                        )
+                    }),
+                    self_ty: _self_ty,
+                    brace_token: Default::default(),
+                    items: _items,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemImpl", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMacro", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &self.ident.as_ref().map(IdentSer))?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _ident = seq
+                    .next_element::<Option<IdentDe>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _mac = seq
+                    .next_element::<Macro>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _semi_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(ItemMacro {
+                    attrs: _attrs,
+                    ident: _ident.map(|_v| _v.0),
+                    mac: _mac,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _ident = None;
+                let mut _mac = None;
+                let mut _semi_token = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _ident = Some(map.next_value::<Option<IdentDe>>()?),
+                        Some(2usize) => _mac = Some(map.next_value::<Macro>()?),
+                        Some(3usize) => _semi_token = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _ident = _ident.unwrap_or_default();
+                let _mac = _mac.ok_or_else(|| de::Error::missing_field("mac"))?;
+                let _semi_token = _semi_token.unwrap_or_default();
+                Ok(ItemMacro {
+                    attrs: _attrs,
+                    ident: _ident.map(|_v| _v.0),
+                    mac: _mac,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemMacro2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMacro2", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("rules", &TokensSer(&self.rules))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemMacro2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "rules"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemMacro2;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMacro2")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _rules = seq
+                    .next_element::<TokensDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(ItemMacro2 {
+                    attrs: _attrs,
+                    vis: _vis,
+                    macro_token: Default::default(),
+                    ident: _ident.0,
+                    rules: _rules.0,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                let mut _rules = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(3usize) => _rules = Some(map.next_value::<TokensDe>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _rules = _rules.ok_or_else(|| de::Error::missing_field("rules"))?;
+                Ok(ItemMacro2 {
+                    attrs: _attrs,
+                    vis: _vis,
+                    macro_token: Default::default(),
+                    ident: _ident.0,
+                    rules: _rules.0,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMacro2", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemMod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMod", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("content", &self.content.as_ref().map(|_v| &_v.1))?;
+        state.serialize_field("semi", &self.semi.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemMod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "content", "semi"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemMod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _content = seq
+                    .next_element::<Option<Vec<Item>>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _semi = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(ItemMod {
+                    attrs: _attrs,
+                    vis: _vis,
+                    mod_token: Default::default(),
+                    ident: _ident.0,
+                    content: _content.map(|_v| (Default::default(), _v)),
+                    semi: if _semi {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                let mut _content = None;
+                let mut _semi = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(3usize) => _content = Some(map.next_value::<Option<Vec<Item>>>()?),
+                        Some(4usize) => _semi = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _content = _content.unwrap_or_default();
+                let _semi = _semi.unwrap_or_default();
+                Ok(ItemMod {
+                    attrs: _attrs,
+                    vis: _vis,
+                    mod_token: Default::default(),
+                    ident: _ident.0,
+                    content: _content.map(|_v| (Default::default(), _v)),
+                    semi: if _semi {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemStatic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemStatic", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemStatic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "mutability", "ident", "ty", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemStatic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemStatic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _mutability = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _ty = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                Ok(ItemStatic {
+                    attrs: _attrs,
+                    vis: _vis,
+                    static_token: Default::default(),
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    eq_token: Default::default(),
+                    expr: _expr,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _mutability = None;
+                let mut _ident = None;
+                let mut _ty = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _mutability = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(4usize) => _ty = Some(map.next_value::<Box<Type>>()?),
+                        Some(5usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _mutability = _mutability.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(ItemStatic {
+                    attrs: _attrs,
+                    vis: _vis,
+                    static_token: Default::default(),
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    eq_token: Default::default(),
+                    expr: _expr,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemStatic", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemStruct", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "fields", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _generics = seq
+                    .next_element::<Generics>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _fields = seq
+                    .next_element::<Fields>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _semi_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                Ok(ItemStruct {
+                    attrs: _attrs,
+                    vis: _vis,
+                    struct_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    fields: _fields,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                let mut _generics = None;
+                let mut _fields = None;
+                let mut _semi_token = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(3usize) => _generics = Some(map.next_value::<Generics>()?),
+                        Some(4usize) => _fields = Some(map.next_value::<Fields>()?),
+                        Some(5usize) => _semi_token = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _generics = _generics.ok_or_else(|| de::Error::missing_field("generics"))?;
+                let _fields = _fields.ok_or_else(|| de::Error::missing_field("fields"))?;
+                let _semi_token = _semi_token.unwrap_or_default();
+                Ok(ItemStruct {
+                    attrs: _attrs,
+                    vis: _vis,
+                    struct_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    fields: _fields,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemTrait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemTrait", 10usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("auto_token", &self.auto_token.is_some())?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("supertraits", &self.supertraits)?;
+        state.serialize_field(
+            "supertraits_trailing_punct",
+            &self.supertraits.trailing_punct(),
+        )?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemTrait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "unsafety",
+            "auto_token",
+            "ident",
+            "generics",
+            "colon_token",
+            "supertraits",
+            "supertraits_trailing_punct",
+            "items",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemTrait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemTrait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _unsafety = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _auto_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _generics = seq
+                    .next_element::<Generics>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                let _colon_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(6usize, &self))?;
+                let _supertraits = seq
+                    .next_element::<Punctuated<TypeParamBound, token::Add>>()?
+                    .ok_or_else(|| de::Error::invalid_length(7usize, &self))?;
+                let _supertraits_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(8usize, &self))?;
+                let _items = seq
+                    .next_element::<Vec<TraitItem>>()?
+                    .ok_or_else(|| de::Error::invalid_length(9usize, &self))?;
+                Ok(ItemTrait {
+                    attrs: _attrs,
+                    vis: _vis,
+                    unsafety: if _unsafety {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    auto_token: if _auto_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    trait_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    supertraits: trailing_punct(_supertraits, _supertraits_trailing_punct),
+                    brace_token: Default::default(),
+                    items: _items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _unsafety = None;
+                let mut _auto_token = None;
+                let mut _ident = None;
+                let mut _generics = None;
+                let mut _colon_token = None;
+                let mut _supertraits = None;
+                let mut _supertraits_trailing_punct = None;
+                let mut _items = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _unsafety = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _auto_token = Some(map.next_value::<bool>()?),
+                        Some(4usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(5usize) => _generics = Some(map.next_value::<Generics>()?),
+                        Some(6usize) => _colon_token = Some(map.next_value::<bool>()?),
+                        Some(7usize) => {
+                            _supertraits =
+                                Some(map.next_value::<Punctuated<TypeParamBound, token::Add>>()?)
+                        }
+                        Some(8usize) => {
+                            _supertraits_trailing_punct = Some(map.next_value::<bool>()?)
+                        }
+                        Some(9usize) => _items = Some(map.next_value::<Vec<TraitItem>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _unsafety = _unsafety.unwrap_or_default();
+                let _auto_token = _auto_token.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _generics = _generics.ok_or_else(|| de::Error::missing_field("generics"))?;
+                let _colon_token = _colon_token.unwrap_or_default();
+                let _supertraits = _supertraits.unwrap_or_default();
+                let _supertraits_trailing_punct = _supertraits_trailing_punct.unwrap_or_default();
+                let _items = _items.unwrap_or_default();
+                Ok(ItemTrait {
+                    attrs: _attrs,
+                    vis: _vis,
+                    unsafety: if _unsafety {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    auto_token: if _auto_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    trait_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    supertraits: trailing_punct(_supertraits, _supertraits_trailing_punct),
+                    brace_token: Default::default(),
+                    items: _items,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemTrait", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemTraitAlias {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemTraitAlias", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("bounds_trailing_punct", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemTraitAlias {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "ident",
+            "generics",
+            "bounds",
+            "bounds_trailing_punct",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemTraitAlias;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemTraitAlias")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _generics = seq
+                    .next_element::<Generics>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _bounds = seq
+                    .next_element::<Punctuated<TypeParamBound, token::Add>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _bounds_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                Ok(ItemTraitAlias {
+                    attrs: _attrs,
+                    vis: _vis,
+                    trait_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    eq_token: Default::default(),
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                let mut _generics = None;
+                let mut _bounds = None;
+                let mut _bounds_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(3usize) => _generics = Some(map.next_value::<Generics>()?),
+                        Some(4usize) => {
+                            _bounds =
+                                Some(map.next_value::<Punctuated<TypeParamBound, token::Add>>()?)
+                        }
+                        Some(5usize) => _bounds_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _generics = _generics.ok_or_else(|| de::Error::missing_field("generics"))?;
+                let _bounds = _bounds.unwrap_or_default();
+                let _bounds_trailing_punct = _bounds_trailing_punct.unwrap_or_default();
+                Ok(ItemTraitAlias {
+                    attrs: _attrs,
+                    vis: _vis,
+                    trait_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    eq_token: Default::default(),
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemTraitAlias", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemType", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _generics = seq
+                    .next_element::<Generics>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _ty = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(ItemType {
+                    attrs: _attrs,
+                    vis: _vis,
+                    type_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    eq_token: Default::default(),
+                    ty: _ty,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                let mut _generics = None;
+                let mut _ty = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(3usize) => _generics = Some(map.next_value::<Generics>()?),
+                        Some(4usize) => _ty = Some(map.next_value::<Box<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _generics = _generics.ok_or_else(|| de::Error::missing_field("generics"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                Ok(ItemType {
+                    attrs: _attrs,
+                    vis: _vis,
+                    type_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    eq_token: Default::default(),
+                    ty: _ty,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemUnion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemUnion", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemUnion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "fields"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemUnion;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemUnion")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _generics = seq
+                    .next_element::<Generics>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _fields = seq
+                    .next_element::<FieldsNamed>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(ItemUnion {
+                    attrs: _attrs,
+                    vis: _vis,
+                    union_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    fields: _fields,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _ident = None;
+                let mut _generics = None;
+                let mut _fields = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(3usize) => _generics = Some(map.next_value::<Generics>()?),
+                        Some(4usize) => _fields = Some(map.next_value::<FieldsNamed>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _generics = _generics.ok_or_else(|| de::Error::missing_field("generics"))?;
+                let _fields = _fields.ok_or_else(|| de::Error::missing_field("fields"))?;
+                Ok(ItemUnion {
+                    attrs: _attrs,
+                    vis: _vis,
+                    union_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    fields: _fields,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemUnion", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemUse {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemUse", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("leading_colon", &self.leading_colon.is_some())?;
+        state.serialize_field("tree", &self.tree)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemUse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "leading_colon", "tree"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemUse;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemUse")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _vis = seq
+                    .next_element::<Visibility>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _leading_colon = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _tree = seq
+                    .next_element::<UseTree>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(ItemUse {
+                    attrs: _attrs,
+                    vis: _vis,
+                    use_token: Default::default(),
+                    leading_colon: if _leading_colon {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    tree: _tree,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _vis = None;
+                let mut _leading_colon = None;
+                let mut _tree = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _vis = Some(map.next_value::<Visibility>()?),
+                        Some(2usize) => _leading_colon = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _tree = Some(map.next_value::<UseTree>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _vis = _vis.ok_or_else(|| de::Error::missing_field("vis"))?;
+                let _leading_colon = _leading_colon.unwrap_or_default();
+                let _tree = _tree.ok_or_else(|| de::Error::missing_field("tree"))?;
+                Ok(ItemUse {
+                    attrs: _attrs,
+                    vis: _vis,
+                    use_token: Default::default(),
+                    leading_colon: if _leading_colon {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    tree: _tree,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemUse", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Label {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Label", 1usize)?;
+        state.serialize_field("name", &self.name)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Label {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["name"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Label;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Label")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _name = seq
+                    .next_element::<Lifetime>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(Label {
+                    name: _name,
+                    colon_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _name = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _name = Some(map.next_value::<Lifetime>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _name = _name.ok_or_else(|| de::Error::missing_field("name"))?;
+                Ok(Label {
+                    name: _name,
+                    colon_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Label", FIELDS, Visitor)
+    }
+}
+impl Serialize for Lifetime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Lifetime", 1usize)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.end()
+    }
+}
+impl<'de> Deserialize<'de> for Lifetime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Lifetime;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Lifetime")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(Lifetime {
+                    apostrophe: Span::call_site(),
+                    ident: _ident.0,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _ident = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                Ok(Lifetime {
+                    apostrophe: Span::call_site(),
+                    ident: _ident.0,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Lifetime", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for LifetimeDef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LifetimeDef", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("lifetime", &self.lifetime)?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("bounds_trailing_punct", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for LifetimeDef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "lifetime",
+            "colon_token",
+            "bounds",
+            "bounds_trailing_punct",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = LifetimeDef;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct LifetimeDef")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _lifetime = seq
+                    .next_element::<Lifetime>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _colon_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _bounds = seq
+                    .next_element::<Punctuated<Lifetime, token::Add>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _bounds_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(LifetimeDef {
+                    attrs: _attrs,
+                    lifetime: _lifetime,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _lifetime = None;
+                let mut _colon_token = None;
+                let mut _bounds = None;
+                let mut _bounds_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _lifetime = Some(map.next_value::<Lifetime>()?),
+                        Some(2usize) => _colon_token = Some(map.next_value::<bool>()?),
+                        Some(3usize) => {
+                            _bounds = Some(map.next_value::<Punctuated<Lifetime, token::Add>>()?)
+                        }
+                        Some(4usize) => _bounds_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _lifetime = _lifetime.ok_or_else(|| de::Error::missing_field("lifetime"))?;
+                let _colon_token = _colon_token.unwrap_or_default();
+                let _bounds = _bounds.unwrap_or_default();
+                let _bounds_trailing_punct = _bounds_trailing_punct.unwrap_or_default();
+                Ok(LifetimeDef {
+                    attrs: _attrs,
+                    lifetime: _lifetime,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("LifetimeDef", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Lit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Lit::Str(_v0) => serializer.serialize_newtype_variant("Lit", 0u32, "Str", _v0),
+            Lit::ByteStr(_v0) => serializer.serialize_newtype_variant("Lit", 1u32, "ByteStr", _v0),
+            Lit::Byte(_v0) => serializer.serialize_newtype_variant("Lit", 2u32, "Byte", _v0),
+            Lit::Char(_v0) => serializer.serialize_newtype_variant("Lit", 3u32, "Char", _v0),
+            Lit::Int(_v0) => serializer.serialize_newtype_variant("Lit", 4u32, "Int", _v0),
+            Lit::Float(_v0) => serializer.serialize_newtype_variant("Lit", 5u32, "Float", _v0),
+            Lit::Bool(_v0) => serializer.serialize_newtype_variant("Lit", 6u32, "Bool", _v0),
+            Lit::Verbatim(_v0) => {
+                serializer.serialize_newtype_variant("Lit", 7u32, "Verbatim", &LiteralSer(_v0))
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Lit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Str", "ByteStr", "Byte", "Char", "Int", "Float", "Bool", "Verbatim",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Lit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Lit")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<LitStr>(access)?;
+                        Ok(Lit::Str(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<LitByteStr>(access)?;
+                        Ok(Lit::ByteStr(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<LitByte>(access)?;
+                        Ok(Lit::Byte(_v))
+                    }
+                    3usize => {
+                        let _v = de::VariantAccess::newtype_variant::<LitChar>(access)?;
+                        Ok(Lit::Char(_v))
+                    }
+                    4usize => {
+                        let _v = de::VariantAccess::newtype_variant::<LitInt>(access)?;
+                        Ok(Lit::Int(_v))
+                    }
+                    5usize => {
+                        let _v = de::VariantAccess::newtype_variant::<LitFloat>(access)?;
+                        Ok(Lit::Float(_v))
+                    }
+                    6usize => {
+                        let _v = de::VariantAccess::newtype_variant::<LitBool>(access)?;
+                        Ok(Lit::Bool(_v))
+                    }
+                    7usize => {
+                        let _v = de::VariantAccess::newtype_variant::<LiteralDe>(access)?;
+                        Ok(Lit::Verbatim(_v.0))
+                    }
+                    _ => Err(unsupported_variant("Lit", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Lit", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for LitBool {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LitBool", 1usize)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for LitBool {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["value"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = LitBool;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct LitBool")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _value = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(LitBool {
+                    value: _value,
+                    span: Span::call_site(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _value = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _value = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _value = _value.ok_or_else(|| de::Error::missing_field("value"))?;
+                Ok(LitBool {
+                    value: _value,
+                    span: Span::call_site(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("LitBool", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Local {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Local", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("init", &self.init.as_ref().map(|_v| &_v.1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Local {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "init"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Local;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Local")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _pat = seq
+                    .next_element::<Pat>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _init = seq
+                    .next_element::<Option<Box<Expr>>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(Local {
+                    attrs: _attrs,
+                    let_token: Default::default(),
+                    pat: _pat,
+                    init: _init.map(|_v| (Default::default(), _v)),
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _pat = None;
+                let mut _init = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _pat = Some(map.next_value::<Pat>()?),
+                        Some(2usize) => _init = Some(map.next_value::<Option<Box<Expr>>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _pat = _pat.ok_or_else(|| de::Error::missing_field("pat"))?;
+                let _init = _init.unwrap_or_default();
+                Ok(Local {
+                    attrs: _attrs,
+                    let_token: Default::default(),
+                    pat: _pat,
+                    init: _init.map(|_v| (Default::default(), _v)),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Local", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Macro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Macro", 3usize)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("delimiter", &self.delimiter)?;
+        state.serialize_field("tokens", &TokensSer(&self.tokens))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Macro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "delimiter", "tokens"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Macro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Macro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _delimiter = seq
+                    .next_element::<MacroDelimiter>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _tokens = seq
+                    .next_element::<TokensDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(Macro {
+                    path: _path,
+                    bang_token: Default::default(),
+                    delimiter: _delimiter,
+                    tokens: _tokens.0,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _path = None;
+                let mut _delimiter = None;
+                let mut _tokens = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _path = Some(map.next_value::<Path>()?),
+                        Some(1usize) => _delimiter = Some(map.next_value::<MacroDelimiter>()?),
+                        Some(2usize) => _tokens = Some(map.next_value::<TokensDe>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                let _delimiter = _delimiter.ok_or_else(|| de::Error::missing_field("delimiter"))?;
+                let _tokens = _tokens.ok_or_else(|| de::Error::missing_field("tokens"))?;
+                Ok(Macro {
+                    path: _path,
+                    bang_token: Default::default(),
+                    delimiter: _delimiter,
+                    tokens: _tokens.0,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Macro", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for MacroDelimiter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            MacroDelimiter::Paren(_v0) => {
+                serializer.serialize_unit_variant("MacroDelimiter", 0u32, "Paren")
+            }
+            MacroDelimiter::Brace(_v0) => {
+                serializer.serialize_unit_variant("MacroDelimiter", 1u32, "Brace")
+            }
+            MacroDelimiter::Bracket(_v0) => {
+                serializer.serialize_unit_variant("MacroDelimiter", 2u32, "Bracket")
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for MacroDelimiter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Paren", "Brace", "Bracket"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MacroDelimiter;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum MacroDelimiter")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(MacroDelimiter::Paren(Default::default()))
+                    }
+                    1usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(MacroDelimiter::Brace(Default::default()))
+                    }
+                    2usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(MacroDelimiter::Bracket(Default::default()))
+                    }
+                    _ => Err(unsupported_variant("MacroDelimiter", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("MacroDelimiter", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Member {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Member::Named(_v0) => {
+                serializer.serialize_newtype_variant("Member", 0u32, "Named", &IdentSer(_v0))
+            }
+            Member::Unnamed(_v0) => {
+                serializer.serialize_newtype_variant("Member", 1u32, "Unnamed", _v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Member {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Named", "Unnamed"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Member;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Member")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<IdentDe>(access)?;
+                        Ok(Member::Named(_v.0))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Index>(access)?;
+                        Ok(Member::Unnamed(_v))
+                    }
+                    _ => Err(unsupported_variant("Member", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Member", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Meta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Meta::Path(_v0) => serializer.serialize_newtype_variant("Meta", 0u32, "Path", _v0),
+            Meta::List(_v0) => serializer.serialize_newtype_variant("Meta", 1u32, "List", _v0),
+            Meta::NameValue(_v0) => {
+                serializer.serialize_newtype_variant("Meta", 2u32, "NameValue", _v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Meta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Path", "List", "NameValue"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Meta;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Meta")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Path>(access)?;
+                        Ok(Meta::Path(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<MetaList>(access)?;
+                        Ok(Meta::List(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<MetaNameValue>(access)?;
+                        Ok(Meta::NameValue(_v))
+                    }
+                    _ => Err(unsupported_variant("Meta", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Meta", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for MetaExpr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            MetaExpr::Path(_v0) => {
+                serializer.serialize_newtype_variant("MetaExpr", 0u32, "Path", _v0)
+            }
+            MetaExpr::List(_v0) => {
+                serializer.serialize_newtype_variant("MetaExpr", 1u32, "List", _v0)
+            }
+            MetaExpr::NameValue(_v0) => {
+                serializer.serialize_newtype_variant("MetaExpr", 2u32, "NameValue", _v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for MetaExpr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Path", "List", "NameValue"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MetaExpr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum MetaExpr")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Path>(access)?;
+                        Ok(MetaExpr::Path(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<MetaExprList>(access)?;
+                        Ok(MetaExpr::List(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<MetaExprNameValue>(access)?;
+                        Ok(MetaExpr::NameValue(_v))
+                    }
+                    _ => Err(unsupported_variant("MetaExpr", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("MetaExpr", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for MetaExprList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MetaExprList", 3usize)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("nested", &self.nested)?;
+        state.serialize_field("nested_trailing_punct", &self.nested.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for MetaExprList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "nested", "nested_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MetaExprList;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MetaExprList")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _nested = seq
+                    .next_element::<Punctuated<NestedMetaExpr, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _nested_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(MetaExprList {
+                    path: _path,
+                    paren_token: Default::default(),
+                    nested: trailing_punct(_nested, _nested_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _path = None;
+                let mut _nested = None;
+                let mut _nested_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _path = Some(map.next_value::<Path>()?),
+                        Some(1usize) => {
+                            _nested =
+                                Some(map.next_value::<Punctuated<NestedMetaExpr, token::Comma>>()?)
+                        }
+                        Some(2usize) => _nested_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                let _nested = _nested.unwrap_or_default();
+                let _nested_trailing_punct = _nested_trailing_punct.unwrap_or_default();
+                Ok(MetaExprList {
+                    path: _path,
+                    paren_token: Default::default(),
+                    nested: trailing_punct(_nested, _nested_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("MetaExprList", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for MetaExprNameValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MetaExprNameValue", 2usize)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for MetaExprNameValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "value"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MetaExprNameValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MetaExprNameValue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _value = seq
+                    .next_element::<MetaValue>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(MetaExprNameValue {
+                    path: _path,
+                    eq_token: Default::default(),
+                    value: _value,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _path = None;
+                let mut _value = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _path = Some(map.next_value::<Path>()?),
+                        Some(1usize) => _value = Some(map.next_value::<MetaValue>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                let _value = _value.ok_or_else(|| de::Error::missing_field("value"))?;
+                Ok(MetaExprNameValue {
+                    path: _path,
+                    eq_token: Default::default(),
+                    value: _value,
+                })
+            }
+        }
+        deserializer.deserialize_struct("MetaExprNameValue", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for MetaList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MetaList", 3usize)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("nested", &self.nested)?;
+        state.serialize_field("nested_trailing_punct", &self.nested.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for MetaList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "nested", "nested_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MetaList;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MetaList")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _nested = seq
+                    .next_element::<Punctuated<NestedMeta, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _nested_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(MetaList {
+                    path: _path,
+                    paren_token: Default::default(),
+                    nested: trailing_punct(_nested, _nested_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _path = None;
+                let mut _nested = None;
+                let mut _nested_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _path = Some(map.next_value::<Path>()?),
+                        Some(1usize) => {
+                            _nested =
+                                Some(map.next_value::<Punctuated<NestedMeta, token::Comma>>()?)
+                        }
+                        Some(2usize) => _nested_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                let _nested = _nested.unwrap_or_default();
+                let _nested_trailing_punct = _nested_trailing_punct.unwrap_or_default();
+                Ok(MetaList {
+                    path: _path,
+                    paren_token: Default::default(),
+                    nested: trailing_punct(_nested, _nested_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("MetaList", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for MetaNameValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MetaNameValue", 2usize)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("lit", &self.lit)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for MetaNameValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "lit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MetaNameValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MetaNameValue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _lit = seq
+                    .next_element::<Lit>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(MetaNameValue {
+                    path: _path,
+                    eq_token: Default::default(),
+                    lit: _lit,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _path = None;
+                let mut _lit = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _path = Some(map.next_value::<Path>()?),
+                        Some(1usize) => _lit = Some(map.next_value::<Lit>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                let _lit = _lit.ok_or_else(|| de::Error::missing_field("lit"))?;
+                Ok(MetaNameValue {
+                    path: _path,
+                    eq_token: Default::default(),
+                    lit: _lit,
+                })
+            }
+        }
+        deserializer.deserialize_struct("MetaNameValue", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for MetaValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            MetaValue::Expr(_v0) => {
+                serializer.serialize_newtype_variant("MetaValue", 0u32, "Expr", _v0)
+            }
+            MetaValue::Verbatim(_v0) => {
+                serializer.serialize_newtype_variant("MetaValue", 1u32, "Verbatim", &TokensSer(_v0))
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for MetaValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Expr", "Verbatim"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MetaValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum MetaValue")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Expr>(access)?;
+                        Ok(MetaValue::Expr(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TokensDe>(access)?;
+                        Ok(MetaValue::Verbatim(_v.0))
+                    }
+                    _ => Err(unsupported_variant("MetaValue", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("MetaValue", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for MethodTurbofish {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MethodTurbofish", 2usize)?;
+        state.serialize_field("args", &self.args)?;
+        state.serialize_field("args_trailing_punct", &self.args.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for MethodTurbofish {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["args", "args_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MethodTurbofish;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MethodTurbofish")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _args = seq
+                    .next_element::<Punctuated<GenericMethodArgument, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _args_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(MethodTurbofish {
+                    colon2_token: Default::default(),
+                    lt_token: Default::default(),
+                    args: trailing_punct(_args, _args_trailing_punct),
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _args = None;
+                let mut _args_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _args = Some(
+                                map.next_value::<Punctuated<GenericMethodArgument, token::Comma>>(
+                                )?,
+                            )
+                        }
+                        Some(1usize) => _args_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _args = _args.unwrap_or_default();
+                let _args_trailing_punct = _args_trailing_punct.unwrap_or_default();
+                Ok(MethodTurbofish {
+                    colon2_token: Default::default(),
+                    lt_token: Default::default(),
+                    args: trailing_punct(_args, _args_trailing_punct),
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("MethodTurbofish", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for NestedMeta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            NestedMeta::Meta(_v0) => {
+                serializer.serialize_newtype_variant("NestedMeta", 0u32, "Meta", _v0)
+            }
+            NestedMeta::Lit(_v0) => {
+                serializer.serialize_newtype_variant("NestedMeta", 1u32, "Lit", _v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for NestedMeta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Meta", "Lit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = NestedMeta;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum NestedMeta")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Meta>(access)?;
+                        Ok(NestedMeta::Meta(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Lit>(access)?;
+                        Ok(NestedMeta::Lit(_v))
+                    }
+                    _ => Err(unsupported_variant("NestedMeta", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("NestedMeta", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for NestedMetaExpr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            NestedMetaExpr::Meta(_v0) => {
+                serializer.serialize_newtype_variant("NestedMetaExpr", 0u32, "Meta", _v0)
+            }
+            NestedMetaExpr::Lit(_v0) => {
+                serializer.serialize_newtype_variant("NestedMetaExpr", 1u32, "Lit", _v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for NestedMetaExpr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Meta", "Lit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = NestedMetaExpr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum NestedMetaExpr")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<MetaExpr>(access)?;
+                        Ok(NestedMetaExpr::Meta(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Lit>(access)?;
+                        Ok(NestedMetaExpr::Lit(_v))
+                    }
+                    _ => Err(unsupported_variant("NestedMetaExpr", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("NestedMetaExpr", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ParenthesizedGenericArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ParenthesizedGenericArguments", 3usize)?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("inputs_trailing_punct", &self.inputs.trailing_punct())?;
+        state.serialize_field("output", &self.output)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ParenthesizedGenericArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["inputs", "inputs_trailing_punct", "output"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ParenthesizedGenericArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ParenthesizedGenericArguments")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _inputs = seq
+                    .next_element::<Punctuated<Type, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _inputs_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _output = seq
+                    .next_element::<ReturnType>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(ParenthesizedGenericArguments {
+                    paren_token: Default::default(),
+                    inputs: trailing_punct(_inputs, _inputs_trailing_punct),
+                    output: _output,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _inputs = None;
+                let mut _inputs_trailing_punct = None;
+                let mut _output = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _inputs = Some(map.next_value::<Punctuated<Type, token::Comma>>()?)
+                        }
+                        Some(1usize) => _inputs_trailing_punct = Some(map.next_value::<bool>()?),
+                        Some(2usize) => _output = Some(map.next_value::<ReturnType>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _inputs = _inputs.unwrap_or_default();
+                let _inputs_trailing_punct = _inputs_trailing_punct.unwrap_or_default();
+                let _output = _output.ok_or_else(|| de::Error::missing_field("output"))?;
+                Ok(ParenthesizedGenericArguments {
+                    paren_token: Default::default(),
+                    inputs: trailing_punct(_inputs, _inputs_trailing_punct),
+                    output: _output,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ParenthesizedGenericArguments", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Pat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Pat::Box(_v0) => serializer.serialize_newtype_variant("Pat", 0u32, "Box", _v0),
+            Pat::Ident(_v0) => serializer.serialize_newtype_variant("Pat", 1u32, "Ident", _v0),
+            Pat::Lit(_v0) => serializer.serialize_newtype_variant("Pat", 2u32, "Lit", _v0),
+            Pat::Macro(_v0) => serializer.serialize_newtype_variant("Pat", 3u32, "Macro", _v0),
+            Pat::Or(_v0) => serializer.serialize_newtype_variant("Pat", 4u32, "Or", _v0),
+            Pat::Path(_v0) => serializer.serialize_newtype_variant("Pat", 5u32, "Path", _v0),
+            Pat::Range(_v0) => serializer.serialize_newtype_variant("Pat", 6u32, "Range", _v0),
+            Pat::Reference(_v0) => {
+                serializer.serialize_newtype_variant("Pat", 7u32, "Reference", _v0)
+            }
+            Pat::Rest(_v0) => serializer.serialize_newtype_variant("Pat", 8u32, "Rest", _v0),
+            Pat::Slice(_v0) => serializer.serialize_newtype_variant("Pat", 9u32, "Slice", _v0),
+            Pat::Struct(_v0) => serializer.serialize_newtype_variant("Pat", 10u32, "Struct", _v0),
+            Pat::Tuple(_v0) => serializer.serialize_newtype_variant("Pat", 11u32, "Tuple", _v0),
+            Pat::TupleStruct(_v0) => {
+                serializer.serialize_newtype_variant("Pat", 12u32, "TupleStruct", _v0)
+            }
+            Pat::Type(_v0) => serializer.serialize_newtype_variant("Pat", 13u32, "Type", _v0),
+            Pat::Verbatim(_v0) => {
+                serializer.serialize_newtype_variant("Pat", 14u32, "Verbatim", &TokensSer(_v0))
+            }
+            Pat::Wild(_v0) => serializer.serialize_newtype_variant("Pat", 15u32, "Wild", _v0),
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Pat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Box",
+            "Ident",
+            "Lit",
+            "Macro",
+            "Or",
+            "Path",
+            "Range",
+            "Reference",
+            "Rest",
+            "Slice",
+            "Struct",
+            "Tuple",
+            "TupleStruct",
+            "Type",
+            "Verbatim",
+            "Wild",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Pat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Pat")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatBox>(access)?;
+                        Ok(Pat::Box(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatIdent>(access)?;
+                        Ok(Pat::Ident(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatLit>(access)?;
+                        Ok(Pat::Lit(_v))
+                    }
+                    3usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatMacro>(access)?;
+                        Ok(Pat::Macro(_v))
+                    }
+                    4usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatOr>(access)?;
+                        Ok(Pat::Or(_v))
+                    }
+                    5usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatPath>(access)?;
+                        Ok(Pat::Path(_v))
+                    }
+                    6usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatRange>(access)?;
+                        Ok(Pat::Range(_v))
+                    }
+                    7usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatReference>(access)?;
+                        Ok(Pat::Reference(_v))
+                    }
+                    8usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatRest>(access)?;
+                        Ok(Pat::Rest(_v))
+                    }
+                    9usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatSlice>(access)?;
+                        Ok(Pat::Slice(_v))
+                    }
+                    10usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatStruct>(access)?;
+                        Ok(Pat::Struct(_v))
+                    }
+                    11usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatTuple>(access)?;
+                        Ok(Pat::Tuple(_v))
+                    }
+                    12usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatTupleStruct>(access)?;
+                        Ok(Pat::TupleStruct(_v))
+                    }
+                    13usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatType>(access)?;
+                        Ok(Pat::Type(_v))
+                    }
+                    14usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TokensDe>(access)?;
+                        Ok(Pat::Verbatim(_v.0))
+                    }
+                    15usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PatWild>(access)?;
+                        Ok(Pat::Wild(_v))
+                    }
+                    _ => Err(unsupported_variant("Pat", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Pat", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatBox {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatBox", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatBox {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatBox;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatBox")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _pat = seq
+                    .next_element::<Box<Pat>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(PatBox {
+                    attrs: _attrs,
+                    box_token: Default::default(),
+                    pat: _pat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _pat = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _pat = Some(map.next_value::<Box<Pat>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _pat = _pat.ok_or_else(|| de::Error::missing_field("pat"))?;
+                Ok(PatBox {
+                    attrs: _attrs,
+                    box_token: Default::default(),
+                    pat: _pat,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatBox", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatIdent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatIdent", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("by_ref", &self.by_ref.is_some())?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("subpat", &self.subpat.as_ref().map(|_v| &_v.1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatIdent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "by_ref", "mutability", "ident", "subpat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatIdent;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatIdent")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _by_ref = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _mutability = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _subpat = seq
+                    .next_element::<Option<Box<Pat>>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(PatIdent {
+                    attrs: _attrs,
+                    by_ref: if _by_ref {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    ident: _ident.0,
+                    subpat: _subpat.map(|_v| (Default::default(), _v)),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _by_ref = None;
+                let mut _mutability = None;
+                let mut _ident = None;
+                let mut _subpat = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _by_ref = Some(map.next_value::<bool>()?),
+                        Some(2usize) => _mutability = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(4usize) => _subpat = Some(map.next_value::<Option<Box<Pat>>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _by_ref = _by_ref.unwrap_or_default();
+                let _mutability = _mutability.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _subpat = _subpat.unwrap_or_default();
+                Ok(PatIdent {
+                    attrs: _attrs,
+                    by_ref: if _by_ref {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    ident: _ident.0,
+                    subpat: _subpat.map(|_v| (Default::default(), _v)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatIdent", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatLit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatLit", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatLit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatLit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatLit")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _expr = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(PatLit {
+                    attrs: _attrs,
+                    expr: _expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _expr = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _expr = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _expr = _expr.ok_or_else(|| de::Error::missing_field("expr"))?;
+                Ok(PatLit {
+                    attrs: _attrs,
+                    expr: _expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatLit", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatMacro", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _mac = seq
+                    .next_element::<Macro>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(PatMacro {
+                    attrs: _attrs,
+                    mac: _mac,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _mac = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _mac = Some(map.next_value::<Macro>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _mac = _mac.ok_or_else(|| de::Error::missing_field("mac"))?;
+                Ok(PatMacro {
+                    attrs: _attrs,
+                    mac: _mac,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatOr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatOr", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("leading_vert", &self.leading_vert.is_some())?;
+        state.serialize_field("cases", &self.cases)?;
+        state.serialize_field("cases_trailing_punct", &self.cases.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatOr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "leading_vert", "cases", "cases_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatOr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatOr")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _leading_vert = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _cases = seq
+                    .next_element::<Punctuated<Pat, token::Or>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _cases_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(PatOr {
+                    attrs: _attrs,
+                    leading_vert: if _leading_vert {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    cases: trailing_punct(_cases, _cases_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _leading_vert = None;
+                let mut _cases = None;
+                let mut _cases_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _leading_vert = Some(map.next_value::<bool>()?),
+                        Some(2usize) => {
+                            _cases = Some(map.next_value::<Punctuated<Pat, token::Or>>()?)
+                        }
+                        Some(3usize) => _cases_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _leading_vert = _leading_vert.unwrap_or_default();
+                let _cases = _cases.unwrap_or_default();
+                let _cases_trailing_punct = _cases_trailing_punct.unwrap_or_default();
+                Ok(PatOr {
+                    attrs: _attrs,
+                    leading_vert: if _leading_vert {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    cases: trailing_punct(_cases, _cases_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatOr", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatPath", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("qself", &self.qself)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "qself", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatPath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatPath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _qself = seq
+                    .next_element::<Option<QSelf>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(PatPath {
+                    attrs: _attrs,
+                    qself: _qself,
+                    path: _path,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _qself = None;
+                let mut _path = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _qself = Some(map.next_value::<Option<QSelf>>()?),
+                        Some(2usize) => _path = Some(map.next_value::<Path>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _qself = _qself.unwrap_or_default();
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                Ok(PatPath {
+                    attrs: _attrs,
+                    qself: _qself,
+                    path: _path,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatPath", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatRange", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("lo", &self.lo)?;
+        state.serialize_field("limits", &self.limits)?;
+        state.serialize_field("hi", &self.hi)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "lo", "limits", "hi"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatRange;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatRange")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _lo = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _limits = seq
+                    .next_element::<RangeLimits>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _hi = seq
+                    .next_element::<Box<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(PatRange {
+                    attrs: _attrs,
+                    lo: _lo,
+                    limits: _limits,
+                    hi: _hi,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _lo = None;
+                let mut _limits = None;
+                let mut _hi = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _lo = Some(map.next_value::<Box<Expr>>()?),
+                        Some(2usize) => _limits = Some(map.next_value::<RangeLimits>()?),
+                        Some(3usize) => _hi = Some(map.next_value::<Box<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _lo = _lo.ok_or_else(|| de::Error::missing_field("lo"))?;
+                let _limits = _limits.ok_or_else(|| de::Error::missing_field("limits"))?;
+                let _hi = _hi.ok_or_else(|| de::Error::missing_field("hi"))?;
+                Ok(PatRange {
+                    attrs: _attrs,
+                    lo: _lo,
+                    limits: _limits,
+                    hi: _hi,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatRange", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatReference", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mutability", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _mutability = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _pat = seq
+                    .next_element::<Box<Pat>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(PatReference {
+                    attrs: _attrs,
+                    and_token: Default::default(),
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    pat: _pat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _mutability = None;
+                let mut _pat = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _mutability = Some(map.next_value::<bool>()?),
+                        Some(2usize) => _pat = Some(map.next_value::<Box<Pat>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _mutability = _mutability.unwrap_or_default();
+                let _pat = _pat.ok_or_else(|| de::Error::missing_field("pat"))?;
+                Ok(PatReference {
+                    attrs: _attrs,
+                    and_token: Default::default(),
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    pat: _pat,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatReference", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatRest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatRest", 1usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatRest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatRest;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatRest")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(PatRest {
+                    attrs: _attrs,
+                    dot2_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                Ok(PatRest {
+                    attrs: _attrs,
+                    dot2_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatRest", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatSlice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatSlice", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.serialize_field("elems_trailing_punct", &self.elems.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatSlice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems", "elems_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatSlice;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatSlice")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _elems = seq
+                    .next_element::<Punctuated<Pat, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _elems_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(PatSlice {
+                    attrs: _attrs,
+                    bracket_token: Default::default(),
+                    elems: trailing_punct(_elems, _elems_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _elems = None;
+                let mut _elems_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => {
+                            _elems = Some(map.next_value::<Punctuated<Pat, token::Comma>>()?)
+                        }
+                        Some(2usize) => _elems_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _elems = _elems.unwrap_or_default();
+                let _elems_trailing_punct = _elems_trailing_punct.unwrap_or_default();
+                Ok(PatSlice {
+                    attrs: _attrs,
+                    bracket_token: Default::default(),
+                    elems: trailing_punct(_elems, _elems_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatSlice", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatStruct", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("fields_trailing_punct", &self.fields.trailing_punct())?;
+        state.serialize_field("dot2_token", &self.dot2_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "path",
+            "fields",
+            "fields_trailing_punct",
+            "dot2_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _fields = seq
+                    .next_element::<Punctuated<FieldPat, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _fields_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _dot2_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                Ok(PatStruct {
+                    attrs: _attrs,
+                    path: _path,
+                    brace_token: Default::default(),
+                    fields: trailing_punct(_fields, _fields_trailing_punct),
+                    dot2_token: if _dot2_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _path = None;
+                let mut _fields = None;
+                let mut _fields_trailing_punct = None;
+                let mut _dot2_token = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _path = Some(map.next_value::<Path>()?),
+                        Some(2usize) => {
+                            _fields = Some(map.next_value::<Punctuated<FieldPat, token::Comma>>()?)
+                        }
+                        Some(3usize) => _fields_trailing_punct = Some(map.next_value::<bool>()?),
+                        Some(4usize) => _dot2_token = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                let _fields = _fields.unwrap_or_default();
+                let _fields_trailing_punct = _fields_trailing_punct.unwrap_or_default();
+                let _dot2_token = _dot2_token.unwrap_or_default();
+                Ok(PatStruct {
+                    attrs: _attrs,
+                    path: _path,
+                    brace_token: Default::default(),
+                    fields: trailing_punct(_fields, _fields_trailing_punct),
+                    dot2_token: if _dot2_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatTuple", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.serialize_field("elems_trailing_punct", &self.elems.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems", "elems_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _elems = seq
+                    .next_element::<Punctuated<Pat, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _elems_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(PatTuple {
+                    attrs: _attrs,
+                    paren_token: Default::default(),
+                    elems: trailing_punct(_elems, _elems_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _elems = None;
+                let mut _elems_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => {
+                            _elems = Some(map.next_value::<Punctuated<Pat, token::Comma>>()?)
+                        }
+                        Some(2usize) => _elems_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _elems = _elems.unwrap_or_default();
+                let _elems_trailing_punct = _elems_trailing_punct.unwrap_or_default();
+                Ok(PatTuple {
+                    attrs: _attrs,
+                    paren_token: Default::default(),
+                    elems: trailing_punct(_elems, _elems_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatTuple", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatTupleStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatTupleStruct", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatTupleStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "path", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatTupleStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatTupleStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _pat = seq
+                    .next_element::<PatTuple>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(PatTupleStruct {
+                    attrs: _attrs,
+                    path: _path,
+                    pat: _pat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _path = None;
+                let mut _pat = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _path = Some(map.next_value::<Path>()?),
+                        Some(2usize) => _pat = Some(map.next_value::<PatTuple>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                let _pat = _pat.ok_or_else(|| de::Error::missing_field("pat"))?;
+                Ok(PatTupleStruct {
+                    attrs: _attrs,
+                    path: _path,
+                    pat: _pat,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatTupleStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatType", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _pat = seq
+                    .next_element::<Box<Pat>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ty = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(PatType {
+                    attrs: _attrs,
+                    pat: _pat,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _pat = None;
+                let mut _ty = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _pat = Some(map.next_value::<Box<Pat>>()?),
+                        Some(2usize) => _ty = Some(map.next_value::<Box<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _pat = _pat.ok_or_else(|| de::Error::missing_field("pat"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                Ok(PatType {
+                    attrs: _attrs,
+                    pat: _pat,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatType", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatWild {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatWild", 1usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatWild {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatWild;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatWild")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(PatWild {
+                    attrs: _attrs,
+                    underscore_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                Ok(PatWild {
+                    attrs: _attrs,
+                    underscore_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatWild", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Path {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Path", 3usize)?;
+        state.serialize_field("leading_colon", &self.leading_colon.is_some())?;
+        state.serialize_field("segments", &self.segments)?;
+        state.serialize_field("segments_trailing_punct", &self.segments.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["leading_colon", "segments", "segments_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Path;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Path")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _leading_colon = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _segments = seq
+                    .next_element::<Punctuated<PathSegment, token::Colon2>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _segments_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(Path {
+                    leading_colon: if _leading_colon {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    segments: trailing_punct(_segments, _segments_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _leading_colon = None;
+                let mut _segments = None;
+                let mut _segments_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _leading_colon = Some(map.next_value::<bool>()?),
+                        Some(1usize) => {
+                            _segments =
+                                Some(map.next_value::<Punctuated<PathSegment, token::Colon2>>()?)
+                        }
+                        Some(2usize) => _segments_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _leading_colon = _leading_colon.unwrap_or_default();
+                let _segments = _segments.unwrap_or_default();
+                let _segments_trailing_punct = _segments_trailing_punct.unwrap_or_default();
+                Ok(Path {
+                    leading_colon: if _leading_colon {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    segments: trailing_punct(_segments, _segments_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Path", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for PathArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            PathArguments::None => serializer.serialize_unit_variant("PathArguments", 0u32, "None"),
+            PathArguments::AngleBracketed(_v0) => {
+                serializer.serialize_newtype_variant("PathArguments", 1u32, "AngleBracketed", _v0)
+            }
+            PathArguments::Parenthesized(_v0) => {
+                serializer.serialize_newtype_variant("PathArguments", 2u32, "Parenthesized", _v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for PathArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["None", "AngleBracketed", "Parenthesized"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PathArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum PathArguments")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(PathArguments::None)
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<
+                            AngleBracketedGenericArguments,
+                        >(access)?;
+                        Ok(PathArguments::AngleBracketed(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<ParenthesizedGenericArguments>(
+                            access,
+                        )?;
+                        Ok(PathArguments::Parenthesized(_v))
+                    }
+                    _ => Err(unsupported_variant("PathArguments", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("PathArguments", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for PathSegment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PathSegment", 2usize)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("arguments", &self.arguments)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for PathSegment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "arguments"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PathSegment;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PathSegment")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _arguments = seq
+                    .next_element::<PathArguments>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(PathSegment {
+                    ident: _ident.0,
+                    arguments: _arguments,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _ident = None;
+                let mut _arguments = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(1usize) => _arguments = Some(map.next_value::<PathArguments>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _arguments = _arguments.ok_or_else(|| de::Error::missing_field("arguments"))?;
+                Ok(PathSegment {
+                    ident: _ident.0,
+                    arguments: _arguments,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PathSegment", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for PredicateEq {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateEq", 2usize)?;
+        state.serialize_field("lhs_ty", &self.lhs_ty)?;
+        state.serialize_field("rhs_ty", &self.rhs_ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for PredicateEq {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lhs_ty", "rhs_ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PredicateEq;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateEq")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _lhs_ty = seq
+                    .next_element::<Type>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _rhs_ty = seq
+                    .next_element::<Type>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(PredicateEq {
+                    lhs_ty: _lhs_ty,
+                    eq_token: Default::default(),
+                    rhs_ty: _rhs_ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _lhs_ty = None;
+                let mut _rhs_ty = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _lhs_ty = Some(map.next_value::<Type>()?),
+                        Some(1usize) => _rhs_ty = Some(map.next_value::<Type>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _lhs_ty = _lhs_ty.ok_or_else(|| de::Error::missing_field("lhs_ty"))?;
+                let _rhs_ty = _rhs_ty.ok_or_else(|| de::Error::missing_field("rhs_ty"))?;
+                Ok(PredicateEq {
+                    lhs_ty: _lhs_ty,
+                    eq_token: Default::default(),
+                    rhs_ty: _rhs_ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateEq", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for PredicateLifetime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateLifetime", 3usize)?;
+        state.serialize_field("lifetime", &self.lifetime)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("bounds_trailing_punct", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for PredicateLifetime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetime", "bounds", "bounds_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PredicateLifetime;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateLifetime")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _lifetime = seq
+                    .next_element::<Lifetime>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _bounds = seq
+                    .next_element::<Punctuated<Lifetime, token::Add>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _bounds_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(PredicateLifetime {
+                    lifetime: _lifetime,
+                    colon_token: Default::default(),
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _lifetime = None;
+                let mut _bounds = None;
+                let mut _bounds_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _lifetime = Some(map.next_value::<Lifetime>()?),
+                        Some(1usize) => {
+                            _bounds = Some(map.next_value::<Punctuated<Lifetime, token::Add>>()?)
+                        }
+                        Some(2usize) => _bounds_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _lifetime = _lifetime.ok_or_else(|| de::Error::missing_field("lifetime"))?;
+                let _bounds = _bounds.unwrap_or_default();
+                let _bounds_trailing_punct = _bounds_trailing_punct.unwrap_or_default();
+                Ok(PredicateLifetime {
+                    lifetime: _lifetime,
+                    colon_token: Default::default(),
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateLifetime", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for PredicateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateType", 4usize)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.serialize_field("bounded_ty", &self.bounded_ty)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("bounds_trailing_punct", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for PredicateType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetimes", "bounded_ty", "bounds", "bounds_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PredicateType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _lifetimes = seq
+                    .next_element::<Option<BoundLifetimes>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _bounded_ty = seq
+                    .next_element::<Type>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _bounds = seq
+                    .next_element::<Punctuated<TypeParamBound, token::Add>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _bounds_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(PredicateType {
+                    lifetimes: _lifetimes,
+                    bounded_ty: _bounded_ty,
+                    colon_token: Default::default(),
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _lifetimes = None;
+                let mut _bounded_ty = None;
+                let mut _bounds = None;
+                let mut _bounds_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _lifetimes = Some(map.next_value::<Option<BoundLifetimes>>()?)
+                        }
+                        Some(1usize) => _bounded_ty = Some(map.next_value::<Type>()?),
+                        Some(2usize) => {
+                            _bounds =
+                                Some(map.next_value::<Punctuated<TypeParamBound, token::Add>>()?)
+                        }
+                        Some(3usize) => _bounds_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _lifetimes = _lifetimes.unwrap_or_default();
+                let _bounded_ty =
+                    _bounded_ty.ok_or_else(|| de::Error::missing_field("bounded_ty"))?;
+                let _bounds = _bounds.unwrap_or_default();
+                let _bounds_trailing_punct = _bounds_trailing_punct.unwrap_or_default();
+                Ok(PredicateType {
+                    lifetimes: _lifetimes,
+                    bounded_ty: _bounded_ty,
+                    colon_token: Default::default(),
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateType", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for QSelf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("QSelf", 3usize)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("position", &self.position)?;
+        state.serialize_field("as_token", &self.as_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for QSelf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ty", "position", "as_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = QSelf;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct QSelf")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _ty = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _position = seq
+                    .next_element::<usize>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _as_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(QSelf {
+                    lt_token: Default::default(),
+                    ty: _ty,
+                    position: _position,
+                    as_token: if _as_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _ty = None;
+                let mut _position = None;
+                let mut _as_token = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _ty = Some(map.next_value::<Box<Type>>()?),
+                        Some(1usize) => _position = Some(map.next_value::<usize>()?),
+                        Some(2usize) => _as_token = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                let _position = _position.ok_or_else(|| de::Error::missing_field("position"))?;
+                let _as_token = _as_token.unwrap_or_default();
+                Ok(QSelf {
+                    lt_token: Default::default(),
+                    ty: _ty,
+                    position: _position,
+                    as_token: if _as_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("QSelf", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for RangeLimits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            RangeLimits::HalfOpen(_v0) => {
+                serializer.serialize_unit_variant("RangeLimits", 0u32, "HalfOpen")
+            }
+            RangeLimits::Closed(_v0) => {
+                serializer.serialize_unit_variant("RangeLimits", 1u32, "Closed")
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for RangeLimits {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["HalfOpen", "Closed"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = RangeLimits;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum RangeLimits")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(RangeLimits::HalfOpen(Default::default()))
+                    }
+                    1usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(RangeLimits::Closed(Default::default()))
+                    }
+                    _ => Err(unsupported_variant("RangeLimits", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("RangeLimits", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Receiver {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Receiver", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("reference", &self.reference.as_ref().map(|_v| (&_v.1,)))?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Receiver {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "reference", "mutability"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Receiver;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Receiver")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _reference = seq
+                    .next_element::<Option<(Option<Lifetime>,)>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _mutability = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(Receiver {
+                    attrs: _attrs,
+                    reference: _reference.map(|_v| {
+                        let (_v0,) = _v;
+                        (Default::default(), _v0)
+                    }),
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    self_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _reference = None;
+                let mut _mutability = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => {
+                            _reference = Some(map.next_value::<Option<(Option<Lifetime>,)>>()?)
+                        }
+                        Some(2usize) => _mutability = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _reference = _reference.unwrap_or_default();
+                let _mutability = _mutability.unwrap_or_default();
+                Ok(Receiver {
+                    attrs: _attrs,
+                    reference: _reference.map(|_v| {
+                        let (_v0,) = _v;
+                        (Default::default(), _v0)
+                    }),
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    self_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Receiver", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ReturnType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ReturnType::Default => serializer.serialize_unit_variant("ReturnType", 0u32, "Default"),
+            ReturnType::Type(_v0, _v1) => {
+                serializer.serialize_newtype_variant("ReturnType", 1u32, "Type", _v1)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ReturnType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Default", "Type"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ReturnType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ReturnType")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(ReturnType::Default)
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Box<Type>>(access)?;
+                        Ok(ReturnType::Type(Default::default(), _v))
+                    }
+                    _ => Err(unsupported_variant("ReturnType", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ReturnType", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Signature", 10usize)?;
+        state.serialize_field("constness", &self.constness.is_some())?;
+        state.serialize_field("asyncness", &self.asyncness.is_some())?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("abi", &self.abi)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("inputs_trailing_punct", &self.inputs.trailing_punct())?;
+        state.serialize_field("variadic", &self.variadic)?;
+        state.serialize_field("output", &self.output)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "constness",
+            "asyncness",
+            "unsafety",
+            "abi",
+            "ident",
+            "generics",
+            "inputs",
+            "inputs_trailing_punct",
+            "variadic",
+            "output",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Signature;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Signature")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _constness = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _asyncness = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _unsafety = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _abi = seq
+                    .next_element::<Option<Abi>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _generics = seq
+                    .next_element::<Generics>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                let _inputs = seq
+                    .next_element::<Punctuated<FnArg, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(6usize, &self))?;
+                let _inputs_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(7usize, &self))?;
+                let _variadic = seq
+                    .next_element::<Option<Variadic>>()?
+                    .ok_or_else(|| de::Error::invalid_length(8usize, &self))?;
+                let _output = seq
+                    .next_element::<ReturnType>()?
+                    .ok_or_else(|| de::Error::invalid_length(9usize, &self))?;
+                Ok(Signature {
+                    constness: if _constness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    asyncness: if _asyncness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    unsafety: if _unsafety {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    abi: _abi,
+                    fn_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    paren_token: Default::default(),
+                    inputs: trailing_punct(_inputs, _inputs_trailing_punct),
+                    variadic: _variadic,
+                    output: _output,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _constness = None;
+                let mut _asyncness = None;
+                let mut _unsafety = None;
+                let mut _abi = None;
+                let mut _ident = None;
+                let mut _generics = None;
+                let mut _inputs = None;
+                let mut _inputs_trailing_punct = None;
+                let mut _variadic = None;
+                let mut _output = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _constness = Some(map.next_value::<bool>()?),
+                        Some(1usize) => _asyncness = Some(map.next_value::<bool>()?),
+                        Some(2usize) => _unsafety = Some(map.next_value::<bool>()?),
+                        Some(3usize) => _abi = Some(map.next_value::<Option<Abi>>()?),
+                        Some(4usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(5usize) => _generics = Some(map.next_value::<Generics>()?),
+                        Some(6usize) => {
+                            _inputs = Some(map.next_value::<Punctuated<FnArg, token::Comma>>()?)
+                        }
+                        Some(7usize) => _inputs_trailing_punct = Some(map.next_value::<bool>()?),
+                        Some(8usize) => _variadic = Some(map.next_value::<Option<Variadic>>()?),
+                        Some(9usize) => _output = Some(map.next_value::<ReturnType>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _constness = _constness.unwrap_or_default();
+                let _asyncness = _asyncness.unwrap_or_default();
+                let _unsafety = _unsafety.unwrap_or_default();
+                let _abi = _abi.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _generics = _generics.ok_or_else(|| de::Error::missing_field("generics"))?;
+                let _inputs = _inputs.unwrap_or_default();
+                let _inputs_trailing_punct = _inputs_trailing_punct.unwrap_or_default();
+                let _variadic = _variadic.unwrap_or_default();
+                let _output = _output.ok_or_else(|| de::Error::missing_field("output"))?;
+                Ok(Signature {
+                    constness: if _constness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    asyncness: if _asyncness {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    unsafety: if _unsafety {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    abi: _abi,
+                    fn_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    paren_token: Default::default(),
+                    inputs: trailing_punct(_inputs, _inputs_trailing_punct),
+                    variadic: _variadic,
+                    output: _output,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Signature", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Stmt {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Stmt::Local(_v0) => serializer.serialize_newtype_variant("Stmt", 0u32, "Local", _v0),
+            Stmt::Item(_v0) => serializer.serialize_newtype_variant("Stmt", 1u32, "Item", _v0),
+            Stmt::Expr(_v0) => serializer.serialize_newtype_variant("Stmt", 2u32, "Expr", _v0),
+            Stmt::Semi(_v0, _v1) => serializer.serialize_newtype_variant("Stmt", 3u32, "Semi", _v0),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Stmt {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Local", "Item", "Expr", "Semi"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Stmt;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Stmt")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Local>(access)?;
+                        Ok(Stmt::Local(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Item>(access)?;
+                        Ok(Stmt::Item(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Expr>(access)?;
+                        Ok(Stmt::Expr(_v))
+                    }
+                    3usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Expr>(access)?;
+                        Ok(Stmt::Semi(_v, Default::default()))
+                    }
+                    _ => Err(unsupported_variant("Stmt", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Stmt", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TraitBound {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitBound", 4usize)?;
+        state.serialize_field("paren_token", &self.paren_token.is_some())?;
+        state.serialize_field("modifier", &self.modifier)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TraitBound {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["paren_token", "modifier", "lifetimes", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitBound;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitBound")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _paren_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _modifier = seq
+                    .next_element::<TraitBoundModifier>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _lifetimes = seq
+                    .next_element::<Option<BoundLifetimes>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(TraitBound {
+                    paren_token: if _paren_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    modifier: _modifier,
+                    lifetimes: _lifetimes,
+                    path: _path,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _paren_token = None;
+                let mut _modifier = None;
+                let mut _lifetimes = None;
+                let mut _path = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _paren_token = Some(map.next_value::<bool>()?),
+                        Some(1usize) => _modifier = Some(map.next_value::<TraitBoundModifier>()?),
+                        Some(2usize) => {
+                            _lifetimes = Some(map.next_value::<Option<BoundLifetimes>>()?)
+                        }
+                        Some(3usize) => _path = Some(map.next_value::<Path>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _paren_token = _paren_token.unwrap_or_default();
+                let _modifier = _modifier.ok_or_else(|| de::Error::missing_field("modifier"))?;
+                let _lifetimes = _lifetimes.unwrap_or_default();
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                Ok(TraitBound {
+                    paren_token: if _paren_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    modifier: _modifier,
+                    lifetimes: _lifetimes,
+                    path: _path,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitBound", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TraitBoundModifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TraitBoundModifier::None => {
+                serializer.serialize_unit_variant("TraitBoundModifier", 0u32, "None")
+            }
+            TraitBoundModifier::Maybe(_v0) => {
+                serializer.serialize_unit_variant("TraitBoundModifier", 1u32, "Maybe")
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TraitBoundModifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["None", "Maybe"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitBoundModifier;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TraitBoundModifier")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(TraitBoundModifier::None)
+                    }
+                    1usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(TraitBoundModifier::Maybe(Default::default()))
+                    }
+                    _ => Err(unsupported_variant("TraitBoundModifier", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TraitBoundModifier", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for TraitItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TraitItem::Const(_v0) => {
+                serializer.serialize_newtype_variant("TraitItem", 0u32, "Const", _v0)
+            }
+            TraitItem::Method(_v0) => {
+                serializer.serialize_newtype_variant("TraitItem", 1u32, "Method", _v0)
+            }
+            TraitItem::Type(_v0) => {
+                serializer.serialize_newtype_variant("TraitItem", 2u32, "Type", _v0)
+            }
+            TraitItem::Macro(_v0) => {
+                serializer.serialize_newtype_variant("TraitItem", 3u32, "Macro", _v0)
+            }
+            TraitItem::Verbatim(_v0) => {
+                serializer.serialize_newtype_variant("TraitItem", 4u32, "Verbatim", &TokensSer(_v0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for TraitItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Const", "Method", "Type", "Macro", "Verbatim"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TraitItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TraitItemConst>(access)?;
+                        Ok(TraitItem::Const(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TraitItemMethod>(access)?;
+                        Ok(TraitItem::Method(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TraitItemType>(access)?;
+                        Ok(TraitItem::Type(_v))
+                    }
+                    3usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TraitItemMacro>(access)?;
+                        Ok(TraitItem::Macro(_v))
+                    }
+                    4usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TokensDe>(access)?;
+                        Ok(TraitItem::Verbatim(_v.0))
+                    }
+                    _ => Err(unsupported_variant("TraitItem", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TraitItem", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for TraitItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemConst", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("default", &self.default.as_ref().map(|_v| &_v.1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for TraitItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "ty", "default"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _ty = seq
+                    .next_element::<Type>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _default = seq
+                    .next_element::<Option<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(TraitItemConst {
+                    attrs: _attrs,
+                    const_token: Default::default(),
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    default: _default.map(|_v| (Default::default(), _v)),
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _ident = None;
+                let mut _ty = None;
+                let mut _default = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(2usize) => _ty = Some(map.next_value::<Type>()?),
+                        Some(3usize) => _default = Some(map.next_value::<Option<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _ty = _ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+                let _default = _default.unwrap_or_default();
+                Ok(TraitItemConst {
+                    attrs: _attrs,
+                    const_token: Default::default(),
+                    ident: _ident.0,
+                    colon_token: Default::default(),
+                    ty: _ty,
+                    default: _default.map(|_v| (Default::default(), _v)),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemConst", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for TraitItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemMacro", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for TraitItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _mac = seq
+                    .next_element::<Macro>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _semi_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(TraitItemMacro {
+                    attrs: _attrs,
+                    mac: _mac,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _mac = None;
+                let mut _semi_token = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _mac = Some(map.next_value::<Macro>()?),
+                        Some(2usize) => _semi_token = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _mac = _mac.ok_or_else(|| de::Error::missing_field("mac"))?;
+                let _semi_token = _semi_token.unwrap_or_default();
+                Ok(TraitItemMacro {
+                    attrs: _attrs,
+                    mac: _mac,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for TraitItemMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemMethod", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("sig", &self.sig)?;
+        state.serialize_field("default", &self.default)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for TraitItemMethod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "sig", "default", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemMethod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemMethod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _sig = seq
+                    .next_element::<Signature>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _default = seq
+                    .next_element::<Option<Block>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _semi_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(TraitItemMethod {
+                    attrs: _attrs,
+                    sig: _sig,
+                    default: _default,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _sig = None;
+                let mut _default = None;
+                let mut _semi_token = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _sig = Some(map.next_value::<Signature>()?),
+                        Some(2usize) => _default = Some(map.next_value::<Option<Block>>()?),
+                        Some(3usize) => _semi_token = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _sig = _sig.ok_or_else(|| de::Error::missing_field("sig"))?;
+                let _default = _default.unwrap_or_default();
+                let _semi_token = _semi_token.unwrap_or_default();
+                Ok(TraitItemMethod {
+                    attrs: _attrs,
+                    sig: _sig,
+                    default: _default,
+                    semi_token: if _semi_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemMethod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for TraitItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemType", 7usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("bounds_trailing_punct", &self.bounds.trailing_punct())?;
+        state.serialize_field("default", &self.default.as_ref().map(|_v| &_v.1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for TraitItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "ident",
+            "generics",
+            "colon_token",
+            "bounds",
+            "bounds_trailing_punct",
+            "default",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _generics = seq
+                    .next_element::<Generics>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _colon_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _bounds = seq
+                    .next_element::<Punctuated<TypeParamBound, token::Add>>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _bounds_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                let _default = seq
+                    .next_element::<Option<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(6usize, &self))?;
+                Ok(TraitItemType {
+                    attrs: _attrs,
+                    type_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                    default: _default.map(|_v| (Default::default(), _v)),
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _ident = None;
+                let mut _generics = None;
+                let mut _colon_token = None;
+                let mut _bounds = None;
+                let mut _bounds_trailing_punct = None;
+                let mut _default = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(2usize) => _generics = Some(map.next_value::<Generics>()?),
+                        Some(3usize) => _colon_token = Some(map.next_value::<bool>()?),
+                        Some(4usize) => {
+                            _bounds =
+                                Some(map.next_value::<Punctuated<TypeParamBound, token::Add>>()?)
+                        }
+                        Some(5usize) => _bounds_trailing_punct = Some(map.next_value::<bool>()?),
+                        Some(6usize) => _default = Some(map.next_value::<Option<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _generics = _generics.ok_or_else(|| de::Error::missing_field("generics"))?;
+                let _colon_token = _colon_token.unwrap_or_default();
+                let _bounds = _bounds.unwrap_or_default();
+                let _bounds_trailing_punct = _bounds_trailing_punct.unwrap_or_default();
+                let _default = _default.unwrap_or_default();
+                Ok(TraitItemType {
+                    attrs: _attrs,
+                    type_token: Default::default(),
+                    ident: _ident.0,
+                    generics: _generics,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                    default: _default.map(|_v| (Default::default(), _v)),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Type::Array(_v0) => serializer.serialize_newtype_variant("Type", 0u32, "Array", _v0),
+            Type::BareFn(_v0) => serializer.serialize_newtype_variant("Type", 1u32, "BareFn", _v0),
+            Type::Group(_v0) => serializer.serialize_newtype_variant("Type", 2u32, "Group", _v0),
+            Type::ImplTrait(_v0) => {
+                serializer.serialize_newtype_variant("Type", 3u32, "ImplTrait", _v0)
+            }
+            Type::Infer(_v0) => serializer.serialize_newtype_variant("Type", 4u32, "Infer", _v0),
+            Type::Macro(_v0) => serializer.serialize_newtype_variant("Type", 5u32, "Macro", _v0),
+            Type::Never(_v0) => serializer.serialize_newtype_variant("Type", 6u32, "Never", _v0),
+            Type::Paren(_v0) => serializer.serialize_newtype_variant("Type", 7u32, "Paren", _v0),
+            Type::Path(_v0) => serializer.serialize_newtype_variant("Type", 8u32, "Path", _v0),
+            Type::Ptr(_v0) => serializer.serialize_newtype_variant("Type", 9u32, "Ptr", _v0),
+            Type::Reference(_v0) => {
+                serializer.serialize_newtype_variant("Type", 10u32, "Reference", _v0)
+            }
+            Type::Slice(_v0) => serializer.serialize_newtype_variant("Type", 11u32, "Slice", _v0),
+            Type::TraitObject(_v0) => {
+                serializer.serialize_newtype_variant("Type", 12u32, "TraitObject", _v0)
+            }
+            Type::Tuple(_v0) => serializer.serialize_newtype_variant("Type", 13u32, "Tuple", _v0),
+            Type::Verbatim(_v0) => {
+                serializer.serialize_newtype_variant("Type", 14u32, "Verbatim", &TokensSer(_v0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Array",
+            "BareFn",
+            "Group",
+            "ImplTrait",
+            "Infer",
+            "Macro",
+            "Never",
+            "Paren",
+            "Path",
+            "Ptr",
+            "Reference",
+            "Slice",
+            "TraitObject",
+            "Tuple",
+            "Verbatim",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Type;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Type")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeArray>(access)?;
+                        Ok(Type::Array(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeBareFn>(access)?;
+                        Ok(Type::BareFn(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeGroup>(access)?;
+                        Ok(Type::Group(_v))
+                    }
+                    3usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeImplTrait>(access)?;
+                        Ok(Type::ImplTrait(_v))
+                    }
+                    4usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeInfer>(access)?;
+                        Ok(Type::Infer(_v))
+                    }
+                    5usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeMacro>(access)?;
+                        Ok(Type::Macro(_v))
+                    }
+                    6usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeNever>(access)?;
+                        Ok(Type::Never(_v))
+                    }
+                    7usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeParen>(access)?;
+                        Ok(Type::Paren(_v))
+                    }
+                    8usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypePath>(access)?;
+                        Ok(Type::Path(_v))
+                    }
+                    9usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypePtr>(access)?;
+                        Ok(Type::Ptr(_v))
+                    }
+                    10usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeReference>(access)?;
+                        Ok(Type::Reference(_v))
+                    }
+                    11usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeSlice>(access)?;
+                        Ok(Type::Slice(_v))
+                    }
+                    12usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeTraitObject>(access)?;
+                        Ok(Type::TraitObject(_v))
+                    }
+                    13usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TypeTuple>(access)?;
+                        Ok(Type::Tuple(_v))
+                    }
+                    14usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TokensDe>(access)?;
+                        Ok(Type::Verbatim(_v.0))
+                    }
+                    _ => Err(unsupported_variant("Type", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Type", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeArray", 2usize)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.serialize_field("len", &self.len)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem", "len"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeArray;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeArray")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _elem = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _len = seq
+                    .next_element::<Expr>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(TypeArray {
+                    bracket_token: Default::default(),
+                    elem: _elem,
+                    semi_token: Default::default(),
+                    len: _len,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _elem = None;
+                let mut _len = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _elem = Some(map.next_value::<Box<Type>>()?),
+                        Some(1usize) => _len = Some(map.next_value::<Expr>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _elem = _elem.ok_or_else(|| de::Error::missing_field("elem"))?;
+                let _len = _len.ok_or_else(|| de::Error::missing_field("len"))?;
+                Ok(TypeArray {
+                    bracket_token: Default::default(),
+                    elem: _elem,
+                    semi_token: Default::default(),
+                    len: _len,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeArray", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeBareFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeBareFn", 7usize)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("abi", &self.abi)?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("inputs_trailing_punct", &self.inputs.trailing_punct())?;
+        state.serialize_field("variadic", &self.variadic.is_some())?;
+        state.serialize_field("output", &self.output)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeBareFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "lifetimes",
+            "unsafety",
+            "abi",
+            "inputs",
+            "inputs_trailing_punct",
+            "variadic",
+            "output",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeBareFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeBareFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _lifetimes = seq
+                    .next_element::<Option<BoundLifetimes>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _unsafety = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _abi = seq
+                    .next_element::<Option<Abi>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _inputs = seq
+                    .next_element::<Punctuated<BareFnArg, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _inputs_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _variadic = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                let _output = seq
+                    .next_element::<ReturnType>()?
+                    .ok_or_else(|| de::Error::invalid_length(6usize, &self))?;
+                Ok(TypeBareFn {
+                    lifetimes: _lifetimes,
+                    unsafety: if _unsafety {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    abi: _abi,
+                    fn_token: Default::default(),
+                    paren_token: Default::default(),
+                    inputs: trailing_punct(_inputs, _inputs_trailing_punct),
+                    variadic: if _variadic {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    output: _output,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _lifetimes = None;
+                let mut _unsafety = None;
+                let mut _abi = None;
+                let mut _inputs = None;
+                let mut _inputs_trailing_punct = None;
+                let mut _variadic = None;
+                let mut _output = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _lifetimes = Some(map.next_value::<Option<BoundLifetimes>>()?)
+                        }
+                        Some(1usize) => _unsafety = Some(map.next_value::<bool>()?),
+                        Some(2usize) => _abi = Some(map.next_value::<Option<Abi>>()?),
+                        Some(3usize) => {
+                            _inputs = Some(map.next_value::<Punctuated<BareFnArg, token::Comma>>()?)
+                        }
+                        Some(4usize) => _inputs_trailing_punct = Some(map.next_value::<bool>()?),
+                        Some(5usize) => _variadic = Some(map.next_value::<bool>()?),
+                        Some(6usize) => _output = Some(map.next_value::<ReturnType>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _lifetimes = _lifetimes.unwrap_or_default();
+                let _unsafety = _unsafety.unwrap_or_default();
+                let _abi = _abi.unwrap_or_default();
+                let _inputs = _inputs.unwrap_or_default();
+                let _inputs_trailing_punct = _inputs_trailing_punct.unwrap_or_default();
+                let _variadic = _variadic.unwrap_or_default();
+                let _output = _output.ok_or_else(|| de::Error::missing_field("output"))?;
+                Ok(TypeBareFn {
+                    lifetimes: _lifetimes,
+                    unsafety: if _unsafety {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    abi: _abi,
+                    fn_token: Default::default(),
+                    paren_token: Default::default(),
+                    inputs: trailing_punct(_inputs, _inputs_trailing_punct),
+                    variadic: if _variadic {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    output: _output,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeBareFn", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeGroup", 1usize)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _elem = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(TypeGroup {
+                    group_token: Default::default(),
+                    elem: _elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _elem = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _elem = Some(map.next_value::<Box<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _elem = _elem.ok_or_else(|| de::Error::missing_field("elem"))?;
+                Ok(TypeGroup {
+                    group_token: Default::default(),
+                    elem: _elem,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeGroup", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeImplTrait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeImplTrait", 2usize)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("bounds_trailing_punct", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeImplTrait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["bounds", "bounds_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeImplTrait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeImplTrait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _bounds = seq
+                    .next_element::<Punctuated<TypeParamBound, token::Add>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _bounds_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(TypeImplTrait {
+                    impl_token: Default::default(),
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _bounds = None;
+                let mut _bounds_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _bounds =
+                                Some(map.next_value::<Punctuated<TypeParamBound, token::Add>>()?)
+                        }
+                        Some(1usize) => _bounds_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _bounds = _bounds.unwrap_or_default();
+                let _bounds_trailing_punct = _bounds_trailing_punct.unwrap_or_default();
+                Ok(TypeImplTrait {
+                    impl_token: Default::default(),
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeImplTrait", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeInfer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = serializer.serialize_struct("TypeInfer", 0usize)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeInfer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeInfer;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeInfer")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeInfer {
+                    underscore_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeInfer {
+                    underscore_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeInfer", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeMacro", 1usize)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["mac"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _mac = seq
+                    .next_element::<Macro>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(TypeMacro { mac: _mac })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _mac = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _mac = Some(map.next_value::<Macro>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _mac = _mac.ok_or_else(|| de::Error::missing_field("mac"))?;
+                Ok(TypeMacro { mac: _mac })
+            }
+        }
+        deserializer.deserialize_struct("TypeMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeNever {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = serializer.serialize_struct("TypeNever", 0usize)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeNever {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeNever;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeNever")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeNever {
+                    bang_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeNever {
+                    bang_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeNever", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeParam", 7usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("bounds_trailing_punct", &self.bounds.trailing_punct())?;
+        state.serialize_field("eq_token", &self.eq_token.is_some())?;
+        state.serialize_field("default", &self.default)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "ident",
+            "colon_token",
+            "bounds",
+            "bounds_trailing_punct",
+            "eq_token",
+            "default",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeParam")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _colon_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _bounds = seq
+                    .next_element::<Punctuated<TypeParamBound, token::Add>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                let _bounds_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(4usize, &self))?;
+                let _eq_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(5usize, &self))?;
+                let _default = seq
+                    .next_element::<Option<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(6usize, &self))?;
+                Ok(TypeParam {
+                    attrs: _attrs,
+                    ident: _ident.0,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                    eq_token: if _eq_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    default: _default,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _ident = None;
+                let mut _colon_token = None;
+                let mut _bounds = None;
+                let mut _bounds_trailing_punct = None;
+                let mut _eq_token = None;
+                let mut _default = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(2usize) => _colon_token = Some(map.next_value::<bool>()?),
+                        Some(3usize) => {
+                            _bounds =
+                                Some(map.next_value::<Punctuated<TypeParamBound, token::Add>>()?)
+                        }
+                        Some(4usize) => _bounds_trailing_punct = Some(map.next_value::<bool>()?),
+                        Some(5usize) => _eq_token = Some(map.next_value::<bool>()?),
+                        Some(6usize) => _default = Some(map.next_value::<Option<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _colon_token = _colon_token.unwrap_or_default();
+                let _bounds = _bounds.unwrap_or_default();
+                let _bounds_trailing_punct = _bounds_trailing_punct.unwrap_or_default();
+                let _eq_token = _eq_token.unwrap_or_default();
+                let _default = _default.unwrap_or_default();
+                Ok(TypeParam {
+                    attrs: _attrs,
+                    ident: _ident.0,
+                    colon_token: if _colon_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                    eq_token: if _eq_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    default: _default,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeParam", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeParamBound {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TypeParamBound::Trait(_v0) => {
+                serializer.serialize_newtype_variant("TypeParamBound", 0u32, "Trait", _v0)
+            }
+            TypeParamBound::Lifetime(_v0) => {
+                serializer.serialize_newtype_variant("TypeParamBound", 1u32, "Lifetime", _v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeParamBound {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Trait", "Lifetime"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeParamBound;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TypeParamBound")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<TraitBound>(access)?;
+                        Ok(TypeParamBound::Trait(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<Lifetime>(access)?;
+                        Ok(TypeParamBound::Lifetime(_v))
+                    }
+                    _ => Err(unsupported_variant("TypeParamBound", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TypeParamBound", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeParen {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeParen", 1usize)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeParen {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeParen;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeParen")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _elem = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(TypeParen {
+                    paren_token: Default::default(),
+                    elem: _elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _elem = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _elem = Some(map.next_value::<Box<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _elem = _elem.ok_or_else(|| de::Error::missing_field("elem"))?;
+                Ok(TypeParen {
+                    paren_token: Default::default(),
+                    elem: _elem,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeParen", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypePath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypePath", 2usize)?;
+        state.serialize_field("qself", &self.qself)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypePath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["qself", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypePath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypePath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _qself = seq
+                    .next_element::<Option<QSelf>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _path = seq
+                    .next_element::<Path>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(TypePath {
+                    qself: _qself,
+                    path: _path,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _qself = None;
+                let mut _path = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _qself = Some(map.next_value::<Option<QSelf>>()?),
+                        Some(1usize) => _path = Some(map.next_value::<Path>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _qself = _qself.unwrap_or_default();
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                Ok(TypePath {
+                    qself: _qself,
+                    path: _path,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypePath", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypePtr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypePtr", 3usize)?;
+        state.serialize_field("const_token", &self.const_token.is_some())?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypePtr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["const_token", "mutability", "elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypePtr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypePtr")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _const_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _mutability = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _elem = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(TypePtr {
+                    star_token: Default::default(),
+                    const_token: if _const_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    elem: _elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _const_token = None;
+                let mut _mutability = None;
+                let mut _elem = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _const_token = Some(map.next_value::<bool>()?),
+                        Some(1usize) => _mutability = Some(map.next_value::<bool>()?),
+                        Some(2usize) => _elem = Some(map.next_value::<Box<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _const_token = _const_token.unwrap_or_default();
+                let _mutability = _mutability.unwrap_or_default();
+                let _elem = _elem.ok_or_else(|| de::Error::missing_field("elem"))?;
+                Ok(TypePtr {
+                    star_token: Default::default(),
+                    const_token: if _const_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    elem: _elem,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypePtr", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeReference", 3usize)?;
+        state.serialize_field("lifetime", &self.lifetime)?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetime", "mutability", "elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _lifetime = seq
+                    .next_element::<Option<Lifetime>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _mutability = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _elem = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(TypeReference {
+                    and_token: Default::default(),
+                    lifetime: _lifetime,
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    elem: _elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _lifetime = None;
+                let mut _mutability = None;
+                let mut _elem = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _lifetime = Some(map.next_value::<Option<Lifetime>>()?),
+                        Some(1usize) => _mutability = Some(map.next_value::<bool>()?),
+                        Some(2usize) => _elem = Some(map.next_value::<Box<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _lifetime = _lifetime.unwrap_or_default();
+                let _mutability = _mutability.unwrap_or_default();
+                let _elem = _elem.ok_or_else(|| de::Error::missing_field("elem"))?;
+                Ok(TypeReference {
+                    and_token: Default::default(),
+                    lifetime: _lifetime,
+                    mutability: if _mutability {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    elem: _elem,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeReference", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeSlice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeSlice", 1usize)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeSlice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeSlice;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeSlice")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _elem = seq
+                    .next_element::<Box<Type>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(TypeSlice {
+                    bracket_token: Default::default(),
+                    elem: _elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _elem = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _elem = Some(map.next_value::<Box<Type>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _elem = _elem.ok_or_else(|| de::Error::missing_field("elem"))?;
+                Ok(TypeSlice {
+                    bracket_token: Default::default(),
+                    elem: _elem,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeSlice", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeTraitObject {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeTraitObject", 3usize)?;
+        state.serialize_field("dyn_token", &self.dyn_token.is_some())?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("bounds_trailing_punct", &self.bounds.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeTraitObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["dyn_token", "bounds", "bounds_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeTraitObject;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeTraitObject")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _dyn_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _bounds = seq
+                    .next_element::<Punctuated<TypeParamBound, token::Add>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _bounds_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                Ok(TypeTraitObject {
+                    dyn_token: if _dyn_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _dyn_token = None;
+                let mut _bounds = None;
+                let mut _bounds_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _dyn_token = Some(map.next_value::<bool>()?),
+                        Some(1usize) => {
+                            _bounds =
+                                Some(map.next_value::<Punctuated<TypeParamBound, token::Add>>()?)
+                        }
+                        Some(2usize) => _bounds_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _dyn_token = _dyn_token.unwrap_or_default();
+                let _bounds = _bounds.unwrap_or_default();
+                let _bounds_trailing_punct = _bounds_trailing_punct.unwrap_or_default();
+                Ok(TypeTraitObject {
+                    dyn_token: if _dyn_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    bounds: trailing_punct(_bounds, _bounds_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeTraitObject", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeTuple", 2usize)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.serialize_field("elems_trailing_punct", &self.elems.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elems", "elems_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _elems = seq
+                    .next_element::<Punctuated<Type, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _elems_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(TypeTuple {
+                    paren_token: Default::default(),
+                    elems: trailing_punct(_elems, _elems_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _elems = None;
+                let mut _elems_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _elems = Some(map.next_value::<Punctuated<Type, token::Comma>>()?)
+                        }
+                        Some(1usize) => _elems_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _elems = _elems.unwrap_or_default();
+                let _elems_trailing_punct = _elems_trailing_punct.unwrap_or_default();
+                Ok(TypeTuple {
+                    paren_token: Default::default(),
+                    elems: trailing_punct(_elems, _elems_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeTuple", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for UnOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            UnOp::Deref(_v0) => serializer.serialize_unit_variant("UnOp", 0u32, "Deref"),
+            UnOp::Not(_v0) => serializer.serialize_unit_variant("UnOp", 1u32, "Not"),
+            UnOp::Neg(_v0) => serializer.serialize_unit_variant("UnOp", 2u32, "Neg"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for UnOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Deref", "Not", "Neg"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UnOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum UnOp")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(UnOp::Deref(Default::default()))
+                    }
+                    1usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(UnOp::Not(Default::default()))
+                    }
+                    2usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(UnOp::Neg(Default::default()))
+                    }
+                    _ => Err(unsupported_variant("UnOp", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("UnOp", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UseGlob {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = serializer.serialize_struct("UseGlob", 0usize)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UseGlob {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseGlob;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseGlob")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(UseGlob {
+                    star_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UseGlob {
+                    star_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseGlob", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UseGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseGroup", 2usize)?;
+        state.serialize_field("items", &self.items)?;
+        state.serialize_field("items_trailing_punct", &self.items.trailing_punct())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UseGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["items", "items_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _items = seq
+                    .next_element::<Punctuated<UseTree, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _items_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(UseGroup {
+                    brace_token: Default::default(),
+                    items: trailing_punct(_items, _items_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _items = None;
+                let mut _items_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _items = Some(map.next_value::<Punctuated<UseTree, token::Comma>>()?)
+                        }
+                        Some(1usize) => _items_trailing_punct = Some(map.next_value::<bool>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _items = _items.unwrap_or_default();
+                let _items_trailing_punct = _items_trailing_punct.unwrap_or_default();
+                Ok(UseGroup {
+                    brace_token: Default::default(),
+                    items: trailing_punct(_items, _items_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseGroup", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UseName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseName", 1usize)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseName;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseName")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(UseName { ident: _ident.0 })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _ident = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                Ok(UseName { ident: _ident.0 })
+            }
+        }
+        deserializer.deserialize_struct("UseName", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UsePath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UsePath", 2usize)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("tree", &self.tree)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UsePath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "tree"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UsePath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UsePath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _tree = seq
+                    .next_element::<Box<UseTree>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(UsePath {
+                    ident: _ident.0,
+                    colon2_token: Default::default(),
+                    tree: _tree,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _ident = None;
+                let mut _tree = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(1usize) => _tree = Some(map.next_value::<Box<UseTree>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _tree = _tree.ok_or_else(|| de::Error::missing_field("tree"))?;
+                Ok(UsePath {
+                    ident: _ident.0,
+                    colon2_token: Default::default(),
+                    tree: _tree,
+                })
+            }
+        }
+        deserializer.deserialize_struct("UsePath", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UseRename {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseRename", 2usize)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("rename", &IdentSer(&self.rename))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UseRename {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "rename"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseRename;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseRename")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _rename = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(UseRename {
+                    ident: _ident.0,
+                    as_token: Default::default(),
+                    rename: _rename.0,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _ident = None;
+                let mut _rename = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(1usize) => _rename = Some(map.next_value::<IdentDe>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _rename = _rename.ok_or_else(|| de::Error::missing_field("rename"))?;
+                Ok(UseRename {
+                    ident: _ident.0,
+                    as_token: Default::default(),
+                    rename: _rename.0,
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseRename", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UseTree {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            UseTree::Path(_v0) => {
+                serializer.serialize_newtype_variant("UseTree", 0u32, "Path", _v0)
+            }
+            UseTree::Name(_v0) => {
+                serializer.serialize_newtype_variant("UseTree", 1u32, "Name", _v0)
+            }
+            UseTree::Rename(_v0) => {
+                serializer.serialize_newtype_variant("UseTree", 2u32, "Rename", _v0)
+            }
+            UseTree::Glob(_v0) => {
+                serializer.serialize_newtype_variant("UseTree", 3u32, "Glob", _v0)
+            }
+            UseTree::Group(_v0) => {
+                serializer.serialize_newtype_variant("UseTree", 4u32, "Group", _v0)
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UseTree {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Path", "Name", "Rename", "Glob", "Group"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseTree;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum UseTree")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<UsePath>(access)?;
+                        Ok(UseTree::Path(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<UseName>(access)?;
+                        Ok(UseTree::Name(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<UseRename>(access)?;
+                        Ok(UseTree::Rename(_v))
+                    }
+                    3usize => {
+                        let _v = de::VariantAccess::newtype_variant::<UseGlob>(access)?;
+                        Ok(UseTree::Glob(_v))
+                    }
+                    4usize => {
+                        let _v = de::VariantAccess::newtype_variant::<UseGroup>(access)?;
+                        Ok(UseTree::Group(_v))
+                    }
+                    _ => Err(unsupported_variant("UseTree", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("UseTree", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Variadic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Variadic", 1usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Variadic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Variadic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Variadic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                Ok(Variadic {
+                    attrs: _attrs,
+                    dots: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                Ok(Variadic {
+                    attrs: _attrs,
+                    dots: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Variadic", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Variant {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Variant", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &IdentSer(&self.ident))?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("discriminant", &self.discriminant.as_ref().map(|_v| &_v.1))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "fields", "discriminant"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Variant;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Variant")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _attrs = seq
+                    .next_element::<Vec<Attribute>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _ident = seq
+                    .next_element::<IdentDe>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                let _fields = seq
+                    .next_element::<Fields>()?
+                    .ok_or_else(|| de::Error::invalid_length(2usize, &self))?;
+                let _discriminant = seq
+                    .next_element::<Option<Expr>>()?
+                    .ok_or_else(|| de::Error::invalid_length(3usize, &self))?;
+                Ok(Variant {
+                    attrs: _attrs,
+                    ident: _ident.0,
+                    fields: _fields,
+                    discriminant: _discriminant.map(|_v| (Default::default(), _v)),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _attrs = None;
+                let mut _ident = None;
+                let mut _fields = None;
+                let mut _discriminant = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _attrs = Some(map.next_value::<Vec<Attribute>>()?),
+                        Some(1usize) => _ident = Some(map.next_value::<IdentDe>()?),
+                        Some(2usize) => _fields = Some(map.next_value::<Fields>()?),
+                        Some(3usize) => _discriminant = Some(map.next_value::<Option<Expr>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _attrs = _attrs.unwrap_or_default();
+                let _ident = _ident.ok_or_else(|| de::Error::missing_field("ident"))?;
+                let _fields = _fields.ok_or_else(|| de::Error::missing_field("fields"))?;
+                let _discriminant = _discriminant.unwrap_or_default();
+                Ok(Variant {
+                    attrs: _attrs,
+                    ident: _ident.0,
+                    fields: _fields,
+                    discriminant: _discriminant.map(|_v| (Default::default(), _v)),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Variant", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for VisCrate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = serializer.serialize_struct("VisCrate", 0usize)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for VisCrate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = VisCrate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisCrate")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(VisCrate {
+                    crate_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(VisCrate {
+                    crate_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("VisCrate", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for VisPublic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let state = serializer.serialize_struct("VisPublic", 0usize)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for VisPublic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = VisPublic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisPublic")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(VisPublic {
+                    pub_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(VisPublic {
+                    pub_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("VisPublic", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for VisRestricted {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("VisRestricted", 2usize)?;
+        state.serialize_field("in_token", &self.in_token.is_some())?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for VisRestricted {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["in_token", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = VisRestricted;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisRestricted")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _in_token = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _path = seq
+                    .next_element::<Box<Path>>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(VisRestricted {
+                    pub_token: Default::default(),
+                    paren_token: Default::default(),
+                    in_token: if _in_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    path: _path,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _in_token = None;
+                let mut _path = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => _in_token = Some(map.next_value::<bool>()?),
+                        Some(1usize) => _path = Some(map.next_value::<Box<Path>>()?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _in_token = _in_token.unwrap_or_default();
+                let _path = _path.ok_or_else(|| de::Error::missing_field("path"))?;
+                Ok(VisRestricted {
+                    pub_token: Default::default(),
+                    paren_token: Default::default(),
+                    in_token: if _in_token {
+                        Some(Default::default())
+                    } else {
+                        None
+                    },
+                    path: _path,
+                })
+            }
+        }
+        deserializer.deserialize_struct("VisRestricted", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Visibility {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Visibility::Public(_v0) => {
+                serializer.serialize_newtype_variant("Visibility", 0u32, "Public", _v0)
+            }
+            Visibility::Crate(_v0) => {
+                serializer.serialize_newtype_variant("Visibility", 1u32, "Crate", _v0)
+            }
+            Visibility::Restricted(_v0) => {
+                serializer.serialize_newtype_variant("Visibility", 2u32, "Restricted", _v0)
+            }
+            Visibility::Inherited => {
+                serializer.serialize_unit_variant("Visibility", 3u32, "Inherited")
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Public", "Crate", "Restricted", "Inherited"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Visibility;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Visibility")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<VisPublic>(access)?;
+                        Ok(Visibility::Public(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<VisCrate>(access)?;
+                        Ok(Visibility::Crate(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<VisRestricted>(access)?;
+                        Ok(Visibility::Restricted(_v))
+                    }
+                    3usize => {
+                        de::VariantAccess::unit_variant(access)?;
+                        Ok(Visibility::Inherited)
+                    }
+                    _ => Err(unsupported_variant("Visibility", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Visibility", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for WhereClause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("WhereClause", 2usize)?;
+        state.serialize_field("predicates", &self.predicates)?;
+        state.serialize_field(
+            "predicates_trailing_punct",
+            &self.predicates.trailing_punct(),
+        )?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for WhereClause {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["predicates", "predicates_trailing_punct"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = WhereClause;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct WhereClause")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let _predicates = seq
+                    .next_element::<Punctuated<WherePredicate, token::Comma>>()?
+                    .ok_or_else(|| de::Error::invalid_length(0usize, &self))?;
+                let _predicates_trailing_punct = seq
+                    .next_element::<bool>()?
+                    .ok_or_else(|| de::Error::invalid_length(1usize, &self))?;
+                Ok(WhereClause {
+                    where_token: Default::default(),
+                    predicates: trailing_punct(_predicates, _predicates_trailing_punct),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut _predicates = None;
+                let mut _predicates_trailing_punct = None;
+                while let Some(key) = map.next_key_seed(FieldKey(FIELDS))? {
+                    match key {
+                        Some(0usize) => {
+                            _predicates =
+                                Some(map.next_value::<Punctuated<WherePredicate, token::Comma>>()?)
+                        }
+                        Some(1usize) => {
+                            _predicates_trailing_punct = Some(map.next_value::<bool>()?)
+                        }
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let _predicates = _predicates.unwrap_or_default();
+                let _predicates_trailing_punct = _predicates_trailing_punct.unwrap_or_default();
+                Ok(WhereClause {
+                    where_token: Default::default(),
+                    predicates: trailing_punct(_predicates, _predicates_trailing_punct),
+                })
+            }
+        }
+        deserializer.deserialize_struct("WhereClause", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for WherePredicate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            WherePredicate::Type(_v0) => {
+                serializer.serialize_newtype_variant("WherePredicate", 0u32, "Type", _v0)
+            }
+            WherePredicate::Lifetime(_v0) => {
+                serializer.serialize_newtype_variant("WherePredicate", 1u32, "Lifetime", _v0)
+            }
+            WherePredicate::Eq(_v0) => {
+                serializer.serialize_newtype_variant("WherePredicate", 2u32, "Eq", _v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for WherePredicate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Lifetime", "Eq"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = WherePredicate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum WherePredicate")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant_seed(VariantKey(VARIANTS))?;
+                match variant {
+                    0usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PredicateType>(access)?;
+                        Ok(WherePredicate::Type(_v))
+                    }
+                    1usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PredicateLifetime>(access)?;
+                        Ok(WherePredicate::Lifetime(_v))
+                    }
+                    2usize => {
+                        let _v = de::VariantAccess::newtype_variant::<PredicateEq>(access)?;
+                        Ok(WherePredicate::Eq(_v))
+                    }
+                    _ => Err(unsupported_variant("WherePredicate", VARIANTS[variant])),
+                }
+            }
+        }
+        deserializer.deserialize_enum("WherePredicate", VARIANTS, Visitor)
+    }
+}
@@ -487,6 +487,18 @@ pub trait Visit<'ast> {
         visit_meta(self, i)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_expr(&mut self, i: &'ast MetaExpr) {
+        visit_meta_expr(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_expr_list(&mut self, i: &'ast MetaExprList) {
+        visit_meta_expr_list(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_expr_name_value(&mut self, i: &'ast MetaExprNameValue) {
+        visit_meta_expr_name_value(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_meta_list(&mut self, i: &'ast MetaList) {
         visit_meta_list(self, i)
     }
@@ -494,6 +506,10 @@ pub trait Visit<'ast> {
     fn visit_meta_name_value(&mut self, i: &'ast MetaNameValue) {
         visit_meta_name_value(self, i)
     }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_value(&mut self, i: &'ast MetaValue) {
+        visit_meta_value(self, i)
+    }
     #[cfg(feature = "full")]
     fn visit_method_turbofish(&mut self, i: &'ast MethodTurbofish) {
         visit_method_turbofish(self, i)
@@ -503,6 +519,10 @@ pub trait Visit<'ast> {
         visit_nested_meta(self, i)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_nested_meta_expr(&mut self, i: &'ast NestedMetaExpr) {
+        visit_nested_meta_expr(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_parenthesized_generic_arguments(&mut self, i: &'ast ParenthesizedGenericArguments) {
         visit_parenthesized_generic_arguments(self, i)
     }
@@ -2688,6 +2708,47 @@ where
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_expr<'ast, V>(v: &mut V, node: &'ast MetaExpr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        MetaExpr::Path(_binding_0) => {
+            v.visit_path(_binding_0);
+        }
+        MetaExpr::List(_binding_0) => {
+            v.visit_meta_expr_list(_binding_0);
+        }
+        MetaExpr::NameValue(_binding_0) => {
+            v.visit_meta_expr_name_value(_binding_0);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_expr_list<'ast, V>(v: &mut V, node: &'ast MetaExprList)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path);
+    tokens_helper(v, &node.paren_token.span);
+    for el in Punctuated::pairs(&node.nested) {
+        let (it, p) = el.into_tuple();
+        v.visit_nested_meta_expr(it);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_expr_name_value<'ast, V>(v: &mut V, node: &'ast MetaExprNameValue)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path);
+    tokens_helper(v, &node.eq_token.spans);
+    v.visit_meta_value(&node.value);
+}
+#[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_meta_list<'ast, V>(v: &mut V, node: &'ast MetaList)
 where
     V: Visit<'ast> + ?Sized,
@@ -2711,6 +2772,20 @@ where
     tokens_helper(v, &node.eq_token.spans);
     v.visit_lit(&node.lit);
 }
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_value<'ast, V>(v: &mut V, node: &'ast MetaValue)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        MetaValue::Expr(_binding_0) => {
+            v.visit_expr(_binding_0);
+        }
+        MetaValue::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+}
 #[cfg(feature = "full")]
 pub fn visit_method_turbofish<'ast, V>(v: &mut V, node: &'ast MethodTurbofish)
 where
@@ -2742,6 +2817,20 @@ where
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_nested_meta_expr<'ast, V>(v: &mut V, node: &'ast NestedMetaExpr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        NestedMetaExpr::Meta(_binding_0) => {
+            v.visit_meta_expr(_binding_0);
+        }
+        NestedMetaExpr::Lit(_binding_0) => {
+            v.visit_lit(_binding_0);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_parenthesized_generic_arguments<'ast, V>(
     v: &mut V,
     node: &'ast ParenthesizedGenericArguments,
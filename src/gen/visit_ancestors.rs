@@ -0,0 +1,3865 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(unused_variables)]
+use crate::node::NodeRef;
+use crate::*;
+use std::ops::Deref;
+#[cfg(feature = "full")]
+macro_rules! full {
+    ($e:expr) => {
+        $e
+    };
+}
+#[cfg(all(feature = "derive", not(feature = "full")))]
+macro_rules! full {
+    ($e:expr) => {
+        unreachable!()
+    };
+}
+/// Syntax tree traversal to walk a shared borrow of a syntax tree
+/// while keeping track of the ancestors of each node.
+///
+/// See the [module documentation] for details.
+///
+/// [module documentation]: self
+///
+/// *This trait is available if Syn is built with the `"visit"` feature.*
+pub trait VisitAncestors<'ast> {
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_abi(&mut self, i: &'ast Abi, ancestors: &mut Ancestors<'ast>) {
+        visit_abi(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_angle_bracketed_generic_arguments(
+        &mut self,
+        i: &'ast AngleBracketedGenericArguments,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_angle_bracketed_generic_arguments(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_arm(&mut self, i: &'ast Arm, ancestors: &mut Ancestors<'ast>) {
+        visit_arm(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_style(&mut self, i: &'ast AttrStyle, ancestors: &mut Ancestors<'ast>) {
+        visit_attr_style(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attribute(&mut self, i: &'ast Attribute, ancestors: &mut Ancestors<'ast>) {
+        visit_attribute(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bare_fn_arg(&mut self, i: &'ast BareFnArg, ancestors: &mut Ancestors<'ast>) {
+        visit_bare_fn_arg(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bin_op(&mut self, i: &'ast BinOp, ancestors: &mut Ancestors<'ast>) {
+        visit_bin_op(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_binding(&mut self, i: &'ast Binding, ancestors: &mut Ancestors<'ast>) {
+        visit_binding(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_block(&mut self, i: &'ast Block, ancestors: &mut Ancestors<'ast>) {
+        visit_block(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bound_lifetimes(&mut self, i: &'ast BoundLifetimes, ancestors: &mut Ancestors<'ast>) {
+        visit_bound_lifetimes(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_const_param(&mut self, i: &'ast ConstParam, ancestors: &mut Ancestors<'ast>) {
+        visit_const_param(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_constraint(&mut self, i: &'ast Constraint, ancestors: &mut Ancestors<'ast>) {
+        visit_constraint(self, i, ancestors)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data(&mut self, i: &'ast Data, ancestors: &mut Ancestors<'ast>) {
+        visit_data(self, i, ancestors)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_enum(&mut self, i: &'ast DataEnum, ancestors: &mut Ancestors<'ast>) {
+        visit_data_enum(self, i, ancestors)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_struct(&mut self, i: &'ast DataStruct, ancestors: &mut Ancestors<'ast>) {
+        visit_data_struct(self, i, ancestors)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_union(&mut self, i: &'ast DataUnion, ancestors: &mut Ancestors<'ast>) {
+        visit_data_union(self, i, ancestors)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_derive_input(&mut self, i: &'ast DeriveInput, ancestors: &mut Ancestors<'ast>) {
+        visit_derive_input(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr(&mut self, i: &'ast Expr, ancestors: &mut Ancestors<'ast>) {
+        visit_expr(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_array(&mut self, i: &'ast ExprArray, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_array(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign(&mut self, i: &'ast ExprAssign, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_assign(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign_op(&mut self, i: &'ast ExprAssignOp, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_assign_op(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_async(&mut self, i: &'ast ExprAsync, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_async(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_await(&mut self, i: &'ast ExprAwait, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_await(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_binary(&mut self, i: &'ast ExprBinary, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_binary(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_block(&mut self, i: &'ast ExprBlock, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_block(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_box(&mut self, i: &'ast ExprBox, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_box(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_break(&mut self, i: &'ast ExprBreak, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_break(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_call(&mut self, i: &'ast ExprCall, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_call(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_cast(&mut self, i: &'ast ExprCast, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_cast(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_closure(&mut self, i: &'ast ExprClosure, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_closure(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_continue(&mut self, i: &'ast ExprContinue, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_continue(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_field(&mut self, i: &'ast ExprField, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_field(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_for_loop(&mut self, i: &'ast ExprForLoop, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_for_loop(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_group(&mut self, i: &'ast ExprGroup, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_group(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_if(&mut self, i: &'ast ExprIf, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_if(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_index(&mut self, i: &'ast ExprIndex, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_index(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_let(&mut self, i: &'ast ExprLet, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_let(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_lit(&mut self, i: &'ast ExprLit, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_lit(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_loop(&mut self, i: &'ast ExprLoop, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_loop(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_macro(&mut self, i: &'ast ExprMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_macro(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_match(&mut self, i: &'ast ExprMatch, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_match(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_method_call(&mut self, i: &'ast ExprMethodCall, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_method_call(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_paren(&mut self, i: &'ast ExprParen, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_paren(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_path(&mut self, i: &'ast ExprPath, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_path(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_range(&mut self, i: &'ast ExprRange, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_range(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_reference(&mut self, i: &'ast ExprReference, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_reference(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_repeat(&mut self, i: &'ast ExprRepeat, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_repeat(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_return(&mut self, i: &'ast ExprReturn, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_return(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_struct(&mut self, i: &'ast ExprStruct, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_struct(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try(&mut self, i: &'ast ExprTry, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_try(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try_block(&mut self, i: &'ast ExprTryBlock, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_try_block(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_tuple(&mut self, i: &'ast ExprTuple, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_tuple(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_type(&mut self, i: &'ast ExprType, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_type(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_unary(&mut self, i: &'ast ExprUnary, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_unary(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_unsafe(&mut self, i: &'ast ExprUnsafe, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_unsafe(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_while(&mut self, i: &'ast ExprWhile, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_while(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_yield(&mut self, i: &'ast ExprYield, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_yield(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_field(&mut self, i: &'ast Field, ancestors: &mut Ancestors<'ast>) {
+        visit_field(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_pat(&mut self, i: &'ast FieldPat, ancestors: &mut Ancestors<'ast>) {
+        visit_field_pat(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_value(&mut self, i: &'ast FieldValue, ancestors: &mut Ancestors<'ast>) {
+        visit_field_value(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields(&mut self, i: &'ast Fields, ancestors: &mut Ancestors<'ast>) {
+        visit_fields(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_named(&mut self, i: &'ast FieldsNamed, ancestors: &mut Ancestors<'ast>) {
+        visit_fields_named(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_unnamed(&mut self, i: &'ast FieldsUnnamed, ancestors: &mut Ancestors<'ast>) {
+        visit_fields_unnamed(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_file(&mut self, i: &'ast File, ancestors: &mut Ancestors<'ast>) {
+        visit_file(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_fn_arg(&mut self, i: &'ast FnArg, ancestors: &mut Ancestors<'ast>) {
+        visit_fn_arg(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item(&mut self, i: &'ast ForeignItem, ancestors: &mut Ancestors<'ast>) {
+        visit_foreign_item(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_fn(&mut self, i: &'ast ForeignItemFn, ancestors: &mut Ancestors<'ast>) {
+        visit_foreign_item_fn(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_macro(
+        &mut self,
+        i: &'ast ForeignItemMacro,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_foreign_item_macro(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_static(
+        &mut self,
+        i: &'ast ForeignItemStatic,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_foreign_item_static(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_type(
+        &mut self,
+        i: &'ast ForeignItemType,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_foreign_item_type(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generic_argument(
+        &mut self,
+        i: &'ast GenericArgument,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_generic_argument(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_generic_method_argument(
+        &mut self,
+        i: &'ast GenericMethodArgument,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_generic_method_argument(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generic_param(&mut self, i: &'ast GenericParam, ancestors: &mut Ancestors<'ast>) {
+        visit_generic_param(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generics(&mut self, i: &'ast Generics, ancestors: &mut Ancestors<'ast>) {
+        visit_generics(self, i, ancestors)
+    }
+    fn visit_ident(&mut self, i: &'ast Ident, ancestors: &mut Ancestors<'ast>) {
+        visit_ident(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item(&mut self, i: &'ast ImplItem, ancestors: &mut Ancestors<'ast>) {
+        visit_impl_item(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_const(&mut self, i: &'ast ImplItemConst, ancestors: &mut Ancestors<'ast>) {
+        visit_impl_item_const(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_macro(&mut self, i: &'ast ImplItemMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_impl_item_macro(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_method(&mut self, i: &'ast ImplItemMethod, ancestors: &mut Ancestors<'ast>) {
+        visit_impl_item_method(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_type(&mut self, i: &'ast ImplItemType, ancestors: &mut Ancestors<'ast>) {
+        visit_impl_item_type(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_index(&mut self, i: &'ast Index, ancestors: &mut Ancestors<'ast>) {
+        visit_index(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item(&mut self, i: &'ast Item, ancestors: &mut Ancestors<'ast>) {
+        visit_item(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_const(&mut self, i: &'ast ItemConst, ancestors: &mut Ancestors<'ast>) {
+        visit_item_const(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_enum(&mut self, i: &'ast ItemEnum, ancestors: &mut Ancestors<'ast>) {
+        visit_item_enum(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_extern_crate(
+        &mut self,
+        i: &'ast ItemExternCrate,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_item_extern_crate(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_fn(&mut self, i: &'ast ItemFn, ancestors: &mut Ancestors<'ast>) {
+        visit_item_fn(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_foreign_mod(&mut self, i: &'ast ItemForeignMod, ancestors: &mut Ancestors<'ast>) {
+        visit_item_foreign_mod(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_impl(&mut self, i: &'ast ItemImpl, ancestors: &mut Ancestors<'ast>) {
+        visit_item_impl(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro(&mut self, i: &'ast ItemMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_item_macro(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro2(&mut self, i: &'ast ItemMacro2, ancestors: &mut Ancestors<'ast>) {
+        visit_item_macro2(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_mod(&mut self, i: &'ast ItemMod, ancestors: &mut Ancestors<'ast>) {
+        visit_item_mod(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_static(&mut self, i: &'ast ItemStatic, ancestors: &mut Ancestors<'ast>) {
+        visit_item_static(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_struct(&mut self, i: &'ast ItemStruct, ancestors: &mut Ancestors<'ast>) {
+        visit_item_struct(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait(&mut self, i: &'ast ItemTrait, ancestors: &mut Ancestors<'ast>) {
+        visit_item_trait(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait_alias(&mut self, i: &'ast ItemTraitAlias, ancestors: &mut Ancestors<'ast>) {
+        visit_item_trait_alias(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_type(&mut self, i: &'ast ItemType, ancestors: &mut Ancestors<'ast>) {
+        visit_item_type(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_union(&mut self, i: &'ast ItemUnion, ancestors: &mut Ancestors<'ast>) {
+        visit_item_union(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_use(&mut self, i: &'ast ItemUse, ancestors: &mut Ancestors<'ast>) {
+        visit_item_use(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_label(&mut self, i: &'ast Label, ancestors: &mut Ancestors<'ast>) {
+        visit_label(self, i, ancestors)
+    }
+    fn visit_lifetime(&mut self, i: &'ast Lifetime, ancestors: &mut Ancestors<'ast>) {
+        visit_lifetime(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lifetime_def(&mut self, i: &'ast LifetimeDef, ancestors: &mut Ancestors<'ast>) {
+        visit_lifetime_def(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit(&mut self, i: &'ast Lit, ancestors: &mut Ancestors<'ast>) {
+        visit_lit(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_bool(&mut self, i: &'ast LitBool, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_bool(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_byte(&mut self, i: &'ast LitByte, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_byte(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_byte_str(&mut self, i: &'ast LitByteStr, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_byte_str(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_char(&mut self, i: &'ast LitChar, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_char(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_float(&mut self, i: &'ast LitFloat, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_float(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_int(&mut self, i: &'ast LitInt, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_int(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_str(&mut self, i: &'ast LitStr, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_str(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_local(&mut self, i: &'ast Local, ancestors: &mut Ancestors<'ast>) {
+        visit_local(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_macro(&mut self, i: &'ast Macro, ancestors: &mut Ancestors<'ast>) {
+        visit_macro(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_macro_delimiter(&mut self, i: &'ast MacroDelimiter, ancestors: &mut Ancestors<'ast>) {
+        visit_macro_delimiter(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_member(&mut self, i: &'ast Member, ancestors: &mut Ancestors<'ast>) {
+        visit_member(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta(&mut self, i: &'ast Meta, ancestors: &mut Ancestors<'ast>) {
+        visit_meta(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_expr(&mut self, i: &'ast MetaExpr, ancestors: &mut Ancestors<'ast>) {
+        visit_meta_expr(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_expr_list(&mut self, i: &'ast MetaExprList, ancestors: &mut Ancestors<'ast>) {
+        visit_meta_expr_list(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_expr_name_value(
+        &mut self,
+        i: &'ast MetaExprNameValue,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_meta_expr_name_value(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_list(&mut self, i: &'ast MetaList, ancestors: &mut Ancestors<'ast>) {
+        visit_meta_list(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_name_value(&mut self, i: &'ast MetaNameValue, ancestors: &mut Ancestors<'ast>) {
+        visit_meta_name_value(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_value(&mut self, i: &'ast MetaValue, ancestors: &mut Ancestors<'ast>) {
+        visit_meta_value(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_method_turbofish(
+        &mut self,
+        i: &'ast MethodTurbofish,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_method_turbofish(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_nested_meta(&mut self, i: &'ast NestedMeta, ancestors: &mut Ancestors<'ast>) {
+        visit_nested_meta(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_nested_meta_expr(&mut self, i: &'ast NestedMetaExpr, ancestors: &mut Ancestors<'ast>) {
+        visit_nested_meta_expr(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_parenthesized_generic_arguments(
+        &mut self,
+        i: &'ast ParenthesizedGenericArguments,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_parenthesized_generic_arguments(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat(&mut self, i: &'ast Pat, ancestors: &mut Ancestors<'ast>) {
+        visit_pat(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_box(&mut self, i: &'ast PatBox, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_box(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_ident(&mut self, i: &'ast PatIdent, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_ident(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_lit(&mut self, i: &'ast PatLit, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_lit(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_macro(&mut self, i: &'ast PatMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_macro(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_or(&mut self, i: &'ast PatOr, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_or(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_path(&mut self, i: &'ast PatPath, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_path(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_range(&mut self, i: &'ast PatRange, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_range(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_reference(&mut self, i: &'ast PatReference, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_reference(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_rest(&mut self, i: &'ast PatRest, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_rest(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_slice(&mut self, i: &'ast PatSlice, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_slice(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_struct(&mut self, i: &'ast PatStruct, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_struct(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple(&mut self, i: &'ast PatTuple, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_tuple(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple_struct(&mut self, i: &'ast PatTupleStruct, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_tuple_struct(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_type(&mut self, i: &'ast PatType, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_type(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_wild(&mut self, i: &'ast PatWild, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_wild(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path(&mut self, i: &'ast Path, ancestors: &mut Ancestors<'ast>) {
+        visit_path(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path_arguments(&mut self, i: &'ast PathArguments, ancestors: &mut Ancestors<'ast>) {
+        visit_path_arguments(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path_segment(&mut self, i: &'ast PathSegment, ancestors: &mut Ancestors<'ast>) {
+        visit_path_segment(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_eq(&mut self, i: &'ast PredicateEq, ancestors: &mut Ancestors<'ast>) {
+        visit_predicate_eq(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_lifetime(
+        &mut self,
+        i: &'ast PredicateLifetime,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_predicate_lifetime(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_type(&mut self, i: &'ast PredicateType, ancestors: &mut Ancestors<'ast>) {
+        visit_predicate_type(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_qself(&mut self, i: &'ast QSelf, ancestors: &mut Ancestors<'ast>) {
+        visit_qself(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_range_limits(&mut self, i: &'ast RangeLimits, ancestors: &mut Ancestors<'ast>) {
+        visit_range_limits(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_receiver(&mut self, i: &'ast Receiver, ancestors: &mut Ancestors<'ast>) {
+        visit_receiver(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_return_type(&mut self, i: &'ast ReturnType, ancestors: &mut Ancestors<'ast>) {
+        visit_return_type(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_signature(&mut self, i: &'ast Signature, ancestors: &mut Ancestors<'ast>) {
+        visit_signature(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_stmt(&mut self, i: &'ast Stmt, ancestors: &mut Ancestors<'ast>) {
+        visit_stmt(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_trait_bound(&mut self, i: &'ast TraitBound, ancestors: &mut Ancestors<'ast>) {
+        visit_trait_bound(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_trait_bound_modifier(
+        &mut self,
+        i: &'ast TraitBoundModifier,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_trait_bound_modifier(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item(&mut self, i: &'ast TraitItem, ancestors: &mut Ancestors<'ast>) {
+        visit_trait_item(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_const(&mut self, i: &'ast TraitItemConst, ancestors: &mut Ancestors<'ast>) {
+        visit_trait_item_const(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_macro(&mut self, i: &'ast TraitItemMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_trait_item_macro(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_method(
+        &mut self,
+        i: &'ast TraitItemMethod,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_trait_item_method(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_type(&mut self, i: &'ast TraitItemType, ancestors: &mut Ancestors<'ast>) {
+        visit_trait_item_type(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type(&mut self, i: &'ast Type, ancestors: &mut Ancestors<'ast>) {
+        visit_type(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_array(&mut self, i: &'ast TypeArray, ancestors: &mut Ancestors<'ast>) {
+        visit_type_array(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_bare_fn(&mut self, i: &'ast TypeBareFn, ancestors: &mut Ancestors<'ast>) {
+        visit_type_bare_fn(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_group(&mut self, i: &'ast TypeGroup, ancestors: &mut Ancestors<'ast>) {
+        visit_type_group(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_impl_trait(&mut self, i: &'ast TypeImplTrait, ancestors: &mut Ancestors<'ast>) {
+        visit_type_impl_trait(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_infer(&mut self, i: &'ast TypeInfer, ancestors: &mut Ancestors<'ast>) {
+        visit_type_infer(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_macro(&mut self, i: &'ast TypeMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_type_macro(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_never(&mut self, i: &'ast TypeNever, ancestors: &mut Ancestors<'ast>) {
+        visit_type_never(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_param(&mut self, i: &'ast TypeParam, ancestors: &mut Ancestors<'ast>) {
+        visit_type_param(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_param_bound(&mut self, i: &'ast TypeParamBound, ancestors: &mut Ancestors<'ast>) {
+        visit_type_param_bound(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_paren(&mut self, i: &'ast TypeParen, ancestors: &mut Ancestors<'ast>) {
+        visit_type_paren(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_path(&mut self, i: &'ast TypePath, ancestors: &mut Ancestors<'ast>) {
+        visit_type_path(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_ptr(&mut self, i: &'ast TypePtr, ancestors: &mut Ancestors<'ast>) {
+        visit_type_ptr(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_reference(&mut self, i: &'ast TypeReference, ancestors: &mut Ancestors<'ast>) {
+        visit_type_reference(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_slice(&mut self, i: &'ast TypeSlice, ancestors: &mut Ancestors<'ast>) {
+        visit_type_slice(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_trait_object(
+        &mut self,
+        i: &'ast TypeTraitObject,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_type_trait_object(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_tuple(&mut self, i: &'ast TypeTuple, ancestors: &mut Ancestors<'ast>) {
+        visit_type_tuple(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_un_op(&mut self, i: &'ast UnOp, ancestors: &mut Ancestors<'ast>) {
+        visit_un_op(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_glob(&mut self, i: &'ast UseGlob, ancestors: &mut Ancestors<'ast>) {
+        visit_use_glob(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_group(&mut self, i: &'ast UseGroup, ancestors: &mut Ancestors<'ast>) {
+        visit_use_group(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_name(&mut self, i: &'ast UseName, ancestors: &mut Ancestors<'ast>) {
+        visit_use_name(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_path(&mut self, i: &'ast UsePath, ancestors: &mut Ancestors<'ast>) {
+        visit_use_path(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_rename(&mut self, i: &'ast UseRename, ancestors: &mut Ancestors<'ast>) {
+        visit_use_rename(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_tree(&mut self, i: &'ast UseTree, ancestors: &mut Ancestors<'ast>) {
+        visit_use_tree(self, i, ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_variadic(&mut self, i: &'ast Variadic, ancestors: &mut Ancestors<'ast>) {
+        visit_variadic(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_variant(&mut self, i: &'ast Variant, ancestors: &mut Ancestors<'ast>) {
+        visit_variant(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_crate(&mut self, i: &'ast VisCrate, ancestors: &mut Ancestors<'ast>) {
+        visit_vis_crate(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_public(&mut self, i: &'ast VisPublic, ancestors: &mut Ancestors<'ast>) {
+        visit_vis_public(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_restricted(&mut self, i: &'ast VisRestricted, ancestors: &mut Ancestors<'ast>) {
+        visit_vis_restricted(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_visibility(&mut self, i: &'ast Visibility, ancestors: &mut Ancestors<'ast>) {
+        visit_visibility(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_where_clause(&mut self, i: &'ast WhereClause, ancestors: &mut Ancestors<'ast>) {
+        visit_where_clause(self, i, ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_where_predicate(&mut self, i: &'ast WherePredicate, ancestors: &mut Ancestors<'ast>) {
+        visit_where_predicate(self, i, ancestors)
+    }
+}
+/// The nodes enclosing the node being visited, from the root of the
+/// traversal down to its parent.
+///
+/// This dereferences to a slice of [`NodeRef`]. The stack is
+/// maintained by the functions in this module, which push a node
+/// before visiting its children and pop it afterwards.
+///
+/// *This type is available if Syn is built with the `"visit"` feature.*
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub struct Ancestors<'ast> {
+    stack: Vec<NodeRef<'ast>>,
+}
+impl<'ast> Ancestors<'ast> {
+    /// An empty stack, for starting a traversal from the root.
+    pub fn new() -> Self {
+        Ancestors { stack: Vec::new() }
+    }
+    /// The innermost enclosing node, if any.
+    pub fn parent(&self) -> Option<NodeRef<'ast>> {
+        self.stack.last().cloned()
+    }
+    fn push(&mut self, node: NodeRef<'ast>) {
+        self.stack.push(node);
+    }
+    fn pop(&mut self) {
+        self.stack.pop();
+    }
+}
+impl<'ast> Deref for Ancestors<'ast> {
+    type Target = [NodeRef<'ast>];
+    fn deref(&self) -> &Self::Target {
+        &self.stack
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_abi<'ast, V>(v: &mut V, node: &'ast Abi, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Abi(node));
+    if let Some(it) = &node.name {
+        v.visit_lit_str(it, ancestors)
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_angle_bracketed_generic_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast AngleBracketedGenericArguments,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::AngleBracketedGenericArguments(node));
+    for it in &node.args {
+        v.visit_generic_argument(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_arm<'ast, V>(v: &mut V, node: &'ast Arm, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Arm(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_pat(&node.pat, ancestors);
+    if let Some(it) = &node.guard {
+        v.visit_expr(&*(it).1, ancestors);
+    };
+    v.visit_expr(&*node.body, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_style<'ast, V>(v: &mut V, node: &'ast AttrStyle, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attribute<'ast, V>(v: &mut V, node: &'ast Attribute, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Attribute(node));
+    v.visit_attr_style(&node.style, ancestors);
+    v.visit_path(&node.path, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_bare_fn_arg<'ast, V>(v: &mut V, node: &'ast BareFnArg, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::BareFnArg(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.name {
+        v.visit_ident(&(it).0, ancestors);
+    };
+    v.visit_type(&node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_bin_op<'ast, V>(v: &mut V, node: &'ast BinOp, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_binding<'ast, V>(v: &mut V, node: &'ast Binding, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Binding(node));
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_type(&node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_block<'ast, V>(v: &mut V, node: &'ast Block, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Block(node));
+    for it in &node.stmts {
+        v.visit_stmt(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_bound_lifetimes<'ast, V>(
+    v: &mut V,
+    node: &'ast BoundLifetimes,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::BoundLifetimes(node));
+    for it in &node.lifetimes {
+        v.visit_lifetime_def(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_const_param<'ast, V>(
+    v: &mut V,
+    node: &'ast ConstParam,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ConstParam(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_type(&node.ty, ancestors);
+    if let Some(it) = &node.default {
+        v.visit_expr(it, ancestors)
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_constraint<'ast, V>(v: &mut V, node: &'ast Constraint, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Constraint(node));
+    v.visit_ident(&node.ident, ancestors);
+    for it in &node.bounds {
+        v.visit_type_param_bound(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "derive")]
+pub fn visit_data<'ast, V>(v: &mut V, node: &'ast Data, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Data(node));
+    match node {
+        Data::Struct(_binding_0) => {
+            v.visit_data_struct(_binding_0, ancestors);
+        }
+        Data::Enum(_binding_0) => {
+            v.visit_data_enum(_binding_0, ancestors);
+        }
+        Data::Union(_binding_0) => {
+            v.visit_data_union(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "derive")]
+pub fn visit_data_enum<'ast, V>(v: &mut V, node: &'ast DataEnum, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::DataEnum(node));
+    for it in &node.variants {
+        v.visit_variant(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "derive")]
+pub fn visit_data_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast DataStruct,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::DataStruct(node));
+    v.visit_fields(&node.fields, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "derive")]
+pub fn visit_data_union<'ast, V>(v: &mut V, node: &'ast DataUnion, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::DataUnion(node));
+    v.visit_fields_named(&node.fields, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "derive")]
+pub fn visit_derive_input<'ast, V>(
+    v: &mut V,
+    node: &'ast DeriveInput,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::DeriveInput(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_generics(&node.generics, ancestors);
+    v.visit_data(&node.data, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr<'ast, V>(v: &mut V, node: &'ast Expr, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Expr(node));
+    match node {
+        Expr::Array(_binding_0) => {
+            full!(v.visit_expr_array(_binding_0, ancestors));
+        }
+        Expr::Assign(_binding_0) => {
+            full!(v.visit_expr_assign(_binding_0, ancestors));
+        }
+        Expr::AssignOp(_binding_0) => {
+            full!(v.visit_expr_assign_op(_binding_0, ancestors));
+        }
+        Expr::Async(_binding_0) => {
+            full!(v.visit_expr_async(_binding_0, ancestors));
+        }
+        Expr::Await(_binding_0) => {
+            full!(v.visit_expr_await(_binding_0, ancestors));
+        }
+        Expr::Binary(_binding_0) => {
+            v.visit_expr_binary(_binding_0, ancestors);
+        }
+        Expr::Block(_binding_0) => {
+            full!(v.visit_expr_block(_binding_0, ancestors));
+        }
+        Expr::Box(_binding_0) => {
+            full!(v.visit_expr_box(_binding_0, ancestors));
+        }
+        Expr::Break(_binding_0) => {
+            full!(v.visit_expr_break(_binding_0, ancestors));
+        }
+        Expr::Call(_binding_0) => {
+            v.visit_expr_call(_binding_0, ancestors);
+        }
+        Expr::Cast(_binding_0) => {
+            v.visit_expr_cast(_binding_0, ancestors);
+        }
+        Expr::Closure(_binding_0) => {
+            full!(v.visit_expr_closure(_binding_0, ancestors));
+        }
+        Expr::Continue(_binding_0) => {
+            full!(v.visit_expr_continue(_binding_0, ancestors));
+        }
+        Expr::Field(_binding_0) => {
+            v.visit_expr_field(_binding_0, ancestors);
+        }
+        Expr::ForLoop(_binding_0) => {
+            full!(v.visit_expr_for_loop(_binding_0, ancestors));
+        }
+        Expr::Group(_binding_0) => {
+            full!(v.visit_expr_group(_binding_0, ancestors));
+        }
+        Expr::If(_binding_0) => {
+            full!(v.visit_expr_if(_binding_0, ancestors));
+        }
+        Expr::Index(_binding_0) => {
+            v.visit_expr_index(_binding_0, ancestors);
+        }
+        Expr::Let(_binding_0) => {
+            full!(v.visit_expr_let(_binding_0, ancestors));
+        }
+        Expr::Lit(_binding_0) => {
+            v.visit_expr_lit(_binding_0, ancestors);
+        }
+        Expr::Loop(_binding_0) => {
+            full!(v.visit_expr_loop(_binding_0, ancestors));
+        }
+        Expr::Macro(_binding_0) => {
+            full!(v.visit_expr_macro(_binding_0, ancestors));
+        }
+        Expr::Match(_binding_0) => {
+            full!(v.visit_expr_match(_binding_0, ancestors));
+        }
+        Expr::MethodCall(_binding_0) => {
+            full!(v.visit_expr_method_call(_binding_0, ancestors));
+        }
+        Expr::Paren(_binding_0) => {
+            v.visit_expr_paren(_binding_0, ancestors);
+        }
+        Expr::Path(_binding_0) => {
+            v.visit_expr_path(_binding_0, ancestors);
+        }
+        Expr::Range(_binding_0) => {
+            full!(v.visit_expr_range(_binding_0, ancestors));
+        }
+        Expr::Reference(_binding_0) => {
+            full!(v.visit_expr_reference(_binding_0, ancestors));
+        }
+        Expr::Repeat(_binding_0) => {
+            full!(v.visit_expr_repeat(_binding_0, ancestors));
+        }
+        Expr::Return(_binding_0) => {
+            full!(v.visit_expr_return(_binding_0, ancestors));
+        }
+        Expr::Struct(_binding_0) => {
+            full!(v.visit_expr_struct(_binding_0, ancestors));
+        }
+        Expr::Try(_binding_0) => {
+            full!(v.visit_expr_try(_binding_0, ancestors));
+        }
+        Expr::TryBlock(_binding_0) => {
+            full!(v.visit_expr_try_block(_binding_0, ancestors));
+        }
+        Expr::Tuple(_binding_0) => {
+            full!(v.visit_expr_tuple(_binding_0, ancestors));
+        }
+        Expr::Type(_binding_0) => {
+            full!(v.visit_expr_type(_binding_0, ancestors));
+        }
+        Expr::Unary(_binding_0) => {
+            v.visit_expr_unary(_binding_0, ancestors);
+        }
+        Expr::Unsafe(_binding_0) => {
+            full!(v.visit_expr_unsafe(_binding_0, ancestors));
+        }
+        Expr::Verbatim(..) => {}
+        Expr::While(_binding_0) => {
+            full!(v.visit_expr_while(_binding_0, ancestors));
+        }
+        Expr::Yield(_binding_0) => {
+            full!(v.visit_expr_yield(_binding_0, ancestors));
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_array<'ast, V>(v: &mut V, node: &'ast ExprArray, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprArray(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    for it in &node.elems {
+        v.visit_expr(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_assign<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprAssign,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprAssign(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.left, ancestors);
+    v.visit_expr(&*node.right, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_assign_op<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprAssignOp,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprAssignOp(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.left, ancestors);
+    v.visit_bin_op(&node.op, ancestors);
+    v.visit_expr(&*node.right, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_async<'ast, V>(v: &mut V, node: &'ast ExprAsync, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprAsync(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_await<'ast, V>(v: &mut V, node: &'ast ExprAwait, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprAwait(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.base, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_binary<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprBinary,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprBinary(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.left, ancestors);
+    v.visit_bin_op(&node.op, ancestors);
+    v.visit_expr(&*node.right, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_block<'ast, V>(v: &mut V, node: &'ast ExprBlock, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprBlock(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it, ancestors)
+    };
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_box<'ast, V>(v: &mut V, node: &'ast ExprBox, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprBox(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_break<'ast, V>(v: &mut V, node: &'ast ExprBreak, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprBreak(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.label {
+        v.visit_lifetime(it, ancestors)
+    };
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it, ancestors)
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_call<'ast, V>(v: &mut V, node: &'ast ExprCall, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprCall(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.func, ancestors);
+    for it in &node.args {
+        v.visit_expr(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_cast<'ast, V>(v: &mut V, node: &'ast ExprCast, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprCast(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.expr, ancestors);
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_closure<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprClosure,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprClosure(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    for it in &node.inputs {
+        v.visit_pat(it, ancestors)
+    }
+    v.visit_return_type(&node.output, ancestors);
+    v.visit_expr(&*node.body, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_continue<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprContinue,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprContinue(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.label {
+        v.visit_lifetime(it, ancestors)
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_field<'ast, V>(v: &mut V, node: &'ast ExprField, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprField(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.base, ancestors);
+    v.visit_member(&node.member, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_for_loop<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprForLoop,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprForLoop(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it, ancestors)
+    };
+    v.visit_pat(&node.pat, ancestors);
+    v.visit_expr(&*node.expr, ancestors);
+    v.visit_block(&node.body, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_group<'ast, V>(v: &mut V, node: &'ast ExprGroup, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprGroup(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_if<'ast, V>(v: &mut V, node: &'ast ExprIf, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprIf(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.cond, ancestors);
+    v.visit_block(&node.then_branch, ancestors);
+    if let Some(it) = &node.else_branch {
+        v.visit_expr(&*(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_index<'ast, V>(v: &mut V, node: &'ast ExprIndex, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprIndex(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.expr, ancestors);
+    v.visit_expr(&*node.index, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_let<'ast, V>(v: &mut V, node: &'ast ExprLet, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprLet(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_pat(&node.pat, ancestors);
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_lit<'ast, V>(v: &mut V, node: &'ast ExprLit, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprLit(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_lit(&node.lit, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_loop<'ast, V>(v: &mut V, node: &'ast ExprLoop, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprLoop(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it, ancestors)
+    };
+    v.visit_block(&node.body, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_macro<'ast, V>(v: &mut V, node: &'ast ExprMacro, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprMacro(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_match<'ast, V>(v: &mut V, node: &'ast ExprMatch, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprMatch(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.expr, ancestors);
+    for it in &node.arms {
+        v.visit_arm(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_method_call<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprMethodCall,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprMethodCall(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.receiver, ancestors);
+    v.visit_ident(&node.method, ancestors);
+    if let Some(it) = &node.turbofish {
+        v.visit_method_turbofish(it, ancestors)
+    };
+    for it in &node.args {
+        v.visit_expr(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_paren<'ast, V>(v: &mut V, node: &'ast ExprParen, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprParen(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_path<'ast, V>(v: &mut V, node: &'ast ExprPath, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprPath(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.qself {
+        v.visit_qself(it, ancestors)
+    };
+    v.visit_path(&node.path, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_range<'ast, V>(v: &mut V, node: &'ast ExprRange, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprRange(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.from {
+        v.visit_expr(&**it, ancestors)
+    };
+    v.visit_range_limits(&node.limits, ancestors);
+    if let Some(it) = &node.to {
+        v.visit_expr(&**it, ancestors)
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_reference<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprReference,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprReference(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_repeat<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprRepeat,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprRepeat(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.expr, ancestors);
+    v.visit_expr(&*node.len, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_return<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprReturn,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprReturn(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it, ancestors)
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprStruct,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprStruct(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_path(&node.path, ancestors);
+    for it in &node.fields {
+        v.visit_field_value(it, ancestors)
+    }
+    if let Some(it) = &node.rest {
+        v.visit_expr(&**it, ancestors)
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_try<'ast, V>(v: &mut V, node: &'ast ExprTry, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprTry(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_try_block<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprTryBlock,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprTryBlock(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_tuple<'ast, V>(v: &mut V, node: &'ast ExprTuple, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprTuple(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    for it in &node.elems {
+        v.visit_expr(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_type<'ast, V>(v: &mut V, node: &'ast ExprType, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprType(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.expr, ancestors);
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_unary<'ast, V>(v: &mut V, node: &'ast ExprUnary, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprUnary(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_un_op(&node.op, ancestors);
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_unsafe<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprUnsafe,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprUnsafe(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_while<'ast, V>(v: &mut V, node: &'ast ExprWhile, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprWhile(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it, ancestors)
+    };
+    v.visit_expr(&*node.cond, ancestors);
+    v.visit_block(&node.body, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_yield<'ast, V>(v: &mut V, node: &'ast ExprYield, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ExprYield(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it, ancestors)
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_field<'ast, V>(v: &mut V, node: &'ast Field, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Field(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    if let Some(it) = &node.ident {
+        v.visit_ident(it, ancestors)
+    };
+    v.visit_type(&node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_field_pat<'ast, V>(v: &mut V, node: &'ast FieldPat, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::FieldPat(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_member(&node.member, ancestors);
+    v.visit_pat(&*node.pat, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_field_value<'ast, V>(
+    v: &mut V,
+    node: &'ast FieldValue,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::FieldValue(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_member(&node.member, ancestors);
+    v.visit_expr(&node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_fields<'ast, V>(v: &mut V, node: &'ast Fields, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Fields(node));
+    match node {
+        Fields::Named(_binding_0) => {
+            v.visit_fields_named(_binding_0, ancestors);
+        }
+        Fields::Unnamed(_binding_0) => {
+            v.visit_fields_unnamed(_binding_0, ancestors);
+        }
+        Fields::Unit => {}
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_fields_named<'ast, V>(
+    v: &mut V,
+    node: &'ast FieldsNamed,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::FieldsNamed(node));
+    for it in &node.named {
+        v.visit_field(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_fields_unnamed<'ast, V>(
+    v: &mut V,
+    node: &'ast FieldsUnnamed,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::FieldsUnnamed(node));
+    for it in &node.unnamed {
+        v.visit_field(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_file<'ast, V>(v: &mut V, node: &'ast File, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::File(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    for it in &node.items {
+        v.visit_item(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_fn_arg<'ast, V>(v: &mut V, node: &'ast FnArg, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::FnArg(node));
+    match node {
+        FnArg::Receiver(_binding_0) => {
+            v.visit_receiver(_binding_0, ancestors);
+        }
+        FnArg::Typed(_binding_0) => {
+            v.visit_pat_type(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItem,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ForeignItem(node));
+    match node {
+        ForeignItem::Fn(_binding_0) => {
+            v.visit_foreign_item_fn(_binding_0, ancestors);
+        }
+        ForeignItem::Static(_binding_0) => {
+            v.visit_foreign_item_static(_binding_0, ancestors);
+        }
+        ForeignItem::Type(_binding_0) => {
+            v.visit_foreign_item_type(_binding_0, ancestors);
+        }
+        ForeignItem::Macro(_binding_0) => {
+            v.visit_foreign_item_macro(_binding_0, ancestors);
+        }
+        ForeignItem::Verbatim(..) => {}
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_fn<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemFn,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ForeignItemFn(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_signature(&node.sig, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemMacro,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ForeignItemMacro(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_static<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemStatic,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ForeignItemStatic(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemType,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ForeignItemType(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_generic_argument<'ast, V>(
+    v: &mut V,
+    node: &'ast GenericArgument,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::GenericArgument(node));
+    match node {
+        GenericArgument::Lifetime(_binding_0) => {
+            v.visit_lifetime(_binding_0, ancestors);
+        }
+        GenericArgument::Type(_binding_0) => {
+            v.visit_type(_binding_0, ancestors);
+        }
+        GenericArgument::Binding(_binding_0) => {
+            v.visit_binding(_binding_0, ancestors);
+        }
+        GenericArgument::Constraint(_binding_0) => {
+            v.visit_constraint(_binding_0, ancestors);
+        }
+        GenericArgument::Const(_binding_0) => {
+            v.visit_expr(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_generic_method_argument<'ast, V>(
+    v: &mut V,
+    node: &'ast GenericMethodArgument,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::GenericMethodArgument(node));
+    match node {
+        GenericMethodArgument::Type(_binding_0) => {
+            v.visit_type(_binding_0, ancestors);
+        }
+        GenericMethodArgument::Const(_binding_0) => {
+            v.visit_expr(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_generic_param<'ast, V>(
+    v: &mut V,
+    node: &'ast GenericParam,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::GenericParam(node));
+    match node {
+        GenericParam::Type(_binding_0) => {
+            v.visit_type_param(_binding_0, ancestors);
+        }
+        GenericParam::Lifetime(_binding_0) => {
+            v.visit_lifetime_def(_binding_0, ancestors);
+        }
+        GenericParam::Const(_binding_0) => {
+            v.visit_const_param(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_generics<'ast, V>(v: &mut V, node: &'ast Generics, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Generics(node));
+    for it in &node.params {
+        v.visit_generic_param(it, ancestors)
+    }
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it, ancestors)
+    };
+    ancestors.pop();
+}
+pub fn visit_ident<'ast, V>(v: &mut V, node: &'ast Ident, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item<'ast, V>(v: &mut V, node: &'ast ImplItem, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ImplItem(node));
+    match node {
+        ImplItem::Const(_binding_0) => {
+            v.visit_impl_item_const(_binding_0, ancestors);
+        }
+        ImplItem::Method(_binding_0) => {
+            v.visit_impl_item_method(_binding_0, ancestors);
+        }
+        ImplItem::Type(_binding_0) => {
+            v.visit_impl_item_type(_binding_0, ancestors);
+        }
+        ImplItem::Macro(_binding_0) => {
+            v.visit_impl_item_macro(_binding_0, ancestors);
+        }
+        ImplItem::Verbatim(..) => {}
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_const<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemConst,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ImplItemConst(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_type(&node.ty, ancestors);
+    v.visit_expr(&node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemMacro,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ImplItemMacro(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_method<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemMethod,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ImplItemMethod(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_signature(&node.sig, ancestors);
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemType,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ImplItemType(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_generics(&node.generics, ancestors);
+    v.visit_type(&node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_index<'ast, V>(v: &mut V, node: &'ast Index, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(feature = "full")]
+pub fn visit_item<'ast, V>(v: &mut V, node: &'ast Item, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Item(node));
+    match node {
+        Item::Const(_binding_0) => {
+            v.visit_item_const(_binding_0, ancestors);
+        }
+        Item::Enum(_binding_0) => {
+            v.visit_item_enum(_binding_0, ancestors);
+        }
+        Item::ExternCrate(_binding_0) => {
+            v.visit_item_extern_crate(_binding_0, ancestors);
+        }
+        Item::Fn(_binding_0) => {
+            v.visit_item_fn(_binding_0, ancestors);
+        }
+        Item::ForeignMod(_binding_0) => {
+            v.visit_item_foreign_mod(_binding_0, ancestors);
+        }
+        Item::Impl(_binding_0) => {
+            v.visit_item_impl(_binding_0, ancestors);
+        }
+        Item::Macro(_binding_0) => {
+            v.visit_item_macro(_binding_0, ancestors);
+        }
+        Item::Macro2(_binding_0) => {
+            v.visit_item_macro2(_binding_0, ancestors);
+        }
+        Item::Mod(_binding_0) => {
+            v.visit_item_mod(_binding_0, ancestors);
+        }
+        Item::Static(_binding_0) => {
+            v.visit_item_static(_binding_0, ancestors);
+        }
+        Item::Struct(_binding_0) => {
+            v.visit_item_struct(_binding_0, ancestors);
+        }
+        Item::Trait(_binding_0) => {
+            v.visit_item_trait(_binding_0, ancestors);
+        }
+        Item::TraitAlias(_binding_0) => {
+            v.visit_item_trait_alias(_binding_0, ancestors);
+        }
+        Item::Type(_binding_0) => {
+            v.visit_item_type(_binding_0, ancestors);
+        }
+        Item::Union(_binding_0) => {
+            v.visit_item_union(_binding_0, ancestors);
+        }
+        Item::Use(_binding_0) => {
+            v.visit_item_use(_binding_0, ancestors);
+        }
+        Item::Verbatim(..) => {}
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_const<'ast, V>(v: &mut V, node: &'ast ItemConst, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemConst(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_type(&*node.ty, ancestors);
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_enum<'ast, V>(v: &mut V, node: &'ast ItemEnum, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemEnum(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_generics(&node.generics, ancestors);
+    for it in &node.variants {
+        v.visit_variant(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_extern_crate<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemExternCrate,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemExternCrate(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    if let Some(it) = &node.rename {
+        v.visit_ident(&(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_fn<'ast, V>(v: &mut V, node: &'ast ItemFn, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemFn(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_signature(&node.sig, ancestors);
+    v.visit_block(&*node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_foreign_mod<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemForeignMod,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemForeignMod(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_abi(&node.abi, ancestors);
+    for it in &node.items {
+        v.visit_foreign_item(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_impl<'ast, V>(v: &mut V, node: &'ast ItemImpl, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemImpl(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_generics(&node.generics, ancestors);
+    if let Some(it) = &node.trait_ {
+        v.visit_path(&(it).1, ancestors);
+    };
+    v.visit_type(&*node.self_ty, ancestors);
+    for it in &node.items {
+        v.visit_impl_item(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_macro<'ast, V>(v: &mut V, node: &'ast ItemMacro, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemMacro(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.ident {
+        v.visit_ident(it, ancestors)
+    };
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_macro2<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemMacro2,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemMacro2(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_mod<'ast, V>(v: &mut V, node: &'ast ItemMod, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemMod(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    if let Some(it) = &node.content {
+        for it in &(it).1 {
+            v.visit_item(it, ancestors)
+        }
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_static<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemStatic,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemStatic(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_type(&*node.ty, ancestors);
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemStruct,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemStruct(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_generics(&node.generics, ancestors);
+    v.visit_fields(&node.fields, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_trait<'ast, V>(v: &mut V, node: &'ast ItemTrait, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemTrait(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_generics(&node.generics, ancestors);
+    for it in &node.supertraits {
+        v.visit_type_param_bound(it, ancestors)
+    }
+    for it in &node.items {
+        v.visit_trait_item(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_trait_alias<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemTraitAlias,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemTraitAlias(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_generics(&node.generics, ancestors);
+    for it in &node.bounds {
+        v.visit_type_param_bound(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_type<'ast, V>(v: &mut V, node: &'ast ItemType, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemType(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_generics(&node.generics, ancestors);
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_union<'ast, V>(v: &mut V, node: &'ast ItemUnion, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemUnion(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_generics(&node.generics, ancestors);
+    v.visit_fields_named(&node.fields, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_use<'ast, V>(v: &mut V, node: &'ast ItemUse, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ItemUse(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_visibility(&node.vis, ancestors);
+    v.visit_use_tree(&node.tree, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_label<'ast, V>(v: &mut V, node: &'ast Label, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Label(node));
+    v.visit_lifetime(&node.name, ancestors);
+    ancestors.pop();
+}
+pub fn visit_lifetime<'ast, V>(v: &mut V, node: &'ast Lifetime, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Lifetime(node));
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_lifetime_def<'ast, V>(
+    v: &mut V,
+    node: &'ast LifetimeDef,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::LifetimeDef(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_lifetime(&node.lifetime, ancestors);
+    for it in &node.bounds {
+        v.visit_lifetime(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_lit<'ast, V>(v: &mut V, node: &'ast Lit, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Lit(node));
+    match node {
+        Lit::Str(_binding_0) => {
+            v.visit_lit_str(_binding_0, ancestors);
+        }
+        Lit::ByteStr(_binding_0) => {
+            v.visit_lit_byte_str(_binding_0, ancestors);
+        }
+        Lit::Byte(_binding_0) => {
+            v.visit_lit_byte(_binding_0, ancestors);
+        }
+        Lit::Char(_binding_0) => {
+            v.visit_lit_char(_binding_0, ancestors);
+        }
+        Lit::Int(_binding_0) => {
+            v.visit_lit_int(_binding_0, ancestors);
+        }
+        Lit::Float(_binding_0) => {
+            v.visit_lit_float(_binding_0, ancestors);
+        }
+        Lit::Bool(_binding_0) => {
+            v.visit_lit_bool(_binding_0, ancestors);
+        }
+        Lit::Verbatim(..) => {}
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_lit_bool<'ast, V>(v: &mut V, node: &'ast LitBool, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_lit_byte<'ast, V>(v: &mut V, node: &'ast LitByte, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_lit_byte_str<'ast, V>(
+    v: &mut V,
+    node: &'ast LitByteStr,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_lit_char<'ast, V>(v: &mut V, node: &'ast LitChar, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_lit_float<'ast, V>(v: &mut V, node: &'ast LitFloat, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_lit_int<'ast, V>(v: &mut V, node: &'ast LitInt, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_lit_str<'ast, V>(v: &mut V, node: &'ast LitStr, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(feature = "full")]
+pub fn visit_local<'ast, V>(v: &mut V, node: &'ast Local, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Local(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_pat(&node.pat, ancestors);
+    if let Some(it) = &node.init {
+        v.visit_expr(&*(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_macro<'ast, V>(v: &mut V, node: &'ast Macro, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Macro(node));
+    v.visit_path(&node.path, ancestors);
+    v.visit_macro_delimiter(&node.delimiter, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_macro_delimiter<'ast, V>(
+    v: &mut V,
+    node: &'ast MacroDelimiter,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_member<'ast, V>(v: &mut V, node: &'ast Member, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Member(node));
+    match node {
+        Member::Named(_binding_0) => {
+            v.visit_ident(_binding_0, ancestors);
+        }
+        Member::Unnamed(_binding_0) => {
+            v.visit_index(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta<'ast, V>(v: &mut V, node: &'ast Meta, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Meta(node));
+    match node {
+        Meta::Path(_binding_0) => {
+            v.visit_path(_binding_0, ancestors);
+        }
+        Meta::List(_binding_0) => {
+            v.visit_meta_list(_binding_0, ancestors);
+        }
+        Meta::NameValue(_binding_0) => {
+            v.visit_meta_name_value(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_expr<'ast, V>(v: &mut V, node: &'ast MetaExpr, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::MetaExpr(node));
+    match node {
+        MetaExpr::Path(_binding_0) => {
+            v.visit_path(_binding_0, ancestors);
+        }
+        MetaExpr::List(_binding_0) => {
+            v.visit_meta_expr_list(_binding_0, ancestors);
+        }
+        MetaExpr::NameValue(_binding_0) => {
+            v.visit_meta_expr_name_value(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_expr_list<'ast, V>(
+    v: &mut V,
+    node: &'ast MetaExprList,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::MetaExprList(node));
+    v.visit_path(&node.path, ancestors);
+    for it in &node.nested {
+        v.visit_nested_meta_expr(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_expr_name_value<'ast, V>(
+    v: &mut V,
+    node: &'ast MetaExprNameValue,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::MetaExprNameValue(node));
+    v.visit_path(&node.path, ancestors);
+    v.visit_meta_value(&node.value, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_list<'ast, V>(v: &mut V, node: &'ast MetaList, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::MetaList(node));
+    v.visit_path(&node.path, ancestors);
+    for it in &node.nested {
+        v.visit_nested_meta(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_name_value<'ast, V>(
+    v: &mut V,
+    node: &'ast MetaNameValue,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::MetaNameValue(node));
+    v.visit_path(&node.path, ancestors);
+    v.visit_lit(&node.lit, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_value<'ast, V>(v: &mut V, node: &'ast MetaValue, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::MetaValue(node));
+    match node {
+        MetaValue::Expr(_binding_0) => {
+            v.visit_expr(_binding_0, ancestors);
+        }
+        MetaValue::Verbatim(..) => {}
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_method_turbofish<'ast, V>(
+    v: &mut V,
+    node: &'ast MethodTurbofish,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::MethodTurbofish(node));
+    for it in &node.args {
+        v.visit_generic_method_argument(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_nested_meta<'ast, V>(
+    v: &mut V,
+    node: &'ast NestedMeta,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::NestedMeta(node));
+    match node {
+        NestedMeta::Meta(_binding_0) => {
+            v.visit_meta(_binding_0, ancestors);
+        }
+        NestedMeta::Lit(_binding_0) => {
+            v.visit_lit(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_nested_meta_expr<'ast, V>(
+    v: &mut V,
+    node: &'ast NestedMetaExpr,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::NestedMetaExpr(node));
+    match node {
+        NestedMetaExpr::Meta(_binding_0) => {
+            v.visit_meta_expr(_binding_0, ancestors);
+        }
+        NestedMetaExpr::Lit(_binding_0) => {
+            v.visit_lit(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_parenthesized_generic_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast ParenthesizedGenericArguments,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ParenthesizedGenericArguments(node));
+    for it in &node.inputs {
+        v.visit_type(it, ancestors)
+    }
+    v.visit_return_type(&node.output, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat<'ast, V>(v: &mut V, node: &'ast Pat, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Pat(node));
+    match node {
+        Pat::Box(_binding_0) => {
+            v.visit_pat_box(_binding_0, ancestors);
+        }
+        Pat::Ident(_binding_0) => {
+            v.visit_pat_ident(_binding_0, ancestors);
+        }
+        Pat::Lit(_binding_0) => {
+            v.visit_pat_lit(_binding_0, ancestors);
+        }
+        Pat::Macro(_binding_0) => {
+            v.visit_pat_macro(_binding_0, ancestors);
+        }
+        Pat::Or(_binding_0) => {
+            v.visit_pat_or(_binding_0, ancestors);
+        }
+        Pat::Path(_binding_0) => {
+            v.visit_pat_path(_binding_0, ancestors);
+        }
+        Pat::Range(_binding_0) => {
+            v.visit_pat_range(_binding_0, ancestors);
+        }
+        Pat::Reference(_binding_0) => {
+            v.visit_pat_reference(_binding_0, ancestors);
+        }
+        Pat::Rest(_binding_0) => {
+            v.visit_pat_rest(_binding_0, ancestors);
+        }
+        Pat::Slice(_binding_0) => {
+            v.visit_pat_slice(_binding_0, ancestors);
+        }
+        Pat::Struct(_binding_0) => {
+            v.visit_pat_struct(_binding_0, ancestors);
+        }
+        Pat::Tuple(_binding_0) => {
+            v.visit_pat_tuple(_binding_0, ancestors);
+        }
+        Pat::TupleStruct(_binding_0) => {
+            v.visit_pat_tuple_struct(_binding_0, ancestors);
+        }
+        Pat::Type(_binding_0) => {
+            v.visit_pat_type(_binding_0, ancestors);
+        }
+        Pat::Verbatim(..) => {}
+        Pat::Wild(_binding_0) => {
+            v.visit_pat_wild(_binding_0, ancestors);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_box<'ast, V>(v: &mut V, node: &'ast PatBox, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatBox(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_pat(&*node.pat, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_ident<'ast, V>(v: &mut V, node: &'ast PatIdent, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatIdent(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_ident(&node.ident, ancestors);
+    if let Some(it) = &node.subpat {
+        v.visit_pat(&*(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_lit<'ast, V>(v: &mut V, node: &'ast PatLit, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatLit(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_macro<'ast, V>(v: &mut V, node: &'ast PatMacro, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatMacro(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_or<'ast, V>(v: &mut V, node: &'ast PatOr, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatOr(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    for it in &node.cases {
+        v.visit_pat(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_path<'ast, V>(v: &mut V, node: &'ast PatPath, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatPath(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.qself {
+        v.visit_qself(it, ancestors)
+    };
+    v.visit_path(&node.path, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_range<'ast, V>(v: &mut V, node: &'ast PatRange, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatRange(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_expr(&*node.lo, ancestors);
+    v.visit_range_limits(&node.limits, ancestors);
+    v.visit_expr(&*node.hi, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_reference<'ast, V>(
+    v: &mut V,
+    node: &'ast PatReference,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatReference(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_pat(&*node.pat, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_rest<'ast, V>(v: &mut V, node: &'ast PatRest, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatRest(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_slice<'ast, V>(v: &mut V, node: &'ast PatSlice, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatSlice(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    for it in &node.elems {
+        v.visit_pat(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_struct<'ast, V>(v: &mut V, node: &'ast PatStruct, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatStruct(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_path(&node.path, ancestors);
+    for it in &node.fields {
+        v.visit_field_pat(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_tuple<'ast, V>(v: &mut V, node: &'ast PatTuple, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatTuple(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    for it in &node.elems {
+        v.visit_pat(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_tuple_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast PatTupleStruct,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatTupleStruct(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_path(&node.path, ancestors);
+    v.visit_pat_tuple(&node.pat, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_type<'ast, V>(v: &mut V, node: &'ast PatType, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatType(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_pat(&*node.pat, ancestors);
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_wild<'ast, V>(v: &mut V, node: &'ast PatWild, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PatWild(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_path<'ast, V>(v: &mut V, node: &'ast Path, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Path(node));
+    for it in &node.segments {
+        v.visit_path_segment(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_path_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast PathArguments,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PathArguments(node));
+    match node {
+        PathArguments::None => {}
+        PathArguments::AngleBracketed(_binding_0) => {
+            v.visit_angle_bracketed_generic_arguments(_binding_0, ancestors);
+        }
+        PathArguments::Parenthesized(_binding_0) => {
+            v.visit_parenthesized_generic_arguments(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_path_segment<'ast, V>(
+    v: &mut V,
+    node: &'ast PathSegment,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PathSegment(node));
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_path_arguments(&node.arguments, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_predicate_eq<'ast, V>(
+    v: &mut V,
+    node: &'ast PredicateEq,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PredicateEq(node));
+    v.visit_type(&node.lhs_ty, ancestors);
+    v.visit_type(&node.rhs_ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_predicate_lifetime<'ast, V>(
+    v: &mut V,
+    node: &'ast PredicateLifetime,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PredicateLifetime(node));
+    v.visit_lifetime(&node.lifetime, ancestors);
+    for it in &node.bounds {
+        v.visit_lifetime(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_predicate_type<'ast, V>(
+    v: &mut V,
+    node: &'ast PredicateType,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::PredicateType(node));
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it, ancestors)
+    };
+    v.visit_type(&node.bounded_ty, ancestors);
+    for it in &node.bounds {
+        v.visit_type_param_bound(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_qself<'ast, V>(v: &mut V, node: &'ast QSelf, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::QSelf(node));
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_range_limits<'ast, V>(
+    v: &mut V,
+    node: &'ast RangeLimits,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(feature = "full")]
+pub fn visit_receiver<'ast, V>(v: &mut V, node: &'ast Receiver, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Receiver(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    if let Some(it) = &node.reference {
+        if let Some(it) = &(it).1 {
+            v.visit_lifetime(it, ancestors)
+        };
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_return_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ReturnType,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::ReturnType(node));
+    match node {
+        ReturnType::Default => {}
+        ReturnType::Type(_binding_0, _binding_1) => {
+            v.visit_type(&**_binding_1, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_signature<'ast, V>(v: &mut V, node: &'ast Signature, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Signature(node));
+    if let Some(it) = &node.abi {
+        v.visit_abi(it, ancestors)
+    };
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_generics(&node.generics, ancestors);
+    for it in &node.inputs {
+        v.visit_fn_arg(it, ancestors)
+    }
+    if let Some(it) = &node.variadic {
+        v.visit_variadic(it, ancestors)
+    };
+    v.visit_return_type(&node.output, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_stmt<'ast, V>(v: &mut V, node: &'ast Stmt, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Stmt(node));
+    match node {
+        Stmt::Local(_binding_0) => {
+            v.visit_local(_binding_0, ancestors);
+        }
+        Stmt::Item(_binding_0) => {
+            v.visit_item(_binding_0, ancestors);
+        }
+        Stmt::Expr(_binding_0) => {
+            v.visit_expr(_binding_0, ancestors);
+        }
+        Stmt::Semi(_binding_0, _binding_1) => {
+            v.visit_expr(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_trait_bound<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitBound,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TraitBound(node));
+    v.visit_trait_bound_modifier(&node.modifier, ancestors);
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it, ancestors)
+    };
+    v.visit_path(&node.path, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_trait_bound_modifier<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitBoundModifier,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item<'ast, V>(v: &mut V, node: &'ast TraitItem, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TraitItem(node));
+    match node {
+        TraitItem::Const(_binding_0) => {
+            v.visit_trait_item_const(_binding_0, ancestors);
+        }
+        TraitItem::Method(_binding_0) => {
+            v.visit_trait_item_method(_binding_0, ancestors);
+        }
+        TraitItem::Type(_binding_0) => {
+            v.visit_trait_item_type(_binding_0, ancestors);
+        }
+        TraitItem::Macro(_binding_0) => {
+            v.visit_trait_item_macro(_binding_0, ancestors);
+        }
+        TraitItem::Verbatim(..) => {}
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_const<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemConst,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TraitItemConst(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_type(&node.ty, ancestors);
+    if let Some(it) = &node.default {
+        v.visit_expr(&(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemMacro,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TraitItemMacro(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_method<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemMethod,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TraitItemMethod(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_signature(&node.sig, ancestors);
+    if let Some(it) = &node.default {
+        v.visit_block(it, ancestors)
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_type<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemType,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TraitItemType(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_generics(&node.generics, ancestors);
+    for it in &node.bounds {
+        v.visit_type_param_bound(it, ancestors)
+    }
+    if let Some(it) = &node.default {
+        v.visit_type(&(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type<'ast, V>(v: &mut V, node: &'ast Type, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Type(node));
+    match node {
+        Type::Array(_binding_0) => {
+            v.visit_type_array(_binding_0, ancestors);
+        }
+        Type::BareFn(_binding_0) => {
+            v.visit_type_bare_fn(_binding_0, ancestors);
+        }
+        Type::Group(_binding_0) => {
+            v.visit_type_group(_binding_0, ancestors);
+        }
+        Type::ImplTrait(_binding_0) => {
+            v.visit_type_impl_trait(_binding_0, ancestors);
+        }
+        Type::Infer(_binding_0) => {
+            v.visit_type_infer(_binding_0, ancestors);
+        }
+        Type::Macro(_binding_0) => {
+            v.visit_type_macro(_binding_0, ancestors);
+        }
+        Type::Never(_binding_0) => {
+            v.visit_type_never(_binding_0, ancestors);
+        }
+        Type::Paren(_binding_0) => {
+            v.visit_type_paren(_binding_0, ancestors);
+        }
+        Type::Path(_binding_0) => {
+            v.visit_type_path(_binding_0, ancestors);
+        }
+        Type::Ptr(_binding_0) => {
+            v.visit_type_ptr(_binding_0, ancestors);
+        }
+        Type::Reference(_binding_0) => {
+            v.visit_type_reference(_binding_0, ancestors);
+        }
+        Type::Slice(_binding_0) => {
+            v.visit_type_slice(_binding_0, ancestors);
+        }
+        Type::TraitObject(_binding_0) => {
+            v.visit_type_trait_object(_binding_0, ancestors);
+        }
+        Type::Tuple(_binding_0) => {
+            v.visit_type_tuple(_binding_0, ancestors);
+        }
+        Type::Verbatim(..) => {}
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_array<'ast, V>(v: &mut V, node: &'ast TypeArray, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeArray(node));
+    v.visit_type(&*node.elem, ancestors);
+    v.visit_expr(&node.len, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_bare_fn<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeBareFn,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeBareFn(node));
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it, ancestors)
+    };
+    if let Some(it) = &node.abi {
+        v.visit_abi(it, ancestors)
+    };
+    for it in &node.inputs {
+        v.visit_bare_fn_arg(it, ancestors)
+    }
+    v.visit_return_type(&node.output, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_group<'ast, V>(v: &mut V, node: &'ast TypeGroup, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeGroup(node));
+    v.visit_type(&*node.elem, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_impl_trait<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeImplTrait,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeImplTrait(node));
+    for it in &node.bounds {
+        v.visit_type_param_bound(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_infer<'ast, V>(v: &mut V, node: &'ast TypeInfer, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_macro<'ast, V>(v: &mut V, node: &'ast TypeMacro, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeMacro(node));
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_never<'ast, V>(v: &mut V, node: &'ast TypeNever, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_param<'ast, V>(v: &mut V, node: &'ast TypeParam, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeParam(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_ident(&node.ident, ancestors);
+    for it in &node.bounds {
+        v.visit_type_param_bound(it, ancestors)
+    }
+    if let Some(it) = &node.default {
+        v.visit_type(it, ancestors)
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_param_bound<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeParamBound,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeParamBound(node));
+    match node {
+        TypeParamBound::Trait(_binding_0) => {
+            v.visit_trait_bound(_binding_0, ancestors);
+        }
+        TypeParamBound::Lifetime(_binding_0) => {
+            v.visit_lifetime(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_paren<'ast, V>(v: &mut V, node: &'ast TypeParen, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeParen(node));
+    v.visit_type(&*node.elem, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_path<'ast, V>(v: &mut V, node: &'ast TypePath, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypePath(node));
+    if let Some(it) = &node.qself {
+        v.visit_qself(it, ancestors)
+    };
+    v.visit_path(&node.path, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_ptr<'ast, V>(v: &mut V, node: &'ast TypePtr, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypePtr(node));
+    v.visit_type(&*node.elem, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_reference<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeReference,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeReference(node));
+    if let Some(it) = &node.lifetime {
+        v.visit_lifetime(it, ancestors)
+    };
+    v.visit_type(&*node.elem, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_slice<'ast, V>(v: &mut V, node: &'ast TypeSlice, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeSlice(node));
+    v.visit_type(&*node.elem, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_trait_object<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeTraitObject,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeTraitObject(node));
+    for it in &node.bounds {
+        v.visit_type_param_bound(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_tuple<'ast, V>(v: &mut V, node: &'ast TypeTuple, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::TypeTuple(node));
+    for it in &node.elems {
+        v.visit_type(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_un_op<'ast, V>(v: &mut V, node: &'ast UnOp, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(feature = "full")]
+pub fn visit_use_glob<'ast, V>(v: &mut V, node: &'ast UseGlob, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(feature = "full")]
+pub fn visit_use_group<'ast, V>(v: &mut V, node: &'ast UseGroup, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::UseGroup(node));
+    for it in &node.items {
+        v.visit_use_tree(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_use_name<'ast, V>(v: &mut V, node: &'ast UseName, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::UseName(node));
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_use_path<'ast, V>(v: &mut V, node: &'ast UsePath, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::UsePath(node));
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_use_tree(&*node.tree, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_use_rename<'ast, V>(v: &mut V, node: &'ast UseRename, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::UseRename(node));
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_ident(&node.rename, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_use_tree<'ast, V>(v: &mut V, node: &'ast UseTree, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::UseTree(node));
+    match node {
+        UseTree::Path(_binding_0) => {
+            v.visit_use_path(_binding_0, ancestors);
+        }
+        UseTree::Name(_binding_0) => {
+            v.visit_use_name(_binding_0, ancestors);
+        }
+        UseTree::Rename(_binding_0) => {
+            v.visit_use_rename(_binding_0, ancestors);
+        }
+        UseTree::Glob(_binding_0) => {
+            v.visit_use_glob(_binding_0, ancestors);
+        }
+        UseTree::Group(_binding_0) => {
+            v.visit_use_group(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_variadic<'ast, V>(v: &mut V, node: &'ast Variadic, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Variadic(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_variant<'ast, V>(v: &mut V, node: &'ast Variant, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Variant(node));
+    for it in &node.attrs {
+        v.visit_attribute(it, ancestors)
+    }
+    v.visit_ident(&node.ident, ancestors);
+    v.visit_fields(&node.fields, ancestors);
+    if let Some(it) = &node.discriminant {
+        v.visit_expr(&(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_vis_crate<'ast, V>(v: &mut V, node: &'ast VisCrate, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_vis_public<'ast, V>(v: &mut V, node: &'ast VisPublic, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_vis_restricted<'ast, V>(
+    v: &mut V,
+    node: &'ast VisRestricted,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::VisRestricted(node));
+    v.visit_path(&*node.path, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_visibility<'ast, V>(v: &mut V, node: &'ast Visibility, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::Visibility(node));
+    match node {
+        Visibility::Public(_binding_0) => {
+            v.visit_vis_public(_binding_0, ancestors);
+        }
+        Visibility::Crate(_binding_0) => {
+            v.visit_vis_crate(_binding_0, ancestors);
+        }
+        Visibility::Restricted(_binding_0) => {
+            v.visit_vis_restricted(_binding_0, ancestors);
+        }
+        Visibility::Inherited => {}
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_where_clause<'ast, V>(
+    v: &mut V,
+    node: &'ast WhereClause,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::WhereClause(node));
+    for it in &node.predicates {
+        v.visit_where_predicate(it, ancestors)
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_where_predicate<'ast, V>(
+    v: &mut V,
+    node: &'ast WherePredicate,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(NodeRef::WherePredicate(node));
+    match node {
+        WherePredicate::Type(_binding_0) => {
+            v.visit_predicate_type(_binding_0, ancestors);
+        }
+        WherePredicate::Lifetime(_binding_0) => {
+            v.visit_predicate_lifetime(_binding_0, ancestors);
+        }
+        WherePredicate::Eq(_binding_0) => {
+            v.visit_predicate_eq(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
@@ -491,6 +491,18 @@ pub trait VisitMut {
         visit_meta_mut(self, i)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_expr_mut(&mut self, i: &mut MetaExpr) {
+        visit_meta_expr_mut(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_expr_list_mut(&mut self, i: &mut MetaExprList) {
+        visit_meta_expr_list_mut(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_expr_name_value_mut(&mut self, i: &mut MetaExprNameValue) {
+        visit_meta_expr_name_value_mut(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_meta_list_mut(&mut self, i: &mut MetaList) {
         visit_meta_list_mut(self, i)
     }
@@ -498,6 +510,10 @@ pub trait VisitMut {
     fn visit_meta_name_value_mut(&mut self, i: &mut MetaNameValue) {
         visit_meta_name_value_mut(self, i)
     }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_value_mut(&mut self, i: &mut MetaValue) {
+        visit_meta_value_mut(self, i)
+    }
     #[cfg(feature = "full")]
     fn visit_method_turbofish_mut(&mut self, i: &mut MethodTurbofish) {
         visit_method_turbofish_mut(self, i)
@@ -507,6 +523,10 @@ pub trait VisitMut {
         visit_nested_meta_mut(self, i)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_nested_meta_expr_mut(&mut self, i: &mut NestedMetaExpr) {
+        visit_nested_meta_expr_mut(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_parenthesized_generic_arguments_mut(&mut self, i: &mut ParenthesizedGenericArguments) {
         visit_parenthesized_generic_arguments_mut(self, i)
     }
@@ -2694,6 +2714,47 @@ where
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_expr_mut<V>(v: &mut V, node: &mut MetaExpr)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        MetaExpr::Path(_binding_0) => {
+            v.visit_path_mut(_binding_0);
+        }
+        MetaExpr::List(_binding_0) => {
+            v.visit_meta_expr_list_mut(_binding_0);
+        }
+        MetaExpr::NameValue(_binding_0) => {
+            v.visit_meta_expr_name_value_mut(_binding_0);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_expr_list_mut<V>(v: &mut V, node: &mut MetaExprList)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_path_mut(&mut node.path);
+    tokens_helper(v, &mut node.paren_token.span);
+    for el in Punctuated::pairs_mut(&mut node.nested) {
+        let (it, p) = el.into_tuple();
+        v.visit_nested_meta_expr_mut(it);
+        if let Some(p) = p {
+            tokens_helper(v, &mut p.spans);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_expr_name_value_mut<V>(v: &mut V, node: &mut MetaExprNameValue)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_path_mut(&mut node.path);
+    tokens_helper(v, &mut node.eq_token.spans);
+    v.visit_meta_value_mut(&mut node.value);
+}
+#[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_meta_list_mut<V>(v: &mut V, node: &mut MetaList)
 where
     V: VisitMut + ?Sized,
@@ -2717,6 +2778,20 @@ where
     tokens_helper(v, &mut node.eq_token.spans);
     v.visit_lit_mut(&mut node.lit);
 }
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_value_mut<V>(v: &mut V, node: &mut MetaValue)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        MetaValue::Expr(_binding_0) => {
+            v.visit_expr_mut(_binding_0);
+        }
+        MetaValue::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+}
 #[cfg(feature = "full")]
 pub fn visit_method_turbofish_mut<V>(v: &mut V, node: &mut MethodTurbofish)
 where
@@ -2748,6 +2823,20 @@ where
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_nested_meta_expr_mut<V>(v: &mut V, node: &mut NestedMetaExpr)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        NestedMetaExpr::Meta(_binding_0) => {
+            v.visit_meta_expr_mut(_binding_0);
+        }
+        NestedMetaExpr::Lit(_binding_0) => {
+            v.visit_lit_mut(_binding_0);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_parenthesized_generic_arguments_mut<V>(
     v: &mut V,
     node: &mut ParenthesizedGenericArguments,
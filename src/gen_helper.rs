@@ -152,3 +152,208 @@ pub mod visit_mut {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+pub mod serde {
+    use crate::punctuated::Punctuated;
+    use ::serde::de::{self, Deserialize, DeserializeSeed, Deserializer, Unexpected, Visitor};
+    use ::serde::ser::{Serialize, Serializer};
+    use proc_macro2::{Ident, Literal, TokenStream, TokenTree};
+    use std::fmt;
+
+    pub struct IdentSer<'a>(pub &'a Ident);
+    pub struct IdentDe(pub Ident);
+
+    pub struct TokensSer<'a>(pub &'a TokenStream);
+    pub struct TokensDe(pub TokenStream);
+
+    pub struct LiteralSer<'a>(pub &'a Literal);
+    pub struct LiteralDe(pub Literal);
+
+    impl<'a> Serialize for IdentSer<'a> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self.0)
+        }
+    }
+
+    impl<'a> Serialize for TokensSer<'a> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self.0)
+        }
+    }
+
+    impl<'a> Serialize for LiteralSer<'a> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self.0)
+        }
+    }
+
+    fn parse_tokens<E>(repr: &str) -> Result<TokenStream, E>
+    where
+        E: de::Error,
+    {
+        repr.parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(repr), &"a valid token stream"))
+    }
+
+    fn parse_token_tree<E>(repr: &str) -> Result<Option<TokenTree>, E>
+    where
+        E: de::Error,
+    {
+        let mut tokens = parse_tokens::<E>(repr)?.into_iter();
+        match (tokens.next(), tokens.next()) {
+            (Some(token), None) => Ok(Some(token)),
+            _ => Ok(None),
+        }
+    }
+
+    impl<'de> Deserialize<'de> for IdentDe {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let repr = String::deserialize(deserializer)?;
+            match parse_token_tree(&repr)? {
+                Some(TokenTree::Ident(ident)) => Ok(IdentDe(ident)),
+                _ => Err(de::Error::invalid_value(
+                    Unexpected::Str(&repr),
+                    &"an identifier",
+                )),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for TokensDe {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let repr = String::deserialize(deserializer)?;
+            parse_tokens(&repr).map(TokensDe)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for LiteralDe {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let repr = String::deserialize(deserializer)?;
+            match parse_token_tree(&repr)? {
+                Some(TokenTree::Literal(literal)) => Ok(LiteralDe(literal)),
+                _ => Err(de::Error::invalid_value(
+                    Unexpected::Str(&repr),
+                    &"a literal",
+                )),
+            }
+        }
+    }
+
+    /// Looks up a struct field by name or index. Unknown fields produce
+    /// `None` so that they can be skipped.
+    pub struct FieldKey(pub &'static [&'static str]);
+
+    /// Looks up an enum variant by name or index.
+    pub struct VariantKey(pub &'static [&'static str]);
+
+    struct KeyVisitor {
+        names: &'static [&'static str],
+        variant: bool,
+    }
+
+    impl<'de> Visitor<'de> for KeyVisitor {
+        type Value = Option<usize>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            if self.variant {
+                formatter.write_str("variant identifier")
+            } else {
+                formatter.write_str("field identifier")
+            }
+        }
+
+        fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if index < self.names.len() as u64 {
+                Ok(Some(index as usize))
+            } else if self.variant {
+                Err(E::invalid_value(Unexpected::Unsigned(index), &self))
+            } else {
+                Ok(None)
+            }
+        }
+
+        fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match self.names.iter().position(|candidate| *candidate == name) {
+                Some(index) => Ok(Some(index)),
+                None if self.variant => Err(E::unknown_variant(name, self.names)),
+                None => Ok(None),
+            }
+        }
+    }
+
+    impl<'de> DeserializeSeed<'de> for FieldKey {
+        type Value = Option<usize>;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_identifier(KeyVisitor {
+                names: self.0,
+                variant: false,
+            })
+        }
+    }
+
+    impl<'de> DeserializeSeed<'de> for VariantKey {
+        type Value = usize;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let index = deserializer.deserialize_identifier(KeyVisitor {
+                names: self.0,
+                variant: true,
+            })?;
+            // KeyVisitor only produces None for struct fields.
+            Ok(index.unwrap_or_default())
+        }
+    }
+
+    pub fn unsupported_variant<E>(name: &str, variant: &str) -> E
+    where
+        E: de::Error,
+    {
+        E::custom(format_args!(
+            "`{}::{}` requires the \"full\" feature",
+            name, variant,
+        ))
+    }
+
+    /// Restores the trailing punctuation recorded next to a punctuated field.
+    pub fn trailing_punct<T, P>(mut elems: Punctuated<T, P>, trailing: bool) -> Punctuated<T, P>
+    where
+        P: Default,
+    {
+        if trailing && !elems.empty_or_trailing() {
+            elems.push_punct(P::default());
+        }
+        elems
+    }
+}
@@ -0,0 +1,425 @@
+/// Define a syntax tree node for custom syntax, along with matching impls of
+/// `Parse` and `ToTokens`.
+///
+/// # Usage
+///
+/// The macro takes a struct whose fields are the parts of the syntax in the
+/// order in which they appear. Each field is parsed in turn by its type's
+/// `Parse` impl, and printed in the same order, so that the two impls cannot
+/// drift apart.
+///
+/// ```
+/// use syn::{Expr, Ident, Token, Type, Visibility};
+///
+/// syn::grammar! {
+///     /// $VISIBILITY static ref $NAME: $TYPE = $EXPR;
+///     pub struct LazyStatic {
+///         pub visibility: Visibility,
+///         pub static_token: Token![static],
+///         pub ref_token: Token![ref],
+///         pub name: Ident,
+///         pub colon_token: Token![:],
+///         pub ty: Type,
+///         pub eq_token: Token![=],
+///         pub init: Expr,
+///         pub semi_token: Token![;],
+///     }
+/// }
+/// ```
+///
+/// The generated syntax tree node supports the following operations just like
+/// the syntax tree nodes of Syn.
+///
+/// - [Parsing] — `input.parse::<LazyStatic>()?`
+///
+/// - [Printing] — `quote!( ... #lazy_static ... )`
+///
+/// - [Spans] — `lazy_static.span()`
+///
+/// [Parsing]: parse::ParseBuffer::parse
+/// [Printing]: quote::ToTokens
+/// [Spans]: spanned::Spanned
+///
+/// # Fields
+///
+/// Besides fields of types that implement `Parse`, like tokens and
+/// [custom keywords], the following are supported.
+///
+/// - A field of type `Punctuated<T, P>` parses the rest of the input as a
+///   sequence of `T` separated by `P`, like
+///   [`Punctuated::parse_terminated`]. It is usually the last field of a
+///   group.
+///
+/// - A field of type `Option<T>` where `T` is a token is parsed if the next
+///   token is `T`. For any other `T`, the field needs a `#[peek(...)]`
+///   attribute naming the token that starts a `T`.
+///
+/// - A field declared as `braced { ... }`, `bracketed { ... }` or
+///   `parenthesized { ... }` is a group delimited by curly braces, square
+///   brackets or parentheses. The field holds the delimiter token, of type
+///   `token::Brace`, `token::Bracket` or `token::Paren`, and the fields in
+///   the braces after it are parsed from the content of the group. They
+///   become fields of the struct like any other.
+///
+/// [custom keywords]: custom_keyword
+/// [`Punctuated::parse_terminated`]: punctuated::Punctuated::parse_terminated
+///
+/// # Example
+///
+/// This example parses input that looks like
+/// `config Server { port: u16 = 80, host: String }`.
+///
+/// ```
+/// use syn::punctuated::Punctuated;
+/// use syn::{token, Expr, Ident, Token, Type};
+///
+/// mod kw {
+///     syn::custom_keyword!(config);
+/// }
+///
+/// syn::grammar! {
+///     pub struct Config {
+///         pub config_token: kw::config,
+///         pub name: Ident,
+///         pub brace_token: braced {
+///             pub settings: Punctuated<Setting, Token![,]>,
+///         },
+///     }
+/// }
+///
+/// syn::grammar! {
+///     pub struct Setting {
+///         pub name: Ident,
+///         pub colon_token: Token![:],
+///         pub ty: Type,
+///         #[peek(Token![=])]
+///         pub default: Option<Default>,
+///     }
+/// }
+///
+/// syn::grammar! {
+///     pub struct Default {
+///         pub eq_token: Token![=],
+///         pub value: Expr,
+///     }
+/// }
+///
+/// let config: Config = syn::parse_str("config Server { port: u16 = 80, host: String }")?;
+/// assert_eq!(config.name, "Server");
+/// assert_eq!(config.settings.len(), 2);
+/// assert!(config.settings[1].default.is_none());
+/// # Ok::<(), syn::Error>(())
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! grammar {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($fields:tt)*
+        }
+    ) => {
+        grammar_fields! {
+            [$name] [$(#[$attr])* $vis struct $name]
+            [] [] [] [] [] []
+            $($fields)*
+        }
+    };
+}
+
+// Not public API.
+//
+// Collects the fields of a `grammar!` struct, in the state:
+//
+//     [name] [struct header]
+//     [struct fields] [field names] [tree of fields] [stack of enclosing groups]
+//     [attributes of the next field] [peek of the next field]
+//     remaining fields...
+//
+// In the tree, a field is `(name [peek])`, or `(name delimiter [fields])` for
+// a group.
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! grammar_fields {
+    // End of a group.
+    (
+        [$name:ident] [$($header:tt)*]
+        [$($flat:tt)*] [$($names:ident)*] [$($tree:tt)*]
+        [($field:ident $delim:ident [$($outer:tt)*] $($rest:tt)*) $($stack:tt)*]
+        [] []
+    ) => {
+        grammar_fields! {
+            [$name] [$($header)*]
+            [$($flat)*] [$($names)*] [$($outer)* ($field $delim [$($tree)*])]
+            [$($stack)*]
+            [] []
+            $($rest)*
+        }
+    };
+
+    // End of the struct.
+    (
+        [$name:ident] [$($header:tt)*]
+        [$($flat:tt)*] [$($names:ident)*] [$($tree:tt)*]
+        []
+        [] []
+    ) => {
+        $($header)* {
+            $($flat)*
+        }
+
+        impl_parse_for_grammar!($name [$($names)*] $($tree)*);
+        impl_to_tokens_for_grammar!($name $($tree)*);
+    };
+
+    (
+        [$name:ident] [$($header:tt)*]
+        [$($flat:tt)*] [$($names:ident)*] [$($tree:tt)*] [$($stack:tt)*]
+        [$($attrs:tt)*] [$($peek:tt)*]
+        , $($rest:tt)*
+    ) => {
+        grammar_fields! {
+            [$name] [$($header)*]
+            [$($flat)*] [$($names)*] [$($tree)*] [$($stack)*]
+            [$($attrs)*] [$($peek)*]
+            $($rest)*
+        }
+    };
+
+    (
+        [$name:ident] [$($header:tt)*]
+        [$($flat:tt)*] [$($names:ident)*] [$($tree:tt)*] [$($stack:tt)*]
+        [$($attrs:tt)*] []
+        #[peek($peek:expr)] $($rest:tt)*
+    ) => {
+        grammar_fields! {
+            [$name] [$($header)*]
+            [$($flat)*] [$($names)*] [$($tree)*] [$($stack)*]
+            [$($attrs)*] [$peek]
+            $($rest)*
+        }
+    };
+
+    (
+        [$name:ident] [$($header:tt)*]
+        [$($flat:tt)*] [$($names:ident)*] [$($tree:tt)*] [$($stack:tt)*]
+        [$($attrs:tt)*] [$($peek:tt)*]
+        #[$attr:meta] $($rest:tt)*
+    ) => {
+        grammar_fields! {
+            [$name] [$($header)*]
+            [$($flat)*] [$($names)*] [$($tree)*] [$($stack)*]
+            [$($attrs)* #[$attr]] [$($peek)*]
+            $($rest)*
+        }
+    };
+
+    // Start of a group.
+    (
+        [$name:ident] [$($header:tt)*]
+        [$($flat:tt)*] [$($names:ident)*] [$($tree:tt)*] [$($stack:tt)*]
+        [$($attrs:tt)*] []
+        $vis:vis $field:ident : $delim:ident { $($inner:tt)* } $($rest:tt)*
+    ) => {
+        grammar_fields! {
+            [$name] [$($header)*]
+            [$($flat)* $($attrs)* $vis $field: grammar_delimiter!($delim),]
+            [$($names)* $field]
+            []
+            [($field $delim [$($tree)*] $($rest)*) $($stack)*]
+            [] []
+            $($inner)*
+        }
+    };
+
+    (
+        [$name:ident] [$($header:tt)*]
+        [$($flat:tt)*] [$($names:ident)*] [$($tree:tt)*] [$($stack:tt)*]
+        [$($attrs:tt)*] [$($peek:tt)*]
+        $vis:vis $field:ident : $ty:ty
+    ) => {
+        grammar_fields! {
+            [$name] [$($header)*]
+            [$($flat)* $($attrs)* $vis $field: $ty,]
+            [$($names)* $field]
+            [$($tree)* ($field [$($peek)*])]
+            [$($stack)*]
+            [] []
+        }
+    };
+
+    (
+        [$name:ident] [$($header:tt)*]
+        [$($flat:tt)*] [$($names:ident)*] [$($tree:tt)*] [$($stack:tt)*]
+        [$($attrs:tt)*] [$($peek:tt)*]
+        $vis:vis $field:ident : $ty:ty, $($rest:tt)*
+    ) => {
+        grammar_fields! {
+            [$name] [$($header)*]
+            [$($flat)* $($attrs)* $vis $field: $ty,]
+            [$($names)* $field]
+            [$($tree)* ($field [$($peek)*])]
+            [$($stack)*]
+            [] []
+            $($rest)*
+        }
+    };
+}
+
+// Not public API.
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! grammar_delimiter {
+    (braced) => {
+        $crate::token::Brace
+    };
+    (bracketed) => {
+        $crate::token::Bracket
+    };
+    (parenthesized) => {
+        $crate::token::Paren
+    };
+}
+
+// Not public API.
+#[cfg(feature = "parsing")]
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! impl_parse_for_grammar {
+    ($name:ident [$($names:ident)*] $($tree:tt)*) => {
+        impl $crate::parse::Parse for $name {
+            fn parse(input: $crate::parse::ParseStream) -> $crate::parse::Result<Self> {
+                grammar_parse!(input $($tree)*);
+                $crate::export::Ok($name { $($names),* })
+            }
+        }
+    };
+}
+
+// Not public API.
+#[cfg(not(feature = "parsing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_parse_for_grammar {
+    ($($tt:tt)*) => {};
+}
+
+// Not public API.
+#[cfg(feature = "parsing")]
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! grammar_parse {
+    ($input:ident) => {};
+
+    ($input:ident ($field:ident []) $($rest:tt)*) => {
+        let $field = $crate::grammar::parse_field($input)?;
+        grammar_parse!($input $($rest)*);
+    };
+
+    ($input:ident ($field:ident [$peek:expr]) $($rest:tt)*) => {
+        let $field = if $input.peek($peek) {
+            $crate::export::Some($input.parse()?)
+        } else {
+            $crate::export::None
+        };
+        grammar_parse!($input $($rest)*);
+    };
+
+    ($input:ident ($field:ident braced [$($inner:tt)*]) $($rest:tt)*) => {
+        let content;
+        let $field = braced!(content in $input);
+        let content = &content;
+        grammar_parse!(content $($inner)*);
+        grammar_parse!($input $($rest)*);
+    };
+
+    ($input:ident ($field:ident bracketed [$($inner:tt)*]) $($rest:tt)*) => {
+        let content;
+        let $field = bracketed!(content in $input);
+        let content = &content;
+        grammar_parse!(content $($inner)*);
+        grammar_parse!($input $($rest)*);
+    };
+
+    ($input:ident ($field:ident parenthesized [$($inner:tt)*]) $($rest:tt)*) => {
+        let content;
+        let $field = parenthesized!(content in $input);
+        let content = &content;
+        grammar_parse!(content $($inner)*);
+        grammar_parse!($input $($rest)*);
+    };
+}
+
+// Not public API.
+#[cfg(feature = "printing")]
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! impl_to_tokens_for_grammar {
+    ($name:ident $($tree:tt)*) => {
+        impl $crate::export::ToTokens for $name {
+            fn to_tokens(&self, tokens: &mut $crate::export::TokenStream2) {
+                grammar_print!(self tokens $($tree)*);
+            }
+        }
+    };
+}
+
+// Not public API.
+#[cfg(not(feature = "printing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_to_tokens_for_grammar {
+    ($($tt:tt)*) => {};
+}
+
+// Not public API.
+#[cfg(feature = "printing")]
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! grammar_print {
+    ($this:ident $tokens:ident) => {};
+
+    ($this:ident $tokens:ident ($field:ident [$($peek:tt)*]) $($rest:tt)*) => {
+        $crate::export::ToTokens::to_tokens(&$this.$field, $tokens);
+        grammar_print!($this $tokens $($rest)*);
+    };
+
+    ($this:ident $tokens:ident ($field:ident $delim:ident [$($inner:tt)*]) $($rest:tt)*) => {
+        $this.$field.surround($tokens, |tokens| {
+            grammar_print!($this tokens $($inner)*);
+        });
+        grammar_print!($this $tokens $($rest)*);
+    };
+}
+
+#[cfg(feature = "parsing")]
+use crate::parse::{Parse, ParseStream, Result};
+#[cfg(feature = "parsing")]
+use crate::punctuated::Punctuated;
+
+// Not public API.
+#[cfg(feature = "parsing")]
+#[doc(hidden)]
+pub trait ParseField: Sized {
+    fn parse_field(input: ParseStream) -> Result<Self>;
+}
+
+#[cfg(feature = "parsing")]
+impl<T: Parse> ParseField for T {
+    fn parse_field(input: ParseStream) -> Result<Self> {
+        input.parse()
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<T: Parse, P: Parse> ParseField for Punctuated<T, P> {
+    fn parse_field(input: ParseStream) -> Result<Self> {
+        Punctuated::parse_terminated(input)
+    }
+}
+
+// Not public API.
+#[cfg(feature = "parsing")]
+#[doc(hidden)]
+pub fn parse_field<T: ParseField>(input: ParseStream) -> Result<T> {
+    T::parse_field(input)
+}
@@ -1074,9 +1074,12 @@ ast_struct! {
 pub mod parsing {
     use super::*;
 
+    use crate::buffer::Cursor;
     use crate::ext::IdentExt;
     use crate::parse::discouraged::Speculative;
-    use crate::parse::{Parse, ParseStream, Result};
+    use crate::parse::{Parse, ParseStream, PeekNode, Result};
+    use crate::token::parsing::{peek_keyword, peek_punct};
+    use crate::token::Token;
     use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenTree};
     use std::iter::{self, FromIterator};
 
@@ -1212,6 +1215,86 @@ pub mod parsing {
         }
     }
 
+    // Tokens that can start an item, other than contextual keywords and
+    // macro invocations.
+    const ITEM_START: &[fn(Cursor) -> bool] = &[
+        <Token![#] as Token>::peek,
+        <Token![async] as Token>::peek,
+        <Token![const] as Token>::peek,
+        <Token![crate] as Token>::peek,
+        <Token![enum] as Token>::peek,
+        <Token![extern] as Token>::peek,
+        <Token![fn] as Token>::peek,
+        <Token![impl] as Token>::peek,
+        <Token![macro] as Token>::peek,
+        <Token![mod] as Token>::peek,
+        <Token![pub] as Token>::peek,
+        <Token![static] as Token>::peek,
+        <Token![struct] as Token>::peek,
+        <Token![trait] as Token>::peek,
+        <Token![type] as Token>::peek,
+        <Token![unsafe] as Token>::peek,
+        <Token![use] as Token>::peek,
+    ];
+
+    impl PeekNode for Item {
+        fn peek(cursor: Cursor) -> bool {
+            ITEM_START.iter().any(|peek| peek(cursor))
+                || peek_contextual_item(cursor)
+                || peek_item_macro(cursor)
+        }
+
+        fn display() -> &'static str {
+            "item"
+        }
+    }
+
+    // An item starting with a contextual keyword, like `union U {}`.
+    fn peek_contextual_item(cursor: Cursor) -> bool {
+        let (ident, rest) = match cursor.ident() {
+            Some(ident) => ident,
+            None => return false,
+        };
+        if ident == "union" {
+            <Ident as Token>::peek(rest)
+        } else if ident == "auto" {
+            peek_keyword(rest, "trait")
+        } else if ident == "default" {
+            rest.ident().is_some()
+        } else if ident == "existential" {
+            peek_keyword(rest, "type")
+        } else {
+            false
+        }
+    }
+
+    // A macro invocation, like `thread_local! { ... }` or `std::thread_local!`.
+    fn peek_item_macro(mut cursor: Cursor) -> bool {
+        if let Some(rest) = skip_colon2(cursor) {
+            cursor = rest;
+        }
+        loop {
+            cursor = match cursor.ident() {
+                Some((_ident, rest)) => rest,
+                None => return false,
+            };
+            match skip_colon2(cursor) {
+                Some(rest) => cursor = rest,
+                None => return peek_punct(cursor, "!") && !peek_punct(cursor, "!="),
+            }
+        }
+    }
+
+    fn skip_colon2(cursor: Cursor) -> Option<Cursor> {
+        if peek_punct(cursor, "::") {
+            let (_colon, rest) = cursor.punct()?;
+            let (_colon, rest) = rest.punct()?;
+            Some(rest)
+        } else {
+            None
+        }
+    }
+
     impl Parse for ItemMacro {
         fn parse(input: ParseStream) -> Result<Self> {
             let attrs = input.call(Attribute::parse_outer)?;
@@ -238,11 +238,35 @@
 //!   types.
 //! - **`extra-traits`** — Debug, Eq, PartialEq, Hash impls for all syntax tree
 //!   types.
+//! - **`serde`** — Serialize and Deserialize impls for all syntax tree types.
+//!   Spans and punctuation tokens are not part of the serialized form. Every
+//!   level of nesting in the syntax tree takes one or two levels in the
+//!   serialized form, so deeply nested code such as long method chains can
+//!   exceed the recursion limit of a deserializer; `serde_json` stops at 128
+//!   levels unless built with its `unbounded_depth` feature and told
+//!   otherwise by `Deserializer::disable_recursion_limit`, after which the
+//!   depth is limited only by the size of the stack.
 //! - **`proc-macro`** *(enabled by default)* — Runtime dependency on the
 //!   dynamic library libproc_macro from rustc toolchain.
+//! - **`span-locations`** — Line and column information for spans of tokens
+//!   parsed from a string, and the [`source`] module built on it.
+//! - **`testing`** — The [`testing`] module, for comparing the output of
+//!   procedural macros in tests. Enables `full`, `parsing`, `printing`,
+//!   `visit`, `visit-mut` and `extra-traits`.
+//!
+//! On a nightly compiler, building Syn with
+//! `RUSTFLAGS='--cfg syn_unstable_diagnostics'` and the `proc-macro` feature
+//! additionally provides `Error::emit` and `Warnings::emit`, which report
+//! errors and warnings through the unstable `proc_macro::Diagnostic` API. This
+//! is never enabled automatically, since that API may change from one nightly
+//! to the next.
 
 // Syn types in rustdoc of other crates get linked to here.
 #![doc(html_root_url = "https://docs.rs/syn/1.0.3")]
+#![cfg_attr(
+    syn_unstable_diagnostics,
+    feature(proc_macro_diagnostic, proc_macro_span)
+)]
 #![deny(clippy::all, clippy::pedantic)]
 // Ignored clippy lints.
 #![allow(
@@ -304,9 +328,25 @@ pub use crate::ident::Ident;
 mod attr;
 #[cfg(any(feature = "full", feature = "derive"))]
 pub use crate::attr::{
-    AttrStyle, Attribute, AttributeArgs, Meta, MetaList, MetaNameValue, NestedMeta,
+    AttrStyle, Attribute, AttributeArgs, Meta, MetaExpr, MetaExprList, MetaExprNameValue, MetaList,
+    MetaNameValue, MetaValue, NestedMeta, NestedMetaExpr,
 };
 
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "parsing",
+    feature = "printing",
+    feature = "clone-impls"
+))]
+pub mod meta;
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "parsing",
+    feature = "printing",
+    feature = "clone-impls"
+))]
+pub use crate::meta::FromMeta;
+
 #[cfg(any(feature = "full", feature = "derive"))]
 mod bigint;
 
@@ -441,6 +481,48 @@ pub mod parse_macro_input;
 #[cfg(all(feature = "parsing", feature = "printing"))]
 pub mod spanned;
 
+#[cfg(all(feature = "span-locations", feature = "parsing"))]
+pub mod source;
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+pub mod cfg;
+
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "visit",
+    feature = "extra-traits"
+))]
+pub mod diff;
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "extra-traits"))]
+pub mod equiv;
+
+#[cfg(all(
+    feature = "full",
+    feature = "parsing",
+    feature = "printing",
+    feature = "visit",
+    feature = "extra-traits"
+))]
+pub mod query;
+
+#[cfg(all(feature = "full", feature = "visit", feature = "visit-mut"))]
+pub mod scope;
+
+#[cfg(all(
+    feature = "full",
+    feature = "parsing",
+    feature = "printing",
+    feature = "visit-mut"
+))]
+pub mod fixup;
+
+#[cfg(any(feature = "full", feature = "derive"))]
+pub mod compact;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 mod gen {
     /// Syntax tree traversal to walk a shared borrow of a syntax tree.
     ///
@@ -562,6 +644,128 @@ mod gen {
     #[rustfmt::skip]
     pub mod visit;
 
+    /// Syntax tree traversal that keeps track of the ancestors of every node.
+    ///
+    /// The [`VisitAncestors`] trait mirrors [`Visit`], except that every
+    /// method also receives the [`Ancestors`] of the node: the chain of nodes
+    /// enclosing it from the root of the traversal down to its parent, as a
+    /// slice of [`NodeRef`]. Spans and tokens are not visited.
+    ///
+    /// [`VisitAncestors`]: visit_ancestors::VisitAncestors
+    /// [`Visit`]: visit::Visit
+    /// [`Ancestors`]: visit_ancestors::Ancestors
+    /// [`NodeRef`]: node::NodeRef
+    ///
+    /// *This module is available if Syn is built with the `"visit"` feature.*
+    ///
+    /// <br>
+    ///
+    /// # Example
+    ///
+    /// This visitor finds paths that are called as a function from within the
+    /// body of a method.
+    ///
+    /// ```
+    /// // [dependencies]
+    /// // quote = "1.0"
+    /// // syn = { version = "1.0", features = ["full", "visit"] }
+    ///
+    /// use quote::quote;
+    /// use syn::node::NodeRef;
+    /// use syn::visit_ancestors::{self, Ancestors, VisitAncestors};
+    /// use syn::{ExprPath, File};
+    ///
+    /// struct CalleeVisitor;
+    ///
+    /// impl<'ast> VisitAncestors<'ast> for CalleeVisitor {
+    ///     fn visit_expr_path(&mut self, node: &'ast ExprPath, ancestors: &mut Ancestors<'ast>) {
+    ///         // The parent is the `Expr` wrapping this path, and above it is
+    ///         // the expression it is part of.
+    ///         let is_callee = match ancestors.iter().rev().nth(1) {
+    ///             Some(NodeRef::ExprCall(call)) => match &*call.func {
+    ///                 syn::Expr::Path(func) => std::ptr::eq(func, node),
+    ///                 _ => false,
+    ///             },
+    ///             _ => false,
+    ///         };
+    ///         let in_method = ancestors.iter().any(|ancestor| match ancestor {
+    ///             NodeRef::ImplItemMethod(_) => true,
+    ///             _ => false,
+    ///         });
+    ///         if is_callee && in_method {
+    ///             let path = &node.path;
+    ///             println!("{} is called in a method", quote!(#path));
+    ///         }
+    ///
+    ///         visit_ancestors::visit_expr_path(self, node, ancestors);
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let code = quote! {
+    ///         impl S {
+    ///             fn f(&self) {
+    ///                 g(h);
+    ///             }
+    ///         }
+    ///     };
+    ///
+    ///     let syntax_tree: File = syn::parse2(code).unwrap();
+    ///     CalleeVisitor.visit_file(&syntax_tree, &mut Ancestors::new());
+    /// }
+    /// ```
+    #[cfg(feature = "visit")]
+    #[rustfmt::skip]
+    pub mod visit_ancestors;
+
+    /// Dynamically typed traversal over syntax tree nodes of every type.
+    ///
+    /// A [`NodeRef`] is a shared reference to a node of any type, and a
+    /// [`NodeMut`] an exclusive one. Both report the name of the node's type
+    /// through `kind()` and list the nodes directly inside of it through
+    /// `children()`, which allows writing tools that handle every type of node
+    /// uniformly without implementing a method for each of them as with
+    /// [`Visit`]. With the `"printing"` feature they also implement
+    /// `ToTokens`, and so [`Spanned`].
+    ///
+    /// [`NodeRef`]: node::NodeRef
+    /// [`NodeMut`]: node::NodeMut
+    /// [`Visit`]: visit::Visit
+    /// [`Spanned`]: spanned::Spanned
+    ///
+    /// *This module is available if Syn is built with the `"visit"` or
+    /// `"visit-mut"` feature.*
+    ///
+    /// <br>
+    ///
+    /// # Example
+    ///
+    /// This prints the type of every node in a syntax tree, indented by its
+    /// depth.
+    ///
+    /// ```
+    /// // [dependencies]
+    /// // syn = { version = "1.0", features = ["full", "visit"] }
+    ///
+    /// use syn::node::NodeRef;
+    /// use syn::File;
+    ///
+    /// fn dump(node: NodeRef, depth: usize) {
+    ///     println!("{:indent$}{}", "", node.kind(), indent = depth * 2);
+    ///     for child in node.children() {
+    ///         dump(child, depth + 1);
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let syntax_tree: File = syn::parse_str("fn f() -> u8 { 1 }").unwrap();
+    ///     dump(NodeRef::from(&syntax_tree), 0);
+    /// }
+    /// ```
+    #[cfg(any(feature = "visit", feature = "visit-mut"))]
+    #[rustfmt::skip]
+    pub mod node;
+
     /// Syntax tree traversal to mutate an exclusive borrow of a syntax tree in
     /// place.
     ///
@@ -744,6 +948,36 @@ mod gen {
     #[rustfmt::skip]
     pub mod fold;
 
+    #[cfg(all(any(feature = "full", feature = "derive"), feature = "serde"))]
+    #[rustfmt::skip]
+    mod serde;
+
+    #[cfg(all(
+        any(feature = "full", feature = "derive"),
+        feature = "visit",
+        feature = "extra-traits"
+    ))]
+    #[rustfmt::skip]
+    mod diff;
+
+    #[cfg(all(any(feature = "full", feature = "derive"), feature = "extra-traits"))]
+    #[rustfmt::skip]
+    mod equiv;
+
+    #[cfg(all(
+        feature = "full",
+        feature = "parsing",
+        feature = "printing",
+        feature = "visit",
+        feature = "extra-traits"
+    ))]
+    #[rustfmt::skip]
+    mod query;
+
+    #[cfg(any(feature = "full", feature = "derive"))]
+    #[rustfmt::skip]
+    mod compact;
+
     #[cfg(any(feature = "full", feature = "derive"))]
     #[path = "../gen_helper.rs"]
     mod helper;
@@ -756,6 +990,10 @@ pub mod export;
 
 mod custom_keyword;
 mod custom_punctuation;
+
+// Not public API.
+#[doc(hidden)]
+pub mod grammar;
 mod sealed;
 
 #[cfg(feature = "parsing")]
@@ -766,8 +1004,14 @@ pub mod parse;
 
 mod span;
 
+#[cfg(all(feature = "full", feature = "parsing", feature = "printing"))]
+mod pretty;
+
 #[cfg(all(any(feature = "full", feature = "derive"), feature = "printing"))]
-mod print;
+pub mod print;
+
+#[cfg(all(feature = "parsing", feature = "full"))]
+mod recover;
 
 mod thread;
 
@@ -785,7 +1029,7 @@ mod rustdoc_workaround {
 ////////////////////////////////////////////////////////////////////////////////
 
 mod error;
-pub use crate::error::{Error, Result};
+pub use crate::error::{Error, Result, Warnings};
 
 /// Parse tokens of source code into the chosen syntax tree node.
 ///
@@ -923,7 +1167,97 @@ pub fn parse_str<T: parse::Parse>(s: &str) -> Result<T> {
 /// # }
 /// ```
 #[cfg(all(feature = "parsing", feature = "full"))]
-pub fn parse_file(mut content: &str) -> Result<File> {
+pub fn parse_file(content: &str) -> Result<File> {
+    let (shebang, content) = split_shebang(content);
+    let mut file: File = parse_str(content)?;
+    file.shebang = shebang;
+    Ok(file)
+}
+
+/// Parse the content of a file of Rust code, recovering from syntax errors.
+///
+/// Unlike [`syn::parse_file`], which stops at the first syntax error, this
+/// function skips over the malformed region and keeps parsing from the next
+/// item or statement boundary. The result is a partial syntax tree in which
+/// each region that failed to parse is represented by an [`Item::Verbatim`]
+/// holding its tokens, or by a [`Stmt::Item`] wrapping one when the error is
+/// inside of a function body. Items whose signature is well formed but whose
+/// body contains an error, such as functions, inline modules and impl blocks,
+/// keep their structure with only the offending part of the body replaced.
+///
+/// Every error encountered along the way is returned as a single [`Error`]
+/// built up using [`Error::combine`]. Iterate over it to see the individual
+/// errors. The error is `None` if the whole file parsed successfully, in which
+/// case the syntax tree is the same as the one returned by `syn::parse_file`.
+///
+/// [`syn::parse_file`]: parse_file
+///
+/// *This function is available if Syn is built with the `"parsing"` and
+/// `"full"` features.*
+///
+/// # Examples
+///
+/// ```
+/// use syn::Item;
+///
+/// let code = "
+///     fn ok() {}
+///     struct Broken { a: }
+///     fn also_ok() {}
+/// ";
+///
+/// let (ast, error) = syn::parse_file_recovering(code);
+/// assert_eq!(ast.items.len(), 3);
+/// if let Item::Verbatim(tokens) = &ast.items[1] {
+///     println!("failed to parse: {}", tokens);
+/// }
+/// for error in error.into_iter().flatten() {
+///     println!("error: {}", error);
+/// }
+/// ```
+#[cfg(all(feature = "parsing", feature = "full"))]
+pub fn parse_file_recovering(content: &str) -> (File, Option<Error>) {
+    let (shebang, content) = split_shebang(content);
+    recover::parse_file(shebang, content)
+}
+
+/// Parse the content of a file of Rust code, along with a table for finding
+/// where in the content each node of the syntax tree came from.
+///
+/// The syntax tree is the same as the one returned by [`syn::parse_file`].
+/// Pass the span of any of its tokens or nodes, or of an error, to the
+/// [`SourceMap`] to get the corresponding byte range or line and column in
+/// `content`, for example to point at the right place in a diagnostic
+/// printed by a linter.
+///
+/// [`syn::parse_file`]: parse_file
+/// [`SourceMap`]: source::SourceMap
+///
+/// *This function is available if Syn is built with the `"span-locations"`,
+/// `"parsing"` and `"full"` features.*
+///
+/// # Examples
+///
+/// ```
+/// use syn::spanned::Spanned;
+///
+/// # fn main() -> syn::Result<()> {
+/// let code = "#!/usr/bin/env run-cargo-script\nstruct Unit;\n";
+///
+/// let (file, map) = syn::parse_file_with_positions(code)?;
+/// let range = map.byte_range(file.items[0].span()).unwrap();
+/// assert_eq!(&code[range], "struct Unit;");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(all(feature = "span-locations", feature = "parsing", feature = "full"))]
+pub fn parse_file_with_positions(content: &str) -> Result<(File, source::SourceMap)> {
+    let file = parse_file(content)?;
+    Ok((file, source::SourceMap::for_file(content)))
+}
+
+#[cfg(all(feature = "parsing", feature = "full"))]
+fn split_shebang(mut content: &str) -> (Option<String>, &str) {
     // Strip the BOM if it is present
     const BOM: &str = "\u{feff}";
     if content.starts_with(BOM) {
@@ -941,7 +1275,5 @@ pub fn parse_file(mut content: &str) -> Result<File> {
         }
     }
 
-    let mut file: File = parse_str(content)?;
-    file.shebang = shebang;
-    Ok(file)
+    (shebang, content)
 }
@@ -665,6 +665,60 @@ lit_extra_traits!(LitInt, repr.token);
 lit_extra_traits!(LitFloat, repr.token);
 lit_extra_traits!(LitBool, value);
 
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+    use ::serde::de::{self, Deserialize, Deserializer, Unexpected};
+    use ::serde::ser::{Serialize, Serializer};
+    use proc_macro2::{TokenStream, TokenTree};
+    use std::result::Result;
+
+    fn parse_literal(repr: &str) -> Option<Literal> {
+        let mut tokens = repr.parse::<TokenStream>().ok()?.into_iter();
+        match (tokens.next(), tokens.next()) {
+            (Some(TokenTree::Literal(literal)), None) => Some(literal),
+            _ => None,
+        }
+    }
+
+    // Literals are represented by their source text, like `"data"` or `1u8`.
+    macro_rules! lit_serde {
+        ($ty:ident, $variant:ident, $expected:expr, $($field:ident).+) => {
+            impl Serialize for $ty {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_str(&self.$($field).+)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let repr = String::deserialize(deserializer)?;
+                    match parse_literal(&repr).map(Lit::new) {
+                        Some(Lit::$variant(lit)) => Ok(lit),
+                        _ => Err(de::Error::invalid_value(
+                            Unexpected::Str(&repr),
+                            &$expected,
+                        )),
+                    }
+                }
+            }
+        };
+    }
+
+    lit_serde!(LitStr, Str, "a string literal", repr.token);
+    lit_serde!(LitByteStr, ByteStr, "a byte string literal", token);
+    lit_serde!(LitByte, Byte, "a byte literal", token);
+    lit_serde!(LitChar, Char, "a character literal", token);
+    lit_serde!(LitInt, Int, "an integer literal", repr.token);
+    lit_serde!(LitFloat, Float, "a floating point literal", repr.token);
+}
+
 ast_enum! {
     /// The style of a string literal, either plain quoted or a raw string like
     /// `r##"data"##`.
@@ -789,6 +843,37 @@ pub mod parsing {
     }
 }
 
+#[cfg(any(feature = "full", feature = "derive"))]
+mod compact {
+    use super::*;
+    use crate::compact::{CompactDebug, Sexpr};
+    use crate::sealed::compact::Sealed;
+
+    macro_rules! lit_compact {
+        ($($ty:ident, $($field:ident).+;)*) => {
+            $(
+                impl Sealed for $ty {}
+
+                impl CompactDebug for $ty {
+                    fn sexpr(&self) -> Sexpr {
+                        Sexpr::atom(&self.$($field).+)
+                    }
+                }
+            )*
+        };
+    }
+
+    lit_compact! {
+        LitStr, repr.token;
+        LitByteStr, token;
+        LitByte, token;
+        LitChar, token;
+        LitInt, repr.token;
+        LitFloat, repr.token;
+        LitBool, value;
+    }
+}
+
 #[cfg(feature = "printing")]
 mod printing {
     use super::*;
@@ -1,12 +1,17 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::rc::Rc;
 
 use proc_macro2::{Delimiter, Span};
 
 use crate::buffer::Cursor;
 use crate::error::{self, Error};
+use crate::parse::combinator::Delimiter as GroupDelimiter;
+use crate::parse::Parse;
 use crate::sealed::lookahead::Sealed;
+use crate::sealed::peek_sequence;
 use crate::span::IntoSpans;
-use crate::token::Token;
+use crate::token::{CustomToken, Token};
 
 /// Support for checking the next token in a stream to decide how to parse.
 ///
@@ -99,6 +104,7 @@ impl<'a> Lookahead1<'a> {
     /// - `input.peek(Ident::peek_any)`
     /// - `input.peek(Lifetime)`
     /// - `input.peek(token::Brace)`
+    /// - `input.peek(node::<Type>)`&emsp;*(any syntax tree node implementing [`PeekNode`])*
     pub fn peek<T: Peek>(&self, token: T) -> bool {
         let _ = token;
         peek_impl(self, T::Token::peek, T::Token::display)
@@ -153,6 +159,240 @@ impl<F: Copy + FnOnce(TokenMarker) -> T, T: Token> Peek for F {
     type Token = T;
 }
 
+/// Syntax tree nodes whose start can be recognized by looking at the next
+/// tokens, without parsing the node.
+///
+/// Unlike [`Peek`], this trait can be implemented outside of Syn. Pass
+/// [`node::<T>`] to [`ParseStream::peek`] or [`Lookahead1::peek`] to peek a
+/// node of type `T`, as in `input.peek(node::<Type>)`. Syn implements this
+/// trait for [`Expr`], [`Type`], [`Pat`] and [`Item`].
+///
+/// [`ParseStream::peek_sequence`] skips over a node in the middle of a
+/// sequence by parsing it, which is why the node also implements [`Parse`].
+///
+/// [`node::<T>`]: node
+/// [`ParseStream::peek_sequence`]: crate::parse::ParseBuffer::peek_sequence
+/// [`ParseStream::peek`]: crate::parse::ParseBuffer::peek
+/// [`Expr`]: crate::Expr
+/// [`Type`]: crate::Type
+/// [`Pat`]: crate::Pat
+/// [`Item`]: crate::Item
+///
+/// # Example
+///
+/// ```
+/// use syn::buffer::Cursor;
+/// use syn::parse::{node, Parse, ParseStream, PeekNode, Result};
+/// use syn::{Ident, LitStr, Token, Type};
+///
+/// // `key = "value"`
+/// struct KeyValue {
+///     key: Ident,
+///     eq_token: Token![=],
+///     value: LitStr,
+/// }
+///
+/// impl PeekNode for KeyValue {
+///     fn peek(cursor: Cursor) -> bool {
+///         match cursor.ident() {
+///             Some((_key, rest)) => match rest.punct() {
+///                 Some((punct, _rest)) => punct.as_char() == '=',
+///                 None => false,
+///             },
+///             None => false,
+///         }
+///     }
+///
+///     fn display() -> &'static str {
+///         "key-value pair"
+///     }
+/// }
+///
+/// impl Parse for KeyValue {
+///     fn parse(input: ParseStream) -> Result<Self> {
+///         Ok(KeyValue {
+///             key: input.parse()?,
+///             eq_token: input.parse()?,
+///             value: input.parse()?,
+///         })
+///     }
+/// }
+///
+/// // Either `key = "value"` or a type.
+/// enum Arg {
+///     KeyValue(KeyValue),
+///     Type(Type),
+/// }
+///
+/// impl Parse for Arg {
+///     fn parse(input: ParseStream) -> Result<Self> {
+///         let lookahead = input.lookahead1();
+///         if lookahead.peek(node::<KeyValue>) {
+///             input.parse().map(Arg::KeyValue)
+///         } else if lookahead.peek(node::<Type>) {
+///             input.parse().map(Arg::Type)
+///         } else {
+///             Err(lookahead.error())
+///         }
+///     }
+/// }
+///
+/// let err = syn::parse_str::<Arg>("= \"value\"").err().unwrap();
+/// assert_eq!(err.to_string(), "expected key-value pair or type");
+/// ```
+pub trait PeekNode: Parse {
+    /// Whether a node of this type starts at the cursor.
+    fn peek(cursor: Cursor) -> bool;
+
+    /// The name of the node in error messages, like `"type"` in "expected
+    /// type".
+    fn display() -> &'static str;
+}
+
+/// Peeks a syntax tree node of type `T`.
+///
+/// This function is not meant to be called. It is passed to a peek method,
+/// as in `input.peek(node::<Type>)`. See [`PeekNode`].
+pub fn node<T: PeekNode>(marker: TokenMarker) -> Node<T> {
+    match marker {}
+}
+
+pub struct Node<T> {
+    marker: PhantomData<T>,
+}
+
+impl<T: PeekNode> CustomToken for Node<T> {
+    fn peek(cursor: Cursor) -> bool {
+        T::peek(cursor)
+    }
+
+    fn display() -> &'static str {
+        T::display()
+    }
+
+    fn skip(cursor: Cursor) -> Option<Cursor> {
+        let scope = Span::call_site();
+        let unexpected = Rc::new(Cell::new(None));
+        let buffer = crate::parse::new_parse_buffer(scope, cursor, unexpected);
+        T::parse(&buffer).ok()?;
+        Some(buffer.cursor())
+    }
+}
+
+/// Sequences of tokens that can be peeked by [`ParseStream::peek_sequence`].
+///
+/// This trait is implemented for every type accepted by [`ParseStream::peek`],
+/// for groups created by [`peek_group`], and for tuples of up to 8 elements
+/// that themselves implement `PeekSequence`. The elements of a tuple are
+/// matched one after another.
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+///
+/// [`ParseStream::peek_sequence`]: crate::parse::ParseBuffer::peek_sequence
+/// [`ParseStream::peek`]: crate::parse::ParseBuffer::peek
+pub trait PeekSequence: peek_sequence::Sealed {
+    // Not public API.
+    #[doc(hidden)]
+    fn __peek_sequence<'a>(&self, cursor: Cursor<'a>) -> Option<Cursor<'a>>;
+}
+
+impl<F: Copy + FnOnce(TokenMarker) -> T, T: Token> PeekSequence for F {
+    fn __peek_sequence<'a>(&self, cursor: Cursor<'a>) -> Option<Cursor<'a>> {
+        if T::peek(cursor) {
+            T::skip(cursor)
+        } else {
+            None
+        }
+    }
+}
+
+impl PeekSequence for () {
+    fn __peek_sequence<'a>(&self, cursor: Cursor<'a>) -> Option<Cursor<'a>> {
+        Some(cursor)
+    }
+}
+
+macro_rules! peek_sequence_tuple {
+    ($($name:ident)+) => {
+        impl<$($name: PeekSequence),+> peek_sequence::Sealed for ($($name,)+) {}
+
+        impl<$($name: PeekSequence),+> PeekSequence for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn __peek_sequence<'a>(&self, cursor: Cursor<'a>) -> Option<Cursor<'a>> {
+                let ($(ref $name,)+) = *self;
+                $(
+                    let cursor = $name.__peek_sequence(cursor)?;
+                )+
+                Some(cursor)
+            }
+        }
+    };
+}
+
+peek_sequence_tuple!(A);
+peek_sequence_tuple!(A B);
+peek_sequence_tuple!(A B C);
+peek_sequence_tuple!(A B C D);
+peek_sequence_tuple!(A B C D E);
+peek_sequence_tuple!(A B C D E F);
+peek_sequence_tuple!(A B C D E F G);
+peek_sequence_tuple!(A B C D E F G H);
+
+/// Peeks a delimited group whose content begins with `content`, without
+/// entering the group.
+///
+/// The delimiter is one of `token::Paren`, `token::Brace` or
+/// `token::Bracket`. The content sequence only needs to match a prefix of
+/// the group's tokens; pass `()` to accept any content.
+///
+/// ```
+/// use syn::parse::{peek_group, ParseStream};
+/// use syn::{token, Token};
+///
+/// // Recognizes `pub(crate) fn` without committing to either token.
+/// fn peek_crate_fn(input: ParseStream) -> bool {
+///     input.peek_sequence((
+///         Token![pub],
+///         peek_group(token::Paren, (Token![crate],)),
+///         Token![fn],
+///     ))
+/// }
+/// ```
+pub fn peek_group<D, S>(delimiter: D, content: S) -> PeekGroup<D, S>
+where
+    D: Peek,
+    D::Token: GroupDelimiter,
+    S: PeekSequence,
+{
+    let _ = delimiter;
+    PeekGroup {
+        delimiter: PhantomData,
+        content,
+    }
+}
+
+/// Group returned by [`peek_group`].
+#[derive(Clone, Copy)]
+pub struct PeekGroup<D, S> {
+    delimiter: PhantomData<D>,
+    content: S,
+}
+
+impl<D: Copy, S: Copy> peek_sequence::Sealed for PeekGroup<D, S> {}
+
+impl<D, S> PeekSequence for PeekGroup<D, S>
+where
+    D: Peek,
+    D::Token: GroupDelimiter,
+    S: PeekSequence,
+{
+    fn __peek_sequence<'a>(&self, cursor: Cursor<'a>) -> Option<Cursor<'a>> {
+        let (content, _span, rest) = cursor.group(D::Token::__delimiter())?;
+        self.content.__peek_sequence(content)?;
+        Some(rest)
+    }
+}
+
 pub enum TokenMarker {}
 
 impl<S> IntoSpans<S> for TokenMarker {
@@ -166,3 +406,7 @@ pub fn is_delimiter(cursor: Cursor, delimiter: Delimiter) -> bool {
 }
 
 impl<F: Copy + FnOnce(TokenMarker) -> T, T: Token> Sealed for F {}
+
+impl<F: Copy + FnOnce(TokenMarker) -> T, T: Token> peek_sequence::Sealed for F {}
+
+impl peek_sequence::Sealed for () {}
@@ -0,0 +1,471 @@
+//! Extracting typed options out of attributes like `#[mytool(rename = "x")]`.
+//!
+//! A type that implements [`FromMeta`] knows how to build itself from an
+//! argument in the [`Meta`] syntax used by attributes. Syn provides impls for
+//! literals, paths, `bool`, `String`, `Option<T>` and `Vec<T>`, and the
+//! [`meta_args!`] macro implements it for a struct whose fields are the
+//! arguments that a particular attribute accepts. [`Attribute::parse_meta_into`]
+//! then parses an attribute into that struct, reporting unknown arguments,
+//! arguments given more than once, missing arguments and values of the wrong
+//! type as errors pointing at the offending tokens.
+//!
+//! [`Meta`]: crate::Meta
+//! [`meta_args!`]: crate::meta_args
+//! [`Attribute::parse_meta_into`]: crate::Attribute::parse_meta_into
+//!
+//! *This module is available if Syn is built with the `"parsing"`,
+//! `"printing"` and `"clone-impls"` features.*
+//!
+//! <br>
+//!
+//! # Example
+//!
+//! ```
+//! use syn::{parse_quote, Attribute, LitStr, Path, Result};
+//!
+//! syn::meta_args! {
+//!     /// Arguments of the `#[mytool(...)]` attribute.
+//!     pub struct MyTool {
+//!         pub rename: Option<LitStr>,
+//!         pub skip: bool,
+//!         pub with: Option<Path>,
+//!         pub aliases: Vec<LitStr>,
+//!     }
+//! }
+//!
+//! # fn main() -> Result<()> {
+//! let attr: Attribute = parse_quote! {
+//!     #[mytool(rename = "x", skip, aliases("y", "z"))]
+//! };
+//! let args: MyTool = attr.parse_meta_into()?;
+//! assert_eq!(args.rename.unwrap().value(), "x");
+//! assert!(args.skip);
+//! assert!(args.with.is_none());
+//! assert_eq!(args.aliases.len(), 2);
+//!
+//! let attr: Attribute = parse_quote! {
+//!     #[mytool(rename = 1, skip, skip, renamed)]
+//! };
+//! let err = attr.parse_meta_into::<MyTool>().err().unwrap();
+//! let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+//! assert_eq!(
+//!     messages,
+//!     [
+//!         "expected string literal",
+//!         "duplicate argument `skip`",
+//!         "unknown argument `renamed`, expected one of: `rename`, `skip`, `with`, `aliases`",
+//!     ],
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use quote::ToTokens;
+
+use crate::error::{Error, Result};
+use crate::{
+    Lit, LitBool, LitByte, LitByteStr, LitChar, LitFloat, LitInt, LitStr, Meta, NestedMeta, Path,
+};
+
+/// Types that can be parsed from an argument of an attribute.
+///
+/// An argument reaches the parser in one of three ways, each with its own
+/// method:
+///
+/// - as a named argument, such as `rename = "x"`, `skip` or `aliases("y")`,
+///   which goes to [`from_meta`] with the whole argument;
+/// - as the literal value of a named argument, which the default
+///   implementation of `from_meta` passes to [`from_lit`];
+/// - as one element of a list, like the `"y"` in `aliases("y")`, which goes to
+///   [`from_nested_meta`].
+///
+/// Every method other than [`from_missing`] defaults to returning an error
+/// that describes what kind of argument was unexpected, so an impl only needs
+/// to provide the methods for the syntax it accepts.
+///
+/// [`from_meta`]: FromMeta::from_meta
+/// [`from_lit`]: FromMeta::from_lit
+/// [`from_nested_meta`]: FromMeta::from_nested_meta
+/// [`from_missing`]: FromMeta::from_missing
+///
+/// *This trait is available if Syn is built with the `"parsing"`,
+/// `"printing"` and `"clone-impls"` features.*
+pub trait FromMeta: Sized {
+    /// Parses a named argument, including its name.
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        match meta {
+            Meta::NameValue(meta) => Self::from_lit(&meta.lit),
+            Meta::Path(path) => Err(Error::new_spanned(
+                path,
+                format!("expected a value for `{}`", path_to_string(path)),
+            )),
+            Meta::List(list) => Err(Error::new_spanned(
+                list,
+                format!("expected `{} = ...`", path_to_string(&list.path)),
+            )),
+        }
+    }
+
+    /// Parses the literal value of a named argument.
+    fn from_lit(lit: &Lit) -> Result<Self> {
+        Err(Error::new_spanned(lit, "unexpected literal"))
+    }
+
+    /// Parses an element of a list of values.
+    fn from_nested_meta(nested: &NestedMeta) -> Result<Self> {
+        match nested {
+            NestedMeta::Lit(lit) => Self::from_lit(lit),
+            NestedMeta::Meta(meta) => Err(Error::new_spanned(meta, "expected literal")),
+        }
+    }
+
+    /// The value to use for an argument that was left out, or `None` if the
+    /// argument is required.
+    fn from_missing() -> Option<Self> {
+        None
+    }
+}
+
+impl FromMeta for bool {
+    /// Accepts a flag given as a bare name like `skip`, or an explicit
+    /// `skip = true` or `skip = false`. A missing flag is false.
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        match meta {
+            Meta::Path(_) => Ok(true),
+            Meta::NameValue(meta) => Self::from_lit(&meta.lit),
+            Meta::List(list) => Err(Error::new_spanned(
+                list,
+                format!("expected `{}` or `{0} = true`", path_to_string(&list.path)),
+            )),
+        }
+    }
+
+    fn from_lit(lit: &Lit) -> Result<Self> {
+        match lit {
+            Lit::Bool(lit) => Ok(lit.value),
+            _ => Err(Error::new_spanned(lit, "expected boolean literal")),
+        }
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(false)
+    }
+}
+
+impl FromMeta for String {
+    fn from_lit(lit: &Lit) -> Result<Self> {
+        match lit {
+            Lit::Str(lit) => Ok(lit.value()),
+            _ => Err(Error::new_spanned(lit, "expected string literal")),
+        }
+    }
+}
+
+impl FromMeta for Lit {
+    fn from_lit(lit: &Lit) -> Result<Self> {
+        Ok(lit.clone())
+    }
+}
+
+macro_rules! impl_from_meta_for_lit {
+    ($($ty:ident => $variant:ident, $expected:expr;)*) => {
+        $(
+            impl FromMeta for $ty {
+                fn from_lit(lit: &Lit) -> Result<Self> {
+                    match lit {
+                        Lit::$variant(lit) => Ok(lit.clone()),
+                        _ => Err(Error::new_spanned(lit, concat!("expected ", $expected))),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_meta_for_lit! {
+    LitStr => Str, "string literal";
+    LitByteStr => ByteStr, "byte string literal";
+    LitByte => Byte, "byte literal";
+    LitChar => Char, "character literal";
+    LitInt => Int, "integer literal";
+    LitFloat => Float, "floating point literal";
+    LitBool => Bool, "boolean literal";
+}
+
+impl FromMeta for Path {
+    /// Parses a path out of a string literal, as in `with = "my::module"`.
+    fn from_lit(lit: &Lit) -> Result<Self> {
+        match lit {
+            Lit::Str(lit) => lit.parse(),
+            _ => Err(Error::new_spanned(lit, "expected path in a string literal")),
+        }
+    }
+
+    /// Accepts either a bare path, as in `derive(Debug, my::Trait)`, or a
+    /// path in a string literal.
+    fn from_nested_meta(nested: &NestedMeta) -> Result<Self> {
+        match nested {
+            NestedMeta::Meta(Meta::Path(path)) => Ok(path.clone()),
+            NestedMeta::Meta(meta) => Err(Error::new_spanned(meta, "expected path")),
+            NestedMeta::Lit(lit) => Self::from_lit(lit),
+        }
+    }
+}
+
+impl<T: FromMeta> FromMeta for Option<T> {
+    /// Parses the argument as `T`. A missing argument is `None`.
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        T::from_meta(meta).map(Some)
+    }
+
+    fn from_lit(lit: &Lit) -> Result<Self> {
+        T::from_lit(lit).map(Some)
+    }
+
+    fn from_nested_meta(nested: &NestedMeta) -> Result<Self> {
+        T::from_nested_meta(nested).map(Some)
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(None)
+    }
+}
+
+impl<T: FromMeta> FromMeta for Vec<T> {
+    /// Parses a list argument like `aliases("y", "z")`, each element of which
+    /// is parsed by `T::from_nested_meta`. A missing argument is an empty
+    /// list.
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        match meta {
+            Meta::List(list) => {
+                let mut values = Vec::new();
+                let mut errors: Option<Error> = None;
+                for nested in &list.nested {
+                    match T::from_nested_meta(nested) {
+                        Ok(value) => values.push(value),
+                        Err(err) => combine(&mut errors, err),
+                    }
+                }
+                match errors {
+                    Some(errors) => Err(errors),
+                    None => Ok(values),
+                }
+            }
+            _ => {
+                let path = match meta {
+                    Meta::Path(path) => path,
+                    Meta::NameValue(meta) => &meta.path,
+                    Meta::List(_) => unreachable!(),
+                };
+                Err(Error::new_spanned(
+                    meta,
+                    format!("expected `{}(...)`", path_to_string(path)),
+                ))
+            }
+        }
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(Vec::new())
+    }
+}
+
+/// Defines a struct holding the arguments of an attribute, and implements
+/// [`FromMeta`] for it.
+///
+/// Each field of the struct is an argument whose name is the name of the
+/// field and whose value is parsed by the field type's `FromMeta` impl. Raw
+/// identifiers like `r#type` become arguments named without the `r#`.
+///
+/// The struct parses from an attribute like `#[mytool(a = 1, b)]`, with the
+/// arguments in any order, or from `#[mytool]` with no arguments. Fields of
+/// type `Option<T>`, `Vec<T>` and `bool` may be left out; every other field is
+/// a required argument. All of the errors in the arguments are reported
+/// together, combined into one [`Error`].
+///
+/// [`Error`]: crate::Error
+///
+/// See the [module documentation] for an example.
+///
+/// [module documentation]: crate::meta
+///
+/// *This macro is available if Syn is built with the `"parsing"`,
+/// `"printing"` and `"clone-impls"` features.*
+#[macro_export]
+macro_rules! meta_args {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident : $ty:ty
+            ),* $(,)*
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl $crate::meta::FromMeta for $name {
+            fn from_meta(meta: &$crate::Meta) -> $crate::Result<Self> {
+                let mut args = $crate::meta::Args::new(meta, &[$(stringify!($field)),*]);
+                $(
+                    let mut $field = $crate::export::None;
+                )*
+                for item in $crate::meta::Args::items(meta) {
+                    if let $crate::export::Some((key, value)) = args.next(item) {
+                        $(
+                            if key == $crate::meta::arg_name(stringify!($field)) {
+                                args.set(
+                                    &mut $field,
+                                    <$ty as $crate::meta::FromMeta>::from_meta(value),
+                                );
+                            }
+                        )*
+                    }
+                }
+                $(
+                    let $field = args.finish_field(stringify!($field), $field);
+                )*
+                args.finish()?;
+                $crate::export::Ok($name {
+                    $(
+                        $field: $field.unwrap(),
+                    )*
+                })
+            }
+        }
+    };
+}
+
+// Not public API.
+//
+// Keeps track of the arguments of a struct defined by `meta_args!` as they
+// are parsed, collecting every error along the way.
+#[doc(hidden)]
+pub struct Args<'a> {
+    meta: &'a Meta,
+    names: &'static [&'static str],
+    seen: Vec<String>,
+    errors: Option<Error>,
+}
+
+impl<'a> Args<'a> {
+    pub fn new(meta: &'a Meta, names: &'static [&'static str]) -> Self {
+        let mut args = Args {
+            meta,
+            names,
+            seen: Vec::new(),
+            errors: None,
+        };
+        if let Meta::NameValue(meta) = meta {
+            let message = format!("expected `{}(...)`", path_to_string(&meta.path));
+            combine(&mut args.errors, Error::new_spanned(meta, message));
+        }
+        args
+    }
+
+    pub fn items(meta: &Meta) -> impl Iterator<Item = &NestedMeta> {
+        let nested = match meta {
+            Meta::List(list) => Some(list.nested.iter()),
+            Meta::Path(_) | Meta::NameValue(_) => None,
+        };
+        nested.into_iter().flatten()
+    }
+
+    // Returns the name and the meta item of an argument that is expected and
+    // has not been seen before.
+    pub fn next<'m>(&mut self, item: &'m NestedMeta) -> Option<(String, &'m Meta)> {
+        let meta = match item {
+            NestedMeta::Meta(meta) => meta,
+            NestedMeta::Lit(lit) => {
+                combine(&mut self.errors, Error::new_spanned(lit, "expected argument name"));
+                return None;
+            }
+        };
+        let path = match meta {
+            Meta::Path(path) => path,
+            Meta::List(list) => &list.path,
+            Meta::NameValue(meta) => &meta.path,
+        };
+
+        let key = path_to_string(path);
+        let key = arg_name(&key).to_owned();
+        if !self.names.iter().any(|name| arg_name(name) == key) {
+            let expected: Vec<String> = self
+                .names
+                .iter()
+                .map(|name| format!("`{}`", arg_name(name)))
+                .collect();
+            let message = match expected.len() {
+                0 => format!("unknown argument `{}`, expected no arguments", key),
+                1 => format!("unknown argument `{}`, expected {}", key, expected[0]),
+                _ => format!(
+                    "unknown argument `{}`, expected one of: {}",
+                    key,
+                    expected.join(", "),
+                ),
+            };
+            combine(&mut self.errors, Error::new_spanned(path, message));
+            return None;
+        }
+        if self.seen.contains(&key) {
+            let message = format!("duplicate argument `{}`", key);
+            combine(&mut self.errors, Error::new_spanned(path, message));
+            return None;
+        }
+        self.seen.push(key.clone());
+        Some((key, meta))
+    }
+
+    pub fn set<T>(&mut self, slot: &mut Option<T>, value: Result<T>) {
+        match value {
+            Ok(value) => *slot = Some(value),
+            Err(err) => combine(&mut self.errors, err),
+        }
+    }
+
+    pub fn finish_field<T: FromMeta>(&mut self, name: &str, slot: Option<T>) -> Option<T> {
+        if slot.is_some() || self.seen.iter().any(|seen| seen == arg_name(name)) {
+            return slot;
+        }
+        let missing = T::from_missing();
+        if missing.is_none() {
+            let message = format!("missing argument `{}`", arg_name(name));
+            combine(&mut self.errors, Error::new_spanned(self.meta, message));
+        }
+        missing
+    }
+
+    pub fn finish(self) -> Result<()> {
+        match self.errors {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+}
+
+// Not public API.
+#[doc(hidden)]
+pub fn arg_name(field: &str) -> &str {
+    if field.starts_with("r#") {
+        &field[2..]
+    } else {
+        field
+    }
+}
+
+fn combine(errors: &mut Option<Error>, err: Error) {
+    match errors {
+        Some(errors) => errors.combine(err),
+        None => *errors = Some(err),
+    }
+}
+
+fn path_to_string(path: &Path) -> String {
+    path.to_token_stream().to_string().replace(' ', "")
+}
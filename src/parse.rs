@@ -188,6 +188,9 @@
 //!
 //! *This module is available if Syn is built with the `"parsing"` feature.*
 
+#[path = "combinator.rs"]
+pub mod combinator;
+
 #[path = "discouraged.rs"]
 pub mod discouraged;
 
@@ -209,11 +212,12 @@ use proc_macro2::{self, Delimiter, Group, Literal, Punct, Span, TokenStream, Tok
 use crate::buffer::{Cursor, TokenBuffer};
 use crate::error;
 use crate::lookahead;
+use crate::parse::discouraged::Speculative;
 use crate::punctuated::Punctuated;
 use crate::token::Token;
 
-pub use crate::error::{Error, Result};
-pub use crate::lookahead::{Lookahead1, Peek};
+pub use crate::error::{Error, Result, Warnings};
+pub use crate::lookahead::{node, peek_group, Lookahead1, Peek, PeekGroup, PeekNode, PeekSequence};
 
 /// Parsing interface implemented by all types that can be parsed in a default
 /// way from a token stream.
@@ -470,6 +474,7 @@ impl<'a> ParseBuffer<'a> {
     /// - `input.peek(Ident::peek_any)`
     /// - `input.peek(Lifetime)`
     /// - `input.peek(token::Brace)`
+    /// - `input.peek(node::<Type>)`&emsp;*(any syntax tree node implementing [`PeekNode`])*
     ///
     /// # Example
     ///
@@ -576,6 +581,63 @@ impl<'a> ParseBuffer<'a> {
         skip(&ahead) && skip(&ahead) && ahead.peek(token)
     }
 
+    /// Looks at the token `n` positions ahead in the parse stream, so that
+    /// `peek_nth(0, token)` is the same as `peek(token)` and
+    /// `peek_nth(1, token)` is the same as `peek2(token)`.
+    ///
+    /// Positions are counted the same way as in `peek2`: each skipped position
+    /// is one token tree or lifetime, so a `None`-delimited group is skipped
+    /// as a whole, while the token at position `n` is looked for inside one.
+    pub fn peek_nth<T: Peek>(&self, n: usize, token: T) -> bool {
+        let ahead = self.fork();
+        (0..n).all(|_| skip(&ahead)) && ahead.peek(token)
+    }
+
+    /// Looks at the next tokens in the parse stream to determine whether they
+    /// match a sequence, without consuming them.
+    ///
+    /// The sequence is a tuple of anything accepted by [`peek`], including
+    /// multi-character punctuation like `Token![::]`, and of groups created
+    /// by [`peek_group`]. `None`-delimited groups in the input are looked
+    /// through. A syntax tree node peeked with [`node`] is skipped over by
+    /// parsing it, so it may appear anywhere in the sequence.
+    ///
+    /// [`peek`]: ParseBuffer::peek
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::parse::{peek_group, Parse, ParseStream, Result};
+    /// use syn::{token, ItemFn, Macro, Token};
+    ///
+    /// // Parses either an async function or a macro invocation.
+    /// enum AsyncFnOrMacro {
+    ///     // pub(crate) async fn f() {}
+    ///     AsyncFn(ItemFn),
+    ///     // pub(crate) async_macro! { ... }
+    ///     Macro(Macro),
+    /// }
+    ///
+    /// impl Parse for AsyncFnOrMacro {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         if input.peek_sequence((
+    ///             Token![pub],
+    ///             peek_group(token::Paren, ()),
+    ///             Token![async],
+    ///             Token![fn],
+    ///         )) || input.peek_sequence((Token![async], Token![fn]))
+    ///         {
+    ///             input.parse().map(AsyncFnOrMacro::AsyncFn)
+    ///         } else {
+    ///             input.parse().map(AsyncFnOrMacro::Macro)
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn peek_sequence<S: PeekSequence>(&self, sequence: S) -> bool {
+        sequence.__peek_sequence(self.cursor()).is_some()
+    }
+
     /// Parses zero or more occurrences of `T` separated by punctuation of type
     /// `P`, with optional trailing punctuation.
     ///
@@ -745,12 +807,15 @@ impl<'a> ParseBuffer<'a> {
     /// When complex speculative parsing against the forked stream is
     /// unavoidable, use [`parse::discouraged::Speculative`] to advance the
     /// original stream once the fork's parse is determined to have been
-    /// successful.
+    /// successful, or [`ParseStream::attempt`] and
+    /// [`ParseStream::alternatives`] which do so for you.
     ///
     /// For a lower level way to perform speculative parsing at the token level,
     /// consider using [`ParseStream::step`] instead.
     ///
     /// [`parse::discouraged::Speculative`]: discouraged::Speculative
+    /// [`ParseStream::attempt`]: ParseBuffer::attempt
+    /// [`ParseStream::alternatives`]: ParseBuffer::alternatives
     /// [`ParseStream::step`]: ParseBuffer::step
     ///
     /// # Example
@@ -852,6 +917,134 @@ impl<'a> ParseBuffer<'a> {
         }
     }
 
+    /// Parses with `function` on a fork of this parse stream, advancing this
+    /// stream past the parsed tokens only if the parse succeeds.
+    ///
+    /// On failure the error is returned and this stream is left where it was,
+    /// so that the caller can go on to try something else. This is the fork,
+    /// try, commit pattern of [`Speculative::advance_to`] in a single call, and
+    /// the caveats described there about the quality of errors apply to it as
+    /// well.
+    ///
+    /// [`Speculative::advance_to`]: discouraged::Speculative::advance_to
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::parse::{Parse, ParseStream};
+    /// use syn::{AngleBracketedGenericArguments, Ident, Result};
+    ///
+    /// // `path<T>`, where the `<` may also be a less-than operator.
+    /// struct Segment {
+    ///     ident: Ident,
+    ///     arguments: Option<AngleBracketedGenericArguments>,
+    /// }
+    ///
+    /// impl Parse for Segment {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         Ok(Segment {
+    ///             ident: input.parse()?,
+    ///             arguments: input.attempt(AngleBracketedGenericArguments::parse).ok(),
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let segment: Segment = syn::parse_str("f<T>").unwrap();
+    /// assert!(segment.arguments.is_some());
+    /// ```
+    pub fn attempt<T>(&self, function: fn(ParseStream) -> Result<T>) -> Result<T> {
+        let fork = self.fork();
+        let result = function(&fork);
+        if result.is_ok() {
+            self.advance_to(&fork);
+        }
+        result
+    }
+
+    /// Tries each parser function in order on a fork of this parse stream,
+    /// advancing this stream with the first one that succeeds.
+    ///
+    /// This is ordered choice for grammars that cannot be decided by peeking
+    /// at a bounded number of tokens. If every alternative fails, this stream
+    /// is left where it was and the error returned is the one from the
+    /// alternative that made the most progress through the input before
+    /// failing, on the assumption that it is the one the user meant to write.
+    /// The errors of alternatives tied for the most progress are combined with
+    /// [`Error::combine`], so that all of them are reported.
+    ///
+    /// Parsing each alternative in full means that input may be parsed several
+    /// times over. Prefer [`ParseStream::peek`] or [`ParseStream::lookahead1`]
+    /// where they are enough to choose.
+    ///
+    /// [`ParseStream::peek`]: ParseBuffer::peek
+    /// [`ParseStream::lookahead1`]: ParseBuffer::lookahead1
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::parse::{Parse, ParseStream};
+    /// use syn::{Expr, Result, Type};
+    ///
+    /// // A macro argument that may be either a type or an expression.
+    /// enum Arg {
+    ///     Type(Type),
+    ///     Expr(Expr),
+    /// }
+    ///
+    /// impl Parse for Arg {
+    ///     fn parse(input: ParseStream) -> Result<Self> {
+    ///         input.alternatives(&[
+    ///             |input| input.parse().map(Arg::Type),
+    ///             |input| input.parse().map(Arg::Expr),
+    ///         ])
+    ///     }
+    /// }
+    ///
+    /// match syn::parse_str::<Arg>("Vec<u8>").unwrap() {
+    ///     Arg::Type(_) => {}
+    ///     Arg::Expr(_) => panic!("expected type"),
+    /// }
+    /// match syn::parse_str::<Arg>("1 + 1").unwrap() {
+    ///     Arg::Expr(_) => {}
+    ///     Arg::Type(_) => panic!("expected expression"),
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `functions` is empty.
+    pub fn alternatives<T>(&self, functions: &[fn(ParseStream) -> Result<T>]) -> Result<T> {
+        assert!(!functions.is_empty(), "no alternatives to parse");
+
+        // The furthest progress made by a failed alternative so far, with the
+        // errors of all the alternatives that got that far.
+        let mut furthest: Option<(usize, Error)> = None;
+        for function in functions {
+            let fork = self.fork();
+            let err = match function(&fork) {
+                Ok(value) => {
+                    self.advance_to(&fork);
+                    return Ok(value);
+                }
+                Err(err) => err,
+            };
+            let progress = crate::buffer::distance(self.cursor(), fork.cursor());
+            furthest = match furthest {
+                Some((best, mut error)) if best == progress => {
+                    error.combine(err);
+                    Some((best, error))
+                }
+                Some((best, error)) if best > progress => Some((best, error)),
+                _ => Some((progress, err)),
+            };
+        }
+
+        match furthest {
+            Some((_progress, error)) => Err(error),
+            None => unreachable!(),
+        }
+    }
+
     /// Triggers an error at the current position of the parse stream.
     ///
     /// # Example
@@ -385,9 +385,11 @@ impl Hash for Pat {
 mod parsing {
     use super::*;
 
+    use crate::buffer::Cursor;
     use crate::ext::IdentExt;
-    use crate::parse::{Parse, ParseStream, Result};
+    use crate::parse::{Parse, ParseStream, PeekNode, Result};
     use crate::path;
+    use crate::token::Token;
 
     impl Parse for Pat {
         fn parse(input: ParseStream) -> Result<Self> {
@@ -441,6 +443,37 @@ mod parsing {
         }
     }
 
+    // Tokens that can start a pattern.
+    const PAT_START: &[fn(Cursor) -> bool] = &[
+        <token::Paren as Token>::peek,
+        <token::Bracket as Token>::peek,
+        <Lit as Token>::peek,
+        <Ident as Token>::peek,
+        <Token![::] as Token>::peek,
+        <Token![<] as Token>::peek,
+        <Token![-] as Token>::peek,
+        <Token![&] as Token>::peek,
+        <Token![_] as Token>::peek,
+        <Token![..] as Token>::peek,
+        <Token![box] as Token>::peek,
+        <Token![crate] as Token>::peek,
+        <Token![mut] as Token>::peek,
+        <Token![ref] as Token>::peek,
+        <Token![self] as Token>::peek,
+        <Token![Self] as Token>::peek,
+        <Token![super] as Token>::peek,
+    ];
+
+    impl PeekNode for Pat {
+        fn peek(cursor: Cursor) -> bool {
+            PAT_START.iter().any(|peek| peek(cursor))
+        }
+
+        fn display() -> &'static str {
+            "pattern"
+        }
+    }
+
     fn pat_path_or_macro_or_struct_or_range(input: ParseStream) -> Result<Pat> {
         let (qself, path) = path::parsing::qpath(input, true)?;
 
@@ -0,0 +1,1895 @@
+use std::mem;
+
+use proc_macro2::{Delimiter, Group, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+
+use crate::attr::FilterAttrs;
+use crate::expr::{
+    callee_needs_parens, contains_exposed_struct, left_needs_parens, prefix_needs_parens,
+    requires_terminator, right_needs_parens, stmt_needs_parens, Precedence,
+};
+use crate::parse::Parser;
+use crate::punctuated::Punctuated;
+use crate::{
+    Arm, AttrStyle, Attribute, Block, Expr, ExprBinary, ExprIf, ExprMethodCall, ExprStruct, Field,
+    FieldValue, Fields, FieldsNamed, File, ForeignItem, Ident, ImplItem, Item, Label, Lit, Macro,
+    MacroDelimiter, Member, Meta, MetaNameValue, Pat, RangeLimits, ReturnType, Signature, Stmt,
+    TraitItem, UseTree, Variant, Visibility, WhereClause,
+};
+
+/// Formats a syntax tree of a whole file as readable Rust source code.
+///
+/// The output is indented with four spaces per level, puts every statement
+/// and item on its own line, and breaks comma-separated lists one element per
+/// line when they would not fit within 100 columns. Parentheses are inserted
+/// wherever the syntax tree could not otherwise be represented, for example
+/// an `Expr::Binary` whose operand is a lower-precedence binary expression,
+/// but nowhere else. Comments are not part of the syntax tree and so are not
+/// preserved, except for doc comments which are printed as `///` comments,
+/// or as `#[doc = "..."]` attributes where code follows on the same line or
+/// the comment ends in whitespace.
+///
+/// *This function is available if Syn is built with the `"full"`,
+/// `"parsing"` and `"printing"` features.*
+///
+/// # Example
+///
+/// ```
+/// let file: syn::File = syn::parse_quote! {
+///     fn main() { let total = (1 + 2) * 3; println!("{}", total) }
+/// };
+///
+/// assert_eq!(
+///     syn::print::pretty_file(&file),
+///     "fn main() {\n    let total = (1 + 2) * 3;\n    println!(\"{}\", total)\n}\n",
+/// );
+/// ```
+pub fn pretty_file(file: &File) -> String {
+    let mut p = Printer::new();
+    if let Some(shebang) = &file.shebang {
+        p.word(shebang);
+        p.newline();
+    }
+    p.inner_attrs(&file.attrs);
+    if !file.items.is_empty() && file.attrs.inner().next().is_some() {
+        p.newline();
+    }
+    p.items(&file.items, Printer::item);
+    p.finish()
+}
+
+/// Formats a single item as readable Rust source code.
+///
+/// See [`pretty_file`] for a description of the output.
+///
+/// *This function is available if Syn is built with the `"full"`,
+/// `"parsing"` and `"printing"` features.*
+pub fn pretty_item(item: &Item) -> String {
+    let mut p = Printer::new();
+    p.item(item);
+    p.finish()
+}
+
+/// Formats an expression as readable Rust source code, without a trailing
+/// newline.
+///
+/// See [`pretty_file`] for a description of the output.
+///
+/// *This function is available if Syn is built with the `"full"`,
+/// `"parsing"` and `"printing"` features.*
+pub fn pretty_expr(expr: &Expr) -> String {
+    let mut p = Printer::new();
+    p.expr(expr);
+    p.out
+}
+
+const INDENT: usize = 4;
+const MARGIN: usize = 100;
+
+struct Printer {
+    out: String,
+    indent: usize,
+    // Set while measuring whether something fits on the current line. Nested
+    // lists are printed flat rather than measured again.
+    flat: bool,
+    // Width of what is known to follow on the same line, such as the `;` of a
+    // statement or the ` {` after the condition of an `if`.
+    trailing: usize,
+}
+
+impl Printer {
+    fn new() -> Self {
+        Printer {
+            out: String::new(),
+            indent: 0,
+            flat: false,
+            trailing: 0,
+        }
+    }
+
+    fn finish(mut self) -> String {
+        trim_end(&mut self.out);
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.out
+    }
+
+    fn word(&mut self, s: &str) {
+        self.out.push_str(s);
+    }
+
+    fn newline(&mut self) {
+        trim_end(&mut self.out);
+        self.out.push('\n');
+        for _ in 0..self.indent * INDENT {
+            self.out.push(' ');
+        }
+    }
+
+    fn column(&self) -> usize {
+        let line = match self.out.rfind('\n') {
+            Some(i) => &self.out[i + 1..],
+            None => &self.out,
+        };
+        line.chars().count()
+    }
+
+    // Prints into a scratch buffer and returns the result if it is a single
+    // line that fits within the margin from the current column.
+    fn trailing<F: FnOnce(&mut Printer)>(&mut self, trailing: usize, f: F) {
+        let outer = mem::replace(&mut self.trailing, trailing);
+        f(self);
+        self.trailing = outer;
+    }
+
+    fn fits<F: FnOnce(&mut Printer)>(&self, f: F) -> Option<String> {
+        let mut scratch = Printer {
+            out: String::new(),
+            indent: self.indent,
+            flat: true,
+            trailing: 0,
+        };
+        f(&mut scratch);
+        let fits = !scratch.out.contains('\n')
+            && self.column() + scratch.out.chars().count() + self.trailing <= MARGIN;
+        if fits {
+            Some(scratch.out)
+        } else {
+            None
+        }
+    }
+
+    // A comma-separated list surrounded by `open` and `close`. If it does not
+    // fit on one line, every element goes on its own line with a trailing
+    // comma. Surrounding spaces in `open` and `close` are only printed when
+    // the list is on one line.
+    fn list<'a, T: ?Sized + 'a, I>(
+        &mut self,
+        open: &str,
+        elems: I,
+        close: &str,
+        f: fn(&mut Self, &T),
+    ) where
+        I: IntoIterator<Item = &'a T>,
+        I::IntoIter: Clone,
+    {
+        self.list_before(open, elems, close, f, 0, true);
+    }
+
+    // Like `list`, but `trailing` columns of what follows the list on the
+    // same line must also fit. The trailing comma is left out when
+    // `terminated` is false, as after the `...` of a variadic function.
+    fn list_before<'a, T: ?Sized + 'a, I>(
+        &mut self,
+        open: &str,
+        elems: I,
+        close: &str,
+        f: fn(&mut Self, &T),
+        trailing: usize,
+        terminated: bool,
+    ) where
+        I: IntoIterator<Item = &'a T>,
+        I::IntoIter: Clone,
+    {
+        let elems = elems.into_iter();
+        if elems.clone().next().is_none() {
+            self.word(open.trim_end());
+            self.word(close.trim_start());
+            return;
+        }
+        let flat = |p: &mut Printer| {
+            p.word(open);
+            for (i, elem) in elems.clone().enumerate() {
+                if i > 0 {
+                    p.word(", ");
+                }
+                f(p, elem);
+            }
+            p.word(close);
+        };
+        if self.flat {
+            flat(self);
+            return;
+        }
+        if let Some(line) = self.fits(flat) {
+            if self.column() + line.chars().count() + trailing <= MARGIN {
+                self.word(&line);
+                return;
+            }
+        }
+        self.word(open.trim_end());
+        self.indent += 1;
+        let mut elems = elems.peekable();
+        while let Some(elem) = elems.next() {
+            let comma = terminated || elems.peek().is_some();
+            self.newline();
+            self.trailing(usize::from(comma), |p| f(p, elem));
+            if comma {
+                self.word(",");
+            }
+        }
+        self.indent -= 1;
+        self.newline();
+        self.word(close.trim_start());
+    }
+
+    // Like `list`, but for the arguments of a macro, whose tokens must be
+    // preserved: there is a trailing comma if and only if the input had one.
+    fn macro_args(&mut self, open: &str, args: &Punctuated<Expr, Token![,]>, close: &str) {
+        let flat = |p: &mut Printer| {
+            p.word(open);
+            for (i, arg) in args.iter().enumerate() {
+                if i > 0 {
+                    p.word(", ");
+                }
+                p.expr(arg);
+            }
+            if args.trailing_punct() {
+                p.word(",");
+            }
+            p.word(close);
+        };
+        if self.flat {
+            flat(self);
+            return;
+        }
+        if let Some(line) = self.fits(flat) {
+            self.word(&line);
+            return;
+        }
+        self.word(open);
+        self.indent += 1;
+        for pair in args.pairs() {
+            self.newline();
+            self.trailing(1, |p| p.expr(pair.value()));
+            if pair.punct().is_some() {
+                self.word(",");
+            }
+        }
+        self.indent -= 1;
+        self.newline();
+        self.word(close);
+    }
+
+    fn items<T>(&mut self, items: &[T], f: fn(&mut Self, &T)) {
+        let mut prev_multiline = false;
+        for (i, item) in items.iter().enumerate() {
+            let indent = self.indent * INDENT;
+            let mut scratch = Printer {
+                out: " ".repeat(indent),
+                indent: self.indent,
+                flat: false,
+                trailing: 0,
+            };
+            f(&mut scratch, item);
+            let text = &scratch.out[indent..];
+            let multiline = text.contains('\n');
+            if i > 0 {
+                let same_kind = mem::discriminant(&items[i - 1]) == mem::discriminant(item);
+                if prev_multiline || multiline || !same_kind {
+                    self.out.push('\n');
+                }
+                self.newline();
+            }
+            self.word(text);
+            prev_multiline = multiline;
+        }
+    }
+
+    // Prints tokens on a single line with conventional spacing. Used for the
+    // parts of the syntax tree that are rarely long enough to need breaking,
+    // such as types, patterns and paths.
+    fn tokens<T: ?Sized + ToTokens>(&mut self, node: &T) {
+        self.atoms(node.to_token_stream(), false);
+    }
+
+    // Prints tokens that are kept as a `TokenStream` in the syntax tree, such
+    // as the body of a macro invocation. Punctuation is kept apart or joined
+    // exactly as it was so that the same tokens are obtained when parsed
+    // again.
+    fn verbatim(&mut self, tokens: &TokenStream) {
+        self.atoms(tokens.clone(), true);
+    }
+
+    fn atoms(&mut self, tokens: TokenStream, strict: bool) {
+        let mut atoms = Vec::new();
+        flatten(tokens, &mut atoms);
+        self.atom_slice(&atoms, strict);
+    }
+
+    // Groups that do not fit on the current line are broken after every `;`
+    // and `,` and between items, with their contents indented.
+    fn atom_slice(&mut self, atoms: &[Atom], strict: bool) {
+        let mut closes = vec![0; atoms.len()];
+        let mut opens = Vec::new();
+        for (i, atom) in atoms.iter().enumerate() {
+            match atom {
+                Atom::Open(_) => opens.push(i),
+                Atom::Close(_) => closes[opens.pop().unwrap()] = i,
+                _ => {}
+            }
+        }
+
+        let mut broken = Vec::new();
+        let mut prev: Option<&Atom> = None;
+        let mut prev_prefix = false;
+        let mut glued = false;
+        for (i, atom) in atoms.iter().enumerate() {
+            if let Atom::Glue = atom {
+                glued = true;
+                continue;
+            }
+            if let (Some(prev), false) = (prev, glued) {
+                let opened_line = match prev {
+                    Atom::Open(_) => broken.last() == Some(&true),
+                    _ => false,
+                };
+                if opened_line {
+                    // Already at the start of the group's first line.
+                } else if broken.last() == Some(&true) && line_break(prev, atom) {
+                    self.newline();
+                } else if strict && is_punct(prev) && is_punct(atom)
+                    || space_between(prev, prev_prefix, atom, atoms.get(i + 1))
+                {
+                    self.word(" ");
+                }
+            }
+            prev_prefix = prev.map_or(true, prefix_position);
+            prev = Some(atom);
+            glued = false;
+            match atom {
+                Atom::Word(word) | Atom::Op(word) => self.word(word),
+                Atom::Open(delimiter) => {
+                    let group = &atoms[i..=closes[i]];
+                    let empty = group.len() == 2;
+                    let fits = self.flat || empty || {
+                        let flat = self.fits(|p| p.atom_slice(group, strict));
+                        flat.is_some()
+                    };
+                    self.word(open(*delimiter));
+                    if !fits {
+                        self.indent += 1;
+                        self.newline();
+                    }
+                    broken.push(!fits);
+                }
+                Atom::Close(delimiter) => {
+                    if broken.pop().unwrap() {
+                        self.indent -= 1;
+                        self.newline();
+                    }
+                    self.word(close(*delimiter));
+                }
+                Atom::Glue => unreachable!(),
+            }
+        }
+    }
+
+    fn outer_attrs(&mut self, attrs: &[Attribute]) {
+        for attr in attrs.outer() {
+            self.attr(attr);
+            self.newline();
+        }
+    }
+
+    fn inner_attrs(&mut self, attrs: &[Attribute]) {
+        for attr in attrs.inner() {
+            self.attr(attr);
+            self.newline();
+        }
+    }
+
+    // Outer attributes on an expression, kept on the same line. Doc comments
+    // would comment out the rest of the line, so they are written as
+    // `#[doc = "..."]`.
+    fn expr_attrs(&mut self, attrs: &[Attribute]) {
+        for attr in attrs.outer() {
+            self.attr_tokens(attr);
+            self.word(" ");
+        }
+    }
+
+    fn attr(&mut self, attr: &Attribute) {
+        if let Some(doc) = doc_comment(attr) {
+            self.word(match attr.style {
+                AttrStyle::Outer => "///",
+                AttrStyle::Inner(_) => "//!",
+            });
+            self.word(&doc);
+        } else {
+            self.attr_tokens(attr);
+        }
+    }
+
+    fn attr_tokens(&mut self, attr: &Attribute) {
+        self.word(match attr.style {
+            AttrStyle::Outer => "#[",
+            AttrStyle::Inner(_) => "#![",
+        });
+        self.tokens(&attr.path);
+        match attr.tokens.clone().into_iter().next() {
+            None => {}
+            Some(TokenTree::Group(ref group)) if group.delimiter() != Delimiter::Brace => {}
+            Some(_) => self.word(" "),
+        }
+        self.verbatim(&attr.tokens);
+        self.word("]");
+    }
+
+    fn vis(&mut self, vis: &Visibility) {
+        if let Visibility::Inherited = vis {
+            return;
+        }
+        self.tokens(vis);
+        self.word(" ");
+    }
+
+    fn where_clause(&mut self, where_clause: &Option<WhereClause>) -> bool {
+        let where_clause = match where_clause {
+            Some(where_clause) if !where_clause.predicates.is_empty() => where_clause,
+            _ => return false,
+        };
+        self.newline();
+        self.word("where");
+        self.indent += 1;
+        for predicate in &where_clause.predicates {
+            self.newline();
+            self.tokens(predicate);
+            self.word(",");
+        }
+        self.indent -= 1;
+        true
+    }
+
+    // Opens a brace-delimited body following a header that may have ended
+    // with a where-clause.
+    fn open_body(&mut self, after_where: bool) {
+        if after_where {
+            self.newline();
+            self.word("{");
+        } else {
+            self.word(" {");
+        }
+    }
+
+    fn item(&mut self, item: &Item) {
+        match item {
+            Item::Const(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("const ");
+                self.tokens(&item.ident);
+                self.word(": ");
+                self.tokens(&*item.ty);
+                self.word(" = ");
+                self.expr(&item.expr);
+                self.word(";");
+            }
+            Item::Enum(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("enum ");
+                self.tokens(&item.ident);
+                self.tokens(&item.generics);
+                let after_where = self.where_clause(&item.generics.where_clause);
+                self.open_body(after_where);
+                self.indent += 1;
+                self.inner_attrs_in_body(&item.attrs);
+                for variant in &item.variants {
+                    self.newline();
+                    self.variant(variant);
+                    self.word(",");
+                }
+                self.close_body();
+            }
+            Item::ExternCrate(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("extern crate ");
+                self.tokens(&item.ident);
+                if let Some((_as, rename)) = &item.rename {
+                    self.word(" as ");
+                    self.tokens(rename);
+                }
+                self.word(";");
+            }
+            Item::Fn(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.signature(&item.sig);
+                let after_where = self.where_clause(&item.sig.generics.where_clause);
+                self.block_with_attrs(&item.block, &item.attrs, after_where);
+            }
+            Item::ForeignMod(item) => {
+                self.outer_attrs(&item.attrs);
+                self.tokens(&item.abi);
+                self.word(" {");
+                self.indent += 1;
+                self.body_items(&item.attrs, &item.items, Printer::foreign_item);
+                self.close_body();
+            }
+            Item::Impl(item) => {
+                self.outer_attrs(&item.attrs);
+                if item.defaultness.is_some() {
+                    self.word("default ");
+                }
+                if item.unsafety.is_some() {
+                    self.word("unsafe ");
+                }
+                self.word("impl");
+                self.tokens(&item.generics);
+                self.word(" ");
+                if let Some((negative, path, _for)) = &item.trait_ {
+                    if negative.is_some() {
+                        self.word("!");
+                    }
+                    self.tokens(path);
+                    self.word(" for ");
+                }
+                self.tokens(&*item.self_ty);
+                let after_where = self.where_clause(&item.generics.where_clause);
+                self.open_body(after_where);
+                self.indent += 1;
+                self.body_items(&item.attrs, &item.items, Printer::impl_item);
+                self.close_body();
+            }
+            Item::Macro(item) => {
+                self.outer_attrs(&item.attrs);
+                self.mac(&item.mac, item.ident.as_ref());
+                if item.semi_token.is_some() {
+                    self.word(";");
+                }
+            }
+            Item::Macro2(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("macro ");
+                self.tokens(&item.ident);
+                self.verbatim(&item.rules);
+            }
+            Item::Mod(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("mod ");
+                self.tokens(&item.ident);
+                match &item.content {
+                    Some((_brace, items)) => {
+                        self.word(" {");
+                        self.indent += 1;
+                        self.body_items(&item.attrs, items, Printer::item);
+                        self.close_body();
+                    }
+                    None => self.word(";"),
+                }
+            }
+            Item::Static(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("static ");
+                if item.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.tokens(&item.ident);
+                self.word(": ");
+                self.tokens(&*item.ty);
+                self.word(" = ");
+                self.expr(&item.expr);
+                self.word(";");
+            }
+            Item::Struct(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("struct ");
+                self.tokens(&item.ident);
+                self.tokens(&item.generics);
+                self.fields_item(&item.fields, &item.generics.where_clause);
+            }
+            Item::Trait(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                if item.unsafety.is_some() {
+                    self.word("unsafe ");
+                }
+                if item.auto_token.is_some() {
+                    self.word("auto ");
+                }
+                self.word("trait ");
+                self.tokens(&item.ident);
+                self.tokens(&item.generics);
+                if !item.supertraits.is_empty() {
+                    self.word(": ");
+                    self.tokens(&item.supertraits);
+                }
+                let after_where = self.where_clause(&item.generics.where_clause);
+                self.open_body(after_where);
+                self.indent += 1;
+                self.body_items(&item.attrs, &item.items, Printer::trait_item);
+                self.close_body();
+            }
+            Item::TraitAlias(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("trait ");
+                self.tokens(&item.ident);
+                self.tokens(&item.generics);
+                self.word(" = ");
+                self.tokens(&item.bounds);
+                self.where_clause(&item.generics.where_clause);
+                self.word(";");
+            }
+            Item::Type(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("type ");
+                self.tokens(&item.ident);
+                self.tokens(&item.generics);
+                if self.where_clause(&item.generics.where_clause) {
+                    self.newline();
+                    self.word("=");
+                } else {
+                    self.word(" =");
+                }
+                self.word(" ");
+                self.tokens(&*item.ty);
+                self.word(";");
+            }
+            Item::Union(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("union ");
+                self.tokens(&item.ident);
+                self.tokens(&item.generics);
+                self.named_fields(&item.fields, &item.generics.where_clause);
+            }
+            Item::Use(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("use ");
+                if item.leading_colon.is_some() {
+                    self.word("::");
+                }
+                self.use_tree(&item.tree);
+                self.word(";");
+            }
+            Item::Verbatim(tokens) => self.verbatim(tokens),
+            Item::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    fn body_items<T>(&mut self, attrs: &[Attribute], items: &[T], f: fn(&mut Self, &T)) {
+        self.inner_attrs_in_body(attrs);
+        if !items.is_empty() {
+            if attrs.inner().next().is_some() {
+                self.out.push('\n');
+            }
+            self.newline();
+            self.items(items, f);
+        }
+    }
+
+    fn close_body(&mut self) {
+        self.indent -= 1;
+        if self.out.ends_with('{') {
+            self.word("}");
+        } else {
+            self.newline();
+            self.word("}");
+        }
+    }
+
+    fn fields_item(&mut self, fields: &Fields, where_clause: &Option<WhereClause>) {
+        match fields {
+            Fields::Named(fields) => self.named_fields(fields, where_clause),
+            Fields::Unnamed(fields) => {
+                self.list("(", &fields.unnamed, ")", Printer::field);
+                self.where_clause(where_clause);
+                self.word(";");
+            }
+            Fields::Unit => {
+                self.where_clause(where_clause);
+                self.word(";");
+            }
+        }
+    }
+
+    fn named_fields(&mut self, fields: &FieldsNamed, where_clause: &Option<WhereClause>) {
+        let after_where = self.where_clause(where_clause);
+        self.open_body(after_where);
+        self.indent += 1;
+        for field in &fields.named {
+            self.newline();
+            self.field(field);
+            self.word(",");
+        }
+        self.close_body();
+    }
+
+    fn field(&mut self, field: &Field) {
+        if self.flat {
+            self.expr_attrs(&field.attrs);
+        } else {
+            self.outer_attrs(&field.attrs);
+        }
+        self.vis(&field.vis);
+        if let Some(ident) = &field.ident {
+            self.tokens(ident);
+            self.word(": ");
+        }
+        self.tokens(&field.ty);
+    }
+
+    fn variant(&mut self, variant: &Variant) {
+        self.outer_attrs(&variant.attrs);
+        self.tokens(&variant.ident);
+        match &variant.fields {
+            Fields::Named(fields) => self.list(" { ", &fields.named, " }", Printer::field),
+            Fields::Unnamed(fields) => self.list("(", &fields.unnamed, ")", Printer::field),
+            Fields::Unit => {}
+        }
+        if let Some((_eq, discriminant)) = &variant.discriminant {
+            self.word(" = ");
+            self.expr(discriminant);
+        }
+    }
+
+    fn use_tree(&mut self, tree: &UseTree) {
+        match tree {
+            UseTree::Path(tree) => {
+                self.tokens(&tree.ident);
+                self.word("::");
+                self.use_tree(&tree.tree);
+            }
+            UseTree::Name(tree) => self.tokens(&tree.ident),
+            UseTree::Rename(tree) => {
+                self.tokens(&tree.ident);
+                self.word(" as ");
+                self.tokens(&tree.rename);
+            }
+            UseTree::Glob(_) => self.word("*"),
+            UseTree::Group(tree) => {
+                self.list_before("{", &tree.items, "}", Printer::use_tree, 1, true);
+            }
+        }
+    }
+
+    fn signature(&mut self, sig: &Signature) {
+        if sig.constness.is_some() {
+            self.word("const ");
+        }
+        if sig.asyncness.is_some() {
+            self.word("async ");
+        }
+        if sig.unsafety.is_some() {
+            self.word("unsafe ");
+        }
+        if let Some(abi) = &sig.abi {
+            self.tokens(abi);
+            self.word(" ");
+        }
+        self.word("fn ");
+        self.tokens(&sig.ident);
+        self.tokens(&sig.generics);
+        let mut inputs: Vec<&dyn ToTokens> = Vec::new();
+        for input in &sig.inputs {
+            inputs.push(input);
+        }
+        if let Some(variadic) = &sig.variadic {
+            inputs.push(variadic);
+        }
+        let mut output = Printer::new();
+        output.return_type(&sig.output);
+        let trailing = output.out.chars().count() + " {".len();
+        let terminated = sig.variadic.is_none();
+        self.list_before("(", inputs, ")", Printer::tokens, trailing, terminated);
+        self.word(&output.out);
+    }
+
+    fn return_type(&mut self, output: &ReturnType) {
+        if let ReturnType::Type(_arrow, ty) = output {
+            self.word(" -> ");
+            self.tokens(&**ty);
+        }
+    }
+
+    fn impl_item(&mut self, item: &ImplItem) {
+        match item {
+            ImplItem::Const(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                if item.defaultness.is_some() {
+                    self.word("default ");
+                }
+                self.word("const ");
+                self.tokens(&item.ident);
+                self.word(": ");
+                self.tokens(&item.ty);
+                self.word(" = ");
+                self.expr(&item.expr);
+                self.word(";");
+            }
+            ImplItem::Method(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                if item.defaultness.is_some() {
+                    self.word("default ");
+                }
+                self.signature(&item.sig);
+                let after_where = self.where_clause(&item.sig.generics.where_clause);
+                self.block_with_attrs(&item.block, &item.attrs, after_where);
+            }
+            ImplItem::Type(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                if item.defaultness.is_some() {
+                    self.word("default ");
+                }
+                self.word("type ");
+                self.tokens(&item.ident);
+                self.tokens(&item.generics);
+                self.where_clause(&item.generics.where_clause);
+                self.word(" = ");
+                self.tokens(&item.ty);
+                self.word(";");
+            }
+            ImplItem::Macro(item) => {
+                self.outer_attrs(&item.attrs);
+                self.mac(&item.mac, None);
+                if item.semi_token.is_some() {
+                    self.word(";");
+                }
+            }
+            ImplItem::Verbatim(tokens) => self.verbatim(tokens),
+            ImplItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    fn trait_item(&mut self, item: &TraitItem) {
+        match item {
+            TraitItem::Const(item) => {
+                self.outer_attrs(&item.attrs);
+                self.word("const ");
+                self.tokens(&item.ident);
+                self.word(": ");
+                self.tokens(&item.ty);
+                if let Some((_eq, default)) = &item.default {
+                    self.word(" = ");
+                    self.expr(default);
+                }
+                self.word(";");
+            }
+            TraitItem::Method(item) => {
+                self.outer_attrs(&item.attrs);
+                self.signature(&item.sig);
+                let after_where = self.where_clause(&item.sig.generics.where_clause);
+                match &item.default {
+                    Some(block) => self.block_with_attrs(block, &item.attrs, after_where),
+                    None => self.word(";"),
+                }
+            }
+            TraitItem::Type(item) => {
+                self.outer_attrs(&item.attrs);
+                self.word("type ");
+                self.tokens(&item.ident);
+                self.tokens(&item.generics);
+                if !item.bounds.is_empty() {
+                    self.word(": ");
+                    self.tokens(&item.bounds);
+                }
+                self.where_clause(&item.generics.where_clause);
+                if let Some((_eq, default)) = &item.default {
+                    self.word(" = ");
+                    self.tokens(default);
+                }
+                self.word(";");
+            }
+            TraitItem::Macro(item) => {
+                self.outer_attrs(&item.attrs);
+                self.mac(&item.mac, None);
+                if item.semi_token.is_some() {
+                    self.word(";");
+                }
+            }
+            TraitItem::Verbatim(tokens) => self.verbatim(tokens),
+            TraitItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    fn foreign_item(&mut self, item: &ForeignItem) {
+        match item {
+            ForeignItem::Fn(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.signature(&item.sig);
+                self.where_clause(&item.sig.generics.where_clause);
+                self.word(";");
+            }
+            ForeignItem::Static(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("static ");
+                if item.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.tokens(&item.ident);
+                self.word(": ");
+                self.tokens(&*item.ty);
+                self.word(";");
+            }
+            ForeignItem::Type(item) => {
+                self.outer_attrs(&item.attrs);
+                self.vis(&item.vis);
+                self.word("type ");
+                self.tokens(&item.ident);
+                self.word(";");
+            }
+            ForeignItem::Macro(item) => {
+                self.outer_attrs(&item.attrs);
+                self.mac(&item.mac, None);
+                if item.semi_token.is_some() {
+                    self.word(";");
+                }
+            }
+            ForeignItem::Verbatim(tokens) => self.verbatim(tokens),
+            ForeignItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    fn mac(&mut self, mac: &Macro, ident: Option<&Ident>) {
+        self.tokens(&mac.path);
+        self.word("!");
+        if let Some(ident) = ident {
+            self.word(" ");
+            self.tokens(ident);
+        }
+        let (delimiter, open, close) = match mac.delimiter {
+            MacroDelimiter::Paren(_) => (Delimiter::Parenthesis, "(", ")"),
+            MacroDelimiter::Bracket(_) => (Delimiter::Bracket, "[", "]"),
+            MacroDelimiter::Brace(_) => {
+                self.word(" ");
+                self.verbatim(&group(Delimiter::Brace, &mac.tokens));
+                return;
+            }
+        };
+        // Most function-like macros take a comma-separated list of
+        // expressions, which can be formatted like the arguments of a call.
+        let parser = Punctuated::<Expr, Token![,]>::parse_terminated;
+        match parser.parse2(mac.tokens.clone()) {
+            // Only if nothing would be lost by printing the parsed expressions
+            // rather than the original tokens, as with the legacy `...` range
+            // syntax that is parsed the same as `..=`.
+            Ok(ref args) if args.to_token_stream().to_string() == mac.tokens.to_string() => {
+                // Printing the expressions normalizes their own trailing
+                // commas, which would change the tokens seen by the macro.
+                let start = self.out.len();
+                self.macro_args(open, args, close);
+                let printed = self.out[start..].parse::<TokenStream>().ok();
+                let original = group(delimiter, &mac.tokens);
+                if printed.map(|printed| printed.to_string()) != Some(original.to_string()) {
+                    self.out.truncate(start);
+                    self.verbatim(&original);
+                }
+            }
+            _ => self.verbatim(&group(delimiter, &mac.tokens)),
+        }
+    }
+
+    fn block(&mut self, block: &Block) {
+        self.block_with_attrs(block, &[], false);
+    }
+
+    fn block_with_attrs(&mut self, block: &Block, attrs: &[Attribute], after_where: bool) {
+        if after_where {
+            self.newline();
+        } else if !self.out.is_empty() && !self.out.ends_with(&[' ', '\n'][..]) {
+            self.word(" ");
+        }
+        self.word("{");
+        self.indent += 1;
+        self.inner_attrs_in_body(attrs);
+        for stmt in &block.stmts {
+            self.newline();
+            self.stmt(stmt);
+        }
+        self.close_body();
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Local(local) => {
+                self.outer_attrs(&local.attrs);
+                self.word("let ");
+                self.tokens(&local.pat);
+                if let Some((_eq, init)) = &local.init {
+                    self.word(" = ");
+                    self.trailing(1, |p| p.expr(init));
+                }
+                self.word(";");
+            }
+            Stmt::Item(item) => self.item(item),
+            Stmt::Expr(expr) => self.trailing(0, |p| p.expr_stmt(expr)),
+            Stmt::Semi(expr, _semi) => {
+                self.trailing(1, |p| p.expr_stmt(expr));
+                self.word(";");
+            }
+        }
+    }
+
+    // An expression statement that begins with a block-like expression but is
+    // not itself block-like, as in `match x {}.len()`, would be parsed as two
+    // statements unless it is parenthesized.
+    fn expr_stmt(&mut self, expr: &Expr) {
+        self.subexpr(expr, stmt_needs_parens(expr));
+    }
+
+    fn subexpr(&mut self, expr: &Expr, paren: bool) {
+        if paren {
+            self.word("(");
+            self.expr(expr);
+            self.word(")");
+        } else {
+            self.expr(expr);
+        }
+    }
+
+    // Expressions in the head of `if`, `while`, `match` and `for` may not
+    // contain a struct literal outside of parentheses.
+    // Returns whether the expression was broken across lines, in which case
+    // the following block starts on a line of its own.
+    fn cond(&mut self, expr: &Expr) -> bool {
+        let start = self.out.len();
+        self.trailing(" {".len(), |p| p.cond_expr(expr));
+        self.out[start..].contains('\n')
+    }
+
+    fn cond_expr(&mut self, expr: &Expr) {
+        if let Expr::Let(expr) = expr {
+            self.expr_attrs(&expr.attrs);
+            self.word("let ");
+            self.tokens(&expr.pat);
+            self.word(" = ");
+            let paren = Precedence::of_expr(&expr.expr) <= Precedence::And
+                || contains_exposed_struct(&expr.expr);
+            self.subexpr(&expr.expr, paren);
+        } else {
+            self.subexpr(expr, contains_exposed_struct(expr));
+        }
+    }
+
+    fn label(&mut self, label: &Option<Label>) {
+        if let Some(label) = label {
+            self.tokens(&label.name);
+            self.word(": ");
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Array(e) => {
+                if e.attrs.inner().next().is_some() {
+                    return self.tokens(e);
+                }
+                self.expr_attrs(&e.attrs);
+                self.list("[", &e.elems, "]", Printer::expr);
+            }
+            Expr::Assign(e) => {
+                self.expr_attrs(&e.attrs);
+                self.subexpr(&e.left, Precedence::of_expr(&e.left) <= Precedence::Assign);
+                self.word(" = ");
+                self.subexpr(&e.right, Precedence::of_expr(&e.right) < Precedence::Assign);
+            }
+            Expr::AssignOp(e) => {
+                self.expr_attrs(&e.attrs);
+                self.subexpr(&e.left, Precedence::of_expr(&e.left) <= Precedence::Assign);
+                self.word(" ");
+                self.tokens(&e.op);
+                self.word(" ");
+                self.subexpr(&e.right, Precedence::of_expr(&e.right) < Precedence::Assign);
+            }
+            Expr::Async(e) => {
+                self.expr_attrs(&e.attrs);
+                self.word("async ");
+                if e.capture.is_some() {
+                    self.word("move ");
+                }
+                self.block(&e.block);
+            }
+            Expr::Await(e) => {
+                self.expr_attrs(&e.attrs);
+                self.postfix_base(&e.base);
+                self.word(".await");
+            }
+            Expr::Binary(e) => {
+                self.expr_attrs(&e.attrs);
+                self.binary(e);
+            }
+            Expr::Block(e) => {
+                self.expr_attrs(&e.attrs);
+                self.label(&e.label);
+                self.block_with_attrs(&e.block, &e.attrs, false);
+            }
+            Expr::Box(e) => {
+                self.expr_attrs(&e.attrs);
+                self.word("box ");
+                self.subexpr(&e.expr, prefix_needs_parens(&e.expr));
+            }
+            Expr::Break(e) => {
+                self.expr_attrs(&e.attrs);
+                self.word("break");
+                if let Some(label) = &e.label {
+                    self.word(" ");
+                    self.tokens(label);
+                }
+                if let Some(value) = &e.expr {
+                    self.word(" ");
+                    self.expr(value);
+                }
+            }
+            Expr::Call(e) => {
+                self.expr_attrs(&e.attrs);
+                self.subexpr(&e.func, callee_needs_parens(&e.func));
+                self.args(&e.args);
+            }
+            Expr::Cast(e) => {
+                self.expr_attrs(&e.attrs);
+                self.subexpr(&e.expr, Precedence::of_expr(&e.expr) < Precedence::Cast);
+                self.word(" as ");
+                self.tokens(&*e.ty);
+            }
+            Expr::Closure(e) => {
+                self.expr_attrs(&e.attrs);
+                if e.asyncness.is_some() {
+                    self.word("async ");
+                }
+                if e.movability.is_some() {
+                    self.word("static ");
+                }
+                if e.capture.is_some() {
+                    self.word("move ");
+                }
+                self.word("|");
+                for (i, input) in e.inputs.iter().enumerate() {
+                    if i > 0 {
+                        self.word(", ");
+                    }
+                    self.tokens(input);
+                }
+                self.word("|");
+                self.return_type(&e.output);
+                self.word(" ");
+                self.expr(&e.body);
+            }
+            Expr::Continue(e) => {
+                self.expr_attrs(&e.attrs);
+                self.word("continue");
+                if let Some(label) = &e.label {
+                    self.word(" ");
+                    self.tokens(label);
+                }
+            }
+            Expr::Field(e) => {
+                self.expr_attrs(&e.attrs);
+                self.postfix_base(&e.base);
+                self.word(".");
+                self.member(&e.member);
+            }
+            Expr::ForLoop(e) => {
+                self.expr_attrs(&e.attrs);
+                self.label(&e.label);
+                self.word("for ");
+                self.tokens(&e.pat);
+                self.word(" in ");
+                let multiline = self.cond(&e.expr);
+                self.block_with_attrs(&e.body, &e.attrs, multiline);
+            }
+            Expr::Group(e) => {
+                self.expr_attrs(&e.attrs);
+                self.expr(&e.expr);
+            }
+            Expr::If(e) => {
+                self.expr_attrs(&e.attrs);
+                self.expr_if(e);
+            }
+            Expr::Index(e) => {
+                self.expr_attrs(&e.attrs);
+                self.postfix_base(&e.expr);
+                self.word("[");
+                self.expr(&e.index);
+                self.word("]");
+            }
+            Expr::Let(e) => {
+                self.expr_attrs(&e.attrs);
+                self.word("let ");
+                self.tokens(&e.pat);
+                self.word(" = ");
+                self.subexpr(&e.expr, Precedence::of_expr(&e.expr) <= Precedence::And);
+            }
+            Expr::Lit(e) => {
+                self.expr_attrs(&e.attrs);
+                self.tokens(&e.lit);
+            }
+            Expr::Loop(e) => {
+                self.expr_attrs(&e.attrs);
+                self.label(&e.label);
+                self.word("loop");
+                self.block_with_attrs(&e.body, &e.attrs, false);
+            }
+            Expr::Macro(e) => {
+                self.expr_attrs(&e.attrs);
+                self.mac(&e.mac, None);
+            }
+            Expr::Match(e) => {
+                self.expr_attrs(&e.attrs);
+                self.word("match ");
+                self.cond(&e.expr);
+                self.word(" {");
+                self.indent += 1;
+                self.inner_attrs_in_body(&e.attrs);
+                for (i, arm) in e.arms.iter().enumerate() {
+                    self.newline();
+                    self.arm(arm, i + 1 == e.arms.len());
+                }
+                self.close_body();
+            }
+            Expr::MethodCall(e) => {
+                self.expr_attrs(&e.attrs);
+                self.method_chain(e);
+            }
+            Expr::Paren(e) => {
+                if e.attrs.inner().next().is_some() {
+                    return self.tokens(e);
+                }
+                self.expr_attrs(&e.attrs);
+                self.word("(");
+                self.expr(&e.expr);
+                self.word(")");
+            }
+            Expr::Path(e) => self.tokens(e),
+            Expr::Range(e) => {
+                self.expr_attrs(&e.attrs);
+                if let Some(from) = &e.from {
+                    self.subexpr(from, Precedence::of_expr(from) <= Precedence::Range);
+                }
+                self.word(match e.limits {
+                    RangeLimits::HalfOpen(_) => "..",
+                    RangeLimits::Closed(_) => "..=",
+                });
+                if let Some(to) = &e.to {
+                    self.subexpr(to, Precedence::of_expr(to) <= Precedence::Range);
+                }
+            }
+            Expr::Reference(e) => {
+                self.expr_attrs(&e.attrs);
+                self.word("&");
+                if e.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.subexpr(&e.expr, prefix_needs_parens(&e.expr));
+            }
+            Expr::Repeat(e) => {
+                if e.attrs.inner().next().is_some() {
+                    return self.tokens(e);
+                }
+                self.expr_attrs(&e.attrs);
+                self.word("[");
+                self.expr(&e.expr);
+                self.word("; ");
+                self.expr(&e.len);
+                self.word("]");
+            }
+            Expr::Return(e) => {
+                self.expr_attrs(&e.attrs);
+                self.word("return");
+                if let Some(value) = &e.expr {
+                    self.word(" ");
+                    self.expr(value);
+                }
+            }
+            Expr::Struct(e) => {
+                if e.attrs.inner().next().is_some() {
+                    return self.tokens(e);
+                }
+                self.expr_attrs(&e.attrs);
+                self.expr_struct(e);
+            }
+            Expr::Try(e) => {
+                self.expr_attrs(&e.attrs);
+                self.postfix_base(&e.expr);
+                self.word("?");
+            }
+            Expr::TryBlock(e) => {
+                self.expr_attrs(&e.attrs);
+                self.word("try ");
+                self.block(&e.block);
+            }
+            Expr::Tuple(e) => {
+                if e.attrs.inner().next().is_some() {
+                    return self.tokens(e);
+                }
+                self.expr_attrs(&e.attrs);
+                if e.elems.len() == 1 {
+                    self.word("(");
+                    self.expr(&e.elems[0]);
+                    self.word(",)");
+                } else {
+                    self.list("(", &e.elems, ")", Printer::expr);
+                }
+            }
+            Expr::Type(e) => {
+                self.expr_attrs(&e.attrs);
+                self.subexpr(&e.expr, Precedence::of_expr(&e.expr) < Precedence::Cast);
+                self.word(": ");
+                self.tokens(&*e.ty);
+            }
+            Expr::Unary(e) => {
+                self.expr_attrs(&e.attrs);
+                self.tokens(&e.op);
+                self.subexpr(&e.expr, prefix_needs_parens(&e.expr));
+            }
+            Expr::Unsafe(e) => {
+                self.expr_attrs(&e.attrs);
+                self.word("unsafe");
+                self.block_with_attrs(&e.block, &e.attrs, false);
+            }
+            Expr::Verbatim(tokens) => self.verbatim(tokens),
+            Expr::While(e) => {
+                self.expr_attrs(&e.attrs);
+                self.label(&e.label);
+                self.word("while ");
+                let multiline = self.cond(&e.cond);
+                self.block_with_attrs(&e.body, &e.attrs, multiline);
+            }
+            Expr::Yield(e) => {
+                self.expr_attrs(&e.attrs);
+                self.word("yield");
+                if let Some(value) = &e.expr {
+                    self.word(" ");
+                    self.expr(value);
+                }
+            }
+            Expr::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    // A chain of operators of the same precedence, such as `a && b && c`, is
+    // broken before every operator if it does not fit on one line.
+    fn binary(&mut self, e: &ExprBinary) {
+        let mut rest = Vec::new();
+        let mut first = e;
+        loop {
+            rest.push(first);
+            match &*first.left {
+                Expr::Binary(left)
+                    if left.attrs.is_empty()
+                        && !left_needs_parens(first)
+                        && Precedence::of(&left.op) == Precedence::of(&first.op) =>
+                {
+                    first = left;
+                }
+                _ => break,
+            }
+        }
+        rest.reverse();
+        let flat = |p: &mut Printer| {
+            p.subexpr(&first.left, left_needs_parens(first));
+            for e in &rest {
+                p.word(" ");
+                p.tokens(&e.op);
+                p.word(" ");
+                p.subexpr(&e.right, right_needs_parens(e));
+            }
+        };
+        if self.flat {
+            flat(self);
+            return;
+        }
+        if let Some(line) = self.fits(flat) {
+            self.word(&line);
+            return;
+        }
+        self.subexpr(&first.left, left_needs_parens(first));
+        self.indent += 1;
+        for e in &rest {
+            self.newline();
+            self.tokens(&e.op);
+            self.word(" ");
+            self.subexpr(&e.right, right_needs_parens(e));
+        }
+        self.indent -= 1;
+    }
+
+    fn inner_attrs_in_body(&mut self, attrs: &[Attribute]) {
+        for attr in attrs.inner() {
+            self.newline();
+            self.attr(attr);
+        }
+    }
+
+    fn postfix_base(&mut self, base: &Expr) {
+        self.subexpr(base, Precedence::of_expr(base) < Precedence::Postfix);
+    }
+
+    fn member(&mut self, member: &Member) {
+        match member {
+            Member::Named(ident) => self.tokens(ident),
+            Member::Unnamed(index) => self.word(&index.index.to_string()),
+        }
+    }
+
+    // Call arguments. When the arguments do not fit on one line but only the
+    // last one spans multiple lines, such as a closure with a block body, the
+    // last argument is allowed to overflow instead of breaking every one.
+    fn args(&mut self, args: &Punctuated<Expr, Token![,]>) {
+        if !self.flat && args.last().map_or(false, overflows) {
+            let fits = self
+                .fits(|p| p.list("(", args, ")", Printer::expr))
+                .is_some();
+            if !fits && self.overflow_args(args) {
+                return;
+            }
+        }
+        self.list("(", args, ")", Printer::expr);
+    }
+
+    // Prints all but the last argument on the current line and lets the last
+    // one continue on the following lines. Undoes the attempt and returns
+    // false if the first line ends up too long.
+    fn overflow_args(&mut self, args: &Punctuated<Expr, Token![,]>) -> bool {
+        let checkpoint = self.out.len();
+        self.word("(");
+        for arg in args.iter().take(args.len() - 1) {
+            self.expr(arg);
+            self.word(", ");
+        }
+        let head_end = self.out.len();
+        self.expr(args.last().unwrap());
+        self.word(")");
+        let line_start = self.out[..checkpoint].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.out[checkpoint..]
+            .find('\n')
+            .map_or(self.out.len(), |i| checkpoint + i);
+        if line_end >= head_end && self.out[line_start..line_end].chars().count() <= MARGIN {
+            return true;
+        }
+        self.out.truncate(checkpoint);
+        false
+    }
+
+    fn method_chain(&mut self, e: &ExprMethodCall) {
+        let mut calls = vec![e];
+        let mut root = &*e.receiver;
+        while let Expr::MethodCall(receiver) = root {
+            calls.push(receiver);
+            root = &*receiver.receiver;
+        }
+        calls.reverse();
+        let flat = |p: &mut Printer| {
+            p.postfix_base(root);
+            for call in &calls {
+                p.method_call(call);
+            }
+        };
+        let simple_root = match root {
+            Expr::Path(_) | Expr::Field(_) => true,
+            _ => false,
+        };
+        if self.flat || calls.len() < 2 && simple_root {
+            flat(self);
+            return;
+        }
+        if let Some(line) = self.fits(flat) {
+            self.word(&line);
+            return;
+        }
+        self.postfix_base(root);
+        self.indent += 1;
+        for call in &calls {
+            self.newline();
+            self.method_call(call);
+        }
+        self.indent -= 1;
+    }
+
+    fn method_call(&mut self, e: &ExprMethodCall) {
+        self.word(".");
+        self.tokens(&e.method);
+        if let Some(turbofish) = &e.turbofish {
+            self.tokens(turbofish);
+        }
+        self.args(&e.args);
+    }
+
+    fn expr_if(&mut self, e: &ExprIf) {
+        self.word("if ");
+        let multiline = self.cond(&e.cond);
+        self.block_with_attrs(&e.then_branch, &[], multiline);
+        if let Some((_else, branch)) = &e.else_branch {
+            self.word(" else ");
+            match &**branch {
+                Expr::If(e) if e.attrs.is_empty() => self.expr_if(e),
+                Expr::Block(e) if e.attrs.is_empty() && e.label.is_none() => {
+                    self.block(&e.block);
+                }
+                other => self.expr(other),
+            }
+        }
+    }
+
+    fn expr_struct(&mut self, e: &ExprStruct) {
+        self.tokens(&e.path);
+        if e.fields.is_empty() && e.rest.is_none() {
+            self.word(if e.dot2_token.is_some() {
+                " { .. }"
+            } else {
+                " {}"
+            });
+            return;
+        }
+        let flat = |p: &mut Printer| {
+            p.word(" { ");
+            for (i, field) in e.fields.iter().enumerate() {
+                if i > 0 {
+                    p.word(", ");
+                }
+                p.field_value(field);
+            }
+            if e.dot2_token.is_some() {
+                if !e.fields.is_empty() {
+                    p.word(", ");
+                }
+                p.word("..");
+                if let Some(rest) = &e.rest {
+                    p.expr(rest);
+                }
+            }
+            p.word(" }");
+        };
+        if self.flat {
+            flat(self);
+            return;
+        }
+        if let Some(line) = self.fits(flat) {
+            self.word(&line);
+            return;
+        }
+        self.word(" {");
+        self.indent += 1;
+        for field in &e.fields {
+            self.newline();
+            self.field_value(field);
+            self.word(",");
+        }
+        if e.dot2_token.is_some() {
+            self.newline();
+            self.word("..");
+            if let Some(rest) = &e.rest {
+                self.expr(rest);
+            }
+        }
+        self.indent -= 1;
+        self.newline();
+        self.word("}");
+    }
+
+    fn field_value(&mut self, field: &FieldValue) {
+        self.expr_attrs(&field.attrs);
+        self.member(&field.member);
+        if field.colon_token.is_some() {
+            self.word(": ");
+            self.expr(&field.expr);
+        }
+    }
+
+    // The comma after an arm is kept as written, and added only where the
+    // next arm could not otherwise be parsed.
+    fn arm(&mut self, arm: &Arm, last: bool) {
+        self.outer_attrs(&arm.attrs);
+        self.trailing(" =>".len(), |p| p.pat_top(&arm.pat));
+        if let Some((_if, guard)) = &arm.guard {
+            self.word(" if ");
+            self.expr(guard);
+        }
+        self.word(" => ");
+        let comma = arm.comma.is_some() || !last && requires_terminator(&arm.body);
+        match &*arm.body {
+            Expr::Block(body) if body.attrs.is_empty() && body.label.is_none() => {
+                self.block(&body.block);
+            }
+            body => self.trailing(usize::from(comma), |p| p.expr(body)),
+        }
+        if comma {
+            self.word(",");
+        }
+    }
+}
+
+impl Printer {
+    // Alternatives of a top-level or-pattern go on separate lines if they do
+    // not fit on one.
+    fn pat_top(&mut self, pat: &Pat) {
+        let cases = match pat {
+            Pat::Or(pat) if pat.attrs.is_empty() && !self.flat => &pat.cases,
+            _ => return self.tokens(pat),
+        };
+        if let Some(line) = self.fits(|p| p.tokens(pat)) {
+            self.word(&line);
+            return;
+        }
+        for (i, case) in cases.iter().enumerate() {
+            if i > 0 {
+                self.newline();
+                self.word("| ");
+            }
+            self.tokens(case);
+        }
+    }
+}
+
+fn trim_end(out: &mut String) {
+    while out.ends_with(' ') {
+        out.pop();
+    }
+}
+
+fn group(delimiter: Delimiter, tokens: &TokenStream) -> TokenStream {
+    TokenStream::from(TokenTree::Group(Group::new(delimiter, tokens.clone())))
+}
+
+// Where a group that does not fit on one line is broken: after separators, and
+// between a brace-delimited body and the start of the next item.
+fn line_break(prev: &Atom, cur: &Atom) -> bool {
+    match (prev, cur) {
+        (_, Atom::Close(_)) => false,
+        (Atom::Op(op), _) => op == ";" || op == ",",
+        (Atom::Close(Delimiter::Brace), Atom::Word(word)) => word != "else",
+        (Atom::Close(Delimiter::Brace), Atom::Op(op)) => op == "#" || op == "$",
+        (Atom::Close(Delimiter::Bracket), Atom::Op(op)) => op == "#",
+        (Atom::Close(Delimiter::Bracket), Atom::Word(word)) => ITEM_KEYWORDS.contains(&&**word),
+        _ => false,
+    }
+}
+
+const ITEM_KEYWORDS: &[&str] = &[
+    "const", "enum", "extern", "fn", "impl", "mod", "pub", "static", "struct", "trait", "type",
+    "union", "unsafe", "use",
+];
+
+fn doc_comment(attr: &Attribute) -> Option<String> {
+    if !attr.path.is_ident("doc") {
+        return None;
+    }
+    match attr.parse_meta() {
+        Ok(Meta::NameValue(MetaNameValue {
+            lit: Lit::Str(lit), ..
+        })) => {
+            let value = lit.value();
+            let multiline = value.contains('\n') || value.contains('\r');
+            // Lines are trimmed at the end, which would lose trailing
+            // whitespace in a `///` comment.
+            if multiline || value.ends_with(char::is_whitespace) {
+                None
+            } else {
+                Some(value)
+            }
+        }
+        _ => None,
+    }
+}
+
+// Whether an expression used as the last argument of a call may start on the
+// same line as the call and continue on the following lines.
+fn overflows(expr: &Expr) -> bool {
+    match expr {
+        Expr::Closure(e) => overflows(&e.body),
+        Expr::Block(_) | Expr::Async(_) | Expr::Unsafe(_) | Expr::Match(_) => true,
+        _ => false,
+    }
+}
+
+enum Atom {
+    Word(String),
+    Op(String),
+    Open(Delimiter),
+    Close(Delimiter),
+    // Between two punctuation tokens that were joined in the input.
+    Glue,
+}
+
+// Multi-character operators, longest first so that they are matched greedily.
+const OPS: &[&str] = &[
+    "..=", "...", "<<=", ">>=", "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=",
+    "*=", "/=", "%=", "^=", "&=", "|=", "<<", ">>", "..",
+];
+
+fn flatten(tokens: TokenStream, atoms: &mut Vec<Atom>) {
+    let mut joint = String::new();
+    let mut lifetime = false;
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(group) => {
+                split_ops(&joint, atoms);
+                joint.clear();
+                let delimiter = group.delimiter();
+                if delimiter != Delimiter::None {
+                    atoms.push(Atom::Open(delimiter));
+                }
+                flatten(group.stream(), atoms);
+                if delimiter != Delimiter::None {
+                    atoms.push(Atom::Close(delimiter));
+                }
+            }
+            TokenTree::Ident(ident) => {
+                split_ops(&joint, atoms);
+                joint.clear();
+                if lifetime {
+                    lifetime = false;
+                    atoms.push(Atom::Word(format!("'{}", ident)));
+                } else {
+                    atoms.push(Atom::Word(ident.to_string()));
+                }
+            }
+            TokenTree::Literal(literal) => {
+                split_ops(&joint, atoms);
+                joint.clear();
+                atoms.push(Atom::Word(literal.to_string()));
+            }
+            TokenTree::Punct(punct) => {
+                if punct.as_char() == '\'' {
+                    if !joint.is_empty() {
+                        split_ops(&joint, atoms);
+                        atoms.push(Atom::Glue);
+                        joint.clear();
+                    }
+                    lifetime = true;
+                    continue;
+                }
+                joint.push(punct.as_char());
+                if punct.spacing() == Spacing::Alone {
+                    split_ops(&joint, atoms);
+                    joint.clear();
+                }
+            }
+        }
+    }
+    split_ops(&joint, atoms);
+}
+
+fn split_ops(mut joint: &str, atoms: &mut Vec<Atom>) {
+    while !joint.is_empty() {
+        let len = OPS
+            .iter()
+            .find(|op| joint.starts_with(*op))
+            .map_or(1, |op| op.len());
+        atoms.push(Atom::Op(joint[..len].to_owned()));
+        joint = &joint[len..];
+        if !joint.is_empty() {
+            atoms.push(Atom::Glue);
+        }
+    }
+}
+
+fn open(delimiter: Delimiter) -> &'static str {
+    match delimiter {
+        Delimiter::Parenthesis => "(",
+        Delimiter::Brace => "{",
+        Delimiter::Bracket => "[",
+        Delimiter::None => "",
+    }
+}
+
+fn close(delimiter: Delimiter) -> &'static str {
+    match delimiter {
+        Delimiter::Parenthesis => ")",
+        Delimiter::Brace => "}",
+        Delimiter::Bracket => "]",
+        Delimiter::None => "",
+    }
+}
+
+fn is_punct(atom: &Atom) -> bool {
+    match atom {
+        Atom::Op(_) => true,
+        _ => false,
+    }
+}
+
+fn is_op(atom: &Atom, s: &str) -> bool {
+    match atom {
+        Atom::Op(op) => op == s,
+        _ => false,
+    }
+}
+
+// Keywords after which an operator is in prefix position and a delimiter is
+// preceded by a space, as in `for &x in (a, b)`.
+const KEYWORDS: &[&str] = &[
+    "as", "box", "break", "const", "dyn", "else", "for", "if", "impl", "in", "let", "match",
+    "move", "mut", "ref", "return", "static", "unsafe", "where", "while", "yield",
+];
+
+fn is_keyword(atom: &Atom) -> bool {
+    match atom {
+        Atom::Word(word) => KEYWORDS.contains(&word.as_str()),
+        _ => false,
+    }
+}
+
+// Whether the given token leaves the next one in prefix position, as with the
+// `&` of `: &T` or the `-` of `= -1`.
+fn prefix_position(prev: &Atom) -> bool {
+    match prev {
+        Atom::Word(_) => is_keyword(prev),
+        Atom::Close(_) => false,
+        Atom::Open(_) => true,
+        Atom::Op(op) => op != ">" && op != ">>" && op != "?",
+        Atom::Glue => false,
+    }
+}
+
+fn space_between(prev: &Atom, prev_prefix: bool, cur: &Atom, next: Option<&Atom>) -> bool {
+    match (prev, cur) {
+        (Atom::Open(Delimiter::Brace), Atom::Close(_)) => return false,
+        (Atom::Open(delimiter), _) | (_, Atom::Close(delimiter)) => {
+            return *delimiter == Delimiter::Brace;
+        }
+        (Atom::Op(op), _) if op == "," || op == ";" => return true,
+        (Atom::Word(_), Atom::Open(delimiter)) => {
+            return *delimiter == Delimiter::Brace || is_keyword(prev);
+        }
+        (Atom::Close(_), Atom::Open(delimiter)) => return *delimiter == Delimiter::Brace,
+        (Atom::Op(op), Atom::Open(delimiter)) if op == ">" || op == ">>" => {
+            return *delimiter == Delimiter::Brace;
+        }
+        _ => {}
+    }
+    if let Atom::Op(op) = prev {
+        match op.as_str() {
+            "::" | "." | ".." | "..=" | "..." | "#" | "$" | "<" | "!" => return false,
+            "&" | "&&" | "*" | "-" | "?" if prev_prefix => return false,
+            _ => {}
+        }
+    }
+    if let Atom::Op(op) = cur {
+        match op.as_str() {
+            "," | ";" | ":" | "." | ">" | ">>" => return false,
+            ".." | "..=" | "..." => return is_op(prev, "=") || is_op(prev, "=>"),
+            "::" | "<" => {
+                return match prev {
+                    Atom::Word(_) => is_keyword(prev) && op == "::",
+                    Atom::Op(_) => prefix_position(prev),
+                    _ => false,
+                };
+            }
+            "?" => return prefix_position(prev),
+            "!" => {
+                let macro_call = match (prev, next) {
+                    (Atom::Word(_), Some(Atom::Open(_))) => !is_keyword(prev),
+                    _ => false,
+                };
+                return !macro_call;
+            }
+            _ => {}
+        }
+    }
+    true
+}
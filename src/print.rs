@@ -1,7 +1,12 @@
+//! Printing syntax trees as formatted Rust source code.
+//!
+//! *The functions in this module are available if Syn is built with the
+//! `"full"`, `"parsing"` and `"printing"` features.*
+
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 
-pub struct TokensOrDefault<'a, T: 'a>(pub &'a Option<T>);
+pub(crate) struct TokensOrDefault<'a, T: 'a>(pub &'a Option<T>);
 
 impl<'a, T> ToTokens for TokensOrDefault<'a, T>
 where
@@ -14,3 +19,6 @@ where
         }
     }
 }
+
+#[cfg(all(feature = "full", feature = "parsing"))]
+pub use crate::pretty::{pretty_expr, pretty_file, pretty_item};
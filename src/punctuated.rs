@@ -860,6 +860,42 @@ impl<T, P> IndexMut<usize> for Punctuated<T, P> {
     }
 }
 
+// Serialized as a sequence of the punctuated values. Deserializing fills in
+// default punctuation between them, without a trailing punctuation. Syntax
+// tree nodes serialize whether each of their punctuated fields has trailing
+// punctuation as a separate field, so that it is restored.
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+    use ::serde::de::{Deserialize, Deserializer};
+    use ::serde::ser::{Serialize, Serializer};
+
+    impl<T, P> Serialize for Punctuated<T, P>
+    where
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self)
+        }
+    }
+
+    impl<'de, T, P> Deserialize<'de> for Punctuated<T, P>
+    where
+        T: Deserialize<'de>,
+        P: Default,
+    {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Vec::<T>::deserialize(deserializer).map(Punctuated::from_iter)
+        }
+    }
+}
+
 #[cfg(feature = "printing")]
 mod printing {
     use super::*;
@@ -0,0 +1,381 @@
+//! Searching syntax trees for code of a particular shape.
+//!
+//! A [`Pattern`] is a snippet of Rust syntax in which some parts are left
+//! open as metavariables, written `$name`. The snippet is parsed by Syn's own
+//! parser for the type of node it describes, such as [`Expr`], [`Type`] or
+//! [`Pat`], and is then matched against nodes of that type anywhere in a
+//! syntax tree. Each match records which part of the tree every metavariable
+//! stood for.
+//!
+//! ```
+//! use quote::ToTokens;
+//! use syn::query::Pattern;
+//! use syn::Expr;
+//!
+//! let pattern: Pattern<Expr> = "$x.lock().unwrap()".parse().unwrap();
+//!
+//! let file = syn::parse_file(r#"
+//!     fn f(m: &Mutex<u8>) -> u8 {
+//!         *m.lock().unwrap() + *STATE.counter.lock().expect("poisoned")
+//!     }
+//! "#).unwrap();
+//!
+//! let matches = pattern.find_all(&file);
+//! assert_eq!(matches.len(), 1);
+//!
+//! let x = matches[0].get("x").unwrap();
+//! assert_eq!(x.to_token_stream().to_string(), "m");
+//! ```
+//!
+//! # Metavariables
+//!
+//! - A metavariable in the position of an expression, a type or a pattern
+//!   matches any expression, type or pattern respectively. In `$x.$f()` the
+//!   `$x` stands for an expression.
+//!
+//! - A metavariable in the position of an identifier matches any identifier.
+//!   In `$x.$f()` the `$f` stands for the name of a method.
+//!
+//! - A metavariable that appears more than once must stand for equal nodes
+//!   every time: `$x == $x` matches `a.b == a.b` but not `a == b`. As
+//!   elsewhere in the pattern, trailing punctuation is ignored, so that it
+//!   also matches `f(a,) == f(a)`.
+//!
+//! - The metavariable `$_` matches anything without recording a binding.
+//!
+//! Every other part of the pattern has to be the same as the node it is
+//! matched against, as compared by the `PartialEq` impls of the
+//! `"extra-traits"` feature. Spans and trailing punctuation are ignored.
+//! Metavariables inside the tokens of a macro invocation are not supported;
+//! they are compared as written.
+//!
+//! *This module is available if Syn is built with the `"full"`, `"parsing"`,
+//! `"printing"`, `"visit"` and `"extra-traits"` features.*
+//!
+//! [`Expr`]: crate::Expr
+//! [`Type`]: crate::Type
+//! [`Pat`]: crate::Pat
+
+use std::str::FromStr;
+
+use proc_macro2::{Group, Ident, Span, TokenStream, TokenTree};
+
+use crate::equiv::{Equivalence, Equivalent};
+use crate::error::{Error, Result};
+use crate::node::NodeRef;
+use crate::parse::Parse;
+use crate::sealed::query::Sealed;
+use crate::spanned::Spanned;
+use crate::{Expr, Pat, Type};
+
+// Metavariables are turned into identifiers with this prefix before the
+// pattern is handed to the parser.
+const METAVAR_PREFIX: &str = "__syn_query_";
+
+/// A snippet of syntax with metavariables, to be searched for in syntax
+/// trees.
+///
+/// Patterns are created by [`Pattern::new`] from tokens, or by parsing a
+/// string with `str::parse`.
+#[derive(Clone, Debug)]
+pub struct Pattern<T> {
+    tree: T,
+}
+
+impl<T: Parse + Searchable> Pattern<T> {
+    /// Parses a pattern from tokens in which metavariables are written as a
+    /// `$` followed by an identifier.
+    pub fn new(tokens: TokenStream) -> Result<Self> {
+        let tree = crate::parse2(replace_metavars(tokens)?)?;
+        Ok(Pattern { tree })
+    }
+}
+
+impl<T: Parse + Searchable> FromStr for Pattern<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Pattern::new(TokenStream::from_str(s)?)
+    }
+}
+
+impl<T: Searchable> Pattern<T> {
+    /// Matches the pattern against the given node itself, but not against
+    /// its descendants.
+    pub fn matches<'a>(&self, node: &'a T) -> Option<Match<'a>> {
+        let mut matcher = Matcher {
+            bindings: Vec::new(),
+        };
+        if T::matches(&self.tree, node, &mut matcher) {
+            Some(Match {
+                node: node.node_ref(),
+                bindings: matcher.bindings,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Finds every node within `root`, including `root` itself, that matches
+    /// the pattern.
+    ///
+    /// Matches are returned in the order of the nodes in the tree, with
+    /// matches that contain other matches coming before them.
+    pub fn find_all<'a, N>(&self, root: &'a N) -> Vec<Match<'a>>
+    where
+        T: 'a,
+        N: Searchable + ?Sized,
+    {
+        let mut matches = Vec::new();
+        let mut stack = vec![root.node_ref()];
+        while let Some(node) = stack.pop() {
+            if let Some(m) = T::downcast(node).and_then(|node| self.matches(node)) {
+                matches.push(m);
+            }
+            stack.extend(node.children().rev());
+        }
+        matches
+    }
+}
+
+/// A node that matches a [`Pattern`], along with the bindings of the
+/// pattern's metavariables.
+#[derive(Clone, Debug)]
+pub struct Match<'a> {
+    node: NodeRef<'a>,
+    bindings: Vec<Binding<'a>>,
+}
+
+impl<'a> Match<'a> {
+    /// The node that matches the pattern.
+    pub fn node(&self) -> NodeRef<'a> {
+        self.node
+    }
+
+    /// The span of the node that matches the pattern.
+    pub fn span(&self) -> Span {
+        self.node.span()
+    }
+
+    /// The node that the metavariable `$name` stands for, if the pattern has
+    /// a metavariable by that name.
+    pub fn get(&self, name: &str) -> Option<NodeRef<'a>> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.name == name)
+            .map(|binding| binding.node)
+    }
+
+    /// The bindings of all metavariables, in the order in which they first
+    /// appear in the pattern.
+    pub fn bindings(&self) -> &[Binding<'a>] {
+        &self.bindings
+    }
+}
+
+/// The node that a metavariable stands for in a [`Match`].
+#[derive(Clone, Debug)]
+pub struct Binding<'a> {
+    name: String,
+    node: NodeRef<'a>,
+}
+
+impl<'a> Binding<'a> {
+    /// The name of the metavariable, without the `$`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The node that the metavariable stands for.
+    pub fn node(&self) -> NodeRef<'a> {
+        self.node
+    }
+
+    /// The span of the node that the metavariable stands for.
+    pub fn span(&self) -> Span {
+        self.node.span()
+    }
+}
+
+/// Syntax tree types that a [`Pattern`] can describe and that can be searched
+/// for matches.
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+pub trait Searchable: Sealed {
+    // Not public API.
+    #[doc(hidden)]
+    fn downcast(node: NodeRef<'_>) -> Option<&Self>;
+
+    // Not public API.
+    #[doc(hidden)]
+    fn node_ref(&self) -> NodeRef<'_>;
+
+    // Not public API.
+    #[doc(hidden)]
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool;
+}
+
+// Not public API.
+#[doc(hidden)]
+pub struct Matcher<'a> {
+    bindings: Vec<Binding<'a>>,
+}
+
+impl<'a> Matcher<'a> {
+    pub fn option<T: Searchable + ?Sized>(
+        &mut self,
+        pattern: Option<&T>,
+        node: Option<&'a T>,
+    ) -> bool {
+        match (pattern, node) {
+            (Some(pattern), Some(node)) => T::matches(pattern, node, self),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    pub fn list<'p, T, P, N>(&mut self, pattern: P, node: N) -> bool
+    where
+        T: Searchable + 'p + 'a,
+        P: IntoIterator<Item = &'p T>,
+        N: IntoIterator<Item = &'a T>,
+    {
+        let mut pattern = pattern.into_iter();
+        let mut node = node.into_iter();
+        loop {
+            match (pattern.next(), node.next()) {
+                (Some(pattern), Some(node)) => {
+                    if !T::matches(pattern, node, self) {
+                        return false;
+                    }
+                }
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+
+    pub fn bind<T: Searchable + Equivalent>(&mut self, name: String, node: &'a T) -> bool {
+        if name == "_" {
+            return true;
+        }
+        match self.bindings.iter().find(|binding| binding.name == name) {
+            Some(binding) => T::downcast(binding.node).map_or(false, |bound| {
+                Equivalence::new()
+                    .ignore_trailing_punct(true)
+                    .equal(bound, node)
+            }),
+            None => {
+                self.bindings.push(Binding {
+                    name,
+                    node: node.node_ref(),
+                });
+                true
+            }
+        }
+    }
+}
+
+fn replace_metavars(tokens: TokenStream) -> Result<TokenStream> {
+    let mut replaced = TokenStream::new();
+    let mut tokens = tokens.into_iter();
+    while let Some(token) = tokens.next() {
+        let token = match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == '$' => match tokens.next() {
+                Some(TokenTree::Ident(name)) => {
+                    let placeholder = format!("{}{}", METAVAR_PREFIX, name);
+                    TokenTree::Ident(Ident::new(&placeholder, name.span()))
+                }
+                _ => {
+                    return Err(Error::new(
+                        punct.span(),
+                        "expected the name of a metavariable after `$`",
+                    ))
+                }
+            },
+            TokenTree::Group(group) => {
+                let stream = replace_metavars(group.stream())?;
+                let mut replaced = Group::new(group.delimiter(), stream);
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            }
+            token => token,
+        };
+        replaced.extend(Some(token));
+    }
+    Ok(replaced)
+}
+
+fn metavar(ident: &Ident) -> Option<String> {
+    let ident = ident.to_string();
+    if ident.starts_with(METAVAR_PREFIX) {
+        Some(ident[METAVAR_PREFIX.len()..].to_owned())
+    } else {
+        None
+    }
+}
+
+// A path that consists of nothing but a metavariable.
+fn path_metavar(path: &crate::Path) -> Option<String> {
+    if path.leading_colon.is_some() || path.segments.len() != 1 {
+        return None;
+    }
+    let segment = &path.segments[0];
+    if segment.arguments.is_empty() {
+        metavar(&segment.ident)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn expr_metavar(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(expr) if expr.attrs.is_empty() && expr.qself.is_none() => {
+            path_metavar(&expr.path)
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn type_metavar(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() => path_metavar(&ty.path),
+        _ => None,
+    }
+}
+
+pub(crate) fn pat_metavar(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(pat)
+            if pat.attrs.is_empty()
+                && pat.by_ref.is_none()
+                && pat.mutability.is_none()
+                && pat.subpat.is_none() =>
+        {
+            metavar(&pat.ident)
+        }
+        _ => None,
+    }
+}
+
+impl Sealed for Ident {}
+
+impl Searchable for Ident {
+    fn downcast(node: NodeRef<'_>) -> Option<&Self> {
+        match node {
+            NodeRef::Ident(ident) => Some(ident),
+            _ => None,
+        }
+    }
+
+    fn node_ref(&self) -> NodeRef<'_> {
+        NodeRef::Ident(self)
+    }
+
+    fn matches<'a>(pattern: &Self, node: &'a Self, matcher: &mut Matcher<'a>) -> bool {
+        match metavar(pattern) {
+            Some(name) => matcher.bind(name, node),
+            None => pattern == node,
+        }
+    }
+}
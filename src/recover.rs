@@ -0,0 +1,280 @@
+use std::iter::FromIterator;
+
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+
+use crate::buffer::Cursor;
+use crate::error::Error;
+use crate::expr;
+use crate::parse::discouraged::Speculative;
+use crate::parse::{self, Parse, ParseStream, Parser};
+use crate::stmt::parsing::parse_stmt;
+use crate::{Attribute, File, ImplItem, Item, Stmt};
+
+// Every syntax error encountered so far, in the order they were found.
+type Errors = Vec<Error>;
+
+pub fn parse_file(shebang: Option<String>, content: &str) -> (File, Option<Error>) {
+    let mut errors = Errors::new();
+    let tokens = match content.parse::<TokenStream>() {
+        Ok(tokens) => tokens,
+        Err(err) => {
+            let file = File {
+                shebang,
+                attrs: Vec::new(),
+                items: Vec::new(),
+            };
+            return (file, Some(Error::from(err)));
+        }
+    };
+
+    let (attrs, items) = parse_recovering(tokens, &mut errors, items);
+    let file = File {
+        shebang,
+        attrs,
+        items,
+    };
+    (file, combine(errors))
+}
+
+fn combine(errors: Errors) -> Option<Error> {
+    let mut errors = errors.into_iter();
+    let mut first = errors.next()?;
+    for error in errors {
+        first.combine(error);
+    }
+    Some(first)
+}
+
+// Runs a recovering parser over the contents of a file or brace-delimited
+// body, starting with any inner attributes.
+fn parse_recovering<T>(
+    tokens: TokenStream,
+    errors: &mut Errors,
+    parser: fn(ParseStream, &mut Errors) -> Vec<T>,
+) -> (Vec<Attribute>, Vec<T>) {
+    let mut result = None;
+    let outcome = (|input: ParseStream| {
+        let attrs = speculate(input, errors, |fork| fork.call(Attribute::parse_inner))
+            .unwrap_or_default();
+        result = Some((attrs, parser(input, errors)));
+        Ok(())
+    })
+    .parse2(tokens);
+    if let Err(err) = outcome {
+        errors.push(err);
+    }
+    result.unwrap_or_default()
+}
+
+// Parses on a fork and commits the fork only if the parse succeeded without
+// leaving unconsumed tokens behind in any nested group.
+fn speculate<T>(
+    input: ParseStream,
+    errors: &mut Errors,
+    parser: fn(ParseStream) -> parse::Result<T>,
+) -> Option<T> {
+    let fork = input.fork();
+    let result = parser(&fork).and_then(|node| match parse::get_unexpected(&fork).get() {
+        Some(span) => Err(Error::new(span, "unexpected token")),
+        None => Ok(node),
+    });
+    match result {
+        Ok(node) => {
+            input.advance_to(&fork);
+            Some(node)
+        }
+        Err(err) => {
+            errors.push(err);
+            None
+        }
+    }
+}
+
+fn items(input: ParseStream, errors: &mut Errors) -> Vec<Item> {
+    let mut items = Vec::new();
+    while !input.is_empty() {
+        let mut attempt = Errors::new();
+        if let Some(item) = speculate(input, &mut attempt, Item::parse) {
+            items.push(item);
+            continue;
+        }
+        let tokens = skip_to_boundary(input);
+        let item = recover_item(tokens.clone(), &mut attempt).unwrap_or(Item::Verbatim(tokens));
+        errors.extend(attempt);
+        items.push(item);
+    }
+    items
+}
+
+fn impl_items(input: ParseStream, errors: &mut Errors) -> Vec<ImplItem> {
+    let mut items = Vec::new();
+    while !input.is_empty() {
+        let mut attempt = Errors::new();
+        if let Some(item) = speculate(input, &mut attempt, ImplItem::parse) {
+            items.push(item);
+            continue;
+        }
+        let tokens = skip_to_boundary(input);
+        let item = recover_impl_item(tokens.clone(), &mut attempt)
+            .unwrap_or(ImplItem::Verbatim(tokens));
+        errors.extend(attempt);
+        items.push(item);
+    }
+    items
+}
+
+fn stmts(input: ParseStream, errors: &mut Errors) -> Vec<Stmt> {
+    let mut stmts = Vec::new();
+    loop {
+        while input.peek(Token![;]) {
+            let _ = input.parse::<Token![;]>();
+        }
+        if input.is_empty() {
+            break;
+        }
+        let mut attempt = Errors::new();
+        match speculate(input, &mut attempt, |fork| parse_stmt(fork, true)) {
+            Some(stmt) => {
+                let requires_semicolon = match &stmt {
+                    Stmt::Expr(expr) => expr::requires_terminator(expr),
+                    _ => false,
+                };
+                stmts.push(stmt);
+                if requires_semicolon && !input.is_empty() {
+                    errors.push(input.error("unexpected token"));
+                }
+            }
+            None => {
+                let tokens = skip_to_boundary(input);
+                let item = recover_item(tokens.clone(), &mut attempt)
+                    .unwrap_or(Item::Verbatim(tokens));
+                errors.extend(attempt);
+                stmts.push(Stmt::Item(item));
+            }
+        }
+    }
+    stmts
+}
+
+// Consumes tokens up to and including the next `;` or brace-delimited group,
+// or up to but not including the start of the next attribute. Attributes at
+// the start are consumed whole, since they belong to the item being skipped.
+fn skip_to_boundary(input: ParseStream) -> TokenStream {
+    input
+        .step(|cursor| {
+            let mut rest = *cursor;
+            let mut skipped = Vec::new();
+            let mut in_attrs = true;
+            while let Some((tt, next)) = rest.token_tree() {
+                if let Some(after) = skip_attr(rest) {
+                    if !in_attrs {
+                        break;
+                    }
+                    while let Some((tt, next)) = rest.token_tree() {
+                        if rest == after {
+                            break;
+                        }
+                        skipped.push(tt);
+                        rest = next;
+                    }
+                    continue;
+                }
+                in_attrs = false;
+                let done = match &tt {
+                    TokenTree::Punct(punct) => punct.as_char() == ';',
+                    TokenTree::Group(group) => group.delimiter() == Delimiter::Brace,
+                    _ => false,
+                };
+                skipped.push(tt);
+                rest = next;
+                if done {
+                    break;
+                }
+            }
+            Ok((TokenStream::from_iter(skipped), rest))
+        })
+        .unwrap()
+}
+
+// Returns the cursor past an outer or inner attribute starting at the cursor.
+fn skip_attr(cursor: Cursor) -> Option<Cursor> {
+    let (pound, rest) = cursor.punct()?;
+    if pound.as_char() != '#' {
+        return None;
+    }
+    let rest = match rest.punct() {
+        Some((bang, rest)) if bang.as_char() == '!' => rest,
+        _ => rest,
+    };
+    let (_content, _span, rest) = rest.group(Delimiter::Bracket)?;
+    Some(rest)
+}
+
+// Splits off a trailing brace-delimited body, returning the preceding tokens
+// followed by an empty body in its place.
+fn split_body(tokens: TokenStream) -> Option<(TokenStream, Group)> {
+    let mut tokens = Vec::from_iter(tokens);
+    let body = match tokens.pop() {
+        Some(TokenTree::Group(group)) => group,
+        _ => return None,
+    };
+    if body.delimiter() != Delimiter::Brace {
+        return None;
+    }
+    let mut empty = Group::new(Delimiter::Brace, TokenStream::new());
+    empty.set_span(body.span());
+    tokens.push(TokenTree::Group(empty));
+    Some((TokenStream::from_iter(tokens), body))
+}
+
+// If the item's signature is well formed and only its body contains errors,
+// parses it again with recovery applied inside of the body.
+fn recover_item(tokens: TokenStream, errors: &mut Errors) -> Option<Item> {
+    let (head, body) = split_body(tokens)?;
+    let mut item: Item = crate::parse2(head).ok()?;
+    let mut inner = Errors::new();
+    match &mut item {
+        Item::Fn(item) => {
+            let (attrs, stmts) = parse_recovering(body.stream(), &mut inner, stmts);
+            item.attrs.extend(attrs);
+            item.block.stmts = stmts;
+        }
+        Item::Mod(item) => {
+            let (attrs, items) = parse_recovering(body.stream(), &mut inner, items);
+            item.attrs.extend(attrs);
+            item.content.as_mut()?.1 = items;
+        }
+        Item::Impl(item) => {
+            let (attrs, items) = parse_recovering(body.stream(), &mut inner, impl_items);
+            item.attrs.extend(attrs);
+            item.items = items;
+        }
+        _ => return None,
+    }
+    // Errors within the body are more precise than the error from the first
+    // attempt, but keep the original if recovery did not reproduce it.
+    if !inner.is_empty() {
+        errors.clear();
+        errors.extend(inner);
+    }
+    Some(item)
+}
+
+fn recover_impl_item(tokens: TokenStream, errors: &mut Errors) -> Option<ImplItem> {
+    let (head, body) = split_body(tokens)?;
+    let mut item: ImplItem = crate::parse2(head).ok()?;
+    let mut inner = Errors::new();
+    match &mut item {
+        ImplItem::Method(item) => {
+            let (attrs, stmts) = parse_recovering(body.stream(), &mut inner, stmts);
+            item.attrs.extend(attrs);
+            item.block.stmts = stmts;
+        }
+        _ => return None,
+    }
+    if !inner.is_empty() {
+        errors.clear();
+        errors.extend(inner);
+    }
+    Some(item)
+}
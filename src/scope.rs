@@ -0,0 +1,626 @@
+//! Resolution of local variables to the bindings that introduce them.
+//!
+//! Code that rewrites function bodies, like a procedural macro that instruments
+//! every use of a variable, cannot tell by name alone whether two identifiers
+//! refer to the same variable: a `let` may shadow an earlier binding of the
+//! same name, and a closure parameter or match arm may introduce a new one
+//! for just part of the body. The [`resolve`] function walks a function body
+//! or other piece of code, tracking the bindings that are in scope at each
+//! point, and links every use of a local variable to the [`PatIdent`] that
+//! introduces it.
+//!
+//! ```
+//! use syn::scope;
+//! use syn::visit_mut::VisitMut;
+//! use syn::{parse_quote, Ident, ItemFn};
+//!
+//! let mut f: ItemFn = parse_quote! {
+//!     fn f(x: u8) -> u8 {
+//!         let y = x + 1;
+//!         let x = y * 2;
+//!         x
+//!     }
+//! };
+//!
+//! let resolution = scope::resolve(&f);
+//! let param = resolution.bindings().next().unwrap();
+//! assert_eq!(param.ident, "x");
+//! assert_eq!(resolution.uses_of(param).count(), 1);
+//!
+//! let new_name: Ident = parse_quote!(input);
+//! let mut rename = resolution.rename_binding(param, new_name).unwrap();
+//! rename.visit_item_fn_mut(&mut f);
+//!
+//! let expected: ItemFn = parse_quote! {
+//!     fn f(input: u8) -> u8 {
+//!         let y = input + 1;
+//!         let x = y * 2;
+//!         x
+//!     }
+//! };
+//! assert_eq!(f, expected);
+//! ```
+//!
+//! # Scopes
+//!
+//! The bindings introduced by a `let` statement are in scope for the rest of
+//! the enclosing block, but not in the statement's own initializer. Function
+//! and closure parameters are in scope in the body, the bindings of a match
+//! arm in its guard and body, those of `if let` and `while let` in the
+//! condition and the block that follows it, and those of a `for` loop in the
+//! loop body. An item nested inside of a block cannot refer to the local
+//! variables around it.
+//!
+//! A use of a local variable is an expression consisting of a path with a
+//! single segment, like `x`. Paths with more than one segment always refer to
+//! items rather than local variables and are not considered. Every
+//! identifier pattern is treated as introducing a binding, even one that
+//! names a unit struct or constant, as a procedural macro has no way to tell
+//! these apart.
+//!
+//! The bindings of the same name in the alternatives of an or-pattern, like
+//! `x` in `A(x) | B(x)`, are a single binding that the alternatives share.
+//!
+//! Variables used only inside the tokens of a macro invocation, such as the
+//! arguments of `println!`, are not resolved. A binding whose name appears in
+//! a macro invocation within its scope cannot be renamed.
+//!
+//! *This module is available if Syn is built with the `"full"`, `"visit"` and
+//! `"visit-mut"` features.*
+//!
+//! [`PatIdent`]: crate::PatIdent
+
+use std::collections::HashMap;
+use std::mem;
+use std::ptr;
+
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+
+use crate::error::{Error, Result};
+use crate::sealed::scope::Sealed;
+use crate::visit::{self, Visit};
+use crate::visit_mut::{self, VisitMut};
+use crate::{
+    Arm, Block, Expr, ExprClosure, ExprForLoop, ExprIf, ExprLet, ExprMatch, ExprPath, ExprWhile,
+    FieldPat, FieldValue, File, ForeignItem, ImplItem, ImplItemMethod, Item, ItemFn, Local, Macro,
+    PatIdent, PatOr, Stmt, TraitItem, TraitItemMethod,
+};
+
+/// Resolves every use of a local variable within `node` to the binding that
+/// introduces it.
+pub fn resolve<T: Resolve + ?Sized>(node: &T) -> Resolution<'_> {
+    let mut numbering = Numbering::default();
+    node.visit(&mut numbering);
+
+    let mut resolver = Resolver {
+        numbering,
+        visible: Vec::new(),
+        scopes: Vec::new(),
+        alternatives: None,
+        resolution: Resolution {
+            bindings: Vec::new(),
+            uses: Vec::new(),
+            macros: Vec::new(),
+        },
+    };
+    node.visit(&mut resolver);
+    resolver.resolution
+}
+
+/// Syntax tree nodes that scope resolution can start from.
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+pub trait Resolve: Sealed {
+    // Not public API.
+    #[doc(hidden)]
+    fn visit<'ast, V: Visit<'ast>>(&'ast self, visitor: &mut V);
+
+    // Not public API.
+    #[doc(hidden)]
+    fn visit_mut<V: VisitMut>(&mut self, visitor: &mut V);
+}
+
+macro_rules! resolve {
+    ($($ty:ident: $visit:ident $visit_mut:ident,)*) => {
+        $(
+            impl Sealed for $ty {}
+
+            impl Resolve for $ty {
+                fn visit<'ast, V: Visit<'ast>>(&'ast self, visitor: &mut V) {
+                    visitor.$visit(self);
+                }
+
+                fn visit_mut<V: VisitMut>(&mut self, visitor: &mut V) {
+                    visitor.$visit_mut(self);
+                }
+            }
+        )*
+    };
+}
+
+resolve! {
+    Block: visit_block visit_block_mut,
+    Expr: visit_expr visit_expr_mut,
+    ExprClosure: visit_expr_closure visit_expr_closure_mut,
+    ExprMatch: visit_expr_match visit_expr_match_mut,
+    File: visit_file visit_file_mut,
+    ImplItemMethod: visit_impl_item_method visit_impl_item_method_mut,
+    Item: visit_item visit_item_mut,
+    ItemFn: visit_item_fn visit_item_fn_mut,
+    Stmt: visit_stmt visit_stmt_mut,
+    TraitItemMethod: visit_trait_item_method visit_trait_item_method_mut,
+}
+
+/// The bindings and uses of local variables in a syntax tree, as computed by
+/// [`resolve`].
+pub struct Resolution<'ast> {
+    bindings: Vec<BindingInfo<'ast>>,
+    uses: Vec<UseInfo<'ast>>,
+    macros: Vec<MacroInfo>,
+}
+
+struct BindingInfo<'ast> {
+    // One identifier pattern for each alternative of an or-pattern that
+    // introduces the binding, first one first.
+    pats: Vec<&'ast PatIdent>,
+    name: String,
+    ordinals: Vec<usize>,
+}
+
+struct UseInfo<'ast> {
+    expr: &'ast ExprPath,
+    name: String,
+    ordinal: usize,
+    binding: Option<usize>,
+    // Indices of every binding in scope at the use, innermost last.
+    visible: Vec<usize>,
+}
+
+struct MacroInfo {
+    idents: Vec<Ident>,
+    // Indices of every binding in scope at the macro invocation.
+    visible: Vec<usize>,
+}
+
+impl<'ast> Resolution<'ast> {
+    /// Every binding in the tree, in the order in which they occur.
+    ///
+    /// A binding introduced by every alternative of an or-pattern is
+    /// represented by its identifier in the first alternative.
+    pub fn bindings<'a>(&'a self) -> impl Iterator<Item = &'ast PatIdent> + 'a {
+        self.bindings.iter().map(|binding| binding.pats[0])
+    }
+
+    /// The binding that a use of a local variable refers to.
+    ///
+    /// Returns `None` if the path does not refer to a binding within the
+    /// resolved tree, for example because it names a function, a constant or
+    /// a variable from outside of the tree.
+    pub fn binding_of(&self, expr: &ExprPath) -> Option<&'ast PatIdent> {
+        let use_ = self.uses.iter().find(|use_| ptr::eq(use_.expr, expr))?;
+        use_.binding.map(|binding| self.bindings[binding].pats[0])
+    }
+
+    /// All uses of the given binding, in the order in which they occur.
+    pub fn uses_of<'a>(&'a self, binding: &PatIdent) -> impl Iterator<Item = &'ast ExprPath> + 'a {
+        let index = self.index_of(binding);
+        self.uses
+            .iter()
+            .filter(move |use_| index.is_some() && use_.binding == index)
+            .map(|use_| use_.expr)
+    }
+
+    /// All single-segment paths in the tree that do not refer to a binding
+    /// within it.
+    pub fn unresolved<'a>(&'a self) -> impl Iterator<Item = &'ast ExprPath> + 'a {
+        self.uses
+            .iter()
+            .filter(|use_| use_.binding.is_none())
+            .map(|use_| use_.expr)
+    }
+
+    /// Prepares to rename a binding along with all of its uses.
+    ///
+    /// The returned [`RenameBinding`] must be applied to the same tree that
+    /// was resolved, starting from the same node, by calling the
+    /// corresponding method of `VisitMut` or [`RenameBinding::apply`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if renaming would change the meaning of the code: if a use of
+    /// the binding would instead refer to a different binding of the new
+    /// name, or if a use of a variable by the new name would instead refer to
+    /// the renamed binding. Also fails if the old or the new name appears in
+    /// a macro invocation where the binding is in scope, as the tokens of
+    /// the macro may refer to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `binding` is not part of the resolved tree.
+    pub fn rename_binding(&self, binding: &PatIdent, new_name: Ident) -> Result<RenameBinding> {
+        let index = match self.index_of(binding) {
+            Some(index) => index,
+            None => panic!("binding is not part of the resolved syntax tree"),
+        };
+        let old_name = &self.bindings[index].name;
+        let new_str = new_name.to_string();
+        let conflict = |span: Span| {
+            Error::new(
+                span,
+                format!(
+                    "renaming `{}` to `{}` would change the variable this refers to",
+                    old_name, new_str,
+                ),
+            )
+        };
+
+        let mut uses = Vec::new();
+        for use_ in &self.uses {
+            if use_.binding == Some(index) {
+                // A binding of the new name between the renamed binding and
+                // the use would capture it.
+                let captured = use_
+                    .visible
+                    .iter()
+                    .any(|&visible| visible > index && self.bindings[visible].name == new_str);
+                if captured {
+                    return Err(conflict(use_.expr.path.segments[0].ident.span()));
+                }
+                uses.push(use_.ordinal);
+            } else if use_.name == new_str && use_.visible.contains(&index) {
+                // The renamed binding would shadow whatever the use refers to
+                // now.
+                let shadowed = match use_.binding {
+                    Some(binding) => binding < index,
+                    None => true,
+                };
+                if shadowed {
+                    return Err(conflict(use_.expr.path.segments[0].ident.span()));
+                }
+            }
+        }
+
+        for mac in &self.macros {
+            if !mac.visible.contains(&index) {
+                continue;
+            }
+            for ident in &mac.idents {
+                if ident == old_name || ident == &new_str {
+                    return Err(Error::new(
+                        ident.span(),
+                        format!(
+                            "renaming `{}` to `{}` may change the variable this macro refers to",
+                            old_name, new_str,
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(RenameBinding {
+            new_name,
+            bindings: self.bindings[index].ordinals.clone(),
+            uses,
+            next_binding: 0,
+            next_use: 0,
+        })
+    }
+
+    fn index_of(&self, binding: &PatIdent) -> Option<usize> {
+        self.bindings
+            .iter()
+            .position(|info| info.pats.iter().any(|&pat| ptr::eq(pat, binding)))
+    }
+}
+
+/// A `VisitMut` that renames one binding and all of its uses, created by
+/// [`Resolution::rename_binding`].
+///
+/// Struct patterns and expressions written in shorthand, like `S { x }`, are
+/// expanded to `S { x: renamed }` so that the field name stays the same.
+pub struct RenameBinding {
+    new_name: Ident,
+    bindings: Vec<usize>,
+    uses: Vec<usize>,
+    next_binding: usize,
+    next_use: usize,
+}
+
+impl RenameBinding {
+    /// Performs the renaming on the tree that was resolved.
+    pub fn apply<T: Resolve + ?Sized>(mut self, node: &mut T) {
+        node.visit_mut(&mut self);
+    }
+
+    fn renamed(&self, old: &Ident) -> Ident {
+        let mut new_name = self.new_name.clone();
+        new_name.set_span(old.span());
+        new_name
+    }
+}
+
+impl VisitMut for RenameBinding {
+    fn visit_pat_ident_mut(&mut self, node: &mut PatIdent) {
+        if self.bindings.contains(&self.next_binding) {
+            node.ident = self.renamed(&node.ident);
+        }
+        self.next_binding += 1;
+        visit_mut::visit_pat_ident_mut(self, node);
+    }
+
+    fn visit_expr_path_mut(&mut self, node: &mut ExprPath) {
+        if self.uses.contains(&self.next_use) {
+            let segment = &mut node.path.segments[0];
+            segment.ident = self.renamed(&segment.ident);
+        }
+        self.next_use += 1;
+        visit_mut::visit_expr_path_mut(self, node);
+    }
+
+    fn visit_field_pat_mut(&mut self, node: &mut FieldPat) {
+        let first_binding = self.next_binding;
+        visit_mut::visit_field_pat_mut(self, node);
+        if node.colon_token.is_none() && self.bindings.contains(&first_binding) {
+            node.colon_token = Some(Token![:](self.new_name.span()));
+        }
+    }
+
+    fn visit_field_value_mut(&mut self, node: &mut FieldValue) {
+        let first_use = self.next_use;
+        visit_mut::visit_field_value_mut(self, node);
+        if node.colon_token.is_none() && self.uses.contains(&first_use) {
+            node.colon_token = Some(Token![:](self.new_name.span()));
+        }
+    }
+}
+
+// Numbers the identifier patterns and path expressions of a tree in the order
+// in which VisitMut visits them, which RenameBinding relies on to find them
+// again.
+#[derive(Default)]
+struct Numbering {
+    bindings: HashMap<*const PatIdent, usize>,
+    uses: HashMap<*const ExprPath, usize>,
+}
+
+impl<'ast> Visit<'ast> for Numbering {
+    fn visit_pat_ident(&mut self, node: &'ast PatIdent) {
+        let ordinal = self.bindings.len();
+        self.bindings.insert(node, ordinal);
+        visit::visit_pat_ident(self, node);
+    }
+
+    fn visit_expr_path(&mut self, node: &'ast ExprPath) {
+        let ordinal = self.uses.len();
+        self.uses.insert(node, ordinal);
+        visit::visit_expr_path(self, node);
+    }
+}
+
+struct Resolver<'ast> {
+    numbering: Numbering,
+    // Indices of the bindings in scope, innermost last.
+    visible: Vec<usize>,
+    // Length of `visible` at the start of each enclosing scope.
+    scopes: Vec<usize>,
+    // Indices of the bindings introduced by the first alternative of the
+    // or-pattern being visited, while visiting the other alternatives.
+    alternatives: Option<Vec<usize>>,
+    resolution: Resolution<'ast>,
+}
+
+impl Resolver<'_> {
+    fn scoped<F: FnOnce(&mut Self)>(&mut self, f: F) {
+        self.scopes.push(self.visible.len());
+        f(self);
+        let len = self.scopes.pop().unwrap();
+        self.visible.truncate(len);
+    }
+
+    // Nested items do not see the local variables around them.
+    fn isolated<F: FnOnce(&mut Self)>(&mut self, f: F) {
+        let visible = mem::replace(&mut self.visible, Vec::new());
+        let scopes = mem::replace(&mut self.scopes, Vec::new());
+        f(self);
+        self.visible = visible;
+        self.scopes = scopes;
+    }
+}
+
+impl<'ast> Visit<'ast> for Resolver<'ast> {
+    fn visit_block(&mut self, node: &'ast Block) {
+        self.scoped(|v| visit::visit_block(v, node));
+    }
+
+    fn visit_local(&mut self, node: &'ast Local) {
+        for attr in &node.attrs {
+            self.visit_attribute(attr);
+        }
+        if let Some((_, init)) = &node.init {
+            self.visit_expr(init);
+        }
+        self.visit_pat(&node.pat);
+    }
+
+    fn visit_expr_let(&mut self, node: &'ast ExprLet) {
+        for attr in &node.attrs {
+            self.visit_attribute(attr);
+        }
+        self.visit_expr(&node.expr);
+        self.visit_pat(&node.pat);
+    }
+
+    fn visit_expr_if(&mut self, node: &'ast ExprIf) {
+        for attr in &node.attrs {
+            self.visit_attribute(attr);
+        }
+        self.scoped(|v| {
+            v.visit_expr(&node.cond);
+            v.visit_block(&node.then_branch);
+        });
+        if let Some((_, else_branch)) = &node.else_branch {
+            self.visit_expr(else_branch);
+        }
+    }
+
+    fn visit_expr_while(&mut self, node: &'ast ExprWhile) {
+        self.scoped(|v| visit::visit_expr_while(v, node));
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'ast ExprForLoop) {
+        for attr in &node.attrs {
+            self.visit_attribute(attr);
+        }
+        if let Some(label) = &node.label {
+            self.visit_label(label);
+        }
+        self.visit_expr(&node.expr);
+        self.scoped(|v| {
+            v.visit_pat(&node.pat);
+            v.visit_block(&node.body);
+        });
+    }
+
+    fn visit_expr_closure(&mut self, node: &'ast ExprClosure) {
+        self.scoped(|v| visit::visit_expr_closure(v, node));
+    }
+
+    fn visit_arm(&mut self, node: &'ast Arm) {
+        self.scoped(|v| visit::visit_arm(v, node));
+    }
+
+    fn visit_item(&mut self, node: &'ast Item) {
+        // A macro in statement position may expand to statements that use
+        // the local variables around it.
+        if let Item::Macro(_) = node {
+            visit::visit_item(self, node);
+        } else {
+            self.isolated(|v| visit::visit_item(v, node));
+        }
+    }
+
+    fn visit_impl_item(&mut self, node: &'ast ImplItem) {
+        self.isolated(|v| visit::visit_impl_item(v, node));
+    }
+
+    fn visit_trait_item(&mut self, node: &'ast TraitItem) {
+        self.isolated(|v| visit::visit_trait_item(v, node));
+    }
+
+    fn visit_foreign_item(&mut self, node: &'ast ForeignItem) {
+        self.isolated(|v| visit::visit_foreign_item(v, node));
+    }
+
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.scoped(|v| visit::visit_item_fn(v, node));
+    }
+
+    fn visit_impl_item_method(&mut self, node: &'ast ImplItemMethod) {
+        self.scoped(|v| visit::visit_impl_item_method(v, node));
+    }
+
+    fn visit_trait_item_method(&mut self, node: &'ast TraitItemMethod) {
+        self.scoped(|v| visit::visit_trait_item_method(v, node));
+    }
+
+    fn visit_pat_or(&mut self, node: &'ast PatOr) {
+        if self.alternatives.is_some() {
+            visit::visit_pat_or(self, node);
+            return;
+        }
+        for attr in &node.attrs {
+            self.visit_attribute(attr);
+        }
+        let mut cases = node.cases.iter();
+        let first = self.resolution.bindings.len();
+        if let Some(case) = cases.next() {
+            self.visit_pat(case);
+        }
+        self.alternatives = Some((first..self.resolution.bindings.len()).collect());
+        for case in cases {
+            self.visit_pat(case);
+        }
+        self.alternatives = None;
+    }
+
+    fn visit_pat_ident(&mut self, node: &'ast PatIdent) {
+        let name = node.ident.to_string();
+        let ordinal = self.numbering.bindings[&(node as *const PatIdent)];
+        let bindings = &self.resolution.bindings;
+        let alternative = self.alternatives.as_ref().and_then(|alternatives| {
+            alternatives
+                .iter()
+                .cloned()
+                .find(|&index| bindings[index].name == name)
+        });
+        if let Some(index) = alternative {
+            let binding = &mut self.resolution.bindings[index];
+            binding.pats.push(node);
+            binding.ordinals.push(ordinal);
+        } else {
+            let index = self.resolution.bindings.len();
+            self.resolution.bindings.push(BindingInfo {
+                pats: vec![node],
+                name,
+                ordinals: vec![ordinal],
+            });
+            self.visible.push(index);
+        }
+        visit::visit_pat_ident(self, node);
+    }
+
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        let mut idents = Vec::new();
+        tokens_idents(node.tokens.clone(), &mut idents);
+        self.resolution.macros.push(MacroInfo {
+            idents,
+            visible: self.visible.clone(),
+        });
+        visit::visit_macro(self, node);
+    }
+
+    fn visit_expr_path(&mut self, node: &'ast ExprPath) {
+        if let Some(ident) = local_variable(node) {
+            let name = ident.to_string();
+            let bindings = &self.resolution.bindings;
+            let binding = self
+                .visible
+                .iter()
+                .rev()
+                .cloned()
+                .find(|&index| bindings[index].name == name);
+            self.resolution.uses.push(UseInfo {
+                expr: node,
+                name,
+                ordinal: self.numbering.uses[&(node as *const ExprPath)],
+                binding,
+                visible: self.visible.clone(),
+            });
+        }
+        visit::visit_expr_path(self, node);
+    }
+}
+
+fn tokens_idents(tokens: TokenStream, idents: &mut Vec<Ident>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Ident(ident) => idents.push(ident),
+            TokenTree::Group(group) => tokens_idents(group.stream(), idents),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+        }
+    }
+}
+
+// The identifier of a path that could refer to a local variable.
+fn local_variable(expr: &ExprPath) -> Option<&Ident> {
+    let path = &expr.path;
+    if expr.qself.is_some() || path.leading_colon.is_some() || path.segments.len() != 1 {
+        return None;
+    }
+    let segment = &path.segments[0];
+    if segment.arguments.is_empty() {
+        Some(&segment.ident)
+    } else {
+        None
+    }
+}
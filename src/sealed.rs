@@ -2,3 +2,48 @@
 pub mod lookahead {
     pub trait Sealed: Copy {}
 }
+
+#[cfg(feature = "parsing")]
+pub mod peek_sequence {
+    pub trait Sealed: Copy {}
+}
+
+#[cfg(feature = "parsing")]
+pub mod combinator {
+    pub trait Sealed {}
+}
+
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "visit",
+    feature = "extra-traits"
+))]
+pub mod diff {
+    pub trait Sealed {}
+}
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "extra-traits"))]
+pub mod equiv {
+    pub trait Sealed {}
+}
+
+#[cfg(all(
+    feature = "full",
+    feature = "parsing",
+    feature = "printing",
+    feature = "visit",
+    feature = "extra-traits"
+))]
+pub mod query {
+    pub trait Sealed {}
+}
+
+#[cfg(all(feature = "full", feature = "visit", feature = "visit-mut"))]
+pub mod scope {
+    pub trait Sealed {}
+}
+
+#[cfg(any(feature = "full", feature = "derive"))]
+pub mod compact {
+    pub trait Sealed {}
+}
@@ -0,0 +1,269 @@
+//! Relating syntax trees to the source text they were parsed from.
+//!
+//! Tokens lexed from a string outside of a procedural macro carry the line
+//! and column of where they appeared in that string, which Syn makes available
+//! through [`Span::start`] and [`Span::end`] when built with the
+//! `"span-locations"` feature. This module builds on those locations.
+//!
+//! - A [`SourceMap`] translates the span of any token or syntax tree node, as
+//!   returned by [`Spanned::span`], into a byte range of the parsed text, for
+//!   example to underline the node in a diagnostic.
+//!
+//! - A [`SourceFile`] keeps the original text of a file alongside its syntax
+//!   tree and uses the location of every token's span to attach the *trivia*
+//!   (whitespace and non-doc comments) found between tokens in the original
+//!   text. Printing through a `SourceFile` reuses that trivia wherever the
+//!   printed tokens are still the ones that were parsed, so an unmodified
+//!   file prints back byte-for-byte identical to the input and a modified
+//!   file keeps its formatting everywhere except near the modification.
+//!
+//! [`Span::start`]: proc_macro2::Span::start
+//! [`Span::end`]: proc_macro2::Span::end
+//! [`Spanned::span`]: crate::spanned::Spanned::span
+//!
+//! *This module is available if Syn is built with the `"span-locations"` and
+//! `"parsing"` features. `SourceFile` additionally requires the `"full"` and
+//! `"printing"` features.*
+//!
+//! <br>
+//!
+//! # Example
+//!
+//! ```
+//! use syn::spanned::Spanned;
+//! use syn::{Item, ItemFn};
+//!
+//! # fn main() -> syn::Result<()> {
+//! let code = "\
+//! fn main() {
+//!     let x = 1;
+//! }
+//! ";
+//!
+//! let (file, map) = syn::parse_file_with_positions(code)?;
+//! if let Item::Fn(ItemFn { block, .. }) = &file.items[0] {
+//!     let range = map.byte_range(block.stmts[0].span()).unwrap();
+//!     assert_eq!(&code[range.clone()], "let x = 1;");
+//!
+//!     let start = map.line_column(range.start).unwrap();
+//!     assert_eq!((start.line, start.column), (2, 4));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A `SourceFile` makes it possible to edit the syntax tree and print it back
+//! out with its formatting and comments intact.
+//!
+//! ```
+//! use syn::source::SourceFile;
+//! use syn::Item;
+//!
+//! # fn main() -> syn::Result<()> {
+//! let code = "\
+//! // Add two numbers.
+//! fn add(a: i32, b: i32) -> i32 {
+//!     a + b // no overflow check
+//! }
+//! ";
+//!
+//! let mut file = SourceFile::parse(code)?;
+//! assert_eq!(file.print(), code);
+//!
+//! if let Item::Fn(item) = &mut file.syntax_mut().items[0] {
+//!     item.sig.ident = syn::Ident::new("sum", item.sig.ident.span());
+//! }
+//! assert_eq!(file.print(), code.replace("add", "sum"));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Limitations
+//!
+//! Tokens are matched up with the original text by the line and column of
+//! their span, which is only available outside of a procedural macro. Tokens
+//! created from scratch, such as by `parse_quote!`, have no location and are
+//! printed separated by single spaces. The shebang line and byte order mark
+//! of the original file, if any, are always printed as they appeared in the
+//! original text.
+//!
+//! Spans are only meaningful to the `SourceMap` of the text they were lexed
+//! from. Nothing stops a span from a different string, or one produced by the
+//! compiler inside of a procedural macro, from being looked up in the wrong
+//! map, and the result will then be nonsense or `None`.
+
+use std::ops::Range;
+
+use proc_macro2::{LineColumn, Span};
+
+use crate::buffer::{self, Cursor};
+
+#[cfg(all(feature = "full", feature = "printing"))]
+#[path = "source_file.rs"]
+mod file;
+
+#[cfg(all(feature = "full", feature = "printing"))]
+pub use self::file::SourceFile;
+
+/// A table for looking up the position in the parsed text of a span.
+///
+/// Build one with [`SourceMap::new`] from the same string that is passed to
+/// [`syn::parse_str`], or get one from [`syn::parse_file_with_positions`]
+/// which takes care of the byte order mark and shebang line that
+/// [`syn::parse_file`] strips from the start of a file.
+///
+/// [`syn::parse_str`]: crate::parse_str
+/// [`syn::parse_file_with_positions`]: crate::parse_file_with_positions
+/// [`syn::parse_file`]: crate::parse_file
+///
+/// Lines are numbered from 1 and columns from 0, counting `char`s, the same
+/// as in the [`LineColumn`] returned by [`Span::start`]. Byte offsets are
+/// relative to the start of the whole text.
+///
+/// [`Span::start`]: proc_macro2::Span::start
+///
+/// *This type is available if Syn is built with the `"span-locations"` and
+/// `"parsing"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::source::SourceMap;
+/// use syn::spanned::Spanned;
+/// use syn::Expr;
+///
+/// # fn main() -> syn::Result<()> {
+/// let code = "call(first, second)";
+/// let map = SourceMap::new(code);
+///
+/// let expr: Expr = syn::parse_str(code)?;
+/// if let Expr::Call(call) = &expr {
+///     let range = map.byte_range(call.args[1].span()).unwrap();
+///     assert_eq!(range, 12..18);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SourceMap {
+    text: String,
+    // Byte offset of the first character that was lexed, past any byte order
+    // mark or shebang line. Line 1 of every span begins here.
+    base: usize,
+    // Byte offset of the start of each line of the text.
+    lines: Vec<usize>,
+}
+
+impl SourceMap {
+    /// Builds the table for a string of Rust code that is parsed exactly as
+    /// it is, as by [`syn::parse_str`].
+    ///
+    /// [`syn::parse_str`]: crate::parse_str
+    pub fn new(text: &str) -> Self {
+        Self::with_base(text, 0)
+    }
+
+    #[cfg(feature = "full")]
+    pub(crate) fn for_file(text: &str) -> Self {
+        let (_shebang, content) = crate::split_shebang(text);
+        Self::with_base(text, text.len() - content.len())
+    }
+
+    fn with_base(text: &str, base: usize) -> Self {
+        let mut lines = vec![0];
+        lines.extend(text.match_indices('\n').map(|(offset, _)| offset + 1));
+        SourceMap {
+            text: text.to_owned(),
+            base,
+            lines,
+        }
+    }
+
+    /// The text that the table was built from.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The byte range in the text covered by a span.
+    ///
+    /// Returns `None` if the span does not cover any part of the text, which
+    /// is the case for [`Span::call_site()`] and for every token that was not
+    /// lexed from a string.
+    ///
+    /// [`Span::call_site()`]: proc_macro2::Span::call_site
+    pub fn byte_range(&self, span: Span) -> Option<Range<usize>> {
+        let start = self.byte_offset(span.start())?;
+        let end = self.byte_offset(span.end())?;
+        if start < end {
+            Some(start..end)
+        } else {
+            None
+        }
+    }
+
+    /// The byte offset in the text of a line and column.
+    ///
+    /// Returns `None` if the text has no such line, or the line has no such
+    /// column.
+    pub fn byte_offset(&self, position: LineColumn) -> Option<usize> {
+        let start = match position.line {
+            0 => return None,
+            1 => self.base,
+            line => *self.lines.get(line - 1)?,
+        };
+        let end = match self.lines.get(position.line) {
+            Some(next) => *next,
+            None => self.text.len(),
+        };
+        let line = &self.text[start..end];
+        match line.char_indices().nth(position.column) {
+            Some((offset, _ch)) => Some(start + offset),
+            None if line.chars().count() == position.column => Some(end),
+            None => None,
+        }
+    }
+
+    /// The line and column in the text of a byte offset.
+    ///
+    /// This is the inverse of [`byte_offset`]. Returns `None` if the offset
+    /// is past the end of the text or not on a `char` boundary.
+    ///
+    /// [`byte_offset`]: SourceMap::byte_offset
+    pub fn line_column(&self, offset: usize) -> Option<LineColumn> {
+        if offset > self.text.len() || !self.text.is_char_boundary(offset) {
+            return None;
+        }
+        let line = match self.lines.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next) => next - 1,
+        };
+        let start = if line == 0 && offset >= self.base {
+            self.base
+        } else {
+            self.lines[line]
+        };
+        Some(LineColumn {
+            line: line + 1,
+            column: self.text[start..offset].chars().count(),
+        })
+    }
+
+    /// The byte offset in the text of the token that a cursor is pointing
+    /// at.
+    ///
+    /// A cursor that has reached the end of a delimited group maps to the
+    /// offset of the closing delimiter, and one that has reached the end of
+    /// all tokens maps to the end of the text. This is where a
+    /// [`ParseStream`] would report an error about unexpected end of input.
+    ///
+    /// [`ParseStream`]: crate::parse::ParseStream
+    pub fn cursor_offset(&self, cursor: Cursor) -> Option<usize> {
+        if !cursor.eof() {
+            return self.byte_range(cursor.span()).map(|range| range.start);
+        }
+        match buffer::close_span_of_scope(cursor) {
+            Some(close) => self.byte_range(close).map(|range| range.start),
+            None => Some(self.text.len()),
+        }
+    }
+}
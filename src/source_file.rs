@@ -0,0 +1,388 @@
+use std::ops::Range;
+
+use proc_macro2::{Delimiter, Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+
+use super::SourceMap;
+use crate::error::Result;
+use crate::File;
+
+/// A syntax tree together with the source text it was parsed from.
+///
+/// See the [module documentation] for an example.
+///
+/// [module documentation]: crate::source
+///
+/// *This type is available if Syn is built with the `"span-locations"`,
+/// `"full"`, `"parsing"` and `"printing"` features.*
+pub struct SourceFile {
+    map: SourceMap,
+    units: Vec<Unit>,
+    syntax: File,
+}
+
+// One token of the original text. Usually this is a single leaf token, but a
+// doc comment is lexed as several tokens which all share the comment's span.
+struct Unit {
+    range: Range<usize>,
+    // Text of the leaf tokens making up this unit, used to detect whether the
+    // printed tokens still say the same thing as the original text.
+    tokens: String,
+}
+
+struct Leaf {
+    span: Span,
+    text: String,
+    joint: bool,
+    delimiter: Option<Side>,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Side {
+    Open,
+    Close,
+}
+
+enum Prev {
+    Start,
+    Located(usize),
+    Unlocated { joint: bool, open: bool },
+}
+
+impl SourceFile {
+    /// Parse the content of a file of Rust code, keeping the text around for
+    /// printing later.
+    ///
+    /// This accepts the same input as [`syn::parse_file`] and produces the
+    /// same syntax tree.
+    ///
+    /// [`syn::parse_file`]: crate::parse_file
+    pub fn parse(text: &str) -> Result<Self> {
+        let syntax = crate::parse_file(text)?;
+        let map = SourceMap::for_file(text);
+        let mut leaves = Vec::new();
+        flatten(map.text[map.base..].parse()?, &mut leaves);
+
+        let mut source = SourceFile {
+            map,
+            units: Vec::new(),
+            syntax,
+        };
+
+        let mut i = 0;
+        while i < leaves.len() {
+            let range = match source.map.byte_range(leaves[i].span) {
+                Some(range) => range,
+                None => {
+                    i += 1;
+                    continue;
+                }
+            };
+            let len = source.run_len(&leaves[i..], &range);
+            source.units.push(Unit {
+                range,
+                tokens: join_texts(&leaves[i..i + len]),
+            });
+            i += len;
+        }
+
+        Ok(source)
+    }
+
+    /// The syntax tree of the file.
+    pub fn syntax(&self) -> &File {
+        &self.syntax
+    }
+
+    /// The syntax tree of the file, for modification before printing.
+    pub fn syntax_mut(&mut self) -> &mut File {
+        &mut self.syntax
+    }
+
+    /// Discards the source text, returning only the syntax tree.
+    pub fn into_syntax(self) -> File {
+        self.syntax
+    }
+
+    /// The original text of the file.
+    pub fn text(&self) -> &str {
+        &self.map.text
+    }
+
+    /// The mapping from spans in the syntax tree to positions in the original
+    /// text of the file.
+    pub fn source_map(&self) -> &SourceMap {
+        &self.map
+    }
+
+    /// Prints the syntax tree, reusing the original whitespace and comments
+    /// around every token that has not been moved or replaced.
+    pub fn print(&self) -> String {
+        let tokens = self.syntax.to_token_stream();
+        let mut leaves = Vec::new();
+        flatten(tokens, &mut leaves);
+
+        let mut out = String::new();
+        let first = leaves.first().and_then(|leaf| self.unit(leaf.span));
+        match (first, self.units.first()) {
+            (Some(0), _) => out.push_str(&self.map.text[..self.units[0].range.start]),
+            (_, Some(_)) => out.push_str(&self.map.text[..self.map.base]),
+            (_, None) => out.push_str(&self.map.text),
+        }
+
+        let last = self.print_leaves(&leaves, &mut out);
+        if let (Some(last), Some(unit)) = (last, self.units.last()) {
+            if last + 1 == self.units.len() {
+                out.push_str(&self.map.text[unit.range.end..]);
+            }
+        }
+        out
+    }
+
+    /// Prints any syntax tree node, such as an item or expression taken from
+    /// this file, reusing the original whitespace and comments between its
+    /// tokens.
+    ///
+    /// Unlike [`print`], the trivia before the first token and after the last
+    /// token of the node are not included.
+    ///
+    /// [`print`]: SourceFile::print
+    pub fn print_node<T: ToTokens>(&self, node: &T) -> String {
+        let mut leaves = Vec::new();
+        flatten(node.to_token_stream(), &mut leaves);
+        let mut out = String::new();
+        self.print_leaves(&leaves, &mut out);
+        out
+    }
+
+    /// The whitespace and comments preceding the token or syntax tree node
+    /// with the given span in the original text.
+    ///
+    /// Trivia between two tokens is split at the end of the first line: the
+    /// part up to and including the first newline is the trailing trivia of
+    /// the token before it, and the rest is the leading trivia of the token
+    /// after it. Returns `None` if the span does not begin at a token of this
+    /// file.
+    pub fn leading_trivia(&self, span: Span) -> Option<&str> {
+        let start = self.map.byte_range(span)?.start;
+        let k = self
+            .units
+            .binary_search_by(|unit| unit.range.start.cmp(&start))
+            .ok()?;
+        Some(&self.map.text[self.split(k)..self.units[k].range.start])
+    }
+
+    /// The whitespace and comments following the token or syntax tree node
+    /// with the given span in the original text, up to and including the end
+    /// of the line.
+    ///
+    /// See [`leading_trivia`] for how trivia between tokens is divided up.
+    /// Returns `None` if the span does not end at a token of this file.
+    ///
+    /// [`leading_trivia`]: SourceFile::leading_trivia
+    pub fn trailing_trivia(&self, span: Span) -> Option<&str> {
+        let end = self.map.byte_range(span)?.end;
+        let k = self
+            .units
+            .binary_search_by(|unit| unit.range.end.cmp(&end))
+            .ok()?;
+        Some(&self.map.text[self.units[k].range.end..self.split(k + 1)])
+    }
+
+    // Returns the index of the original unit of the last leaf printed, if it
+    // was located.
+    fn print_leaves(&self, leaves: &[Leaf], out: &mut String) -> Option<usize> {
+        let mut prev = Prev::Start;
+        let mut i = 0;
+        while i < leaves.len() {
+            let leaf = &leaves[i];
+            let located = self
+                .map
+                .byte_range(leaf.span)
+                .and_then(|range| self.unit(leaf.span).map(|k| (k, range)));
+            if let Some((k, range)) = located {
+                let len = self.run_len(&leaves[i..], &range);
+                match prev {
+                    Prev::Located(j) if j < k && self.only_semicolons(j + 1..k) => {
+                        out.push_str(&self.map.text[self.units[j].range.end..range.start]);
+                    }
+                    Prev::Located(j) => {
+                        out.push_str(&self.map.text[self.units[j].range.end..self.split(j + 1)]);
+                        out.push_str(&self.map.text[self.split(k)..range.start]);
+                        space(out, false, false);
+                    }
+                    Prev::Unlocated { joint, open } => {
+                        let split = self.split(k);
+                        if self.map.text[..split].ends_with('\n') {
+                            out.push('\n');
+                        }
+                        out.push_str(&self.map.text[split..range.start]);
+                        space(out, joint || open, leaf.delimiter == Some(Side::Close));
+                    }
+                    Prev::Start => {}
+                }
+                let run = &leaves[i..i + len];
+                if join_texts(run) == self.units[k].tokens {
+                    out.push_str(&self.map.text[range]);
+                } else {
+                    let mut joint = true;
+                    for leaf in run {
+                        space(out, joint, false);
+                        out.push_str(&leaf.text);
+                        joint = leaf.joint || leaf.delimiter == Some(Side::Open);
+                    }
+                }
+                prev = Prev::Located(k);
+                i += len;
+            } else {
+                match prev {
+                    Prev::Located(j) => {
+                        out.push_str(&self.map.text[self.units[j].range.end..self.split(j + 1)]);
+                        space(out, false, leaf.delimiter == Some(Side::Close));
+                    }
+                    Prev::Unlocated { joint, open } => {
+                        space(out, joint || open, leaf.delimiter == Some(Side::Close));
+                    }
+                    Prev::Start => {}
+                }
+                out.push_str(&leaf.text);
+                prev = Prev::Unlocated {
+                    joint: leaf.joint,
+                    open: leaf.delimiter == Some(Side::Open),
+                };
+                i += 1;
+            }
+        }
+        match prev {
+            Prev::Located(k) => Some(k),
+            _ => None,
+        }
+    }
+
+    // Number of leaves at the front of `leaves` that belong to the same unit
+    // as the first one, which covers `range`.
+    fn run_len(&self, leaves: &[Leaf], range: &Range<usize>) -> usize {
+        1 + leaves[1..]
+            .iter()
+            .take_while(|leaf| {
+                let start = self.map.byte_offset(leaf.span.start());
+                let end = self.map.byte_offset(leaf.span.end());
+                match (start, end) {
+                    (Some(start), Some(end)) => {
+                        range.start <= start && end <= range.end && start < end
+                    }
+                    _ => false,
+                }
+            })
+            .count()
+    }
+
+    // Empty statements are not represented in the syntax tree. Semicolons
+    // that went missing between two printed tokens are assumed to be those
+    // and are printed as in the original text.
+    fn only_semicolons(&self, units: Range<usize>) -> bool {
+        self.units[units]
+            .iter()
+            .all(|unit| &self.map.text[unit.range.clone()] == ";")
+    }
+
+    fn unit(&self, span: Span) -> Option<usize> {
+        let range = self.map.byte_range(span)?;
+        let k = self
+            .units
+            .binary_search_by(|unit| unit.range.start.cmp(&range.start))
+            .ok()?;
+        if self.units[k].range == range {
+            Some(k)
+        } else {
+            None
+        }
+    }
+
+    // Byte offset at which the trivia in front of unit `k` is split between
+    // the previous unit and this one.
+    fn split(&self, k: usize) -> usize {
+        let start = if k == 0 {
+            self.map.base
+        } else {
+            self.units[k - 1].range.end
+        };
+        let end = match self.units.get(k) {
+            Some(unit) => unit.range.start,
+            None => self.map.text.len(),
+        };
+        match self.map.text[start..end].find('\n') {
+            Some(newline) => start + newline + 1,
+            None => end,
+        }
+    }
+}
+
+fn flatten(tokens: TokenStream, leaves: &mut Vec<Leaf>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => {
+                        flatten(group.stream(), leaves);
+                        continue;
+                    }
+                };
+                leaves.push(Leaf {
+                    span: group.span_open(),
+                    text: open.to_owned(),
+                    joint: false,
+                    delimiter: Some(Side::Open),
+                });
+                flatten(group.stream(), leaves);
+                leaves.push(Leaf {
+                    span: group.span_close(),
+                    text: close.to_owned(),
+                    joint: false,
+                    delimiter: Some(Side::Close),
+                });
+            }
+            TokenTree::Punct(punct) => leaves.push(Leaf {
+                span: punct.span(),
+                text: punct.as_char().to_string(),
+                joint: punct.spacing() == Spacing::Joint,
+                delimiter: None,
+            }),
+            TokenTree::Ident(ident) => leaves.push(Leaf {
+                span: ident.span(),
+                text: ident.to_string(),
+                joint: false,
+                delimiter: None,
+            }),
+            TokenTree::Literal(literal) => leaves.push(Leaf {
+                span: literal.span(),
+                text: literal.to_string(),
+                joint: false,
+                delimiter: None,
+            }),
+        }
+    }
+}
+
+fn join_texts(leaves: &[Leaf]) -> String {
+    let mut joined = String::new();
+    for leaf in leaves {
+        if !joined.is_empty() {
+            joined.push(' ');
+        }
+        joined.push_str(&leaf.text);
+    }
+    joined
+}
+
+// Separates two tokens by a space unless there is already whitespace between
+// them or they are meant to be printed adjacent.
+fn space(out: &mut String, joint: bool, close: bool) {
+    if joint || close || out.is_empty() || out.ends_with(char::is_whitespace) {
+        return;
+    }
+    out.push(' ');
+}
@@ -145,7 +145,7 @@ pub mod parsing {
         }
     }
 
-    fn parse_stmt(input: ParseStream, allow_nosemi: bool) -> Result<Stmt> {
+    pub(crate) fn parse_stmt(input: ParseStream, allow_nosemi: bool) -> Result<Stmt> {
         // TODO: optimize using advance_to
         let ahead = input.fork();
         ahead.call(Attribute::parse_outer)?;
@@ -0,0 +1,249 @@
+//! Helpers for testing code that generates Rust source code, such as
+//! procedural macros.
+//!
+//! Comparing the output of a macro against the expected output as strings of
+//! tokens breaks whenever the spacing of the printed tokens changes, and a
+//! failure shows two long lines of tokens that are hard to tell apart. The
+//! functions in this module instead parse both sides as a [`File`] and
+//! compare the syntax trees, so that only differences in the code itself
+//! count. Spans, the spacing of tokens and the presence of trailing commas do
+//! not.
+//!
+//! ```
+//! use proc_macro2::TokenStream;
+//! use quote::quote;
+//! use syn::testing;
+//!
+//! // The expansion of a derive macro that is being tested.
+//! fn expand() -> TokenStream {
+//!     quote! {
+//!         impl Default for S {
+//!             fn default() -> Self { S { a: 0 , } }
+//!         }
+//!     }
+//! }
+//!
+//! testing::assert_tokens_eq(
+//!     expand(),
+//!     quote! {
+//!         impl Default for S {
+//!             fn default() -> Self {
+//!                 S { a: 0 }
+//!             }
+//!         }
+//!     },
+//! );
+//! ```
+//!
+//! When the two differ, the failure names the first node that is different,
+//! along with the item that contains it on either side, formatted as Rust
+//! source code:
+//!
+//! ```text
+//! tokens differ at .items[0].items[0].block.stmts[0].fields[0].expr.lit:
+//!
+//! expected: 0
+//!   actual: 1
+//!
+//! expected item:
+//!     impl Default for S {
+//!         fn default() -> Self {
+//!             S { a: 0 }
+//!         }
+//!     }
+//!
+//! actual item:
+//!     impl Default for S {
+//!         fn default() -> Self {
+//!             S { a: 1 }
+//!         }
+//!     }
+//! ```
+//!
+//! *This module is available if Syn is built with the `"testing"` feature.*
+
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Write};
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+use crate::diff::{self, Change, Step};
+use crate::node::NodeRef;
+use crate::print;
+use crate::punctuated::Punctuated;
+use crate::visit_mut::{self, VisitMut};
+use crate::File;
+
+/// Asserts that two token streams are the same Rust code.
+///
+/// # Panics
+///
+/// Panics if either token stream is not a valid Rust file, or if the two
+/// differ. The panic message describes the first difference as explained in
+/// the [module documentation][self].
+pub fn assert_tokens_eq(actual: TokenStream, expected: TokenStream) {
+    if let Err(mismatch) = compare_tokens(actual, expected) {
+        panic!("{}", mismatch);
+    }
+}
+
+/// Compares two token streams as Rust code, returning a description of the
+/// first difference if they are not the same.
+///
+/// This is the non-panicking form of [`assert_tokens_eq`].
+pub fn compare_tokens(actual: TokenStream, expected: TokenStream) -> Result<(), Mismatch> {
+    let actual = parse("actual", actual)?;
+    let expected = parse("expected", expected)?;
+
+    let changes = diff::diff(&expected, &actual);
+    let change = match changes.first() {
+        Some(change) => change,
+        None => return Ok(()),
+    };
+
+    let mut report = String::from("tokens differ");
+    let path = change.path();
+    if !path.is_empty() {
+        report.push_str(" at ");
+        for step in path {
+            let _ = write!(report, "{}", step);
+        }
+    }
+    report.push_str(":\n\n");
+
+    let (expected_node, actual_node) = match change {
+        Change::Added { new, .. } => (None, Some(new)),
+        Change::Removed { old, .. } => (Some(old), None),
+        Change::Modified { old, new, .. } => (Some(old), Some(new)),
+    };
+    labeled(&mut report, "expected", expected_node.map(pretty_node));
+    labeled(&mut report, "  actual", actual_node.map(pretty_node));
+
+    // The item containing the difference, unless the difference is the whole
+    // item. All items before the first difference are the same on both sides,
+    // so the item has the same index in both files.
+    if path.len() > 2 && path[0] == Step::Field("items") {
+        if let Step::Index(i) = path[1] {
+            for (label, file) in &[("expected item", &expected), ("actual item", &actual)] {
+                report.push('\n');
+                labeled(&mut report, label, Some(print::pretty_item(&file.items[i])));
+            }
+        }
+    }
+
+    if changes.len() > 1 {
+        let _ = write!(report, "\n({} more differences)\n", changes.len() - 1);
+    }
+    Err(Mismatch { report })
+}
+
+/// The difference between two token streams, as found by
+/// [`compare_tokens`].
+///
+/// The `Display` impl gives the same description as the panic message of
+/// [`assert_tokens_eq`].
+pub struct Mismatch {
+    report: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.report.trim_end())
+    }
+}
+
+impl Debug for Mismatch {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+impl Error for Mismatch {}
+
+fn parse(side: &str, tokens: TokenStream) -> Result<File, Mismatch> {
+    match crate::parse2::<File>(tokens.clone()) {
+        Ok(mut file) => {
+            IgnoreTrailingPunct.visit_file_mut(&mut file);
+            Ok(file)
+        }
+        Err(err) => Err(Mismatch {
+            report: format!(
+                "{} tokens are not valid Rust code: {}\n\n{} tokens:\n    {}\n",
+                side, err, side, tokens,
+            ),
+        }),
+    }
+}
+
+// Formats a node as source code, by the pretty printer if it can format the
+// node, or otherwise as tokens.
+fn pretty_node(node: &NodeRef) -> String {
+    match node {
+        NodeRef::Item(item) => print::pretty_item(item),
+        NodeRef::Expr(expr) => print::pretty_expr(expr),
+        node => node.to_token_stream().to_string(),
+    }
+}
+
+// Appends `label: value`, or the value on lines of its own under the label if
+// it spans several lines.
+fn labeled(report: &mut String, label: &str, value: Option<String>) {
+    let value = value.unwrap_or_else(|| String::from("(nothing)"));
+    let value = value.trim_end();
+    if value.contains('\n') {
+        let _ = writeln!(report, "{}:", label.trim_start());
+        for line in value.lines() {
+            if line.is_empty() {
+                report.push('\n');
+            } else {
+                let _ = writeln!(report, "    {}", line);
+            }
+        }
+    } else {
+        let _ = writeln!(report, "{}: {}", label, value);
+    }
+}
+
+// Removes trailing punctuation from every punctuated sequence that can do
+// without it, so that `S { a: 0, }` and `S { a: 0 }` compare equal.
+struct IgnoreTrailingPunct;
+
+macro_rules! ignore_trailing_punct {
+    ($($visit:ident($ty:ty).$($field:ident).+;)*) => {
+        impl VisitMut for IgnoreTrailingPunct {
+            $(
+                fn $visit(&mut self, node: &mut $ty) {
+                    visit_mut::$visit(self, node);
+                    pop_punct(&mut node.$($field).+);
+                }
+            )*
+        }
+    };
+}
+
+ignore_trailing_punct! {
+    visit_angle_bracketed_generic_arguments_mut(crate::AngleBracketedGenericArguments).args;
+    visit_expr_array_mut(crate::ExprArray).elems;
+    visit_expr_call_mut(crate::ExprCall).args;
+    visit_expr_closure_mut(crate::ExprClosure).inputs;
+    visit_expr_method_call_mut(crate::ExprMethodCall).args;
+    visit_expr_struct_mut(crate::ExprStruct).fields;
+    visit_fields_named_mut(crate::FieldsNamed).named;
+    visit_fields_unnamed_mut(crate::FieldsUnnamed).unnamed;
+    visit_generics_mut(crate::Generics).params;
+    visit_item_enum_mut(crate::ItemEnum).variants;
+    visit_pat_slice_mut(crate::PatSlice).elems;
+    visit_pat_struct_mut(crate::PatStruct).fields;
+    visit_pat_tuple_struct_mut(crate::PatTupleStruct).pat.elems;
+    visit_signature_mut(crate::Signature).inputs;
+    visit_type_bare_fn_mut(crate::TypeBareFn).inputs;
+    visit_use_group_mut(crate::UseGroup).items;
+    visit_where_clause_mut(crate::WhereClause).predicates;
+}
+
+fn pop_punct<T, P>(punctuated: &mut Punctuated<T, P>) {
+    if let Some(value) = punctuated.pop() {
+        punctuated.push_value(value.into_value());
+    }
+}
@@ -136,6 +136,12 @@ pub trait Token: private::Sealed {
     // Not public API.
     #[doc(hidden)]
     fn display() -> &'static str;
+
+    // Not public API.
+    #[doc(hidden)]
+    fn skip(cursor: Cursor) -> Option<Cursor> {
+        cursor.skip()
+    }
 }
 
 mod private {
@@ -214,6 +220,10 @@ impl_token!(LitBool "boolean literal");
 pub trait CustomToken {
     fn peek(cursor: Cursor) -> bool;
     fn display() -> &'static str;
+
+    fn skip(cursor: Cursor) -> Option<Cursor> {
+        cursor.skip()
+    }
 }
 
 #[cfg(feature = "parsing")]
@@ -228,6 +238,10 @@ impl<T: CustomToken> Token for T {
     fn display() -> &'static str {
         <Self as CustomToken>::display()
     }
+
+    fn skip(cursor: Cursor) -> Option<Cursor> {
+        <Self as CustomToken>::skip(cursor)
+    }
 }
 
 macro_rules! define_keywords {
@@ -425,6 +439,10 @@ macro_rules! define_punctuation {
                 fn display() -> &'static str {
                     concat!("`", $token, "`")
                 }
+
+                fn skip(cursor: Cursor) -> Option<Cursor> {
+                    parsing::skip_punct(cursor, $token)
+                }
             }
 
             #[cfg(feature = "parsing")]
@@ -588,6 +606,12 @@ impl Token for Group {
     fn display() -> &'static str {
         "invisible group"
     }
+
+    fn skip(cursor: Cursor) -> Option<Cursor> {
+        cursor
+            .group(Delimiter::None)
+            .map(|(_content, _span, rest)| rest)
+    }
 }
 
 define_keywords! {
@@ -886,14 +910,18 @@ pub mod parsing {
         })
     }
 
-    pub fn peek_punct(mut cursor: Cursor, token: &str) -> bool {
+    pub fn peek_punct(cursor: Cursor, token: &str) -> bool {
+        skip_punct(cursor, token).is_some()
+    }
+
+    pub fn skip_punct<'a>(mut cursor: Cursor<'a>, token: &str) -> Option<Cursor<'a>> {
         for (i, ch) in token.chars().enumerate() {
             match cursor.punct() {
                 Some((punct, rest)) => {
                     if punct.as_char() != ch {
                         break;
                     } else if i == token.len() - 1 {
-                        return true;
+                        return Some(rest);
                     } else if punct.spacing() != Spacing::Joint {
                         break;
                     }
@@ -902,7 +930,7 @@ pub mod parsing {
                 None => break,
             }
         }
-        false
+        None
     }
 }
 
@@ -383,9 +383,11 @@ ast_enum! {
 pub mod parsing {
     use super::*;
 
+    use crate::buffer::Cursor;
     use crate::ext::IdentExt;
-    use crate::parse::{Parse, ParseStream, Result};
+    use crate::parse::{Parse, ParseStream, PeekNode, Result};
     use crate::path;
+    use crate::token::Token;
 
     impl Parse for Type {
         fn parse(input: ParseStream) -> Result<Self> {
@@ -404,6 +406,41 @@ pub mod parsing {
         }
     }
 
+    // Tokens that can start a type.
+    const TYPE_START: &[fn(Cursor) -> bool] = &[
+        <token::Group as Token>::peek,
+        <token::Paren as Token>::peek,
+        <token::Bracket as Token>::peek,
+        <Ident as Token>::peek,
+        <Lifetime as Token>::peek,
+        <Token![::] as Token>::peek,
+        <Token![<] as Token>::peek,
+        <Token![!] as Token>::peek,
+        <Token![&] as Token>::peek,
+        <Token![*] as Token>::peek,
+        <Token![_] as Token>::peek,
+        <Token![crate] as Token>::peek,
+        <Token![dyn] as Token>::peek,
+        <Token![extern] as Token>::peek,
+        <Token![fn] as Token>::peek,
+        <Token![for] as Token>::peek,
+        <Token![impl] as Token>::peek,
+        <Token![self] as Token>::peek,
+        <Token![Self] as Token>::peek,
+        <Token![super] as Token>::peek,
+        <Token![unsafe] as Token>::peek,
+    ];
+
+    impl PeekNode for Type {
+        fn peek(cursor: Cursor) -> bool {
+            TYPE_START.iter().any(|peek| peek(cursor))
+        }
+
+        fn display() -> &'static str {
+            "type"
+        }
+    }
+
     fn ambig_ty(input: ParseStream, allow_plus: bool) -> Result<Type> {
         if input.peek(token::Group) {
             return input.parse().map(Type::Group);
@@ -469,7 +506,7 @@ pub mod parsing {
                         elems.push_punct(content.parse()?);
                         let rest: Punctuated<Type, Token![,]> =
                             content.parse_terminated(Parse::parse)?;
-                        elems.extend(rest);
+                        elems.extend(rest.into_pairs());
                         elems
                     },
                 }));
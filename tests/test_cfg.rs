@@ -0,0 +1,178 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::cfg::{CfgExpr, CfgSet, StripCfg};
+use syn::visit_mut::VisitMut;
+use syn::{parse_quote, Attribute, File};
+
+fn cfgs() -> CfgSet {
+    let mut cfgs = CfgSet::new();
+    cfgs.insert("unix");
+    cfgs.insert_value("feature", "std");
+    cfgs.insert_value("target_os", "linux");
+    cfgs
+}
+
+fn eval(predicate: &str) -> bool {
+    syn::parse_str::<CfgExpr>(predicate).unwrap().eval(&cfgs())
+}
+
+#[test]
+fn test_eval() {
+    assert!(eval("unix"));
+    assert!(!eval("windows"));
+    assert!(eval("feature = \"std\""));
+    assert!(!eval("feature = \"alloc\""));
+    assert!(!eval("feature"));
+    assert!(eval("all(unix, target_os = \"linux\")"));
+    assert!(!eval("all(unix, windows)"));
+    assert!(eval("any(windows, unix)"));
+    assert!(eval("not(any(windows, test))"));
+    assert!(eval("all()"));
+    assert!(!eval("any()"));
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = |predicate: &str| {
+        syn::parse_str::<CfgExpr>(predicate)
+            .err()
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(
+        error("not(unix, windows)"),
+        "expected exactly one cfg predicate inside of `not(...)`",
+    );
+    assert_eq!(
+        error("either(unix, windows)"),
+        "invalid cfg predicate `either`, expected `all`, `any` or `not`",
+    );
+    assert_eq!(error("feature = std"), "expected literal");
+}
+
+#[test]
+fn test_attrs() {
+    let cfgs = cfgs();
+
+    let mut attrs: Vec<Attribute> = vec![
+        parse_quote!(#[inline]),
+        parse_quote!(#[cfg_attr(unix, derive(Debug), cfg_attr(feature = "std", doc = "std"))]),
+        parse_quote!(#[cfg_attr(windows, cfg(any()))]),
+        parse_quote!(#[cfg(feature = "std")]),
+    ];
+    cfgs.expand_cfg_attr(&mut attrs).unwrap();
+    let expected: Vec<Attribute> = vec![
+        parse_quote!(#[inline]),
+        parse_quote!(#[derive(Debug)]),
+        parse_quote!(#[doc = "std"]),
+        parse_quote!(#[cfg(feature = "std")]),
+    ];
+    assert_eq!(attrs, expected);
+    assert!(cfgs.is_enabled(&attrs).unwrap());
+
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[cfg(unix)]), parse_quote!(#[cfg(test)])];
+    assert!(!cfgs.is_enabled(&attrs).unwrap());
+}
+
+#[test]
+fn test_strip() {
+    let mut file: File = parse_quote! {
+        #[cfg(windows)]
+        use windows::*;
+
+        #[cfg_attr(unix, derive(Debug))]
+        struct S {
+            a: u8,
+            #[cfg(windows)]
+            b: u8,
+        }
+
+        enum E {
+            A,
+            #[cfg(windows)]
+            B
+        }
+
+        impl S {
+            #[cfg(test)]
+            fn test() {}
+
+            fn f(#[cfg(windows)] handle: Handle, x: u8) {
+                #[cfg(windows)]
+                let y = 1;
+                let s = S { a: x };
+                let v = [1, #[cfg(windows)] 2];
+                match s {
+                    #[cfg(unix)]
+                    S { a: 0, .. } => {}
+                    #[cfg(not(unix))]
+                    _ => {}
+                }
+            }
+        }
+
+        #[cfg(unix)]
+        mod unix {
+            #[cfg(not(feature = "std"))]
+            fn no_std() {}
+        }
+    };
+
+    let cfgs = cfgs();
+    let mut strip = StripCfg::new(&cfgs);
+    strip.visit_file_mut(&mut file);
+    strip.finish().unwrap();
+
+    let expected: File = parse_quote! {
+        #[derive(Debug)]
+        struct S {
+            a: u8,
+        }
+
+        enum E {
+            A
+        }
+
+        impl S {
+            fn f(x: u8) {
+                let s = S { a: x };
+                let v = [1];
+                match s {
+                    S { a: 0, .. } => {}
+                }
+            }
+        }
+
+        mod unix {}
+    };
+    assert_eq!(quote!(#file).to_string(), quote!(#expected).to_string());
+}
+
+#[test]
+fn test_strip_errors() {
+    let mut file: File = parse_quote! {
+        #![cfg(unix)]
+
+        #[cfg(unix, windows)]
+        fn a() {}
+
+        #[cfg_attr(unix)]
+        fn b() {}
+    };
+
+    let cfgs = cfgs();
+    let mut strip = StripCfg::new(&cfgs);
+    strip.visit_file_mut(&mut file);
+    let errors: Vec<String> = strip
+        .finish()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.to_string())
+        .collect();
+    assert_eq!(errors, ["unexpected token", "expected `,`"]);
+    assert_eq!(file.items.len(), 2);
+}
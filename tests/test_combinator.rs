@@ -0,0 +1,118 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::parse::combinator::{alt, delimited, many0, many_until, map, opt, separated, token, when};
+use syn::parse::{Parse, Parser};
+use syn::{token, Attribute, Expr, Ident, LitInt, LitStr, Token, Type};
+
+#[test]
+fn test_sequence() {
+    let fields = delimited(
+        token::Brace,
+        separated(
+            (token(Ident), opt((token(Token![:]), Type::parse))),
+            Token![,],
+        ),
+    );
+    let (_, fields) = fields.parse_str("{ a: u8, b, c: Vec<u8>, }").unwrap();
+    assert_eq!(fields.len(), 3);
+    assert!(fields.trailing_punct());
+    assert_eq!(fields[0].0, "a");
+    assert!(fields[1].1.is_none());
+
+    let err = fields_error("{ a: u8 b }");
+    assert_eq!(err, "unexpected token");
+    let err = fields_error("{ a: u8, } c");
+    assert_eq!(err, "unexpected token");
+    let err = fields_error("( a )");
+    assert_eq!(err, "expected curly braces");
+}
+
+fn fields_error(input: &str) -> String {
+    let fields = delimited(token::Brace, separated(token(Ident), Token![,]));
+    fields.parse_str(input).err().unwrap().to_string()
+}
+
+#[test]
+fn test_repetition() {
+    let (idents, _, exprs) = (
+        many_until(token(Ident), Token![;]),
+        token(Token![;]),
+        many0((Expr::parse, token(Token![;]))),
+    )
+        .parse_str("a b c; 1 + 1; f();")
+        .unwrap();
+    assert_eq!(idents.len(), 3);
+    assert_eq!(exprs.len(), 2);
+
+    // An element that fails after consuming input is an error rather than the
+    // end of the repetition.
+    let err = many0((Expr::parse, token(Token![;])))
+        .parse_str("1; 2 +;")
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "expected expression");
+}
+
+#[test]
+fn test_alt() {
+    enum Value {
+        Int(LitInt),
+        Str(LitStr),
+        Neg(Expr),
+    }
+
+    let value = alt((
+        map(token(LitInt), Value::Int),
+        map(token(LitStr), Value::Str),
+        map(when(Token![-], Expr::parse), Value::Neg),
+    ));
+    match value.parse_str("\"s\"").unwrap() {
+        Value::Str(lit) => assert_eq!(lit.value(), "s"),
+        _ => panic!("expected string"),
+    }
+    match value.parse_str("-1").unwrap() {
+        Value::Neg(_) => {}
+        _ => panic!("expected negation"),
+    }
+
+    let err = value.parse_str("x").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "expected one of: integer literal, string literal, `-`",
+    );
+
+    // Alternatives that cannot be peeked are attempted in order.
+    let path = alt((map(Type::parse, |_| "type"), map(Expr::parse, |_| "expr")));
+    assert_eq!(path.parse_str("Vec<u8>").unwrap(), "type");
+    assert_eq!(path.parse_str("1 + 1").unwrap(), "expr");
+    let err = path.parse_str("+").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "expected one of: `for`, parentheses, `fn`, `unsafe`, `extern`, identifier, `::`, \
+         `<`, square brackets, `*`, `&`, `!`, `impl`, `_`, lifetime",
+    );
+}
+
+#[test]
+fn test_parse_args_with() {
+    let attr: Attribute = syn::parse_quote! {
+        #[route(GET, "/", "/index")]
+    };
+    let parser = (
+        token(Ident),
+        token(Token![,]),
+        separated(token(LitStr), Token![,]),
+    );
+    let (method, _, paths) = attr.parse_args_with(parser).unwrap();
+    assert_eq!(method, "GET");
+    assert_eq!(paths.len(), 2);
+
+    let tokens = quote! { [a, b] };
+    let parser = delimited(token::Bracket, separated(token(Ident), Token![,]));
+    let (_, idents) = parser.parse2(tokens).unwrap();
+    assert_eq!(idents.len(), 2);
+}
@@ -0,0 +1,89 @@
+extern crate syn;
+
+mod features;
+
+use syn::{compact, Expr, Item, Type};
+
+#[test]
+fn test_elided() {
+    let item: Item = syn::parse_str("fn f(mut x: u8, y: &'a str) -> u8 { x }").unwrap();
+    assert_eq!(
+        format!("{:?}", compact::debug(&item)),
+        concat!(
+            "(ItemFn",
+            " :sig (Signature",
+            " :ident f",
+            " :inputs [",
+            "(PatType :pat (PatIdent :mutability mut :ident x) :ty (TypePath :path u8)) ",
+            "(PatType :pat (PatIdent :ident y) :ty (TypeReference :lifetime 'a :elem (TypePath :path str)))",
+            "]",
+            " :output (ReturnType::Type (TypePath :path u8)))",
+            " :block (Block :stmts [(Stmt::Expr (ExprPath :path x))]))",
+        ),
+    );
+}
+
+#[test]
+fn test_variants() {
+    let item: Item = syn::parse_str("pub(crate) struct S<T>(Vec<T>);").unwrap();
+    assert_eq!(
+        format!("{:?}", compact::debug(&item)),
+        concat!(
+            "(ItemStruct",
+            " :vis (VisRestricted :path crate)",
+            " :ident S",
+            " :generics (Generics :params [(TypeParam :ident T)])",
+            " :fields (FieldsUnnamed :unnamed [(Field :ty (TypePath :path (Path :segments [",
+            "(PathSegment :ident Vec :arguments (AngleBracketedGenericArguments",
+            " :args [(GenericArgument::Type (TypePath :path T))]))",
+            "])))]))",
+        ),
+    );
+
+    let ty: Type = syn::parse_str("[u8; m!(1.5 b\"x\" 'c')]").unwrap();
+    assert_eq!(
+        format!("{:?}", compact::debug(&ty)),
+        concat!(
+            "(TypeArray :elem (TypePath :path u8)",
+            " :len (ExprMacro :mac (Macro :path m :delimiter Paren :tokens `1.5 b\"x\" 'c'`)))",
+        ),
+    );
+
+    let expr: Expr = syn::parse_str("[true, 'c', 1.5f32, b\"x\", r\"y\"]").unwrap();
+    assert_eq!(
+        format!("{:?}", compact::debug(&expr)),
+        concat!(
+            "(ExprArray :elems [",
+            "(ExprLit :lit true) (ExprLit :lit 'c') (ExprLit :lit 1.5f32) ",
+            "(ExprLit :lit b\"x\") (ExprLit :lit r\"y\")",
+            "])",
+        ),
+    );
+}
+
+#[test]
+fn test_alternate() {
+    let expr: Expr = syn::parse_str(
+        "match value { Some(value) if value > threshold => value - threshold, _ => 0 }",
+    )
+    .unwrap();
+    let expected = r#"
+(ExprMatch
+  :expr (ExprPath :path value)
+  :arms [
+    (Arm
+      :pat (PatTupleStruct
+        :path Some
+        :pat (PatTuple :elems [(PatIdent :ident value)]))
+      :guard (ExprBinary
+        :left (ExprPath :path value)
+        :op Gt
+        :right (ExprPath :path threshold))
+      :body (ExprBinary
+        :left (ExprPath :path value)
+        :op Sub
+        :right (ExprPath :path threshold)))
+    (Arm :pat (PatWild) :body (ExprLit :lit 0))
+  ])"#;
+    assert_eq!(format!("{:#?}", compact::debug(&expr)), &expected[1..]);
+}
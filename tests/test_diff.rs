@@ -0,0 +1,122 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::diff::{self, Change};
+use syn::{parse_quote, Expr, File, Item, Stmt};
+
+// Renders each change as a line like `~ .items[0].sig.ident: f -> g`.
+fn summary(changes: &[Change]) -> Vec<String> {
+    changes
+        .iter()
+        .map(|change| {
+            let path: String = change.path().iter().map(ToString::to_string).collect();
+            match change {
+                Change::Added { new, .. } => format!("+ {}: {}", path, quote!(#new)),
+                Change::Removed { old, .. } => format!("- {}: {}", path, quote!(#old)),
+                Change::Modified { old, new, .. } => {
+                    format!("~ {}: {} -> {}", path, quote!(#old), quote!(#new))
+                }
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_items() {
+    let old: File = parse_quote! {
+        use std::io;
+
+        fn f(x: u8) -> u8 {
+            let y = x * 2;
+            y + 1
+        }
+
+        struct Removed;
+
+        fn g() {}
+    };
+    let new: File = parse_quote! {
+        use std::io;
+
+        fn f(x: u8) -> u8 {
+            let y = x * 3;
+            y + 1
+        }
+
+        fn g() {}
+
+        const ADDED: u8 = 0;
+    };
+
+    assert_eq!(
+        summary(&diff::diff(&old, &new)),
+        [
+            "~ .items[1].block.stmts[0].init.right.lit: 2 -> 3",
+            "- .items[2]: struct Removed ;",
+            "+ .items[3]: const ADDED : u8 = 0 ;",
+        ],
+    );
+}
+
+#[test]
+fn test_spans_ignored() {
+    let old: File = syn::parse_str("fn f() { g(1, 2) }").unwrap();
+    let new: File = syn::parse_str("fn f()\n{\n    g(1, 2,)\n}\n").unwrap();
+    assert!(diff::diff(&old, &new).is_empty());
+}
+
+#[test]
+fn test_expr() {
+    let old: Expr = parse_quote!(a + f(1, 2));
+    let new: Expr = parse_quote!(a - f(1, x, 2));
+    assert_eq!(
+        summary(&diff::diff(&old, &new)),
+        ["~ .op: + -> -", "+ .right.args[1]: x"],
+    );
+
+    // A different kind of expression replaces the whole node.
+    let new: Expr = parse_quote!(a + f(1).await);
+    assert_eq!(
+        summary(&diff::diff(&old, &new)),
+        ["~ .right: f (1 , 2) -> f (1) . await"],
+    );
+}
+
+#[test]
+fn test_rename() {
+    let old: Item = parse_quote! { fn f() {} };
+    let new: Item = parse_quote! { fn g() {} };
+    let changes = diff::diff(&old, &new);
+    assert_eq!(summary(&changes), ["~ .sig.ident: f -> g"]);
+    match &changes[0] {
+        Change::Modified { old, new, .. } => {
+            assert_eq!(old.kind(), "Ident");
+            assert_eq!(new.kind(), "Ident");
+        }
+        _ => panic!("expected a modification"),
+    }
+}
+
+#[test]
+fn test_optional() {
+    let old: Stmt = parse_quote!(let x = 1;);
+    let new: Stmt = parse_quote!(let x;);
+    assert_eq!(summary(&diff::diff(&old, &new)), ["- .init: 1"]);
+    assert_eq!(summary(&diff::diff(&new, &old)), ["+ .init: 1"]);
+
+    // Parts that are not nodes of their own are reported on their parent.
+    let old: Item = parse_quote! { impl Send for S {} };
+    let new: Item = parse_quote! { impl !Send for S {} };
+    assert_eq!(
+        summary(&diff::diff(&old, &new)),
+        ["~ : impl Send for S { } -> impl ! Send for S { }"],
+    );
+    let new: Item = parse_quote! { impl Sync for S {} };
+    assert_eq!(
+        summary(&diff::diff(&old, &new)),
+        ["~ .trait_.segments[0].ident: Send -> Sync"],
+    );
+}
@@ -0,0 +1,136 @@
+extern crate syn;
+
+mod features;
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::Hasher;
+use syn::equiv::{Equivalence, Equivalent};
+use syn::{parse_quote, DeriveInput, Expr, ExprParen, ItemImpl, Type};
+
+fn hash<T: Equivalent>(equiv: &Equivalence, node: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    equiv.hash(node, &mut hasher);
+    hasher.finish()
+}
+
+fn assert_equiv<T: Equivalent>(equiv: &Equivalence, a: &T, b: &T) {
+    assert!(equiv.equal(a, b));
+    assert!(equiv.equal(b, a));
+    assert_eq!(hash(equiv, a), hash(equiv, b));
+}
+
+#[test]
+fn test_strict() {
+    let strict = Equivalence::new();
+    let a: Expr = syn::parse_str("f(x, 1)").unwrap();
+    let b: Expr = parse_quote!(f(x, 1));
+    assert_equiv(&strict, &a, &b);
+    assert_eq!(a, b);
+
+    let c: Expr = parse_quote!(f(x, 2));
+    assert!(!strict.equal(&a, &c));
+    let d: Expr = parse_quote!(f((x), 1));
+    assert!(!strict.equal(&a, &d));
+}
+
+#[test]
+fn test_transparent_groups() {
+    let equiv = Equivalence::new().transparent_groups(true);
+
+    let a: Expr = parse_quote!((a + b) * c);
+    let b: Expr = parse_quote! { ((((a + b))) * (c)) };
+    assert_equiv(&equiv, &a, &b);
+
+    // Parentheses that change the structure of the expression still count.
+    let c: Expr = parse_quote!(a + b * c);
+    assert!(!equiv.equal(&a, &c));
+
+    let t: Type = parse_quote!(Vec<(u8)>);
+    let u: Type = parse_quote!((Vec<u8>));
+    assert_equiv(&equiv, &t, &u);
+    let v: Type = parse_quote!(Vec<(u8,)>);
+    assert!(!equiv.equal(&t, &v));
+
+    // A group with attributes is transparent only if attributes are ignored.
+    let mut paren: ExprParen = parse_quote!((x));
+    paren.attrs.push(parse_quote!(#[cfg(a)]));
+    let paren = Expr::Paren(paren);
+    let x: Expr = parse_quote!(x);
+    assert!(!equiv.equal(&paren, &x));
+    assert!(!equiv.equal(&x, &paren));
+    assert_ne!(hash(&equiv, &paren), hash(&equiv, &x));
+    assert_equiv(&equiv, &paren, &paren);
+    assert_equiv(&equiv.ignore_attrs(true), &paren, &x);
+}
+
+#[test]
+fn test_trailing_punct() {
+    let equiv = Equivalence::new().ignore_trailing_punct(true);
+    let a: Expr = parse_quote!(f(x, [1, 2]));
+    let b: Expr = parse_quote!(f(x, [1, 2,],));
+    assert!(!Equivalence::new().equal(&a, &b));
+    assert_equiv(&equiv, &a, &b);
+
+    let c: Expr = parse_quote!(f(x, [1, 2, 3]));
+    assert!(!equiv.equal(&a, &c));
+}
+
+#[test]
+fn test_ignore_attrs() {
+    let equiv = Equivalence::new().ignore_attrs(true);
+    let a: DeriveInput = parse_quote! {
+        /// Documented.
+        #[derive(Debug)]
+        struct S {
+            #[serde(skip)]
+            a: u8,
+        }
+    };
+    let b: DeriveInput = parse_quote! {
+        struct S {
+            a: u8,
+        }
+    };
+    assert!(!Equivalence::new().equal(&a, &b));
+    assert_equiv(&equiv, &a, &b);
+}
+
+#[test]
+fn test_unraw_idents() {
+    let equiv = Equivalence::new().unraw_idents(true);
+    let a: Expr = parse_quote!(r#type.r#match);
+    let b: Expr = parse_quote!(r#type.match_);
+    let c: Expr = parse_quote!(type_.r#match);
+    assert!(!equiv.equal(&a, &b));
+    assert!(!equiv.equal(&a, &c));
+
+    let a: ItemImpl = parse_quote!(impl r#Trait for S {});
+    let b: ItemImpl = parse_quote!(impl Trait for S {});
+    assert!(!Equivalence::new().equal(&a, &b));
+    assert_equiv(&equiv, &a, &b);
+}
+
+#[test]
+fn test_key() {
+    let equiv = Equivalence::new()
+        .transparent_groups(true)
+        .ignore_trailing_punct(true);
+    let exprs: Vec<Expr> = vec![
+        parse_quote!(f(a, b)),
+        parse_quote!(f((a), b,)),
+        parse_quote!(f(a, (b))),
+        parse_quote!(f(b, a)),
+    ];
+
+    let mut set = HashSet::new();
+    for expr in &exprs {
+        set.insert(equiv.key(expr));
+    }
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&equiv.key(&exprs[3])));
+
+    let owned = equiv.key(exprs[1].clone());
+    assert!(set.contains(&equiv.key(&*owned)));
+    assert_eq!(owned.into_inner(), exprs[1]);
+}
@@ -0,0 +1,136 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::Span;
+use quote::{quote, ToTokens};
+use syn::{Error, Field, ItemStruct, Warnings};
+
+#[test]
+fn test_compile_error_with_children() {
+    let err = Error::new(Span::call_site(), "field `a` is declared twice")
+        .span_label(Span::call_site(), "first declared here")
+        .note("fields must have unique names")
+        .span_help(Span::call_site(), "rename one of the fields");
+
+    assert_eq!(err.to_string(), "field `a` is declared twice");
+    assert_eq!(
+        err.to_compile_error().to_string(),
+        concat!(
+            "compile_error ! { \"field `a` is declared twice\" } ",
+            "compile_error ! { \"first declared here\" } ",
+            "compile_error ! { \"note: fields must have unique names\" } ",
+            "compile_error ! { \"help: rename one of the fields\" }",
+        ),
+    );
+}
+
+#[test]
+fn test_combine_with_children() {
+    let mut err = Error::new(Span::call_site(), "first").help("fix the first");
+    err.combine(Error::new(Span::call_site(), "second").note("about the second"));
+    let err = err.note("also about the first");
+
+    let messages: Vec<String> = err
+        .into_iter()
+        .map(|err| err.to_compile_error().to_string())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            concat!(
+                "compile_error ! { \"first\" } ",
+                "compile_error ! { \"help: fix the first\" } ",
+                "compile_error ! { \"note: also about the first\" }",
+            ),
+            concat!(
+                "compile_error ! { \"second\" } ",
+                "compile_error ! { \"note: about the second\" }",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn test_children_across_threads() {
+    let err = Error::new(Span::call_site(), "message").span_note(Span::call_site(), "note");
+    let clone = std::thread::spawn(move || err.clone()).join().unwrap();
+    assert_eq!(
+        clone.to_compile_error().to_string(),
+        "compile_error ! { \"message\" } compile_error ! { \"note: note\" }",
+    );
+}
+
+#[test]
+fn test_render() {
+    let code = "struct S {\n    a: u8,\n    b: u8,\n    a: u8,\n}\n";
+    let item: ItemStruct = syn::parse_str(code).unwrap();
+    let fields: Vec<&Field> = item.fields.iter().collect();
+    let first = fields[0].ident.as_ref().unwrap();
+    let second = fields[2].ident.as_ref().unwrap();
+
+    let mut err = Error::new(second.span(), "field `a` is declared twice")
+        .span_label(first.span(), "first declared here")
+        .help("rename one of the fields");
+    err.combine(
+        Error::new_spanned(&fields[1].ty, "unsupported type").note("only `u8` is not supported"),
+    );
+
+    let expected = "\
+error: field `a` is declared twice
+ --> lib.rs:4:5
+  |
+2 |     a: u8,
+  |     - first declared here
+...
+4 |     a: u8,
+  |     ^
+  = help: rename one of the fields
+
+error: unsupported type
+ --> lib.rs:3:8
+  |
+3 |     b: u8,
+  |        ^^
+  = note: only `u8` is not supported
+";
+    assert_eq!(err.render("lib.rs", code), expected);
+}
+
+#[test]
+fn test_render_unlocated() {
+    let err = Error::new(Span::call_site(), "failed").note("somewhere");
+    assert_eq!(
+        err.render("lib.rs", "struct S;\n"),
+        "error: failed\n --> lib.rs\n  = note: somewhere\n",
+    );
+}
+
+#[test]
+fn test_warnings() {
+    let warnings = Warnings::new();
+    assert!(warnings.is_empty());
+    assert!(warnings.to_token_stream().is_empty());
+
+    warnings.warn(Span::call_site(), "first");
+    warnings.warn_spanned(quote!(old_option), "second");
+    assert_eq!(warnings.len(), 2);
+
+    let warning = |message: &str| {
+        format!(
+            concat!(
+                "{{ # [deprecated (note = {:?})] ",
+                "# [allow (non_upper_case_globals)] ",
+                "const warning : () = () ; ",
+                "let _ : () = warning ; }}",
+            ),
+            message,
+        )
+    };
+    assert_eq!(
+        warnings.to_token_stream().to_string(),
+        format!("const _ : () = {{ {} {} }} ;", warning("first"), warning("second")),
+    );
+}
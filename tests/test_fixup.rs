@@ -0,0 +1,148 @@
+extern crate quote;
+extern crate syn;
+extern crate walkdir;
+
+mod features;
+
+use quote::quote;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    parse_quote, Block, Expr, ExprBinary, ExprCall, ExprMethodCall, ExprReference, ExprUnary, File,
+    Stmt,
+};
+use walkdir::WalkDir;
+
+use syn::fixup::FixupParens;
+
+// Replaces every parenthesized expression by its contents.
+struct StripParens;
+
+impl VisitMut for StripParens {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        while let Expr::Paren(paren) = expr {
+            *expr = (*paren.expr).clone();
+        }
+        visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+fn fixup(mut expr: Expr) -> String {
+    expr.fixup_parens();
+    quote!(#expr).to_string()
+}
+
+#[test]
+fn test_precedence() {
+    let mut binary: ExprBinary = parse_quote!(a - b);
+    binary.right = Box::new(parse_quote!(c - d));
+    assert_eq!(fixup(Expr::Binary(binary.clone())), "a - (c - d)");
+    binary.left = Box::new(parse_quote!(x * y));
+    assert_eq!(fixup(Expr::Binary(binary)), "x * y - (c - d)");
+
+    let mut cast: ExprBinary = parse_quote!(a < b);
+    cast.left = Box::new(parse_quote!(a as u8));
+    assert_eq!(fixup(Expr::Binary(cast)), "(a as u8) < b");
+
+    let mut call: ExprCall = parse_quote!(f());
+    call.func = Box::new(parse_quote!(s.f));
+    assert_eq!(fixup(Expr::Call(call.clone())), "(s . f) ()");
+    call.func = Box::new(parse_quote!(s.0));
+    assert_eq!(fixup(Expr::Call(call)), "s . 0 ()");
+
+    // A cast at the end of the left operand of `<<` is taken as the start
+    // of generic arguments.
+    let mut shift: ExprBinary = parse_quote!(a << 3);
+    shift.left = Box::new(parse_quote!(a as u32 + b as u32));
+    assert_eq!(fixup(Expr::Binary(shift)), "(a as u32 + b as u32) << 3");
+
+    // Closures and jumps extend as far as the operand of a prefix operator
+    // anyway, but not as far as the operand of a binary operator.
+    let mut reference: ExprReference = parse_quote!(&x);
+    reference.expr = Box::new(parse_quote!(|a, b| a < b));
+    assert_eq!(
+        fixup(Expr::Reference(reference.clone())),
+        "& | a , b | a < b"
+    );
+    let mut binary: ExprBinary = parse_quote!(x + 1);
+    binary.left = Box::new(Expr::Reference(reference));
+    assert_eq!(fixup(Expr::Binary(binary)), "(& | a , b | a < b) + 1");
+    let mut unary: ExprUnary = parse_quote!(!x);
+    unary.expr = Box::new(parse_quote!(return));
+    assert_eq!(fixup(Expr::Unary(unary)), "! return");
+
+    let mut method: ExprMethodCall = parse_quote!(x.abs());
+    method.receiver = Box::new(parse_quote!(-x));
+    assert_eq!(fixup(Expr::MethodCall(method)), "(- x) . abs ()");
+
+    // Already correct trees are left alone.
+    let expr: Expr = parse_quote!(a = b + c * -d.e()?);
+    assert_eq!(fixup(expr), "a = b + c * - d . e () ?");
+}
+
+#[test]
+fn test_struct_in_head() {
+    let mut stmt: Stmt = parse_quote!(match x {});
+    if let Stmt::Expr(Expr::Match(expr)) = &mut stmt {
+        expr.expr = Box::new(parse_quote!(S { a }.b));
+    }
+    FixupParens.visit_stmt_mut(&mut stmt);
+    assert_eq!(quote!(#stmt).to_string(), "match (S { a } . b) { }");
+
+    let mut stmt: Stmt = parse_quote!(if let Some(x) = y {});
+    if let Stmt::Expr(Expr::If(expr)) = &mut stmt {
+        if let Expr::Let(cond) = &mut *expr.cond {
+            cond.expr = Box::new(parse_quote!(S { a }));
+        }
+    }
+    FixupParens.visit_stmt_mut(&mut stmt);
+    assert_eq!(quote!(#stmt).to_string(), "if let Some (x) = (S { a }) { }");
+}
+
+#[test]
+fn test_blocklike_stmt() {
+    let mut block: Block = parse_quote!({
+        a + 1;
+        a.len();
+    });
+    if let Stmt::Semi(Expr::Binary(expr), _) = &mut block.stmts[0] {
+        expr.left = Box::new(parse_quote!(match x {}));
+    }
+    if let Stmt::Semi(Expr::MethodCall(expr), _) = &mut block.stmts[1] {
+        expr.receiver = Box::new(parse_quote!(match x {}));
+    }
+    FixupParens.visit_block_mut(&mut block);
+    assert_eq!(
+        quote!(#block).to_string(),
+        "{ (match x { } + 1) ; match x { } . len () ; }",
+    );
+}
+
+// Real code has no missing parentheses. Removing all of them and putting back
+// the ones that are needed gives back the same syntax tree.
+#[test]
+fn test_roundtrip() {
+    for entry in WalkDir::new("src") {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        if path.extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let content = std::fs::read_to_string(path).unwrap();
+        let mut file: File = syn::parse_str(&content).unwrap();
+        let mut unchanged = file.clone();
+        FixupParens.visit_file_mut(&mut unchanged);
+        assert!(file == unchanged, "{} changed by fixup", path.display());
+
+        StripParens.visit_file_mut(&mut file);
+
+        let mut fixed = file.clone();
+        FixupParens.visit_file_mut(&mut fixed);
+        let tokens = quote!(#fixed).to_string();
+        let mut reparsed: File = match syn::parse_str(&tokens) {
+            Ok(reparsed) => reparsed,
+            Err(err) => panic!("failed to reparse {}: {}", path.display(), err),
+        };
+        StripParens.visit_file_mut(&mut reparsed);
+        assert!(file == reparsed, "{} changed in round trip", path.display());
+    }
+}
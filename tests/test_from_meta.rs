@@ -0,0 +1,89 @@
+extern crate syn;
+
+mod features;
+
+use syn::{parse_quote, Attribute, LitInt, LitStr, Path};
+
+syn::meta_args! {
+    struct Args {
+        name: LitStr,
+        rename: Option<String>,
+        skip: bool,
+        r#type: Option<Path>,
+        bounds: Vec<Path>,
+        inner: Option<Inner>,
+    }
+}
+
+syn::meta_args! {
+    struct Inner {
+        limit: LitInt,
+    }
+}
+
+fn errors(attr: Attribute) -> Vec<String> {
+    match attr.parse_meta_into::<Args>() {
+        Ok(_) => panic!("expected an error"),
+        Err(err) => err.into_iter().map(|err| err.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_parse_args() {
+    let attr: Attribute = parse_quote! {
+        #[tool(bounds(Clone, "std::fmt::Debug"), name = "n", type = "u8", inner(limit = 5))]
+    };
+    let args: Args = attr.parse_meta_into().unwrap();
+    assert_eq!(args.name.value(), "n");
+    assert_eq!(args.rename, None);
+    assert!(!args.skip);
+    assert!(args.r#type.unwrap().is_ident("u8"));
+    assert_eq!(args.bounds.len(), 2);
+    assert!(args.bounds[0].is_ident("Clone"));
+    assert_eq!(args.bounds[1].segments.len(), 3);
+    assert_eq!(args.inner.unwrap().limit.base10_digits(), "5");
+
+    let attr: Attribute = parse_quote!(#[tool(name = "n", skip = false, rename = "r")]);
+    let args: Args = attr.parse_meta_into().unwrap();
+    assert!(!args.skip);
+    assert_eq!(args.rename, Some("r".to_owned()));
+}
+
+#[test]
+fn test_missing() {
+    assert_eq!(errors(parse_quote!(#[tool])), ["missing argument `name`"]);
+    assert_eq!(errors(parse_quote!(#[tool()])), ["missing argument `name`"]);
+    assert_eq!(
+        errors(parse_quote!(#[tool(name = "n", inner)])),
+        ["missing argument `limit`"],
+    );
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        errors(parse_quote!(#[tool = "n"])),
+        ["expected `tool(...)`", "missing argument `name`"],
+    );
+    assert_eq!(
+        errors(parse_quote!(#[tool(name = "n", "lit", a::b, skip(true))])),
+        [
+            "expected argument name",
+            "unknown argument `a::b`, expected one of: `name`, `rename`, `skip`, `type`, `bounds`, `inner`",
+            "expected `skip` or `skip = true`",
+        ],
+    );
+    assert_eq!(
+        errors(parse_quote!(#[tool(name = 1, rename, bounds = "Clone", name = "n")])),
+        [
+            "expected string literal",
+            "expected a value for `rename`",
+            "expected `bounds(...)`",
+            "duplicate argument `name`",
+        ],
+    );
+    assert_eq!(
+        errors(parse_quote!(#[tool(name = "n", bounds(1, Clone, x = "y"))])),
+        ["expected path in a string literal", "expected path"],
+    );
+}
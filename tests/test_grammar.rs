@@ -0,0 +1,100 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::Span;
+use quote::{quote, ToTokens};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Expr, Ident, LitStr, Token, Type};
+
+mod kw {
+    syn::custom_keyword!(route);
+}
+
+syn::grammar! {
+    /// route GET ["/", "/index"] (handler, 2) => Response
+    struct Route {
+        route_token: kw::route,
+        method: Ident,
+        bracket_token: bracketed {
+            paths: Punctuated<LitStr, Token![,]>,
+        },
+        paren_token: parenthesized {
+            handler: Ident,
+            #[peek(Token![,])]
+            weight: Option<Weight>,
+        },
+        arrow_token: Option<Token![=>]>,
+        #[peek(Ident)]
+        response: Option<Type>,
+    }
+}
+
+syn::grammar! {
+    struct Weight {
+        comma_token: Token![,],
+        value: Expr,
+    }
+}
+
+#[test]
+fn test_parse() {
+    let route: Route =
+        syn::parse_str(r#"route GET ["/", "/index",] (handler, 2) => Response"#).unwrap();
+    assert_eq!(route.method, "GET");
+    assert_eq!(route.paths.len(), 2);
+    assert_eq!(route.handler, "handler");
+    assert!(route.weight.is_some());
+    assert!(route.arrow_token.is_some());
+    assert!(route.response.is_some());
+
+    let route: Route = syn::parse_str("route POST [] (handler)").unwrap();
+    assert!(route.paths.is_empty());
+    assert!(route.weight.is_none());
+    assert!(route.response.is_none());
+
+    let err = syn::parse_str::<Route>("route GET [] (handler, 2 3)")
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "unexpected token");
+    let err = syn::parse_str::<Route>("route GET (handler)")
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "expected square brackets");
+}
+
+#[test]
+fn test_print() {
+    let tokens = quote! {
+        route GET ["/", "/index"] (handler, 2 + 1) => Response
+    };
+    let route: Route = syn::parse2(tokens.clone()).unwrap();
+    assert_eq!(route.into_token_stream().to_string(), tokens.to_string());
+
+    let tokens = quote!(route GET [] (handler));
+    let route: Route = syn::parse2(tokens.clone()).unwrap();
+    assert_eq!(route.to_token_stream().to_string(), tokens.to_string());
+}
+
+#[test]
+fn test_spanned() {
+    let route = Route {
+        route_token: kw::route(Span::call_site()),
+        method: Ident::new("GET", Span::call_site()),
+        bracket_token: Default::default(),
+        paths: Punctuated::new(),
+        paren_token: Default::default(),
+        handler: Ident::new("handler", Span::call_site()),
+        weight: None,
+        arrow_token: None,
+        response: None,
+    };
+    let _ = route.span();
+    assert_eq!(
+        route.to_token_stream().to_string(),
+        quote!(route GET [] (handler)).to_string(),
+    );
+}
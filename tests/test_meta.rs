@@ -1,3 +1,4 @@
+extern crate quote;
 extern crate syn;
 
 mod features;
@@ -5,7 +6,11 @@ mod features;
 #[macro_use]
 mod macros;
 
-use syn::{Meta, MetaList, MetaNameValue, NestedMeta};
+use quote::quote;
+use syn::{
+    parse_quote, Attribute, Expr, Meta, MetaExpr, MetaList, MetaNameValue, MetaValue, NestedMeta,
+    NestedMetaExpr,
+};
 
 #[test]
 fn test_parse_meta_item_word() {
@@ -341,3 +346,101 @@ fn test_parse_nested_meta() {
    ⋮})
     "###);
 }
+
+#[test]
+fn test_parse_meta_expr() {
+    let input = "serde(default = path::to::func)";
+    snapshot!(input as MetaExpr, @r###"
+   ⋮MetaExpr::List {
+   ⋮    path: Path {
+   ⋮        segments: [
+   ⋮            PathSegment {
+   ⋮                ident: "serde",
+   ⋮                arguments: None,
+   ⋮            },
+   ⋮        ],
+   ⋮    },
+   ⋮    nested: [
+   ⋮        Meta(MetaExpr::NameValue {
+   ⋮            path: Path {
+   ⋮                segments: [
+   ⋮                    PathSegment {
+   ⋮                        ident: "default",
+   ⋮                        arguments: None,
+   ⋮                    },
+   ⋮                ],
+   ⋮            },
+   ⋮            value: Expr(Expr::Path {
+   ⋮                path: Path {
+   ⋮                    segments: [
+   ⋮                        PathSegment {
+   ⋮                            ident: "path",
+   ⋮                            arguments: None,
+   ⋮                        },
+   ⋮                        PathSegment {
+   ⋮                            ident: "to",
+   ⋮                            arguments: None,
+   ⋮                        },
+   ⋮                        PathSegment {
+   ⋮                            ident: "func",
+   ⋮                            arguments: None,
+   ⋮                        },
+   ⋮                    ],
+   ⋮                },
+   ⋮            }),
+   ⋮        }),
+   ⋮    ],
+   ⋮}
+    "###);
+}
+
+#[test]
+fn test_parse_meta_expr_values() {
+    let attr: Attribute = parse_quote! {
+        #[tool(a = 1 + 2, b = "lit", c = include_str!("x"), d = Vec<u8>, e(f = -1), "g")]
+    };
+    assert!(attr.parse_meta().is_err());
+
+    let list = match attr.parse_meta_expr().unwrap() {
+        MetaExpr::List(list) => list,
+        meta => panic!("expected MetaExpr::List, got {:?}", meta),
+    };
+    let values: Vec<&MetaValue> = list
+        .nested
+        .iter()
+        .filter_map(|nested| match nested {
+            NestedMetaExpr::Meta(MetaExpr::NameValue(nv)) => Some(&nv.value),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(values.len(), 4);
+    match values[0] {
+        MetaValue::Expr(Expr::Binary(_)) => {}
+        value => panic!("expected binary expression, got {:?}", value),
+    }
+    assert_eq!(values[1].lit(), Some(&parse_quote!("lit")));
+    match values[2] {
+        MetaValue::Expr(Expr::Macro(_)) => {}
+        value => panic!("expected macro expression, got {:?}", value),
+    }
+    match values[3] {
+        MetaValue::Verbatim(tokens) => assert_eq!(tokens.to_string(), "Vec < u8 >"),
+        value => panic!("expected verbatim tokens, got {:?}", value),
+    }
+    assert_eq!(
+        quote!(#list).to_string(),
+        quote!(tool(a = 1 + 2, b = "lit", c = include_str!("x"), d = Vec<u8>, e(f = -1), "g"))
+            .to_string(),
+    );
+
+    let attr: Attribute = parse_quote!(#[tool(a =)]);
+    let err = attr.parse_meta_expr().unwrap_err();
+    assert_eq!(err.to_string(), "unexpected end of input, expected a value");
+}
+
+#[test]
+fn test_meta_expr_from_meta() {
+    let meta: Meta = parse_quote!(tool(word, name = "value", 1));
+    let expected: MetaExpr = parse_quote!(tool(word, name = "value", 1));
+    assert_eq!(MetaExpr::from(meta), expected);
+}
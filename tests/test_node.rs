@@ -0,0 +1,108 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::Span;
+use quote::quote;
+use syn::node::{NodeMut, NodeRef};
+use syn::{parse_quote, DeriveInput, Expr, File, Ident};
+
+fn kinds(node: NodeRef) -> Vec<&'static str> {
+    node.children().map(|child| child.kind()).collect()
+}
+
+fn idents(node: NodeRef, idents: &mut Vec<String>) {
+    if let NodeRef::Ident(ident) = node {
+        idents.push(ident.to_string());
+    }
+    for child in node.children() {
+        self::idents(child, idents);
+    }
+}
+
+#[test]
+fn test_children() {
+    let expr: Expr = parse_quote!(a + f(1));
+    let node = NodeRef::from(&expr);
+    assert_eq!(node.kind(), "Expr");
+    assert_eq!(kinds(node), ["ExprBinary"]);
+
+    let binary = node.children().next().unwrap();
+    assert_eq!(kinds(binary), ["Expr", "BinOp", "Expr"]);
+    assert_eq!(binary.children().len(), 3);
+
+    let call = binary.children().last().unwrap().children().next().unwrap();
+    assert_eq!(kinds(call), ["Expr", "Expr"]);
+
+    let mut names = Vec::new();
+    idents(node, &mut names);
+    assert_eq!(names, ["a", "f"]);
+}
+
+#[test]
+fn test_walk_file() {
+    let file: File = parse_quote! {
+        #[derive(Debug)]
+        struct S<T> {
+            t: Option<T>,
+        }
+
+        impl<T> S<T> {
+            fn get(&self) -> &Option<T> {
+                &self.t
+            }
+        }
+    };
+
+    // The tokens of the attribute are not syntax tree nodes.
+    let mut names = Vec::new();
+    idents(NodeRef::from(&file), &mut names);
+    assert_eq!(
+        names,
+        [
+            "derive", "S", "T", "t", "Option", "T", "T", "S", "T", "get", "Option", "T", "self",
+            "t"
+        ],
+    );
+}
+
+#[test]
+fn test_to_tokens() {
+    let input: DeriveInput = parse_quote! {
+        pub struct S {
+            a: u8,
+        }
+    };
+    let data = NodeRef::from(&input.data);
+    assert_eq!(quote!(#data).to_string(), "struct { a : u8 , }");
+
+    let data_struct = data.children().next().unwrap();
+    assert_eq!(data_struct.kind(), "DataStruct");
+    let fields = data_struct.children().next().unwrap();
+    assert_eq!(fields.kind(), "Fields");
+    assert_eq!(quote!(#fields).to_string(), "{ a : u8 , }");
+}
+
+#[test]
+fn test_node_mut() {
+    fn rename(node: NodeMut) {
+        match node {
+            NodeMut::Ident(ident) => {
+                if ident == "a" {
+                    *ident = Ident::new("b", Span::call_site());
+                }
+            }
+            node => {
+                for child in node.children() {
+                    rename(child);
+                }
+            }
+        }
+    }
+
+    let mut expr: Expr = parse_quote!(a.a(|a| a + c));
+    rename(NodeMut::from(&mut expr));
+    assert_eq!(quote!(#expr).to_string(), "b . b (| b | b + c)");
+}
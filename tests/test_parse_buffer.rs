@@ -2,6 +2,7 @@
 extern crate syn;
 
 use syn::parse::{discouraged::Speculative, Parse, ParseStream, Parser, Result};
+use syn::{Expr, Ident, Type};
 
 #[test]
 #[should_panic(expected = "Fork was not derived from the advancing parse stream")]
@@ -53,3 +54,46 @@ fn smuggled_speculative_cursor_into_brackets() {
 
     syn::parse_str::<BreakRules>("()").unwrap();
 }
+
+#[test]
+fn attempt_restores_on_failure() {
+    let parser = |input: ParseStream| {
+        assert!(input.attempt(|input| input.parse::<Token![fn]>()).is_err());
+        let ident: Ident = input.attempt(Ident::parse)?;
+        assert_eq!(ident, "a");
+        input.parse::<Token![+]>()?;
+        input.parse::<Ident>()
+    };
+    assert_eq!(parser.parse_str("a + b").unwrap(), "b");
+}
+
+#[test]
+fn alternatives_error_from_furthest() {
+    fn parse_arg(input: ParseStream) -> Result<&'static str> {
+        input.alternatives(&[
+            |input| {
+                input.parse::<Type>()?;
+                input.parse::<Token![;]>()?;
+                Ok("type")
+            },
+            |input| {
+                input.parse::<Expr>()?;
+                input.parse::<Token![;]>()?;
+                Ok("expr")
+            },
+        ])
+    }
+
+    assert_eq!(parse_arg.parse_str("Vec<u8>;").unwrap(), "type");
+    assert_eq!(parse_arg.parse_str("1 + 1;").unwrap(), "expr");
+
+    // The expression gets further than the type, which stops at `1`.
+    let err = parse_arg.parse_str("a + 1 c").err().unwrap();
+    assert_eq!(err.to_string(), "expected `;`");
+
+    // Both alternatives fail at the first token, so both errors are kept.
+    let err = parse_arg.parse_str(";").err().unwrap();
+    let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[1], "expected expression");
+}
@@ -0,0 +1,155 @@
+extern crate proc_macro2;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::TokenStream;
+use syn::buffer::Cursor;
+use syn::parse::{node, Parse, ParseStream, Parser, PeekNode, Result};
+use syn::{Expr, Ident, Item, Pat, Token, Type};
+
+fn peek<T: PeekNode>(input: &str) -> bool {
+    let parser = |input: ParseStream| {
+        let peeked = input.peek(node::<T>);
+        input.parse::<TokenStream>()?;
+        Ok(peeked)
+    };
+    parser.parse_str(input).unwrap()
+}
+
+#[test]
+fn test_builtin() {
+    for input in &[
+        "u8",
+        "&'a str",
+        "[T; 4]",
+        "fn()",
+        "dyn Trait",
+        "impl Trait",
+        "<T as Trait>::U",
+        "!",
+        "_",
+        "(A, B)",
+    ] {
+        assert!(peek::<Type>(input), "{}", input);
+        assert!(syn::parse_str::<Type>(input).is_ok(), "{}", input);
+    }
+    for input in &["= u8", "1", "+ Send", "?Sized"] {
+        assert!(!peek::<Type>(input), "{}", input);
+    }
+
+    for input in &[
+        "x",
+        "1",
+        "-x",
+        "!x",
+        "&mut x",
+        "|x| x",
+        "..",
+        "if x {}",
+        "'a: loop {}",
+        "match x {}",
+        "S { x }",
+        "[1, 2]",
+    ] {
+        assert!(peek::<Expr>(input), "{}", input);
+        assert!(syn::parse_str::<Expr>(input).is_ok(), "{}", input);
+    }
+    for input in &["=> x", "+ 1", ";", "struct S;"] {
+        assert!(!peek::<Expr>(input), "{}", input);
+    }
+
+    for input in &[
+        "x",
+        "_",
+        "ref mut x",
+        "&(a, b)",
+        "Some(x)",
+        "-1",
+        "1..=2",
+        "[a, ..]",
+    ] {
+        assert!(peek::<Pat>(input), "{}", input);
+    }
+    for input in &["=> x", "+ 1", "|"] {
+        assert!(!peek::<Pat>(input), "{}", input);
+    }
+
+    for input in &[
+        "fn f() {}",
+        "pub struct S;",
+        "#[a] const X: u8 = 0;",
+        "union U {}",
+        "auto trait T {}",
+        "default impl T for S {}",
+        "thread_local! {}",
+        "std::thread_local! {}",
+        "macro_rules! m {}",
+    ] {
+        assert!(peek::<Item>(input), "{}", input);
+        assert!(syn::parse_str::<Item>(input).is_ok(), "{}", input);
+    }
+    for input in &["x", "x + 1", "union", "a::b", "a != b", "let x = 1;"] {
+        assert!(!peek::<Item>(input), "{}", input);
+    }
+}
+
+// `< $ident >`
+struct Placeholder;
+
+impl Parse for Placeholder {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<Token![<]>()?;
+        input.parse::<Ident>()?;
+        input.parse::<Token![>]>()?;
+        Ok(Placeholder)
+    }
+}
+
+impl PeekNode for Placeholder {
+    fn peek(cursor: Cursor) -> bool {
+        match cursor.punct() {
+            Some((punct, rest)) => punct.as_char() == '<' && rest.ident().is_some(),
+            None => false,
+        }
+    }
+
+    fn display() -> &'static str {
+        "placeholder"
+    }
+}
+
+#[test]
+fn test_lookahead() {
+    let parser = |input: ParseStream| -> Result<&'static str> {
+        let lookahead = input.lookahead1();
+        let kind = if lookahead.peek(node::<Placeholder>) {
+            "placeholder"
+        } else if lookahead.peek(Token![;]) {
+            "semi"
+        } else if lookahead.peek(node::<Expr>) {
+            "expr"
+        } else {
+            return Err(lookahead.error());
+        };
+        input.parse::<TokenStream>()?;
+        Ok(kind)
+    };
+    assert_eq!(parser.parse_str("<x>").unwrap(), "placeholder");
+    assert_eq!(parser.parse_str("<[u8]>::len").unwrap(), "expr");
+    assert_eq!(parser.parse_str(";").unwrap(), "semi");
+    let err = parser.parse_str("=> x").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected one of: placeholder, `;`, expression",
+    );
+
+    let parser = |input: ParseStream| -> Result<bool> {
+        input.parse::<Token![let]>()?;
+        let peeked = input.peek3(node::<Type>);
+        input.parse::<TokenStream>()?;
+        Ok(peeked)
+    };
+    assert!(parser.parse_str("let x: u8").unwrap());
+    assert!(!parser.parse_str("let x = 1").unwrap());
+}
@@ -0,0 +1,101 @@
+extern crate proc_macro2;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use syn::parse::{node, peek_group, ParseStream, Parser, Result};
+use syn::{token, Ident, Lifetime, Token, Type};
+
+fn peek<F: Fn(ParseStream) -> bool>(input: &str, f: F) -> bool {
+    let parser = |input: ParseStream| -> Result<bool> {
+        let peeked = f(input);
+        input.parse::<TokenStream>()?;
+        Ok(peeked)
+    };
+    parser.parse_str(input).unwrap()
+}
+
+#[test]
+fn test_peek_sequence() {
+    let crate_async_fn = |input: ParseStream| {
+        input.peek_sequence((
+            Token![pub],
+            peek_group(token::Paren, (Token![crate],)),
+            Token![async],
+            Token![fn],
+        ))
+    };
+    assert!(peek("pub(crate) async fn f() {}", crate_async_fn));
+    assert!(!peek("pub(super) async fn f() {}", crate_async_fn));
+    assert!(!peek("pub[crate] async fn f() {}", crate_async_fn));
+    assert!(!peek("pub(crate) fn f() {}", crate_async_fn));
+
+    // Multi-character punctuation is matched as a single element.
+    let path = |input: ParseStream| input.peek_sequence((Ident, Token![::], Ident, Token![<]));
+    assert!(peek("std::vec::Vec", |input| input.peek_sequence((
+        Ident,
+        Token![::],
+        Ident
+    ))));
+    assert!(peek("a::b<T>", path));
+    assert!(!peek("a:b<T>", path));
+    assert!(!peek("a::b", path));
+
+    // Group content only needs to match as a prefix, and nodes are allowed.
+    let group = |input: ParseStream| {
+        input.peek_sequence((
+            Lifetime,
+            peek_group(token::Brace, (Ident, Token![:], node::<Type>)),
+        ))
+    };
+    assert!(peek("'a { x: Vec<u8>, y: u8 }", group));
+    assert!(!peek("'a { x: = }", group));
+    let typed = |input: ParseStream| input.peek_sequence((node::<Type>, Token![=]));
+    assert!(peek("Vec<u8> = 1", typed));
+    assert!(peek("(u8, [u8; 4]) = 1", typed));
+    assert!(!peek("Vec<u8> 1", typed));
+    assert!(!peek("= 1", typed));
+    assert!(peek("{}", |input| input
+        .peek_sequence(peek_group(token::Brace, ()))));
+    assert!(peek("", |input| input.peek_sequence(())));
+}
+
+#[test]
+fn test_none_group() {
+    // `pub crate fn ()`, with `crate fn` inside a None-delimited group as produced
+    // by macro_rules metavariable substitution.
+    let tokens: TokenStream = vec![
+        TokenTree::Ident(Ident::new("pub", Span::call_site())),
+        TokenTree::Group(Group::new(Delimiter::None, "crate fn".parse().unwrap())),
+        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+    ]
+    .into_iter()
+    .collect();
+
+    let parser = |input: ParseStream| -> Result<()> {
+        assert!(input.peek_sequence((Token![pub], Token![crate], Token![fn])));
+        assert!(input.peek_sequence((Token![pub], Token![crate], Token![fn], token::Paren)));
+        // Peeking the group itself skips past all of its contents.
+        assert!(input.peek_sequence((Token![pub], token::Group, token::Paren)));
+        assert!(!input.peek_sequence((Token![pub], token::Group, Token![fn])));
+        // Like peek2, peek_nth skips a None-delimited group as a whole.
+        assert!(input.peek_nth(1, Token![crate]));
+        assert!(input.peek_nth(2, token::Paren));
+        input.parse::<TokenStream>()?;
+        Ok(())
+    };
+    parser.parse2(tokens).unwrap();
+}
+
+#[test]
+fn test_peek_nth() {
+    let tokens = "'a struct S<T> (u8);";
+    assert!(peek(tokens, |input| input.peek_nth(0, Lifetime)));
+    assert!(peek(tokens, |input| input.peek_nth(1, Token![struct])));
+    assert!(peek(tokens, |input| input.peek_nth(3, Token![<])));
+    assert!(peek(tokens, |input| input.peek_nth(6, token::Paren)));
+    assert!(peek(tokens, |input| input.peek_nth(7, Token![;])));
+    assert!(!peek(tokens, |input| input.peek_nth(8, Token![;])));
+    assert!(!peek(tokens, |input| input.peek_nth(100, Ident)));
+}
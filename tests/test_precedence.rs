@@ -13,6 +13,10 @@
 //! 4. Respan all of the expressions, replacing the spans with the default
 //!    spans.
 //! 5. Compare the expressions with one another, if they are not equal fail.
+//!
+//! The same is done for the expression with all of its parentheses removed and
+//! the needed ones put back by `syn::fixup::FixupParens`, which must also leave
+//! the original expression unchanged.
 
 extern crate quote;
 extern crate rayon;
@@ -40,6 +44,8 @@ use std::io::Read;
 use std::process;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+use syn::visit_mut::VisitMut;
+
 use common::eq::SpanlessEq;
 use common::parse;
 
@@ -74,12 +80,11 @@ fn test_simple_precedence() {
         };
 
         let pf = match test_expressions(vec![expr]) {
-            (1, 0) => "passed",
-            (0, 1) => {
+            (_, 0) => "passed",
+            _ => {
                 failed += 1;
                 "failed"
             }
-            _ => unreachable!(),
         };
         errorf!("=== {}: {}\n", input, pf);
     }
@@ -175,30 +180,22 @@ fn test_expressions(exprs: Vec<syn::Expr>) -> (usize, usize) {
 
     syntax::with_globals(Edition::Edition2018, || {
         for expr in exprs {
-            let raw = quote!(#expr).to_string();
-
-            let libsyntax_ast = if let Some(e) = libsyntax_parse_and_rewrite(&raw) {
-                e
-            } else {
-                failed += 1;
-                errorf!("\nFAIL - libsyntax failed to parse raw\n");
-                continue;
-            };
-
-            let syn_expr = syn_brackets(expr);
-            let syn_ast = if let Some(e) = parse::libsyntax_expr(&quote!(#syn_expr).to_string()) {
-                e
-            } else {
+            let mut fixed = expr.clone();
+            fixed.fixup_parens();
+            if fixed != expr {
                 failed += 1;
-                errorf!("\nFAIL - libsyntax failed to parse bracketed\n");
+                errorf!("\nFAIL - fixup changed {}\n", quote!(#expr));
                 continue;
-            };
-
-            if SpanlessEq::eq(&syn_ast, &libsyntax_ast) {
-                passed += 1;
-            } else {
-                failed += 1;
-                errorf!("\nFAIL\n{:?}\n!=\n{:?}\n", syn_ast, libsyntax_ast);
+            }
+            StripParens.visit_expr_mut(&mut fixed);
+            fixed.fixup_parens();
+
+            for expr in vec![expr, fixed] {
+                if test_expression(expr) {
+                    passed += 1;
+                } else {
+                    failed += 1;
+                }
             }
         }
     });
@@ -206,6 +203,32 @@ fn test_expressions(exprs: Vec<syn::Expr>) -> (usize, usize) {
     (passed, failed)
 }
 
+fn test_expression(expr: syn::Expr) -> bool {
+    let raw = quote!(#expr).to_string();
+
+    let libsyntax_ast = if let Some(e) = libsyntax_parse_and_rewrite(&raw) {
+        e
+    } else {
+        errorf!("\nFAIL - libsyntax failed to parse raw\n");
+        return false;
+    };
+
+    let syn_expr = syn_brackets(expr);
+    let syn_ast = if let Some(e) = parse::libsyntax_expr(&quote!(#syn_expr).to_string()) {
+        e
+    } else {
+        errorf!("\nFAIL - libsyntax failed to parse bracketed\n");
+        return false;
+    };
+
+    if SpanlessEq::eq(&syn_ast, &libsyntax_ast) {
+        true
+    } else {
+        errorf!("\nFAIL\n{:?}\n!=\n{:?}\n", syn_ast, libsyntax_ast);
+        false
+    }
+}
+
 fn libsyntax_parse_and_rewrite(input: &str) -> Option<P<ast::Expr>> {
     parse::libsyntax_expr(input).and_then(libsyntax_brackets)
 }
@@ -350,6 +373,18 @@ fn syn_brackets(syn_expr: syn::Expr) -> syn::Expr {
     folder.fold_expr(syn_expr)
 }
 
+/// Replaces every parenthesized expression by its contents.
+struct StripParens;
+
+impl VisitMut for StripParens {
+    fn visit_expr_mut(&mut self, expr: &mut syn::Expr) {
+        while let syn::Expr::Paren(paren) = expr {
+            *expr = (*paren.expr).clone();
+        }
+        syn::visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
 /// Walk through a crate collecting all expressions we can find in it.
 fn collect_exprs(file: syn::File) -> Vec<syn::Expr> {
     use syn::fold::*;
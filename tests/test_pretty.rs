@@ -0,0 +1,327 @@
+extern crate proc_macro2;
+extern crate syn;
+extern crate walkdir;
+
+mod features;
+
+use proc_macro2::{TokenStream, TokenTree};
+use syn::equiv::Equivalence;
+use syn::print::{pretty_expr, pretty_file, pretty_item};
+use syn::{parse_quote, Expr, ExprBinary, ExprMethodCall, File, Item, Stmt};
+use walkdir::WalkDir;
+
+#[test]
+fn test_idempotent_own_source() {
+    let equiv = Equivalence::new().ignore_trailing_punct(true);
+    for entry in WalkDir::new("src").into_iter().chain(WalkDir::new("tests")) {
+        let entry = entry.unwrap();
+        if entry.path().extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let text = std::fs::read_to_string(entry.path()).unwrap();
+        let file = match syn::parse_file(&text) {
+            Ok(file) => file,
+            Err(_) => continue,
+        };
+        let once = pretty_file(&file);
+        let reparsed = match syn::parse_file(&once) {
+            Ok(file) => file,
+            Err(err) => panic!("failed to reparse {}: {}", entry.path().display(), err),
+        };
+        assert!(
+            equiv.equal(&reparsed, &file),
+            "pretty printing changes the syntax tree of {}",
+            entry.path().display(),
+        );
+        assert!(
+            pretty_file(&reparsed) == once,
+            "pretty printing is not idempotent for {}",
+            entry.path().display(),
+        );
+    }
+}
+
+#[test]
+fn test_binary_parens() {
+    let mut expr: ExprBinary = parse_quote!(a * c);
+    *expr.left = parse_quote!(a + b);
+    assert_eq!(pretty_expr(&Expr::Binary(expr)), "(a + b) * c");
+
+    let mut expr: ExprBinary = parse_quote!(a - c);
+    *expr.right = parse_quote!(b - c);
+    assert_eq!(pretty_expr(&Expr::Binary(expr)), "a - (b - c)");
+
+    let mut expr: ExprBinary = parse_quote!(x < y);
+    *expr.left = parse_quote!(a as usize);
+    assert_eq!(pretty_expr(&Expr::Binary(expr)), "(a as usize) < y");
+}
+
+#[test]
+fn test_prefix_and_postfix_parens() {
+    let mut expr: ExprMethodCall = parse_quote!(x.len());
+    *expr.receiver = parse_quote!(-x);
+    assert_eq!(pretty_expr(&Expr::MethodCall(expr)), "(-x).len()");
+
+    let expr: Expr = parse_quote!((self.f)(0));
+    assert_eq!(pretty_expr(&expr), "(self.f)(0)");
+    let expr: Expr = parse_quote!(self.0(0));
+    assert_eq!(pretty_expr(&expr), "self.0(0)");
+
+    let expr: Expr = parse_quote!(f(&|a, b| a < b, !return));
+    assert_eq!(pretty_expr(&expr), "f(&|a, b| a < b, !return)");
+}
+
+#[test]
+fn test_struct_literal_in_condition() {
+    let mut item: Item = parse_quote! {
+        fn f() {
+            if cond {}
+        }
+    };
+    if let Item::Fn(item) = &mut item {
+        if let Stmt::Expr(Expr::If(expr)) = &mut item.block.stmts[0] {
+            *expr.cond = parse_quote!(x == S { a: 0 });
+        }
+    }
+    assert_eq!(
+        pretty_item(&item),
+        "fn f() {\n    if (x == S { a: 0 }) {}\n}\n",
+    );
+}
+
+#[test]
+fn test_block_like_statement() {
+    let mut item: Item = parse_quote! {
+        fn f() {
+            x.len();
+        }
+    };
+    if let Item::Fn(item) = &mut item {
+        if let Stmt::Semi(Expr::MethodCall(expr), _) = &mut item.block.stmts[0] {
+            *expr.receiver = parse_quote!(match x {});
+        }
+    }
+    assert_eq!(
+        pretty_item(&item),
+        "fn f() {\n    match x {}.len();\n}\n",
+    );
+}
+
+#[test]
+fn test_layout() {
+    let file: File = parse_quote! {
+        #![allow(dead_code)]
+        /// Documented.
+        pub struct S<T> where T: Clone { pub first: T, second: Vec<T> }
+        impl<T> S<T> where T: Clone {
+            fn new(first: T) -> Self { S { first, second: vec![] } }
+        }
+        fn call() {
+            let result = function_with_a_long_name(first_argument_value, second_argument_value, third_argument);
+        }
+    };
+    let expected = "\
+#![allow(dead_code)]
+
+/// Documented.
+pub struct S<T>
+where
+    T: Clone,
+{
+    pub first: T,
+    second: Vec<T>,
+}
+
+impl<T> S<T>
+where
+    T: Clone,
+{
+    fn new(first: T) -> Self {
+        S { first, second: vec![] }
+    }
+}
+
+fn call() {
+    let result = function_with_a_long_name(
+        first_argument_value,
+        second_argument_value,
+        third_argument,
+    );
+}
+";
+    assert_eq!(pretty_file(&file), expected);
+}
+
+#[test]
+fn test_macro_tokens() {
+    let file: File = parse_quote! {
+        macro_rules! m {
+            ($first:expr, $second_argument:ident, $third_argument:ty) => { let x: $third_argument = $first; };
+        }
+        fn f() {
+            my_macro!(first_argument_value, second_argument_value, third_argument_value, fourth_argument, fifth);
+            my_macro!(vec![a, b,], c,);
+        }
+    };
+    let expected = "\
+macro_rules! m {
+    ($first: expr, $second_argument: ident, $third_argument: ty) => {
+        let x: $third_argument = $first;
+    };
+}
+
+fn f() {
+    my_macro!(
+        first_argument_value,
+        second_argument_value,
+        third_argument_value,
+        fourth_argument,
+        fifth
+    );
+    my_macro!(vec![a, b,], c,);
+}
+";
+    assert_eq!(pretty_file(&file), expected);
+}
+
+#[test]
+fn test_macro_joint_punct() {
+    let inputs = &[
+        "matches!(c, 'a'..='b' | 'c')",
+        "m!(x ..= 'b'; y => z; a..=b)",
+        "m!(x += 1 ; f() -> T ; <<= 'a: loop {} ; x != ! y)",
+        "m!(a..=[b] | c::<d>=>(e); #[f]=>g)",
+    ];
+    for input in inputs {
+        let expr: Expr = syn::parse_str(input).unwrap();
+        let printed = pretty_expr(&expr);
+        let reparsed: Expr = syn::parse_str(&printed).unwrap();
+        let tokens = |expr: &Expr| match expr {
+            Expr::Macro(expr) => token_strings(expr.mac.tokens.clone()),
+            _ => unreachable!(),
+        };
+        assert_eq!(tokens(&reparsed), tokens(&expr), "{}", printed);
+    }
+}
+
+// The tokens of a stream without their spacing, with groups flattened.
+fn token_strings(tokens: TokenStream) -> Vec<String> {
+    let mut strings = Vec::new();
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(group) => {
+                strings.push(format!("{:?}", group.delimiter()));
+                strings.extend(token_strings(group.stream()));
+                strings.push("end".to_owned());
+            }
+            TokenTree::Punct(punct) => strings.push(punct.as_char().to_string()),
+            tt => strings.push(tt.to_string()),
+        }
+    }
+    strings
+}
+
+#[test]
+fn test_inline_doc_comments() {
+    let file: File = syn::parse_str(
+        "
+        enum E {
+            A {
+                /// Doc.
+                a: u8,
+            },
+            B(
+                /// Doc.
+                u8,
+            ),
+        }
+        fn f() {
+            S {
+                /// Doc.
+                a: 1,
+            };
+        }
+        ",
+    )
+    .unwrap();
+    let expected = r#"enum E {
+    A { #[doc = " Doc."] a: u8 },
+    B(#[doc = " Doc."] u8),
+}
+
+fn f() {
+    S { #[doc = " Doc."] a: 1 };
+}
+"#;
+    let printed = pretty_file(&file);
+    assert_eq!(printed, expected);
+    let reparsed = syn::parse_file(&printed).unwrap();
+    let equiv = Equivalence::new().ignore_trailing_punct(true);
+    assert!(equiv.equal(&reparsed, &file));
+}
+
+#[test]
+fn test_variadic() {
+    let file: File = syn::parse_str(
+        "
+        extern \"C\" {
+            fn printf(format: *const c_char, ...) -> c_int;
+            fn mremap(address: *mut c_void, old_size: size_t, new_size: size_t, flags: c_int, ...) -> *mut c_void;
+        }
+        ",
+    )
+    .unwrap();
+    let expected = r#"extern "C" {
+    fn printf(format: *const c_char, ...) -> c_int;
+
+    fn mremap(
+        address: *mut c_void,
+        old_size: size_t,
+        new_size: size_t,
+        flags: c_int,
+        ...
+    ) -> *mut c_void;
+}
+"#;
+    let printed = pretty_file(&file);
+    assert_eq!(printed, expected);
+    let reparsed = syn::parse_file(&printed).unwrap();
+    assert!(Equivalence::new().equal(&reparsed, &file));
+}
+
+#[test]
+fn test_doc_trailing_whitespace() {
+    let file: File = syn::parse_str("/// Doc.\n/// \nstruct S;\n").unwrap();
+    let expected = "/// Doc.\n#[doc = \" \"]\nstruct S;\n";
+    let printed = pretty_file(&file);
+    assert_eq!(printed, expected);
+    let reparsed = syn::parse_file(&printed).unwrap();
+    assert!(Equivalence::new().equal(&reparsed, &file));
+}
+
+#[test]
+fn test_arm_commas() {
+    let item: Item = parse_quote! {
+        fn f() {
+            match x {
+                0 => {},
+                1 => {}
+                2 => a,
+                _ => b
+            }
+        }
+    };
+    let expected = "fn f() {
+    match x {
+        0 => {},
+        1 => {}
+        2 => a,
+        _ => b
+    }
+}
+";
+    let printed = pretty_item(&item);
+    assert_eq!(printed, expected);
+    let reparsed: Item = syn::parse_str(&printed).unwrap();
+    assert!(Equivalence::new().equal(&reparsed, &item));
+}
@@ -0,0 +1,118 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::{quote, ToTokens};
+use syn::query::{Match, Pattern};
+use syn::{Expr, File, Pat, Stmt, Type};
+
+// Renders each match as the tokens of its bindings, like `x = m`.
+fn bindings(m: &Match) -> Vec<String> {
+    m.bindings()
+        .iter()
+        .map(|binding| format!("{} = {}", binding.name(), binding.node().to_token_stream()))
+        .collect()
+}
+
+#[test]
+fn test_find_all() {
+    let pattern: Pattern<Expr> = "$x.lock().unwrap()".parse().unwrap();
+    let file: File = syn::parse_str(
+        "
+        fn f(m: &Mutex<u8>) {
+            let a = *m.lock().unwrap();
+            let b = *self.state.lock().unwrap();
+            let c = m.lock();
+            let d = m.unwrap();
+            let e = m.lock().expect(\"poisoned\");
+        }
+        ",
+    )
+    .unwrap();
+
+    let matches = pattern.find_all(&file);
+    let found: Vec<_> = matches.iter().map(bindings).collect();
+    assert_eq!(found, [["x = m"], ["x = self . state"]]);
+
+    let m = &matches[1];
+    assert_eq!(m.node().kind(), "Expr");
+    assert_eq!(
+        m.node().to_token_stream().to_string(),
+        "self . state . lock () . unwrap ()",
+    );
+    let span = m.bindings()[0].span();
+    assert_eq!(span.start().line, 4);
+    assert_eq!(span.start().column, 21);
+    assert_eq!(m.span().end().column, 47);
+}
+
+#[test]
+fn test_nested() {
+    let pattern: Pattern<Expr> = "$x.unwrap()".parse().unwrap();
+    let expr: Expr = syn::parse_str("a.unwrap().b.unwrap()").unwrap();
+    let found: Vec<_> = pattern.find_all(&expr).iter().map(bindings).collect();
+    assert_eq!(found, [["x = a . unwrap () . b"], ["x = a"]]);
+}
+
+#[test]
+fn test_ident_metavar() {
+    let pattern: Pattern<Expr> = "$recv.$method($_)".parse().unwrap();
+    let expr: Expr = syn::parse_str("v.push(1 + 2)").unwrap();
+    let m = pattern.matches(&expr).unwrap();
+    assert_eq!(bindings(&m), ["recv = v", "method = push"]);
+    assert_eq!(m.get("method").unwrap().kind(), "Ident");
+
+    // The method call has to have exactly one argument.
+    let expr: Expr = syn::parse_str("v.insert(0, 1)").unwrap();
+    assert!(pattern.matches(&expr).is_none());
+}
+
+#[test]
+fn test_repeated_metavar() {
+    let pattern: Pattern<Expr> = "$x == $x".parse().unwrap();
+    let same: Expr = syn::parse_str("a.b == a . b").unwrap();
+    let different: Expr = syn::parse_str("a.b == a.c").unwrap();
+    assert_eq!(bindings(&pattern.matches(&same).unwrap()), ["x = a . b"]);
+    assert!(pattern.matches(&different).is_none());
+
+    // Trailing punctuation is ignored.
+    let trailing: Expr = syn::parse_str("f(a, b,) == f(a, b)").unwrap();
+    assert!(pattern.matches(&trailing).is_some());
+    let tuple: Expr = syn::parse_str("(a,) == (a)").unwrap();
+    assert!(pattern.matches(&tuple).is_none());
+}
+
+#[test]
+fn test_types_and_pats() {
+    let pattern: Pattern<Type> = "Option<Box<$t>>".parse().unwrap();
+    let file: File = syn::parse_str(
+        "struct S { a: Option<Box<dyn Fn()>>, b: Option<u8>, c: Vec<Option<Box<S>>> }",
+    )
+    .unwrap();
+    let found: Vec<_> = pattern.find_all(&file).iter().map(bindings).collect();
+    assert_eq!(found, [["t = dyn Fn ()"], ["t = S"]]);
+
+    let pattern: Pattern<Pat> = "Some(($a, $_))".parse().unwrap();
+    let stmt: Stmt = syn::parse_str("if let Some((k, v)) = it.next() {}").unwrap();
+    let found: Vec<_> = pattern.find_all(&stmt).iter().map(bindings).collect();
+    assert_eq!(found, [["a = k"]]);
+}
+
+#[test]
+fn test_new() {
+    let pattern: Pattern<Expr> = Pattern::new(quote!(mem::replace($dest, $src))).unwrap();
+    let expr: Expr = syn::parse_str("mem::replace(&mut self.buf, Vec::new())").unwrap();
+    let m = pattern.matches(&expr).unwrap();
+    assert_eq!(
+        bindings(&m),
+        ["dest = & mut self . buf", "src = Vec :: new ()"],
+    );
+
+    let err = "$.lock()".parse::<Pattern<Expr>>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected the name of a metavariable after `$`"
+    );
+}
@@ -0,0 +1,131 @@
+extern crate syn;
+
+mod features;
+
+use syn::{ImplItem, Item, Stmt};
+
+#[test]
+fn test_no_errors() {
+    let code = "fn f() {} struct S;";
+    let (file, error) = syn::parse_file_recovering(code);
+    assert!(error.is_none());
+    assert_eq!(file, syn::parse_file(code).unwrap());
+}
+
+#[test]
+fn test_broken_item() {
+    let code = "
+        fn f() {}
+        struct S { a: }
+        #[derive(Debug)]
+        enum E { A }
+    ";
+    let (file, error) = syn::parse_file_recovering(code);
+    assert_eq!(file.items.len(), 3);
+    assert!(match &file.items[0] {
+        Item::Fn(item) => item.sig.ident == "f",
+        _ => false,
+    });
+    assert!(match &file.items[1] {
+        Item::Verbatim(tokens) => tokens.to_string().starts_with("struct S"),
+        _ => false,
+    });
+    assert!(match &file.items[2] {
+        Item::Enum(item) => item.ident == "E" && item.attrs.len() == 1,
+        _ => false,
+    });
+    assert_eq!(error.unwrap().into_iter().count(), 1);
+}
+
+#[test]
+fn test_broken_item_with_attrs() {
+    let code = "#[derive(Debug)] #[repr(C)] struct Broken { a: } fn ok() {}";
+    let (file, error) = syn::parse_file_recovering(code);
+    assert_eq!(file.items.len(), 2);
+    assert!(match &file.items[0] {
+        Item::Verbatim(tokens) => tokens
+            .to_string()
+            .starts_with("# [derive (Debug)] # [repr (C)]"),
+        _ => false,
+    });
+    assert!(match &file.items[1] {
+        Item::Fn(item) => item.sig.ident == "ok",
+        _ => false,
+    });
+    assert_eq!(error.unwrap().into_iter().count(), 1);
+}
+
+#[test]
+fn test_broken_statements() {
+    let code = "
+        fn f() {
+            let a = 1;
+            let b = ;
+            a + b;
+            call(1 2);
+            a
+        }
+    ";
+    let (file, error) = syn::parse_file_recovering(code);
+    let stmts = match &file.items[0] {
+        Item::Fn(item) => &item.block.stmts,
+        _ => panic!("expected fn"),
+    };
+    assert_eq!(stmts.len(), 5);
+    assert!(match &stmts[0] {
+        Stmt::Local(_) => true,
+        _ => false,
+    });
+    assert!(match &stmts[1] {
+        Stmt::Item(Item::Verbatim(_)) => true,
+        _ => false,
+    });
+    assert!(match &stmts[2] {
+        Stmt::Semi(..) => true,
+        _ => false,
+    });
+    assert!(match &stmts[3] {
+        Stmt::Item(Item::Verbatim(_)) => true,
+        _ => false,
+    });
+    assert!(match &stmts[4] {
+        Stmt::Expr(_) => true,
+        _ => false,
+    });
+    assert_eq!(error.unwrap().into_iter().count(), 2);
+}
+
+#[test]
+fn test_broken_method() {
+    let code = "
+        impl S {
+            fn ok(&self) {}
+            fn broken(&self) {
+                self.0 +;
+            }
+            const C: = 1;
+        }
+    ";
+    let (file, error) = syn::parse_file_recovering(code);
+    let items = match &file.items[0] {
+        Item::Impl(item) => &item.items,
+        _ => panic!("expected impl"),
+    };
+    assert_eq!(items.len(), 3);
+    assert!(match &items[1] {
+        ImplItem::Method(method) => method.block.stmts.len() == 1,
+        _ => false,
+    });
+    assert!(match &items[2] {
+        ImplItem::Verbatim(_) => true,
+        _ => false,
+    });
+    assert_eq!(error.unwrap().into_iter().count(), 2);
+}
+
+#[test]
+fn test_lex_error() {
+    let (file, error) = syn::parse_file_recovering("fn f() { \"unterminated }");
+    assert!(file.items.is_empty());
+    assert!(error.is_some());
+}
@@ -0,0 +1,225 @@
+extern crate proc_macro2;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::Span;
+use syn::scope::{self, Resolution};
+use syn::visit_mut::VisitMut;
+use syn::{parse_quote, Block, ExprPath, Ident, ItemFn, PatIdent};
+
+// The nth binding of the given name.
+fn binding<'ast>(resolution: &Resolution<'ast>, name: &str, n: usize) -> &'ast PatIdent {
+    resolution
+        .bindings()
+        .filter(|binding| binding.ident == name)
+        .nth(n)
+        .unwrap()
+}
+
+// Renders each use of a variable as the name and the index, among bindings of
+// that name, of the binding it refers to, like `x#1`, or `x?` if unresolved.
+fn uses(resolution: &Resolution) -> Vec<String> {
+    let mut uses: Vec<(&ExprPath, String)> = Vec::new();
+    for binding in resolution.bindings() {
+        let n = resolution
+            .bindings()
+            .filter(|other| other.ident == binding.ident)
+            .position(|other| std::ptr::eq(other, binding))
+            .unwrap();
+        for use_ in resolution.uses_of(binding) {
+            uses.push((use_, format!("{}#{}", binding.ident, n)));
+        }
+    }
+    for use_ in resolution.unresolved() {
+        uses.push((use_, format!("{}?", use_.path.segments[0].ident)));
+    }
+    uses.sort_by_key(|(use_, _)| {
+        let start = use_.path.segments[0].ident.span().start();
+        (start.line, start.column)
+    });
+    uses.into_iter().map(|(_, use_)| use_).collect()
+}
+
+#[test]
+fn test_shadowing() {
+    let f: ItemFn = syn::parse_str(
+        "
+        fn f(x: u8) -> u8 {
+            let x = x + 1;
+            let y = { let x = x * 2; x };
+            x + y + z
+        }
+        ",
+    )
+    .unwrap();
+    let resolution = scope::resolve(&f);
+    assert_eq!(uses(&resolution), ["x#0", "x#1", "x#2", "x#1", "y#0", "z?"]);
+}
+
+#[test]
+fn test_scopes() {
+    let block: Block = syn::parse_str(
+        "
+        {
+            let v = vec![1, 2];
+            let f = |v: u8| v + 1;
+            for v in v.iter() { v; }
+            match v.get(0) {
+                Some(v) if v > 0 => v,
+                _ => v.len(),
+            };
+            if let Some(w) = v.pop() { w } else { w };
+            while let Some(w) = v.pop() { w; }
+            fn inner() { v; }
+        }
+        ",
+    )
+    .unwrap();
+    let resolution = scope::resolve(&block);
+    assert_eq!(
+        uses(&resolution),
+        [
+            "v#1", "v#0", "v#2", "v#0", "v#3", "v#3", "v#0", "v#0", "w#0", "w?", "v#0", "w#1",
+            "v?",
+        ],
+    );
+}
+
+#[test]
+fn test_binding_of() {
+    let block: Block = syn::parse_str("{ let a = 1; let b = a; a }").unwrap();
+    let resolution = scope::resolve(&block);
+    let a = binding(&resolution, "a", 0);
+    let uses: Vec<_> = resolution.uses_of(a).collect();
+    assert_eq!(uses.len(), 2);
+    for use_ in uses {
+        assert!(std::ptr::eq(resolution.binding_of(use_).unwrap(), a));
+    }
+    assert_eq!(resolution.unresolved().count(), 0);
+}
+
+#[test]
+fn test_rename() {
+    let mut block: Block = parse_quote! {{
+        let x = S { x: 1 };
+        let S { x } = x;
+        let t = T { x };
+        let x = x + 1;
+        x
+    }};
+    let resolution = scope::resolve(&block);
+    let target = binding(&resolution, "x", 1);
+    let new_name: Ident = parse_quote!(field);
+    let mut rename = resolution.rename_binding(target, new_name).unwrap();
+    rename.visit_block_mut(&mut block);
+
+    let expected: Block = parse_quote! {{
+        let x = S { x: 1 };
+        let S { x: field } = x;
+        let t = T { x: field };
+        let x = field + 1;
+        x
+    }};
+    assert_eq!(block, expected);
+}
+
+#[test]
+fn test_rename_conflicts() {
+    let f: ItemFn = syn::parse_str(
+        "
+        fn f(a: u8, b: u8) -> u8 {
+            let c = a + 1;
+            let d = b;
+            c + d
+        }
+        ",
+    )
+    .unwrap();
+    let resolution = scope::resolve(&f);
+    let rename = |from: &str, to: &str| {
+        let new_name = Ident::new(to, Span::call_site());
+        resolution.rename_binding(binding(&resolution, from, 0), new_name)
+    };
+
+    // The use of `a` would be captured by the parameter `b`.
+    let err = rename("a", "b").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "renaming `a` to `b` would change the variable this refers to",
+    );
+    assert_eq!(err.span().start().line, 3);
+
+    // The renamed `c` would capture the use of `b` in the next statement.
+    assert!(rename("c", "b").is_err());
+
+    // The use of `c` comes after `d` is in scope.
+    assert!(rename("c", "d").is_err());
+
+    // The renamed `d` would shadow `c` in `c + d`.
+    assert!(rename("d", "c").is_err());
+    assert!(rename("d", "e").is_ok());
+    assert!(rename("a", "c").is_ok());
+}
+
+#[test]
+fn test_or_pattern() {
+    let mut f: ItemFn = parse_quote! {
+        fn f(e: E) -> u8 {
+            match e {
+                A(x) | B(x) | C(_, x) => x,
+            }
+        }
+    };
+    let resolution = scope::resolve(&f);
+    assert_eq!(resolution.bindings().filter(|b| b.ident == "x").count(), 1);
+    assert_eq!(uses(&resolution), ["e#0", "x#0"]);
+
+    let target = binding(&resolution, "x", 0);
+    let new_name: Ident = parse_quote!(y);
+    let mut rename = resolution.rename_binding(target, new_name).unwrap();
+    rename.visit_item_fn_mut(&mut f);
+
+    let expected: ItemFn = parse_quote! {
+        fn f(e: E) -> u8 {
+            match e {
+                A(y) | B(y) | C(_, y) => y,
+            }
+        }
+    };
+    assert_eq!(f, expected);
+}
+
+#[test]
+fn test_rename_macro() {
+    let f: ItemFn = syn::parse_str(
+        "
+        fn f(a: u8, b: u8) -> u8 {
+            println!(\"{}\", [a]);
+            let c = 1;
+            assert!(n > 0);
+            c + b
+        }
+        ",
+    )
+    .unwrap();
+    let resolution = scope::resolve(&f);
+    let rename = |from: &str, to: &str| {
+        let new_name = Ident::new(to, Span::call_site());
+        resolution.rename_binding(binding(&resolution, from, 0), new_name)
+    };
+
+    // The macro may use `a`.
+    let err = rename("a", "x").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "renaming `a` to `x` may change the variable this macro refers to",
+    );
+    assert_eq!(err.span().start().line, 3);
+    assert!(rename("b", "x").is_ok());
+
+    // The renamed `c` is not in scope in `println!`, but would be what `n`
+    // in `assert!` refers to.
+    assert!(rename("c", "a").is_ok());
+    assert!(rename("c", "n").is_err());
+}
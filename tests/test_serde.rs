@@ -0,0 +1,192 @@
+extern crate quote;
+extern crate serde;
+extern crate serde_json;
+extern crate syn;
+extern crate walkdir;
+
+mod features;
+
+use quote::quote;
+use serde::Deserialize;
+use serde_json::json;
+use std::thread;
+use syn::{parse_quote, Expr, File, Item, Type};
+use walkdir::WalkDir;
+
+#[test]
+fn test_round_trip() {
+    let file: File = parse_quote! {
+        #![allow(dead_code)]
+
+        use std::{fmt, io::Write as _};
+
+        #[derive(Debug)]
+        pub(crate) struct S<'a, T: ?Sized + 'a> where T: Clone {
+            r#type: &'a T,
+            pair: (u8,)
+        }
+
+        impl<'a, T> !Send for S<'a, T> {}
+
+        unsafe extern "C" fn f(x: *const u8) -> Option<[u8; 4]> {
+            let (a,) = (1,);
+            let s = b"bytes";
+            match x as usize {
+                0 | 1 if a > 0 => Some([b'a', 0x10u8, 1_000 as u8, 'c' as u8]),
+                _ => loop { break None },
+            }
+        }
+
+        macro_rules! m {
+            ($e:expr) => { $e + 1.5e3 };
+        }
+    };
+
+    let json = serde_json::to_string(&file).unwrap();
+    let back: File = serde_json::from_str(&json).unwrap();
+    assert!(back == file);
+    assert_eq!(quote!(#back).to_string(), quote!(#file).to_string());
+    assert_eq!(serde_json::to_string(&back).unwrap(), json);
+}
+
+#[test]
+fn test_round_trip_own_source() {
+    for entry in WalkDir::new("src").into_iter().chain(WalkDir::new("tests")) {
+        let entry = entry.unwrap();
+        if entry.path().extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let text = std::fs::read_to_string(entry.path()).unwrap();
+        let file = match syn::parse_file(&text) {
+            Ok(file) => file,
+            Err(_) => continue,
+        };
+        let json = serde_json::to_string(&file).unwrap();
+        let back: File = serde_json::from_str(&json).unwrap();
+        assert!(
+            back == file,
+            "serde round trip changes the syntax tree of {}",
+            entry.path().display(),
+        );
+    }
+}
+
+#[test]
+fn test_receiver() {
+    for input in &["fn f(self) {}", "fn f(&self) {}", "fn f(&'a mut self) {}"] {
+        let item: Item = syn::parse_str(input).unwrap();
+        let json = serde_json::to_string(&item).unwrap();
+        let back: Item = serde_json::from_str(&json).unwrap();
+        assert!(back == item, "{}", input);
+    }
+}
+
+#[test]
+fn test_json() {
+    let expr: Expr = parse_quote!(-a.0 + 1);
+    let expected = json!({
+        "Binary": {
+            "attrs": [],
+            "left": {
+                "Unary": {
+                    "attrs": [],
+                    "op": "Neg",
+                    "expr": {
+                        "Field": {
+                            "attrs": [],
+                            "base": {
+                                "Path": {
+                                    "attrs": [],
+                                    "qself": null,
+                                    "path": {
+                                        "leading_colon": false,
+                                        "segments": [
+                                            {
+                                                "ident": "a",
+                                                "arguments": "None",
+                                            },
+                                        ],
+                                        "segments_trailing_punct": false,
+                                    },
+                                },
+                            },
+                            "member": {
+                                "Unnamed": {
+                                    "index": 0,
+                                },
+                            },
+                        },
+                    },
+                },
+            },
+            "op": "Add",
+            "right": {
+                "Lit": {
+                    "attrs": [],
+                    "lit": {
+                        "Int": "1",
+                    },
+                },
+            },
+        },
+    });
+    assert_eq!(serde_json::to_value(&expr).unwrap(), expected);
+
+    // Fields with an empty value may be left out.
+    let ty: Type = serde_json::from_value(json!({
+        "Path": {
+            "path": {
+                "segments": [{ "ident": "u8", "arguments": "None" }],
+            },
+        },
+    }))
+    .unwrap();
+    assert_eq!(quote!(#ty).to_string(), "u8");
+}
+
+#[test]
+fn test_recursion_limit() {
+    let mut code = "a".to_owned();
+    for _ in 0..70 {
+        code += " + a";
+    }
+    let expr: Expr = syn::parse_str(&code).unwrap();
+    let json = serde_json::to_string(&expr).unwrap();
+    let error = serde_json::from_str::<Expr>(&json).unwrap_err();
+    assert!(error.to_string().starts_with("recursion limit exceeded"));
+
+    // Without the limit, the depth is bounded only by the stack.
+    let input = json.clone();
+    let back = thread::Builder::new()
+        .stack_size(64 * 1024 * 1024)
+        .spawn(move || {
+            let mut deserializer = serde_json::Deserializer::from_str(&input);
+            deserializer.disable_recursion_limit();
+            let back = Expr::deserialize(&mut deserializer).unwrap();
+            serde_json::to_string(&back).unwrap()
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(back, json);
+}
+
+#[test]
+fn test_errors() {
+    let error = |value| {
+        serde_json::from_value::<Type>(value)
+            .unwrap_err()
+            .to_string()
+    };
+    assert_eq!(
+        error(json!({ "Path": { "path": { "segments": [{ "ident": "a b" }] } } })),
+        "invalid value: string \"a b\", expected an identifier",
+    );
+    assert_eq!(error(json!({ "Path": {} })), "missing field `path`");
+    let len = json!({ "Lit": { "lit": { "Str": "1" } } });
+    assert_eq!(
+        error(json!({ "Array": { "elem": { "Infer": {} }, "len": len } })),
+        "invalid value: string \"1\", expected a string literal",
+    );
+    assert!(error(json!("Unknown")).starts_with("unknown variant `Unknown`"));
+}
@@ -0,0 +1,147 @@
+extern crate proc_macro2;
+extern crate syn;
+extern crate walkdir;
+
+mod features;
+
+use proc_macro2::Span;
+use syn::parse::{ParseStream, Parser};
+use syn::source::{SourceFile, SourceMap};
+use syn::spanned::Spanned;
+use syn::{braced, parse_quote, Expr, Ident, Item, ItemFn, Stmt};
+use walkdir::WalkDir;
+
+#[test]
+fn test_round_trip_own_source() {
+    for entry in WalkDir::new("src") {
+        let entry = entry.unwrap();
+        if entry.path().extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let text = std::fs::read_to_string(entry.path()).unwrap();
+        let file = SourceFile::parse(&text).unwrap();
+        assert!(
+            file.print() == text,
+            "failed to round trip {}",
+            entry.path().display(),
+        );
+    }
+}
+
+#[test]
+fn test_round_trip_shebang() {
+    let text = "\u{feff}#!/usr/bin/env rustx\n\n/* block */ fn main() {} // end";
+    let file = SourceFile::parse(text).unwrap();
+    assert_eq!(file.syntax().shebang.as_ref().unwrap(), "#!/usr/bin/env rustx");
+    assert_eq!(file.print(), text);
+}
+
+#[test]
+fn test_remove_item() {
+    let text = "\
+/// First.
+fn first() {}
+
+// Second.
+fn second() {}
+
+/// Third.
+fn third() {}
+";
+    let mut file = SourceFile::parse(text).unwrap();
+    file.syntax_mut().items.remove(1);
+    assert_eq!(
+        file.print(),
+        "\
+/// First.
+fn first() {}
+
+/// Third.
+fn third() {}
+",
+    );
+}
+
+#[test]
+fn test_insert_tokens() {
+    let text = "fn f() {\n    let x = 1; // one\n}\n";
+    let mut file = SourceFile::parse(text).unwrap();
+    if let Item::Fn(item) = &mut file.syntax_mut().items[0] {
+        item.block.stmts.push(parse_quote!(drop(x);));
+    }
+    assert_eq!(
+        file.print(),
+        "fn f() {\n    let x = 1; // one\ndrop (x) ;\n}\n",
+    );
+}
+
+#[test]
+fn test_trivia() {
+    let text = "struct S; // trailing\n\n// leading\nstruct T;\n";
+    let file = SourceFile::parse(text).unwrap();
+    let s = &file.syntax().items[0];
+    let t = &file.syntax().items[1];
+    assert_eq!(file.trailing_trivia(s.span()), Some(" // trailing\n"));
+    assert_eq!(file.leading_trivia(t.span()), Some("\n// leading\n"));
+    assert_eq!(file.leading_trivia(Span::call_site()), None);
+    assert_eq!(file.print_node(t), "struct T;");
+}
+
+#[test]
+fn test_source_map() {
+    let code = "fn f() {\n    let café = \"ü\"; g(café)\n}\n";
+    let map = SourceMap::new(code);
+    let item: ItemFn = syn::parse_str(code).unwrap();
+    let call = match &item.block.stmts[1] {
+        Stmt::Expr(Expr::Call(call)) => call,
+        _ => panic!("expected call"),
+    };
+
+    let range = map.byte_range(call.span()).unwrap();
+    assert_eq!(&code[range.clone()], "g(café)");
+    let start = map.line_column(range.start).unwrap();
+    assert_eq!((start.line, start.column), (2, 20));
+    assert_eq!(map.byte_offset(call.span().start()), Some(range.start));
+
+    let range = map.byte_range(call.args.span()).unwrap();
+    assert_eq!(&code[range], "café");
+    assert_eq!(map.byte_range(Span::call_site()), None);
+    assert_eq!(map.line_column(code.len() + 1), None);
+}
+
+#[test]
+fn test_source_map_shebang() {
+    let code = "\u{feff}#!/usr/bin/env rustx\nfn main() {}\n";
+    let (file, map) = syn::parse_file_with_positions(code).unwrap();
+    let range = map.byte_range(file.items[0].span()).unwrap();
+    assert_eq!(&code[range.clone()], "fn main() {}");
+    let start = map.line_column(range.start).unwrap();
+    assert_eq!((start.line, start.column), (2, 0));
+}
+
+#[test]
+fn test_source_map_error() {
+    let code = "struct S {\n    a: i32\n    b: i32,\n}";
+    let map = SourceMap::new(code);
+    let err = syn::parse_str::<Item>(code).unwrap_err();
+    let range = map.byte_range(err.span()).unwrap();
+    assert_eq!(&code[range], "b");
+}
+
+#[test]
+fn test_source_map_cursor() {
+    let code = "{ a } b";
+    let map = SourceMap::new(code);
+    let parser = |input: ParseStream| {
+        let content;
+        braced!(content in input);
+        assert_eq!(map.cursor_offset(content.cursor()), Some(2));
+        content.parse::<Ident>()?;
+        assert_eq!(map.cursor_offset(content.cursor()), Some(4));
+        assert_eq!(map.cursor_offset(input.cursor()), Some(6));
+        input.parse::<Ident>()?;
+        assert_eq!(map.cursor_offset(input.cursor()), Some(7));
+        Ok(())
+    };
+    parser.parse_str(code).unwrap();
+}
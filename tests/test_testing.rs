@@ -0,0 +1,112 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::testing;
+
+#[test]
+fn test_equal() {
+    testing::assert_tokens_eq(
+        quote! {
+            #[derive(Debug,)]
+            struct S<T,> { a: T, b: Vec<T,>, }
+            fn f(a: u8,) -> [u8; 2] { g(a,); [a, a,] }
+        },
+        quote! {
+            #[derive(Debug,)]
+            struct S<T> {
+                a: T,
+                b: Vec<T>
+            }
+
+            fn f(a: u8) -> [u8; 2] {
+                g(a);
+                [a, a]
+            }
+        },
+    );
+
+    // A trailing comma makes a one-element tuple.
+    let actual = quote! { type T = (u8,); };
+    let expected = quote! { type T = (u8); };
+    assert!(testing::compare_tokens(actual, expected).is_err());
+}
+
+#[test]
+fn test_modified() {
+    let actual = quote! {
+        struct S;
+        impl Default for S {
+            fn default() -> Self { S { a: 1 } }
+        }
+    };
+    let expected = quote! {
+        struct S;
+        impl Default for S {
+            fn default() -> Self { S { a: 0 } }
+        }
+    };
+    let mismatch = testing::compare_tokens(actual, expected).unwrap_err();
+    let report = r#"
+tokens differ at .items[1].items[0].block.stmts[0].fields[0].expr.lit:
+
+expected: 0
+  actual: 1
+
+expected item:
+    impl Default for S {
+        fn default() -> Self {
+            S { a: 0 }
+        }
+    }
+
+actual item:
+    impl Default for S {
+        fn default() -> Self {
+            S { a: 1 }
+        }
+    }"#;
+    assert_eq!(mismatch.to_string(), &report[1..]);
+}
+
+#[test]
+fn test_added() {
+    let actual = quote! {
+        fn f() {}
+        fn g() -> u8 { 0 }
+    };
+    let expected = quote! {
+        fn f() {}
+    };
+    let mismatch = testing::compare_tokens(actual, expected).unwrap_err();
+    let report = r#"
+tokens differ at .items[1]:
+
+expected: (nothing)
+actual:
+    fn g() -> u8 {
+        0
+    }"#;
+    assert_eq!(mismatch.to_string(), &report[1..]);
+}
+
+#[test]
+fn test_invalid() {
+    let actual = quote! { fn f() -> {} };
+    let expected = quote! { fn f() {} };
+    let mismatch = testing::compare_tokens(actual, expected).unwrap_err();
+    assert_eq!(
+        mismatch.to_string(),
+        "actual tokens are not valid Rust code: expected one of: `for`, parentheses, `fn`, \
+         `unsafe`, `extern`, identifier, `::`, `<`, square brackets, `*`, `&`, `!`, `impl`, \
+         `_`, lifetime\n\nactual tokens:\n    fn f () -> { }",
+    );
+}
+
+#[test]
+#[should_panic(expected = "tokens differ at .items[0].sig.ident:\n\nexpected: g\n  actual: f")]
+fn test_assert() {
+    testing::assert_tokens_eq(quote! { fn f() {} }, quote! { fn g() {} });
+}
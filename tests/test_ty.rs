@@ -0,0 +1,25 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::ToTokens;
+use syn::Type;
+
+#[test]
+fn test_tuple_trailing_comma() {
+    for input in &["(u8,)", "(u8, u16,)", "(u8, u16)"] {
+        let ty: Type = syn::parse_str(input).unwrap();
+        let expected: proc_macro2::TokenStream = input.parse().unwrap();
+        assert_eq!(ty.into_token_stream().to_string(), expected.to_string());
+    }
+
+    match syn::parse_str::<Type>("(u8, u16,)").unwrap() {
+        Type::Tuple(ty) => {
+            assert_eq!(ty.elems.len(), 2);
+            assert!(ty.elems.trailing_punct());
+        }
+        _ => panic!("expected tuple type"),
+    }
+}
@@ -0,0 +1,93 @@
+extern crate syn;
+
+mod features;
+
+use syn::node::NodeRef;
+use syn::visit_ancestors::{self, Ancestors, VisitAncestors};
+use syn::{parse_quote, Expr, ExprPath, File, Ident};
+
+#[derive(Default)]
+struct Callees {
+    in_method: Vec<String>,
+    elsewhere: Vec<String>,
+    idents: usize,
+}
+
+impl<'ast> VisitAncestors<'ast> for Callees {
+    fn visit_expr_path(&mut self, node: &'ast ExprPath, ancestors: &mut Ancestors<'ast>) {
+        let parent = match ancestors.parent() {
+            Some(NodeRef::Expr(expr)) => expr,
+            _ => panic!("expected the path to be wrapped in Expr"),
+        };
+        let is_callee = match ancestors.iter().rev().nth(1) {
+            Some(NodeRef::ExprCall(call)) => std::ptr::eq(&*call.func, parent),
+            _ => false,
+        };
+        if is_callee {
+            let name = node.path.segments[0].ident.to_string();
+            let in_method = ancestors.iter().any(|ancestor| match ancestor {
+                NodeRef::ImplItemMethod(_) => true,
+                _ => false,
+            });
+            if in_method {
+                self.in_method.push(name);
+            } else {
+                self.elsewhere.push(name);
+            }
+        }
+
+        let depth = ancestors.len();
+        visit_ancestors::visit_expr_path(self, node, ancestors);
+        assert_eq!(ancestors.len(), depth);
+    }
+
+    fn visit_ident(&mut self, _node: &'ast Ident, ancestors: &mut Ancestors<'ast>) {
+        assert!(!ancestors.is_empty());
+        self.idents += 1;
+    }
+}
+
+#[test]
+fn test_callees() {
+    let file: File = parse_quote! {
+        fn free() {
+            a(b);
+        }
+
+        impl S {
+            fn method(&self) {
+                c(d(e));
+                f.g(h);
+            }
+        }
+    };
+
+    let mut callees = Callees::default();
+    let mut ancestors = Ancestors::new();
+    callees.visit_file(&file, &mut ancestors);
+    assert!(ancestors.is_empty());
+
+    assert_eq!(callees.elsewhere, ["a"]);
+    assert_eq!(callees.in_method, ["c", "d"]);
+    assert_eq!(callees.idents, 11);
+}
+
+#[test]
+fn test_root() {
+    struct Depths(Vec<usize>);
+
+    impl<'ast> VisitAncestors<'ast> for Depths {
+        fn visit_expr(&mut self, node: &'ast Expr, ancestors: &mut Ancestors<'ast>) {
+            self.0.push(ancestors.len());
+            visit_ancestors::visit_expr(self, node, ancestors);
+        }
+    }
+
+    let expr: Expr = parse_quote!(-(x + 1));
+    let mut depths = Depths(Vec::new());
+    depths.visit_expr(&expr, &mut Ancestors::new());
+
+    // Every subexpression is nested two levels deeper than its parent
+    // expression: one for the enclosing Expr and one for its variant.
+    assert_eq!(depths.0, [0, 2, 4, 6, 6]);
+}